@@ -113,6 +113,41 @@ mod test {
         run_tests(test_dir(), "tests/rust_traits.kts");
     }
 
+    #[test]
+    fn test_tuples() {
+        run_tests(test_dir(), "tests/tuples.kts");
+    }
+
+    #[test]
+    fn test_streams() {
+        run_tests(test_dir(), "tests/streams.kts");
+    }
+
+    #[test]
+    fn test_iterators() {
+        run_tests(test_dir(), "tests/iterators.kts");
+    }
+
+    #[test]
+    fn test_closures() {
+        run_tests(test_dir(), "tests/closures.kts");
+    }
+
+    #[test]
+    fn test_static_methods() {
+        run_tests(test_dir(), "tests/static_methods.kts");
+    }
+
+    #[test]
+    fn test_arrays() {
+        run_tests(test_dir(), "tests/arrays.kts");
+    }
+
+    #[test]
+    fn test_constants() {
+        run_tests(test_dir(), "tests/constants.kts");
+    }
+
     fn test_dir() -> &'static Utf8Path {
         static TEST_TEMPDIR: OnceLock<Utf8PathBuf> = OnceLock::new();
         TEST_TEMPDIR.get_or_init(|| {
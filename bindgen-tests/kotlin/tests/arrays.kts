@@ -0,0 +1,20 @@
+import uniffi.uniffi_bindgen_tests.*
+
+assert(roundtripU8Array(listOf<UByte>(1u, 2u, 3u, 4u)) == listOf<UByte>(1u, 2u, 3u, 4u))
+assert(roundtripF32Array(listOf(0.5f, 1.5f, 2.5f)) == listOf(0.5f, 1.5f, 2.5f))
+assert(roundtripStringArray(listOf("a", "b")) == listOf("a", "b"))
+assert(roundtripEmptyArray(listOf()) == listOf<UInt>())
+assert(sumArray(listOf(1u, 2u, 3u, 4u)) == 10u)
+
+assert(roundtripVecOfArrays(listOf(listOf(1, 2), listOf(-3, -4))) == listOf(listOf(1, 2), listOf(-3, -4)))
+
+assert(roundtripArrayRec(ArrayRec(listOf(1u, 2u), listOf(true, false, true))) == ArrayRec(listOf(1u, 2u), listOf(true, false, true)))
+assert(roundtripArrayRec(ArrayRec(listOf(3u, 4u), null)) == ArrayRec(listOf(3u, 4u), null))
+
+// The length is checked before calling into Rust
+try {
+    roundtripU8Array(listOf<UByte>(1u, 2u, 3u))
+    throw RuntimeException("Should have thrown")
+} catch (e: IllegalArgumentException) {
+    // Expected
+}
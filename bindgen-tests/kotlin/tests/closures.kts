@@ -0,0 +1,25 @@
+import uniffi.uniffi_bindgen_tests.*
+
+// Kotlin functions passed to Rust
+assert(closureCall({ x -> x * 2u }, 21u) == 42u)
+assert(closureCallTwice({ s, n -> "$s$n" }) == "a12")
+assert(closureCallNoArgs({ listOf("a", "b") }) == listOf("a", "b"))
+
+// Closures without a return value, called from a different thread
+val received = mutableListOf<UInt?>()
+closureCallOnThread({ received.add(it) }, 1u)
+closureCallOnThread({ received.add(it) }, null)
+assert(received == listOf(1u, null))
+
+// Rust closures returned to Kotlin
+val addTwo = closureMakeAdder(2u)
+assert(addTwo(40u) == 42u)
+val counter = closureMakeCounter()
+assert(counter() == 1u)
+assert(counter() == 2u)
+
+// Passing Rust closures back to Rust
+assert(closureCall(addTwo, 1u) == 3u)
+val composed = closureCompose(addTwo, { x -> x * 10u })
+assert(composed(1u) == 30u)
+assert(closureCall(composed, 2u) == 40u)
@@ -17,6 +17,13 @@ assert(roundtripHashMap(mapOf("a" to 1u, "b" to 2u)) == mapOf("a" to 1u, "b" to
 assert(roundtripHashSet(setOf("a", "b", "c")) == setOf("a", "b", "c"))
 assert(roundtripHashMapU32Key(mapOf(1u to 2u, 2u to 4u)) == mapOf(1u to 2u, 2u to 4u))
 
+// Ordered collections keep their order, so compare the entries as lists
+assert(roundtripBtreeMap(mapOf("c" to 3u, "a" to 1u, "b" to 2u)).toList() == listOf("a" to 1u, "b" to 2u, "c" to 3u))
+assert(roundtripBtreeSet(setOf("c", "a", "b")).toList() == listOf("a", "b", "c"))
+assert(roundtripIndexMap(mapOf("c" to 3u, "a" to 1u, "b" to 2u)).toList() == listOf("c" to 3u, "a" to 1u, "b" to 2u))
+assert(roundtripIndexSet(setOf("c", "a", "b")).toList() == listOf("c", "a", "b"))
+assert(indexMapKeys(mapOf("c" to 3u, "a" to 1u, "b" to 2u)) == listOf("c", "a", "b"))
+
 assert(
     roundtripRecWithCollections(
         RecWithCollections(
@@ -0,0 +1,13 @@
+import uniffi.uniffi_bindgen_tests.*
+
+assert(MAX_ITEMS == 100u)
+assert(MIN_OFFSET == -42L)
+assert(SCALE == 1.5)
+assert(ENABLED)
+assert(GREETING == "hello")
+assert(MAYBE_COUNT == 7.toUShort())
+assert(SEPARATOR == "🦀")
+
+assert(DEFAULT_LEVEL == ConstantLevel.HIGH)
+assert(DEFAULT_LIMITS == ConstantLimits(10u, "", ConstantLevel.LOW, null))
+assert(DEFAULT_LIMITS == getDefaultLimits())
@@ -20,6 +20,14 @@ assert(
     roundtripComplexEnum(ComplexEnum.C(SimpleRec(a=30.toUByte()))) ==
     ComplexEnum.C(SimpleRec(a=30.toUByte())))
 
+// generic enums
+assert(roundtripLoadableU32(LoadableU32.Loading) == LoadableU32.Loading)
+assert(roundtripLoadableU32(LoadableU32.Loaded(5u)) == LoadableU32.Loaded(5u))
+assert(roundtripLoadableU32(LoadableU32.Failed("oops")) == LoadableU32.Failed("oops"))
+assert(
+    roundtripLoadableSimpleRec(LoadableSimpleRec.Loaded(SimpleRec(a=1.toUByte()))) ==
+    LoadableSimpleRec.Loaded(SimpleRec(a=1.toUByte())))
+
 // enum discriminants
 assert(ExplicitValuedEnum.FIRST.value == 1.toUByte())
 assert(ExplicitValuedEnum.SECOND.value == 2.toUByte())
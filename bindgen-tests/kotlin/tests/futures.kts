@@ -28,6 +28,11 @@ runBlocking {
     }
 }
 
+// Async runtime adapters
+runBlocking {
+    assert(asyncInTestRuntime())
+}
+
 // Objects and methods
 runBlocking {
     val obj = AsyncInterface("Alice")
@@ -85,3 +90,15 @@ runBlocking {
         CallbackInterfaceNumbers(a=10u, b=11u)
     ) == CallbackInterfaceNumbers(a=10u, b=11u))
 }
+
+// Cancellation
+runBlocking {
+    val tracker = CancellationTracker()
+    val job = launch { tracker.waitForever() }
+    // Let the job start and poll the Rust future
+    yield()
+    assert(!tracker.wasDropped())
+    job.cancelAndJoin()
+    // Cancelling the job should drop the Rust future
+    assert(tracker.wasDropped())
+}
@@ -25,5 +25,32 @@ assert(en.i.getValue() == 1u)
 // the following calls will fail if the argument name differs
 testInterface.methodWithMultiWordArg(theArgument="test")
 
+// Test interfaces stored behind a lock
+val mutexCounter = MutexCounter(1u)
+assert(mutexCounter.increment() == 2u)
+assert(mutexCounter.increment() == 3u)
+assert(mutexCounter.getValue() == 3u)
+assert(mutexCounterAdd(mutexCounter, 10u).getValue() == 13u)
+
+val rwlockCounter = RwLockCounter(1u)
+assert(rwlockCounter.increment() == 2u)
+assert(rwlockCounter.getValue() == 2u)
+assert(rwlockCounterGetValue(rwlockCounter) == 2u)
+
+// Panicking while the lock is held poisons it
+val poisonedCounter = MutexCounter(1u)
+try {
+    poisonedCounter.incrementAndPanic()
+    throw RuntimeException("Expected InternalException")
+} catch (e: InternalException) {
+    // expected
+}
+try {
+    poisonedCounter.getValue()
+    throw RuntimeException("Expected InternalException")
+} catch (e: InternalException) {
+    assert(e.message!!.contains("Lock poisoned"))
+}
+
 // Other bindgens test that we free references, but that's much harder for Kotlin.
 // For now, we don't test this at all
@@ -0,0 +1,26 @@
+import uniffi.uniffi_bindgen_tests.*
+
+assert(iterCountTo(3u).toList() == listOf(0u, 1u, 2u))
+assert(iterCountTo(0u).toList() == listOf<UInt>())
+assert(iterStrings(listOf("a", "b")).toList() == listOf("a", "b"))
+assert(iterOptionals(listOf(1u, null, 2u)).toList() == listOf(1u, null, 2u))
+
+// Items are fetched in chunks, make sure iterators that span multiple chunks work
+assert(iterCountTo(200u).toList() == (0u until 200u).toList())
+
+// Methods
+val counter = IterCounter(10u)
+assert(counter.count(3u).toList() == listOf(10u, 11u, 12u))
+
+// Stopping iteration early
+assert(iterCountTo(100u).take(2).toList() == listOf(0u, 1u))
+
+// Sequences can only be iterated once
+val sequence = iterCountTo(3u)
+assert(sequence.toList() == listOf(0u, 1u, 2u))
+try {
+    sequence.toList()
+    throw RuntimeException("Expected IllegalStateException")
+} catch (e: IllegalStateException) {
+    // expected
+}
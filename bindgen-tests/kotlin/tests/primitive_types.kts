@@ -43,6 +43,40 @@ assert(roundtripF32(0.5f) == 0.5f)
 assert(roundtripF64(-3.5) == -3.5)
 assert(roundtripBool(true) == true)
 assert(roundtripString("ABC") == "ABC")
+// 128-bit integers map to BigInteger
+val u128Max = java.math.BigInteger.ONE.shiftLeft(128).subtract(java.math.BigInteger.ONE)
+val i128Min = java.math.BigInteger.ONE.shiftLeft(127).negate()
+assert(roundtripU128(u128Max) == u128Max)
+assert(roundtripU128(java.math.BigInteger.ZERO) == java.math.BigInteger.ZERO)
+assert(roundtripI128(i128Min) == i128Min)
+assert(roundtripI128(java.math.BigInteger.valueOf(-1)) == java.math.BigInteger.valueOf(-1))
+assert(roundtripVecI128(listOf(i128Min, java.math.BigInteger.TEN)) == listOf(i128Min, java.math.BigInteger.TEN))
+assert(outputU128Max() == u128Max)
+assert(outputI128Min() == i128Min)
+try {
+    roundtripU128(u128Max.add(java.math.BigInteger.ONE))
+    throw RuntimeException("Should have thrown")
+} catch (e: IllegalArgumentException) {
+    // Expected
+}
+try {
+    roundtripI128(i128Min.subtract(java.math.BigInteger.ONE))
+    throw RuntimeException("Should have thrown")
+} catch (e: IllegalArgumentException) {
+    // Expected
+}
+// Chars map to single code point strings
+assert(roundtripChar("a") == "a")
+assert(roundtripChar("\uD83E\uDD80") == "\uD83E\uDD80")
+assert(roundtripVecChar(listOf("a", "\u00e9")) == listOf("a", "\u00e9"))
+assert(firstChar("\uD83E\uDD80crab") == "\uD83E\uDD80")
+assert(firstChar("") == null)
+try {
+    roundtripChar("ab")
+    throw RuntimeException("Should have thrown")
+} catch (e: IllegalArgumentException) {
+    // Expected
+}
 // Test calling a function with lots of args
 // This function will sum up all the numbers, then negate the value since we passed in `true`
 assert(sumWithManyTypes(
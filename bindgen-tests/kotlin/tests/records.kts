@@ -2,6 +2,16 @@ import uniffi.uniffi_bindgen_tests.*
 
 assert(roundtripSimpleRec(SimpleRec(a=42.toUByte())) == SimpleRec(a=42.toUByte()))
 assert(UnitRec() == UnitRec())
+assert(roundtripUnitRec(UnitRec()) == UnitRec())
+assert(roundtripTupleStructRec(TupleStructRec(v0=1.toUByte(), v1="one")) == TupleStructRec(v0=1.toUByte(), v1="one"))
+assert(createSkippedFieldsRec(1.toUByte()) == SkippedFieldsRec(a=1.toUByte()))
+val (skippedCache, skippedMagic) = getSkippedFieldValues(createSkippedFieldsRec(1.toUByte()))
+assert(skippedCache.isEmpty() && skippedMagic == 42u)
+assert(roundtripRenamedTupleStructRec(RenamedTupleStructRec(x=1.5, y=-2.5)) == RenamedTupleStructRec(x=1.5, y=-2.5))
+// generic records
+val simpleRecPage = SimpleRecPage(items=listOf(SimpleRec(a=1.toUByte()), SimpleRec(a=2.toUByte())), next="cursor")
+assert(roundtripSimpleRecPage(simpleRecPage) == simpleRecPage)
+assert(roundtripStringPage(StringPage(items=listOf("one", "two"), next=null)) == StringPage(items=listOf("one", "two"), next=null))
 assert(
   roundtripComplexRec(
     ComplexRec(
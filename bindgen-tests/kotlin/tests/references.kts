@@ -12,3 +12,21 @@ assert(callDoubleValue(i, 3u) == 6u)
 
 val t = createReferenceTestTraitInterface()
 assert(callTripleValueTraitInterface(t, 10u) == 30u)
+
+val records = listOf(
+    ReferenceTestRecord("a", listOf(1u, 2u)),
+    ReferenceTestRecord("b", listOf()),
+)
+val getters = BorrowedGetters("getters", byteArrayOf(0, 1, -1), records)
+assert(getters.name() == "getters")
+assert(getters.data().contentEquals(byteArrayOf(0, 1, -1)))
+assert(getters.records() == records)
+assert(getters.firstRecord() == records[0])
+assert(getters.findRecord("b") == records[1])
+assert(getters.findRecord("c") == null)
+assert(getters.kind() == ReferenceTestEnum.Named("getters"))
+
+val label = LockedLabel("first")
+assert(label.label() == "first")
+label.setLabel("second")
+assert(label.label() == "second")
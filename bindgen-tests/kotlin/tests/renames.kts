@@ -19,6 +19,12 @@ assert(obj.renamedMethod() == 123)
 val traitImpl = createTraitImpl(5)
 assert(traitImpl.renamedTraitMethod(10) == 50)
 
+// Names from serde attributes
+val serdeRec = SerdeRenamedRecord(firstValue=1, renamedBySerde=2)
+assert(roundtripSerdeRenamedRecord(serdeRec) == serdeRec)
+assert(roundtripSerdeRenamedEnum(SerdeRenamedEnum.RenamedBySerde(innerValue=3)) == SerdeRenamedEnum.RenamedBySerde(innerValue=3))
+assert(roundtripSerdeRenamedEnum(SerdeRenamedEnum.RenamedByUniffi) == SerdeRenamedEnum.RenamedByUniffi)
+
 val ktRec = KtRecord(ktItem=100)
 assert(ktRec.ktItem == 100)
 
@@ -19,3 +19,27 @@ assert(RustTraitTest(1, 2) <= RustTraitTest(1, 3)
 // The Rust code only uses `a` for the hash
 assert(RustTraitTest(1, 2).hashCode() == RustTraitTest(1, 3).hashCode())
 assert(RustTraitTest(2, 2).hashCode() != RustTraitTest(1, 2).hashCode())
+
+// `Clone` copies the Rust value, so the copy is independent of the original
+val items = RustTraitList.parse("a,b")
+val copied = items.clone()
+items.push("c")
+assert(copied.items() == listOf("a", "b"))
+
+// `Default` generates a `default()` factory
+assert(RustTraitList.default().items().isEmpty())
+assert(RustTraitFloat.default() == RustTraitFloat(0.0))
+
+// `FromStr` generates a `parse()` factory that throws
+assert(RustTraitList.parse("a,b,c").items() == listOf("a", "b", "c"))
+try {
+    RustTraitList.parse("")
+    throw RuntimeException("Should have thrown")
+} catch (e: RustTraitParseException.Empty) {
+    // Expected
+}
+
+// `PartialOrd` returns `null` for values that can't be compared
+assert(RustTraitFloat(1.0).partialCompareTo(RustTraitFloat(2.0))!! < 0)
+assert(RustTraitFloat(2.0).partialCompareTo(RustTraitFloat(1.0))!! > 0)
+assert(RustTraitFloat(Double.NaN).partialCompareTo(RustTraitFloat(1.0)) == null)
@@ -0,0 +1,26 @@
+import kotlinx.coroutines.*
+import uniffi.uniffi_bindgen_tests.*
+
+// Objects
+assert(StaticMethodObject.defaultValue() == 42u)
+assert(StaticMethodObject.fromDefault().value() == 42u)
+assert(StaticMethodObject.checkedValue(5) == 5u)
+try {
+    StaticMethodObject.checkedValue(-1)
+    throw RuntimeException("Expected StaticMethodException.Negative")
+} catch (e: StaticMethodException.Negative) {
+    // expected
+}
+runBlocking {
+    assert(StaticMethodObject.asyncDefaultValue() == 42u)
+}
+
+// Records
+val origin = StaticMethodPoint.origin()
+assert(origin == StaticMethodPoint(0, 0))
+assert(origin.sum() == 0)
+
+// Enums
+assert(StaticMethodDirection.parse("north") == StaticMethodDirection.NORTH)
+assert(StaticMethodDirection.parse("south") == StaticMethodDirection.SOUTH)
+assert(StaticMethodDirection.parse("west") == null)
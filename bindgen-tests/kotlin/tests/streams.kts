@@ -0,0 +1,35 @@
+import kotlinx.coroutines.*
+import kotlinx.coroutines.flow.*
+import uniffi.uniffi_bindgen_tests.*
+
+runBlocking {
+    assert(streamCountTo(3u).toList() == listOf(0u, 1u, 2u))
+    assert(streamCountTo(0u).toList() == listOf<UInt>())
+    assert(streamStrings(listOf("a", "b")).toList() == listOf("a", "b"))
+    assert(streamOptionals(listOf(1u, null, 2u)).toList() == listOf(1u, null, 2u))
+    assert(streamCountToWithPending(3u).toList() == listOf(0u, 1u, 2u))
+    assert(streamInRuntime(2u).toList() == listOf(true, true))
+}
+
+// Methods
+runBlocking {
+    val counter = StreamCounter(10u)
+    assert(counter.count(3u).toList() == listOf(10u, 11u, 12u))
+}
+
+// Stopping collection early frees the stream
+runBlocking {
+    assert(streamCountTo(100u).take(2).toList() == listOf(0u, 1u))
+}
+
+// Streams can only be collected once
+runBlocking {
+    val flow = streamCountTo(3u)
+    assert(flow.toList() == listOf(0u, 1u, 2u))
+    try {
+        flow.toList()
+        throw RuntimeException("Expected IllegalStateException")
+    } catch (e: IllegalStateException) {
+        // expected
+    }
+}
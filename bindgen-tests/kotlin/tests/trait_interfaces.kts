@@ -215,3 +215,30 @@ fun checkForeignOnlyImpl(impl: TestForeignOnlyTraitInterface) {
 
 checkForeignOnlyImpl(ForeignOnlyImpl())
 checkForeignOnlyImpl(roundtripTestForeignOnlyTrait(ForeignOnlyImpl()))
+
+// Trait with default methods: foreign implementations only need to implement `getValue`
+open class DefaultsImpl(val value: UInt) : TestTraitWithDefaults {
+    override fun getValue() = value
+}
+
+class DefaultsOverrideImpl(value: UInt) : DefaultsImpl(value) {
+    override fun describe() = "kotlin value: $value"
+}
+
+val rustDefaultsImpl = createTestTraitWithDefaults(42u)
+assert(rustDefaultsImpl.getValuePlus(1u) == 43u)
+assert(rustDefaultsImpl.getValueChecked() == 42u)
+assert(rustDefaultsImpl.describe() == "value: 42")
+
+val kotlinDefaultsImpl = DefaultsImpl(42u)
+assert(kotlinDefaultsImpl.getValuePlus(1u) == 43u)
+assert(invokeTestTraitWithDefaultsGetValuePlus(kotlinDefaultsImpl, 2u) == 44u)
+assert(invokeTestTraitWithDefaultsGetValueChecked(kotlinDefaultsImpl) == 42u)
+assert(invokeTestTraitWithDefaultsDescribe(kotlinDefaultsImpl) == "value: 42")
+try {
+    invokeTestTraitWithDefaultsGetValueChecked(DefaultsImpl(0u))
+    throw RuntimeException("Expected TestException.Failure1")
+} catch (e: TestException.Failure1) {
+    // expected
+}
+assert(invokeTestTraitWithDefaultsDescribe(DefaultsOverrideImpl(42u)) == "kotlin value: 42")
@@ -0,0 +1,19 @@
+import uniffi.uniffi_bindgen_tests.*
+
+assert(roundtripPair(Pair("a", 1u)) == Pair("a", 1u))
+assert(roundtripTriple(Triple(1.toUByte(), true, 2.5)) == Triple(1.toUByte(), true, 2.5))
+assert(roundtripTuple4(Tuple4(-1, "b", null, false)) == Tuple4(-1, "b", null, false))
+assert(roundtripTuple4(Tuple4(-1, "b", 2u, false)) == Tuple4(-1, "b", 2u, false))
+assert(roundtripNestedTuple(Pair(Pair(1.toUByte(), 2.toUByte()), "c")) == Pair(Pair(1.toUByte(), 2.toUByte()), "c"))
+assert(splitAtFirstSpace("hello tuple world") == Pair("hello", "tuple world"))
+
+// Destructuring works like any other Kotlin pair
+val (first, rest) = splitAtFirstSpace("hello world")
+assert(first == "hello")
+assert(rest == "world")
+
+assert(roundtripVecOfPairs(listOf("a" to 1u, "b" to 2u)) == listOf("a" to 1u, "b" to 2u))
+assert(roundtripVecOfPairs(listOf()) == listOf<Pair<String, UInt>>())
+
+assert(roundtripTupleRec(TupleRec(Pair(1u, "a"), Pair(true, false))) == TupleRec(Pair(1u, "a"), Pair(true, false)))
+assert(roundtripTupleRec(TupleRec(Pair(2u, "b"), null)) == TupleRec(Pair(2u, "b"), null))
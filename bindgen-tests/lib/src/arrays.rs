@@ -0,0 +1,45 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+// Rec that contains a fixed-size array
+#[derive(uniffi::Record)]
+pub struct ArrayRec {
+    pub a: [u32; 2],
+    pub b: Option<[bool; 3]>,
+}
+
+#[uniffi::export]
+pub fn roundtrip_u8_array(a: [u8; 4]) -> [u8; 4] {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_f32_array(a: [f32; 3]) -> [f32; 3] {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_string_array(a: [String; 2]) -> [String; 2] {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_empty_array(a: [u32; 0]) -> [u32; 0] {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_vec_of_arrays(a: Vec<[i32; 2]>) -> Vec<[i32; 2]> {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_array_rec(a: ArrayRec) -> ArrayRec {
+    a
+}
+
+#[uniffi::export]
+pub fn sum_array(a: [u32; 4]) -> u32 {
+    a.iter().sum()
+}
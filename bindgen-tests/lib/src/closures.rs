@@ -0,0 +1,53 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Functions that input and return closures.
+
+use std::sync::{Arc, Mutex};
+
+#[uniffi::export]
+pub fn closure_call(f: Box<dyn Fn(u32) -> u32 + Send + Sync>, value: u32) -> u32 {
+    f(value)
+}
+
+#[uniffi::export]
+pub fn closure_call_twice(f: Arc<dyn Fn(String, u32) -> String + Send + Sync>) -> String {
+    let first = f("a".to_string(), 1);
+    f(first, 2)
+}
+
+#[uniffi::export]
+pub fn closure_call_no_args(f: Box<dyn Fn() -> Vec<String> + Send + Sync>) -> Vec<String> {
+    f()
+}
+
+/// Call a closure that doesn't return anything, from a different thread
+#[uniffi::export]
+pub fn closure_call_on_thread(f: Arc<dyn Fn(Option<u32>) + Send + Sync>, value: Option<u32>) {
+    std::thread::spawn(move || f(value)).join().unwrap();
+}
+
+#[uniffi::export]
+pub fn closure_make_adder(amount: u32) -> Box<dyn Fn(u32) -> u32 + Send + Sync> {
+    Box::new(move |value| value + amount)
+}
+
+#[uniffi::export]
+pub fn closure_make_counter() -> Arc<dyn Fn() -> u32 + Send + Sync> {
+    let count = Mutex::new(0);
+    Arc::new(move || {
+        let mut count = count.lock().unwrap();
+        *count += 1;
+        *count
+    })
+}
+
+/// Compose two closures, the result can be passed back to the foreign code
+#[uniffi::export]
+pub fn closure_compose(
+    f: Arc<dyn Fn(u32) -> u32 + Send + Sync>,
+    g: Arc<dyn Fn(u32) -> u32 + Send + Sync>,
+) -> Arc<dyn Fn(u32) -> u32 + Send + Sync> {
+    Arc::new(move |value| g(f(value)))
+}
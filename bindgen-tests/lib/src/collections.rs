@@ -2,7 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use std::collections::{HashMap, HashSet};
+use indexmap::{IndexMap, IndexSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 // Simple tests for inputting and returning arguments
 
@@ -111,6 +112,34 @@ pub fn roundtrip_hash_map_u32_key(a: HashMap<u32, u32>) -> HashMap<u32, u32> {
     a
 }
 
+// Ordered collections.  The bindings should preserve the order on both sides.
+
+#[uniffi::export]
+pub fn roundtrip_btree_map(a: BTreeMap<String, u32>) -> BTreeMap<String, u32> {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_btree_set(a: BTreeSet<String>) -> BTreeSet<String> {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_index_map(a: IndexMap<String, u32>) -> IndexMap<String, u32> {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_index_set(a: IndexSet<String>) -> IndexSet<String> {
+    a
+}
+
+// Return the keys in the order Rust received them, to check that lowering preserves the order.
+#[uniffi::export]
+pub fn index_map_keys(a: IndexMap<String, u32>) -> Vec<String> {
+    a.into_keys().collect()
+}
+
 #[uniffi::export]
 pub fn roundtrip_rec_with_collections(a: RecWithCollections) -> RecWithCollections {
     a
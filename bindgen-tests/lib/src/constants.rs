@@ -0,0 +1,61 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, uniffi::Enum)]
+pub enum ConstantLevel {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct ConstantLimits {
+    pub max_items: u32,
+    pub label: String,
+    pub level: ConstantLevel,
+    pub ratio: Option<f64>,
+    #[uniffi(skip)]
+    pub lookups: u32,
+}
+
+/// The maximum number of items
+#[uniffi::export]
+pub const MAX_ITEMS: u32 = 100;
+
+#[uniffi::export]
+pub const MIN_OFFSET: i64 = -42;
+
+#[uniffi::export]
+pub const SCALE: f64 = 1.5;
+
+#[uniffi::export]
+pub const ENABLED: bool = true;
+
+#[uniffi::export]
+pub const GREETING: &str = "hello";
+
+// Outside the BMP, to check that bindings don't assume a single UTF-16 code unit
+#[uniffi::export]
+pub const SEPARATOR: char = '🦀';
+
+#[uniffi::export]
+pub static MAYBE_COUNT: Option<u16> = Some(7);
+
+#[uniffi::export]
+pub const DEFAULT_LEVEL: ConstantLevel = ConstantLevel::High;
+
+// Fields are given out of order to check that bindings use the definition order
+#[uniffi::export]
+pub const DEFAULT_LIMITS: ConstantLimits = ConstantLimits {
+    label: String::new(),
+    max_items: 10,
+    level: ConstantLevel::Low,
+    ratio: None,
+    lookups: 0,
+};
+
+// Returns the same value as `DEFAULT_LIMITS`, so tests can compare the two.
+#[uniffi::export]
+pub fn get_default_limits() -> ConstantLimits {
+    DEFAULT_LIMITS
+}
@@ -32,6 +32,27 @@ pub enum ComplexEnum {
     C { value: SimpleRec },
 }
 
+// Enum with variant fields that aren't exposed to the foreign side
+#[derive(uniffi::Enum)]
+pub enum SkippedFieldsEnum {
+    A {
+        value: u8,
+        #[uniffi(skip)]
+        cache: Option<String>,
+    },
+    B(u8, #[uniffi(skip = String::from("default"))] String),
+}
+
+// Generic enum, each instantiation is exposed as a separate enum
+#[derive(uniffi::Enum)]
+pub enum Loadable<T> {
+    Loading,
+    Loaded { value: T },
+    Failed(String),
+}
+
+uniffi::instantiate!(Loadable<u32> as LoadableU32, Loadable<SimpleRec> as LoadableSimpleRec);
+
 // Test enum with explicit discriminant values and gaps
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, uniffi::Enum)]
@@ -67,3 +88,30 @@ pub fn roundtrip_enum_with_data(en: EnumWithData) -> EnumWithData {
 pub fn roundtrip_complex_enum(en: ComplexEnum) -> ComplexEnum {
     en
 }
+
+#[uniffi::export]
+pub fn roundtrip_loadable_u32(en: Loadable<u32>) -> Loadable<u32> {
+    en
+}
+
+#[uniffi::export]
+pub fn roundtrip_loadable_simple_rec(en: Loadable<SimpleRec>) -> Loadable<SimpleRec> {
+    en
+}
+
+#[uniffi::export]
+pub fn create_skipped_fields_enum(value: u8) -> SkippedFieldsEnum {
+    SkippedFieldsEnum::A {
+        value,
+        cache: Some("cached".to_string()),
+    }
+}
+
+/// Get the value of the skipped field, which was filled in when the enum was lifted
+#[uniffi::export]
+pub fn get_skipped_field_value(en: SkippedFieldsEnum) -> Option<String> {
+    match en {
+        SkippedFieldsEnum::A { cache, .. } => cache,
+        SkippedFieldsEnum::B(_, skipped) => Some(skipped),
+    }
+}
@@ -8,7 +8,14 @@
 
 use crate::callback_interfaces::CallbackInterfaceNumbers;
 use crate::errors::TestError;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
 #[uniffi::export]
 pub async fn async_roundtrip_u8(v: u8) -> u8 {
@@ -75,6 +82,33 @@ pub async fn async_roundtrip_map(v: HashMap<String, String>) -> HashMap<String,
     v
 }
 
+/// Async runtime adapter that marks the current thread while it's polling the inner future
+pub struct TestRuntimeAdapter;
+
+thread_local! {
+    static IN_TEST_RUNTIME: Cell<bool> = const { Cell::new(false) };
+}
+
+impl uniffi::AsyncRuntimeAdapter for TestRuntimeAdapter {
+    fn wrap<F, T>(future: F) -> impl uniffi::UniffiCompatibleFuture<T>
+    where
+        F: uniffi::UniffiCompatibleFuture<T>,
+    {
+        let mut future = Box::pin(future);
+        std::future::poll_fn(move |context| {
+            IN_TEST_RUNTIME.set(true);
+            let poll = future.as_mut().poll(context);
+            IN_TEST_RUNTIME.set(false);
+            poll
+        })
+    }
+}
+
+#[uniffi::export(async_runtime = TestRuntimeAdapter)]
+pub async fn async_in_test_runtime() -> bool {
+    IN_TEST_RUNTIME.get()
+}
+
 #[uniffi::export]
 pub async fn async_throw_error() -> Result<(), TestError> {
     Err(TestError::Failure1)
@@ -97,6 +131,40 @@ impl AsyncInterface {
     }
 }
 
+/// Tracks whether a never-ending future has been dropped, to test cancellation
+#[derive(uniffi::Object)]
+pub struct CancellationTracker {
+    dropped: AtomicBool,
+}
+
+struct DropGuard<'a>(&'a AtomicBool);
+
+impl Drop for DropGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[uniffi::export]
+impl CancellationTracker {
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            dropped: AtomicBool::new(false),
+        })
+    }
+
+    /// Never completes, the only way out is to cancel the call
+    pub async fn wait_forever(&self) -> Result<(), TestError> {
+        let _guard = DropGuard(&self.dropped);
+        std::future::pending().await
+    }
+
+    pub fn was_dropped(&self) -> bool {
+        self.dropped.load(Ordering::SeqCst)
+    }
+}
+
 #[derive(uniffi::Record)]
 pub struct AsyncRecord {
     name: String,
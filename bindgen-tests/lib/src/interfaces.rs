@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
 
 #[derive(uniffi::Object)]
 pub struct TestInterface {
@@ -73,3 +73,70 @@ pub enum TestInterfaceEnum {
     One { i: Arc<TestInterface> },
     Two { i: Arc<TestInterface> },
 }
+
+// Test interfaces stored behind a lock, which can have `&mut self` methods
+#[derive(uniffi::Object)]
+#[uniffi(mutex)]
+pub struct MutexCounter {
+    value: u32,
+}
+
+#[uniffi::export]
+impl MutexCounter {
+    #[uniffi::constructor]
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn increment(&mut self) -> u32 {
+        self.value += 1;
+        self.value
+    }
+
+    pub fn get_value(&self) -> u32 {
+        self.value
+    }
+
+    /// Panic while holding the lock, which poisons it
+    pub fn increment_and_panic(&mut self) {
+        self.value += 1;
+        panic!("MutexCounter panic");
+    }
+}
+
+#[uniffi::export]
+pub fn mutex_counter_add(
+    counter: Arc<Mutex<MutexCounter>>,
+    amount: u32,
+) -> Arc<Mutex<MutexCounter>> {
+    counter.lock().unwrap().value += amount;
+    counter
+}
+
+#[derive(uniffi::Object)]
+#[uniffi(rwlock)]
+pub struct RwLockCounter {
+    value: u32,
+}
+
+#[uniffi::export]
+impl RwLockCounter {
+    #[uniffi::constructor]
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn increment(&mut self) -> u32 {
+        self.value += 1;
+        self.value
+    }
+
+    pub fn get_value(&self) -> u32 {
+        self.value
+    }
+}
+
+#[uniffi::export]
+pub fn rwlock_counter_get_value(counter: Arc<RwLock<RwLockCounter>>) -> u32 {
+    counter.read().unwrap().value
+}
@@ -0,0 +1,38 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Functions and methods that return iterators.
+
+#[uniffi::export]
+pub fn iter_count_to(n: u32) -> impl Iterator<Item = u32> + Send {
+    0..n
+}
+
+#[uniffi::export]
+pub fn iter_strings(items: Vec<String>) -> Box<dyn Iterator<Item = String> + Send> {
+    Box::new(items.into_iter())
+}
+
+/// `None` items need to be distinguished from the end of the iterator
+#[uniffi::export]
+pub fn iter_optionals(items: Vec<Option<u32>>) -> uniffi::Iter<Option<u32>> {
+    uniffi::Iter::new(items.into_iter())
+}
+
+#[derive(uniffi::Object)]
+pub struct IterCounter {
+    start: u32,
+}
+
+#[uniffi::export]
+impl IterCounter {
+    #[uniffi::constructor]
+    pub fn new(start: u32) -> Self {
+        Self { start }
+    }
+
+    pub fn count(&self, len: u32) -> impl Iterator<Item = u32> + Send {
+        self.start..self.start + len
+    }
+}
@@ -10,6 +10,12 @@ pub mod bytes;
 #[cfg(feature = "callback_interfaces")]
 pub mod callback_interfaces;
 
+#[cfg(feature = "closures")]
+pub mod closures;
+
+#[cfg(feature = "constants")]
+pub mod constants;
+
 #[cfg(feature = "collections")]
 pub mod collections;
 
@@ -34,6 +40,9 @@ pub mod futures;
 #[cfg(feature = "interfaces")]
 pub mod interfaces;
 
+#[cfg(feature = "iterators")]
+pub mod iterators;
+
 #[cfg(feature = "options")]
 pub mod options;
 
@@ -58,12 +67,23 @@ pub mod rust_traits;
 #[cfg(feature = "simple_fns")]
 pub mod simple_fns;
 
+#[cfg(feature = "static_methods")]
+pub mod static_methods;
+
+#[cfg(feature = "streams")]
+pub mod streams;
+
 #[cfg(feature = "time")]
 pub mod time;
 
 #[cfg(feature = "trait_interfaces")]
 pub mod trait_interfaces;
 
+#[cfg(feature = "arrays")]
+pub mod arrays;
+#[cfg(feature = "tuples")]
+pub mod tuples;
+
 // Utility functions for the Rust tests
 pub mod test_util {
     use camino::{Utf8Path, Utf8PathBuf};
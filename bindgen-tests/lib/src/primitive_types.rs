@@ -172,6 +172,46 @@ pub fn roundtrip_string(a: String) -> String {
     a
 }
 
+#[uniffi::export]
+pub fn roundtrip_u128(a: u128) -> u128 {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_i128(a: i128) -> i128 {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_vec_i128(a: Vec<i128>) -> Vec<i128> {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_char(a: char) -> char {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_vec_char(a: Vec<char>) -> Vec<char> {
+    a
+}
+
+#[uniffi::export]
+pub fn first_char(a: String) -> Option<char> {
+    a.chars().next()
+}
+
+#[uniffi::export]
+pub fn output_u128_max() -> u128 {
+    u128::MAX
+}
+
+#[uniffi::export]
+pub fn output_i128_min() -> i128 {
+    i128::MIN
+}
+
 /// Complex test: input a bunch of different values and add them together
 #[uniffi::export]
 #[allow(clippy::too_many_arguments)]
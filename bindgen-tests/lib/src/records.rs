@@ -17,7 +17,33 @@ impl SimpleRec {
 #[derive(uniffi::Record)]
 pub struct UnitRec;
 
-// Note: tuple-style records are currently not supported
+#[derive(uniffi::Record)]
+pub struct TupleStructRec(pub u8, pub String);
+
+#[derive(uniffi::Record)]
+pub struct RenamedTupleStructRec(#[uniffi(name = "x")] pub f64, #[uniffi(name = "y")] pub f64);
+
+// Record with fields that aren't exposed to the foreign side
+#[derive(uniffi::Record)]
+pub struct SkippedFieldsRec {
+    pub a: u8,
+    #[uniffi(skip)]
+    pub cache: Vec<u8>,
+    #[uniffi(skip = 42)]
+    pub magic: u32,
+}
+
+#[derive(uniffi::Record)]
+pub struct SkippedFieldsTupleRec(pub u8, #[uniffi(skip)] pub Vec<u8>, pub String);
+
+// Generic record, each instantiation is exposed as a separate record
+#[derive(uniffi::Record)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next: Option<String>,
+}
+
+uniffi::instantiate!(Page<SimpleRec> as SimpleRecPage, Page<String> as StringPage);
 
 #[derive(uniffi::Record)]
 pub struct ComplexRec {
@@ -40,6 +66,52 @@ pub fn roundtrip_simple_rec(rec: SimpleRec) -> SimpleRec {
     rec
 }
 
+#[uniffi::export]
+pub fn roundtrip_unit_rec(rec: UnitRec) -> UnitRec {
+    rec
+}
+
+#[uniffi::export]
+pub fn roundtrip_tuple_struct_rec(rec: TupleStructRec) -> TupleStructRec {
+    rec
+}
+
+#[uniffi::export]
+pub fn roundtrip_renamed_tuple_struct_rec(rec: RenamedTupleStructRec) -> RenamedTupleStructRec {
+    rec
+}
+
+#[uniffi::export]
+pub fn create_skipped_fields_rec(a: u8) -> SkippedFieldsRec {
+    SkippedFieldsRec {
+        a,
+        cache: vec![1, 2, 3],
+        magic: 0,
+    }
+}
+
+/// Get the values of the skipped fields, which were filled in when the record was lifted
+#[uniffi::export]
+pub fn get_skipped_field_values(rec: SkippedFieldsRec) -> (Vec<u8>, u32) {
+    (rec.cache, rec.magic)
+}
+
+#[uniffi::export]
+pub fn roundtrip_skipped_fields_tuple_rec(rec: SkippedFieldsTupleRec) -> SkippedFieldsTupleRec {
+    assert!(rec.1.is_empty());
+    rec
+}
+
+#[uniffi::export]
+pub fn roundtrip_simple_rec_page(page: Page<SimpleRec>) -> Page<SimpleRec> {
+    page
+}
+
+#[uniffi::export]
+pub fn roundtrip_string_page(page: Page<String>) -> Page<String> {
+    page
+}
+
 #[uniffi::export]
 pub fn roundtrip_complex_rec(rec: ComplexRec) -> ComplexRec {
     rec
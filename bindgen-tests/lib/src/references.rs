@@ -55,3 +55,86 @@ impl ReferenceTestTraitInterface for ReferenceTestTraitInterfaceImpl {
         a * 3
     }
 }
+
+#[derive(uniffi::Record, Clone)]
+pub struct ReferenceTestRecord {
+    pub key: String,
+    pub values: Vec<u32>,
+}
+
+#[derive(uniffi::Enum, Clone)]
+pub enum ReferenceTestEnum {
+    Empty,
+    Named { name: String },
+}
+
+// Object with methods that return borrowed values
+#[derive(uniffi::Object)]
+pub struct BorrowedGetters {
+    name: String,
+    data: Vec<u8>,
+    records: Vec<ReferenceTestRecord>,
+    kind: ReferenceTestEnum,
+}
+
+#[uniffi::export]
+impl BorrowedGetters {
+    #[uniffi::constructor]
+    pub fn new(name: String, data: Vec<u8>, records: Vec<ReferenceTestRecord>) -> Self {
+        let kind = ReferenceTestEnum::Named { name: name.clone() };
+        Self {
+            name,
+            data,
+            records,
+            kind,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn records(&self) -> &[ReferenceTestRecord] {
+        &self.records
+    }
+
+    pub fn first_record(&self) -> &ReferenceTestRecord {
+        &self.records[0]
+    }
+
+    pub fn find_record(&self, key: String) -> Option<&ReferenceTestRecord> {
+        self.records.iter().find(|r| r.key == key)
+    }
+
+    pub fn kind(&self) -> &ReferenceTestEnum {
+        &self.kind
+    }
+}
+
+// Borrowed values can also be returned from objects stored behind a lock.  The lock is held
+// until the value is lowered.
+#[derive(uniffi::Object)]
+#[uniffi(mutex)]
+pub struct LockedLabel {
+    label: String,
+}
+
+#[uniffi::export]
+impl LockedLabel {
+    #[uniffi::constructor]
+    pub fn new(label: String) -> Self {
+        Self { label }
+    }
+
+    pub fn set_label(&mut self, label: String) {
+        self.label = label;
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
@@ -70,6 +70,45 @@ pub fn create_trait_to_rename_impl(multiplier: i32) -> std::sync::Arc<dyn TraitT
     std::sync::Arc::new(TraitImpl { multiplier })
 }
 
+// Names taken from serde attributes
+#[derive(uniffi::Record, serde::Serialize, serde::Deserialize)]
+#[uniffi(serde_names)]
+#[serde(rename = "SerdeRenamedRecord", rename_all = "camelCase")]
+pub struct SerdeRecordToRename {
+    pub first_value: i32,
+    #[serde(rename = "renamed_by_serde", alias = "other_name")]
+    pub second_value: i32,
+}
+
+#[derive(uniffi::Enum, serde::Serialize, serde::Deserialize)]
+#[uniffi(serde_names)]
+#[serde(
+    rename = "SerdeRenamedEnum",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+pub enum SerdeEnumToRename {
+    FirstVariant,
+    #[serde(rename = "RenamedBySerde")]
+    Record {
+        inner_value: i32,
+    },
+    // `#[uniffi(name)]` takes precedence
+    #[uniffi(name = "RenamedByUniffi")]
+    #[serde(rename = "Ignored")]
+    Other,
+}
+
+#[uniffi::export]
+pub fn roundtrip_serde_renamed_record(record: SerdeRecordToRename) -> SerdeRecordToRename {
+    record
+}
+
+#[uniffi::export]
+pub fn roundtrip_serde_renamed_enum(en: SerdeEnumToRename) -> SerdeEnumToRename {
+    en
+}
+
 /// BINDINGS TESTS
 ///
 /// The way our tests are setup makes it inconvenient to reuse the above,
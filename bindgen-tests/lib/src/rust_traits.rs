@@ -7,6 +7,8 @@ use std::{
     collections::hash_map::DefaultHasher,
     fmt,
     hash::{Hash, Hasher},
+    str::FromStr,
+    sync::Mutex,
 };
 
 #[derive(Eq, uniffi::Record)]
@@ -75,3 +77,56 @@ impl fmt::Debug for RustTraitTest2 {
         write!(f, "debug-test-string")
     }
 }
+
+// `Clone`, `Default` and `FromStr` on an object.  The foreign copies should be independent of the
+// original object.
+#[derive(Default, uniffi::Object)]
+#[uniffi::export(Clone, Default, FromStr)]
+pub struct RustTraitList {
+    items: Mutex<Vec<String>>,
+}
+
+impl Clone for RustTraitList {
+    fn clone(&self) -> Self {
+        Self {
+            items: Mutex::new(self.items.lock().unwrap().clone()),
+        }
+    }
+}
+
+impl FromStr for RustTraitList {
+    type Err = RustTraitParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(RustTraitParseError::Empty);
+        }
+        Ok(Self {
+            items: Mutex::new(s.split(',').map(str::to_string).collect()),
+        })
+    }
+}
+
+#[uniffi::export]
+impl RustTraitList {
+    pub fn push(&self, item: String) {
+        self.items.lock().unwrap().push(item);
+    }
+
+    pub fn items(&self) -> Vec<String> {
+        self.items.lock().unwrap().clone()
+    }
+}
+
+#[derive(uniffi::Error, thiserror::Error, Debug)]
+pub enum RustTraitParseError {
+    #[error("Empty")]
+    Empty,
+}
+
+// `PartialOrd` and `Default` on a record that contains a float, so it can't implement `Ord`.
+#[derive(Default, PartialEq, PartialOrd, uniffi::Record)]
+#[uniffi::export(Default, PartialOrd)]
+pub struct RustTraitFloat {
+    pub value: f64,
+}
@@ -0,0 +1,80 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Associated functions without a `self` receiver.
+
+use std::sync::Arc;
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum StaticMethodError {
+    #[error("Negative input")]
+    Negative,
+}
+
+#[derive(uniffi::Object)]
+pub struct StaticMethodObject {
+    value: u32,
+}
+
+#[uniffi::export]
+impl StaticMethodObject {
+    #[uniffi::constructor]
+    pub fn new(value: u32) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn default_value() -> u32 {
+        42
+    }
+
+    pub fn from_default() -> Arc<Self> {
+        Arc::new(Self::new(Self::default_value()))
+    }
+
+    pub fn checked_value(value: i32) -> Result<u32, StaticMethodError> {
+        u32::try_from(value).map_err(|_| StaticMethodError::Negative)
+    }
+
+    pub async fn async_default_value() -> u32 {
+        Self::default_value()
+    }
+}
+
+#[derive(uniffi::Record, Debug, PartialEq)]
+pub struct StaticMethodPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[uniffi::export]
+impl StaticMethodPoint {
+    pub fn origin() -> Self {
+        Self { x: 0, y: 0 }
+    }
+
+    pub fn sum(&self) -> i32 {
+        self.x + self.y
+    }
+}
+
+#[derive(uniffi::Enum, Debug, PartialEq)]
+pub enum StaticMethodDirection {
+    North,
+    South,
+}
+
+#[uniffi::export]
+impl StaticMethodDirection {
+    pub fn parse(name: String) -> Option<Self> {
+        match name.as_str() {
+            "north" => Some(Self::North),
+            "south" => Some(Self::South),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,94 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+//! Functions and methods that return streams.
+
+use std::{cell::Cell, future::poll_fn, task::Poll};
+
+use futures::stream::{self, Stream, StreamExt};
+
+#[uniffi::export]
+pub fn stream_count_to(n: u32) -> impl Stream<Item = u32> {
+    stream::iter(0..n)
+}
+
+#[uniffi::export]
+pub fn stream_strings(items: Vec<String>) -> impl Stream<Item = String> {
+    stream::iter(items)
+}
+
+/// `None` items need to be distinguished from the end of the stream
+#[uniffi::export]
+pub fn stream_optionals(items: Vec<Option<u32>>) -> impl Stream<Item = Option<u32>> {
+    stream::iter(items)
+}
+
+/// Like `stream_count_to`, but each item is only ready after the stream returns `Poll::Pending`
+#[uniffi::export]
+pub fn stream_count_to_with_pending(n: u32) -> impl Stream<Item = u32> {
+    stream::iter(0..n).then(|i| async move {
+        yield_now().await;
+        i
+    })
+}
+
+/// Async runtime adapter that marks the current thread while it's polling the inner future
+pub struct StreamRuntimeAdapter;
+
+thread_local! {
+    static IN_STREAM_RUNTIME: Cell<bool> = const { Cell::new(false) };
+}
+
+impl uniffi::AsyncRuntimeAdapter for StreamRuntimeAdapter {
+    fn wrap<F, T>(future: F) -> impl uniffi::UniffiCompatibleFuture<T>
+    where
+        F: uniffi::UniffiCompatibleFuture<T>,
+    {
+        let mut future = Box::pin(future);
+        poll_fn(move |context| {
+            IN_STREAM_RUNTIME.set(true);
+            let poll = future.as_mut().poll(context);
+            IN_STREAM_RUNTIME.set(false);
+            poll
+        })
+    }
+}
+
+/// Each item is whether the stream was polled inside `StreamRuntimeAdapter`
+#[uniffi::export(async_runtime = StreamRuntimeAdapter)]
+pub fn stream_in_runtime(n: u32) -> impl Stream<Item = bool> {
+    stream::iter(0..n).then(|_| async { IN_STREAM_RUNTIME.get() })
+}
+
+#[derive(uniffi::Object)]
+pub struct StreamCounter {
+    start: u32,
+}
+
+#[uniffi::export]
+impl StreamCounter {
+    #[uniffi::constructor]
+    pub fn new(start: u32) -> Self {
+        Self { start }
+    }
+
+    pub fn count(&self, len: u32) -> impl Stream<Item = u32> {
+        stream::iter(self.start..self.start + len)
+    }
+}
+
+/// Return `Poll::Pending` once, waking the task immediately
+async fn yield_now() {
+    let mut yielded = false;
+    poll_fn(|cx| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    })
+    .await
+}
@@ -276,6 +276,69 @@ pub fn roundtrip_test_foreign_only_trait(
     interface
 }
 
+/// Trait interface with default method implementations
+///
+/// Foreign implementations only need to implement `get_value`, the other methods fall back to the
+/// Rust default implementations.
+#[uniffi::export(rust, foreign)]
+pub trait TestTraitWithDefaults: Send + Sync {
+    fn get_value(&self) -> u32;
+
+    /// Default implementation that calls back into `get_value`
+    fn get_value_plus(&self, amount: u32) -> u32 {
+        self.get_value() + amount
+    }
+
+    /// Default implementation that can throw
+    fn get_value_checked(&self) -> Result<u32, TestError> {
+        match self.get_value() {
+            0 => Err(TestError::Failure1),
+            value => Ok(value),
+        }
+    }
+
+    /// Default implementation that the foreign tests override
+    fn describe(&self) -> String {
+        format!("value: {}", self.get_value())
+    }
+}
+
+#[uniffi::export]
+pub fn invoke_test_trait_with_defaults_get_value_plus(
+    interface: Arc<dyn TestTraitWithDefaults>,
+    amount: u32,
+) -> u32 {
+    interface.get_value_plus(amount)
+}
+
+#[uniffi::export]
+pub fn invoke_test_trait_with_defaults_get_value_checked(
+    interface: Arc<dyn TestTraitWithDefaults>,
+) -> Result<u32, TestError> {
+    interface.get_value_checked()
+}
+
+#[uniffi::export]
+pub fn invoke_test_trait_with_defaults_describe(
+    interface: Arc<dyn TestTraitWithDefaults>,
+) -> String {
+    interface.describe()
+}
+
+/// Create a Rust implementation that only implements the required method
+#[uniffi::export]
+pub fn create_test_trait_with_defaults(value: u32) -> Arc<dyn TestTraitWithDefaults> {
+    Arc::new(TestTraitWithDefaultsImpl(value))
+}
+
+struct TestTraitWithDefaultsImpl(u32);
+
+impl TestTraitWithDefaults for TestTraitWithDefaultsImpl {
+    fn get_value(&self) -> u32 {
+        self.0
+    }
+}
+
 // Functions to round-trip trait interfaces across the FFI
 //
 // These are used to test that they still work after being lifted/lowered.
@@ -0,0 +1,48 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+// Rec that contains a tuple
+#[derive(uniffi::Record)]
+pub struct TupleRec {
+    pub a: (u32, String),
+    pub b: Option<(bool, bool)>,
+}
+
+#[uniffi::export]
+pub fn roundtrip_pair(a: (String, u32)) -> (String, u32) {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_triple(a: (u8, bool, f64)) -> (u8, bool, f64) {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_tuple4(a: (i32, String, Option<u32>, bool)) -> (i32, String, Option<u32>, bool) {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_vec_of_pairs(a: Vec<(String, u32)>) -> Vec<(String, u32)> {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_nested_tuple(a: ((u8, u8), String)) -> ((u8, u8), String) {
+    a
+}
+
+#[uniffi::export]
+pub fn roundtrip_tuple_rec(a: TupleRec) -> TupleRec {
+    a
+}
+
+#[uniffi::export]
+pub fn split_at_first_space(a: String) -> (String, String) {
+    match a.split_once(' ') {
+        Some((first, rest)) => (first.to_string(), rest.to_string()),
+        None => (a, String::new()),
+    }
+}
@@ -120,6 +120,41 @@ mod test {
         run_tests(test_dir(), "tests/rust_traits.py");
     }
 
+    #[test]
+    fn test_tuples() {
+        run_tests(test_dir(), "tests/tuples.py");
+    }
+
+    #[test]
+    fn test_streams() {
+        run_tests(test_dir(), "tests/streams.py");
+    }
+
+    #[test]
+    fn test_iterators() {
+        run_tests(test_dir(), "tests/iterators.py");
+    }
+
+    #[test]
+    fn test_closures() {
+        run_tests(test_dir(), "tests/closures.py");
+    }
+
+    #[test]
+    fn test_static_methods() {
+        run_tests(test_dir(), "tests/static_methods.py");
+    }
+
+    #[test]
+    fn test_arrays() {
+        run_tests(test_dir(), "tests/arrays.py");
+    }
+
+    #[test]
+    fn test_constants() {
+        run_tests(test_dir(), "tests/constants.py");
+    }
+
     fn test_dir() -> &'static Utf8Path {
         static TEST_TEMPDIR: OnceLock<Utf8PathBuf> = OnceLock::new();
         TEST_TEMPDIR.get_or_init(|| {
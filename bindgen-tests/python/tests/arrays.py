@@ -0,0 +1,35 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+class TestArrays(unittest.TestCase):
+    def test_roundtrip(self):
+        self.assertEqual(roundtrip_u8_array([1, 2, 3, 4]), [1, 2, 3, 4])
+        self.assertEqual(roundtrip_f32_array([0.5, 1.5, 2.5]), [0.5, 1.5, 2.5])
+        self.assertEqual(roundtrip_string_array(["a", "b"]), ["a", "b"])
+        self.assertEqual(roundtrip_empty_array([]), [])
+        self.assertEqual(sum_array([1, 2, 3, 4]), 10)
+
+    def test_collections(self):
+        self.assertEqual(roundtrip_vec_of_arrays([[1, 2], [-3, -4]]), [[1, 2], [-3, -4]])
+        self.assertEqual(roundtrip_vec_of_arrays([]), [])
+
+    def test_records(self):
+        rec = ArrayRec(a=[1, 2], b=[True, False, True])
+        self.assertEqual(roundtrip_array_rec(rec), rec)
+        rec = ArrayRec(a=[3, 4], b=None)
+        self.assertEqual(roundtrip_array_rec(rec), rec)
+
+    def test_check_lower(self):
+        with self.assertRaises(ValueError):
+            roundtrip_u8_array([1, 2, 3])
+        with self.assertRaises(ValueError):
+            roundtrip_u8_array([1, 2, 3, 4, 5])
+        with self.assertRaises(ValueError):
+            roundtrip_empty_array([1])
+        with self.assertRaises(ValueError):
+            roundtrip_array_rec(ArrayRec(a=[1], b=None))
+        with self.assertRaises(ValueError):
+            roundtrip_u8_array([1, 2, 3, 256])
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,41 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+class TestClosures(unittest.TestCase):
+    def test_foreign_closures(self):
+        self.assertEqual(closure_call(lambda x: x * 2, 21), 42)
+        self.assertEqual(closure_call_twice(lambda s, n: f"{s}{n}"), "a12")
+        self.assertEqual(closure_call_no_args(lambda: ["a", "b"]), ["a", "b"])
+
+    def test_unit_return(self):
+        received = []
+        closure_call_on_thread(received.append, 1)
+        closure_call_on_thread(received.append, None)
+        self.assertEqual(received, [1, None])
+
+    def test_rust_closures(self):
+        add_two = closure_make_adder(2)
+        self.assertEqual(add_two(40), 42)
+        counter = closure_make_counter()
+        self.assertEqual(counter(), 1)
+        self.assertEqual(counter(), 2)
+
+    def test_round_trip(self):
+        add_two = closure_make_adder(2)
+        self.assertEqual(closure_call(add_two, 1), 3)
+        composed = closure_compose(add_two, lambda x: x * 10)
+        self.assertEqual(composed(1), 30)
+        self.assertEqual(closure_call(composed, 2), 40)
+
+    def test_check_lower(self):
+        with self.assertRaises(TypeError):
+            closure_call(1, 2)
+
+    def test_exception_in_closure(self):
+        def fail(x):
+            raise ValueError("boom")
+        with self.assertRaises(InternalError):
+            closure_call(fail, 1)
+
+if __name__ == '__main__':
+    unittest.main()
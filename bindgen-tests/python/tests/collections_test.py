@@ -19,6 +19,13 @@ class TestCollections(unittest.TestCase):
         self.assertEqual(roundtrip_hash_set({"a", "b", "c"}), {"a", "b", "c"})
         self.assertEqual(roundtrip_hash_map_u32_key({ 1: 2, 2: 4 }), { 1: 2, 2: 4})
 
+    def test_ordered_collections(self):
+        self.assertEqual(list(roundtrip_btree_map({ "c": 3, "a": 1, "b": 2 }).items()), [("a", 1), ("b", 2), ("c", 3)])
+        self.assertEqual(roundtrip_btree_set(["c", "a", "b"]), ["a", "b", "c"])
+        self.assertEqual(list(roundtrip_index_map({ "c": 3, "a": 1, "b": 2 }).items()), [("c", 3), ("a", 1), ("b", 2)])
+        self.assertEqual(roundtrip_index_set(["c", "a", "b"]), ["c", "a", "b"])
+        self.assertEqual(index_map_keys({ "c": 3, "a": 1, "b": 2 }), ["c", "a", "b"])
+
     def test_rec_with_collections(self):
         self.assertEqual(
             roundtrip_rec_with_collections(
@@ -0,0 +1,20 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+class TestConstants(unittest.TestCase):
+    def test_primitives(self):
+        self.assertEqual(MAX_ITEMS, 100)
+        self.assertEqual(MIN_OFFSET, -42)
+        self.assertEqual(SCALE, 1.5)
+        self.assertEqual(ENABLED, True)
+        self.assertEqual(GREETING, "hello")
+        self.assertEqual(MAYBE_COUNT, 7)
+        self.assertEqual(SEPARATOR, "🦀")
+
+    def test_enums_and_records(self):
+        self.assertEqual(DEFAULT_LEVEL, ConstantLevel.HIGH)
+        self.assertEqual(DEFAULT_LIMITS, ConstantLimits(max_items=10, label="", level=ConstantLevel.LOW, ratio=None))
+        self.assertEqual(DEFAULT_LIMITS, get_default_limits())
+
+if __name__ == '__main__':
+    unittest.main()
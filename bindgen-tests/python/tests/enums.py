@@ -22,6 +22,20 @@ class TestEnums(unittest.TestCase):
         self.assertEqual(b[0], "Ten")
         self.assertEqual(b[1], 10)
 
+    def test_generic_enum(self):
+        self.assertEqual(roundtrip_loadable_u32(LoadableU32.LOADING()), LoadableU32.LOADING())
+        self.assertEqual(roundtrip_loadable_u32(LoadableU32.LOADED(value=5)), LoadableU32.LOADED(value=5))
+        self.assertEqual(roundtrip_loadable_u32(LoadableU32.FAILED("oops")), LoadableU32.FAILED("oops"))
+        self.assertEqual(
+            roundtrip_loadable_simple_rec(LoadableSimpleRec.LOADED(value=SimpleRec(a=1))),
+            LoadableSimpleRec.LOADED(value=SimpleRec(a=1)))
+
+    def test_skipped_fields(self):
+        en = create_skipped_fields_enum(1)
+        self.assertEqual(en, SkippedFieldsEnum.A(value=1))
+        self.assertIsNone(get_skipped_field_value(en))
+        self.assertEqual(get_skipped_field_value(SkippedFieldsEnum.B(1)), "default")
+
     def test_complex_enums(self):
         self.assertEqual(
             roundtrip_complex_enum(ComplexEnum.A(EnumNoData.C)),
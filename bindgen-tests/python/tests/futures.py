@@ -1,4 +1,5 @@
 from test_package.uniffi_bindgen_tests import *
+import asyncio
 import unittest
 
 class TestFutures(unittest.IsolatedAsyncioTestCase):
@@ -21,6 +22,9 @@ class TestFutures(unittest.IsolatedAsyncioTestCase):
         with self.assertRaises(TestError.Failure1):
             await async_throw_error()
 
+    async def test_async_runtime_adapter(self):
+        self.assertTrue(await async_in_test_runtime())
+
     async def test_methods(self):
         obj = AsyncInterface("Alice")
         self.assertEqual(await obj.name(), "Alice")
@@ -31,6 +35,18 @@ class TestFutures(unittest.IsolatedAsyncioTestCase):
         rec = AsyncRecord(name="Bob")
         assert(await rec.get_name() == "Bob")
 
+    async def test_cancellation(self):
+        tracker = CancellationTracker()
+        task = asyncio.ensure_future(tracker.wait_forever())
+        # Let the task start and poll the Rust future
+        await asyncio.sleep(0)
+        self.assertFalse(tracker.was_dropped())
+        task.cancel()
+        with self.assertRaises(asyncio.CancelledError):
+            await task
+        # Cancelling the task should drop the Rust future
+        self.assertTrue(tracker.was_dropped())
+
     async def test_async_callback_interfaces(self):
         class AsyncCallbackImpl:
             ref_count = 0
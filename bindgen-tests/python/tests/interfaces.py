@@ -42,5 +42,24 @@ class TestErrors(unittest.TestCase):
         # the following calls will fail if the argument name differs
         interface.method_with_multi_word_arg(the_argument="test")
 
+    def test_mutex_interfaces(self):
+        counter = MutexCounter(1)
+        self.assertEqual(counter.increment(), 2)
+        self.assertEqual(counter.increment(), 3)
+        self.assertEqual(counter.get_value(), 3)
+        self.assertEqual(mutex_counter_add(counter, 10).get_value(), 13)
+
+        counter = RwLockCounter(1)
+        self.assertEqual(counter.increment(), 2)
+        self.assertEqual(counter.get_value(), 2)
+        self.assertEqual(rwlock_counter_get_value(counter), 2)
+
+    def test_poisoned_lock(self):
+        counter = MutexCounter(1)
+        with self.assertRaises(InternalError):
+            counter.increment_and_panic()
+        with self.assertRaisesRegex(InternalError, "Lock poisoned"):
+            counter.get_value()
+
 if __name__ == '__main__':
     unittest.main()
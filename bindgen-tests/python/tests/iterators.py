@@ -0,0 +1,28 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+class TestIterators(unittest.TestCase):
+    def test_functions(self):
+        self.assertEqual(list(iter_count_to(3)), [0, 1, 2])
+        self.assertEqual(list(iter_count_to(0)), [])
+        self.assertEqual(list(iter_strings(["a", "b"])), ["a", "b"])
+        self.assertEqual(list(iter_optionals([1, None, 2])), [1, None, 2])
+
+    def test_multiple_chunks(self):
+        self.assertEqual(list(iter_count_to(200)), list(range(200)))
+
+    def test_methods(self):
+        counter = IterCounter(10)
+        self.assertEqual(list(counter.count(3)), [10, 11, 12])
+
+    def test_early_exit(self):
+        iterator = iter_count_to(100)
+        for item in iterator:
+            if item == 2:
+                break
+        iterator.close()
+        # Once the iterator is closed, it doesn't return any more items
+        self.assertEqual(list(iterator), [])
+
+if __name__ == '__main__':
+    unittest.main()
@@ -47,6 +47,40 @@ class TestPrimitiveTypes(unittest.TestCase):
         # This function will sum up all the numbers, then negate the value since we passed in `true`
         self.assertEqual(sum_with_many_types(1, -2, 3, -4, 5, -6, 7, -8, 9.5, -10.5, True), 5)
 
+    def test_128_bit_ints(self):
+        self.assertEqual(roundtrip_u128(2**128 - 1), 2**128 - 1)
+        self.assertEqual(roundtrip_u128(0), 0)
+        self.assertEqual(roundtrip_i128(-2**127), -2**127)
+        self.assertEqual(roundtrip_i128(2**127 - 1), 2**127 - 1)
+        self.assertEqual(roundtrip_i128(-1), -1)
+        self.assertEqual(roundtrip_vec_i128([-(2**100), 0, 2**100]), [-(2**100), 0, 2**100])
+        self.assertEqual(output_u128_max(), 2**128 - 1)
+        self.assertEqual(output_i128_min(), -2**127)
+        with self.assertRaises(ValueError):
+            roundtrip_u128(2**128)
+        with self.assertRaises(ValueError):
+            roundtrip_u128(-1)
+        with self.assertRaises(ValueError):
+            roundtrip_i128(2**127)
+        with self.assertRaises(TypeError):
+            roundtrip_i128(1.5)
+
+    def test_char(self):
+        self.assertEqual(roundtrip_char("a"), "a")
+        self.assertEqual(roundtrip_char("\u00e9"), "\u00e9")
+        self.assertEqual(roundtrip_char("\U0001f980"), "\U0001f980")
+        self.assertEqual(roundtrip_vec_char(["a", "\U0001f980"]), ["a", "\U0001f980"])
+        self.assertEqual(first_char("\U0001f980crab"), "\U0001f980")
+        self.assertEqual(first_char(""), None)
+        with self.assertRaises(ValueError):
+            roundtrip_char("ab")
+        with self.assertRaises(ValueError):
+            roundtrip_char("")
+        with self.assertRaises(ValueError):
+            roundtrip_char("\ud800")
+        with self.assertRaises(TypeError):
+            roundtrip_char(97)
+
     def test_argument_name_mapping(self):
         # the following calls will fail if the argument name differs
         func_with_multi_word_arg(the_argument=16)
@@ -7,6 +7,20 @@ class TestRecords(unittest.TestCase):
 
     def test_unit_record(self):
         self.assertEqual(UnitRec(), UnitRec())
+        self.assertEqual(roundtrip_unit_rec(UnitRec()), UnitRec())
+
+    def test_tuple_struct_record(self):
+        self.assertEqual(roundtrip_tuple_struct_rec(TupleStructRec(v0=1, v1="one")), TupleStructRec(v0=1, v1="one"))
+        self.assertEqual(
+            roundtrip_renamed_tuple_struct_rec(RenamedTupleStructRec(x=1.5, y=-2.5)),
+            RenamedTupleStructRec(x=1.5, y=-2.5))
+
+    def test_generic_record(self):
+        page = SimpleRecPage(items=[SimpleRec(a=1), SimpleRec(a=2)], next="cursor")
+        self.assertEqual(roundtrip_simple_rec_page(page), page)
+        self.assertEqual(
+            roundtrip_string_page(StringPage(items=["one", "two"], next=None)),
+            StringPage(items=["one", "two"], next=None))
 
     def test_complex_record(self):
         self.assertEqual(
@@ -41,6 +55,15 @@ class TestRecords(unittest.TestCase):
           )
         )
 
+    def test_skipped_fields(self):
+        rec = create_skipped_fields_rec(1)
+        self.assertEqual(rec, SkippedFieldsRec(a=1))
+        self.assertFalse(hasattr(rec, "cache"))
+        self.assertEqual(get_skipped_field_values(rec), (b"", 42))
+        self.assertEqual(
+            roundtrip_skipped_fields_tuple_rec(SkippedFieldsTupleRec(v0=1, v1="a")),
+            SkippedFieldsTupleRec(v0=1, v1="a"))
+
     def test_methods(self):
         self.assertEqual(SimpleRec(a=42).roundtrip(), SimpleRec(a=42))
 
@@ -14,6 +14,26 @@ class TestReferences(unittest.TestCase):
         trait_interface = create_reference_test_trait_interface()
         self.assertEqual(call_triple_value_trait_interface(trait_interface, 10), 30)
 
+    def test_borrowed_returns(self):
+        records = [
+            ReferenceTestRecord(key="a", values=[1, 2]),
+            ReferenceTestRecord(key="b", values=[]),
+        ]
+        getters = BorrowedGetters("getters", b"\x00\x01\xff", records)
+        self.assertEqual(getters.name(), "getters")
+        self.assertEqual(getters.data(), b"\x00\x01\xff")
+        self.assertEqual(getters.records(), records)
+        self.assertEqual(getters.first_record(), records[0])
+        self.assertEqual(getters.find_record("b"), records[1])
+        self.assertIsNone(getters.find_record("c"))
+        self.assertEqual(getters.kind(), ReferenceTestEnum.NAMED(name="getters"))
+
+    def test_borrowed_returns_from_locked_object(self):
+        label = LockedLabel("first")
+        self.assertEqual(label.label(), "first")
+        label.set_label("second")
+        self.assertEqual(label.label(), "second")
+
 if __name__ == '__main__':
     unittest.main()
 
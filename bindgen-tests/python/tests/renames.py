@@ -22,6 +22,16 @@ class TestRenames(unittest.TestCase):
         trait_impl = create_trait_impl(5)
         self.assertEqual(trait_impl.renamed_trait_method(10), 50)
 
+    def test_serde_names(self):
+        rec = SerdeRenamedRecord(first_value=1, renamed_by_serde=2)
+        self.assertEqual(roundtrip_serde_renamed_record(rec), rec)
+        self.assertEqual(
+            roundtrip_serde_renamed_enum(SerdeRenamedEnum.RENAMED_BY_SERDE(inner_value=3)),
+            SerdeRenamedEnum.RENAMED_BY_SERDE(inner_value=3))
+        self.assertEqual(
+            roundtrip_serde_renamed_enum(SerdeRenamedEnum.RENAMED_BY_UNIFFI()),
+            SerdeRenamedEnum.RENAMED_BY_UNIFFI())
+
     def test_py_rename_record(self):
         rec = PyRecord(py_item=100)
         self.assertEqual(rec.py_item, 100)
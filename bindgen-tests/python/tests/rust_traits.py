@@ -1,3 +1,5 @@
+import copy
+import math
 import unittest
 from test_package.uniffi_bindgen_tests import *
 
@@ -28,6 +30,35 @@ class TestRustTraits(unittest.TestCase):
         self.assertEqual(hash(RustTraitTest(a=1, b=2)), hash(RustTraitTest(a=1, b=3)))
         self.assertNotEqual(hash(RustTraitTest(a=2, b=2)), hash(RustTraitTest(a=1, b=2)))
 
+    def test_clone(self):
+        # Copies should be independent of the original, since `Clone` copies the Rust value
+        items = RustTraitList.parse("a,b")
+        for copied in [copy.copy(items), copy.deepcopy(items)]:
+            items.push("c")
+            self.assertEqual(copied.items(), ["a", "b"])
+            copied.push("d")
+            self.assertEqual(copied.items(), ["a", "b", "d"])
+
+    def test_default(self):
+        self.assertEqual(RustTraitList.default().items(), [])
+        self.assertEqual(RustTraitFloat.default(), RustTraitFloat(value=0.0))
+
+    def test_from_str(self):
+        self.assertEqual(RustTraitList.parse("a,b,c").items(), ["a", "b", "c"])
+        with self.assertRaises(RustTraitParseError.Empty):
+            RustTraitList.parse("")
+
+    def test_partial_ord(self):
+        self.assertTrue(RustTraitFloat(value=1.0) < RustTraitFloat(value=2.0))
+        self.assertTrue(RustTraitFloat(value=1.0) <= RustTraitFloat(value=1.0))
+        self.assertTrue(RustTraitFloat(value=2.0) > RustTraitFloat(value=1.0))
+        # NaN can't be compared, so all the comparisons should be false
+        nan = RustTraitFloat(value=math.nan)
+        self.assertFalse(nan < RustTraitFloat(value=1.0))
+        self.assertFalse(nan <= RustTraitFloat(value=1.0))
+        self.assertFalse(nan > RustTraitFloat(value=1.0))
+        self.assertFalse(nan >= RustTraitFloat(value=1.0))
+
 if __name__ == '__main__':
     unittest.main()
 
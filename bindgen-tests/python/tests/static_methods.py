@@ -0,0 +1,26 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+class TestStaticMethods(unittest.IsolatedAsyncioTestCase):
+    def test_objects(self):
+        self.assertEqual(StaticMethodObject.default_value(), 42)
+        self.assertEqual(StaticMethodObject.from_default().value(), 42)
+        self.assertEqual(StaticMethodObject.checked_value(5), 5)
+        with self.assertRaises(StaticMethodError.Negative):
+            StaticMethodObject.checked_value(-1)
+
+    async def test_async(self):
+        self.assertEqual(await StaticMethodObject.async_default_value(), 42)
+
+    def test_records(self):
+        origin = StaticMethodPoint.origin()
+        self.assertEqual(origin, StaticMethodPoint(x=0, y=0))
+        self.assertEqual(origin.sum(), 0)
+
+    def test_enums(self):
+        self.assertEqual(StaticMethodDirection.parse("north"), StaticMethodDirection.NORTH)
+        self.assertEqual(StaticMethodDirection.parse("south"), StaticMethodDirection.SOUTH)
+        self.assertIsNone(StaticMethodDirection.parse("west"))
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,32 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+async def collect(stream):
+    return [item async for item in stream]
+
+class TestStreams(unittest.IsolatedAsyncioTestCase):
+    async def test_functions(self):
+        self.assertEqual(await collect(stream_count_to(3)), [0, 1, 2])
+        self.assertEqual(await collect(stream_count_to(0)), [])
+        self.assertEqual(await collect(stream_strings(["a", "b"])), ["a", "b"])
+        self.assertEqual(await collect(stream_optionals([1, None, 2])), [1, None, 2])
+        self.assertEqual(await collect(stream_count_to_with_pending(3)), [0, 1, 2])
+
+    async def test_async_runtime(self):
+        self.assertEqual(await collect(stream_in_runtime(2)), [True, True])
+
+    async def test_methods(self):
+        counter = StreamCounter(10)
+        self.assertEqual(await collect(counter.count(3)), [10, 11, 12])
+
+    async def test_early_exit(self):
+        stream = stream_count_to(100)
+        async for item in stream:
+            if item == 2:
+                break
+        await stream.aclose()
+        # Once the stream is closed, it doesn't return any more items
+        self.assertEqual(await collect(stream), [])
+
+if __name__ == '__main__':
+    unittest.main()
@@ -222,5 +222,41 @@ class TestForeignOnlyTraitInterfaces(unittest.TestCase):
     def test_foreign_only_impl_roundtripped(self):
         self.check_foreign_only_impl(roundtrip_test_foreign_only_trait(ForeignOnlyImpl()))
 
+class DefaultsImpl(TestTraitWithDefaults):
+    def __init__(self, value):
+        self.value = value
+
+    def get_value(self):
+        return self.value
+
+class DefaultsOverrideImpl(DefaultsImpl):
+    def describe(self):
+        return f"python value: {self.value}"
+
+class TestTraitWithDefaultMethods(unittest.TestCase):
+    def test_rust_impl(self):
+        impl = create_test_trait_with_defaults(42)
+        self.assertEqual(impl.get_value_plus(1), 43)
+        self.assertEqual(impl.get_value_checked(), 42)
+        self.assertEqual(impl.describe(), "value: 42")
+        with self.assertRaises(TestError.Failure1):
+            create_test_trait_with_defaults(0).get_value_checked()
+
+    def test_py_impl_uses_rust_defaults(self):
+        impl = DefaultsImpl(42)
+        self.assertEqual(impl.get_value_plus(1), 43)
+        self.assertEqual(invoke_test_trait_with_defaults_get_value_plus(impl, 2), 44)
+        self.assertEqual(invoke_test_trait_with_defaults_get_value_checked(impl), 42)
+        self.assertEqual(invoke_test_trait_with_defaults_describe(impl), "value: 42")
+        with self.assertRaises(TestError.Failure1):
+            invoke_test_trait_with_defaults_get_value_checked(DefaultsImpl(0))
+        with self.assertRaises(TestError.Failure1):
+            DefaultsImpl(0).get_value_checked()
+
+    def test_py_impl_overrides_default(self):
+        impl = DefaultsOverrideImpl(42)
+        self.assertEqual(invoke_test_trait_with_defaults_describe(impl), "python value: 42")
+        self.assertEqual(invoke_test_trait_with_defaults_get_value_plus(impl, 1), 43)
+
 if __name__ == '__main__':
     unittest.main()
@@ -0,0 +1,30 @@
+from test_package.uniffi_bindgen_tests import *
+import unittest
+
+class TestTuples(unittest.TestCase):
+    def test_roundtrip(self):
+        self.assertEqual(roundtrip_pair(("a", 1)), ("a", 1))
+        self.assertEqual(roundtrip_triple((1, True, 2.5)), (1, True, 2.5))
+        self.assertEqual(roundtrip_tuple4((-1, "b", None, False)), (-1, "b", None, False))
+        self.assertEqual(roundtrip_tuple4((-1, "b", 2, False)), (-1, "b", 2, False))
+        self.assertEqual(roundtrip_nested_tuple(((1, 2), "c")), ((1, 2), "c"))
+        self.assertEqual(split_at_first_space("hello tuple world"), ("hello", "tuple world"))
+
+    def test_collections(self):
+        self.assertEqual(roundtrip_vec_of_pairs([("a", 1), ("b", 2)]), [("a", 1), ("b", 2)])
+        self.assertEqual(roundtrip_vec_of_pairs([]), [])
+
+    def test_records(self):
+        rec = TupleRec(a=(1, "a"), b=(True, False))
+        self.assertEqual(roundtrip_tuple_rec(rec), rec)
+        rec = TupleRec(a=(2, "b"), b=None)
+        self.assertEqual(roundtrip_tuple_rec(rec), rec)
+
+    def test_check_lower(self):
+        with self.assertRaises(TypeError):
+            roundtrip_pair(("a", 1, 2))
+        with self.assertRaises(ValueError):
+            roundtrip_pair(("a", -1))
+
+if __name__ == '__main__':
+    unittest.main()
@@ -109,6 +109,41 @@ mod test {
         run_tests(test_dir(), "tests/rust_traits.rb");
     }
 
+    #[test]
+    fn test_tuples() {
+        run_tests(test_dir(), "tests/tuples.rb");
+    }
+
+    #[test]
+    fn test_streams() {
+        run_tests(test_dir(), "tests/streams.rb");
+    }
+
+    #[test]
+    fn test_iterators() {
+        run_tests(test_dir(), "tests/iterators.rb");
+    }
+
+    #[test]
+    fn test_closures() {
+        run_tests(test_dir(), "tests/closures.rb");
+    }
+
+    #[test]
+    fn test_static_methods() {
+        run_tests(test_dir(), "tests/static_methods.rb");
+    }
+
+    #[test]
+    fn test_arrays() {
+        run_tests(test_dir(), "tests/arrays.rb");
+    }
+
+    #[test]
+    fn test_constants() {
+        run_tests(test_dir(), "tests/constants.rb");
+    }
+
     #[test]
     fn test_futures() {
         run_tests(test_dir(), "tests/futures.rb");
@@ -0,0 +1,37 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestArrays < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_roundtrip
+    assert_equal [1, 2, 3, 4], UniffiBindgenTests.roundtrip_u8_array([1, 2, 3, 4])
+    assert_equal [0.5, 1.5, 2.5], UniffiBindgenTests.roundtrip_f32_array([0.5, 1.5, 2.5])
+    assert_equal %w[a b], UniffiBindgenTests.roundtrip_string_array(%w[a b])
+    assert_equal [], UniffiBindgenTests.roundtrip_empty_array([])
+    assert_equal 10, UniffiBindgenTests.sum_array([1, 2, 3, 4])
+  end
+
+  def test_collections
+    assert_equal [[1, 2], [-3, -4]], UniffiBindgenTests.roundtrip_vec_of_arrays([[1, 2], [-3, -4]])
+  end
+
+  def test_records
+    rec = ArrayRec.new(a: [1, 2], b: [true, false, true])
+    assert_equal rec, UniffiBindgenTests.roundtrip_array_rec(rec)
+    rec = ArrayRec.new(a: [3, 4], b: nil)
+    assert_equal rec, UniffiBindgenTests.roundtrip_array_rec(rec)
+  end
+
+  def test_check_lower
+    assert_raise ArgumentError do
+      UniffiBindgenTests.roundtrip_u8_array([1, 2, 3])
+    end
+  end
+end
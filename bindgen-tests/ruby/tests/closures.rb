@@ -0,0 +1,50 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestClosures < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_foreign_closures
+    assert_equal 42, UniffiBindgenTests.closure_call(->(x) { x * 2 }, 21)
+    assert_equal 'a12', UniffiBindgenTests.closure_call_twice(->(s, n) { "#{s}#{n}" })
+    assert_equal %w[a b], UniffiBindgenTests.closure_call_no_args(-> { %w[a b] })
+  end
+
+  def test_unit_return
+    received = Queue.new
+    UniffiBindgenTests.closure_call_on_thread(->(v) { received << v }, 1)
+    UniffiBindgenTests.closure_call_on_thread(->(v) { received << v }, nil)
+    assert_equal [1, nil], [received.pop, received.pop]
+  end
+
+  def test_rust_closures
+    add_two = UniffiBindgenTests.closure_make_adder(2)
+    assert_equal 42, add_two.call(40)
+    counter = UniffiBindgenTests.closure_make_counter
+    assert_equal 1, counter.call
+    assert_equal 2, counter.call
+    assert_raise ArgumentError do
+      add_two.call(1, 2)
+    end
+  end
+
+  def test_round_trip
+    add_two = UniffiBindgenTests.closure_make_adder(2)
+    assert_equal 3, UniffiBindgenTests.closure_call(add_two, 1)
+    composed = UniffiBindgenTests.closure_compose(add_two, ->(x) { x * 10 })
+    assert_equal 30, composed.call(1)
+    assert_equal 40, UniffiBindgenTests.closure_call(composed, 2)
+  end
+
+  def test_check_lower
+    assert_raise TypeError do
+      UniffiBindgenTests.closure_call(1, 2)
+    end
+  end
+end
@@ -27,6 +27,14 @@ class TestCollections < Test::Unit::TestCase
     )
   end
 
+  def test_ordered_collections
+    assert_equal [['a', 1], ['b', 2], ['c', 3]], UniffiBindgenTests.roundtrip_btree_map({ 'c' => 3, 'a' => 1, 'b' => 2 }).to_a
+    assert_equal %w[a b c], UniffiBindgenTests.roundtrip_btree_set(Set.new(%w[c a b])).to_a
+    assert_equal [['c', 3], ['a', 1], ['b', 2]], UniffiBindgenTests.roundtrip_index_map({ 'c' => 3, 'a' => 1, 'b' => 2 }).to_a
+    assert_equal %w[c a b], UniffiBindgenTests.roundtrip_index_set(Set.new(%w[c a b])).to_a
+    assert_equal %w[c a b], UniffiBindgenTests.index_map_keys({ 'c' => 3, 'a' => 1, 'b' => 2 })
+  end
+
   def test_hash_map
     map = { 'a' => 1, 'b' => 2 }
 
@@ -0,0 +1,31 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestConstants < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_primitives
+    assert_equal 100, UniffiBindgenTests::MAX_ITEMS
+    assert_equal(-42, UniffiBindgenTests::MIN_OFFSET)
+    assert_equal 1.5, UniffiBindgenTests::SCALE
+    assert_equal true, UniffiBindgenTests::ENABLED
+    assert_equal 'hello', UniffiBindgenTests::GREETING
+    assert_equal 7, UniffiBindgenTests::MAYBE_COUNT
+    assert_equal '🦀', UniffiBindgenTests::SEPARATOR
+  end
+
+  def test_enums_and_records
+    assert_equal UniffiBindgenTests::ConstantLevel::HIGH, UniffiBindgenTests::DEFAULT_LEVEL
+    expected = UniffiBindgenTests::ConstantLimits.new(
+      max_items: 10, label: '', level: UniffiBindgenTests::ConstantLevel::LOW, ratio: nil
+    )
+    assert_equal expected, UniffiBindgenTests::DEFAULT_LIMITS
+    assert_equal UniffiBindgenTests.get_default_limits, UniffiBindgenTests::DEFAULT_LIMITS
+  end
+end
@@ -43,6 +43,16 @@ class TestEnums < Test::Unit::TestCase
     )
   end
 
+  def test_generic_enums
+    assert_equal LoadableU32::LOADING.new, UniffiBindgenTests.roundtrip_loadable_u32(LoadableU32::LOADING.new)
+    assert_equal LoadableU32::LOADED.new(value: 5), UniffiBindgenTests.roundtrip_loadable_u32(LoadableU32::LOADED.new(value: 5))
+    assert_equal LoadableU32::FAILED.new('oops'), UniffiBindgenTests.roundtrip_loadable_u32(LoadableU32::FAILED.new('oops'))
+    assert_equal(
+      LoadableSimpleRec::LOADED.new(value: SimpleRec.new(a: 1)),
+      UniffiBindgenTests.roundtrip_loadable_simple_rec(LoadableSimpleRec::LOADED.new(value: SimpleRec.new(a: 1)))
+    )
+  end
+
   def test_methods
     assert_equal(
       EnumWithData::A.new(value: 1, value2: 0),
@@ -69,6 +69,10 @@ class TestFutures < Test::Unit::TestCase
     assert_raises(TestError::Failure1) { UniffiBindgenTests.async_throw_error }
   end
 
+  def test_async_runtime_adapter
+    assert UniffiBindgenTests.async_in_test_runtime
+  end
+
   def test_methods
     obj = AsyncInterface.new 'Alice'
     assert_equal 'Alice', obj.name
@@ -78,4 +78,28 @@ class TestInterfaces < Test::Unit::TestCase
 
     assert_equal 'test', iface.method_with_multi_word_arg('test')
   end
+
+  def test_mutex_interfaces
+    counter = MutexCounter.new 1
+    assert_equal 2, counter.increment
+    assert_equal 3, counter.increment
+    assert_equal 3, counter.get_value
+    assert_equal 13, UniffiBindgenTests.mutex_counter_add(counter, 10).get_value
+
+    counter = RwLockCounter.new 1
+    assert_equal 2, counter.increment
+    assert_equal 2, counter.get_value
+    assert_equal 2, UniffiBindgenTests.rwlock_counter_get_value(counter)
+  end
+
+  def test_poisoned_lock
+    counter = MutexCounter.new 1
+    assert_raise InternalError do
+      counter.increment_and_panic
+    end
+    error = assert_raise InternalError do
+      counter.get_value
+    end
+    assert_match(/Lock poisoned/, error.message)
+  end
 end
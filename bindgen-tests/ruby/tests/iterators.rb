@@ -0,0 +1,35 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestIterators < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_functions
+    assert_equal [0, 1, 2], UniffiBindgenTests.iter_count_to(3).to_a
+    assert_equal [], UniffiBindgenTests.iter_count_to(0).to_a
+    assert_equal %w[a b], UniffiBindgenTests.iter_strings(%w[a b]).to_a
+    assert_equal [1, nil, 2], UniffiBindgenTests.iter_optionals([1, nil, 2]).to_a
+  end
+
+  def test_multiple_chunks
+    assert_equal (0...200).to_a, UniffiBindgenTests.iter_count_to(200).to_a
+  end
+
+  def test_methods
+    counter = IterCounter.new(10)
+    assert_equal [10, 11, 12], counter.count(3).to_a
+  end
+
+  def test_early_exit
+    iterator = UniffiBindgenTests.iter_count_to(100)
+    assert_equal [0, 1], iterator.first(2)
+    # The iterator is freed after the first iteration, so it doesn't return any more items
+    assert_equal [], iterator.to_a
+  end
+end
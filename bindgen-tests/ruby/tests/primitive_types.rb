@@ -54,6 +54,28 @@ class TestPrimitiveTypes < Test::Unit::TestCase
     assert_equal 'test-string', UniffiBindgenTests.roundtrip_string('test-string')
   end
 
+  def test_128_bit_ints
+    assert_equal 2**128 - 1, UniffiBindgenTests.roundtrip_u128(2**128 - 1)
+    assert_equal(-2**127, UniffiBindgenTests.roundtrip_i128(-2**127))
+    assert_equal [-2**100, 0, 2**100], UniffiBindgenTests.roundtrip_vec_i128([-2**100, 0, 2**100])
+    assert_equal 2**128 - 1, UniffiBindgenTests.output_u128_max
+    assert_equal(-2**127, UniffiBindgenTests.output_i128_min)
+    assert_raise RangeError do
+      UniffiBindgenTests.roundtrip_u128(2**128)
+    end
+  end
+
+  def test_char
+    assert_equal 'a', UniffiBindgenTests.roundtrip_char('a')
+    assert_equal "\u{1F980}", UniffiBindgenTests.roundtrip_char("\u{1F980}")
+    assert_equal ['a', "\u{e9}"], UniffiBindgenTests.roundtrip_vec_char(['a', "\u{e9}"])
+    assert_equal "\u{1F980}", UniffiBindgenTests.first_char("\u{1F980}crab")
+    assert_nil UniffiBindgenTests.first_char('')
+    assert_raise ArgumentError do
+      UniffiBindgenTests.roundtrip_char('ab')
+    end
+  end
+
   def test_sum_with_many_types
     result = UniffiBindgenTests.sum_with_many_types 1, -2, 3, -4, 5, -6, 7, -8, 9.5, -10.5, true
 
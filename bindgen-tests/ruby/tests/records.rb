@@ -19,6 +19,40 @@ class TestRecords < Test::Unit::TestCase
 
   def test_unit_rec
     assert_equal UnitRec.new, UnitRec.new
+    assert_equal UnitRec.new, UniffiBindgenTests.roundtrip_unit_rec(UnitRec.new)
+  end
+
+  def test_tuple_struct_rec
+    result = UniffiBindgenTests.roundtrip_tuple_struct_rec TupleStructRec.new(v0: 1, v1: 'one')
+
+    assert_equal 1, result.v0
+    assert_equal 'one', result.v1
+  end
+
+  def test_skipped_fields_rec
+    rec = UniffiBindgenTests.create_skipped_fields_rec 1
+    cache, magic = UniffiBindgenTests.get_skipped_field_values rec
+
+    assert_equal SkippedFieldsRec.new(a: 1), rec
+    assert cache.empty?
+    assert_equal 42, magic
+  end
+
+  def test_renamed_tuple_struct_rec
+    result = UniffiBindgenTests.roundtrip_renamed_tuple_struct_rec RenamedTupleStructRec.new(x: 1.5, y: -2.5)
+
+    assert_equal 1.5, result.x
+    assert_equal(-2.5, result.y)
+  end
+
+  def test_generic_rec
+    page = SimpleRecPage.new(items: [SimpleRec.new(a: 1), SimpleRec.new(a: 2)], _next: 'cursor')
+
+    assert_equal page, UniffiBindgenTests.roundtrip_simple_rec_page(page)
+    assert_equal(
+      StringPage.new(items: %w[one two], _next: nil),
+      UniffiBindgenTests.roundtrip_string_page(StringPage.new(items: %w[one two], _next: nil))
+    )
   end
 
   def test_complex_rec
@@ -29,4 +29,26 @@ class TestReferences < Test::Unit::TestCase
     triple_result = UniffiBindgenTests.call_triple_value_trait_interface trait_interface, 10
     assert_equal 30, triple_result
   end
+
+  def test_borrowed_returns
+    records = [
+      ReferenceTestRecord.new(key: 'a', values: [1, 2]),
+      ReferenceTestRecord.new(key: 'b', values: [])
+    ]
+    getters = BorrowedGetters.new 'getters', "\x00\x01\xFF".b, records
+    assert_equal 'getters', getters.name
+    assert_equal "\x00\x01\xFF".b, getters.data
+    assert_equal records, getters.records
+    assert_equal records[0], getters.first_record
+    assert_equal records[1], getters.find_record('b')
+    assert_nil getters.find_record('c')
+    assert_equal ReferenceTestEnum::NAMED.new(name: 'getters'), getters.kind
+  end
+
+  def test_borrowed_returns_from_locked_object
+    label = LockedLabel.new 'first'
+    assert_equal 'first', label.label
+    label.set_label 'second'
+    assert_equal 'second', label.label
+  end
 end
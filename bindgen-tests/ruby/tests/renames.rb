@@ -33,6 +33,15 @@ class TestRenames < Test::Unit::TestCase
     assert_kind_of RenamedEnum::RENAMED_VARIANT, variant
   end
 
+  def test_serde_names
+    rec = SerdeRenamedRecord.new first_value: 1, renamed_by_serde: 2
+    result = UniffiBindgenTests.roundtrip_serde_renamed_record rec
+
+    assert_equal rec, result
+    assert_kind_of SerdeRenamedEnum::RENAMED_BY_SERDE,
+                   UniffiBindgenTests.roundtrip_serde_renamed_enum(SerdeRenamedEnum::RENAMED_BY_SERDE.new(inner_value: 3))
+  end
+
   def test_rename_object
     obj = RenamedObject.renamed_constructor 123
 
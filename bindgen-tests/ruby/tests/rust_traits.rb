@@ -52,4 +52,35 @@ class TestRustTraits < Test::Unit::TestCase
       UniffiBindgenTests.rust_trait_test_hash(RustTraitTest.new(a: 2, b: 2))
     )
   end
+
+  def test_clone
+    # The copy is independent of the original, since `Clone` copies the Rust value
+    items = RustTraitList.parse('a,b')
+    copied = items.clone
+    items.push('c')
+    assert_equal %w[a b], copied.items
+  end
+
+  def test_default
+    assert_equal [], RustTraitList.default.items
+    assert_equal RustTraitFloat.new(value: 0.0), RustTraitFloat.default
+  end
+
+  def test_from_str
+    assert_equal %w[a b c], RustTraitList.parse('a,b,c').items
+    assert_raises RustTraitParseError::Empty do
+      RustTraitList.parse('')
+    end
+  end
+
+  def test_partial_ord
+    assert RustTraitFloat.new(value: 1.0) < RustTraitFloat.new(value: 2.0)
+    assert RustTraitFloat.new(value: 1.0) <= RustTraitFloat.new(value: 1.0)
+    assert RustTraitFloat.new(value: 2.0) > RustTraitFloat.new(value: 1.0)
+    # NaN can't be compared, so all the comparisons should be false
+    nan = RustTraitFloat.new(value: Float::NAN)
+    assert_nil nan <=> RustTraitFloat.new(value: 1.0)
+    assert !(nan < RustTraitFloat.new(value: 1.0))
+    assert !(nan >= RustTraitFloat.new(value: 1.0))
+  end
 end
@@ -0,0 +1,35 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestStaticMethods < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_objects
+    assert_equal 42, StaticMethodObject.default_value
+    assert_equal 42, StaticMethodObject.from_default.value
+    assert_equal 5, StaticMethodObject.checked_value(5)
+    assert_raises(StaticMethodError::Negative) { StaticMethodObject.checked_value(-1) }
+  end
+
+  def test_async
+    assert_equal 42, StaticMethodObject.async_default_value
+  end
+
+  def test_records
+    origin = StaticMethodPoint.origin
+    assert_equal StaticMethodPoint.new(x: 0, y: 0), origin
+    assert_equal 0, origin.sum
+  end
+
+  def test_enums
+    assert_equal StaticMethodDirection::NORTH, StaticMethodDirection.parse('north')
+    assert_equal StaticMethodDirection::SOUTH, StaticMethodDirection.parse('south')
+    assert_nil StaticMethodDirection.parse('west')
+  end
+end
@@ -0,0 +1,33 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestStreams < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_functions
+    assert_equal [0, 1, 2], UniffiBindgenTests.stream_count_to(3).to_a
+    assert_equal [], UniffiBindgenTests.stream_count_to(0).to_a
+    assert_equal %w[a b], UniffiBindgenTests.stream_strings(%w[a b]).to_a
+    assert_equal [1, nil, 2], UniffiBindgenTests.stream_optionals([1, nil, 2]).to_a
+    assert_equal [0, 1, 2], UniffiBindgenTests.stream_count_to_with_pending(3).to_a
+    assert_equal [true, true], UniffiBindgenTests.stream_in_runtime(2).to_a
+  end
+
+  def test_methods
+    counter = StreamCounter.new(10)
+    assert_equal [10, 11, 12], counter.count(3).to_a
+  end
+
+  def test_early_exit
+    stream = UniffiBindgenTests.stream_count_to(100)
+    assert_equal [0, 1], stream.first(2)
+    # The stream is freed after the first iteration, so it doesn't return any more items
+    assert_equal [], stream.to_a
+  end
+end
@@ -0,0 +1,39 @@
+# frozen_string_literal: true
+
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+require 'test/unit'
+require 'uniffi_bindgen_tests'
+
+class TestTuples < Test::Unit::TestCase
+  include UniffiBindgenTests
+
+  def test_roundtrip
+    assert_equal ['a', 1], UniffiBindgenTests.roundtrip_pair(['a', 1])
+    assert_equal [1, true, 2.5], UniffiBindgenTests.roundtrip_triple([1, true, 2.5])
+    assert_equal [-1, 'b', nil, false], UniffiBindgenTests.roundtrip_tuple4([-1, 'b', nil, false])
+    assert_equal [-1, 'b', 2, false], UniffiBindgenTests.roundtrip_tuple4([-1, 'b', 2, false])
+    assert_equal [[1, 2], 'c'], UniffiBindgenTests.roundtrip_nested_tuple([[1, 2], 'c'])
+    assert_equal ['hello', 'tuple world'], UniffiBindgenTests.split_at_first_space('hello tuple world')
+  end
+
+  def test_collections
+    assert_equal [['a', 1], ['b', 2]], UniffiBindgenTests.roundtrip_vec_of_pairs([['a', 1], ['b', 2]])
+    assert_equal [], UniffiBindgenTests.roundtrip_vec_of_pairs([])
+  end
+
+  def test_records
+    rec = TupleRec.new(a: [1, 'a'], b: [true, false])
+    assert_equal rec, UniffiBindgenTests.roundtrip_tuple_rec(rec)
+    rec = TupleRec.new(a: [2, 'b'], b: nil)
+    assert_equal rec, UniffiBindgenTests.roundtrip_tuple_rec(rec)
+  end
+
+  def test_check_lower
+    assert_raise TypeError do
+      UniffiBindgenTests.roundtrip_pair(['a', 1, 2])
+    end
+  end
+end
@@ -119,6 +119,41 @@ mod test {
         run_tests(test_dir(), "tests/rust_traits.swift");
     }
 
+    #[test]
+    fn test_tuples() {
+        run_tests(test_dir(), "tests/tuples.swift");
+    }
+
+    #[test]
+    fn test_streams() {
+        run_tests(test_dir(), "tests/streams.swift");
+    }
+
+    #[test]
+    fn test_iterators() {
+        run_tests(test_dir(), "tests/iterators.swift");
+    }
+
+    #[test]
+    fn test_closures() {
+        run_tests(test_dir(), "tests/closures.swift");
+    }
+
+    #[test]
+    fn test_static_methods() {
+        run_tests(test_dir(), "tests/static_methods.swift");
+    }
+
+    #[test]
+    fn test_arrays() {
+        run_tests(test_dir(), "tests/arrays.swift");
+    }
+
+    #[test]
+    fn test_constants() {
+        run_tests(test_dir(), "tests/constants.swift");
+    }
+
     fn test_dir() -> &'static Utf8Path {
         static TEST_TEMPDIR: OnceLock<Utf8PathBuf> = OnceLock::new();
         TEST_TEMPDIR.get_or_init(|| {
@@ -0,0 +1,16 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_bindgen_tests
+
+assert(roundtripU8Array(a: [1, 2, 3, 4]) == [1, 2, 3, 4])
+assert(roundtripF32Array(a: [0.5, 1.5, 2.5]) == [0.5, 1.5, 2.5])
+assert(roundtripStringArray(a: ["a", "b"]) == ["a", "b"])
+assert(roundtripEmptyArray(a: []) == [])
+assert(sumArray(a: [1, 2, 3, 4]) == 10)
+
+assert(roundtripVecOfArrays(a: [[1, 2], [-3, -4]]) == [[1, 2], [-3, -4]])
+
+assert(roundtripArrayRec(a: ArrayRec(a: [1, 2], b: [true, false, true])) == ArrayRec(a: [1, 2], b: [true, false, true]))
+assert(roundtripArrayRec(a: ArrayRec(a: [3, 4], b: nil)) == ArrayRec(a: [3, 4], b: nil))
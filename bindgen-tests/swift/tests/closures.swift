@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_bindgen_tests
+
+// Swift closures passed to Rust
+assert(closureCall(f: { $0 * 2 }, value: 21) == 42)
+assert(closureCallTwice(f: { s, n in "\(s)\(n)" }) == "a12")
+assert(closureCallNoArgs(f: { ["a", "b"] }) == ["a", "b"])
+
+// Closures without a return value, called from a different thread
+var received: [UInt32?] = []
+closureCallOnThread(f: { received.append($0) }, value: 1)
+closureCallOnThread(f: { received.append($0) }, value: nil)
+assert(received == [1, nil])
+
+// Rust closures returned to Swift
+let addTwo = closureMakeAdder(amount: 2)
+assert(addTwo(40) == 42)
+let counter = closureMakeCounter()
+assert(counter() == 1)
+assert(counter() == 2)
+
+// Passing Rust closures back to Rust
+assert(closureCall(f: addTwo, value: 1) == 3)
+let composed = closureCompose(f: addTwo, g: { $0 * 10 })
+assert(composed(1) == 30)
+assert(closureCall(f: composed, value: 2) == 40)
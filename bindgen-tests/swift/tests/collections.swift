@@ -21,6 +21,17 @@ assert(roundtripHashMap(a: ["a": 1, "b": 2]) == ["a": 1, "b": 2])
 assert(roundtripHashSet(a: ["a", "b", "c"]) == ["a", "b", "c"])
 assert(roundtripHashMapU32Key(a: [1: 2, 2: 4]) == [1: 2, 2: 4])
 
+// Ordered maps are arrays of key/value pairs and ordered sets are arrays
+let btreeMap = roundtripBtreeMap(a: [(key: "c", value: 3), (key: "a", value: 1), (key: "b", value: 2)])
+assert(btreeMap.map { $0.key } == ["a", "b", "c"])
+assert(btreeMap.map { $0.value } == [1, 2, 3])
+assert(roundtripBtreeSet(a: ["c", "a", "b"]) == ["a", "b", "c"])
+let indexMap = roundtripIndexMap(a: [(key: "c", value: 3), (key: "a", value: 1), (key: "b", value: 2)])
+assert(indexMap.map { $0.key } == ["c", "a", "b"])
+assert(indexMap.map { $0.value } == [3, 1, 2])
+assert(roundtripIndexSet(a: ["c", "a", "b"]) == ["c", "a", "b"])
+assert(indexMapKeys(a: [(key: "c", value: 3), (key: "a", value: 1), (key: "b", value: 2)]) == ["c", "a", "b"])
+
 assert(
     roundtripRecWithCollections(a: RecWithCollections(
             a: EnumWithCollections.a(nil),
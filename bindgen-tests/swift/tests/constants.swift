@@ -0,0 +1,17 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_bindgen_tests
+
+assert(maxItems == 100)
+assert(minOffset == -42)
+assert(scale == 1.5)
+assert(enabled)
+assert(greeting == "hello")
+assert(maybeCount == 7)
+assert(separator == "🦀")
+
+assert(defaultLevel == .high)
+assert(defaultLimits == ConstantLimits(maxItems: 10, label: "", level: .low, ratio: nil))
+assert(defaultLimits == getDefaultLimits())
@@ -24,6 +24,13 @@ assert(
     roundtripComplexEnum(en: ComplexEnum.c(value: SimpleRec(a: 30))) ==
     ComplexEnum.c(value: SimpleRec(a: 30)))
 
+assert(roundtripLoadableU32(en: .loading) == .loading)
+assert(roundtripLoadableU32(en: .loaded(value: 5)) == .loaded(value: 5))
+assert(roundtripLoadableU32(en: .failed("oops")) == .failed("oops"))
+assert(
+    roundtripLoadableSimpleRec(en: .loaded(value: SimpleRec(a: 1))) ==
+    .loaded(value: SimpleRec(a: 1)))
+
 // Test that the enum discriminant values
 
 // All discriminants specified, use the specified values
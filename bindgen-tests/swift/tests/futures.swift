@@ -53,6 +53,16 @@ Task {
 }
 dispatchGroup.wait()
 
+// Async runtime adapters
+dispatchGroup.enter()
+Task {
+    let inTestRuntime = await asyncInTestRuntime()
+    assert(inTestRuntime)
+
+    dispatchGroup.leave()
+}
+dispatchGroup.wait()
+
 // Errors
 dispatchGroup.enter()
 Task {
@@ -152,3 +162,27 @@ Task {
 }
 dispatchGroup.wait()
 
+// Cancellation
+dispatchGroup.enter()
+Task {
+    let tracker = CancellationTracker()
+    let task = Task {
+        try await tracker.waitForever()
+    }
+    // Give the task a chance to start and poll the Rust future
+    try! await Task.sleep(nanoseconds: 10_000_000)
+    task.cancel()
+    do {
+        try await task.value
+        fatalError("Expected CancellationError")
+    } catch is CancellationError {
+        // expected
+    } catch {
+        fatalError("unexpected error \(error)")
+    }
+    // Cancelling the task should drop the Rust future
+    assert(tracker.wasDropped())
+
+    dispatchGroup.leave()
+}
+dispatchGroup.wait()
@@ -52,3 +52,14 @@ assert(interface.refCount() == 2);
 // Test that the argument names get mapped to camelCase
 let _ = interface.methodWithMultiWordArg(theArgument: "test")
 
+// Test interfaces stored behind a lock
+let mutexCounter = MutexCounter(value: 1)
+assert(mutexCounter.increment() == 2)
+assert(mutexCounter.increment() == 3)
+assert(mutexCounter.getValue() == 3)
+assert(mutexCounterAdd(counter: mutexCounter, amount: 10).getValue() == 13)
+
+let rwlockCounter = RwLockCounter(value: 1)
+assert(rwlockCounter.increment() == 2)
+assert(rwlockCounter.getValue() == 2)
+assert(rwlockCounterGetValue(counter: rwlockCounter) == 2)
@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_bindgen_tests
+
+assert(Array(iterCountTo(n: 3)) == [0, 1, 2])
+assert(Array(iterCountTo(n: 0)) == [])
+assert(Array(iterStrings(items: ["a", "b"])) == ["a", "b"])
+assert(Array(iterOptionals(items: [1, nil, 2])) == [1, nil, 2])
+
+// Items are fetched in chunks, make sure iterators that span multiple chunks work
+assert(Array(iterCountTo(n: 200)) == Array(0..<200))
+
+// Methods
+let counter = IterCounter(start: 10)
+assert(Array(counter.count(len: 3)) == [10, 11, 12])
+
+// Stopping iteration early
+for item in iterCountTo(n: 100) {
+    if item == 2 {
+        break
+    }
+}
+
+// Once an iterator is exhausted, it doesn't return any more items
+var iterator = iterCountTo(n: 3)
+assert(Array(iterator) == [0, 1, 2])
+assert(iterator.next() == nil)
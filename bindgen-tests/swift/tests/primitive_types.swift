@@ -43,6 +43,18 @@ assert(roundtripF32(a: 0.5) == 0.5);
 assert(roundtripF64(a: -3.5) == -3.5);
 assert(roundtripBool(a: true) == true);
 assert(roundtripString(a: "ABC") == "ABC");
+// 128-bit integers use the `UniffiUInt128` and `UniffiInt128` structs
+assert(roundtripU128(a: UniffiUInt128(high: UInt64.max, low: UInt64.max)) == UniffiUInt128(high: UInt64.max, low: UInt64.max))
+assert(roundtripI128(a: -1) == UniffiInt128(-1))
+assert(roundtripVecI128(a: [1, -2]) == [1, -2])
+assert(outputU128Max().description == "340282366920938463463374607431768211455")
+assert(outputI128Min().description == "-170141183460469231731687303715884105728")
+// Chars map to `Unicode.Scalar`
+assert(roundtripChar(a: "a") == "a")
+assert(roundtripChar(a: "\u{1F980}") == "\u{1F980}")
+assert(roundtripVecChar(a: ["a", "\u{e9}"]) == ["a", "\u{e9}"])
+assert(firstChar(a: "\u{1F980}crab") == "\u{1F980}")
+assert(firstChar(a: "") == nil)
 // Test calling a function with lots of args
 // This function will sum up all the numbers, then negate the value since we passed in `true`
 assert(sumWithManyTypes(a: 1, b: -2, c: 3, d: -4, e: 5, f: -6, g: 7, h: -8, i: 9.5, j: -10.5, negate: true) == 5);
@@ -6,6 +6,16 @@ import uniffi_bindgen_tests
 
 assert(roundtripSimpleRec(rec: SimpleRec(a: 42)) == SimpleRec(a: 42))
 assert(UnitRec() == UnitRec())
+assert(roundtripUnitRec(rec: UnitRec()) == UnitRec())
+assert(roundtripTupleStructRec(rec: TupleStructRec(v0: 1, v1: "one")) == TupleStructRec(v0: 1, v1: "one"))
+assert(createSkippedFieldsRec(a: 1) == SkippedFieldsRec(a: 1))
+let skippedFieldValues = getSkippedFieldValues(rec: createSkippedFieldsRec(a: 1))
+assert(skippedFieldValues.0.isEmpty && skippedFieldValues.1 == 42)
+assert(roundtripRenamedTupleStructRec(rec: RenamedTupleStructRec(x: 1.5, y: -2.5)) == RenamedTupleStructRec(x: 1.5, y: -2.5))
+// generic records
+let simpleRecPage = SimpleRecPage(items: [SimpleRec(a: 1), SimpleRec(a: 2)], next: "cursor")
+assert(roundtripSimpleRecPage(page: simpleRecPage) == simpleRecPage)
+assert(roundtripStringPage(page: StringPage(items: ["one", "two"], next: nil)) == StringPage(items: ["one", "two"], next: nil))
 assert(
   roundtripComplexRec(
     rec: ComplexRec(
@@ -12,3 +12,21 @@ assert(callDoubleValue(i: interface, a: 3) == 6)
 
 let traitInterface = createReferenceTestTraitInterface()
 assert(callTripleValueTraitInterface(t: traitInterface, a: 10) == 30)
+
+let records = [
+    ReferenceTestRecord(key: "a", values: [1, 2]),
+    ReferenceTestRecord(key: "b", values: []),
+]
+let getters = BorrowedGetters(name: "getters", data: Data([0, 1, 255]), records: records)
+assert(getters.name() == "getters")
+assert(getters.data() == Data([0, 1, 255]))
+assert(getters.records() == records)
+assert(getters.firstRecord() == records[0])
+assert(getters.findRecord(key: "b") == records[1])
+assert(getters.findRecord(key: "c") == nil)
+assert(getters.kind() == .named(name: "getters"))
+
+let label = LockedLabel(label: "first")
+assert(label.label() == "first")
+label.setLabel(label: "second")
+assert(label.label() == "second")
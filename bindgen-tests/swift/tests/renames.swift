@@ -26,6 +26,12 @@ assert(methodResult == 123)
 let traitImpl = createTraitImpl(multiplier: 5)
 assert(traitImpl.renamedTraitMethod(value: 10) == 50) // 10 * 5
 
+// Test names from serde attributes
+let serdeRecord = SerdeRenamedRecord(firstValue: 1, renamedBySerde: 2)
+assert(roundtripSerdeRenamedRecord(record: serdeRecord) == serdeRecord)
+assert(roundtripSerdeRenamedEnum(en: .renamedBySerde(innerValue: 3)) == .renamedBySerde(innerValue: 3))
+assert(roundtripSerdeRenamedEnum(en: .renamedByUniffi) == .renamedByUniffi)
+
 // Test TOML-based renaming for Swift
 // These use the Swift-specific renames from uniffi.toml
 
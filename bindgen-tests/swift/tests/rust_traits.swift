@@ -19,3 +19,31 @@ assert(RustTraitTest(a: 1, b: 2) <= RustTraitTest(a: 1, b: 3)
 // The Rust code only uses `a` for the hash
 assert(RustTraitTest(a: 1, b: 2).hashValue == RustTraitTest(a: 1, b: 3).hashValue)
 assert(RustTraitTest(a: 2, b: 2).hashValue != RustTraitTest(a: 1, b: 2).hashValue)
+
+// `Clone` copies the Rust value, so the copy is independent of the original
+let items = try! RustTraitList.parse("a,b")
+let copied = items.clone()
+items.push(item: "c")
+assert(copied.items() == ["a", "b"])
+
+// `Default` generates a `default()` factory
+assert(RustTraitList.default().items().isEmpty)
+assert(RustTraitFloat.default() == RustTraitFloat(value: 0.0))
+
+// `FromStr` generates a `parse()` factory that throws
+assert(try! RustTraitList.parse("a,b,c").items() == ["a", "b", "c"])
+do {
+    _ = try RustTraitList.parse("")
+    fatalError("Should have thrown")
+} catch RustTraitParseError.Empty {
+    // Expected
+} catch {
+    fatalError("Unexpected error: \(error)")
+}
+
+// `PartialOrd` comparisons are all false for values that can't be compared
+assert(RustTraitFloat(value: 1.0) < RustTraitFloat(value: 2.0))
+assert(RustTraitFloat(value: 1.0) <= RustTraitFloat(value: 1.0))
+assert(RustTraitFloat(value: 2.0) > RustTraitFloat(value: 1.0))
+assert(!(RustTraitFloat(value: .nan) < RustTraitFloat(value: 1.0)))
+assert(!(RustTraitFloat(value: .nan) >= RustTraitFloat(value: 1.0)))
@@ -0,0 +1,36 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_bindgen_tests
+
+// Objects
+assert(StaticMethodObject.defaultValue() == 42)
+assert(StaticMethodObject.fromDefault().value() == 42)
+assert(try! StaticMethodObject.checkedValue(value: 5) == 5)
+do {
+    _ = try StaticMethodObject.checkedValue(value: -1)
+    fatalError("Expected StaticMethodError.Negative")
+} catch StaticMethodError.Negative {
+    // expected
+}
+
+var dispatchGroup = DispatchGroup()
+dispatchGroup.enter()
+Task {
+    let value = await StaticMethodObject.asyncDefaultValue()
+    assert(value == 42)
+    dispatchGroup.leave()
+}
+dispatchGroup.wait()
+
+// Records
+let origin = StaticMethodPoint.origin()
+assert(origin == StaticMethodPoint(x: 0, y: 0))
+assert(origin.sum() == 0)
+
+// Enums
+assert(StaticMethodDirection.parse(name: "north") == .north)
+assert(StaticMethodDirection.parse(name: "south") == .south)
+assert(StaticMethodDirection.parse(name: "west") == nil)
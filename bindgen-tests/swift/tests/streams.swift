@@ -0,0 +1,40 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import Foundation
+import uniffi_bindgen_tests
+
+func collect<T>(_ stream: AsyncThrowingStream<T, Error>) async throws -> [T] {
+    var items: [T] = []
+    for try await item in stream {
+        items.append(item)
+    }
+    return items
+}
+
+var dispatchGroup = DispatchGroup()
+
+dispatchGroup.enter()
+Task {
+    assert(try! await collect(streamCountTo(n: 3)) == [0, 1, 2])
+    assert(try! await collect(streamCountTo(n: 0)) == [])
+    assert(try! await collect(streamStrings(items: ["a", "b"])) == ["a", "b"])
+    assert(try! await collect(streamOptionals(items: [1, nil, 2])) == [1, nil, 2])
+    assert(try! await collect(streamCountToWithPending(n: 3)) == [0, 1, 2])
+    assert(try! await collect(streamInRuntime(n: 2)) == [true, true])
+
+    // Methods
+    let counter = StreamCounter(start: 10)
+    assert(try! await collect(counter.count(len: 3)) == [10, 11, 12])
+
+    // Stopping iteration early
+    for try await item in streamCountTo(n: 100) {
+        if item == 2 {
+            break
+        }
+    }
+
+    dispatchGroup.leave()
+}
+dispatchGroup.wait()
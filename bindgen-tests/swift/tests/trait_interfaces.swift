@@ -259,3 +259,52 @@ func testForeignOnlyImpl(impl: TestForeignOnlyTraitInterface) {
 
 testForeignOnlyImpl(impl: ForeignOnlyImpl())
 testForeignOnlyImpl(impl: roundtripTestForeignOnlyTrait(interface: ForeignOnlyImpl()))
+
+// Trait with default methods: foreign implementations only need to implement `getValue`
+class DefaultsImpl: TestTraitWithDefaults, @unchecked Sendable {
+    let value: UInt32
+
+    init(value: UInt32) {
+        self.value = value
+    }
+
+    func getValue() -> UInt32 {
+        return value
+    }
+}
+
+class DefaultsOverrideImpl: TestTraitWithDefaults, @unchecked Sendable {
+    let value: UInt32
+
+    init(value: UInt32) {
+        self.value = value
+    }
+
+    func getValue() -> UInt32 {
+        return value
+    }
+
+    func describe() -> String {
+        return "swift value: \(value)"
+    }
+}
+
+let rustDefaultsImpl = createTestTraitWithDefaults(value: 42)
+assert(rustDefaultsImpl.getValuePlus(amount: 1) == 43)
+assert(try! rustDefaultsImpl.getValueChecked() == 42)
+assert(rustDefaultsImpl.describe() == "value: 42")
+
+let swiftDefaultsImpl = DefaultsImpl(value: 42)
+assert(swiftDefaultsImpl.getValuePlus(amount: 1) == 43)
+assert(invokeTestTraitWithDefaultsGetValuePlus(interface: swiftDefaultsImpl, amount: 2) == 44)
+assert(try! invokeTestTraitWithDefaultsGetValueChecked(interface: swiftDefaultsImpl) == 42)
+assert(invokeTestTraitWithDefaultsDescribe(interface: swiftDefaultsImpl) == "value: 42")
+do {
+    let _ = try invokeTestTraitWithDefaultsGetValueChecked(interface: DefaultsImpl(value: 0))
+    fatalError("expected getValueChecked to throw")
+} catch TestError.Failure1 {
+    // expected
+} catch {
+    fatalError("unexpected error \(error)")
+}
+assert(invokeTestTraitWithDefaultsDescribe(interface: DefaultsOverrideImpl(value: 42)) == "swift value: 42")
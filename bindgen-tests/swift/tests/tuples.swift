@@ -0,0 +1,29 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+import uniffi_bindgen_tests
+
+assert(roundtripPair(a: ("a", 1)) == ("a", 1))
+assert(roundtripTriple(a: (1, true, 2.5)) == (1, true, 2.5))
+assert(roundtripTuple4(a: (-1, "b", nil, false)) == (-1, "b", nil, false))
+assert(roundtripTuple4(a: (-1, "b", 2, false)) == (-1, "b", 2, false))
+
+let nested = roundtripNestedTuple(a: ((1, 2), "c"))
+assert(nested.0 == (1, 2))
+assert(nested.1 == "c")
+
+let (first, rest) = splitAtFirstSpace(a: "hello tuple world")
+assert(first == "hello")
+assert(rest == "tuple world")
+
+let pairs = roundtripVecOfPairs(a: [("a", 1), ("b", 2)])
+assert(pairs.count == 2)
+assert(pairs[0] == ("a", 1))
+assert(pairs[1] == ("b", 2))
+
+// Swift tuples aren't `Equatable`, so records that contain them aren't either.
+let rec = roundtripTupleRec(a: TupleRec(a: (1, "a"), b: (true, false)))
+assert(rec.a == (1, "a"))
+assert(rec.b! == (true, false))
+assert(roundtripTupleRec(a: TupleRec(a: (2, "b"), b: nil)).b == nil)
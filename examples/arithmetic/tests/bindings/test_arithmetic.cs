@@ -0,0 +1,50 @@
+using System;
+using uniffi.arithmetic;
+
+AssertThrows<ArithmeticError.IntegerOverflow>(() => ArithmeticMethods.Add(18446744073709551615, 1));
+
+AssertEqual(6UL, ArithmeticMethods.Add(2, 4));
+AssertEqual(12UL, ArithmeticMethods.Add(4, 8));
+
+AssertThrows<ArithmeticError.IntegerOverflow>(() => ArithmeticMethods.Sub(0, 1));
+
+AssertEqual(2UL, ArithmeticMethods.Sub(4, 2));
+AssertEqual(4UL, ArithmeticMethods.Sub(8, 4));
+AssertEqual(2UL, ArithmeticMethods.Div(8, 4));
+
+AssertThrows<UniffiInternalException>(() => ArithmeticMethods.Div(8, 0));
+
+Assert(ArithmeticMethods.Equal(2, 2));
+Assert(ArithmeticMethods.Equal(4, 4));
+
+Assert(!ArithmeticMethods.Equal(2, 4));
+Assert(!ArithmeticMethods.Equal(4, 8));
+
+static void Assert(bool condition)
+{
+    if (!condition)
+    {
+        throw new Exception("Assertion failed");
+    }
+}
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
+
+static void AssertThrows<E>(Action action) where E : Exception
+{
+    try
+    {
+        action();
+    }
+    catch (E)
+    {
+        return;
+    }
+    throw new Exception($"Expected {typeof(E).Name} to be thrown");
+}
@@ -0,0 +1,54 @@
+package test
+
+import (
+	"errors"
+	"testing"
+
+	"uniffi/arithmetic"
+)
+
+func TestArithmetic(t *testing.T) {
+	var overflow *arithmetic.ArithmeticErrorIntegerOverflow
+
+	if _, err := arithmetic.Add(18446744073709551615, 1); !errors.As(err, &overflow) {
+		t.Fatalf("expected IntegerOverflow, got %v", err)
+	}
+
+	if v, err := arithmetic.Add(2, 4); err != nil || v != 6 {
+		t.Fatalf("Add(2, 4) = %v, %v", v, err)
+	}
+	if v, err := arithmetic.Add(4, 8); err != nil || v != 12 {
+		t.Fatalf("Add(4, 8) = %v, %v", v, err)
+	}
+
+	if _, err := arithmetic.Sub(0, 1); !errors.As(err, &overflow) {
+		t.Fatalf("expected IntegerOverflow, got %v", err)
+	}
+
+	if v, err := arithmetic.Sub(4, 2); err != nil || v != 2 {
+		t.Fatalf("Sub(4, 2) = %v, %v", v, err)
+	}
+	if v, err := arithmetic.Sub(8, 4); err != nil || v != 4 {
+		t.Fatalf("Sub(8, 4) = %v, %v", v, err)
+	}
+	if v := arithmetic.Div(8, 4); v != 2 {
+		t.Fatalf("Div(8, 4) = %v", v)
+	}
+
+	// Rust panics are turned into Go panics
+	func() {
+		defer func() {
+			if r := recover(); r == nil {
+				t.Fatal("expected Div(8, 0) to panic")
+			}
+		}()
+		arithmetic.Div(8, 0)
+	}()
+
+	if !arithmetic.Equal(2, 2) || !arithmetic.Equal(4, 4) {
+		t.Fatal("Equal returned false for equal values")
+	}
+	if arithmetic.Equal(2, 4) || arithmetic.Equal(4, 8) {
+		t.Fatal("Equal returned true for different values")
+	}
+}
@@ -0,0 +1,81 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using uniffi.callbacks;
+
+// Run the same tests against the UDL and the proc-macro telephones
+foreach (Func<string, ICallAnswerer, string> call in new Func<string, ICallAnswerer, string>[]
+{
+    (mode, answerer) => new Telephone().Call(GetSim(), answerer),
+    (mode, answerer) => new FancyTelephone().Call(GetSim(), answerer),
+})
+{
+    AssertEqual("Bonjour", call("ready", new CallAnswererImpl("ready")));
+    AssertThrows<TelephoneError.Busy>(() => call("busy", new CallAnswererImpl("busy")));
+    AssertThrows<TelephoneError.InternalTelephoneError>(
+        () => call("something-else", new CallAnswererImpl("something-else")));
+}
+
+AssertEqual("C# est bon marché", new Telephone().Call(new DiscountSim(), new CallAnswererImpl("ready")));
+AssertEqual("C# est bon marché", new FancyTelephone().Call(new DiscountSim(), new CallAnswererImpl("ready")));
+
+static ISimCard GetSim()
+{
+    return CallbacksMethods.GetSimCards()[0];
+}
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
+
+static void AssertThrows<E>(Action action) where E : Exception
+{
+    try
+    {
+        action();
+    }
+    catch (E)
+    {
+        return;
+    }
+    throw new Exception($"Expected {typeof(E).Name} to be thrown");
+}
+
+// This is defined in UDL as a "callback". It's not possible to have a Rust
+// implementation of a callback, they only exist on the foreign side.
+class CallAnswererImpl : ICallAnswerer
+{
+    private readonly string mode;
+
+    public CallAnswererImpl(string mode)
+    {
+        this.mode = mode;
+    }
+
+    public string Answer()
+    {
+        return mode switch
+        {
+            "ready" => "Bonjour",
+            "busy" => throw new TelephoneError.Busy("Busy"),
+            _ => throw new InvalidOperationException("Testing an unexpected error"),
+        };
+    }
+}
+
+// This is a normal Rust trait - very much like a callback but can be implemented
+// in Rust or in foreign code and is generally more consistent with the uniffi
+// Arc<>-based object model.
+class DiscountSim : ISimCard
+{
+    public string Name()
+    {
+        return "C#";
+    }
+}
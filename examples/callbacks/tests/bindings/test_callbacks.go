@@ -0,0 +1,69 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package test
+
+import (
+	"errors"
+	"testing"
+
+	"uniffi/callbacks"
+)
+
+// This is defined in UDL as a "callback". It's not possible to have a Rust
+// implementation of a callback, they only exist on the foreign side.
+type callAnswererImpl struct {
+	mode string
+}
+
+func (c *callAnswererImpl) Answer() (string, error) {
+	switch c.mode {
+	case "ready":
+		return "Bonjour", nil
+	case "busy":
+		return "", &callbacks.TelephoneErrorBusy{Message: "Busy"}
+	default:
+		return "", errors.New("Testing an unexpected error")
+	}
+}
+
+// This is a normal Rust trait - very much like a callback but can be implemented
+// in Rust or in foreign code and is generally more consistent with the uniffi
+// Arc<>-based object model.
+type discountSim struct{}
+
+func (discountSim) Name() string {
+	return "Go"
+}
+
+func TestCallbacks(t *testing.T) {
+	getSim := func() callbacks.SimCard {
+		return callbacks.GetSimCards()[0]
+	}
+
+	// Run the same tests against the UDL and the proc-macro telephones
+	calls := []func(sim callbacks.SimCard, answerer callbacks.CallAnswerer) (string, error){
+		callbacks.NewTelephone().Call,
+		callbacks.NewFancyTelephone().Call,
+	}
+	for _, call := range calls {
+		if v, err := call(getSim(), &callAnswererImpl{"ready"}); err != nil || v != "Bonjour" {
+			t.Fatalf("ready: got %q, %v", v, err)
+		}
+
+		var busy *callbacks.TelephoneErrorBusy
+		if _, err := call(getSim(), &callAnswererImpl{"busy"}); !errors.As(err, &busy) {
+			t.Fatalf("busy: expected TelephoneErrorBusy, got %v", err)
+		}
+
+		var internal *callbacks.TelephoneErrorInternalTelephoneError
+		if _, err := call(getSim(), &callAnswererImpl{"something-else"}); !errors.As(err, &internal) {
+			t.Fatalf("something-else: expected TelephoneErrorInternalTelephoneError, got %v", err)
+		}
+
+		if v, err := call(discountSim{}, &callAnswererImpl{"ready"}); err != nil || v != "Go est bon marché" {
+			t.Fatalf("discount: got %q, %v", v, err)
+		}
+	}
+}
@@ -3,4 +3,6 @@ uniffi::build_foreign_language_testcases!(
     "tests/bindings/test_callbacks.swift",
     "tests/bindings/test_callbacks.py",
     "tests/bindings/test_callbacks.rb",
+    "tests/bindings/test_callbacks.cs",
+    "tests/bindings/test_callbacks.go",
 );
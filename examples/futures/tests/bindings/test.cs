@@ -0,0 +1,8 @@
+using System;
+using uniffi.uniffi_example_futures;
+
+var result = await UniffiExampleFuturesMethods.SayAfter(200, "Alice");
+if (result != "Hello, Alice!")
+{
+    throw new Exception($"Expected 'Hello, Alice!', got '{result}'");
+}
@@ -0,0 +1,14 @@
+package test
+
+import (
+	"testing"
+
+	"uniffi/uniffi_example_futures"
+)
+
+func TestFutures(t *testing.T) {
+	// Async functions block the calling goroutine until the Rust future completes
+	if result := uniffi_example_futures.SayAfter(200, "Alice"); result != "Hello, Alice!" {
+		t.Fatalf("Expected 'Hello, Alice!', got '%s'", result)
+	}
+}
@@ -1 +1,6 @@
-uniffi::build_foreign_language_testcases!("tests/bindings/test.py", "tests/bindings/test.rb");
+uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test.py",
+    "tests/bindings/test.rb",
+    "tests/bindings/test.cs",
+    "tests/bindings/test.go",
+);
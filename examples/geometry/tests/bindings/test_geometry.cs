@@ -0,0 +1,20 @@
+using System;
+using uniffi.geometry;
+
+var ln1 = new Line(new Point(0.0, 0.0), new Point(1.0, 2.0));
+var ln2 = new Line(new Point(1.0, 1.0), new Point(2.0, 2.0));
+
+AssertEqual(2.0, GeometryMethods.Gradient(ln1));
+AssertEqual(1.0, GeometryMethods.Gradient(ln2));
+
+// Records are compared by value
+AssertEqual(new Point(0.0, 0.0), GeometryMethods.Intersection(ln1, ln2));
+AssertEqual(null, GeometryMethods.Intersection(ln1, ln1));
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
@@ -0,0 +1,27 @@
+package test
+
+import (
+	"testing"
+
+	"uniffi/geometry"
+)
+
+func TestGeometry(t *testing.T) {
+	ln1 := geometry.Line{Start: geometry.Point{CoordX: 0.0, CoordY: 0.0}, End: geometry.Point{CoordX: 1.0, CoordY: 2.0}}
+	ln2 := geometry.Line{Start: geometry.Point{CoordX: 1.0, CoordY: 1.0}, End: geometry.Point{CoordX: 2.0, CoordY: 2.0}}
+
+	if v := geometry.Gradient(ln1); v != 2.0 {
+		t.Fatalf("Gradient(ln1) = %v", v)
+	}
+	if v := geometry.Gradient(ln2); v != 1.0 {
+		t.Fatalf("Gradient(ln2) = %v", v)
+	}
+
+	// Records are compared by value
+	if v := geometry.Intersection(ln1, ln2); v == nil || *v != (geometry.Point{CoordX: 0.0, CoordY: 0.0}) {
+		t.Fatalf("Intersection(ln1, ln2) = %v", v)
+	}
+	if v := geometry.Intersection(ln1, ln1); v != nil {
+		t.Fatalf("Intersection(ln1, ln1) = %v", v)
+	}
+}
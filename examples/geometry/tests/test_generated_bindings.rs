@@ -3,4 +3,6 @@ uniffi::build_foreign_language_testcases!(
     "tests/bindings/test_geometry.rb",
     "tests/bindings/test_geometry.kts",
     "tests/bindings/test_geometry.swift",
+    "tests/bindings/test_geometry.cs",
+    "tests/bindings/test_geometry.go",
 );
@@ -0,0 +1,27 @@
+using System;
+using uniffi.sprites;
+
+using (var sempty = new Sprite(null))
+{
+    AssertEqual(new Point(0, 0), sempty.GetPosition());
+}
+
+using var s = new Sprite(new Point(0, 1));
+AssertEqual(new Point(0, 1), s.GetPosition());
+
+s.MoveTo(new Point(1, 2));
+AssertEqual(new Point(1, 2), s.GetPosition());
+
+s.MoveBy(new Vector(-4, 2));
+AssertEqual(new Point(-3, 4), s.GetPosition());
+
+using var srel = Sprite.NewRelativeTo(new Point(0, 1), new Vector(1, 1.5));
+AssertEqual(new Point(1, 2.5), srel.GetPosition());
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
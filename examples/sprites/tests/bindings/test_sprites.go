@@ -0,0 +1,34 @@
+package test
+
+import (
+	"testing"
+
+	"uniffi/sprites"
+)
+
+func TestSprites(t *testing.T) {
+	checkPosition := func(s *sprites.Sprite, expected sprites.Point) {
+		t.Helper()
+		if v := s.GetPosition(); v != expected {
+			t.Fatalf("expected %v, got %v", expected, v)
+		}
+	}
+
+	sempty := sprites.NewSprite(nil)
+	checkPosition(sempty, sprites.Point{X: 0, Y: 0})
+	sempty.Destroy()
+
+	s := sprites.NewSprite(&sprites.Point{X: 0, Y: 1})
+	defer s.Destroy()
+	checkPosition(s, sprites.Point{X: 0, Y: 1})
+
+	s.MoveTo(sprites.Point{X: 1, Y: 2})
+	checkPosition(s, sprites.Point{X: 1, Y: 2})
+
+	s.MoveBy(sprites.Vector{Dx: -4, Dy: 2})
+	checkPosition(s, sprites.Point{X: -3, Y: 4})
+
+	srel := sprites.SpriteNewRelativeTo(sprites.Point{X: 0, Y: 1}, sprites.Vector{Dx: 1, Dy: 1.5})
+	defer srel.Destroy()
+	checkPosition(srel, sprites.Point{X: 1, Y: 2.5})
+}
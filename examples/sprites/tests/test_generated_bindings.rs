@@ -3,4 +3,6 @@ uniffi::build_foreign_language_testcases!(
     "tests/bindings/test_sprites.rb",
     "tests/bindings/test_sprites.kts",
     "tests/bindings/test_sprites.swift",
+    "tests/bindings/test_sprites.cs",
+    "tests/bindings/test_sprites.go",
 );
@@ -0,0 +1,71 @@
+using System;
+using System.Linq;
+using uniffi.todolist;
+
+var todo = new TodoList();
+var entry = new TodoEntry("Write bindings for strings in records");
+
+todo.AddItem("Write C# bindings");
+AssertEqual("Write C# bindings", todo.GetLast());
+
+todo.AddItem("Write tests for bindings");
+AssertEqual("Write tests for bindings", todo.GetLast());
+
+todo.AddEntry(entry);
+AssertEqual("Write bindings for strings in records", todo.GetLast());
+AssertEqual("Write bindings for strings in records", todo.GetLastEntry().Text);
+
+todo.AddItem("Test Ünicode hàndling without an entry can't believe I didn't test this at first 🤣");
+AssertEqual("Test Ünicode hàndling without an entry can't believe I didn't test this at first 🤣", todo.GetLast());
+
+var entry2 = new TodoEntry("Test Ünicode hàndling in an entry can't believe I didn't test this at first 🤣");
+todo.AddEntry(entry2);
+AssertEqual("Test Ünicode hàndling in an entry can't believe I didn't test this at first 🤣", todo.GetLastEntry().Text);
+
+AssertThrows<TodoError.EmptyString>(() => todo.AddItem(""));
+
+var todo2 = new TodoList();
+Assert(!todo2.GetItems().SequenceEqual(todo.GetItems()));
+
+Assert(TodolistMethods.GetDefaultList() == null);
+
+TodolistMethods.SetDefaultList(todo);
+Assert(todo.GetItems().SequenceEqual(TodolistMethods.GetDefaultList()!.GetItems()));
+
+todo2.MakeDefault();
+Assert(todo2.GetItems().SequenceEqual(TodolistMethods.GetDefaultList()!.GetItems()));
+
+todo.AddItem("Test liveness after being demoted from default");
+AssertEqual("Test liveness after being demoted from default", todo.GetLast());
+
+todo2.AddItem("Test shared state through local vs default reference");
+AssertEqual("Test shared state through local vs default reference", TodolistMethods.GetDefaultList()!.GetLast());
+
+static void Assert(bool condition)
+{
+    if (!condition)
+    {
+        throw new Exception("Assertion failed");
+    }
+}
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
+
+static void AssertThrows<E>(Action action) where E : Exception
+{
+    try
+    {
+        action();
+    }
+    catch (E)
+    {
+        return;
+    }
+    throw new Exception($"Expected {typeof(E).Name} to be thrown");
+}
@@ -0,0 +1,80 @@
+package test
+
+import (
+	"errors"
+	"slices"
+	"testing"
+
+	"uniffi/todolist"
+)
+
+func TestTodolist(t *testing.T) {
+	checkLast := func(list *todolist.TodoList, expected string) {
+		t.Helper()
+		if v, err := list.GetLast(); err != nil || v != expected {
+			t.Fatalf("GetLast() = %q, %v, expected %q", v, err, expected)
+		}
+	}
+	checkLastEntry := func(list *todolist.TodoList, expected string) {
+		t.Helper()
+		if v, err := list.GetLastEntry(); err != nil || v.Text != expected {
+			t.Fatalf("GetLastEntry() = %v, %v, expected %q", v, err, expected)
+		}
+	}
+	must := func(err error) {
+		t.Helper()
+		if err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	todo := todolist.NewTodoList()
+	entry := todolist.TodoEntry{Text: "Write bindings for strings in records"}
+
+	must(todo.AddItem("Write Go bindings"))
+	checkLast(todo, "Write Go bindings")
+
+	must(todo.AddItem("Write tests for bindings"))
+	checkLast(todo, "Write tests for bindings")
+
+	must(todo.AddEntry(entry))
+	checkLast(todo, "Write bindings for strings in records")
+	checkLastEntry(todo, "Write bindings for strings in records")
+
+	must(todo.AddItem("Test Ünicode hàndling without an entry can't believe I didn't test this at first 🤣"))
+	checkLast(todo, "Test Ünicode hàndling without an entry can't believe I didn't test this at first 🤣")
+
+	entry2 := todolist.TodoEntry{Text: "Test Ünicode hàndling in an entry can't believe I didn't test this at first 🤣"}
+	must(todo.AddEntry(entry2))
+	checkLastEntry(todo, "Test Ünicode hàndling in an entry can't believe I didn't test this at first 🤣")
+
+	var emptyString *todolist.TodoErrorEmptyString
+	if err := todo.AddItem(""); !errors.As(err, &emptyString) {
+		t.Fatalf("expected TodoErrorEmptyString, got %v", err)
+	}
+
+	todo2 := todolist.NewTodoList()
+	if slices.Equal(todo2.GetItems(), todo.GetItems()) {
+		t.Fatal("todo2 should have different items")
+	}
+
+	if todolist.GetDefaultList() != nil {
+		t.Fatal("there should be no default list")
+	}
+
+	todolist.SetDefaultList(todo)
+	if !slices.Equal(todo.GetItems(), todolist.GetDefaultList().GetItems()) {
+		t.Fatal("todo should be the default list")
+	}
+
+	todo2.MakeDefault()
+	if !slices.Equal(todo2.GetItems(), todolist.GetDefaultList().GetItems()) {
+		t.Fatal("todo2 should be the default list")
+	}
+
+	must(todo.AddItem("Test liveness after being demoted from default"))
+	checkLast(todo, "Test liveness after being demoted from default")
+
+	must(todo2.AddItem("Test shared state through local vs default reference"))
+	checkLast(todolist.GetDefaultList(), "Test shared state through local vs default reference")
+}
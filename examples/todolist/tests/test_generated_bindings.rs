@@ -2,5 +2,7 @@ uniffi::build_foreign_language_testcases!(
     "tests/bindings/test_todolist.kts",
     "tests/bindings/test_todolist.swift",
     "tests/bindings/test_todolist.rb",
-    "tests/bindings/test_todolist.py"
+    "tests/bindings/test_todolist.py",
+    "tests/bindings/test_todolist.cs",
+    "tests/bindings/test_todolist.go",
 );
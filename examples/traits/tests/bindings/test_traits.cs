@@ -0,0 +1,33 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using uniffi.traits;
+
+var buttons = TraitsMethods.GetButtons();
+
+AssertEqual("stop", buttons[0].Name());
+AssertEqual("stop", TraitsMethods.Press(buttons[0]).Name());
+
+AssertEqual("go", buttons[1].Name());
+AssertEqual("go", TraitsMethods.Press(buttons[1]).Name());
+
+// A C# implementation makes it through Rust and back again
+AssertEqual("CsButton", TraitsMethods.Press(new CsButton()).Name());
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
+
+class CsButton : IButton
+{
+    public string Name()
+    {
+        return "CsButton";
+    }
+}
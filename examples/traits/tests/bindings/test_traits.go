@@ -0,0 +1,40 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package test
+
+import (
+	"testing"
+
+	"uniffi/traits"
+)
+
+type goButton struct{}
+
+func (goButton) Name() string {
+	return "GoButton"
+}
+
+func TestTraits(t *testing.T) {
+	buttons := traits.GetButtons()
+
+	if v := buttons[0].Name(); v != "stop" {
+		t.Fatalf("buttons[0].Name() = %q", v)
+	}
+	if v := traits.Press(buttons[0]).Name(); v != "stop" {
+		t.Fatalf("Press(buttons[0]).Name() = %q", v)
+	}
+
+	if v := buttons[1].Name(); v != "go" {
+		t.Fatalf("buttons[1].Name() = %q", v)
+	}
+	if v := traits.Press(buttons[1]).Name(); v != "go" {
+		t.Fatalf("Press(buttons[1]).Name() = %q", v)
+	}
+
+	// A Go implementation makes it through Rust and back again
+	if v := traits.Press(goButton{}).Name(); v != "GoButton" {
+		t.Fatalf("Press(goButton{}).Name() = %q", v)
+	}
+}
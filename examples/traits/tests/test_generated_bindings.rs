@@ -3,4 +3,6 @@ uniffi::build_foreign_language_testcases!(
     "tests/bindings/test_traits.kts",
     "tests/bindings/test_traits.swift",
     "tests/bindings/test_traits.rb",
+    "tests/bindings/test_traits.cs",
+    "tests/bindings/test_traits.go",
 );
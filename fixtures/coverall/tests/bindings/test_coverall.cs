@@ -0,0 +1,265 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text;
+using System.Threading.Tasks;
+using uniffi.coverall;
+
+// Records
+{
+    var d = CoverallMethods.CreateSomeDict();
+    AssertEqual("text", d.Text);
+    AssertEqual("maybe_text", d.MaybeText);
+    Assert(d.SomeBytes.SequenceEqual(Encoding.UTF8.GetBytes("some_bytes")));
+    Assert(d.MaybeSomeBytes!.SequenceEqual(Encoding.UTF8.GetBytes("maybe_some_bytes")));
+    Assert(d.ABool);
+    AssertEqual(false, d.MaybeABool);
+    AssertEqual((byte)1, d.Unsigned8);
+    AssertEqual((byte?)2, d.MaybeUnsigned8);
+    AssertEqual((ushort)3, d.Unsigned16);
+    AssertEqual((ushort?)4, d.MaybeUnsigned16);
+    AssertEqual(18446744073709551615UL, d.Unsigned64);
+    AssertEqual((ulong?)0, d.MaybeUnsigned64);
+    AssertEqual((sbyte)8, d.Signed8);
+    AssertEqual((sbyte?)0, d.MaybeSigned8);
+    AssertEqual(9223372036854775807L, d.Signed64);
+    AssertEqual((long?)0, d.MaybeSigned64);
+    Assert(Math.Abs(d.Float32 - 1.2345f) < 0.0001f);
+    Assert(Math.Abs(d.MaybeFloat32!.Value - 22.0f / 7.0f) < 0.0001f);
+    AssertEqual(0.0, d.Float64);
+    AssertEqual((double?)1.0, d.MaybeFloat64);
+    AssertEqual("some_dict", d.Coveralls!.GetName());
+    AssertEqual("some_dict_1", d.CoverallsList[0]!.GetName());
+    AssertEqual(null, d.CoverallsList[1]);
+    AssertEqual("some_dict_2", d.CoverallsList[2]!.GetName());
+    AssertEqual("some_dict_3", d.CoverallsMap["some_dict_3"]!.GetName());
+    AssertEqual(null, d.CoverallsMap["none"]);
+    AssertEqual("some_dict_4", d.CoverallsMap["some_dict_4"]!.GetName());
+
+    AssertEqual(5UL, CoverallMethods.GetNumAlive());
+    d.Coveralls!.Dispose();
+    foreach (var c in d.CoverallsList)
+    {
+        c?.Dispose();
+    }
+    foreach (var c in d.CoverallsMap.Values)
+    {
+        c?.Dispose();
+    }
+    AssertEqual(0UL, CoverallMethods.GetNumAlive());
+}
+
+{
+    var d = CoverallMethods.CreateNoneDict();
+    AssertEqual("text", d.Text);
+    AssertEqual(null, d.MaybeText);
+    AssertEqual(null, d.MaybeSomeBytes);
+    AssertEqual(null, d.MaybeABool);
+    AssertEqual(null, d.MaybeUnsigned8);
+    AssertEqual(null, d.MaybeUnsigned16);
+    AssertEqual(null, d.MaybeUnsigned64);
+    AssertEqual(null, d.MaybeSigned8);
+    AssertEqual(null, d.MaybeSigned64);
+    AssertEqual(null, d.MaybeFloat32);
+    AssertEqual(null, d.MaybeFloat64);
+    AssertEqual(null, d.Coveralls);
+    AssertEqual(0UL, CoverallMethods.GetNumAlive());
+}
+
+// Constructors
+{
+    var coveralls = new Coveralls("c1");
+    AssertEqual(1UL, CoverallMethods.GetNumAlive());
+    AssertEqual("c1", coveralls.GetName());
+
+    var coveralls2 = Coveralls.FallibleNew("c2", false);
+    AssertEqual(2UL, CoverallMethods.GetNumAlive());
+    AssertEqual("c2", coveralls2.GetName());
+
+    AssertThrows<CoverallError.TooManyHoles>(() => Coveralls.FallibleNew("", true));
+    var panic = AssertThrows<UniffiInternalException>(() => Coveralls.PanickingNew("expected panic: woe is me"));
+    Assert(panic.Message.Contains("expected panic: woe is me"));
+
+    coveralls.Dispose();
+    coveralls2.Dispose();
+    AssertEqual(0UL, CoverallMethods.GetNumAlive());
+}
+
+// Errors
+using (var coveralls = new Coveralls("test_errors"))
+{
+    var err = AssertThrows<CoverallError.TooManyHoles>(() => coveralls.MaybeThrow(true));
+    AssertEqual("The coverall has too many holes", err.Message);
+    AssertThrows<CoverallError.TooManyHoles>(() => coveralls.MaybeThrowInto(true));
+    var panic = AssertThrows<UniffiInternalException>(() => coveralls.Panic("expected panic: oh no"));
+    Assert(panic.Message.Contains("expected panic: oh no"));
+
+    Assert(coveralls.MaybeThrowComplex(0));
+    var osError = AssertThrows<ComplexError.OsError>(() => coveralls.MaybeThrowComplex(1));
+    AssertEqual((short)10, osError.Code);
+    AssertEqual((short)20, osError.ExtendedCode);
+    var permissionDenied = AssertThrows<ComplexError.PermissionDenied>(() => coveralls.MaybeThrowComplex(2));
+    AssertEqual("Forbidden", permissionDenied.Reason);
+    AssertThrows<ComplexError.UnknownError>(() => coveralls.MaybeThrowComplex(3));
+    AssertThrows<UniffiInternalException>(() => coveralls.MaybeThrowComplex(4));
+
+    AssertThrows<HtmlError.InvalidHtml>(() => CoverallMethods.ValidateHtml("test"));
+}
+
+// Objects
+using (var coveralls = new Coveralls("test_arcs"))
+{
+    // One reference is held by the handle, and one by the `Arc<Self>` method receiver.
+    AssertEqual(2UL, coveralls.StrongCount());
+    AssertEqual(null, coveralls.GetOther());
+    coveralls.TakeOther(coveralls);
+    AssertEqual(3UL, coveralls.StrongCount());
+    AssertEqual(1UL, CoverallMethods.GetNumAlive());
+    using (var other = coveralls.GetOther()!)
+    {
+        AssertEqual("test_arcs", other.GetName());
+    }
+    AssertThrows<CoverallError.TooManyHoles>(() => coveralls.TakeOtherFallible());
+    AssertThrows<UniffiInternalException>(() => coveralls.TakeOtherPanic("expected panic: with an arc!"));
+    coveralls.TakeOther(null);
+    AssertEqual(2UL, coveralls.StrongCount());
+
+    using (var c2 = coveralls.CloneMe())
+    {
+        AssertEqual(coveralls.GetName(), c2.GetName());
+        AssertEqual(2UL, CoverallMethods.GetNumAlive());
+    }
+    AssertEqual(1UL, CoverallMethods.GetNumAlive());
+
+    Assert(coveralls.Reverse(new byte[] { 1, 2, 3 }).SequenceEqual(new byte[] { 3, 2, 1 }));
+}
+AssertEqual(0UL, CoverallMethods.GetNumAlive());
+
+// Trait interfaces implemented in Rust and C#
+{
+    var getters = new CsGetters();
+    CoverallMethods.TestGetters(getters);
+    CoverallMethods.TestGetters(CoverallMethods.MakeRustGetters());
+    CoverallMethods.TestRoundTripThroughForeign(getters);
+    AssertEqual(getters, CoverallMethods.TestRoundTripThroughRust(getters));
+
+    var traits = CoverallMethods.GetTraits();
+    AssertEqual("node-1", traits[0].Name());
+    AssertEqual(2UL, traits[0].StrongCount());
+    AssertEqual("node-2", traits[1].Name());
+
+    traits[0].SetParent(traits[1]);
+    AssertEqual("node-2", traits[0].GetParent()!.Name());
+    Assert(CoverallMethods.AncestorNames(traits[0]).SequenceEqual(new[] { "node-2" }));
+
+    var csNode = new CsNode();
+    traits[1].SetParent(csNode);
+    Assert(CoverallMethods.AncestorNames(traits[0]).SequenceEqual(new[] { "node-2", "node-cs" }));
+    AssertEqual("node-cs", traits[1].GetParent()!.Name());
+    traits[1].SetParent(null);
+}
+
+// Async functions
+Assert(await CoverallMethods.AsyncBool(true));
+Assert(!await CoverallMethods.AsyncBool(false));
+
+static void Assert(bool condition)
+{
+    if (!condition)
+    {
+        throw new Exception("Assertion failed");
+    }
+}
+
+static void AssertEqual<T>(T expected, T actual)
+{
+    if (!Equals(expected, actual))
+    {
+        throw new Exception($"Expected {expected}, got {actual}");
+    }
+}
+
+static E AssertThrows<E>(Action action) where E : Exception
+{
+    try
+    {
+        action();
+    }
+    catch (E e)
+    {
+        return e;
+    }
+    throw new Exception($"Expected {typeof(E).Name} to be thrown");
+}
+
+class CsGetters : IGetters
+{
+    public bool GetBool(bool v, bool arg2)
+    {
+        return v ^ arg2;
+    }
+
+    public List<int> GetList(List<int> v, bool arg2)
+    {
+        return arg2 ? v : new List<int>();
+    }
+
+    public void GetNothing(string v)
+    {
+    }
+
+    public string? GetOption(string v, bool arg2)
+    {
+        return v switch
+        {
+            "os-error" => throw new ComplexError.OsError(100, 200),
+            "unknown-error" => throw new ComplexError.UnknownError(),
+            "" when arg2 => null,
+            _ => arg2 ? v.ToUpperInvariant() : v,
+        };
+    }
+
+    public string GetString(string v, bool arg2)
+    {
+        return v switch
+        {
+            "too-many-holes" => throw new CoverallError.TooManyHoles("Too many holes"),
+            "unexpected-error" => throw new InvalidOperationException("unexpected error"),
+            _ => arg2 ? v.ToUpperInvariant() : v,
+        };
+    }
+
+    public Coveralls RoundTripObject(Coveralls coveralls)
+    {
+        return coveralls;
+    }
+}
+
+class CsNode : INodeTrait
+{
+    private INodeTrait? parent;
+
+    public INodeTrait? GetParent()
+    {
+        return parent;
+    }
+
+    public string Name()
+    {
+        return "node-cs";
+    }
+
+    public void SetParent(INodeTrait? parent)
+    {
+        this.parent = parent;
+    }
+
+    public ulong StrongCount()
+    {
+        return 0;
+    }
+}
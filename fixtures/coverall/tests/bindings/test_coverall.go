@@ -0,0 +1,347 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+package test
+
+import (
+	"bytes"
+	"errors"
+	"math"
+	"slices"
+	"strings"
+	"testing"
+
+	"uniffi/coverall"
+)
+
+// Run `f` and return the value it panicked with, or nil if it didn't panic
+func recoverPanic(f func()) (r any) {
+	defer func() {
+		r = recover()
+	}()
+	f()
+	return nil
+}
+
+func checkPanic(t *testing.T, f func(), message string) {
+	t.Helper()
+	r := recoverPanic(f)
+	if r == nil {
+		t.Fatalf("expected panic containing %q", message)
+	}
+	err, ok := r.(error)
+	if !ok || !strings.Contains(err.Error(), message) {
+		t.Fatalf("expected panic containing %q, got %v", message, r)
+	}
+}
+
+func checkNumAlive(t *testing.T, expected uint64) {
+	t.Helper()
+	if v := coverall.GetNumAlive(); v != expected {
+		t.Fatalf("GetNumAlive() = %v, expected %v", v, expected)
+	}
+}
+
+func TestRecords(t *testing.T) {
+	d := coverall.CreateSomeDict()
+	if d.Text != "text" || *d.MaybeText != "maybe_text" {
+		t.Fatalf("unexpected text fields: %v", d)
+	}
+	if !bytes.Equal(d.SomeBytes, []byte("some_bytes")) || !bytes.Equal(*d.MaybeSomeBytes, []byte("maybe_some_bytes")) {
+		t.Fatalf("unexpected bytes fields: %v", d)
+	}
+	if !d.ABool || *d.MaybeABool {
+		t.Fatalf("unexpected bool fields: %v", d)
+	}
+	if d.Unsigned8 != 1 || *d.MaybeUnsigned8 != 2 || d.Unsigned16 != 3 || *d.MaybeUnsigned16 != 4 {
+		t.Fatalf("unexpected unsigned fields: %v", d)
+	}
+	if d.Unsigned64 != math.MaxUint64 || *d.MaybeUnsigned64 != 0 {
+		t.Fatalf("unexpected unsigned 64 fields: %v", d)
+	}
+	if d.Signed8 != 8 || *d.MaybeSigned8 != 0 || d.Signed64 != math.MaxInt64 || *d.MaybeSigned64 != 0 {
+		t.Fatalf("unexpected signed fields: %v", d)
+	}
+	if math.Abs(float64(d.Float32-1.2345)) > 0.0001 || math.Abs(float64(*d.MaybeFloat32-22.0/7.0)) > 0.0001 {
+		t.Fatalf("unexpected float32 fields: %v", d)
+	}
+	if d.Float64 != 0.0 || *d.MaybeFloat64 != 1.0 {
+		t.Fatalf("unexpected float64 fields: %v", d)
+	}
+	if v := d.Coveralls.GetName(); v != "some_dict" {
+		t.Fatalf("d.Coveralls.GetName() = %q", v)
+	}
+	if d.CoverallsList[0].GetName() != "some_dict_1" || d.CoverallsList[1] != nil || d.CoverallsList[2].GetName() != "some_dict_2" {
+		t.Fatalf("unexpected CoverallsList: %v", d.CoverallsList)
+	}
+	if d.CoverallsMap["some_dict_3"].GetName() != "some_dict_3" || d.CoverallsMap["none"] != nil || d.CoverallsMap["some_dict_4"].GetName() != "some_dict_4" {
+		t.Fatalf("unexpected CoverallsMap: %v", d.CoverallsMap)
+	}
+
+	checkNumAlive(t, 5)
+	d.Coveralls.Destroy()
+	for _, c := range d.CoverallsList {
+		if c != nil {
+			c.Destroy()
+		}
+	}
+	for _, c := range d.CoverallsMap {
+		if c != nil {
+			c.Destroy()
+		}
+	}
+	checkNumAlive(t, 0)
+
+	d = coverall.CreateNoneDict()
+	if d.Text != "text" {
+		t.Fatalf("d.Text = %q", d.Text)
+	}
+	if d.MaybeText != nil || d.MaybeSomeBytes != nil || d.MaybeABool != nil ||
+		d.MaybeUnsigned8 != nil || d.MaybeUnsigned16 != nil || d.MaybeUnsigned64 != nil ||
+		d.MaybeSigned8 != nil || d.MaybeSigned64 != nil || d.MaybeFloat32 != nil ||
+		d.MaybeFloat64 != nil || d.Coveralls != nil {
+		t.Fatalf("expected all optional fields to be nil: %v", d)
+	}
+	checkNumAlive(t, 0)
+}
+
+func TestConstructors(t *testing.T) {
+	coveralls := coverall.NewCoveralls("c1")
+	checkNumAlive(t, 1)
+	if v := coveralls.GetName(); v != "c1" {
+		t.Fatalf("GetName() = %q", v)
+	}
+
+	coveralls2, err := coverall.CoverallsFallibleNew("c2", false)
+	if err != nil {
+		t.Fatal(err)
+	}
+	checkNumAlive(t, 2)
+	if v := coveralls2.GetName(); v != "c2" {
+		t.Fatalf("GetName() = %q", v)
+	}
+
+	var tooManyHoles *coverall.CoverallErrorTooManyHoles
+	if _, err := coverall.CoverallsFallibleNew("", true); !errors.As(err, &tooManyHoles) {
+		t.Fatalf("expected CoverallErrorTooManyHoles, got %v", err)
+	}
+	checkPanic(t, func() { coverall.CoverallsPanickingNew("expected panic: woe is me") }, "expected panic: woe is me")
+
+	coveralls.Destroy()
+	coveralls2.Destroy()
+	checkNumAlive(t, 0)
+}
+
+func TestErrors(t *testing.T) {
+	coveralls := coverall.NewCoveralls("test_errors")
+	defer coveralls.Destroy()
+
+	var tooManyHoles *coverall.CoverallErrorTooManyHoles
+	if _, err := coveralls.MaybeThrow(true); !errors.As(err, &tooManyHoles) {
+		t.Fatalf("expected CoverallErrorTooManyHoles, got %v", err)
+	}
+	if tooManyHoles.Error() != "The coverall has too many holes" {
+		t.Fatalf("unexpected error message: %q", tooManyHoles.Error())
+	}
+	if _, err := coveralls.MaybeThrowInto(true); !errors.As(err, &tooManyHoles) {
+		t.Fatalf("expected CoverallErrorTooManyHoles, got %v", err)
+	}
+	checkPanic(t, func() { coveralls.Panic("expected panic: oh no") }, "expected panic: oh no")
+
+	if v, err := coveralls.MaybeThrowComplex(0); err != nil || !v {
+		t.Fatalf("MaybeThrowComplex(0) = %v, %v", v, err)
+	}
+	var osError *coverall.ComplexErrorOsError
+	if _, err := coveralls.MaybeThrowComplex(1); !errors.As(err, &osError) {
+		t.Fatalf("expected ComplexErrorOsError, got %v", err)
+	}
+	if osError.Code != 10 || osError.ExtendedCode != 20 {
+		t.Fatalf("unexpected OsError: %v", osError)
+	}
+	var permissionDenied *coverall.ComplexErrorPermissionDenied
+	if _, err := coveralls.MaybeThrowComplex(2); !errors.As(err, &permissionDenied) {
+		t.Fatalf("expected ComplexErrorPermissionDenied, got %v", err)
+	}
+	if permissionDenied.Reason != "Forbidden" {
+		t.Fatalf("unexpected PermissionDenied: %v", permissionDenied)
+	}
+	var unknownError *coverall.ComplexErrorUnknownError
+	if _, err := coveralls.MaybeThrowComplex(3); !errors.As(err, &unknownError) {
+		t.Fatalf("expected ComplexErrorUnknownError, got %v", err)
+	}
+	if recoverPanic(func() { _, _ = coveralls.MaybeThrowComplex(4) }) == nil {
+		t.Fatal("expected MaybeThrowComplex(4) to panic")
+	}
+
+	var invalidHtml *coverall.HtmlErrorInvalidHtml
+	if err := coverall.ValidateHtml("test"); !errors.As(err, &invalidHtml) {
+		t.Fatalf("expected HtmlErrorInvalidHtml, got %v", err)
+	}
+}
+
+func TestObjects(t *testing.T) {
+	coveralls := coverall.NewCoveralls("test_arcs")
+
+	// One reference is held by the handle, and one by the `Arc<Self>` method receiver.
+	if v := coveralls.StrongCount(); v != 2 {
+		t.Fatalf("StrongCount() = %v", v)
+	}
+	if coveralls.GetOther() != nil {
+		t.Fatal("GetOther() should be nil")
+	}
+	coveralls.TakeOther(coveralls)
+	if v := coveralls.StrongCount(); v != 3 {
+		t.Fatalf("StrongCount() = %v", v)
+	}
+	checkNumAlive(t, 1)
+	other := coveralls.GetOther()
+	if v := other.GetName(); v != "test_arcs" {
+		t.Fatalf("other.GetName() = %q", v)
+	}
+	other.Destroy()
+
+	var tooManyHoles *coverall.CoverallErrorTooManyHoles
+	if err := coveralls.TakeOtherFallible(); !errors.As(err, &tooManyHoles) {
+		t.Fatalf("expected CoverallErrorTooManyHoles, got %v", err)
+	}
+	checkPanic(t, func() { coveralls.TakeOtherPanic("expected panic: with an arc!") }, "expected panic: with an arc!")
+	coveralls.TakeOther(nil)
+	if v := coveralls.StrongCount(); v != 2 {
+		t.Fatalf("StrongCount() = %v", v)
+	}
+
+	c2 := coveralls.CloneMe()
+	if c2.GetName() != coveralls.GetName() {
+		t.Fatalf("CloneMe() name mismatch: %q", c2.GetName())
+	}
+	checkNumAlive(t, 2)
+	c2.Destroy()
+	checkNumAlive(t, 1)
+
+	if v := coveralls.Reverse([]byte{1, 2, 3}); !bytes.Equal(v, []byte{3, 2, 1}) {
+		t.Fatalf("Reverse() = %v", v)
+	}
+
+	coveralls.Destroy()
+	checkNumAlive(t, 0)
+	if recoverPanic(func() { coveralls.GetName() }) == nil {
+		t.Fatal("expected using a destroyed object to panic")
+	}
+}
+
+type goGetters struct{}
+
+func (goGetters) GetBool(v bool, arg2 bool) bool {
+	return v != arg2
+}
+
+func (goGetters) GetList(v []int32, arg2 bool) []int32 {
+	if arg2 {
+		return v
+	}
+	return []int32{}
+}
+
+func (goGetters) GetNothing(v string) {}
+
+func (goGetters) GetOption(v string, arg2 bool) (*string, error) {
+	switch {
+	case v == "os-error":
+		return nil, &coverall.ComplexErrorOsError{Code: 100, ExtendedCode: 200}
+	case v == "unknown-error":
+		return nil, &coverall.ComplexErrorUnknownError{}
+	case v == "" && arg2:
+		return nil, nil
+	case arg2:
+		result := strings.ToUpper(v)
+		return &result, nil
+	default:
+		return &v, nil
+	}
+}
+
+func (goGetters) GetString(v string, arg2 bool) (string, error) {
+	switch {
+	case v == "too-many-holes":
+		return "", &coverall.CoverallErrorTooManyHoles{Message: "Too many holes"}
+	case v == "unexpected-error":
+		return "", errors.New("unexpected error")
+	case arg2:
+		return strings.ToUpper(v), nil
+	default:
+		return v, nil
+	}
+}
+
+func (goGetters) RoundTripObject(coveralls *coverall.Coveralls) *coverall.Coveralls {
+	return coveralls
+}
+
+type goNode struct {
+	parent coverall.NodeTrait
+}
+
+func (n *goNode) GetParent() coverall.NodeTrait {
+	return n.parent
+}
+
+func (n *goNode) Name() string {
+	return "node-go"
+}
+
+func (n *goNode) SetParent(parent coverall.NodeTrait) {
+	n.parent = parent
+}
+
+func (n *goNode) StrongCount() uint64 {
+	return 0
+}
+
+// Trait interfaces implemented in Rust and Go
+func TestTraitInterfaces(t *testing.T) {
+	getters := &goGetters{}
+	coverall.TestGetters(getters)
+	coverall.TestGetters(coverall.MakeRustGetters())
+	coverall.TestRoundTripThroughForeign(getters)
+	if v := coverall.TestRoundTripThroughRust(getters); v != coverall.Getters(getters) {
+		t.Fatalf("TestRoundTripThroughRust() returned %v", v)
+	}
+
+	traits := coverall.GetTraits()
+	if v := traits[0].Name(); v != "node-1" {
+		t.Fatalf("traits[0].Name() = %q", v)
+	}
+	if v := traits[0].StrongCount(); v != 2 {
+		t.Fatalf("traits[0].StrongCount() = %v", v)
+	}
+	if v := traits[1].Name(); v != "node-2" {
+		t.Fatalf("traits[1].Name() = %q", v)
+	}
+
+	traits[0].SetParent(traits[1])
+	if v := traits[0].GetParent().Name(); v != "node-2" {
+		t.Fatalf("traits[0].GetParent().Name() = %q", v)
+	}
+	if v := coverall.AncestorNames(traits[0]); !slices.Equal(v, []string{"node-2"}) {
+		t.Fatalf("AncestorNames(traits[0]) = %v", v)
+	}
+
+	traits[1].SetParent(&goNode{})
+	if v := coverall.AncestorNames(traits[0]); !slices.Equal(v, []string{"node-2", "node-go"}) {
+		t.Fatalf("AncestorNames(traits[0]) = %v", v)
+	}
+	if v := traits[1].GetParent().Name(); v != "node-go" {
+		t.Fatalf("traits[1].GetParent().Name() = %q", v)
+	}
+	traits[1].SetParent(nil)
+}
+
+func TestAsync(t *testing.T) {
+	if !coverall.AsyncBool(true) {
+		t.Fatal("AsyncBool(true) returned false")
+	}
+	if coverall.AsyncBool(false) {
+		t.Fatal("AsyncBool(false) returned true")
+	}
+}
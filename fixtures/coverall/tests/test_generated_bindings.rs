@@ -1,4 +1,6 @@
 uniffi::build_foreign_language_testcases!(
+    "tests/bindings/test_coverall.cs",
+    "tests/bindings/test_coverall.go",
     "tests/bindings/test_coverall.py",
     "tests/bindings/test_coverall.kts",
     "tests/bindings/test_coverall.rb",
@@ -31,6 +31,31 @@ mod weapon {
     }
 }
 
+mod serde_names {
+    #[derive(uniffi::Record, serde::Serialize)]
+    #[uniffi(serde_names)]
+    #[serde(rename = "SerdeRecord", rename_all = "camelCase")]
+    pub struct SerdeNamedRecord {
+        first_value: u8,
+        #[serde(rename = "second", alias = "other")]
+        second_value: u8,
+    }
+
+    #[derive(uniffi::Enum, serde::Serialize)]
+    #[uniffi(serde_names)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE", rename_all_fields = "PascalCase")]
+    pub enum SerdeNamedEnum {
+        EmptyState,
+        WithValue {
+            inner_value: u8,
+        },
+        #[serde(rename_all = "kebab-case")]
+        WithRenamedFields {
+            inner_value: u8,
+        },
+    }
+}
+
 mod state {
     use super::Person;
 
@@ -133,10 +158,18 @@ pub use weapon::Weapon;
 
 mod test_type_ids {
     use super::*;
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
     use std::sync::Arc;
     use uniffi_core::TypeId;
 
+    // Closure types require a foreign closure VTable, which is normally defined by
+    // `setup_scaffolding!()`.
+    impl uniffi_core::HasForeignClosureVTable for UniFfiTag {
+        fn foreign_closure_vtable() -> &'static uniffi_core::ForeignClosureVTable {
+            unreachable!()
+        }
+    }
+
     fn check_type_id<T: TypeId<UniFfiTag>>(correct_type: Type) {
         let buf = &mut T::TYPE_ID_META.as_ref();
         assert_eq!(
@@ -157,6 +190,9 @@ mod test_type_ids {
         check_type_id::<i16>(Type::Int16);
         check_type_id::<i32>(Type::Int32);
         check_type_id::<i64>(Type::Int64);
+        check_type_id::<u128>(Type::UInt128);
+        check_type_id::<i128>(Type::Int128);
+        check_type_id::<char>(Type::Char);
         check_type_id::<f32>(Type::Float32);
         check_type_id::<f64>(Type::Float64);
         check_type_id::<bool>(Type::Boolean);
@@ -180,6 +216,21 @@ mod test_type_ids {
         });
     }
 
+    #[test]
+    fn test_borrowed_types() {
+        check_type_id::<&str>(Type::String);
+        check_type_id::<&[u8]>(Type::Bytes);
+        check_type_id::<&[u16]>(Type::Sequence {
+            inner_type: Box::new(Type::UInt16),
+        });
+        check_type_id::<Option<&Person>>(Type::Optional {
+            inner_type: Box::new(Type::Record {
+                module_path: "uniffi_fixture_metadata::tests::person".into(),
+                name: "PersonRenamed".into(),
+            }),
+        });
+    }
+
     #[test]
     fn test_generics() {
         check_type_id::<Option<u8>>(Type::Optional {
@@ -195,6 +246,62 @@ mod test_type_ids {
         check_type_id::<HashMap<String, u8>>(Type::Map {
             key_type: Box::new(Type::String),
             value_type: Box::new(Type::UInt8),
+            ordered: false,
+        });
+        check_type_id::<BTreeMap<String, u8>>(Type::Map {
+            key_type: Box::new(Type::String),
+            value_type: Box::new(Type::UInt8),
+            ordered: true,
+        });
+        check_type_id::<HashSet<u32>>(Type::Set {
+            inner_type: Box::new(Type::UInt32),
+            ordered: false,
+        });
+        check_type_id::<BTreeSet<u32>>(Type::Set {
+            inner_type: Box::new(Type::UInt32),
+            ordered: true,
+        });
+        check_type_id::<(String, u8)>(Type::Tuple {
+            inner_types: vec![Type::String, Type::UInt8],
+        });
+        check_type_id::<(u8, (bool, String), Vec<u16>)>(Type::Tuple {
+            inner_types: vec![
+                Type::UInt8,
+                Type::Tuple {
+                    inner_types: vec![Type::Boolean, Type::String],
+                },
+                Type::Sequence {
+                    inner_type: Box::new(Type::UInt16),
+                },
+            ],
+        });
+        check_type_id::<[u8; 32]>(Type::Array {
+            inner_type: Box::new(Type::UInt8),
+            len: 32,
+        });
+        check_type_id::<Vec<[String; 2]>>(Type::Sequence {
+            inner_type: Box::new(Type::Array {
+                inner_type: Box::new(Type::String),
+                len: 2,
+            }),
+        });
+        check_type_id::<uniffi_core::RustStream<Option<String>>>(Type::Stream {
+            item_type: Box::new(Type::Optional {
+                inner_type: Box::new(Type::String),
+            }),
+        });
+        check_type_id::<uniffi_core::Iter<Option<String>>>(Type::Iterator {
+            item_type: Box::new(Type::Optional {
+                inner_type: Box::new(Type::String),
+            }),
+        });
+        check_type_id::<Box<dyn Fn(u32, String) -> bool + Send + Sync>>(Type::Closure {
+            argument_types: vec![Type::UInt32, Type::String],
+            return_type: Some(Box::new(Type::Boolean)),
+        });
+        check_type_id::<Arc<dyn Fn() + Send + Sync>>(Type::Closure {
+            argument_types: vec![],
+            return_type: None,
         });
     }
 }
@@ -250,6 +357,88 @@ mod test_metadata {
         );
     }
 
+    #[test]
+    fn test_serde_names_record() {
+        check_metadata(
+            &serde_names::UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_SERDERECORD,
+            RecordMetadata {
+                module_path: "uniffi_fixture_metadata::tests::serde_names".into(),
+                name: "SerdeRecord".into(),
+                orig_name: Some("SerdeNamedRecord".into()),
+                remote: false,
+                fields: vec![
+                    FieldMetadata {
+                        name: "firstValue".into(),
+                        orig_name: Some("first_value".into()),
+                        ty: Type::UInt8,
+                        default: None,
+                        docstring: None,
+                    },
+                    FieldMetadata {
+                        name: "second".into(),
+                        orig_name: Some("second_value".into()),
+                        ty: Type::UInt8,
+                        default: None,
+                        docstring: None,
+                    },
+                ],
+                docstring: None,
+            },
+        );
+    }
+
+    #[test]
+    fn test_serde_names_enum() {
+        check_metadata(
+            &serde_names::UNIFFI_META_UNIFFI_FIXTURE_METADATA_ENUM_SERDENAMEDENUM,
+            EnumMetadata {
+                module_path: "uniffi_fixture_metadata::tests::serde_names".into(),
+                name: "SerdeNamedEnum".into(),
+                orig_name: None,
+                shape: EnumShape::Enum,
+                remote: false,
+                discr_type: None,
+                variants: vec![
+                    VariantMetadata {
+                        name: "EMPTY_STATE".into(),
+                        orig_name: Some("EmptyState".into()),
+                        discr: None,
+                        fields: vec![],
+                        docstring: None,
+                    },
+                    VariantMetadata {
+                        name: "WITH_VALUE".into(),
+                        orig_name: Some("WithValue".into()),
+                        discr: None,
+                        fields: vec![FieldMetadata {
+                            name: "InnerValue".into(),
+                            orig_name: Some("inner_value".into()),
+                            ty: Type::UInt8,
+                            default: None,
+                            docstring: None,
+                        }],
+                        docstring: None,
+                    },
+                    VariantMetadata {
+                        name: "WITH_RENAMED_FIELDS".into(),
+                        orig_name: Some("WithRenamedFields".into()),
+                        discr: None,
+                        fields: vec![FieldMetadata {
+                            name: "inner-value".into(),
+                            orig_name: Some("inner_value".into()),
+                            ty: Type::UInt8,
+                            default: None,
+                            docstring: None,
+                        }],
+                        docstring: None,
+                    },
+                ],
+                non_exhaustive: false,
+                docstring: None,
+            },
+        );
+    }
+
     #[test]
     fn test_simple_enum() {
         check_metadata(
@@ -423,6 +612,7 @@ mod test_metadata {
                 return_type: None,
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: Some(
                     state::uniffi_uniffi_fixture_metadata_checksum_method_state_state_method_renamed(),
                 ),
@@ -675,6 +865,11 @@ mod test_function_metadata {
         pub fn get_display(&self) -> Arc<dyn CalculatorDisplay> {
             unimplemented!()
         }
+
+        #[allow(unused)]
+        pub fn max_value(digits: u8) -> u8 {
+            unimplemented!()
+        }
     }
 
     #[uniffi::export(with_foreign)]
@@ -838,6 +1033,7 @@ mod test_function_metadata {
                 return_type: Some(Type::UInt8),
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_METHOD_CALCULATORRENAMED_ADD
                         .checksum(),
@@ -847,6 +1043,28 @@ mod test_function_metadata {
         );
     }
 
+    #[test]
+    fn test_static_method() {
+        check_metadata(
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_STATIC_METHOD_CALCULATORRENAMED_MAX_VALUE,
+            StaticMethodMetadata {
+                module_path: "uniffi_fixture_metadata::tests::test_function_metadata".into(),
+                self_name: "CalculatorRenamed".into(),
+                name: "max_value".into(),
+                orig_name: None,
+                is_async: false,
+                inputs: vec![FnParamMetadata::simple("digits", Type::UInt8)],
+                return_type: Some(Type::UInt8),
+                throws: None,
+                checksum: Some(
+                    UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_STATIC_METHOD_CALCULATORRENAMED_MAX_VALUE
+                        .checksum(),
+                ),
+                docstring: None,
+            },
+        );
+    }
+
     #[test]
     fn test_constructor() {
         check_metadata(
@@ -964,6 +1182,7 @@ mod test_function_metadata {
                 return_type: Some(Type::UInt8),
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_METHOD_CALCULATORRENAMED_ASYNC_SUB
                         .checksum(),
@@ -1022,6 +1241,7 @@ mod test_function_metadata {
                 inputs: vec![],
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_METHOD_CALCULATORRENAMED_GET_DISPLAY
                         .checksum(),
@@ -1048,6 +1268,7 @@ mod test_function_metadata {
                 return_type: None,
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: Some(UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_METHOD_CALCULATORDISPLAY_DISPLAY_RESULT_RENAMED
                     .checksum()),
                 docstring: None,
@@ -1111,6 +1332,7 @@ mod test_function_metadata {
                 return_type: None,
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: Some(
                     UNIFFI_META_CONST_UNIFFI_FIXTURE_METADATA_METHOD_LOGGER_LOG.checksum(),
                 ),
@@ -1309,3 +1531,127 @@ mod test_custom_types {
         );
     }
 }
+
+mod test_constants {
+    use super::*;
+
+    #[derive(uniffi::Record, Debug)]
+    pub struct Loadout {
+        weapon: Weapon,
+        #[uniffi(name = "count_renamed")]
+        count: u8,
+        #[uniffi(skip)]
+        uses: u32,
+    }
+
+    #[uniffi::export]
+    pub const SEPARATOR: char = 'é';
+
+    #[uniffi::export]
+    pub const DEFAULT_WEAPON: Weapon = Weapon::Scissors;
+
+    /// The default loadout
+    #[uniffi::export]
+    pub const DEFAULT_LOADOUT: Loadout = Loadout {
+        count: 2,
+        weapon: Weapon::Rock,
+        uses: 0,
+    };
+
+    fn weapon_type() -> Type {
+        Type::Enum {
+            module_path: "uniffi_fixture_metadata::tests::weapon".into(),
+            name: "WeaponRenamed".into(),
+        }
+    }
+
+    fn loadout_type() -> Type {
+        Type::Record {
+            module_path: "uniffi_fixture_metadata::tests::test_constants".into(),
+            name: "Loadout".into(),
+        }
+    }
+
+    #[test]
+    fn test_constant_metadata() {
+        check_metadata(
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_CONSTANT_SEPARATOR,
+            ConstantMetadata {
+                module_path: "uniffi_fixture_metadata::tests::test_constants".into(),
+                name: "SEPARATOR".into(),
+                ty: Type::Char,
+                value: LiteralMetadata::Char('é'),
+                docstring: None,
+            },
+        );
+        // The proc-macros only see the Rust names and the source order, including skipped fields
+        check_metadata(
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_CONSTANT_DEFAULT_WEAPON,
+            ConstantMetadata {
+                module_path: "uniffi_fixture_metadata::tests::test_constants".into(),
+                name: "DEFAULT_WEAPON".into(),
+                ty: weapon_type(),
+                value: LiteralMetadata::Enum("Scissors".into(), weapon_type()),
+                docstring: None,
+            },
+        );
+        check_metadata(
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_CONSTANT_DEFAULT_LOADOUT,
+            ConstantMetadata {
+                module_path: "uniffi_fixture_metadata::tests::test_constants".into(),
+                name: "DEFAULT_LOADOUT".into(),
+                ty: loadout_type(),
+                value: LiteralMetadata::Record {
+                    fields: vec![
+                        FieldLiteralMetadata {
+                            name: "count".into(),
+                            ty: Type::UInt8,
+                            value: LiteralMetadata::UInt(2, Radix::Decimal, Type::UInt8),
+                        },
+                        FieldLiteralMetadata {
+                            name: "weapon".into(),
+                            ty: weapon_type(),
+                            value: LiteralMetadata::Enum("Rock".into(), weapon_type()),
+                        },
+                        FieldLiteralMetadata {
+                            name: "uses".into(),
+                            ty: Type::UInt32,
+                            value: LiteralMetadata::UInt(0, Radix::Decimal, Type::UInt32),
+                        },
+                    ],
+                    ty: loadout_type(),
+                },
+                docstring: Some("The default loadout".into()),
+            },
+        );
+    }
+
+    #[test]
+    fn test_resolve_constant_literals() {
+        let mut items = [
+            &weapon::UNIFFI_META_UNIFFI_FIXTURE_METADATA_ENUM_WEAPONRENAMED[..],
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_RECORD_LOADOUT,
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_CONSTANT_DEFAULT_WEAPON,
+            &UNIFFI_META_UNIFFI_FIXTURE_METADATA_CONSTANT_DEFAULT_LOADOUT,
+        ]
+        .map(|buf| read_metadata(buf).unwrap());
+        resolve_constant_literals(&mut items).unwrap();
+
+        let Metadata::Constant(default_weapon) = &items[2] else {
+            panic!("Unexpected item: {:?}", items[2]);
+        };
+        assert_eq!(
+            default_weapon.value,
+            LiteralMetadata::Enum("ScissorsRenamed".into(), weapon_type())
+        );
+        let Metadata::Constant(default_loadout) = &items[3] else {
+            panic!("Unexpected item: {:?}", items[3]);
+        };
+        let LiteralMetadata::Record { fields, .. } = &default_loadout.value else {
+            panic!("Unexpected literal: {:?}", default_loadout.value);
+        };
+        // Skipped fields are dropped
+        let names: Vec<_> = fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["weapon", "count_renamed"]);
+    }
+}
@@ -0,0 +1,30 @@
+uniffi::setup_scaffolding!();
+
+fn main() { /* empty main required by `trybuild` */}
+
+#[derive(uniffi::Object)]
+pub struct Object {
+    name: String,
+}
+
+#[uniffi::export]
+impl Object {
+    // Borrowed values can only be returned from methods that take `&self` or `&mut self`
+    pub fn name_arc(self: std::sync::Arc<Self>) -> &'static str {
+        "name"
+    }
+}
+
+#[uniffi::export]
+impl Object {
+    // Async methods can't return borrowed values
+    pub async fn name_async(&self) -> &str {
+        &self.name
+    }
+}
+
+// Functions can't return borrowed values
+#[uniffi::export]
+pub fn static_name() -> &'static str {
+    "name"
+}
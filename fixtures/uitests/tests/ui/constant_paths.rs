@@ -0,0 +1,35 @@
+fn main() {} /* empty main required by `trybuild` */
+
+#[uniffi::export]
+pub const MAX_U32: u32 = u32::MAX;
+
+#[uniffi::export]
+pub const PI: Option<f64> = Some(std::f64::consts::PI);
+
+#[uniffi::export]
+pub const MAX_U128: u128 = u128::MAX;
+
+#[derive(uniffi::Record)]
+pub struct Limits {
+    pub max_items: u32,
+    pub level: Level,
+}
+
+// Record fields don't have a declared type, so the path is checked instead
+#[uniffi::export]
+pub const LIMITS: Limits = Limits {
+    max_items: u32::MAX,
+    level: Level::Low,
+};
+
+// Enum variants are still allowed
+#[derive(uniffi::Enum)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[uniffi::export]
+pub const DEFAULT_LEVEL: Level = Level::High;
+
+uniffi_macros::setup_scaffolding!();
@@ -0,0 +1,26 @@
+fn main() {} /* empty main required by `trybuild` */
+
+#[derive(uniffi::Enum)]
+pub enum Level {
+    Low,
+    High,
+}
+
+#[derive(uniffi::Record)]
+pub struct Limits {
+    pub max_items: u32,
+    pub level: Level,
+}
+
+impl Limits {
+    pub const DEFAULT_MAX: u32 = 10;
+}
+
+// Paths to other constants can't be detected by the macro, this fails when the metadata is evaluated
+#[uniffi::export]
+pub const LIMITS: Limits = Limits {
+    max_items: Limits::DEFAULT_MAX,
+    level: Level::Low,
+};
+
+uniffi_macros::setup_scaffolding!();
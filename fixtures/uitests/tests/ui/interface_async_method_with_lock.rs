@@ -0,0 +1,17 @@
+uniffi::setup_scaffolding!();
+
+fn main() { /* empty main required by `trybuild` */}
+
+#[derive(uniffi::Object)]
+#[uniffi(mutex)]
+pub struct Counter {
+    value: u32,
+}
+
+#[uniffi::export]
+impl Counter {
+    // This will fail to compile, because the lock can't be held across `.await` points.
+    pub async fn get_value(&self) -> u32 {
+        self.value
+    }
+}
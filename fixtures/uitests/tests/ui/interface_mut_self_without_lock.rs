@@ -0,0 +1,19 @@
+uniffi::setup_scaffolding!();
+
+fn main() { /* empty main required by `trybuild` */}
+
+#[derive(uniffi::Object)]
+pub struct Counter {
+    value: u32,
+}
+
+#[uniffi::export]
+impl Counter {
+    // This will fail to compile, because `&mut self` methods require `#[uniffi(mutex)]` or
+    // `#[uniffi(rwlock)]`.
+    pub fn increment(&mut self) -> u32 {
+        self.value += 1;
+        self.value
+    }
+}
+
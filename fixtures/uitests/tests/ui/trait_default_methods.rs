@@ -1,6 +1,6 @@
-#[uniffi::export]
+#[uniffi::export(callback_interface)]
 pub trait Trait: Send + Sync {
-    // a method with a default impl causes problems (#2597 etc)
+    // callback interfaces are always implemented by the foreign side
     fn default(&self) -> String {
         unreachable!()
     }
@@ -6,7 +6,9 @@ use camino::Utf8PathBuf;
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::fmt;
 use uniffi_bindgen::{
-    bindings::{generate, python, GenerateOptions, TargetLanguage},
+    bindings::{
+        csharp, generate, go, kotlin, python, ruby, swift, GenerateOptions, TargetLanguage,
+    },
     pipeline::initial,
     GlobalConfig,
 };
@@ -19,6 +21,9 @@ enum TargetLanguageArg {
     Swift,
     Python,
     Ruby,
+    #[value(name = "csharp")]
+    CSharp,
+    Go,
 }
 
 impl fmt::Display for TargetLanguageArg {
@@ -28,6 +33,8 @@ impl fmt::Display for TargetLanguageArg {
             Self::Swift => write!(f, "swift"),
             Self::Python => write!(f, "python"),
             Self::Ruby => write!(f, "ruby"),
+            Self::CSharp => write!(f, "csharp"),
+            Self::Go => write!(f, "go"),
         }
     }
 }
@@ -39,6 +46,8 @@ impl From<TargetLanguageArg> for TargetLanguage {
             TargetLanguageArg::Swift => Self::Swift,
             TargetLanguageArg::Python => Self::Python,
             TargetLanguageArg::Ruby => Self::Ruby,
+            TargetLanguageArg::CSharp => Self::CSharp,
+            TargetLanguageArg::Go => Self::Go,
         }
     }
 }
@@ -223,8 +232,12 @@ pub fn run_main() -> anyhow::Result<()> {
                 filter_name: args.filter_name,
             };
             match args.language {
+                TargetLanguageArg::Kotlin => kotlin::pipeline().print_passes(initial_root, opts)?,
                 TargetLanguageArg::Python => python::pipeline().print_passes(initial_root, opts)?,
-                language => unimplemented!("{language} does not use the bindings IR pipeline yet"),
+                TargetLanguageArg::Swift => swift::pipeline().print_passes(initial_root, opts)?,
+                TargetLanguageArg::Ruby => ruby::pipeline().print_passes(initial_root, opts)?,
+                TargetLanguageArg::CSharp => csharp::pipeline().print_passes(initial_root, opts)?,
+                TargetLanguageArg::Go => go::pipeline().print_passes(initial_root, opts)?,
             };
         }
     };
@@ -0,0 +1,35 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// Template filters for Askama
+///
+/// In general, prefer adding fields using a pipeline pass to writing filters.
+/// That's allows devs to use the `pipeline` command to follow what's going on.
+///
+/// We currently only use filter functions for display logic that depends on where the
+/// template renders something, like the indentation for the `docstring` filter.
+use askama::Result;
+
+/// Get the idiomatic C# rendering of docstring, as an XML doc comment
+#[askama::filter_fn]
+pub fn docstring<S: AsRef<str>>(
+    docstring: S,
+    _: &dyn askama::Values,
+    spaces: &i32,
+) -> Result<String> {
+    let escaped = textwrap::dedent(docstring.as_ref())
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    let middle = escaped
+        .trim_end()
+        .lines()
+        .map(|line| format!("/// {line}").trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let wrapped = format!("/// <summary>\n{middle}\n/// </summary>");
+
+    let spaces = usize::try_from(*spaces).unwrap_or_default();
+    Ok(textwrap::indent(&wrapped, &" ".repeat(spaces)))
+}
@@ -0,0 +1,57 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::process::Command;
+
+use crate::{bindings::GenerateOptions, BindgenLoader};
+use anyhow::{bail, Context, Result};
+use askama::Template;
+use fs_err as fs;
+
+pub mod filters;
+mod pipeline;
+pub use pipeline::{pipeline, Root};
+
+#[cfg(feature = "bindgen-tests")]
+pub mod test;
+
+/// Generate C# bindings
+pub fn generate(loader: &BindgenLoader, options: GenerateOptions) -> Result<()> {
+    let metadata = loader.load_metadata(&options.source)?;
+    if let Some(crate_filter) = &options.crate_filter {
+        if !metadata.contains_key(crate_filter) {
+            bail!("No UniFFI metadata found for crate {crate_filter}");
+        }
+    }
+    let root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    let cs_root = pipeline().execute(root)?;
+
+    for module in cs_root.modules.values() {
+        if let Some(crate_filter) = &options.crate_filter {
+            if &module.crate_name != crate_filter {
+                continue;
+            }
+        }
+        let cs_file = options.out_dir.join(format!("{}.cs", module.name));
+        fs::write(
+            &cs_file,
+            module.render().context("failed to render C# bindings")?,
+        )?;
+
+        if options.format {
+            if let Err(e) = Command::new("dotnet")
+                .arg("csharpier")
+                .arg("format")
+                .arg(&cs_file)
+                .output()
+            {
+                println!(
+                    "Warning: Unable to auto-format {} using csharpier: {e:?}",
+                    cs_file.file_name().unwrap(),
+                )
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// This module contains the serde structs to parse the `uniffi.toml` config.
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use uniffi_pipeline::Node;
+
+// These just exist so we can parse the entire `uniffi.toml` file, the codegen only uses the
+// `CSharpConfig` part.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub bindings: BindingsConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BindingsConfig {
+    #[serde(default)]
+    pub csharp: CSharpConfig,
+}
+
+/// Config options for the caller to customize the generated C#.
+///
+/// `exclude` and `rename` are also read from this table, but those are handled by the general
+/// pipeline.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CSharpConfig {
+    pub(super) cdylib_name: Option<String>,
+    /// C# namespace for the generated code, defaults to `uniffi.{namespace}`
+    pub(super) namespace: Option<String>,
+    #[serde(default)]
+    pub custom_types: IndexMap<String, CustomTypeConfig>,
+    /// Maps UniFFI namespaces to the C# namespaces their bindings were generated into.
+    #[serde(default)]
+    pub external_namespaces: IndexMap<String, String>,
+}
+
+#[derive(Debug, Clone, Node, Default, Deserialize)]
+#[serde(default)]
+pub struct CustomTypeConfig {
+    /// `using` directives that the custom type needs
+    pub imports: Option<Vec<String>>,
+    pub type_name: Option<String>,
+    pub into_custom: String, // b/w compat alias for lift
+    pub lift: String,
+    pub from_custom: String, // b/w compat alias for lower
+    pub lower: String,
+}
+
+impl CSharpConfig {
+    pub fn from_uniffi_toml(toml: &str) -> Result<Self> {
+        let root: Config = toml::from_str(toml)?;
+        Ok(root.bindings.csharp)
+    }
+}
+
+// functions replace literal "{}" in strings with a specified value.
+impl CustomTypeConfig {
+    /// Produce a C# expression that lifts a builtin value `nm` into the custom type.
+    pub fn lift(&self, name: &str) -> String {
+        let converter = if self.lift.is_empty() {
+            &self.into_custom
+        } else {
+            &self.lift
+        };
+        converter.replace("{}", name)
+    }
+
+    /// Produce a C# expression that lowers a custom value `nm` into its builtin.
+    pub fn lower(&self, name: &str) -> String {
+        let converter = if self.lower.is_empty() {
+            &self.from_custom
+        } else {
+            &self.lower
+        };
+        converter.replace("{}", name)
+    }
+}
@@ -0,0 +1,158 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub cdylib: Option<String>,
+    pub current_config: Option<CSharpConfig>,
+    pub module_namespace: Option<String>,
+    /// FFI function types for the current namespace, keyed by name
+    ///
+    /// These are needed to render the unmanaged function pointer types.
+    pub ffi_function_types: HashMap<String, general::FfiFunctionType>,
+    /// Maps FFI function type names to the VTable struct fields that store them
+    pub vtable_field_names: HashMap<String, String>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.cdylib = root.cdylib.clone();
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => CSharpConfig::from_uniffi_toml(toml)?,
+            None => CSharpConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        self.ffi_function_types = namespace
+            .ffi_definitions
+            .iter()
+            .filter_map(|def| match def {
+                general::FfiDefinition::FunctionType(func_type) => {
+                    Some((func_type.name.0.clone(), func_type.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        self.vtable_field_names.clear();
+        for def in namespace.ffi_definitions.iter() {
+            if let general::FfiDefinition::Struct(st) = def {
+                for field in st.fields.iter() {
+                    if let FfiType::Function(name) = &field.ty {
+                        self.vtable_field_names
+                            .insert(name.0.clone(), field.name.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&CSharpConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    /// Name of the library to pass to `DllImport`
+    pub fn cdylib(&self) -> Result<String> {
+        Ok(match &self.config()?.cdylib_name {
+            Some(cdylib) => cdylib.clone(),
+            None => match &self.cdylib {
+                Some(cdylib) => cdylib.clone(),
+                None => format!("uniffi_{}", self.module_namespace()?),
+            },
+        })
+    }
+
+    /// C# namespace for the current module
+    pub fn cs_namespace(&self) -> Result<String> {
+        Ok(match &self.config()?.namespace {
+            Some(namespace) => namespace.clone(),
+            None => format!("uniffi.{}", self.module_namespace()?),
+        })
+    }
+
+    /// C# namespace for types from another UniFFI namespace
+    ///
+    /// Returns `None` for types defined in the current namespace.
+    pub fn external_cs_namespace(&self, namespace: &str) -> Result<Option<String>> {
+        if self.module_namespace()? == namespace {
+            return Ok(None);
+        }
+        Ok(Some(
+            match self.config()?.external_namespaces.get(namespace) {
+                Some(cs_namespace) => cs_namespace.clone(),
+                None => format!("uniffi.{namespace}"),
+            },
+        ))
+    }
+
+    pub fn ffi_function_type(&self, name: &str) -> Result<&general::FfiFunctionType> {
+        self.ffi_function_types
+            .get(name)
+            .ok_or_else(|| anyhow!("FFI function type not found: {name}"))
+    }
+
+    /// Config for a custom type
+    ///
+    /// Configs are keyed by the renamed type name, but fall back to the original name for configs
+    /// written against the Rust name.
+    pub fn custom_type_config(
+        &self,
+        name: &str,
+        orig_name: &str,
+    ) -> Result<Option<&CustomTypeConfig>> {
+        let custom_types = &self.config()?.custom_types;
+        Ok(custom_types
+            .get(name)
+            .or_else(|| custom_types.get(orig_name)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn context(toml: &str) -> Context {
+        Context {
+            current_config: Some(CSharpConfig::from_uniffi_toml(toml).unwrap()),
+            module_namespace: Some("todolist".to_string()),
+            ..Context::default()
+        }
+    }
+
+    #[test]
+    fn test_cs_namespace() {
+        assert_eq!(context("").cs_namespace().unwrap(), "uniffi.todolist");
+        let toml = r#"
+            [bindings.csharp]
+            namespace = "Example.TodoList"
+            external_namespaces = { other = "Example.Other" }
+        "#;
+        let context = context(toml);
+        assert_eq!(context.cs_namespace().unwrap(), "Example.TodoList");
+        assert_eq!(context.external_cs_namespace("todolist").unwrap(), None);
+        assert_eq!(
+            context.external_cs_namespace("other").unwrap().as_deref(),
+            Some("Example.Other")
+        );
+        assert_eq!(
+            context.external_cs_namespace("third").unwrap().as_deref(),
+            Some("uniffi.third")
+        );
+    }
+}
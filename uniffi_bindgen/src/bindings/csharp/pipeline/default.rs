@@ -0,0 +1,213 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Default values and literals
+//!
+//! C# only allows compile-time constants for optional parameters and they all need to come after
+//! the required parameters.  Defaults that can't be expressed that way are dropped, which means
+//! callers need to pass those arguments explicitly.
+
+use super::*;
+
+pub fn map_arguments(
+    arguments: Vec<general::Argument>,
+    context: &Context,
+) -> Result<Vec<Argument>> {
+    let defaults = trailing_defaults(arguments.iter().map(|a| a.default.as_ref()), context)?;
+    arguments
+        .into_iter()
+        .zip(defaults)
+        .map(|(arg, default)| {
+            Ok(Argument {
+                name: names::var_name(&arg.name),
+                is_borrowed_bytes: arg.is_borrowed_bytes(),
+                default,
+                ty: arg.ty.map_node(context)?,
+            })
+        })
+        .collect()
+}
+
+/// Map the fields of a record or enum variant
+///
+/// `class_name` is the class that the fields are defined in.  C# doesn't allow members with the
+/// same name as their enclosing type, so those fields get a `Value` suffix.
+pub fn map_fields(
+    fields: Vec<general::Field>,
+    class_name: &str,
+    context: &Context,
+) -> Result<Vec<Field>> {
+    let defaults = trailing_defaults(fields.iter().map(|f| f.default.as_ref()), context)?;
+    fields
+        .into_iter()
+        .zip(defaults)
+        .map(|(field, default)| {
+            let mut name = names::member_name(&field.name);
+            if name == class_name {
+                name.push_str("Value");
+            }
+            Ok(Field {
+                name,
+                var_name: names::var_name(&field.name),
+                default,
+                ty: field.ty.map_node(context)?,
+                docstring: field.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Render the defaults that C# can use
+///
+/// This walks the list backwards and stops at the first value without a constant default.
+fn trailing_defaults<'a>(
+    defaults: impl DoubleEndedIterator<Item = Option<&'a general::DefaultValue>>,
+    context: &Context,
+) -> Result<Vec<Option<String>>> {
+    let mut in_trailing_run = true;
+    let mut result = defaults
+        .rev()
+        .map(|default| {
+            let rendered = match default {
+                Some(default) if in_trailing_run => render_default(default, context)?,
+                _ => None,
+            };
+            in_trailing_run = rendered.is_some();
+            Ok(rendered)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    result.reverse();
+    Ok(result)
+}
+
+/// Render a default value as a C# constant
+///
+/// Returns `None` if the value can't be represented as a constant.
+pub fn render_default(
+    default: &general::DefaultValue,
+    context: &Context,
+) -> Result<Option<String>> {
+    match default {
+        general::DefaultValue::Default(type_node) => Ok(render_zero_value(&type_node.ty)),
+        general::DefaultValue::Literal(literal) => render_literal(literal, context),
+    }
+}
+
+/// Render the zero value for a type, used for `#[uniffi(default)]`
+fn render_zero_value(ty: &Type) -> Option<String> {
+    Some(match ty {
+        Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::Int32
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64 => "0".to_string(),
+        Type::Float32 => "0.0f".to_string(),
+        Type::Float64 => "0.0".to_string(),
+        Type::Boolean => "false".to_string(),
+        Type::String => "\"\"".to_string(),
+        Type::Optional { .. } => "null".to_string(),
+        Type::Box { inner_type } => render_zero_value(inner_type)?,
+        Type::Custom { builtin, .. } => render_zero_value(builtin)?,
+        _ => return None,
+    })
+}
+
+/// Render a literal as a C# constant
+///
+/// Returns `None` if the value can't be represented as a constant.
+pub fn render_literal(literal: &general::Literal, context: &Context) -> Result<Option<String>> {
+    Ok(Some(match literal {
+        general::Literal::Boolean(v) => format!("{v}"),
+        general::Literal::String(s) => string_literal(s),
+        general::Literal::Char(c) => format!("new Rune(0x{:X})", u32::from(*c)),
+        general::Literal::None => "null".to_string(),
+        general::Literal::Some { inner } => return render_default(inner, context),
+        general::Literal::Enum(variant, type_node) => match &type_node.ty {
+            Type::Enum { .. } => format!(
+                "{}.{}",
+                types::type_name(&type_node.ty, context)?,
+                names::member_name(variant)
+            ),
+            ty => bail!("Unexpected type in enum literal: {ty:?}"),
+        },
+        general::Literal::Int(i, radix, _) => int_literal(*i, i.unsigned_abs(), radix),
+        general::Literal::UInt(i, radix, _) => int_literal(*i, *i, radix),
+        general::Literal::Float(string, type_node) => match type_node.ty {
+            Type::Float32 => format!("{string}f"),
+            _ => string.clone(),
+        },
+        general::Literal::EmptySequence
+        | general::Literal::EmptyMap
+        | general::Literal::EmptySet
+        | general::Literal::Record { .. } => return Ok(None),
+    }))
+}
+
+/// Render a literal as a C# expression, this works for non-constant values too
+pub fn render_literal_expr(literal: &general::Literal, context: &Context) -> Result<String> {
+    if let Some(value) = render_literal(literal, context)? {
+        return Ok(value);
+    }
+    Ok(match literal {
+        general::Literal::EmptySequence
+        | general::Literal::EmptyMap
+        | general::Literal::EmptySet => "new()".to_string(),
+        general::Literal::Record { fields, ty } => {
+            let fields = fields
+                .iter()
+                .map(|f| render_literal_expr(&f.value, context))
+                .collect::<Result<Vec<_>>>()?;
+            format!(
+                "new {}({})",
+                types::type_name(&ty.ty, context)?,
+                fields.join(", ")
+            )
+        }
+        _ => bail!("Can't render literal: {literal:?}"),
+    })
+}
+
+fn int_literal(
+    value: impl std::fmt::Display + PartialOrd + Default,
+    abs: impl std::fmt::UpperHex,
+    radix: &Radix,
+) -> String {
+    // C# doesn't have octal literals, so those are rendered as decimal.
+    match radix {
+        Radix::Hexadecimal if value >= Default::default() => format!("0x{abs:X}"),
+        _ => value.to_string(),
+    }
+}
+
+fn string_literal(s: &str) -> String {
+    let mut result = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(string_literal("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+        assert_eq!(int_literal(255u64, 255u64, &Radix::Hexadecimal), "0xFF");
+        assert_eq!(int_literal(-1i64, 1u64, &Radix::Hexadecimal), "-1");
+        assert_eq!(int_literal(8u64, 8u64, &Radix::Octal), "8");
+    }
+}
@@ -0,0 +1,46 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_variants(variants: Vec<general::Variant>, context: &Context) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            // This works for both enum members and nested variant classes, since both use
+            // `UpperCamelCase`.
+            let name = names::member_name(&v.name);
+            Ok(Variant {
+                fields: default::map_fields(name_unnamed_fields(v.fields), &name, context)?,
+                name,
+                discr: discr_literal(&v.discr)?,
+                fields_kind: v.fields_kind,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Give tuple-style fields the names `V1`, `V2`, etc.
+fn name_unnamed_fields(fields: Vec<general::Field>) -> Vec<general::Field> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut field)| {
+            if field.name.is_empty() {
+                field.name = format!("v{}", i + 1);
+            }
+            field
+        })
+        .collect()
+}
+
+/// Render a discriminant literal
+fn discr_literal(discr: &general::Literal) -> Result<String> {
+    match discr {
+        general::Literal::UInt(v, _, _) => Ok(v.to_string()),
+        general::Literal::Int(v, _, _) => Ok(v.to_string()),
+        _ => bail!("Only integer discriminants are supported: {discr:?}"),
+    }
+}
@@ -0,0 +1,144 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::ToLowerCamelCase;
+
+use super::*;
+
+/// Get the C# type for an FFI type
+///
+/// Function pointers are passed around as `IntPtr`.  The generated code only needs the actual
+/// `delegate* unmanaged` types when it takes the address of a callback method or calls a
+/// function pointer that Rust passed to it.
+pub fn ffi_type_name(ffi_type: &FfiType) -> String {
+    match ffi_type {
+        FfiType::Int8 => "sbyte".to_string(),
+        FfiType::UInt8 => "byte".to_string(),
+        FfiType::Int16 => "short".to_string(),
+        FfiType::UInt16 => "ushort".to_string(),
+        FfiType::Int32 => "int".to_string(),
+        FfiType::UInt32 => "uint".to_string(),
+        FfiType::Int64 => "long".to_string(),
+        FfiType::UInt64 => "ulong".to_string(),
+        FfiType::Float32 => "float".to_string(),
+        FfiType::Float64 => "double".to_string(),
+        FfiType::Handle(_) => "ulong".to_string(),
+        FfiType::RustBuffer(_) => "UniffiRustBuffer".to_string(),
+        FfiType::RustCallStatus => "UniffiRustCallStatus".to_string(),
+        FfiType::ForeignBytes => "UniffiForeignBytes".to_string(),
+        FfiType::Function(_) => "IntPtr".to_string(),
+        FfiType::Struct(name) => names::ffi_struct_name(&name.0),
+        FfiType::Reference(inner) | FfiType::MutReference(inner) => {
+            format!("{}*", ffi_type_name(inner))
+        }
+        FfiType::VoidPointer => "void*".to_string(),
+    }
+}
+
+/// Get the `delegate* unmanaged` type for an FFI function type
+pub fn fn_pointer_type(name: &str, context: &Context) -> Result<String> {
+    let func_type = context.ffi_function_type(name)?;
+    let mut types: Vec<String> = func_type
+        .arguments
+        .iter()
+        .map(|arg| ffi_type_name(&arg.ty))
+        .collect();
+    if func_type.has_rust_call_status_arg {
+        types.push("UniffiRustCallStatus*".to_string());
+    }
+    types.push(match &func_type.return_type.ty {
+        Some(ty) => ffi_type_name(ty),
+        None => "void".to_string(),
+    });
+    Ok(format!("delegate* unmanaged[Cdecl]<{}>", types.join(", ")))
+}
+
+/// Get the name of the struct for a VTable
+pub fn vtable_struct_name(vtable: &general::VTable) -> Result<String> {
+    match &vtable.struct_type {
+        FfiType::Struct(name) => Ok(names::ffi_struct_name(&name.0)),
+        ffi_type => bail!("Invalid VTable struct type: {ffi_type:?}"),
+    }
+}
+
+fn vtable_method_function_type<'a>(
+    vtable_method: &general::VTableMethod,
+    context: &'a Context,
+) -> Result<&'a general::FfiFunctionType> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context.ffi_function_type(&name.0)
+}
+
+/// Lookup the name of the VTable struct field for a VTable method
+pub fn vtable_method_field_name(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<String> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context
+        .vtable_field_names
+        .get(&name.0)
+        .map(|field_name| names::escape(field_name))
+        .ok_or_else(|| anyhow!("VTable field not found: {}", name.0))
+}
+
+pub fn vtable_method_fn_pointer_type(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<String> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    fn_pointer_type(&name.0, context)
+}
+
+/// Render the parameter list for the method that implements a VTable method
+///
+/// The first parameter is the handle and the ones after that are the method arguments, which get
+/// the same names as the C# arguments so that the template can lift them.  The types come from
+/// the FFI function type, which ensures they match `fn_pointer_type`.
+pub fn vtable_method_params(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<String> {
+    let func_type = vtable_method_function_type(vtable_method, context)?;
+    let arg_count = vtable_method.callable.arguments.len();
+    let mut params = func_type
+        .arguments
+        .iter()
+        .enumerate()
+        .map(|(i, arg)| {
+            let name = if i == 0 {
+                "uniffiHandle".to_string()
+            } else if i <= arg_count {
+                names::var_name(&vtable_method.callable.arguments[i - 1].name)
+            } else {
+                arg.name.to_lower_camel_case()
+            };
+            format!("{} {name}", ffi_type_name(&arg.ty))
+        })
+        .collect::<Vec<_>>();
+    if func_type.has_rust_call_status_arg {
+        params.push("UniffiRustCallStatus* uniffiCallStatus".to_string());
+    }
+    Ok(params.join(", "))
+}
+
+/// Get the function pointer type of the callback that completes an async VTable method
+pub fn future_callback_fn_pointer_type(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<Option<String>> {
+    match &vtable_method.callable.async_data {
+        Some(async_data) => Ok(Some(fn_pointer_type(
+            &async_data.ffi_foreign_future_complete.0,
+            context,
+        )?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,85 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+use heck::ToUpperCamelCase;
+
+use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?.clone();
+
+    let imports = config
+        .custom_types
+        .values()
+        .filter_map(|custom_type| custom_type.imports.as_ref())
+        .flatten()
+        .cloned()
+        .collect::<IndexSet<_>>()
+        .into_iter()
+        .collect();
+
+    Ok(Module {
+        cs_namespace: context.cs_namespace()?,
+        class_name: format!("{}Methods", namespace.name.to_upper_camel_case()),
+        cdylib_name: context.cdylib()?,
+        imports,
+        name: namespace.name.clone(),
+        crate_name: namespace.crate_name.clone(),
+        has_async_fns: namespace.has_descendant(|callable: &general::Callable| {
+            callable.is_async()
+                && !matches!(callable.kind, general::CallableKind::VTableMethod { .. })
+        }),
+        has_callback_definitions: namespace.has_descendant(|_: &general::CallbackInterface| true)
+            || namespace
+                .has_descendant(|int: &general::Interface| int.imp.has_callback_interface()),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        docstring: namespace.docstring,
+        functions: namespace.functions.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        ffi_definitions: dedup_ffi_definitions(namespace.ffi_definitions).map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        correct_contract_version: namespace.correct_contract_version,
+    })
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+/// Remove FFI definitions with duplicate names
+///
+/// Several FFI types share the same `rust_future` functions, but each function can only be
+/// declared once.
+fn dedup_ffi_definitions(
+    ffi_definitions: IndexSet<general::FfiDefinition>,
+) -> IndexSet<general::FfiDefinition> {
+    let mut seen = HashSet::new();
+    ffi_definitions
+        .into_iter()
+        .filter(|def| {
+            let name = match def {
+                general::FfiDefinition::RustFunction(func) => func.name.0.clone(),
+                general::FfiDefinition::FunctionType(func_type) => func_type.name.0.clone(),
+                general::FfiDefinition::Struct(st) => st.name.0.clone(),
+            };
+            seen.insert(name)
+        })
+        .collect()
+}
@@ -0,0 +1,138 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+
+// https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/
+const RESERVED_WORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED_WORDS.contains(&word)
+}
+
+/// Escape reserved words with a `@` prefix
+pub fn escape(nm: &str) -> String {
+    if is_reserved_word(nm) {
+        format!("@{nm}")
+    } else {
+        nm.to_string()
+    }
+}
+
+/// Get the idiomatic C# rendering of a class name (for enums, records, errors, etc).
+pub fn class_name(nm: &str) -> String {
+    escape(&nm.to_upper_camel_case())
+}
+
+/// Get the idiomatic C# rendering of a method or property name.
+pub fn member_name(nm: &str) -> String {
+    escape(&nm.to_upper_camel_case())
+}
+
+/// Get the idiomatic C# rendering of a variable or parameter name.
+pub fn var_name(nm: &str) -> String {
+    escape(&nm.to_lower_camel_case())
+}
+
+/// Get the C# interface name for an object or callback interface
+pub fn interface_name(nm: &str) -> String {
+    format!("I{}", nm.to_upper_camel_case())
+}
+
+/// Get the C# name of an FFI struct
+pub fn ffi_struct_name(nm: &str) -> String {
+    format!("Uniffi{nm}")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(var_name("string"), "@string");
+        assert_eq!(var_name("my_value"), "myValue");
+        assert_eq!(member_name("get_value"), "GetValue");
+        assert_eq!(class_name("my_record"), "MyRecord");
+        assert_eq!(interface_name("Getters"), "IGetters");
+    }
+}
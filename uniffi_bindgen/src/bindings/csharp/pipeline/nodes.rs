@@ -0,0 +1,660 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::csharp::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(general::FfiFunctionTypeName);
+use_prev_node!(general::FfiStructName);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(general::remove_unsupported_items(
+        self.namespaces,
+        "C#",
+        types::is_supported,
+    ).map_node(context)?)]
+    pub modules: IndexMap<String, Module>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "cs", escape = "none", path = "wrapper.cs")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    pub crate_name: String,
+    /// C# namespace that the bindings are generated into
+    pub cs_namespace: String,
+    /// Static class that holds the top-level functions and constants
+    pub class_name: String,
+    /// Name of the library to pass to `DllImport`
+    pub cdylib_name: String,
+    /// `using` directives from the custom type configs
+    pub imports: Vec<String>,
+    pub has_async_fns: bool,
+    pub has_callback_definitions: bool,
+    pub has_async_callback_method: bool,
+    pub docstring: Option<String>,
+    pub functions: Vec<Function>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    pub correct_contract_version: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Array(ArrayType),
+    Map(MapType),
+    Set(SetType),
+    Tuple(TupleType),
+    Stream(StreamType),
+    Iterator(IteratorType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::member_name(&self.name))]
+    pub name: String,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    #[map_node(default::map_arguments(self.arguments, context)?)]
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method { self_type: TypeNode },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    VTableMethod {
+        self_type: TypeNode,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    // FFI types for async Rust functions
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    // FFI types for async foreign functions
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    #[map_node(names::ffi_struct_name(&self.ffi_foreign_future_result.0))]
+    pub ffi_foreign_future_result: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ReturnType))]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ThrowsType))]
+#[map_node(types::map_throws_type)]
+pub struct ThrowsType {
+    pub ty: Option<TypeNode>,
+    /// Exception class that's thrown for this type
+    pub error: Option<ErrorClass>,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct ErrorClass {
+    /// Exception class, this is what callback interface methods catch
+    pub name: String,
+    /// `Func<UniffiRustBuffer, Exception>` expression that lifts the error buffer
+    pub lift_expr: String,
+    /// Expression that lowers a caught exception, `e`, into a `RustBuffer`
+    pub lower_expr: String,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Argument {
+    pub name: String,
+    /// Default value, rendered as a C# constant
+    ///
+    /// This is only set for trailing arguments with defaults that C# can represent as a
+    /// compile-time constant.
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    /// Is this a `&[u8]` argument that's passed to Rust as `ForeignBytes`?
+    pub is_borrowed_bytes: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(names::member_name(&self.name))]
+    pub name: String,
+    #[map_node(default::render_literal_expr(&self.value, context)?)]
+    pub value: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub fields_kind: FieldsKind,
+    #[map_node(default::map_fields(self.fields, &names::class_name(&self.name), context)?)]
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Field {
+    /// Property name
+    pub name: String,
+    /// Name to use for constructor parameters
+    pub var_name: String,
+    /// Default value, see `Argument::default`
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+pub struct Enum {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    #[map_node(enums::map_variants(self.variants, context)?)]
+    pub variants: Vec<Variant>,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    pub discr_type: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    /// Enum member name for flat enums, nested class name otherwise
+    pub name: String,
+    /// The discriminant, rendered as a C# literal
+    pub discr: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    /// Name of the C# interface that the class implements
+    #[map_node(names::interface_name(&self.name))]
+    pub interface_name: String,
+    pub constructors: Vec<Constructor>,
+    pub docstring: Option<String>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+impl Interface {
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn alternate_constructors(&self) -> Vec<&Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+            .collect()
+    }
+
+    pub fn has_callback_interface(&self) -> bool {
+        self.imp.has_callback_interface()
+    }
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::interface_name(&self.name))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    #[map_node(ffi_types::vtable_struct_name(&self)?)]
+    pub struct_name: String,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    #[map_node(ffi_types::fn_pointer_type(&self.clone_fn_type.0, context)?)]
+    pub clone_fn_pointer_type: String,
+    #[map_node(ffi_types::fn_pointer_type(&self.free_fn_type.0, context)?)]
+    pub free_fn_pointer_type: String,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// Name of the VTable struct field for this method
+    #[map_node(ffi_types::vtable_method_field_name(&self, context)?)]
+    pub ffi_field_name: String,
+    /// Unmanaged function pointer type for the method
+    #[map_node(ffi_types::vtable_method_fn_pointer_type(&self, context)?)]
+    pub fn_pointer_type: String,
+    /// Parameter list for the `UnmanagedCallersOnly` method that implements this
+    #[map_node(ffi_types::vtable_method_params(&self, context)?)]
+    pub ffi_params: String,
+    /// Unmanaged function pointer type for the future callback of async methods
+    #[map_node(ffi_types::future_callback_fn_pointer_type(&self, context)?)]
+    pub future_callback_type: Option<String>,
+    pub callable: Callable,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(context.custom_type_config(&self.name, &self.orig_name)?.cloned())]
+    pub config: Option<CustomTypeConfig>,
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::IteratorType))]
+pub struct IteratorType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: Option<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    pub name: String,
+    pub namespace: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    /// C# type
+    #[map_node(types::type_name(&self.ty, context)?)]
+    pub type_name: String,
+    /// Static class that converts values of this type
+    #[map_node(types::ffi_converter_name(&self, context)?)]
+    pub ffi_converter_name: String,
+    /// Expression that lifts a value, see `TypeNode::lift`
+    #[map_node(types::lift(types::NAME_PLACEHOLDER, &self, context)?)]
+    pub lift_expr: String,
+    /// Expression that lowers a value, see `TypeNode::lower`
+    #[map_node(types::lower(types::NAME_PLACEHOLDER, &self, context)?)]
+    pub lower_expr: String,
+    pub canonical_name: String,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// C# type used in the P/Invoke declarations and FFI structs
+    #[map_node(ffi_types::ffi_type_name(&self))]
+    pub type_name: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    pub name: RustFfiFunctionName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    #[map_node(names::ffi_struct_name(&self.name.0))]
+    pub name: String,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    #[map_node(names::escape(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    #[map_node(names::escape(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+
+    pub fn is_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { .. })
+    }
+
+    /// Type of `self` for methods
+    pub fn self_type(&self) -> Option<&TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type } | CallableKind::VTableMethod { self_type, .. } => {
+                Some(self_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Expression that lowers `self` for methods
+    ///
+    /// Objects pass a cloned handle, records and enums lower `self_expr`.
+    pub fn ffi_self_arg(&self, self_expr: &str) -> Option<String> {
+        self.self_type().map(|self_type| match &self_type.ty {
+            Type::Interface { .. } => "UniffiCloneHandle()".to_string(),
+            _ => self_type.lower(self_expr),
+        })
+    }
+
+    /// C# return type for the method declaration
+    pub fn return_type_decl(&self) -> String {
+        match (&self.return_type.ty, self.is_async()) {
+            (Some(ty), false) => ty.type_name.clone(),
+            (None, false) => "void".to_string(),
+            (Some(ty), true) => format!("Task<{}>", ty.type_name),
+            (None, true) => "Task".to_string(),
+        }
+    }
+
+    /// C# type that the Rust FFI function returns, for sync functions this is what
+    /// `RustFutureComplete` returns.
+    pub fn ffi_return_type_name(&self) -> String {
+        match &self.return_type.ty {
+            Some(ty) => ty.ffi_type.type_name.clone(),
+            None => "void".to_string(),
+        }
+    }
+
+    pub fn borrowed_bytes_arguments(&self) -> Vec<&Argument> {
+        self.arguments
+            .iter()
+            .filter(|arg| arg.is_borrowed_bytes)
+            .collect()
+    }
+}
+
+impl Argument {
+    /// Variable that holds the `UniffiPinnedBytes` for borrowed bytes arguments
+    pub fn pinned_name(&self) -> String {
+        format!("{}Pinned", self.name.trim_start_matches('@'))
+    }
+
+    /// Expression that lowers this argument when calling a Rust function
+    pub fn lower_arg(&self) -> String {
+        if self.is_borrowed_bytes {
+            format!("{}.ForeignBytes", self.pinned_name())
+        } else {
+            self.ty.lower(&self.name)
+        }
+    }
+}
+
+impl TypeNode {
+    /// Expression that lowers `nm` so that it can be passed across the FFI
+    pub fn lower(&self, nm: &str) -> String {
+        self.lower_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+
+    /// Expression that lifts `nm` after it was returned from the FFI
+    pub fn lift(&self, nm: &str) -> String {
+        self.lift_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+
+    /// Exception message for error variants with fields, this lists the field values
+    pub fn error_message_expr(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|f| format!("{}={{{}}}", f.var_name.trim_start_matches('@'), f.var_name))
+            .collect::<Vec<_>>();
+        format!("$\"{}\"", fields.join(", "))
+    }
+}
@@ -0,0 +1,215 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Placeholder for the value in the lift/lower expressions stored in `TypeNode`
+pub const NAME_PLACEHOLDER: &str = "{}";
+
+/// Can the C# bindings render this type?
+///
+/// Items that use other types are skipped, see `general::remove_unsupported_items`.
+pub fn is_supported(_namespace: &str, ty: &Type) -> bool {
+    !matches!(
+        ty,
+        Type::Stream { .. } | Type::Iterator { .. } | Type::Closure { .. }
+    )
+}
+
+/// Get the C# type for a type
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "bool".to_string(),
+        Type::Int8 => "sbyte".to_string(),
+        Type::UInt8 => "byte".to_string(),
+        Type::Int16 => "short".to_string(),
+        Type::UInt16 => "ushort".to_string(),
+        Type::Int32 => "int".to_string(),
+        Type::UInt32 => "uint".to_string(),
+        Type::Int64 => "long".to_string(),
+        Type::UInt64 => "ulong".to_string(),
+        Type::Int128 => "Int128".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Float32 => "float".to_string(),
+        Type::Float64 => "double".to_string(),
+        Type::Char => "Rune".to_string(),
+        Type::String => "string".to_string(),
+        Type::Bytes => "byte[]".to_string(),
+        Type::Timestamp => "DateTime".to_string(),
+        Type::Duration => "TimeSpan".to_string(),
+        Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        } => qualified_name(namespace, &names::class_name(name), context)?,
+        Type::Interface {
+            namespace,
+            name,
+            imp,
+            ..
+        } => {
+            if imp.is_trait_interface() {
+                qualified_name(namespace, &names::interface_name(name), context)?
+            } else {
+                qualified_name(namespace, &names::class_name(name), context)?
+            }
+        }
+        Type::CallbackInterface {
+            namespace, name, ..
+        } => qualified_name(namespace, &names::interface_name(name), context)?,
+        Type::Custom {
+            name,
+            orig_name,
+            builtin,
+            ..
+        } => match context.custom_type_config(name, orig_name)? {
+            Some(CustomTypeConfig {
+                type_name: Some(type_name),
+                ..
+            }) => type_name.clone(),
+            _ => type_name(builtin, context)?,
+        },
+        Type::Optional { inner_type } => format!("{}?", type_name(inner_type, context)?),
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => {
+            format!("List<{}>", type_name(inner_type, context)?)
+        }
+        // `Dictionary` doesn't guarantee the iteration order, but it does preserve the insertion
+        // order as long as there are no removals, which is enough for lifted values.
+        Type::Map {
+            key_type,
+            value_type,
+            ..
+        } => format!(
+            "Dictionary<{}, {}>",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        // There's no builtin ordered set, so use a list to preserve the order.
+        Type::Set {
+            inner_type,
+            ordered: true,
+        } => format!("List<{}>", type_name(inner_type, context)?),
+        Type::Set { inner_type, .. } => {
+            format!("HashSet<{}>", type_name(inner_type, context)?)
+        }
+        Type::Box { inner_type } => type_name(inner_type, context)?,
+        Type::Tuple { inner_types } => match inner_types.len() {
+            0 => "ValueTuple".to_string(),
+            1 => format!("ValueTuple<{}>", type_name(&inner_types[0], context)?),
+            _ => format!(
+                "({})",
+                inner_types
+                    .iter()
+                    .map(|ty| type_name(ty, context))
+                    .collect::<Result<Vec<_>>>()?
+                    .join(", ")
+            ),
+        },
+        Type::Stream { .. } | Type::Iterator { .. } | Type::Closure { .. } => {
+            bail!("{ty:?} is not yet supported in the C# bindings")
+        }
+    })
+}
+
+/// Prefix a name with the C# namespace for types from other UniFFI namespaces
+fn qualified_name(namespace: &str, name: &str, context: &Context) -> Result<String> {
+    Ok(match context.external_cs_namespace(namespace)? {
+        Some(cs_namespace) => format!("global::{cs_namespace}.{name}"),
+        None => name.to_string(),
+    })
+}
+
+/// Namespace that a type is defined in, for user-defined types
+fn type_namespace(ty: &Type) -> Option<&str> {
+    match ty {
+        Type::Record { namespace, .. }
+        | Type::Enum { namespace, .. }
+        | Type::Interface { namespace, .. }
+        | Type::CallbackInterface { namespace, .. }
+        | Type::Custom { namespace, .. } => Some(namespace),
+        _ => None,
+    }
+}
+
+/// Is this type defined in another UniFFI namespace?
+pub fn is_external(ty: &Type, context: &Context) -> Result<bool> {
+    Ok(match type_namespace(ty) {
+        Some(namespace) => context.external_cs_namespace(namespace)?.is_some(),
+        None => false,
+    })
+}
+
+/// Name of the static class that converts values of a type
+pub fn ffi_converter_name(type_node: &general::TypeNode, context: &Context) -> Result<String> {
+    let name = format!("FfiConverter{}", type_node.canonical_name);
+    Ok(match type_namespace(&type_node.ty) {
+        Some(namespace) => qualified_name(namespace, &name, context)?,
+        None => name,
+    })
+}
+
+/// Expression that lifts the FFI value `nm`
+///
+/// External types that are passed as a `RustBuffer` get lifted using their `Read` method.  Each
+/// module defines its own `UniffiRustBuffer` struct, so we can't pass ours to another module's
+/// `Lift` method.
+pub fn lift(nm: &str, type_node: &general::TypeNode, context: &Context) -> Result<String> {
+    let converter = ffi_converter_name(type_node, context)?;
+    Ok(
+        if is_external(&type_node.ty, context)?
+            && matches!(type_node.ffi_type, FfiType::RustBuffer(_))
+        {
+            format!("UniffiRustBufferHelpers.Lift({nm}, {converter}.Read)")
+        } else {
+            format!("{converter}.Lift({nm})")
+        },
+    )
+}
+
+/// Expression that lowers the value `nm`, see [lift] for how external types are handled
+pub fn lower(nm: &str, type_node: &general::TypeNode, context: &Context) -> Result<String> {
+    let converter = ffi_converter_name(type_node, context)?;
+    Ok(
+        if is_external(&type_node.ty, context)?
+            && matches!(type_node.ffi_type, FfiType::RustBuffer(_))
+        {
+            format!("UniffiRustBufferHelpers.Lower({nm}, {converter}.Write)")
+        } else {
+            format!("{converter}.Lower({nm})")
+        },
+    )
+}
+
+pub fn map_throws_type(throws_type: general::ThrowsType, context: &Context) -> Result<ThrowsType> {
+    let Some(ty) = throws_type.ty else {
+        return Ok(ThrowsType {
+            ty: None,
+            error: None,
+        });
+    };
+    let converter = ffi_converter_name(&ty, context)?;
+    let error = match &ty.ty {
+        // Objects are thrown as themselves, but the error buffer stores a serialized handle.
+        Type::Interface {
+            namespace, name, ..
+        } => ErrorClass {
+            name: qualified_name(namespace, &names::class_name(name), context)?,
+            lift_expr: format!(
+                "uniffiBuf => (Exception)UniffiRustBufferHelpers.Lift(uniffiBuf, {converter}.Read)"
+            ),
+            lower_expr: format!("UniffiRustBufferHelpers.Lower(e, {converter}.Write)"),
+        },
+        Type::Enum { .. } => ErrorClass {
+            name: type_name(&ty.ty, context)?,
+            lift_expr: format!("uniffiBuf => {}", lift("uniffiBuf", &ty, context)?),
+            lower_expr: lower("e", &ty, context)?,
+        },
+        _ => bail!("Invalid error type: {:?}", ty.ty),
+    };
+    Ok(ThrowsType {
+        ty: Some(ty.map_node(context)?),
+        error: Some(error),
+    })
+}
@@ -0,0 +1,33 @@
+
+internal static class {{ array.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(array.self_type.type_name) %}{% endcall %}
+
+    public static {{ array.self_type.type_name }} Read(Stream stream)
+    {
+        var count = UniffiStreamHelpers.ReadLength(stream);
+        if (count != {{ array.len }})
+        {
+            throw new UniffiInternalException($"Expected an array of length {{ array.len }}, found {count}");
+        }
+        var result = new {{ array.self_type.type_name }}(count);
+        for (var i = 0; i < count; i++)
+        {
+            result.Add({{ array.inner.ffi_converter_name }}.Read(stream));
+        }
+        return result;
+    }
+
+    public static void Write({{ array.self_type.type_name }} value, Stream stream)
+    {
+        if (value.Count != {{ array.len }})
+        {
+            throw new ArgumentException($"Expected an array of length {{ array.len }}, found {value.Count}");
+        }
+        UniffiStreamHelpers.WriteInt32(value.Count, stream);
+        foreach (var item in value)
+        {
+            {{ array.inner.ffi_converter_name }}.Write(item, stream);
+        }
+    }
+}
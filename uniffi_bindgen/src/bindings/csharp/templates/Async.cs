@@ -0,0 +1,81 @@
+internal delegate T UniffiCompleteFunc<T>(ulong rustFuture, ref UniffiRustCallStatus status);
+internal delegate void UniffiCompleteAction(ulong rustFuture, ref UniffiRustCallStatus status);
+
+// Drives Rust futures using the `rust_future_*` FFI functions
+internal static unsafe class UniffiAsync
+{
+    // RustFuturePoll values
+    private const sbyte PollReady = 0;
+    private const sbyte PollWake = 1;
+
+    // Stores the tasks for `ContinuationCallback`
+    private static readonly UniffiHandleMap<TaskCompletionSource<sbyte>> ContinuationHandleMap = new();
+
+    private static readonly IntPtr ContinuationCallbackPtr =
+        (IntPtr)(delegate* unmanaged[Cdecl]<ulong, sbyte, void>)&ContinuationCallback;
+
+    // Continuation callback for async functions.
+    // Resolve the task, which causes the async function to poll the future again.
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    private static void ContinuationCallback(ulong data, sbyte pollResult)
+    {
+        ContinuationHandleMap.Remove(data).TrySetResult(pollResult);
+    }
+
+    private static async Task PollUntilReady(ulong rustFuture, Action<ulong, IntPtr, ulong> pollFunc)
+    {
+        sbyte pollResult;
+        do
+        {
+            // Run continuations asynchronously, so that we don't poll the future again from
+            // inside the continuation callback.
+            var completion = new TaskCompletionSource<sbyte>(TaskCreationOptions.RunContinuationsAsynchronously);
+            pollFunc(rustFuture, ContinuationCallbackPtr, ContinuationHandleMap.Insert(completion));
+            pollResult = await completion.Task.ConfigureAwait(false);
+        } while (pollResult != PollReady);
+    }
+
+    public static async Task<T> RustCallAsync<F, T>(
+        ulong rustFuture,
+        Action<ulong, IntPtr, ulong> pollFunc,
+        UniffiCompleteFunc<F> completeFunc,
+        Action<ulong> freeFunc,
+        Func<F, T> liftFunc,
+        Func<UniffiRustBuffer, Exception>? errorHandler
+    )
+    {
+        try
+        {
+            await PollUntilReady(rustFuture, pollFunc).ConfigureAwait(false);
+            var status = new UniffiRustCallStatus();
+            var result = completeFunc(rustFuture, ref status);
+            UniffiHelpers.CheckCallStatus(errorHandler, status);
+            return liftFunc(result);
+        }
+        finally
+        {
+            freeFunc(rustFuture);
+        }
+    }
+
+    public static async Task RustCallAsyncVoid(
+        ulong rustFuture,
+        Action<ulong, IntPtr, ulong> pollFunc,
+        UniffiCompleteAction completeFunc,
+        Action<ulong> freeFunc,
+        Func<UniffiRustBuffer, Exception>? errorHandler
+    )
+    {
+        try
+        {
+            await PollUntilReady(rustFuture, pollFunc).ConfigureAwait(false);
+            var status = new UniffiRustCallStatus();
+            completeFunc(rustFuture, ref status);
+            UniffiHelpers.CheckCallStatus(errorHandler, status);
+        }
+        finally
+        {
+            freeFunc(rustFuture);
+        }
+    }
+}
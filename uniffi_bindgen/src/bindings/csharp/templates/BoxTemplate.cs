@@ -0,0 +1,15 @@
+
+internal static class {{ box_.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(box_.self_type.type_name) %}{% endcall %}
+
+    public static {{ box_.self_type.type_name }} Read(Stream stream)
+    {
+        return {{ box_.inner.ffi_converter_name }}.Read(stream);
+    }
+
+    public static void Write({{ box_.self_type.type_name }} value, Stream stream)
+    {
+        {{ box_.inner.ffi_converter_name }}.Write(value, stream);
+    }
+}
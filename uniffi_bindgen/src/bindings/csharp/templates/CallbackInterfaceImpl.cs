@@ -0,0 +1,113 @@
+{#
+// Implements the VTable for a callback interface or trait interface.
+// `vtable` and `ffi_converter_name` must be set by the including template.
+#}
+
+// Methods that Rust calls through the VTable.  These look up the C# object in the handle map,
+// call the interface method, then write the result back to Rust.
+internal static unsafe class UniffiCallbackImpl{{ vtable.interface_name }}
+{
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    private static void UniffiFree(ulong uniffiHandle)
+    {
+        {{ ffi_converter_name }}.HandleMap.Remove(uniffiHandle);
+    }
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    private static ulong UniffiClone(ulong uniffiHandle)
+    {
+        return {{ ffi_converter_name }}.HandleMap.Clone(uniffiHandle);
+    }
+    {%- for meth in vtable.methods %}
+    {%- let callable = meth.callable %}
+
+    [UnmanagedCallersOnly(CallConvs = new[] { typeof(CallConvCdecl) })]
+    private static void {{ callable.name }}({{ meth.ffi_params }})
+    {
+        {%- match callable.async_data %}
+        {%- when None %}
+        try
+        {
+            var uniffiObj = {{ ffi_converter_name }}.HandleMap.Get(uniffiHandle);
+            {% if callable.return_type.ty.is_some() %}var uniffiResult = {% endif -%}
+            uniffiObj.{{ callable.name }}(
+                {%- for arg in callable.arguments %}
+                {{- arg.ty.lift(arg.name) }}{% if !loop.last %}, {% endif %}
+                {%- endfor -%}
+            );
+            {%- if let Some(return_type) = callable.return_type.ty %}
+            *uniffiOutReturn = {{ return_type.lower("uniffiResult") }};
+            {%- endif %}
+        }
+        {%- if let Some(error) = callable.throws_type.error %}
+        catch ({{ error.name }} e)
+        {
+            uniffiCallStatus->Code = UniffiRustCallStatus.Error;
+            uniffiCallStatus->ErrorBuf = {{ error.lower_expr }};
+        }
+        {%- endif %}
+        catch (Exception uniffiException)
+        {
+            uniffiCallStatus->Code = UniffiRustCallStatus.UnexpectedError;
+            uniffiCallStatus->ErrorBuf = UniffiRustBuffer.FromString(uniffiException.ToString());
+        }
+        {%- when Some(async_data) %}
+        {{ callable.return_type_decl() }} uniffiTask;
+        try
+        {
+            uniffiTask = {{ ffi_converter_name }}.HandleMap.Get(uniffiHandle).{{ callable.name }}(
+                {%- for arg in callable.arguments %}
+                {{- arg.ty.lift(arg.name) }}{% if !loop.last %}, {% endif %}
+                {%- endfor -%}
+            );
+        }
+        catch (Exception uniffiException)
+        {
+            uniffiTask = Task.From{% if let Some(return_type) = callable.return_type.ty %}Exception<{{ return_type.type_name }}>{% else %}Exception{% endif %}(uniffiException);
+        }
+        // Function pointers can't be captured, so cast the callback inside the continuation.
+        var uniffiCallback = uniffiFutureCallback;
+        uniffiTask.ContinueWith(uniffiCompletedTask =>
+        {
+            var uniffiResult = new {{ async_data.ffi_foreign_future_result }}();
+            if (uniffiCompletedTask.IsCompletedSuccessfully)
+            {
+                {%- if let Some(return_type) = callable.return_type.ty %}
+                uniffiResult.return_value = {{ return_type.lower("uniffiCompletedTask.Result") }};
+                {%- endif %}
+            }
+            else
+            {
+                var uniffiException = uniffiCompletedTask.Exception?.InnerException
+                    ?? new TaskCanceledException(uniffiCompletedTask);
+                {%- if let Some(error) = callable.throws_type.error %}
+                if (uniffiException is {{ error.name }} e)
+                {
+                    uniffiResult.call_status.Code = UniffiRustCallStatus.Error;
+                    uniffiResult.call_status.ErrorBuf = {{ error.lower_expr }};
+                }
+                else
+                {%- endif %}
+                {
+                    uniffiResult.call_status.Code = UniffiRustCallStatus.UnexpectedError;
+                    uniffiResult.call_status.ErrorBuf = UniffiRustBuffer.FromString(uniffiException.ToString());
+                }
+            }
+            (({{ meth.future_callback_type.as_ref().unwrap() }})uniffiCallback)(uniffiCallbackData, uniffiResult);
+        }, TaskScheduler.Default);
+        {%- endmatch %}
+    }
+    {%- endfor %}
+
+    // Register the VTable with Rust.  The VTable is never freed, since Rust keeps a pointer to it.
+    internal static void UniffiRegister()
+    {
+        var vtable = ({{ vtable.struct_name }}*)NativeMemory.Alloc((nuint)sizeof({{ vtable.struct_name }}));
+        vtable->uniffi_free = (IntPtr)({{ vtable.free_fn_pointer_type }})&UniffiFree;
+        vtable->uniffi_clone = (IntPtr)({{ vtable.clone_fn_pointer_type }})&UniffiClone;
+        {%- for meth in vtable.methods %}
+        vtable->{{ meth.ffi_field_name }} = (IntPtr)({{ meth.fn_pointer_type }})&{{ meth.callable.name }};
+        {%- endfor %}
+        UniffiLib.{{ vtable.init_fn.0 }}(vtable);
+    }
+}
@@ -0,0 +1,36 @@
+{%- let type_name = cbi.self_type.type_name %}
+{%- let ffi_converter_name = cbi.self_type.ffi_converter_name %}
+{%- let vtable = cbi.vtable %}
+{%- call cs::docstring(cbi, 0) %}{% endcall %}
+public interface {{ cbi.name }}
+{
+    {%- call cs::interface_method_decls(cbi.methods) %}{% endcall %}
+}
+
+{%- include "CallbackInterfaceImpl.cs" %}
+
+// Converts callback interface implementations into handles to pass to Rust.
+internal static class {{ ffi_converter_name }}
+{
+    internal static readonly UniffiHandleMap<{{ type_name }}> HandleMap = new();
+
+    public static {{ type_name }} Lift(ulong value)
+    {
+        return HandleMap.Get(value);
+    }
+
+    public static ulong Lower({{ type_name }} value)
+    {
+        return HandleMap.Insert(value);
+    }
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        return Lift(UniffiStreamHelpers.ReadUInt64(stream));
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteUInt64(Lower(value), stream);
+    }
+}
@@ -0,0 +1,58 @@
+{%- let builtin = custom.builtin %}
+{%- let type_name = custom.self_type.type_name %}
+{%- let ffi_type_name = builtin.ffi_type.type_name %}
+{%- match custom.config %}
+{%- when None %}
+{#- No custom type config, `{{ custom.name }}` is just the builtin type #}
+internal static class {{ custom.self_type.ffi_converter_name }}
+{
+    public static {{ type_name }} Lift({{ ffi_type_name }} value)
+    {
+        return {{ builtin.lift("value") }};
+    }
+
+    public static {{ ffi_type_name }} Lower({{ type_name }} value)
+    {
+        return {{ builtin.lower("value") }};
+    }
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        return {{ builtin.ffi_converter_name }}.Read(stream);
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        {{ builtin.ffi_converter_name }}.Write(value, stream);
+    }
+}
+
+{%- when Some(config) %}
+{#- Custom type config supplied, use it to convert the builtin type #}
+internal static class {{ custom.self_type.ffi_converter_name }}
+{
+    public static {{ type_name }} Lift({{ ffi_type_name }} value)
+    {
+        var builtinValue = {{ builtin.lift("value") }};
+        return {{ config.lift("builtinValue") }};
+    }
+
+    public static {{ ffi_type_name }} Lower({{ type_name }} value)
+    {
+        var builtinValue = {{ config.lower("value") }};
+        return {{ builtin.lower("builtinValue") }};
+    }
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        var builtinValue = {{ builtin.ffi_converter_name }}.Read(stream);
+        return {{ config.lift("builtinValue") }};
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        var builtinValue = {{ config.lower("value") }};
+        {{ builtin.ffi_converter_name }}.Write(builtinValue, stream);
+    }
+}
+{%- endmatch %}
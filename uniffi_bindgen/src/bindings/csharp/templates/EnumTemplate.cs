@@ -0,0 +1,129 @@
+{%- let type_name = e.self_type.type_name %}
+{%- if e.is_flat %}
+{%- call cs::docstring(e, 0) %}{% endcall %}
+public enum {{ e.name }} : {{ e.discr_type.type_name }}
+{
+    {%- for variant in e.variants %}
+    {%- call cs::docstring(variant, 4) %}{% endcall %}
+    {{ variant.name }} = {{ variant.discr }},
+    {%- endfor %}
+}
+{%- if !e.constructors.is_empty() || !e.methods.is_empty() || !e.static_methods.is_empty() %}
+
+// C# enums can't have methods, so they're defined as extension methods instead.
+public static class {{ e.name }}Extensions
+{
+    {%- for cons in e.constructors %}
+    {%- call cs::method_decl("public static", cons, "self") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in e.methods %}
+    {%- let callable = meth.callable %}
+    {%- call cs::docstring(meth, 4) %}{% endcall %}
+    public static {{ callable.return_type_decl() }} {{ callable.name }}(this {{ type_name }} self{% if !callable.arguments.is_empty() %}, {% endif %}{% call cs::arg_list_decl(callable) %}{% endcall %})
+    {
+        {%- call cs::call_body(callable, "self") %}{% endcall %}
+    }
+    {%- endfor %}
+    {%- for meth in e.static_methods %}
+    {%- call cs::method_decl("public static", meth, "self") %}{% endcall %}
+    {%- endfor %}
+}
+{%- endif %}
+
+internal static class {{ e.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(type_name) %}{% endcall %}
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        return UniffiStreamHelpers.ReadInt32(stream) switch
+        {
+            {%- for variant in e.variants %}
+            {{ loop.index }} => {{ type_name }}.{{ variant.name }},
+            {%- endfor %}
+            var index => throw new UniffiInternalException($"Invalid enum value: {index}"),
+        };
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteInt32(value switch
+        {
+            {%- for variant in e.variants %}
+            {{ type_name }}.{{ variant.name }} => {{ loop.index }},
+            {%- endfor %}
+            _ => throw new ArgumentException($"Invalid enum value: {value}"),
+        }, stream);
+    }
+}
+
+{%- else %}
+{%- call cs::docstring(e, 0) %}{% endcall %}
+public abstract record class {{ e.name }}{% if e.uniffi_trait_methods.ord_cmp.is_some() %} : IComparable<{{ e.name }}>{% endif %}
+{
+    private {{ e.name }}() { }
+    {%- for variant in e.variants %}
+{% call cs::docstring(variant, 4) %}{% endcall %}
+    {%- if variant.fields.is_empty() %}
+    public sealed record class {{ variant.name }}() : {{ e.name }};
+    {%- else %}
+    public sealed record class {{ variant.name }}(
+        {%- for field in variant.fields %}
+        {{ field.ty.type_name }} {{ field.name }}{% if let Some(default) = field.default %} = {{ default }}{% endif %}{% if !loop.last %},{% endif %}
+        {%- endfor %}
+    ) : {{ e.name }};
+    {%- endif %}
+    {%- endfor %}
+    {%- for cons in e.constructors %}
+    {%- call cs::method_decl("public static", cons, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in e.methods %}
+    {%- call cs::method_decl("public", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in e.static_methods %}
+    {%- call cs::method_decl("public static", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- call cs::uniffi_trait_impls(e.uniffi_trait_methods, e.name, false) %}{% endcall %}
+}
+
+internal static class {{ e.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(type_name) %}{% endcall %}
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        return UniffiStreamHelpers.ReadInt32(stream) switch
+        {
+            {%- for variant in e.variants %}
+            {%- if variant.fields.is_empty() %}
+            {{ loop.index }} => new {{ type_name }}.{{ variant.name }}(),
+            {%- else %}
+            {{ loop.index }} => new {{ type_name }}.{{ variant.name }}(
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.Read(stream){% if !loop.last %},{% endif %}
+                {%- endfor %}
+            ),
+            {%- endif %}
+            {%- endfor %}
+            var index => throw new UniffiInternalException($"Invalid enum value: {index}"),
+        };
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        switch (value)
+        {
+            {%- for variant in e.variants %}
+            case {{ type_name }}.{{ variant.name }}{% if variant.has_fields() %} variant{% endif %}:
+                UniffiStreamHelpers.WriteInt32({{ loop.index }}, stream);
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.Write(variant.{{ field.name }}, stream);
+                {%- endfor %}
+                break;
+            {%- endfor %}
+            default:
+                throw new ArgumentException($"Invalid enum value: {value}");
+        }
+    }
+}
+{%- endif %}
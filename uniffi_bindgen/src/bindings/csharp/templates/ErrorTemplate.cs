@@ -0,0 +1,87 @@
+{%- let type_name = e.self_type.type_name %}
+{%- call cs::docstring(e, 0) %}{% endcall %}
+public class {{ e.name }} : Exception{% if e.uniffi_trait_methods.ord_cmp.is_some() %}, IComparable<{{ e.name }}>{% endif %}
+{
+    private {{ e.name }}(string message) : base(message) { }
+    {%- for variant in e.variants %}
+{% call cs::docstring(variant, 4) %}{% endcall %}
+    public sealed class {{ variant.name }} : {{ e.name }}
+    {
+        {%- if e.is_flat %}
+        public {{ variant.name }}(string message) : base(message) { }
+        {%- elif variant.fields.is_empty() %}
+        public {{ variant.name }}() : base("") { }
+        {%- else %}
+        public {{ variant.name }}(
+            {%- for field in variant.fields %}
+            {{ field.ty.type_name }} {{ field.var_name }}{% if let Some(default) = field.default %} = {{ default }}{% endif %}{% if !loop.last %},{% endif %}
+            {%- endfor %}
+        ) : base({{ variant.error_message_expr() }})
+        {
+            {%- for field in variant.fields %}
+            this.{{ field.name }} = {{ field.var_name }};
+            {%- endfor %}
+        }
+        {%- for field in variant.fields %}
+        {%- call cs::docstring(field, 8) %}{% endcall %}
+        public {{ field.ty.type_name }} {{ field.name }} { get; }
+        {%- endfor %}
+        {%- endif %}
+    }
+    {%- endfor %}
+    {%- for cons in e.constructors %}
+    {%- call cs::method_decl("public static", cons, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in e.methods %}
+    {%- call cs::method_decl("public", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in e.static_methods %}
+    {%- call cs::method_decl("public static", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- call cs::uniffi_trait_impls(e.uniffi_trait_methods, e.name, true) %}{% endcall %}
+}
+
+internal static class {{ e.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(type_name) %}{% endcall %}
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        return UniffiStreamHelpers.ReadInt32(stream) switch
+        {
+            {%- for variant in e.variants %}
+            {%- if e.is_flat %}
+            {{ loop.index }} => new {{ type_name }}.{{ variant.name }}(UniffiStreamHelpers.ReadString(stream)),
+            {%- elif variant.fields.is_empty() %}
+            {{ loop.index }} => new {{ type_name }}.{{ variant.name }}(),
+            {%- else %}
+            {{ loop.index }} => new {{ type_name }}.{{ variant.name }}(
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.Read(stream){% if !loop.last %},{% endif %}
+                {%- endfor %}
+            ),
+            {%- endif %}
+            {%- endfor %}
+            var index => throw new UniffiInternalException($"Invalid error value: {index}"),
+        };
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        switch (value)
+        {
+            {%- for variant in e.variants %}
+            case {{ type_name }}.{{ variant.name }}{% if !e.is_flat && variant.has_fields() %} variant{% endif %}:
+                UniffiStreamHelpers.WriteInt32({{ loop.index }}, stream);
+                {%- if !e.is_flat %}
+                {%- for field in variant.fields %}
+                {{ field.ty.ffi_converter_name }}.Write(variant.{{ field.name }}, stream);
+                {%- endfor %}
+                {%- endif %}
+                break;
+            {%- endfor %}
+            default:
+                throw new ArgumentException($"Invalid error value: {value}");
+        }
+    }
+}
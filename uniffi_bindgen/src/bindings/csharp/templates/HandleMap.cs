@@ -0,0 +1,73 @@
+// Map of objects that are passed to Rust as handles
+//
+// Handles always have the lowest bit set, which lets the bindings tell them apart from the
+// handles that Rust generates.
+internal class UniffiHandleMap<T> where T : notnull
+{
+    private readonly object lockObj = new();
+    private readonly Dictionary<ulong, T> map = new();
+    private ulong counter = 1;
+
+    public ulong Insert(T obj)
+    {
+        lock (lockObj)
+        {
+            return InsertLocked(obj);
+        }
+    }
+
+    public T Get(ulong handle)
+    {
+        lock (lockObj)
+        {
+            if (map.TryGetValue(handle, out var obj))
+            {
+                return obj;
+            }
+            throw new UniffiInternalException($"UniffiHandleMap.Get: Invalid handle {handle}");
+        }
+    }
+
+    public ulong Clone(ulong handle)
+    {
+        lock (lockObj)
+        {
+            if (map.TryGetValue(handle, out var obj))
+            {
+                return InsertLocked(obj);
+            }
+            throw new UniffiInternalException($"UniffiHandleMap.Clone: Invalid handle {handle}");
+        }
+    }
+
+    public T Remove(ulong handle)
+    {
+        lock (lockObj)
+        {
+            if (map.Remove(handle, out var obj))
+            {
+                return obj;
+            }
+            throw new UniffiInternalException($"UniffiHandleMap.Remove: Invalid handle {handle}");
+        }
+    }
+
+    public int Count
+    {
+        get
+        {
+            lock (lockObj)
+            {
+                return map.Count;
+            }
+        }
+    }
+
+    private ulong InsertLocked(T obj)
+    {
+        var handle = counter;
+        counter += 2;
+        map[handle] = obj;
+        return handle;
+    }
+}
@@ -0,0 +1,260 @@
+// Thrown for unexpected errors, like Rust panics or mismatched bindings
+public class UniffiInternalException : Exception
+{
+    public UniffiInternalException(string message) : base(message) { }
+}
+
+// Tag type for the constructors that wrap an existing handle
+internal readonly struct UniffiHandleTag { }
+
+internal static class UniffiHelpers
+{
+    public static void CheckCallStatus(Func<UniffiRustBuffer, Exception>? errorHandler, UniffiRustCallStatus status)
+    {
+        switch (status.Code)
+        {
+            case UniffiRustCallStatus.Success:
+                return;
+            case UniffiRustCallStatus.Error:
+                if (errorHandler != null)
+                {
+                    throw errorHandler(status.ErrorBuf);
+                }
+                UniffiRustBuffer.Free(status.ErrorBuf);
+                throw new UniffiInternalException("CALL_ERROR, but no error handler was provided");
+            case UniffiRustCallStatus.UnexpectedError:
+                // When the Rust code sees a panic, it tries to construct a RustBuffer
+                // with the message. But if that code panics, then it just sends back
+                // an empty buffer.
+                if (status.ErrorBuf.Len > 0)
+                {
+                    throw new UniffiInternalException(UniffiRustBuffer.ConsumeString(status.ErrorBuf));
+                }
+                throw new UniffiInternalException("Rust panic");
+            case UniffiRustCallStatus.Cancelled:
+                throw new OperationCanceledException();
+            default:
+                throw new UniffiInternalException($"Unknown rust call status: {status.Code}");
+        }
+    }
+}
+
+// Convert values that are passed across the FFI in a `RustBuffer`
+internal static class UniffiRustBufferHelpers
+{
+    public static T Lift<T>(UniffiRustBuffer buf, Func<Stream, T> read)
+    {
+        var stream = new MemoryStream(UniffiRustBuffer.ConsumeBytes(buf));
+        var value = read(stream);
+        if (stream.Position != stream.Length)
+        {
+            throw new UniffiInternalException("junk remaining in buffer after lifting, something is very wrong!!");
+        }
+        return value;
+    }
+
+    public static UniffiRustBuffer Lower<T>(T value, Action<T, Stream> write)
+    {
+        var stream = new MemoryStream();
+        write(value, stream);
+        return UniffiRustBuffer.FromBytes(stream.ToArray());
+    }
+}
+
+// Read and write the big-endian values that make up the serialized data
+internal static class UniffiStreamHelpers
+{
+    public static byte[] ReadBytes(Stream stream, int count)
+    {
+        var bytes = new byte[count];
+        stream.ReadExactly(bytes);
+        return bytes;
+    }
+
+    public static int ReadLength(Stream stream)
+    {
+        var length = ReadInt32(stream);
+        if (length < 0)
+        {
+            throw new UniffiInternalException("Unexpected negative length");
+        }
+        return length;
+    }
+
+    public static sbyte ReadInt8(Stream stream)
+    {
+        return unchecked((sbyte)ReadUInt8(stream));
+    }
+
+    public static byte ReadUInt8(Stream stream)
+    {
+        var value = stream.ReadByte();
+        if (value < 0)
+        {
+            throw new EndOfStreamException();
+        }
+        return (byte)value;
+    }
+
+    public static short ReadInt16(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[2];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadInt16BigEndian(buf);
+    }
+
+    public static ushort ReadUInt16(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[2];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadUInt16BigEndian(buf);
+    }
+
+    public static int ReadInt32(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[4];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadInt32BigEndian(buf);
+    }
+
+    public static uint ReadUInt32(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[4];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadUInt32BigEndian(buf);
+    }
+
+    public static long ReadInt64(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[8];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadInt64BigEndian(buf);
+    }
+
+    public static ulong ReadUInt64(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[8];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadUInt64BigEndian(buf);
+    }
+
+    public static Int128 ReadInt128(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[16];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadInt128BigEndian(buf);
+    }
+
+    public static UInt128 ReadUInt128(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[16];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadUInt128BigEndian(buf);
+    }
+
+    public static float ReadFloat32(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[4];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadSingleBigEndian(buf);
+    }
+
+    public static double ReadFloat64(Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[8];
+        stream.ReadExactly(buf);
+        return BinaryPrimitives.ReadDoubleBigEndian(buf);
+    }
+
+    public static string ReadString(Stream stream)
+    {
+        return Encoding.UTF8.GetString(ReadBytes(stream, ReadLength(stream)));
+    }
+
+    public static void WriteInt8(sbyte value, Stream stream)
+    {
+        stream.WriteByte(unchecked((byte)value));
+    }
+
+    public static void WriteUInt8(byte value, Stream stream)
+    {
+        stream.WriteByte(value);
+    }
+
+    public static void WriteInt16(short value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[2];
+        BinaryPrimitives.WriteInt16BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteUInt16(ushort value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[2];
+        BinaryPrimitives.WriteUInt16BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteInt32(int value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[4];
+        BinaryPrimitives.WriteInt32BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteUInt32(uint value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[4];
+        BinaryPrimitives.WriteUInt32BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteInt64(long value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[8];
+        BinaryPrimitives.WriteInt64BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteUInt64(ulong value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[8];
+        BinaryPrimitives.WriteUInt64BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteInt128(Int128 value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[16];
+        BinaryPrimitives.WriteInt128BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteUInt128(UInt128 value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[16];
+        BinaryPrimitives.WriteUInt128BigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteFloat32(float value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[4];
+        BinaryPrimitives.WriteSingleBigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteFloat64(double value, Stream stream)
+    {
+        Span<byte> buf = stackalloc byte[8];
+        BinaryPrimitives.WriteDoubleBigEndian(buf, value);
+        stream.Write(buf);
+    }
+
+    public static void WriteString(string value, Stream stream)
+    {
+        var bytes = Encoding.UTF8.GetBytes(value);
+        WriteInt32(bytes.Length, stream);
+        stream.Write(bytes);
+    }
+}
@@ -0,0 +1,27 @@
+
+internal static class {{ map.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(map.self_type.type_name) %}{% endcall %}
+
+    public static {{ map.self_type.type_name }} Read(Stream stream)
+    {
+        var count = UniffiStreamHelpers.ReadLength(stream);
+        var result = new {{ map.self_type.type_name }}(count);
+        for (var i = 0; i < count; i++)
+        {
+            var key = {{ map.key.ffi_converter_name }}.Read(stream);
+            result[key] = {{ map.value.ffi_converter_name }}.Read(stream);
+        }
+        return result;
+    }
+
+    public static void Write({{ map.self_type.type_name }} value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteInt32(value.Count, stream);
+        foreach (var entry in value)
+        {
+            {{ map.key.ffi_converter_name }}.Write(entry.Key, stream);
+            {{ map.value.ffi_converter_name }}.Write(entry.Value, stream);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+{%- for def in ffi_definitions %}
+{%- if let FfiDefinition::Struct(ffi_struct) = def -%}
+[StructLayout(LayoutKind.Sequential)]
+internal unsafe struct {{ ffi_struct.name }}
+{
+    {%- for field in ffi_struct.fields %}
+    public {{ field.ty.type_name }} {{ field.name }};
+    {%- endfor %}
+}
+
+{% endif %}
+{%- endfor -%}
+// P/Invoke declarations for the extern-C FFI functions.
+// This is an implementation detail which will be called internally by the public API.
+internal static unsafe class UniffiLib
+{
+    private const string LibName = "{{ cdylib_name }}";
+
+    static UniffiLib()
+    {
+        UniffiCheckContractApiVersion();
+        UniffiCheckApiChecksums();
+        {%- for type_def in type_definitions %}
+        {%- match type_def %}
+        {%- when TypeDefinition::Interface(int) %}
+        {%- if let Some(vtable) = int.vtable %}
+        UniffiCallbackImpl{{ vtable.interface_name }}.UniffiRegister();
+        {%- endif %}
+        {%- when TypeDefinition::CallbackInterface(cbi) %}
+        UniffiCallbackImpl{{ cbi.vtable.interface_name }}.UniffiRegister();
+        {%- else %}
+        {%- endmatch %}
+        {%- endfor %}
+    }
+
+    private static void UniffiCheckContractApiVersion()
+    {
+        // Get the bindings contract version from our ComponentInterface
+        uint bindingsContractVersion = {{ correct_contract_version }};
+        // Get the scaffolding contract version by calling the into the dylib
+        uint scaffoldingContractVersion = {{ ffi_uniffi_contract_version.0 }}();
+        if (bindingsContractVersion != scaffoldingContractVersion)
+        {
+            throw new UniffiInternalException("UniFFI contract version mismatch: try cleaning and rebuilding your project");
+        }
+    }
+
+    private static void UniffiCheckApiChecksums()
+    {
+        {%- for checksum in checksums %}
+        if ({{ checksum.fn_name.0 }}() != {{ checksum.checksum }})
+        {
+            throw new UniffiInternalException("UniFFI API checksum mismatch: try cleaning and rebuilding your project");
+        }
+        {%- endfor %}
+    }
+    {%- for def in ffi_definitions %}
+    {%- if let FfiDefinition::RustFunction(func) = def %}
+
+    [DllImport(LibName, CallingConvention = CallingConvention.Cdecl)]
+    public static extern {% match func.return_type.ty %}{% when Some(return_type) %}{{ return_type.type_name }}{% when None %}void{% endmatch %} {{ func.name.0 }}(
+        {%- for arg in func.arguments %}
+        {{- arg.ty.type_name }} {{ arg.name }}{% if !loop.last || func.has_rust_call_status_arg %}, {% endif %}
+        {%- endfor %}
+        {%- if func.has_rust_call_status_arg %}ref UniffiRustCallStatus uniffiCallStatus{% endif -%}
+    );
+    {%- endif %}
+    {%- endfor %}
+}
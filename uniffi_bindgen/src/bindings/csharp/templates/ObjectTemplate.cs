@@ -0,0 +1,160 @@
+{%- let type_name = int.self_type.type_name %}
+{%- let ffi_converter_name = int.self_type.ffi_converter_name %}
+{%- call cs::docstring(int, 0) %}{% endcall %}
+public interface {{ int.interface_name }}
+{
+    {%- call cs::interface_method_decls(int.methods) %}{% endcall %}
+}
+{% call cs::docstring(int, 0) %}{% endcall %}
+public class {{ int.name }} : {% if int.self_type.is_used_as_error %}Exception, {% endif %}{{ int.interface_name }}, IDisposable
+{%- if int.uniffi_trait_methods.ord_cmp.is_some() %}, IComparable<{{ int.name }}>{% endif %}
+{
+    private readonly UniffiHandle uniffiHandle;
+
+    internal {{ int.name }}(UniffiHandleTag _, ulong handle)
+    {
+        uniffiHandle = new UniffiHandle(handle);
+    }
+    {%- if let Some(cons) = int.primary_constructor() %}
+    {%- let callable = cons.callable %}
+    {%- if callable.is_async() %}
+    {%- call cs::method_decl("public static", cons, "this") %}{% endcall %}
+    {%- else %}
+{% call cs::docstring(cons, 4) %}{% endcall %}
+    public {{ int.name }}({% call cs::arg_list_decl(callable) %}{% endcall %})
+        : this(default(UniffiHandleTag), UniffiConstructor({% for arg in callable.arguments %}{{ arg.name }}{% if !loop.last %}, {% endif %}{% endfor %}))
+    {
+    }
+
+    private static ulong UniffiConstructor({% for arg in callable.arguments %}{{ arg.ty.type_name }} {{ arg.name }}{% if !loop.last %}, {% endif %}{% endfor %})
+    {
+        {%- call cs::sync_ffi_call(callable, "this") %}{% endcall %}
+        return uniffiResult;
+    }
+    {%- endif %}
+    {%- endif %}
+    {%- for cons in int.alternate_constructors() %}
+    {%- call cs::method_decl("public static", cons, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in int.methods %}
+    {%- call cs::method_decl("public", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in int.static_methods %}
+    {%- call cs::method_decl("public static", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- call cs::uniffi_trait_impls(int.uniffi_trait_methods, int.name, true) %}{% endcall %}
+
+    public void Dispose()
+    {
+        uniffiHandle.Dispose();
+    }
+
+    // Clone the Rust handle, this is what gets passed to Rust when lowering the object.
+    internal ulong UniffiCloneHandle()
+    {
+        var success = false;
+        uniffiHandle.DangerousAddRef(ref success);
+        try
+        {
+            var uniffiStatus = new UniffiRustCallStatus();
+            var handle = UniffiLib.{{ int.ffi_func_clone.0 }}(uniffiHandle.Value, ref uniffiStatus);
+            UniffiHelpers.CheckCallStatus(null, uniffiStatus);
+            return handle;
+        }
+        finally
+        {
+            if (success)
+            {
+                uniffiHandle.DangerousRelease();
+            }
+        }
+    }
+
+    // Frees the Rust object when the C# object is disposed or garbage collected
+    private sealed class UniffiHandle : SafeHandle
+    {
+        public UniffiHandle(ulong handle) : base(IntPtr.Zero, true)
+        {
+            SetHandle(new IntPtr(unchecked((long)handle)));
+        }
+
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        public ulong Value => unchecked((ulong)handle.ToInt64());
+
+        protected override bool ReleaseHandle()
+        {
+            var uniffiStatus = new UniffiRustCallStatus();
+            UniffiLib.{{ int.ffi_func_free.0 }}(Value, ref uniffiStatus);
+            return uniffiStatus.Code == UniffiRustCallStatus.Success;
+        }
+    }
+}
+
+{%- match int.vtable %}
+{%- when None %}
+
+internal static class {{ ffi_converter_name }}
+{
+    public static {{ type_name }} Lift(ulong value)
+    {
+        return new {{ int.name }}(default(UniffiHandleTag), value);
+    }
+
+    public static ulong Lower({{ type_name }} value)
+    {
+        {%- if !int.imp.is_trait_interface() %}
+        return value.UniffiCloneHandle();
+        {%- else %}
+        return (({{ int.name }})value).UniffiCloneHandle();
+        {%- endif %}
+    }
+{%- when Some(vtable) %}
+
+{%- include "CallbackInterfaceImpl.cs" %}
+
+// The interface can be implemented in Rust or C#, so check which side a handle came from to know
+// how to handle it.
+internal static class {{ ffi_converter_name }}
+{
+    internal static readonly UniffiHandleMap<{{ int.interface_name }}> HandleMap = new();
+
+    public static {{ type_name }} Lift(ulong value)
+    {
+        if ((value & 1) == 0)
+        {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return new {{ int.name }}(default(UniffiHandleTag), value);
+        }
+        // C#-generated handle, get the object from the handle map
+        return HandleMap.Remove(value);
+    }
+
+    public static ulong Lower({{ type_name }} value)
+    {
+        if (value is {{ int.name }} obj)
+        {
+            // Rust-implemented object.  Clone the handle and return it
+            return obj.UniffiCloneHandle();
+        }
+        // C#-implemented object, generate a new handle and return that.
+        return HandleMap.Insert(value);
+    }
+{%- endmatch %}
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        var handle = UniffiStreamHelpers.ReadUInt64(stream);
+        if (handle == 0)
+        {
+            throw new UniffiInternalException("Raw handle value was null");
+        }
+        return Lift(handle);
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteUInt64(Lower(value), stream);
+    }
+}
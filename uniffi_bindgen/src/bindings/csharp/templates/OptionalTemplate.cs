@@ -0,0 +1,27 @@
+
+internal static class {{ opt.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(opt.self_type.type_name) %}{% endcall %}
+
+    public static {{ opt.self_type.type_name }} Read(Stream stream)
+    {
+        if (UniffiStreamHelpers.ReadInt8(stream) == 0)
+        {
+            return null;
+        }
+        return {{ opt.inner.ffi_converter_name }}.Read(stream);
+    }
+
+    public static void Write({{ opt.self_type.type_name }} value, Stream stream)
+    {
+        if (value is { } inner)
+        {
+            UniffiStreamHelpers.WriteInt8(1, stream);
+            {{ opt.inner.ffi_converter_name }}.Write(inner, stream);
+        }
+        else
+        {
+            UniffiStreamHelpers.WriteInt8(0, stream);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+{%- let type_name = rec.self_type.type_name %}
+{%- call cs::docstring(rec, 0) %}{% endcall %}
+{%- if rec.fields.is_empty() %}
+public record class {{ rec.name }}()
+{%- else %}
+public record class {{ rec.name }}(
+    {%- for field in rec.fields %}
+    {{ field.ty.type_name }} {{ field.name }}{% if let Some(default) = field.default %} = {{ default }}{% endif %}{% if !loop.last %},{% endif %}
+    {%- endfor %}
+)
+{%- endif %}
+{%- if rec.uniffi_trait_methods.ord_cmp.is_some() %} : IComparable<{{ rec.name }}>{% endif %}
+{
+    {%- for cons in rec.constructors %}
+    {%- call cs::method_decl("public static", cons, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in rec.methods %}
+    {%- call cs::method_decl("public", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for meth in rec.static_methods %}
+    {%- call cs::method_decl("public static", meth, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- call cs::uniffi_trait_impls(rec.uniffi_trait_methods, rec.name, false) %}{% endcall %}
+}
+
+internal static class {{ rec.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(type_name) %}{% endcall %}
+
+    public static {{ type_name }} Read(Stream stream)
+    {
+        {%- if rec.fields.is_empty() %}
+        return new {{ type_name }}();
+        {%- else %}
+        return new {{ type_name }}(
+            {%- for field in rec.fields %}
+            {{ field.ty.ffi_converter_name }}.Read(stream){% if !loop.last %},{% endif %}
+            {%- endfor %}
+        );
+        {%- endif %}
+    }
+
+    public static void Write({{ type_name }} value, Stream stream)
+    {
+        {%- for field in rec.fields %}
+        {{ field.ty.ffi_converter_name }}.Write(value.{{ field.name }}, stream);
+        {%- endfor %}
+    }
+}
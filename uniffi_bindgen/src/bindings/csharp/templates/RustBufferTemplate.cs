@@ -0,0 +1,96 @@
+[StructLayout(LayoutKind.Sequential)]
+internal struct UniffiRustBuffer
+{
+    public ulong Capacity;
+    public ulong Len;
+    public IntPtr Data;
+
+    public static UniffiRustBuffer FromBytes(byte[] bytes)
+    {
+        unsafe
+        {
+            fixed (byte* ptr = bytes)
+            {
+                var foreignBytes = new UniffiForeignBytes { Len = bytes.Length, Data = (IntPtr)ptr };
+                var uniffiStatus = new UniffiRustCallStatus();
+                var buf = UniffiLib.{{ ffi_rustbuffer_from_bytes.0 }}(foreignBytes, ref uniffiStatus);
+                UniffiHelpers.CheckCallStatus(null, uniffiStatus);
+                return buf;
+            }
+        }
+    }
+
+    public static UniffiRustBuffer FromString(string value)
+    {
+        return FromBytes(Encoding.UTF8.GetBytes(value));
+    }
+
+    // Copy the data out of the buffer and free it
+    public static byte[] ConsumeBytes(UniffiRustBuffer buf)
+    {
+        try
+        {
+            var bytes = new byte[checked((int)buf.Len)];
+            if (bytes.Length > 0)
+            {
+                Marshal.Copy(buf.Data, bytes, 0, bytes.Length);
+            }
+            return bytes;
+        }
+        finally
+        {
+            Free(buf);
+        }
+    }
+
+    public static string ConsumeString(UniffiRustBuffer buf)
+    {
+        return Encoding.UTF8.GetString(ConsumeBytes(buf));
+    }
+
+    public static void Free(UniffiRustBuffer buf)
+    {
+        var uniffiStatus = new UniffiRustCallStatus();
+        UniffiLib.{{ ffi_rustbuffer_free.0 }}(buf, ref uniffiStatus);
+        UniffiHelpers.CheckCallStatus(null, uniffiStatus);
+    }
+}
+
+// Borrowed bytes that are passed to Rust without copying them, see `UniffiPinnedBytes`
+[StructLayout(LayoutKind.Sequential)]
+internal struct UniffiForeignBytes
+{
+    public int Len;
+    public IntPtr Data;
+}
+
+// Pins a byte array so that it can be passed to Rust as `ForeignBytes`
+internal sealed class UniffiPinnedBytes : IDisposable
+{
+    private GCHandle handle;
+
+    public UniffiPinnedBytes(byte[] bytes)
+    {
+        handle = GCHandle.Alloc(bytes, GCHandleType.Pinned);
+        ForeignBytes = new UniffiForeignBytes { Len = bytes.Length, Data = handle.AddrOfPinnedObject() };
+    }
+
+    public UniffiForeignBytes ForeignBytes { get; }
+
+    public void Dispose()
+    {
+        handle.Free();
+    }
+}
+
+[StructLayout(LayoutKind.Sequential)]
+internal struct UniffiRustCallStatus
+{
+    public const sbyte Success = 0;
+    public const sbyte Error = 1;
+    public const sbyte UnexpectedError = 2;
+    public const sbyte Cancelled = 3;
+
+    public sbyte Code;
+    public UniffiRustBuffer ErrorBuf;
+}
@@ -0,0 +1,25 @@
+
+internal static class {{ set.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(set.self_type.type_name) %}{% endcall %}
+
+    public static {{ set.self_type.type_name }} Read(Stream stream)
+    {
+        var count = UniffiStreamHelpers.ReadLength(stream);
+        var result = new {{ set.self_type.type_name }}(count);
+        for (var i = 0; i < count; i++)
+        {
+            result.Add({{ set.inner.ffi_converter_name }}.Read(stream));
+        }
+        return result;
+    }
+
+    public static void Write({{ set.self_type.type_name }} value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteInt32(value.Count, stream);
+        foreach (var item in value)
+        {
+            {{ set.inner.ffi_converter_name }}.Write(item, stream);
+        }
+    }
+}
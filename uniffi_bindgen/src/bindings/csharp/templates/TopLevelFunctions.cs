@@ -0,0 +1,11 @@
+{%- call cs::docstring_value(docstring, 0) %}{% endcall %}
+public static class {{ class_name }}
+{
+    {%- for func in functions %}
+    {%- call cs::method_decl("public static", func, "this") %}{% endcall %}
+    {%- endfor %}
+    {%- for constant in constants %}
+{% call cs::docstring(constant, 4) %}{% endcall %}
+    public static readonly {{ constant.ty.type_name }} {{ constant.name }} = {{ constant.value }};
+    {%- endfor %}
+}
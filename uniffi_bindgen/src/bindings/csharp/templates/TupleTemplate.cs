@@ -0,0 +1,28 @@
+
+internal static class {{ tuple.self_type.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods(tuple.self_type.type_name) %}{% endcall %}
+
+    public static {{ tuple.self_type.type_name }} Read(Stream stream)
+    {
+        {%- match tuple.inners.len() %}
+        {%- when 0 %}
+        return default;
+        {%- when 1 %}
+        return new {{ tuple.self_type.type_name }}({{ tuple.inners[0].ffi_converter_name }}.Read(stream));
+        {%- else %}
+        return (
+            {%- for inner in tuple.inners %}
+            {{ inner.ffi_converter_name }}.Read(stream){% if !loop.last %},{% endif %}
+            {%- endfor %}
+        );
+        {%- endmatch %}
+    }
+
+    public static void Write({{ tuple.self_type.type_name }} value, Stream stream)
+    {
+        {%- for inner in tuple.inners %}
+        {{ inner.ffi_converter_name }}.Write(value.Item{{ loop.index }}, stream);
+        {%- endfor %}
+    }
+}
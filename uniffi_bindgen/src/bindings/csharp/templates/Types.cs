@@ -0,0 +1,113 @@
+{#
+ # Map `Type` instances to an include statement for that type.
+ #}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
+
+{%- when Type::Boolean %}
+{% include "builtins/Boolean.cs" %}
+
+{%- when Type::Char %}
+{% include "builtins/Char.cs" %}
+
+{%- when Type::Int8 %}
+{% include "builtins/Int8.cs" %}
+
+{%- when Type::Int16 %}
+{% include "builtins/Int16.cs" %}
+
+{%- when Type::Int32 %}
+{% include "builtins/Int32.cs" %}
+
+{%- when Type::Int64 %}
+{% include "builtins/Int64.cs" %}
+
+{%- when Type::Int128 %}
+{% include "builtins/Int128.cs" %}
+
+{%- when Type::UInt8 %}
+{% include "builtins/UInt8.cs" %}
+
+{%- when Type::UInt16 %}
+{% include "builtins/UInt16.cs" %}
+
+{%- when Type::UInt32 %}
+{% include "builtins/UInt32.cs" %}
+
+{%- when Type::UInt64 %}
+{% include "builtins/UInt64.cs" %}
+
+{%- when Type::UInt128 %}
+{% include "builtins/UInt128.cs" %}
+
+{%- when Type::Float32 %}
+{% include "builtins/Float32.cs" %}
+
+{%- when Type::Float64 %}
+{% include "builtins/Float64.cs" %}
+
+{%- when Type::String %}
+{% include "builtins/String.cs" %}
+
+{%- when Type::Bytes %}
+{% include "builtins/Bytes.cs" %}
+
+{%- when Type::Timestamp %}
+{% include "builtins/Timestamp.cs" %}
+
+{%- when Type::Duration %}
+{% include "builtins/Duration.cs" %}
+
+{%- else %}
+{# Type::Simple shouldn't hold any other Type variants #}
+{%- endmatch %}
+
+{%- when TypeDefinition::Box(box_) %}
+{% include "BoxTemplate.cs" %}
+
+{%- when TypeDefinition::Optional(opt) %}
+{% include "OptionalTemplate.cs" %}
+
+{%- when TypeDefinition::Sequence(seq) %}
+{% include "SequenceTemplate.cs" %}
+
+{%- when TypeDefinition::Array(array) %}
+{% include "ArrayTemplate.cs" %}
+
+{%- when TypeDefinition::Map(map) %}
+{% include "MapTemplate.cs" %}
+
+{%- when TypeDefinition::Set(set) %}
+{% include "SetTemplate.cs" %}
+
+{%- when TypeDefinition::Tuple(tuple) %}
+{% include "TupleTemplate.cs" %}
+
+{%- when TypeDefinition::Enum(e) %}
+{#- For enums, there are either an error *or* an enum, they can't be both. #}
+{%- if e.self_type.is_used_as_error %}
+{% include "ErrorTemplate.cs" %}
+{%- else %}
+{% include "EnumTemplate.cs" %}
+{%- endif %}
+
+{%- when TypeDefinition::Record(rec) %}
+{% include "RecordTemplate.cs" %}
+
+{%- when TypeDefinition::Interface(int) %}
+{% include "ObjectTemplate.cs" %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{% include "CallbackInterfaceTemplate.cs" %}
+
+{%- when TypeDefinition::Custom(custom) %}
+{% include "CustomTypeTemplate.cs" %}
+
+{%- else %}
+{#- Stream/Iterator/Closure types are rejected by the pipeline, external types are defined in
+    their own module. #}
+{%- endmatch %}
+{%- endfor %}
@@ -0,0 +1,23 @@
+
+internal static class {{ type_node.ffi_converter_name }}
+{
+    public static bool Lift(sbyte value)
+    {
+        return value != 0;
+    }
+
+    public static sbyte Lower(bool value)
+    {
+        return value ? (sbyte)1 : (sbyte)0;
+    }
+
+    public static bool Read(Stream stream)
+    {
+        return Lift(UniffiStreamHelpers.ReadInt8(stream));
+    }
+
+    public static void Write(bool value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteInt8(Lower(value), stream);
+    }
+}
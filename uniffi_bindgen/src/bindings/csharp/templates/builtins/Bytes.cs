@@ -0,0 +1,16 @@
+
+internal static class {{ type_node.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods("byte[]") %}{% endcall %}
+
+    public static byte[] Read(Stream stream)
+    {
+        return UniffiStreamHelpers.ReadBytes(stream, UniffiStreamHelpers.ReadLength(stream));
+    }
+
+    public static void Write(byte[] value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteInt32(value.Length, stream);
+        stream.Write(value);
+    }
+}
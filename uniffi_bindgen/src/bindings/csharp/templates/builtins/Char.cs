@@ -0,0 +1,24 @@
+
+// Rust chars are Unicode scalar values, which map to `System.Text.Rune`
+internal static class {{ type_node.ffi_converter_name }}
+{
+    public static Rune Lift(uint value)
+    {
+        return new Rune(value);
+    }
+
+    public static uint Lower(Rune value)
+    {
+        return (uint)value.Value;
+    }
+
+    public static Rune Read(Stream stream)
+    {
+        return Lift(UniffiStreamHelpers.ReadUInt32(stream));
+    }
+
+    public static void Write(Rune value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteUInt32(Lower(value), stream);
+    }
+}
@@ -0,0 +1,27 @@
+
+// Durations are `TimeSpan` values.
+//
+// `TimeSpan` has a precision of 100ns, so the sub-tick part of Rust durations is lost.
+internal static class {{ type_node.ffi_converter_name }}
+{
+    private const long NanosecondsPerTick = 100;
+
+    {%- call cs::rust_buffer_converter_methods("TimeSpan") %}{% endcall %}
+
+    public static TimeSpan Read(Stream stream)
+    {
+        var seconds = UniffiStreamHelpers.ReadUInt64(stream);
+        var nanoseconds = UniffiStreamHelpers.ReadUInt32(stream);
+        return TimeSpan.FromTicks(checked((long)seconds * TimeSpan.TicksPerSecond) + nanoseconds / NanosecondsPerTick);
+    }
+
+    public static void Write(TimeSpan value, Stream stream)
+    {
+        if (value < TimeSpan.Zero)
+        {
+            throw new ArgumentException("Invalid duration, must be non-negative");
+        }
+        UniffiStreamHelpers.WriteUInt64((ulong)(value.Ticks / TimeSpan.TicksPerSecond), stream);
+        UniffiStreamHelpers.WriteUInt32((uint)(value.Ticks % TimeSpan.TicksPerSecond * NanosecondsPerTick), stream);
+    }
+}
@@ -0,0 +1 @@
+{%- call cs::primitive_converter(type_node, "Float32") %}{% endcall %}
@@ -0,0 +1 @@
+{%- call cs::primitive_converter(type_node, "Float64") %}{% endcall %}
@@ -0,0 +1 @@
+{%- call cs::primitive_converter(type_node, "Int8") %}{% endcall %}
@@ -0,0 +1,25 @@
+
+// Top-level strings are passed as a RustBuffer that contains the UTF-8 data, without a length
+// prefix.
+internal static class {{ type_node.ffi_converter_name }}
+{
+    public static string Lift(UniffiRustBuffer value)
+    {
+        return UniffiRustBuffer.ConsumeString(value);
+    }
+
+    public static UniffiRustBuffer Lower(string value)
+    {
+        return UniffiRustBuffer.FromString(value);
+    }
+
+    public static string Read(Stream stream)
+    {
+        return UniffiStreamHelpers.ReadString(stream);
+    }
+
+    public static void Write(string value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteString(value, stream);
+    }
+}
@@ -0,0 +1,30 @@
+
+// Timestamps are `DateTime` values in UTC.
+//
+// `DateTime` has a precision of 100ns, so the sub-tick part of Rust timestamps is lost.
+internal static class {{ type_node.ffi_converter_name }}
+{
+    private const long NanosecondsPerTick = 100;
+
+    {%- call cs::rust_buffer_converter_methods("DateTime") %}{% endcall %}
+
+    public static DateTime Read(Stream stream)
+    {
+        // The sign of the seconds applies to the entire value
+        var seconds = UniffiStreamHelpers.ReadInt64(stream);
+        var nanoseconds = UniffiStreamHelpers.ReadUInt32(stream);
+        var offset = TimeSpan.FromSeconds(Math.Abs(seconds)) + TimeSpan.FromTicks(nanoseconds / NanosecondsPerTick);
+        return seconds >= 0 ? DateTime.UnixEpoch + offset : DateTime.UnixEpoch - offset;
+    }
+
+    public static void Write(DateTime value, Stream stream)
+    {
+        var offset = value.ToUniversalTime() - DateTime.UnixEpoch;
+        var sign = offset < TimeSpan.Zero ? -1 : 1;
+        offset = offset.Duration();
+        var seconds = offset.Ticks / TimeSpan.TicksPerSecond;
+        var nanoseconds = (offset.Ticks % TimeSpan.TicksPerSecond) * NanosecondsPerTick;
+        UniffiStreamHelpers.WriteInt64(sign * seconds, stream);
+        UniffiStreamHelpers.WriteUInt32((uint)nanoseconds, stream);
+    }
+}
@@ -0,0 +1,16 @@
+
+// 128-bit integers don't fit in a C type, so they're passed in a RustBuffer as 16 big-endian bytes.
+internal static class {{ type_node.ffi_converter_name }}
+{
+    {%- call cs::rust_buffer_converter_methods("UInt128") %}{% endcall %}
+
+    public static UInt128 Read(Stream stream)
+    {
+        return UniffiStreamHelpers.ReadUInt128(stream);
+    }
+
+    public static void Write(UInt128 value, Stream stream)
+    {
+        UniffiStreamHelpers.WriteUInt128(value, stream);
+    }
+}
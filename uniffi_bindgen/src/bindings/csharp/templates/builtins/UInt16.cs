@@ -0,0 +1 @@
+{%- call cs::primitive_converter(type_node, "UInt16") %}{% endcall %}
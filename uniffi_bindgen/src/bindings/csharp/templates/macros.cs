@@ -0,0 +1,201 @@
+{#
+// Template macros that are shared between the C# templates.
+#}
+
+{#- Converter for primitive types that are passed directly across the FFI #}
+{%- macro primitive_converter(type_node, stream_name) %}
+internal static class {{ type_node.ffi_converter_name }}
+{
+    public static {{ type_node.type_name }} Lift({{ type_node.type_name }} value)
+    {
+        return value;
+    }
+
+    public static {{ type_node.type_name }} Lower({{ type_node.type_name }} value)
+    {
+        return value;
+    }
+
+    public static {{ type_node.type_name }} Read(Stream stream)
+    {
+        return UniffiStreamHelpers.Read{{ stream_name }}(stream);
+    }
+
+    public static void Write({{ type_node.type_name }} value, Stream stream)
+    {
+        UniffiStreamHelpers.Write{{ stream_name }}(value, stream);
+    }
+}
+{%- endmacro %}
+
+{#- `Lift` and `Lower` for types that are passed across the FFI in a RustBuffer #}
+{%- macro rust_buffer_converter_methods(type_name) %}
+    public static {{ type_name }} Lift(UniffiRustBuffer value)
+    {
+        return UniffiRustBufferHelpers.Lift(value, Read);
+    }
+
+    public static UniffiRustBuffer Lower({{ type_name }} value)
+    {
+        return UniffiRustBufferHelpers.Lower(value, Write);
+    }
+{%- endmacro %}
+
+{#- Parameter list for a C# method declaration #}
+{%- macro arg_list_decl(callable) %}
+    {%- for arg in callable.arguments -%}
+    {{ arg.ty.type_name }} {{ arg.name }}
+    {%- if let Some(default) = arg.default %} = {{ default }}{% endif %}
+    {%- if !loop.last %}, {% endif %}
+    {%- endfor %}
+{%- endmacro %}
+
+{#- Arguments for calling the FFI function for a callable, `self_expr` is used for the receiver
+    of record and enum methods #}
+{%- macro ffi_call_args(callable, self_expr) %}
+    {%- let self_arg = callable.ffi_self_arg(self_expr) %}
+    {%- if let Some(self_arg) = self_arg %}
+    {{- self_arg }}
+    {%- if !callable.arguments.is_empty() || !callable.is_async() %}, {% endif %}
+    {%- endif %}
+    {%- for arg in callable.arguments %}
+    {{- arg.lower_arg() }}
+    {%- if !loop.last || !callable.is_async() %}, {% endif %}
+    {%- endfor %}
+    {%- if !callable.is_async() %}ref uniffiStatus{% endif %}
+{%- endmacro %}
+
+{#- Error handler argument for `CheckCallStatus` and the async helpers #}
+{%- macro error_handler(callable) %}
+    {%- match callable.throws_type.error %}
+    {%- when Some(error) %}{{ error.lift_expr }}
+    {%- when None %}null
+    {%- endmatch %}
+{%- endmacro %}
+
+{#- Statements that call a sync FFI function and store the result in `uniffiResult` #}
+{%- macro sync_ffi_call(callable, self_expr) %}
+        {%- for arg in callable.borrowed_bytes_arguments() %}
+        using var {{ arg.pinned_name() }} = new UniffiPinnedBytes({{ arg.name }});
+        {%- endfor %}
+        var uniffiStatus = new UniffiRustCallStatus();
+        {% if callable.return_type.ty.is_some() %}var uniffiResult = {% endif -%}
+        UniffiLib.{{ callable.ffi_func.0 }}({% call ffi_call_args(callable, self_expr) %}{% endcall %});
+        UniffiHelpers.CheckCallStatus({% call error_handler(callable) %}{% endcall %}, uniffiStatus);
+{%- endmacro %}
+
+{#- Body of a function or method that calls into Rust and returns the lifted result #}
+{%- macro call_body(callable, self_expr) %}
+    {%- match callable.async_data %}
+    {%- when None %}
+        {%- call sync_ffi_call(callable, self_expr) %}{% endcall %}
+        {%- if let Some(return_type) = callable.return_type.ty %}
+        return {{ return_type.lift("uniffiResult") }};
+        {%- endif %}
+    {%- when Some(async_data) %}
+        {%- match callable.return_type.ty %}
+        {%- when Some(return_type) %}
+        return UniffiAsync.RustCallAsync<{{ return_type.ffi_type.type_name }}, {{ return_type.type_name }}>(
+            UniffiLib.{{ callable.ffi_func.0 }}({% call ffi_call_args(callable, self_expr) %}{% endcall %}),
+            UniffiLib.{{ async_data.ffi_rust_future_poll.0 }},
+            UniffiLib.{{ async_data.ffi_rust_future_complete.0 }},
+            UniffiLib.{{ async_data.ffi_rust_future_free.0 }},
+            uniffiResult => {{ return_type.lift("uniffiResult") }},
+            {% call error_handler(callable) %}{% endcall %}
+        );
+        {%- when None %}
+        return UniffiAsync.RustCallAsyncVoid(
+            UniffiLib.{{ callable.ffi_func.0 }}({% call ffi_call_args(callable, self_expr) %}{% endcall %}),
+            UniffiLib.{{ async_data.ffi_rust_future_poll.0 }},
+            UniffiLib.{{ async_data.ffi_rust_future_complete.0 }},
+            UniffiLib.{{ async_data.ffi_rust_future_free.0 }},
+            {% call error_handler(callable) %}{% endcall %}
+        );
+        {%- endmatch %}
+    {%- endmatch %}
+{%- endmacro %}
+
+{#- A method, static method or top-level function.  `modifiers` is something like "public static" #}
+{%- macro method_decl(modifiers, meth, self_expr) %}
+    {%- let callable = meth.callable %}
+{% call docstring(meth, 4) %}{% endcall %}
+    {{ modifiers }} {{ callable.return_type_decl() }} {{ callable.name }}({% call arg_list_decl(callable) %}{% endcall %})
+    {
+        {%- call call_body(callable, self_expr) %}{% endcall %}
+    }
+{%- endmacro %}
+
+{%- macro docstring_value(maybe_docstring, indent_spaces) %}
+{%- match maybe_docstring %}
+{%- when Some(docstring) %}
+{{ docstring|docstring(indent_spaces) }}
+{%- else %}
+{%- endmatch %}
+{%- endmacro %}
+
+{%- macro docstring(defn, indent_spaces) %}
+{%- call docstring_value(defn.docstring, indent_spaces) %}{% endcall %}
+{%- endmacro %}
+
+{#- Implementations of exported Rust traits, `class_name` is the class that's being defined.
+    Records and data enums get `Equals` and `GetHashCode` from the record class, so those are
+    only generated for objects. #}
+{%- macro uniffi_trait_impls(uniffi_trait_methods, class_name, is_object) %}
+{%- if let Some(fmt) = uniffi_trait_methods.display_fmt.clone().or(uniffi_trait_methods.debug_fmt.clone()) %}
+
+    // The local Rust `Display`/`Debug` implementation.
+    {%- if is_object %}
+    public override string ToString()
+    {%- else %}
+    // This is sealed, so that it's also used for derived records.
+    public sealed override string ToString()
+    {%- endif %}
+    {
+        {%- call call_body(fmt.callable, "this") %}{% endcall %}
+    }
+{%- endif %}
+{%- if is_object %}
+{%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+
+    // The local Rust `Eq` implementation - only `eq` is used.
+    public override bool Equals(object? obj)
+    {
+        if (obj is not {{ class_name }} {{ eq.callable.arguments[0].name }})
+        {
+            return false;
+        }
+        {%- call call_body(eq.callable, "this") %}{% endcall %}
+    }
+{%- endif %}
+{%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
+
+    // The local Rust `Hash` implementation.
+    public override int GetHashCode()
+    {
+        {%- call sync_ffi_call(hash.callable, "this") %}{% endcall %}
+        return {{ hash.callable.return_type.ty.as_ref().unwrap().lift("uniffiResult") }}.GetHashCode();
+    }
+{%- endif %}
+{%- endif %}
+{%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+
+    // The local Rust `Ord` implementation.
+    public int CompareTo({{ class_name }}? {{ cmp.callable.arguments[0].name }})
+    {
+        if ({{ cmp.callable.arguments[0].name }} is null)
+        {
+            return 1;
+        }
+        {%- call call_body(cmp.callable, "this") %}{% endcall %}
+    }
+{%- endif %}
+{%- endmacro %}
+
+{#- Method declarations for the C# interface of an object or callback interface #}
+{%- macro interface_method_decls(methods) %}
+    {%- for meth in methods.iter() %}
+    {%- let callable = meth.callable %}
+    {%- call docstring(meth, 4) %}{% endcall %}
+    {{ callable.return_type_decl() }} {{ callable.name }}({% call arg_list_decl(callable) %}{% endcall %});
+    {%- endfor %}
+{%- endmacro %}
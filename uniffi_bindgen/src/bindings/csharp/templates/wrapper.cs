@@ -0,0 +1,52 @@
+// <auto-generated>
+// This file was autogenerated by some hot garbage in the `uniffi` crate.
+// Trust me, you don't want to mess with it!
+// </auto-generated>
+
+// Common helper code.
+//
+// Ideally this would live in a separate library where it can be unittested etc
+// in isolation, and perhaps even published as a re-useable package.
+//
+// However, it's important that the details of how this helper code works (e.g. the
+// way that different builtin types are passed across the FFI) exactly match what's
+// expected by the Rust code on the other side of the interface. In practice right
+// now that means coming from the exact some version of `uniffi` that was used to
+// compile the Rust component. The easiest way to ensure this is to bundle the C#
+// helpers directly inline like we're doing here.
+
+#nullable enable
+
+using System;
+using System.Buffers.Binary;
+using System.Collections.Generic;
+using System.IO;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Threading;
+using System.Threading.Tasks;
+{%- for import in imports %}
+using {{ import }};
+{%- endfor %}
+
+namespace {{ cs_namespace }};
+
+{% include "RustBufferTemplate.cs" %}
+
+{% include "Helpers.cs" %}
+
+{% include "NamespaceLibraryTemplate.cs" %}
+{%- if has_callback_definitions || has_async_fns %}
+
+{% include "HandleMap.cs" %}
+{%- endif %}
+{%- if has_async_fns %}
+
+{% include "Async.cs" %}
+{%- endif %}
+
+{%- include "Types.cs" %}
+
+{% include "TopLevelFunctions.cs" %}
+{% import "macros.cs" as cs %}
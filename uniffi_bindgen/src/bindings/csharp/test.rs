@@ -0,0 +1,117 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindings::{generate, GenerateOptions, RunScriptOptions, TargetLanguage};
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use fs_err as fs;
+use std::process::Command;
+use uniffi_testing::UniFFITestHelper;
+
+/// Run C# tests for a UniFFI test fixture
+pub fn run_test(tmp_dir: &str, fixture_name: &str, script_file: &str) -> Result<()> {
+    run_script(
+        tmp_dir,
+        fixture_name,
+        script_file,
+        vec![],
+        &RunScriptOptions::default(),
+    )
+}
+
+/// Run a C# script
+///
+/// The script is a program that uses top-level statements.  It gets compiled together with the
+/// generated bindings into a console app, which is then run with `dotnet run`.
+pub fn run_script(
+    tmp_dir: &str,
+    crate_name: &str,
+    script_file: &str,
+    args: Vec<String>,
+    options: &RunScriptOptions,
+) -> Result<()> {
+    let script_path = Utf8Path::new(script_file).canonicalize_utf8()?;
+    let test_helper = UniFFITestHelper::new(crate_name)?;
+    let out_dir = test_helper.create_out_dir(tmp_dir, &script_path)?;
+    let cdylib_path = test_helper.copy_cdylib_to_out_dir(&out_dir)?;
+
+    generate(GenerateOptions {
+        languages: vec![TargetLanguage::CSharp],
+        source: cdylib_path.to_path_buf(),
+        out_dir: out_dir.to_path_buf(),
+        ..GenerateOptions::default()
+    })?;
+    let project_file = write_project_file(crate_name, &out_dir, &cdylib_path, &script_path)?;
+
+    let mut command = Command::new("dotnet");
+    command
+        .current_dir(&out_dir)
+        .env("DOTNET_CLI_TELEMETRY_OPTOUT", "1")
+        .env("DOTNET_NOLOGO", "1")
+        .arg("run")
+        .arg("--project")
+        .arg(&project_file);
+    if !options.show_compiler_messages {
+        command.arg("--verbosity").arg("quiet");
+    }
+    if !args.is_empty() {
+        command.arg("--").args(args);
+    }
+
+    let status = command
+        .spawn()
+        .context("Failed to spawn `dotnet` to run C# script")?
+        .wait()
+        .context("Failed to wait for `dotnet` when running C# script")?;
+    if !status.success() {
+        bail!("running `dotnet` failed")
+    }
+    Ok(())
+}
+
+/// Write a project that compiles the generated bindings and the test script
+///
+/// The cdylib gets copied next to the compiled assembly so that `DllImport` can find it.
+fn write_project_file(
+    crate_name: &str,
+    out_dir: &Utf8Path,
+    cdylib_path: &Utf8Path,
+    script_path: &Utf8Path,
+) -> Result<Utf8PathBuf> {
+    let sources = glob::glob(out_dir.join("*.cs").as_str())?
+        .flatten()
+        .map(|p| String::from(p.to_string_lossy()))
+        .collect::<Vec<String>>();
+    if sources.is_empty() {
+        bail!("No C# sources found in {out_dir}")
+    }
+    let compile_items = sources
+        .iter()
+        .chain(std::iter::once(&script_path.to_string()))
+        .map(|path| format!("    <Compile Include=\"{path}\" />"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let project_file = out_dir.join(format!("{crate_name}.csproj"));
+    fs::write(
+        &project_file,
+        format!(
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <OutputType>Exe</OutputType>
+    <TargetFramework>net8.0</TargetFramework>
+    <Nullable>enable</Nullable>
+    <AllowUnsafeBlocks>true</AllowUnsafeBlocks>
+    <EnableDefaultCompileItems>false</EnableDefaultCompileItems>
+  </PropertyGroup>
+  <ItemGroup>
+{compile_items}
+    <None Include="{cdylib_path}" CopyToOutputDirectory="PreserveNewest" />
+  </ItemGroup>
+</Project>
+"#
+        ),
+    )?;
+    Ok(project_file)
+}
@@ -0,0 +1,68 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::process::Command;
+
+use crate::{bindings::GenerateOptions, BindgenLoader};
+use anyhow::{bail, Context, Result};
+use askama::Template;
+use fs_err as fs;
+
+pub mod filters;
+mod pipeline;
+pub use pipeline::{pipeline, Root};
+
+#[cfg(feature = "bindgen-tests")]
+pub mod test;
+
+/// Generate Go bindings
+///
+/// Each module is generated as a cgo package in its own directory, named after the package.
+pub fn generate(loader: &BindgenLoader, options: GenerateOptions) -> Result<()> {
+    let metadata = loader.load_metadata(&options.source)?;
+    if let Some(crate_filter) = &options.crate_filter {
+        if !metadata.contains_key(crate_filter) {
+            bail!("No UniFFI metadata found for crate {crate_filter}");
+        }
+    }
+    let root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    let go_root = pipeline().execute(root)?;
+
+    for module in go_root.modules.values() {
+        if let Some(crate_filter) = &options.crate_filter {
+            if &module.crate_name != crate_filter {
+                continue;
+            }
+        }
+        let package_dir = options.out_dir.join(&module.package_name);
+        fs::create_dir_all(&package_dir)?;
+        let go_file = package_dir.join(format!("{}.go", module.package_name));
+        fs::write(
+            &go_file,
+            module.render().context("failed to render Go bindings")?,
+        )?;
+        fs::write(
+            package_dir.join(format!("{}.h", module.package_name)),
+            pipeline::HeaderFile { module }
+                .render()
+                .context("failed to render Go C header")?,
+        )?;
+        fs::write(
+            package_dir.join(format!("{}.c", module.package_name)),
+            pipeline::CHelpersFile { module }
+                .render()
+                .context("failed to render Go C helpers")?,
+        )?;
+
+        if options.format {
+            if let Err(e) = Command::new("gofmt").arg("-w").arg(&go_file).output() {
+                println!(
+                    "Warning: Unable to auto-format {} using gofmt: {e:?}",
+                    go_file.file_name().unwrap(),
+                )
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Get the Go name for a callable
+///
+/// Go doesn't have static methods, so constructors and static methods become package-level
+/// functions prefixed with the type name.  The primary constructor uses the Go convention of
+/// `New{Type}`.  Methods on enums that are rendered as Go interfaces also become functions, since
+/// interfaces can't have method implementations.
+pub fn callable_name(callable: &general::Callable, context: &Context) -> Result<String> {
+    let name = names::member_name(&callable.name);
+    Ok(match &callable.kind {
+        general::CallableKind::Constructor {
+            self_type,
+            primary: true,
+        } => format!("New{}", self_type_name(self_type)?),
+        general::CallableKind::Constructor { self_type, .. }
+        | general::CallableKind::StaticMethod { self_type } => {
+            format!("{}{name}", self_type_name(self_type)?)
+        }
+        general::CallableKind::Method { self_type } => match &self_type.ty {
+            Type::Enum {
+                name: enum_name, ..
+            } if context.is_interface_enum(enum_name) => {
+                format!("{}{name}", self_type_name(self_type)?)
+            }
+            _ => name,
+        },
+        general::CallableKind::Function | general::CallableKind::VTableMethod { .. } => name,
+    })
+}
+
+fn self_type_name(self_type: &general::TypeNode) -> Result<String> {
+    match &self_type.ty {
+        Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::Interface { name, .. }
+        | Type::CallbackInterface { name, .. } => Ok(names::type_name(name)),
+        ty => bail!("Invalid self type: {ty:?}"),
+    }
+}
@@ -0,0 +1,177 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{HashMap, HashSet};
+
+use heck::ToUpperCamelCase;
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub cdylib: Option<String>,
+    pub current_config: Option<GoConfig>,
+    pub module_namespace: Option<String>,
+    /// FFI function types for the current namespace, keyed by name
+    ///
+    /// These are needed to render the functions that Go exports for the VTables.
+    pub ffi_function_types: HashMap<String, general::FfiFunctionType>,
+    /// Maps FFI function type names to the VTable struct fields that store them
+    pub vtable_field_names: HashMap<String, String>,
+    /// Enums that are rendered as a Go interface with a struct for each variant
+    ///
+    /// This is all enums with associated data, plus all error enums.
+    pub interface_enums: HashSet<String>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.cdylib = root.cdylib.clone();
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => GoConfig::from_uniffi_toml(toml)?,
+            None => GoConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        self.ffi_function_types = namespace
+            .ffi_definitions
+            .iter()
+            .filter_map(|def| match def {
+                general::FfiDefinition::FunctionType(func_type) => {
+                    Some((func_type.name.0.clone(), func_type.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        self.vtable_field_names.clear();
+        for def in namespace.ffi_definitions.iter() {
+            if let general::FfiDefinition::Struct(st) = def {
+                for field in st.fields.iter() {
+                    if let FfiType::Function(name) = &field.ty {
+                        self.vtable_field_names
+                            .insert(name.0.clone(), field.name.clone());
+                    }
+                }
+            }
+        }
+        self.interface_enums = namespace
+            .type_definitions
+            .iter()
+            .filter_map(|type_def| match type_def {
+                general::TypeDefinition::Enum(e) if !e.is_flat || e.self_type.is_used_as_error => {
+                    Some(e.name.clone())
+                }
+                _ => None,
+            })
+            .collect();
+        Ok(())
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&GoConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    /// Name of the library to link against
+    pub fn cdylib(&self) -> Result<String> {
+        Ok(match &self.config()?.cdylib_name {
+            Some(cdylib) => cdylib.clone(),
+            None => match &self.cdylib {
+                Some(cdylib) => cdylib.clone(),
+                None => format!("uniffi_{}", self.module_namespace()?),
+            },
+        })
+    }
+
+    /// Go package for the current module
+    pub fn package_name(&self) -> Result<String> {
+        Ok(match &self.config()?.package_name {
+            Some(package_name) => package_name.clone(),
+            None => self.module_namespace()?.to_string(),
+        })
+    }
+
+    /// Prefix for the C symbols that the package exports
+    ///
+    /// Exported Go functions share a single C namespace for the entire program, so these need to
+    /// be unique across all the UniFFI packages that get linked together.
+    pub fn symbol_prefix(&self) -> Result<String> {
+        Ok(format!(
+            "uniffi{}",
+            self.module_namespace()?.to_upper_camel_case()
+        ))
+    }
+
+    /// Check that a type from `namespace` can be used in the current module
+    pub fn check_local_type(&self, namespace: &str, name: &str) -> Result<()> {
+        if self.module_namespace()? != namespace {
+            bail!("External types are not yet supported in the Go bindings ({namespace}::{name})");
+        }
+        Ok(())
+    }
+
+    /// Is an enum rendered as a Go interface, see `Context::interface_enums`
+    pub fn is_interface_enum(&self, name: &str) -> bool {
+        self.interface_enums.contains(name)
+    }
+
+    pub fn ffi_function_type(&self, name: &str) -> Result<&general::FfiFunctionType> {
+        self.ffi_function_types
+            .get(name)
+            .ok_or_else(|| anyhow!("FFI function type not found: {name}"))
+    }
+
+    /// Config for a custom type
+    ///
+    /// Configs are keyed by the renamed type name, but fall back to the original name for configs
+    /// written against the Rust name.
+    pub fn custom_type_config(
+        &self,
+        name: &str,
+        orig_name: &str,
+    ) -> Result<Option<&CustomTypeConfig>> {
+        let custom_types = &self.config()?.custom_types;
+        Ok(custom_types
+            .get(name)
+            .or_else(|| custom_types.get(orig_name)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn context(toml: &str) -> Context {
+        Context {
+            current_config: Some(GoConfig::from_uniffi_toml(toml).unwrap()),
+            module_namespace: Some("todolist".to_string()),
+            ..Context::default()
+        }
+    }
+
+    #[test]
+    fn test_package_name() {
+        assert_eq!(context("").package_name().unwrap(), "todolist");
+        assert_eq!(context("").symbol_prefix().unwrap(), "uniffiTodolist");
+        let toml = r#"
+            [bindings.go]
+            package_name = "todo"
+        "#;
+        assert_eq!(context(toml).package_name().unwrap(), "todo");
+        assert!(context("")
+            .check_local_type("todolist", "TodoEntry")
+            .is_ok());
+        assert!(context("").check_local_type("other", "TodoEntry").is_err());
+    }
+}
@@ -0,0 +1,46 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_variants(
+    enum_name: &str,
+    variants: Vec<general::Variant>,
+    is_error: bool,
+    context: &Context,
+) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            let name = names::type_name(&v.name);
+            let mut fields = types::map_fields(v.fields, context)?;
+            if is_error {
+                // Error variants implement the `error` interface, so a field can't be named
+                // `Error`.
+                for field in fields.iter_mut().filter(|f| f.name == "Error") {
+                    field.name = "Error_".to_string();
+                }
+            }
+            Ok(Variant {
+                // Go has no nested types or scoped constants, so variants are prefixed with the
+                // enum name.
+                type_name: format!("{}{name}", names::type_name(enum_name)),
+                name,
+                discr: discr_literal(&v.discr)?,
+                fields_kind: v.fields_kind,
+                fields,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Render a discriminant literal
+fn discr_literal(discr: &general::Literal) -> Result<String> {
+    match discr {
+        general::Literal::UInt(v, _, _) => Ok(v.to_string()),
+        general::Literal::Int(v, _, _) => Ok(v.to_string()),
+        _ => bail!("Only integer discriminants are supported: {discr:?}"),
+    }
+}
@@ -0,0 +1,211 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+
+use super::*;
+
+/// Get the cgo type for an FFI type, this is how Go code refers to the C type
+pub fn ffi_type_name(ffi_type: &FfiType) -> String {
+    match ffi_type {
+        FfiType::VoidPointer => "unsafe.Pointer".to_string(),
+        FfiType::Reference(inner) | FfiType::MutReference(inner) => {
+            format!("*{}", ffi_type_name(inner))
+        }
+        _ => format!("C.{}", c_type_name(ffi_type)),
+    }
+}
+
+/// Get the C type for an FFI type, used in the header that cgo reads
+pub fn c_type_name(ffi_type: &FfiType) -> String {
+    match ffi_type {
+        FfiType::Int8 => "int8_t".to_string(),
+        FfiType::UInt8 => "uint8_t".to_string(),
+        FfiType::Int16 => "int16_t".to_string(),
+        FfiType::UInt16 => "uint16_t".to_string(),
+        FfiType::Int32 => "int32_t".to_string(),
+        FfiType::UInt32 => "uint32_t".to_string(),
+        FfiType::Int64 => "int64_t".to_string(),
+        FfiType::UInt64 => "uint64_t".to_string(),
+        FfiType::Float32 => "float".to_string(),
+        FfiType::Float64 => "double".to_string(),
+        FfiType::Handle(_) => "uint64_t".to_string(),
+        FfiType::RustBuffer(_) => "RustBuffer".to_string(),
+        FfiType::RustCallStatus => "RustCallStatus".to_string(),
+        FfiType::ForeignBytes => "ForeignBytes".to_string(),
+        FfiType::Function(name) => names::ffi_struct_name(&name.0),
+        FfiType::Struct(name) => names::ffi_struct_name(&name.0),
+        FfiType::Reference(inner) => format!("const {}*", c_type_name(inner)),
+        FfiType::MutReference(inner) => format!("{}*", c_type_name(inner)),
+        FfiType::VoidPointer => "void*".to_string(),
+    }
+}
+
+/// Get the name of the struct for a VTable
+pub fn vtable_struct_name(vtable: &general::VTable) -> Result<String> {
+    match &vtable.struct_type {
+        FfiType::Struct(name) => Ok(names::ffi_struct_name(&name.0)),
+        ffi_type => bail!("Invalid VTable struct type: {ffi_type:?}"),
+    }
+}
+
+/// Name of the function that registers a VTable with Rust
+pub fn vtable_register_fn_name(vtable: &general::VTable) -> String {
+    format!(
+        "uniffiRegisterVTable{}",
+        vtable.interface_name.to_upper_camel_case()
+    )
+}
+
+/// Build an exported Go function that implements an FFI function type
+///
+/// `param_names` are the names for the Go parameters, in order.  Rust calls the function
+/// through a function pointer, so the signature must exactly match the function type.
+fn exported_function(
+    name: String,
+    fn_type_name: &str,
+    param_names: impl Fn(usize, &general::FfiArgument) -> String,
+    context: &Context,
+) -> Result<ExportedFunction> {
+    let func_type = context.ffi_function_type(fn_type_name)?;
+    let mut go_params = vec![];
+    let mut c_params = vec![];
+    for (i, arg) in func_type.arguments.iter().enumerate() {
+        go_params.push(format!(
+            "{} {}",
+            param_names(i, arg),
+            ffi_type_name(&arg.ty)
+        ));
+        c_params.push(exported_c_type_name(&arg.ty));
+    }
+    if func_type.has_rust_call_status_arg {
+        go_params.push("uniffiCallStatus *C.RustCallStatus".to_string());
+        c_params.push("RustCallStatus*".to_string());
+    }
+    let (go_return, c_return) = match &func_type.return_type.ty {
+        Some(ty) => (format!(" {}", ffi_type_name(ty)), exported_c_type_name(ty)),
+        None => (String::new(), "void".to_string()),
+    };
+    Ok(ExportedFunction {
+        c_declaration: format!("{c_return} {name}({});", c_params.join(", ")),
+        name,
+        fn_type: names::ffi_struct_name(fn_type_name),
+        go_params: go_params.join(", "),
+        go_return,
+    })
+}
+
+/// C type for the signature of an exported Go function
+///
+/// This needs to match the declaration that cgo generates, which doesn't have `const` pointers.
+fn exported_c_type_name(ffi_type: &FfiType) -> String {
+    match ffi_type {
+        FfiType::Reference(inner) => format!("{}*", c_type_name(inner)),
+        _ => c_type_name(ffi_type),
+    }
+}
+
+pub fn vtable_free_fn(vtable: &general::VTable, context: &Context) -> Result<ExportedFunction> {
+    exported_function(
+        format!(
+            "{}Free{}",
+            context.symbol_prefix()?,
+            vtable.interface_name.to_upper_camel_case()
+        ),
+        &vtable.free_fn_type.0,
+        |_, _| "uniffiHandle".to_string(),
+        context,
+    )
+}
+
+pub fn vtable_clone_fn(vtable: &general::VTable, context: &Context) -> Result<ExportedFunction> {
+    exported_function(
+        format!(
+            "{}Clone{}",
+            context.symbol_prefix()?,
+            vtable.interface_name.to_upper_camel_case()
+        ),
+        &vtable.clone_fn_type.0,
+        |_, _| "uniffiHandle".to_string(),
+        context,
+    )
+}
+
+/// Build the exported function for a VTable method
+///
+/// The first parameter is the handle and the ones after that are the method arguments, which get
+/// the same names as the Go arguments so that the template can lift them.  The rest are the
+/// out-params and callbacks that UniFFI adds.
+pub fn vtable_method_fn(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<ExportedFunction> {
+    let FfiType::Function(fn_type_name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    let general::CallableKind::VTableMethod { self_type, .. } = &vtable_method.callable.kind else {
+        bail!("Invalid VTable method: {:?}", vtable_method.callable.name);
+    };
+    let (Type::Interface { name, .. } | Type::CallbackInterface { name, .. }) = &self_type.ty
+    else {
+        bail!("Invalid VTable self type: {:?}", self_type.ty);
+    };
+    let arguments = &vtable_method.callable.arguments;
+    exported_function(
+        format!(
+            "{}{}{}",
+            context.symbol_prefix()?,
+            names::type_name(name),
+            vtable_method.callable.name.to_upper_camel_case(),
+        ),
+        &fn_type_name.0,
+        |i, arg| {
+            if i == 0 {
+                "uniffiHandle".to_string()
+            } else if i <= arguments.len() {
+                names::var_name(&arguments[i - 1].name)
+            } else {
+                arg.name.to_lower_camel_case()
+            }
+        },
+        context,
+    )
+}
+
+/// Lookup the name of the VTable struct field for a VTable method, as Go code accesses it
+pub fn vtable_method_field_name(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<String> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context
+        .vtable_field_names
+        .get(&name.0)
+        .map(|field_name| names::cgo_field_name(field_name))
+        .ok_or_else(|| anyhow!("VTable field not found: {}", name.0))
+}
+
+/// Name of the C helper that calls a function pointer
+///
+/// cgo can't call C function pointers directly, so the bindings define a C function for each
+/// function type that does it.
+pub fn fn_pointer_caller_name(fn_type_name: &str, context: &Context) -> Result<String> {
+    Ok(format!("{}Call{fn_type_name}", context.symbol_prefix()?))
+}
+
+/// Name of the C helper that completes an async VTable method
+pub fn future_callback_caller(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<Option<String>> {
+    match &vtable_method.callable.async_data {
+        Some(async_data) => Ok(Some(fn_pointer_caller_name(
+            &async_data.ffi_foreign_future_complete.0,
+            context,
+        )?)),
+        None => Ok(None),
+    }
+}
@@ -0,0 +1,176 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Literals
+//!
+//! Go doesn't have default arguments or default field values, so literals are only rendered for
+//! constants.  Callers need to pass all arguments explicitly.
+
+use super::*;
+
+/// Can a constant of this type be declared with `const`?  Others are declared with `var`.
+pub fn is_const_type(ty: &Type, context: &Context) -> Result<bool> {
+    Ok(match ty {
+        Type::Boolean
+        | Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::Int32
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64
+        | Type::Float32
+        | Type::Float64
+        | Type::String => true,
+        Type::Custom {
+            name,
+            orig_name,
+            builtin,
+            ..
+        } => {
+            context.custom_type_config(name, orig_name)?.is_none()
+                && is_const_type(builtin, context)?
+        }
+        _ => false,
+    })
+}
+
+/// Render the value of a constant
+///
+/// Literals for custom types are written as the builtin, which then gets lifted when the custom
+/// type has a config.
+pub fn render_constant(
+    literal: &general::Literal,
+    ty: &general::TypeNode,
+    context: &Context,
+) -> Result<String> {
+    match &ty.ty {
+        Type::Custom {
+            name,
+            orig_name,
+            builtin,
+            ..
+        } => {
+            let value = render_literal(literal, builtin, context)?;
+            Ok(match context.custom_type_config(name, orig_name)? {
+                Some(config) => config.lift(&value),
+                None => value,
+            })
+        }
+        _ => render_literal(literal, &ty.ty, context),
+    }
+}
+
+/// Render a literal as a Go expression
+pub fn render_literal(literal: &general::Literal, ty: &Type, context: &Context) -> Result<String> {
+    Ok(match literal {
+        general::Literal::Boolean(v) => format!("{v}"),
+        general::Literal::String(s) => string_literal(s),
+        general::Literal::Char(c) => format!("'\\U{:08X}'", u32::from(*c)),
+        general::Literal::Int(i, radix, _) => int_literal(*i < 0, i.unsigned_abs(), radix),
+        general::Literal::UInt(i, radix, _) => int_literal(false, *i, radix),
+        general::Literal::Float(string, _) => string.clone(),
+        general::Literal::Enum(variant, type_node) => match &type_node.ty {
+            Type::Enum { name, .. } => {
+                let variant_name =
+                    format!("{}{}", names::type_name(name), names::type_name(variant));
+                // Variants of interface enums are structs, flat enum variants are constants
+                if context.is_interface_enum(name) {
+                    format!("{variant_name}{{}}")
+                } else {
+                    variant_name
+                }
+            }
+            ty => bail!("Unexpected type in enum literal: {ty:?}"),
+        },
+        general::Literal::None => "nil".to_string(),
+        general::Literal::Some { inner } => {
+            let Type::Optional { inner_type } = ty else {
+                bail!("Unexpected type for Some literal: {ty:?}");
+            };
+            let value = render_default(inner, inner_type, context)?;
+            if types::is_nilable(inner_type, context)? {
+                value
+            } else {
+                format!(
+                    "uniffiPtr[{}]({value})",
+                    types::type_name(inner_type, context)?
+                )
+            }
+        }
+        general::Literal::EmptySequence
+        | general::Literal::EmptyMap
+        | general::Literal::EmptySet => format!("{}{{}}", types::type_name(ty, context)?),
+        general::Literal::Record { fields, ty } => {
+            let fields = fields
+                .iter()
+                .map(|f| {
+                    Ok(format!(
+                        "{}: {}",
+                        names::member_name(&f.name),
+                        render_literal(&f.value, &f.ty.ty, context)?
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            format!(
+                "{}{{{}}}",
+                types::type_name(&ty.ty, context)?,
+                fields.join(", ")
+            )
+        }
+    })
+}
+
+fn render_default(default: &general::DefaultValue, ty: &Type, context: &Context) -> Result<String> {
+    match default {
+        // `*new(T)` is the zero value for any type
+        general::DefaultValue::Default(type_node) => Ok(format!(
+            "*new({})",
+            types::type_name(&type_node.ty, context)?
+        )),
+        general::DefaultValue::Literal(literal) => render_literal(literal, ty, context),
+    }
+}
+
+fn int_literal(negative: bool, abs: u64, radix: &Radix) -> String {
+    let sign = if negative { "-" } else { "" };
+    match radix {
+        Radix::Decimal => format!("{sign}{abs}"),
+        Radix::Hexadecimal => format!("{sign}0x{abs:X}"),
+        Radix::Octal => format!("{sign}0o{abs:o}"),
+    }
+}
+
+fn string_literal(s: &str) -> String {
+    let mut result = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_literals() {
+        assert_eq!(string_literal("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+        assert_eq!(string_literal("\u{7}"), "\"\\u0007\"");
+        assert_eq!(int_literal(false, 255, &Radix::Hexadecimal), "0xFF");
+        assert_eq!(int_literal(true, 1, &Radix::Hexadecimal), "-0x1");
+        assert_eq!(int_literal(false, 8, &Radix::Octal), "0o10");
+        assert_eq!(int_literal(true, 8, &Radix::Decimal), "-8");
+    }
+}
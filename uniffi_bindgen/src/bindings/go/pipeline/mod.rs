@@ -0,0 +1,28 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use anyhow::{anyhow, bail, Result};
+use indexmap::{IndexMap, IndexSet};
+
+use uniffi_pipeline::{use_prev_node, MapNode, Node, Pipeline};
+mod callables;
+mod config;
+mod context;
+mod enums;
+mod ffi_types;
+mod literals;
+mod modules;
+mod names;
+pub mod nodes;
+mod types;
+
+pub use config::*;
+pub use context::Context;
+pub use nodes::*;
+
+pub use crate::pipeline::{general, initial};
+
+pub fn pipeline() -> Pipeline<initial::Root, Root> {
+    general::pipeline("go").pass::<Root, Context>(Context::default())
+}
@@ -0,0 +1,136 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?.clone();
+
+    let has_objects = namespace.has_descendant(|_: &general::Interface| true);
+    let has_callback_definitions = namespace.has_descendant(|_: &general::CallbackInterface| true)
+        || namespace.has_descendant(|int: &general::Interface| int.imp.has_callback_interface());
+    let has_async_fns = namespace.has_descendant(|callable: &general::Callable| {
+        callable.is_async() && !matches!(callable.kind, general::CallableKind::VTableMethod { .. })
+    });
+
+    Ok(Module {
+        package_name: context.package_name()?,
+        cdylib_name: context.cdylib()?,
+        symbol_prefix: context.symbol_prefix()?,
+        imports: imports(
+            &namespace,
+            &config,
+            has_objects,
+            has_callback_definitions || has_async_fns,
+        ),
+        tuple_sizes: tuple_sizes(&namespace),
+        name: namespace.name.clone(),
+        crate_name: namespace.crate_name.clone(),
+        has_async_fns,
+        has_callback_definitions,
+        docstring: namespace.docstring,
+        functions: namespace.functions.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        ffi_definitions: dedup_ffi_definitions(namespace.ffi_definitions).map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        correct_contract_version: namespace.correct_contract_version,
+    })
+}
+
+/// Packages that the generated code imports
+///
+/// Go refuses to compile code with unused imports, so the optional ones are only added when
+/// something uses them.
+fn imports(
+    namespace: &general::Namespace,
+    config: &GoConfig,
+    has_objects: bool,
+    has_handle_maps: bool,
+) -> Vec<String> {
+    let mut imports: BTreeSet<String> = ["bytes", "encoding/binary", "fmt", "io", "math", "unsafe"]
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    let mut add_if = |condition: bool, import: &str| {
+        if condition {
+            imports.insert(import.to_string());
+        }
+    };
+    // Errors returned by callback interface methods are matched with `errors.As`
+    add_if(
+        namespace.has_descendant(|vtable: &general::VTable| {
+            vtable.has_descendant(|callable: &general::Callable| callable.throws_type.ty.is_some())
+        }),
+        "errors",
+    );
+    add_if(has_objects, "runtime");
+    add_if(has_objects || has_handle_maps, "sync");
+    add_if(
+        namespace.has_descendant(|type_node: &general::TypeNode| {
+            matches!(type_node.ty, Type::Timestamp | Type::Duration)
+        }),
+        "time",
+    );
+    add_if(
+        namespace.has_descendant(|type_node: &general::TypeNode| {
+            matches!(type_node.ty, Type::Int128 | Type::UInt128)
+        }),
+        "math/big",
+    );
+    for import in config
+        .custom_types
+        .values()
+        .filter_map(|custom_type| custom_type.imports.as_ref())
+        .flatten()
+    {
+        imports.insert(import.clone());
+    }
+    imports.into_iter().collect()
+}
+
+/// Sizes of the non-empty tuples used in the namespace, each one needs a generic `TupleN` type
+fn tuple_sizes(namespace: &general::Namespace) -> Vec<u32> {
+    namespace
+        .type_definitions
+        .iter()
+        .filter_map(|type_def| match type_def {
+            general::TypeDefinition::Tuple(tuple) if !tuple.inners.is_empty() => {
+                Some(tuple.inners.len() as u32)
+            }
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Remove FFI definitions with duplicate names
+///
+/// Several FFI types share the same `rust_future` functions, but each function can only be
+/// declared once.
+fn dedup_ffi_definitions(
+    ffi_definitions: IndexSet<general::FfiDefinition>,
+) -> IndexSet<general::FfiDefinition> {
+    let mut seen = HashSet::new();
+    ffi_definitions
+        .into_iter()
+        .filter(|def| {
+            let name = match def {
+                general::FfiDefinition::RustFunction(func) => func.name.0.clone(),
+                general::FfiDefinition::FunctionType(func_type) => func_type.name.0.clone(),
+                general::FfiDefinition::Struct(st) => st.name.0.clone(),
+            };
+            seen.insert(name)
+        })
+        .collect()
+}
@@ -0,0 +1,132 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToLowerCamelCase, ToUpperCamelCase};
+
+// https://go.dev/ref/spec#Keywords
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+// Identifiers that are legal for variables, but would shadow something that the generated code
+// uses.  This is the predeclared identifiers plus the packages that the bindings import.
+//
+// https://go.dev/ref/spec#Predeclared_identifiers
+const SHADOWED_IDENTIFIERS: &[&str] = &[
+    "any", "append", "big", "binary", "bool", "byte", "bytes", "cap", "clear", "close", "complex",
+    "copy", "delete", "error", "errors", "false", "float32", "float64", "fmt", "int", "int16",
+    "int32", "int64", "int8", "io", "len", "make", "math", "max", "min", "new", "nil", "panic",
+    "recover", "rune", "runtime", "string", "sync", "time", "true", "uint", "uint16", "uint32",
+    "uint64", "uint8", "unsafe",
+];
+
+// C keywords that can't be used as struct field names in the cgo header
+const C_KEYWORDS: &[&str] = &[
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+    "enum", "extern", "float", "for", "goto", "if", "inline", "int", "long", "register",
+    "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef",
+    "union", "unsigned", "void", "volatile", "while",
+];
+
+/// Get the idiomatic Go rendering of a type name (for records, enums, objects, etc).
+///
+/// These are all exported, so they start with an uppercase letter and can't clash with keywords.
+pub fn type_name(nm: &str) -> String {
+    nm.to_upper_camel_case()
+}
+
+/// Get the name of the struct that wraps a Rust object handle
+///
+/// Trait interfaces are Go interfaces, so the struct for the Rust implementation gets an `Impl`
+/// suffix.
+pub fn object_struct_name(nm: &str, is_trait_interface: bool) -> String {
+    if is_trait_interface {
+        format!("{}Impl", type_name(nm))
+    } else {
+        type_name(nm)
+    }
+}
+
+/// Get the idiomatic Go rendering of an exported function, method or field name.
+pub fn member_name(nm: &str) -> String {
+    nm.to_upper_camel_case()
+}
+
+/// Get the idiomatic Go rendering of a variable or parameter name.
+pub fn var_name(nm: &str) -> String {
+    let nm = nm.to_lower_camel_case();
+    if KEYWORDS.contains(&nm.as_str()) || SHADOWED_IDENTIFIERS.contains(&nm.as_str()) {
+        format!("{nm}_")
+    } else {
+        nm
+    }
+}
+
+/// Get the C name of an FFI struct or function pointer typedef
+pub fn ffi_struct_name(nm: &str) -> String {
+    format!("Uniffi{nm}")
+}
+
+/// Get the name of a field in an FFI struct, as it's written in the C header
+pub fn c_field_name(nm: &str) -> String {
+    if C_KEYWORDS.contains(&nm) {
+        format!("{nm}_")
+    } else {
+        nm.to_string()
+    }
+}
+
+/// Get the name that Go code uses to access a C struct field
+///
+/// cgo prefixes fields that are Go keywords with an underscore.
+pub fn cgo_field_name(nm: &str) -> String {
+    let nm = c_field_name(nm);
+    if KEYWORDS.contains(&nm.as_str()) {
+        format!("_{nm}")
+    } else {
+        nm
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!(var_name("my_value"), "myValue");
+        assert_eq!(var_name("type"), "type_");
+        assert_eq!(var_name("bytes"), "bytes_");
+        assert_eq!(member_name("get_value"), "GetValue");
+        assert_eq!(type_name("my_record"), "MyRecord");
+        assert_eq!(object_struct_name("Getters", true), "GettersImpl");
+        assert_eq!(cgo_field_name("get_bool"), "get_bool");
+        assert_eq!(cgo_field_name("default"), "default_");
+        assert_eq!(cgo_field_name("range"), "_range");
+    }
+}
@@ -0,0 +1,740 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::go::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(general::FfiFunctionTypeName);
+use_prev_node!(general::FfiStructName);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(general::remove_unsupported_items(
+        self.namespaces,
+        "Go",
+        types::is_supported,
+    ).map_node(context)?)]
+    pub modules: IndexMap<String, Module>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "go", escape = "none", path = "wrapper.go")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    /// Go package that the bindings are generated into
+    pub package_name: String,
+    /// Name of the library to link against
+    pub cdylib_name: String,
+    /// Prefix for the C symbols exported by the package, see `Context::symbol_prefix`
+    pub symbol_prefix: String,
+    /// Packages to import
+    pub imports: Vec<String>,
+    /// Sizes of the `TupleN` types to declare
+    pub tuple_sizes: Vec<u32>,
+    pub name: String,
+    pub crate_name: String,
+    pub has_async_fns: bool,
+    pub has_callback_definitions: bool,
+    pub docstring: Option<String>,
+    pub functions: Vec<Function>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    pub correct_contract_version: String,
+}
+
+/// C header for the package
+///
+/// This only has declarations, since cgo doesn't allow definitions in the preamble of files that
+/// use `//export`.
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "GoHeaderTemplate.h")]
+pub struct HeaderFile<'a> {
+    pub module: &'a Module,
+}
+
+/// C helpers for the package, these call the function pointers that Rust passes to Go.
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "GoHelpersTemplate.c")]
+pub struct CHelpersFile<'a> {
+    pub module: &'a Module,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Array(ArrayType),
+    Map(MapType),
+    Set(SetType),
+    Tuple(TupleType),
+    Stream(StreamType),
+    Iterator(IteratorType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(callables::callable_name(&self, context)?)]
+    pub name: String,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method { self_type: TypeNode },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    VTableMethod {
+        self_type: TypeNode,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    // FFI types for async Rust functions
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    // FFI types for async foreign functions
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    #[map_node(names::ffi_struct_name(&self.ffi_foreign_future_result.0))]
+    pub ffi_foreign_future_result: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ReturnType))]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ThrowsType))]
+#[map_node(types::map_throws_type)]
+pub struct ThrowsType {
+    pub ty: Option<TypeNode>,
+    pub error: Option<ErrorType>,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct ErrorType {
+    /// Go type of the error, this is what callback interface methods match with `errors.As`
+    pub type_name: String,
+    /// `func(C.RustBuffer) error` expression that lifts the error buffer
+    pub lift_expr: String,
+    /// Expression that lowers a matched error, `uniffiError`, into a `RustBuffer`
+    pub lower_expr: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Is this a `&[u8]` argument that's passed to Rust as `ForeignBytes`?
+    #[map_node(self.is_borrowed_bytes())]
+    pub is_borrowed_bytes: bool,
+    pub ty: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(names::member_name(&self.name))]
+    pub name: String,
+    #[map_node(literals::render_constant(&self.value, &self.ty, context)?)]
+    pub value: String,
+    /// Can this be declared with `const`?  If not, it's declared with `var`.
+    #[map_node(literals::is_const_type(&self.ty.ty, context)?)]
+    pub is_const: bool,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    pub fields_kind: FieldsKind,
+    #[map_node(types::map_fields(self.fields, context)?)]
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Field {
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+pub struct Enum {
+    /// Is this rendered as a Go interface, with a struct for each variant?  If not, it's an
+    /// integer type with a constant for each variant.
+    #[map_node(context.is_interface_enum(&self.name))]
+    pub is_interface: bool,
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    #[map_node(enums::map_variants(
+        &self.name,
+        self.variants,
+        self.self_type.is_used_as_error,
+        context
+    )?)]
+    pub variants: Vec<Variant>,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    pub discr_type: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub constructors: Vec<Constructor>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    /// Constant name for flat enums, struct name otherwise
+    pub type_name: String,
+    pub name: String,
+    /// The discriminant, rendered as a Go literal
+    pub discr: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    /// Name of the struct that wraps the handle
+    #[map_node(names::object_struct_name(&self.name, self.imp.is_trait_interface()))]
+    pub name: String,
+    /// Name of the Go interface for trait interfaces
+    #[map_node(names::type_name(&self.name))]
+    pub interface_name: String,
+    pub constructors: Vec<Constructor>,
+    pub docstring: Option<String>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    #[map_node(ffi_types::vtable_struct_name(&self)?)]
+    pub struct_name: String,
+    /// Go function that registers the VTable with Rust
+    #[map_node(ffi_types::vtable_register_fn_name(&self))]
+    pub register_fn_name: String,
+    #[map_node(ffi_types::vtable_free_fn(&self, context)?)]
+    pub free_fn: ExportedFunction,
+    #[map_node(ffi_types::vtable_clone_fn(&self, context)?)]
+    pub clone_fn: ExportedFunction,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// Name of the VTable struct field for this method
+    #[map_node(ffi_types::vtable_method_field_name(&self, context)?)]
+    pub ffi_field_name: String,
+    /// Exported Go function that implements the method
+    #[map_node(ffi_types::vtable_method_fn(&self, context)?)]
+    pub exported_fn: ExportedFunction,
+    /// C helper that calls the future callback of async methods
+    #[map_node(ffi_types::future_callback_caller(&self, context)?)]
+    pub future_callback_caller: Option<String>,
+    pub callable: Callable,
+}
+
+/// Go function that's exported to C, so that Rust can call it through a function pointer
+#[derive(Debug, Clone, Node)]
+pub struct ExportedFunction {
+    pub name: String,
+    /// C declaration for the header
+    pub c_declaration: String,
+    /// Function pointer type that the function gets stored as
+    pub fn_type: String,
+    /// Go parameter list
+    pub go_params: String,
+    /// Go return type, with a leading space, or an empty string for void functions
+    pub go_return: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(context.custom_type_config(&self.name, &self.orig_name)?.cloned())]
+    pub config: Option<CustomTypeConfig>,
+    #[map_node(names::type_name(&self.name))]
+    pub name: String,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::IteratorType))]
+pub struct IteratorType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: Option<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    pub name: String,
+    pub namespace: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    /// Go type
+    #[map_node(types::type_name(&self.ty, context)?)]
+    pub type_name: String,
+    /// Struct that converts values of this type
+    #[map_node(types::ffi_converter_name(&self))]
+    pub ffi_converter_name: String,
+    /// Expression that lifts a value, see `TypeNode::lift`
+    #[map_node(types::lift(types::NAME_PLACEHOLDER, &self))]
+    pub lift_expr: String,
+    /// Expression that lowers a value, see `TypeNode::lower`
+    #[map_node(types::lower(types::NAME_PLACEHOLDER, &self))]
+    pub lower_expr: String,
+    /// Can `nil` represent a missing value?
+    #[map_node(types::is_nilable(&self.ty, context)?)]
+    pub is_nilable: bool,
+    pub canonical_name: String,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// cgo type, used in Go code
+    #[map_node(ffi_types::ffi_type_name(&self))]
+    pub type_name: String,
+    /// C type, used in the header
+    #[map_node(ffi_types::c_type_name(&self))]
+    pub c_type_name: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    pub name: RustFfiFunctionName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    /// Name of the C typedef
+    #[map_node(names::ffi_struct_name(&self.name.0))]
+    pub type_name: String,
+    /// C helper that calls function pointers of this type
+    #[map_node(ffi_types::fn_pointer_caller_name(&self.name.0, context)?)]
+    pub caller_name: String,
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    #[map_node(names::ffi_struct_name(&self.name.0))]
+    pub name: String,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    #[map_node(names::c_field_name(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Module {
+    /// All VTables in the module, the header declares the Go functions that implement them
+    pub fn vtables(&self) -> Vec<&VTable> {
+        self.type_definitions
+            .iter()
+            .filter_map(|type_def| match type_def {
+                TypeDefinition::Interface(int) => int.vtable.as_ref(),
+                TypeDefinition::CallbackInterface(cbi) => Some(&cbi.vtable),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Interface {
+    /// Receiver for method declarations
+    pub fn receiver(&self) -> String {
+        format!("(_self *{}) ", self.name)
+    }
+}
+
+impl Record {
+    /// Receiver for method declarations
+    pub fn receiver(&self) -> String {
+        format!("(_self {}) ", self.name)
+    }
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn throws(&self) -> bool {
+        self.throws_type.ty.is_some()
+    }
+
+    /// Type of `self` for methods
+    pub fn self_type(&self) -> Option<&TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type } | CallableKind::VTableMethod { self_type, .. } => {
+                Some(self_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Expression that lowers `self` for methods
+    ///
+    /// Objects pass a cloned handle, records and enums lower `self_expr`.
+    pub fn ffi_self_arg(&self, self_expr: &str) -> Option<String> {
+        self.self_type().map(|self_type| match &self_type.ty {
+            Type::Interface { .. } => format!("{self_expr}.uniffiCloneHandle()"),
+            _ => self_type.lower(self_expr),
+        })
+    }
+
+    /// Go return types for the function declaration, with a leading space
+    ///
+    /// Functions that throw add an `error` result.
+    pub fn return_type_decl(&self) -> String {
+        match (&self.return_type.ty, self.throws()) {
+            (Some(ty), false) => format!(" {}", ty.type_name),
+            (Some(ty), true) => format!(" ({}, error)", ty.type_name),
+            (None, false) => String::new(),
+            (None, true) => " error".to_string(),
+        }
+    }
+
+    /// cgo type that the Rust FFI function returns, for async functions this is what
+    /// `RustFutureComplete` returns.
+    pub fn ffi_return_type_name(&self) -> String {
+        match &self.return_type.ty {
+            Some(ty) => ty.ffi_type.type_name.clone(),
+            None => "struct{}".to_string(),
+        }
+    }
+}
+
+impl Argument {
+    /// Expression that lowers this argument when calling a Rust function
+    pub fn lower_arg(&self) -> String {
+        if self.is_borrowed_bytes {
+            format!("uniffiForeignBytes({})", self.name)
+        } else {
+            self.ty.lower(&self.name)
+        }
+    }
+}
+
+impl TypeNode {
+    /// Expression that lowers `nm` so that it can be passed across the FFI
+    pub fn lower(&self, nm: &str) -> String {
+        self.lower_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+
+    /// Expression that lifts `nm` after it was returned from the FFI
+    pub fn lift(&self, nm: &str) -> String {
+        self.lift_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+}
+
+impl Enum {
+    /// Receiver for method declarations on flat enums
+    pub fn receiver(&self) -> String {
+        format!("(_self {}) ", self.name)
+    }
+
+    /// First parameter for the functions that implement methods of interface enums
+    pub fn self_param(&self) -> String {
+        format!("_self {}", self.name)
+    }
+
+    /// Receiver for method declarations on the struct for an interface enum variant
+    ///
+    /// Errors use pointer receivers, so that `errors.As` can match the variant structs.
+    pub fn variant_receiver(&self, variant: &Variant) -> String {
+        if self.self_type.is_used_as_error {
+            format!("(_self *{}) ", variant.type_name)
+        } else {
+            format!("(_self {}) ", variant.type_name)
+        }
+    }
+
+    /// Does any variant have fields?  If not, the `Write` method doesn't need to bind the variant.
+    pub fn has_variant_fields(&self) -> bool {
+        self.variants.iter().any(|v| v.has_fields())
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+
+    /// Error message for non-flat error variants, this lists the field values
+    pub fn error_message_expr(&self) -> String {
+        if self.fields.is_empty() {
+            return format!("\"{}\"", self.type_name);
+        }
+        let format_str = self
+            .fields
+            .iter()
+            .map(|f| format!("{}=%v", f.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let args = self
+            .fields
+            .iter()
+            .map(|f| format!(", err.{}", f.name))
+            .collect::<String>();
+        format!("fmt.Sprintf(\"{}({format_str})\"{args})", self.type_name)
+    }
+}
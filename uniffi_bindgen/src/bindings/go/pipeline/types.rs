@@ -0,0 +1,216 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Placeholder for the value in the lift/lower expressions stored in `TypeNode`
+///
+/// This can't be `{}`, since that's also how the expressions create the converter structs.
+pub const NAME_PLACEHOLDER: &str = "{value}";
+
+/// Can the Go bindings render a type used in `namespace`?
+///
+/// Items that use other types are skipped, see `general::remove_unsupported_items`.  This
+/// includes types from other namespaces, since the bindings don't support external types yet.
+pub fn is_supported(namespace: &str, ty: &Type) -> bool {
+    match ty {
+        Type::Stream { .. } | Type::Iterator { .. } | Type::Closure { .. } => false,
+        _ => match ty.namespace() {
+            Some(ty_namespace) => ty_namespace == namespace,
+            None => true,
+        },
+    }
+}
+
+/// Get the Go type for a type
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "bool".to_string(),
+        Type::Int8 => "int8".to_string(),
+        Type::UInt8 => "uint8".to_string(),
+        Type::Int16 => "int16".to_string(),
+        Type::UInt16 => "uint16".to_string(),
+        Type::Int32 => "int32".to_string(),
+        Type::UInt32 => "uint32".to_string(),
+        Type::Int64 => "int64".to_string(),
+        Type::UInt64 => "uint64".to_string(),
+        Type::Int128 | Type::UInt128 => "*big.Int".to_string(),
+        Type::Float32 => "float32".to_string(),
+        Type::Float64 => "float64".to_string(),
+        Type::Char => "rune".to_string(),
+        Type::String => "string".to_string(),
+        Type::Bytes => "[]byte".to_string(),
+        Type::Timestamp => "time.Time".to_string(),
+        Type::Duration => "time.Duration".to_string(),
+        Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        }
+        | Type::CallbackInterface {
+            namespace, name, ..
+        } => {
+            context.check_local_type(namespace, name)?;
+            names::type_name(name)
+        }
+        Type::Interface {
+            namespace,
+            name,
+            imp,
+            ..
+        } => {
+            context.check_local_type(namespace, name)?;
+            // Trait interfaces are Go interfaces, which are already nilable.  Other objects are
+            // passed around as pointers to the struct.
+            if imp.is_trait_interface() {
+                names::type_name(name)
+            } else {
+                format!("*{}", names::type_name(name))
+            }
+        }
+        // Custom types are declared as an alias for either the configured type or the builtin
+        Type::Custom {
+            namespace, name, ..
+        } => {
+            context.check_local_type(namespace, name)?;
+            names::type_name(name)
+        }
+        Type::Optional { inner_type } => {
+            if is_nilable(inner_type, context)? {
+                type_name(inner_type, context)?
+            } else {
+                format!("*{}", type_name(inner_type, context)?)
+            }
+        }
+        Type::Sequence { inner_type } => format!("[]{}", type_name(inner_type, context)?),
+        Type::Array { inner_type, len } => format!("[{len}]{}", type_name(inner_type, context)?),
+        // Go maps don't preserve the order, so ordered maps lose it when they're lifted.
+        Type::Map {
+            key_type,
+            value_type,
+            ..
+        } => format!(
+            "map[{}]{}",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        // There's no builtin set type, so use a slice to preserve the order for ordered sets and
+        // a map with empty values for the rest.
+        Type::Set {
+            inner_type,
+            ordered: true,
+        } => format!("[]{}", type_name(inner_type, context)?),
+        Type::Set { inner_type, .. } => {
+            format!("map[{}]struct{{}}", type_name(inner_type, context)?)
+        }
+        Type::Box { inner_type } => type_name(inner_type, context)?,
+        Type::Tuple { inner_types } => match inner_types.len() {
+            0 => "struct{}".to_string(),
+            len => format!(
+                "Tuple{len}[{}]",
+                inner_types
+                    .iter()
+                    .map(|ty| type_name(ty, context))
+                    .collect::<Result<Vec<_>>>()?
+                    .join(", ")
+            ),
+        },
+        Type::Stream { .. } | Type::Iterator { .. } | Type::Closure { .. } => {
+            bail!("{ty:?} is not yet supported in the Go bindings")
+        }
+    })
+}
+
+/// Can `nil` represent a missing value for this type?
+///
+/// Optionals of these types use the type itself, rather than a pointer to it.
+pub fn is_nilable(ty: &Type, context: &Context) -> Result<bool> {
+    Ok(match ty {
+        Type::Interface { .. } | Type::CallbackInterface { .. } => true,
+        Type::Enum { name, .. } => context.is_interface_enum(name),
+        Type::Box { inner_type } => is_nilable(inner_type, context)?,
+        Type::Custom {
+            name,
+            orig_name,
+            builtin,
+            ..
+        } => match context.custom_type_config(name, orig_name)? {
+            Some(CustomTypeConfig {
+                type_name: Some(_), ..
+            }) => false,
+            _ => is_nilable(builtin, context)?,
+        },
+        _ => false,
+    })
+}
+
+/// Name of the converter struct for a type
+pub fn ffi_converter_name(type_node: &general::TypeNode) -> String {
+    format!("ffiConverter{}", type_node.canonical_name)
+}
+
+/// Expression that lifts the FFI value `nm`
+pub fn lift(nm: &str, type_node: &general::TypeNode) -> String {
+    format!("{}{{}}.Lift({nm})", ffi_converter_name(type_node))
+}
+
+/// Expression that lowers the value `nm`
+pub fn lower(nm: &str, type_node: &general::TypeNode) -> String {
+    format!("{}{{}}.Lower({nm})", ffi_converter_name(type_node))
+}
+
+/// Map the fields of a record or enum variant
+///
+/// Tuple-style fields get the names `V0`, `V1`, etc.
+pub fn map_fields(fields: Vec<general::Field>, context: &Context) -> Result<Vec<Field>> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| {
+            Ok(Field {
+                name: if field.name.is_empty() {
+                    format!("V{i}")
+                } else {
+                    names::member_name(&field.name)
+                },
+                ty: field.ty.map_node(context)?,
+                docstring: field.docstring,
+            })
+        })
+        .collect()
+}
+
+pub fn map_throws_type(throws_type: general::ThrowsType, context: &Context) -> Result<ThrowsType> {
+    let Some(ty) = throws_type.ty else {
+        return Ok(ThrowsType {
+            ty: None,
+            error: None,
+        });
+    };
+    let converter = ffi_converter_name(&ty);
+    let error = match &ty.ty {
+        // Objects are returned as themselves, but the error buffer stores a serialized handle.
+        Type::Interface { .. } => ErrorType {
+            type_name: type_name(&ty.ty, context)?,
+            lift_expr: format!(
+                "func(uniffiBuf C.RustBuffer) error {{ return uniffiLiftFromRustBuffer(uniffiBuf, {converter}{{}}.Read) }}"
+            ),
+            lower_expr: format!("uniffiLowerIntoRustBuffer(uniffiError, {converter}{{}}.Write)"),
+        },
+        Type::Enum { .. } => ErrorType {
+            type_name: type_name(&ty.ty, context)?,
+            lift_expr: format!(
+                "func(uniffiBuf C.RustBuffer) error {{ return {} }}",
+                lift("uniffiBuf", &ty)
+            ),
+            lower_expr: lower("uniffiError", &ty),
+        },
+        _ => bail!("Invalid error type: {:?}", ty.ty),
+    };
+    Ok(ThrowsType {
+        ty: Some(ty.map_node(context)?),
+        error: Some(error),
+    })
+}
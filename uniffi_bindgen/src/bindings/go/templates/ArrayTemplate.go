@@ -0,0 +1,22 @@
+{%- let type_name = array.self_type.type_name %}
+{%- let ffi_converter_name = array.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(array.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	count := uniffiReadLength(reader)
+	if count != {{ array.len }} {
+		panic(&InternalError{Message: fmt.Sprintf("expected an array of length {{ array.len }}, found %d", count)})
+	}
+	var result {{ type_name }}
+	for i := range result {
+		result[i] = {{ array.inner.ffi_converter_name }}{}.Read(reader)
+	}
+	return result
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	uniffiWriteLength(writer, len(value))
+	for _, item := range value {
+		{{ array.inner.ffi_converter_name }}{}.Write(writer, item)
+	}
+}
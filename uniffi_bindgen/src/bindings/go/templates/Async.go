@@ -0,0 +1,49 @@
+// Poll results that Rust sends to `{{ symbol_prefix }}FutureContinuationCallback`
+const (
+	uniffiRustFuturePollReady C.int8_t = 0
+	uniffiRustFuturePollWake  C.int8_t = 1
+)
+
+// Stores the channels for `{{ symbol_prefix }}FutureContinuationCallback`
+var uniffiContinuationHandleMap = newUniffiHandleMap[chan C.int8_t]()
+
+// Exported to C so that Rust can call it when a future is ready to be polled again.
+// Send the poll result to the channel, which causes the async function to poll the future again.
+//
+//export {{ symbol_prefix }}FutureContinuationCallback
+func {{ symbol_prefix }}FutureContinuationCallback(data C.uint64_t, pollResult C.int8_t) {
+	uniffiContinuationHandleMap.remove(uint64(data)) <- pollResult
+}
+
+// Drive a Rust future to completion using the `rust_future_*` FFI functions
+//
+// This blocks the calling goroutine until the future completes, callers can run the function in
+// a goroutine to run it concurrently.
+func uniffiRustCallAsync[F any](
+	rustFuture C.uint64_t,
+	pollFunc func(C.uint64_t, C.UniffiRustFutureContinuationCallback, C.uint64_t),
+	completeFunc func(C.uint64_t, *C.RustCallStatus) F,
+	freeFunc func(C.uint64_t),
+	errorHandler func(C.RustBuffer) error,
+) (F, error) {
+	defer freeFunc(rustFuture)
+	for {
+		// Buffered, so that the continuation callback never blocks
+		continuation := make(chan C.int8_t, 1)
+		pollFunc(
+			rustFuture,
+			C.UniffiRustFutureContinuationCallback(C.{{ symbol_prefix }}FutureContinuationCallback),
+			C.uint64_t(uniffiContinuationHandleMap.insert(continuation)),
+		)
+		if <-continuation == uniffiRustFuturePollReady {
+			break
+		}
+	}
+	var uniffiStatus C.RustCallStatus
+	result := completeFunc(rustFuture, &uniffiStatus)
+	if err := uniffiCheckCallStatusWithError(uniffiStatus, errorHandler); err != nil {
+		var zero F
+		return zero, err
+	}
+	return result, nil
+}
@@ -0,0 +1,11 @@
+{%- let type_name = box_.self_type.type_name %}
+{%- let ffi_converter_name = box_.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(box_.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	return {{ box_.inner.ffi_converter_name }}{}.Read(reader)
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	{{ box_.inner.ffi_converter_name }}{}.Write(writer, value)
+}
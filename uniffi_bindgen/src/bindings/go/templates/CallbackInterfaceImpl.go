@@ -0,0 +1,61 @@
+{#
+// Exported cgo functions for the VTable of a callback interface or trait interface.  They look up
+// the Go implementation in `{{ ffi_converter_name }}HandleMap`, which the including template
+// defines along with `vtable`.
+#}
+
+//export {{ vtable.free_fn.name }}
+func {{ vtable.free_fn.name }}({{ vtable.free_fn.go_params }}){{ vtable.free_fn.go_return }} {
+	{{ ffi_converter_name }}HandleMap.remove(uint64(uniffiHandle))
+}
+
+//export {{ vtable.clone_fn.name }}
+func {{ vtable.clone_fn.name }}({{ vtable.clone_fn.go_params }}){{ vtable.clone_fn.go_return }} {
+	return C.uint64_t({{ ffi_converter_name }}HandleMap.clone(uint64(uniffiHandle)))
+}
+{%- for meth in vtable.methods %}
+{%- let callable = meth.callable %}
+{%- let exported_fn = meth.exported_fn %}
+
+//export {{ exported_fn.name }}
+func {{ exported_fn.name }}({{ exported_fn.go_params }}){{ exported_fn.go_return }} {
+	{%- match callable.async_data %}
+	{%- when None %}
+	defer uniffiRecoverCallbackPanic(uniffiCallStatus)
+	{%- call go::callback_method_call(callable, ffi_converter_name) %}{% endcall %}
+	{%- call go::callback_handle_error(callable, "uniffiCallStatus") %}{% endcall %}
+	{%- if let Some(return_type) = callable.return_type.ty %}
+	*uniffiOutReturn = {{ return_type.lower("uniffiReturnValue") }}
+	{%- endif %}
+	{%- when Some(async_data) %}
+	// Go methods are blocking, so run the method in a goroutine and complete the Rust future
+	// when it returns.
+	go func() {
+		var uniffiResult C.{{ async_data.ffi_foreign_future_result }}
+		defer func() {
+			C.{{ meth.future_callback_caller.as_ref().unwrap() }}(uniffiFutureCallback, uniffiCallbackData, uniffiResult)
+		}()
+		defer uniffiRecoverCallbackPanic(&uniffiResult.call_status)
+		{%- filter indent("\t") %}
+		{%- call go::callback_method_call(callable, ffi_converter_name) %}{% endcall %}
+		{%- call go::callback_handle_error(callable, "uniffiResult.call_status") %}{% endcall %}
+		{%- endfilter %}
+		{%- if let Some(return_type) = callable.return_type.ty %}
+		uniffiResult.return_value = {{ return_type.lower("uniffiReturnValue") }}
+		{%- endif %}
+	}()
+	{%- endmatch %}
+}
+{%- endfor %}
+
+// Register the VTable with Rust.  It's allocated with C.malloc, since cgo doesn't allow C to keep
+// a pointer to Go memory, and is never freed.
+func {{ vtable.register_fn_name }}() {
+	vtable := (*C.{{ vtable.struct_name }})(C.malloc(C.size_t(unsafe.Sizeof(C.{{ vtable.struct_name }}{}))))
+	vtable.uniffi_free = C.{{ vtable.free_fn.fn_type }}(C.{{ vtable.free_fn.name }})
+	vtable.uniffi_clone = C.{{ vtable.clone_fn.fn_type }}(C.{{ vtable.clone_fn.name }})
+	{%- for meth in vtable.methods %}
+	vtable.{{ meth.ffi_field_name }} = C.{{ meth.exported_fn.fn_type }}(C.{{ meth.exported_fn.name }})
+	{%- endfor %}
+	C.{{ vtable.init_fn.0 }}(vtable)
+}
@@ -0,0 +1,29 @@
+{%- let type_name = cbi.self_type.type_name %}
+{%- let ffi_converter_name = cbi.self_type.ffi_converter_name %}
+{%- let vtable = cbi.vtable %}
+{% call go::docstring(cbi, 0) %}{% endcall %}
+type {{ cbi.name }} interface {
+	{%- call go::interface_method_decls(cbi.methods) %}{% endcall %}
+}
+
+var {{ ffi_converter_name }}HandleMap = newUniffiHandleMap[{{ type_name }}]()
+
+// Lowering stores the Go implementation in the handle map and passes its handle to Rust.
+type {{ ffi_converter_name }} struct{}
+
+func ({{ ffi_converter_name }}) Lift(value C.uint64_t) {{ type_name }} {
+	return {{ ffi_converter_name }}HandleMap.get(uint64(value))
+}
+
+func ({{ ffi_converter_name }}) Lower(value {{ type_name }}) C.uint64_t {
+	return C.uint64_t({{ ffi_converter_name }}HandleMap.insert(value))
+}
+
+func (c {{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	return c.Lift(C.uint64_t(uniffiRead[uint64](reader)))
+}
+
+func (c {{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	uniffiWrite(writer, uint64(c.Lower(value)))
+}
+{% include "CallbackInterfaceImpl.go" %}
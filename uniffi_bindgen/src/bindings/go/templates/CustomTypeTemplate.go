@@ -0,0 +1,55 @@
+{%- let builtin = custom.builtin %}
+{%- let type_name = custom.self_type.type_name %}
+{%- let ffi_converter_name = custom.self_type.ffi_converter_name %}
+{%- let ffi_type_name = builtin.ffi_type.type_name %}
+{% call go::docstring(custom, 0) %}{% endcall %}
+{%- match custom.config %}
+{%- when None %}
+{#- Without a config, `{{ custom.name }}` is a Go alias for the builtin type #}
+type {{ custom.name }} = {{ builtin.type_name }}
+
+type {{ ffi_converter_name }} struct{}
+
+func ({{ ffi_converter_name }}) Lift(value {{ ffi_type_name }}) {{ type_name }} {
+	return {{ builtin.lift("value") }}
+}
+
+func ({{ ffi_converter_name }}) Lower(value {{ type_name }}) {{ ffi_type_name }} {
+	return {{ builtin.lower("value") }}
+}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	return {{ builtin.ffi_converter_name }}{}.Read(reader)
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	{{ builtin.ffi_converter_name }}{}.Write(writer, value)
+}
+
+{%- when Some(config) %}
+{#- With a config, `{{ custom.name }}` aliases the configured Go type and `lift`/`lower`
+	convert it to and from the builtin type #}
+type {{ custom.name }} = {{ config.type_name.as_deref().unwrap_or(builtin.type_name.as_str()) }}
+
+type {{ ffi_converter_name }} struct{}
+
+func ({{ ffi_converter_name }}) Lift(value {{ ffi_type_name }}) {{ type_name }} {
+	builtinValue := {{ builtin.lift("value") }}
+	return {{ config.lift("builtinValue") }}
+}
+
+func ({{ ffi_converter_name }}) Lower(value {{ type_name }}) {{ ffi_type_name }} {
+	builtinValue := {{ config.lower("value") }}
+	return {{ builtin.lower("builtinValue") }}
+}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	builtinValue := {{ builtin.ffi_converter_name }}{}.Read(reader)
+	return {{ config.lift("builtinValue") }}
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	builtinValue := {{ config.lower("value") }}
+	{{ builtin.ffi_converter_name }}{}.Write(writer, builtinValue)
+}
+{%- endmatch %}
@@ -0,0 +1,108 @@
+{%- let type_name = e.self_type.type_name %}
+{%- let ffi_converter_name = e.self_type.ffi_converter_name %}
+{%- if e.is_flat %}
+{% call go::docstring(e, 0) %}{% endcall %}
+type {{ e.name }} {{ e.discr_type.type_name }}
+
+const (
+	{%- for variant in e.variants %}
+	{%- call go::docstring(variant, 1) %}{% endcall %}
+	{{ variant.type_name }} {{ e.name }} = {{ variant.discr }}
+	{%- endfor %}
+)
+{%- for cons in e.constructors %}
+{%- call go::func_decl("", "", cons) %}{% endcall %}
+{%- endfor %}
+{%- for meth in e.static_methods %}
+{%- call go::func_decl("", "", meth) %}{% endcall %}
+{%- endfor %}
+{%- for meth in e.methods %}
+{%- call go::func_decl(e.receiver(), "", meth) %}{% endcall %}
+{%- endfor %}
+{%- call go::uniffi_trait_impls(e.uniffi_trait_methods, e.receiver()) %}{% endcall %}
+{% call go::rust_buffer_converter(e.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	switch index := uniffiRead[int32](reader); index {
+	{%- for variant in e.variants %}
+	case {{ loop.index }}:
+		return {{ variant.type_name }}
+	{%- endfor %}
+	default:
+		panic(&InternalError{Message: fmt.Sprintf("invalid enum value: %d", index)})
+	}
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	switch value {
+	{%- for variant in e.variants %}
+	case {{ variant.type_name }}:
+		uniffiWrite(writer, int32({{ loop.index }}))
+	{%- endfor %}
+	default:
+		panic(&InternalError{Message: fmt.Sprintf("invalid enum value: %d", value)})
+	}
+}
+
+{%- else %}
+{% call go::docstring(e, 0) %}{% endcall %}
+type {{ e.name }} interface {
+	is{{ e.name }}()
+	{%- call go::uniffi_trait_decls(e.uniffi_trait_methods) %}{% endcall %}
+}
+{%- for variant in e.variants %}
+{% call go::docstring(variant, 0) %}{% endcall %}
+type {{ variant.type_name }} struct {
+	{%- for field in variant.fields %}
+	{%- call go::docstring(field, 1) %}{% endcall %}
+	{{ field.name }} {{ field.ty.type_name }}
+	{%- endfor %}
+}
+
+func {{ e.variant_receiver(variant) }}is{{ e.name }}() {}
+{%- call go::uniffi_trait_impls(e.uniffi_trait_methods, e.variant_receiver(variant)) %}{% endcall %}
+{%- endfor %}
+{%- for cons in e.constructors %}
+{%- call go::func_decl("", "", cons) %}{% endcall %}
+{%- endfor %}
+{%- for meth in e.static_methods %}
+{%- call go::func_decl("", "", meth) %}{% endcall %}
+{%- endfor %}
+{%- for meth in e.methods %}
+{%- call go::func_decl("", e.self_param(), meth) %}{% endcall %}
+{%- endfor %}
+{% call go::rust_buffer_converter(e.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	switch index := uniffiRead[int32](reader); index {
+	{%- for variant in e.variants %}
+	case {{ loop.index }}:
+		{%- if variant.fields.is_empty() %}
+		return {{ variant.type_name }}{}
+		{%- else %}
+		return {{ variant.type_name }}{
+			{%- for field in variant.fields %}
+			{{ field.name }}: {{ field.ty.ffi_converter_name }}{}.Read(reader),
+			{%- endfor %}
+		}
+		{%- endif %}
+	{%- endfor %}
+	default:
+		panic(&InternalError{Message: fmt.Sprintf("invalid enum value: %d", index)})
+	}
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	switch {% if e.has_variant_fields() %}variant := {% endif %}value.(type) {
+	{%- for variant in e.variants %}
+	case {{ variant.type_name }}:
+		uniffiWrite(writer, int32({{ loop.index }}))
+		{%- for field in variant.fields %}
+		{{ field.ty.ffi_converter_name }}{}.Write(writer, variant.{{ field.name }})
+		{%- endfor %}
+	{%- endfor %}
+	default:
+		panic(&InternalError{Message: fmt.Sprintf("invalid enum value: %v", value)})
+	}
+}
+{%- endif %}
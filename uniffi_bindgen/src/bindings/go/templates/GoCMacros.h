@@ -0,0 +1,10 @@
+{#
+// Template macros that are shared between the C header and helpers.
+#}
+
+{#- Signature of the C helper that calls a function pointer #}
+{%- macro c_helper_signature(callback) %}
+{%- match callback.return_type.ty %}{% when Some(return_type) %}{{ return_type.c_type_name }}{% when None %}void{% endmatch %} {{ callback.caller_name }}({{ callback.type_name }} uniffiFn
+{%- for arg in callback.arguments %}, {{ arg.ty.c_type_name }} a{{ loop.index0 }}{% endfor %}
+{%- if callback.has_rust_call_status_arg %}, RustCallStatus* uniffiCallStatus{% endif %})
+{%- endmacro %}
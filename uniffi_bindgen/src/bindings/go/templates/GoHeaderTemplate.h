@@ -0,0 +1,75 @@
+// Code generated by uniffi. DO NOT EDIT.
+
+#pragma once
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct RustBuffer {
+    uint64_t capacity;
+    uint64_t len;
+    uint8_t* data;
+} RustBuffer;
+
+typedef struct ForeignBytes {
+    int32_t len;
+    const uint8_t* data;
+} ForeignBytes;
+
+typedef struct RustCallStatus {
+    int8_t code;
+    RustBuffer errorBuf;
+} RustCallStatus;
+{%- for def in module.ffi_definitions %}
+{%- match def %}
+{%- when FfiDefinition::FunctionType(callback) %}
+
+typedef {% match callback.return_type.ty %}{% when Some(return_type) %}{{ return_type.c_type_name }}{% when None %}void{% endmatch %} (*{{ callback.type_name }})(
+    {%- for arg in callback.arguments -%}
+    {{ arg.ty.c_type_name }}
+    {%- if !loop.last || callback.has_rust_call_status_arg %}, {% endif %}
+    {%- endfor -%}
+    {%- if callback.has_rust_call_status_arg %}RustCallStatus*{% endif -%}
+);
+{%- when FfiDefinition::Struct(struct_item) %}
+
+typedef struct {{ struct_item.name }} {
+    {%- for field in struct_item.fields %}
+    {{ field.ty.c_type_name }} {{ field.name }};
+    {%- endfor %}
+} {{ struct_item.name }};
+{%- when FfiDefinition::RustFunction(func) %}
+
+{% match func.return_type.ty %}{% when Some(return_type) %}{{ return_type.c_type_name }}{% when None %}void{% endmatch %} {{ func.name.0 }}(
+    {%- for arg in func.arguments -%}
+    {{ arg.ty.c_type_name }} {{ arg.name }}
+    {%- if !loop.last || func.has_rust_call_status_arg %}, {% endif %}
+    {%- endfor -%}
+    {%- if func.has_rust_call_status_arg %}RustCallStatus* uniffiCallStatus{% endif -%}
+    {%- if func.arguments.is_empty() && !func.has_rust_call_status_arg %}void{% endif -%}
+);
+{%- endmatch %}
+{%- endfor %}
+
+// Go functions that are exported to C, these are passed to Rust as function pointers.  The
+// declarations must match the ones that cgo generates.
+{%- if module.has_async_fns %}
+void {{ module.symbol_prefix }}FutureContinuationCallback(uint64_t, int8_t);
+{%- endif %}
+{%- for vtable in module.vtables() %}
+{{ vtable.free_fn.c_declaration }}
+{{ vtable.clone_fn.c_declaration }}
+{%- for meth in vtable.methods %}
+{{ meth.exported_fn.c_declaration }}
+{%- endfor %}
+{%- endfor %}
+
+// cgo can't call function pointers, so these C helpers call them on behalf of the Go code.
+{%- for def in module.ffi_definitions %}
+{%- if let FfiDefinition::FunctionType(callback) = def %}
+{% call c::c_helper_signature(callback) %}{% endcall %};
+{%- endif %}
+{%- endfor %}
+
+{% import "GoCMacros.h" as c %}
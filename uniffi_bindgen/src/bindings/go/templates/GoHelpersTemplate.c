@@ -0,0 +1,15 @@
+// Code generated by uniffi. DO NOT EDIT.
+
+#include "{{ module.package_name }}.h"
+{%- for def in module.ffi_definitions %}
+{%- if let FfiDefinition::FunctionType(callback) = def %}
+
+{% call c::c_helper_signature(callback) %}{% endcall %} {
+    {% if callback.return_type.ty.is_some() %}return {% endif %}uniffiFn(
+    {%- for arg in callback.arguments %}a{{ loop.index0 }}{% if !loop.last || callback.has_rust_call_status_arg %}, {% endif %}{% endfor %}
+    {%- if callback.has_rust_call_status_arg %}uniffiCallStatus{% endif %});
+}
+{%- endif %}
+{%- endfor %}
+
+{% import "GoCMacros.h" as c %}
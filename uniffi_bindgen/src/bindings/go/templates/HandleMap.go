@@ -0,0 +1,46 @@
+// Map handles to Go objects that are passed to Rust
+//
+// Handles are always odd, so that Rust can tell them apart from the handles it generates.
+type uniffiHandleMap[T any] struct {
+	lock    sync.Mutex
+	objects map[uint64]T
+	next    uint64
+}
+
+func newUniffiHandleMap[T any]() *uniffiHandleMap[T] {
+	return &uniffiHandleMap[T]{objects: map[uint64]T{}, next: 1}
+}
+
+func (m *uniffiHandleMap[T]) insert(obj T) uint64 {
+	m.lock.Lock()
+	defer m.lock.Unlock()
+	handle := m.next
+	m.next += 2
+	m.objects[handle] = obj
+	return handle
+}
+
+func (m *uniffiHandleMap[T]) get(handle uint64) T {
+	m.lock.Lock()
+	defer m.lock.Unlock()
+	obj, ok := m.objects[handle]
+	if !ok {
+		panic(&InternalError{Message: fmt.Sprintf("invalid handle: %d", handle)})
+	}
+	return obj
+}
+
+func (m *uniffiHandleMap[T]) clone(handle uint64) uint64 {
+	return m.insert(m.get(handle))
+}
+
+func (m *uniffiHandleMap[T]) remove(handle uint64) T {
+	m.lock.Lock()
+	defer m.lock.Unlock()
+	obj, ok := m.objects[handle]
+	if !ok {
+		panic(&InternalError{Message: fmt.Sprintf("invalid handle: %d", handle)})
+	}
+	delete(m.objects, handle)
+	return obj
+}
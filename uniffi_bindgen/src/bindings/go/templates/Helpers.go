@@ -0,0 +1,63 @@
+// InternalError is the panic value for unexpected errors, like Rust panics or invalid data from
+// the FFI.
+type InternalError struct {
+	Message string
+}
+
+func (err *InternalError) Error() string {
+	return err.Message
+}
+
+// Values for RustCallStatus.code
+const (
+	uniffiCallStatusSuccess         C.int8_t = 0
+	uniffiCallStatusError           C.int8_t = 1
+	uniffiCallStatusUnexpectedError C.int8_t = 2
+	uniffiCallStatusCancelled       C.int8_t = 3
+)
+
+// Check the status of a call to a Rust function that doesn't return errors
+func uniffiCheckCallStatus(status C.RustCallStatus) {
+	_ = uniffiCheckCallStatusWithError(status, nil)
+}
+
+// Check the status of a call to a Rust function
+//
+// Errors are lifted with `errorHandler` and returned, Rust panics cause a Go panic.
+func uniffiCheckCallStatusWithError(status C.RustCallStatus, errorHandler func(C.RustBuffer) error) error {
+	switch status.code {
+	case uniffiCallStatusSuccess:
+		return nil
+	case uniffiCallStatusError:
+		if errorHandler != nil {
+			return errorHandler(status.errorBuf)
+		}
+		uniffiRustBufferFree(status.errorBuf)
+		panic(&InternalError{Message: "unexpected error status from Rust"})
+	case uniffiCallStatusUnexpectedError:
+		// When the Rust code panics, the buffer holds the panic message
+		if status.errorBuf.len > 0 {
+			panic(&InternalError{Message: string(uniffiRustBufferConsumeBytes(status.errorBuf))})
+		}
+		uniffiRustBufferFree(status.errorBuf)
+		panic(&InternalError{Message: "Rust panic"})
+	case uniffiCallStatusCancelled:
+		panic(&InternalError{Message: "cancelled"})
+	default:
+		panic(&InternalError{Message: fmt.Sprintf("unknown RustCallStatus code: %d", status.code)})
+	}
+}
+
+// Deferred by the functions that Rust calls, so that Go panics are reported back to Rust rather
+// than crashing the program.
+func uniffiRecoverCallbackPanic(status *C.RustCallStatus) {
+	if r := recover(); r != nil {
+		status.code = uniffiCallStatusUnexpectedError
+		status.errorBuf = uniffiRustBufferFromBytes([]byte(fmt.Sprint(r)))
+	}
+}
+
+// Get a pointer to a value, for optional values
+func uniffiPtr[T any](value T) *T {
+	return &value
+}
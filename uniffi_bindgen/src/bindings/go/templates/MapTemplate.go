@@ -0,0 +1,21 @@
+{%- let type_name = map.self_type.type_name %}
+{%- let ffi_converter_name = map.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(map.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	count := uniffiReadLength(reader)
+	result := make({{ type_name }}, count)
+	for i := 0; i < count; i++ {
+		key := {{ map.key.ffi_converter_name }}{}.Read(reader)
+		result[key] = {{ map.value.ffi_converter_name }}{}.Read(reader)
+	}
+	return result
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	uniffiWriteLength(writer, len(value))
+	for key, item := range value {
+		{{ map.key.ffi_converter_name }}{}.Write(writer, key)
+		{{ map.value.ffi_converter_name }}{}.Write(writer, item)
+	}
+}
@@ -0,0 +1,22 @@
+func init() {
+	uniffiCheckContractApiVersion()
+	uniffiCheckApiChecksums()
+	{%- for vtable in self.vtables() %}
+	{{ vtable.register_fn_name }}()
+	{%- endfor %}
+}
+
+func uniffiCheckContractApiVersion() {
+	// Compare the bindings contract version with the scaffolding contract version from the dylib
+	if C.{{ ffi_uniffi_contract_version.0 }}() != {{ correct_contract_version }} {
+		panic(&InternalError{Message: "UniFFI contract version mismatch: try cleaning and rebuilding your project"})
+	}
+}
+
+func uniffiCheckApiChecksums() {
+	{%- for checksum in checksums %}
+	if C.{{ checksum.fn_name.0 }}() != {{ checksum.checksum }} {
+		panic(&InternalError{Message: "UniFFI API checksum mismatch: try cleaning and rebuilding your project"})
+	}
+	{%- endfor %}
+}
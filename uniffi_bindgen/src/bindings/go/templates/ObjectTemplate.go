@@ -0,0 +1,125 @@
+{%- let type_name = int.self_type.type_name %}
+{%- let ffi_converter_name = int.self_type.ffi_converter_name %}
+{%- if int.imp.is_trait_interface() %}
+{% call go::docstring(int, 0) %}{% endcall %}
+type {{ int.interface_name }} interface {
+	{%- call go::interface_method_decls(int.methods) %}{% endcall %}
+}
+{%- endif %}
+{% call go::docstring(int, 0) %}{% endcall %}
+type {{ int.name }} struct {
+	uniffiHandle    C.uint64_t
+	uniffiLock      sync.RWMutex
+	uniffiDestroyed bool
+}
+
+func uniffiNew{{ int.name }}(handle C.uint64_t) *{{ int.name }} {
+	obj := &{{ int.name }}{uniffiHandle: handle}
+	runtime.SetFinalizer(obj, (*{{ int.name }}).Destroy)
+	return obj
+}
+{%- for cons in int.constructors %}
+{%- call go::func_decl("", "", cons) %}{% endcall %}
+{%- endfor %}
+{%- for meth in int.static_methods %}
+{%- call go::func_decl("", "", meth) %}{% endcall %}
+{%- endfor %}
+{%- for meth in int.methods %}
+{%- call go::func_decl(int.receiver(), "", meth) %}{% endcall %}
+{%- endfor %}
+{%- call go::uniffi_trait_impls(int.uniffi_trait_methods, int.receiver()) %}{% endcall %}
+{%- if int.self_type.is_used_as_error %}
+
+func {{ int.receiver() }}Error() string {
+	{%- if int.uniffi_trait_methods.display_fmt.is_some() || int.uniffi_trait_methods.debug_fmt.is_some() %}
+	return _self.String()
+	{%- else %}
+	return "{{ int.name }}"
+	{%- endif %}
+}
+{%- endif %}
+
+// Destroy frees the Rust object.
+//
+// This happens automatically when the object is garbage collected, call Destroy to free it
+// deterministically.  The object can't be used after it's destroyed.
+func {{ int.receiver() }}Destroy() {
+	_self.uniffiLock.Lock()
+	defer _self.uniffiLock.Unlock()
+	if _self.uniffiDestroyed {
+		return
+	}
+	_self.uniffiDestroyed = true
+	runtime.SetFinalizer(_self, nil)
+	var uniffiStatus C.RustCallStatus
+	C.{{ int.ffi_func_free.0 }}(_self.uniffiHandle, &uniffiStatus)
+	uniffiCheckCallStatus(uniffiStatus)
+}
+
+// Rust consumes the handles it's passed, so lowering passes a clone and keeps the original.
+func {{ int.receiver() }}uniffiCloneHandle() C.uint64_t {
+	_self.uniffiLock.RLock()
+	defer _self.uniffiLock.RUnlock()
+	if _self.uniffiDestroyed {
+		panic(&InternalError{Message: "{{ int.name }} used after Destroy"})
+	}
+	var uniffiStatus C.RustCallStatus
+	handle := C.{{ int.ffi_func_clone.0 }}(_self.uniffiHandle, &uniffiStatus)
+	uniffiCheckCallStatus(uniffiStatus)
+	return handle
+}
+
+{%- match int.vtable %}
+{%- when None %}
+
+type {{ ffi_converter_name }} struct{}
+
+func ({{ ffi_converter_name }}) Lift(value C.uint64_t) {{ type_name }} {
+	return uniffiNew{{ int.name }}(value)
+}
+
+func ({{ ffi_converter_name }}) Lower(value {{ type_name }}) C.uint64_t {
+	{%- if !int.imp.is_trait_interface() %}
+	return value.uniffiCloneHandle()
+	{%- else %}
+	return value.(*{{ int.name }}).uniffiCloneHandle()
+	{%- endif %}
+}
+{%- when Some(vtable) %}
+{% include "CallbackInterfaceImpl.go" %}
+
+var {{ ffi_converter_name }}HandleMap = newUniffiHandleMap[{{ type_name }}]()
+
+// Handles from `{{ ffi_converter_name }}HandleMap` are always odd, Rust handles are always even.
+type {{ ffi_converter_name }} struct{}
+
+func ({{ ffi_converter_name }}) Lift(value C.uint64_t) {{ type_name }} {
+	if (value & 1) == 0 {
+		// Rust object, wrap the handle in a `*{{ int.name }}`
+		return uniffiNew{{ int.name }}(value)
+	}
+	// Go implementation, take it back out of the handle map
+	return {{ ffi_converter_name }}HandleMap.remove(uint64(value))
+}
+
+func ({{ ffi_converter_name }}) Lower(value {{ type_name }}) C.uint64_t {
+	if obj, ok := value.(*{{ int.name }}); ok {
+		// Rust object, pass Rust a clone of its handle
+		return obj.uniffiCloneHandle()
+	}
+	// Go implementation, store it in the handle map and pass its handle
+	return C.uint64_t({{ ffi_converter_name }}HandleMap.insert(value))
+}
+{%- endmatch %}
+
+func (c {{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	handle := uniffiRead[uint64](reader)
+	if handle == 0 {
+		panic(&InternalError{Message: "raw handle value was null"})
+	}
+	return c.Lift(C.uint64_t(handle))
+}
+
+func (c {{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	uniffiWrite(writer, uint64(c.Lower(value)))
+}
@@ -0,0 +1,27 @@
+{%- let type_name = opt.self_type.type_name %}
+{%- let ffi_converter_name = opt.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(opt.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	if uniffiRead[int8](reader) == 0 {
+		return nil
+	}
+	{%- if opt.inner.is_nilable %}
+	return {{ opt.inner.ffi_converter_name }}{}.Read(reader)
+	{%- else %}
+	return uniffiPtr({{ opt.inner.ffi_converter_name }}{}.Read(reader))
+	{%- endif %}
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	if value == nil {
+		uniffiWrite(writer, int8(0))
+		return
+	}
+	uniffiWrite(writer, int8(1))
+	{%- if opt.inner.is_nilable %}
+	{{ opt.inner.ffi_converter_name }}{}.Write(writer, value)
+	{%- else %}
+	{{ opt.inner.ffi_converter_name }}{}.Write(writer, *value)
+	{%- endif %}
+}
@@ -0,0 +1,38 @@
+{%- let type_name = rec.self_type.type_name %}
+{%- let ffi_converter_name = rec.self_type.ffi_converter_name %}
+{% call go::docstring(rec, 0) %}{% endcall %}
+type {{ rec.name }} struct {
+	{%- for field in rec.fields %}
+	{%- call go::docstring(field, 1) %}{% endcall %}
+	{{ field.name }} {{ field.ty.type_name }}
+	{%- endfor %}
+}
+{%- for cons in rec.constructors %}
+{%- call go::func_decl("", "", cons) %}{% endcall %}
+{%- endfor %}
+{%- for meth in rec.static_methods %}
+{%- call go::func_decl("", "", meth) %}{% endcall %}
+{%- endfor %}
+{%- for meth in rec.methods %}
+{%- call go::func_decl(rec.receiver(), "", meth) %}{% endcall %}
+{%- endfor %}
+{%- call go::uniffi_trait_impls(rec.uniffi_trait_methods, rec.receiver()) %}{% endcall %}
+{% call go::rust_buffer_converter(rec.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	{%- if rec.fields.is_empty() %}
+	return {{ type_name }}{}
+	{%- else %}
+	return {{ type_name }}{
+		{%- for field in rec.fields %}
+		{{ field.name }}: {{ field.ty.ffi_converter_name }}{}.Read(reader),
+		{%- endfor %}
+	}
+	{%- endif %}
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	{%- for field in rec.fields %}
+	{{ field.ty.ffi_converter_name }}{}.Write(writer, value.{{ field.name }})
+	{%- endfor %}
+}
@@ -0,0 +1,94 @@
+// Create a RustBuffer that holds a copy of `b`
+func uniffiRustBufferFromBytes(b []byte) C.RustBuffer {
+	var uniffiStatus C.RustCallStatus
+	buf := C.{{ ffi_rustbuffer_from_bytes.0 }}(uniffiForeignBytes(b), &uniffiStatus)
+	uniffiCheckCallStatus(uniffiStatus)
+	return buf
+}
+
+func uniffiRustBufferFree(buf C.RustBuffer) {
+	var uniffiStatus C.RustCallStatus
+	C.{{ ffi_rustbuffer_free.0 }}(buf, &uniffiStatus)
+	uniffiCheckCallStatus(uniffiStatus)
+}
+
+// Copy the contents of a RustBuffer into a byte slice, then free the RustBuffer
+func uniffiRustBufferConsumeBytes(buf C.RustBuffer) []byte {
+	b := C.GoBytes(unsafe.Pointer(buf.data), C.int(buf.len))
+	uniffiRustBufferFree(buf)
+	return b
+}
+
+// Borrow a byte slice as ForeignBytes, this is only valid for the duration of an FFI call
+func uniffiForeignBytes(b []byte) C.ForeignBytes {
+	return C.ForeignBytes{
+		len:  C.int32_t(len(b)),
+		data: (*C.uint8_t)(unsafe.SliceData(b)),
+	}
+}
+
+// Lift a value that was serialized into a RustBuffer, then free the buffer
+func uniffiLiftFromRustBuffer[T any](buf C.RustBuffer, read func(*bytes.Reader) T) T {
+	reader := bytes.NewReader(uniffiRustBufferConsumeBytes(buf))
+	value := read(reader)
+	if reader.Len() > 0 {
+		panic(&InternalError{Message: "junk remaining in buffer after lifting"})
+	}
+	return value
+}
+
+// Serialize a value into a new RustBuffer
+func uniffiLowerIntoRustBuffer[T any](value T, write func(*bytes.Buffer, T)) C.RustBuffer {
+	var buf bytes.Buffer
+	write(&buf, value)
+	return uniffiRustBufferFromBytes(buf.Bytes())
+}
+
+// Read a fixed-size value, in big-endian order
+func uniffiRead[T any](reader *bytes.Reader) T {
+	var value T
+	if err := binary.Read(reader, binary.BigEndian, &value); err != nil {
+		panic(&InternalError{Message: fmt.Sprintf("failed to read from buffer: %v", err)})
+	}
+	return value
+}
+
+// Write a fixed-size value, in big-endian order
+func uniffiWrite[T any](writer *bytes.Buffer, value T) {
+	if err := binary.Write(writer, binary.BigEndian, value); err != nil {
+		panic(&InternalError{Message: fmt.Sprintf("failed to write to buffer: %v", err)})
+	}
+}
+
+// Read the length prefix of a string, sequence, map, etc.
+func uniffiReadLength(reader *bytes.Reader) int {
+	length := uniffiRead[int32](reader)
+	if length < 0 {
+		panic(&InternalError{Message: fmt.Sprintf("invalid length: %d", length)})
+	}
+	return int(length)
+}
+
+func uniffiWriteLength(writer *bytes.Buffer, length int) {
+	if length > math.MaxInt32 {
+		panic(&InternalError{Message: fmt.Sprintf("length too large: %d", length)})
+	}
+	uniffiWrite(writer, int32(length))
+}
+
+func uniffiReadBytes(reader *bytes.Reader, length int) []byte {
+	b := make([]byte, length)
+	if _, err := io.ReadFull(reader, b); err != nil {
+		panic(&InternalError{Message: fmt.Sprintf("failed to read from buffer: %v", err)})
+	}
+	return b
+}
+
+func uniffiReadString(reader *bytes.Reader) string {
+	return string(uniffiReadBytes(reader, uniffiReadLength(reader)))
+}
+
+func uniffiWriteString(writer *bytes.Buffer, value string) {
+	uniffiWriteLength(writer, len(value))
+	writer.WriteString(value)
+}
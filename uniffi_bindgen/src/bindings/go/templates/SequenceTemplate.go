@@ -0,0 +1,19 @@
+{%- let type_name = seq.self_type.type_name %}
+{%- let ffi_converter_name = seq.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(seq.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	count := uniffiReadLength(reader)
+	result := make({{ type_name }}, 0, count)
+	for i := 0; i < count; i++ {
+		result = append(result, {{ seq.inner.ffi_converter_name }}{}.Read(reader))
+	}
+	return result
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	uniffiWriteLength(writer, len(value))
+	for _, item := range value {
+		{{ seq.inner.ffi_converter_name }}{}.Write(writer, item)
+	}
+}
@@ -0,0 +1,30 @@
+{%- let type_name = set.self_type.type_name %}
+{%- let ffi_converter_name = set.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(set.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	count := uniffiReadLength(reader)
+	{%- if set.ordered %}
+	result := make({{ type_name }}, 0, count)
+	for i := 0; i < count; i++ {
+		result = append(result, {{ set.inner.ffi_converter_name }}{}.Read(reader))
+	}
+	{%- else %}
+	result := make({{ type_name }}, count)
+	for i := 0; i < count; i++ {
+		result[{{ set.inner.ffi_converter_name }}{}.Read(reader)] = struct{}{}
+	}
+	{%- endif %}
+	return result
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	uniffiWriteLength(writer, len(value))
+	{%- if set.ordered %}
+	for _, item := range value {
+	{%- else %}
+	for item := range value {
+	{%- endif %}
+		{{ set.inner.ffi_converter_name }}{}.Write(writer, item)
+	}
+}
@@ -0,0 +1,7 @@
+{%- for func in functions %}
+{%- call go::func_decl("", "", func) %}{% endcall %}
+{%- endfor %}
+{%- for constant in constants %}
+{% call go::docstring(constant, 0) %}{% endcall %}
+{% if constant.is_const %}const{% else %}var{% endif %} {{ constant.name }} {{ constant.ty.type_name }} = {{ constant.value }}
+{%- endfor %}
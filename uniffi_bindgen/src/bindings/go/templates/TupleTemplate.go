@@ -0,0 +1,17 @@
+{%- let type_name = tuple.self_type.type_name %}
+{%- let ffi_converter_name = tuple.self_type.ffi_converter_name %}
+{%- call go::rust_buffer_converter(tuple.self_type) %}{% endcall %}
+
+func ({{ ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_name }} {
+	return {{ type_name }}{
+		{%- for inner in tuple.inners %}
+		{{ inner.ffi_converter_name }}{}.Read(reader),
+		{%- endfor %}
+	}
+}
+
+func ({{ ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_name }}) {
+	{%- for inner in tuple.inners %}
+	{{ inner.ffi_converter_name }}{}.Write(writer, value.V{{ loop.index0 }})
+	{%- endfor %}
+}
@@ -0,0 +1,122 @@
+{#
+ # Package-level Go types: the generic `TupleN` structs, then the Go type and converter struct
+ # for each type definition.
+ #}
+{%- for size in tuple_sizes.iter().copied() %}
+
+// Generic tuple type with {{ size }} elements
+type Tuple{{ size }}[{% for i in 0..size %}T{{ i }}{% if i + 1 < size %}, {% endif %}{% endfor %} any] struct {
+{%- for i in 0..size %}
+	V{{ i }} T{{ i }}
+{%- endfor %}
+}
+{%- endfor %}
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
+
+{%- when Type::Boolean %}
+{% include "builtins/Boolean.go" %}
+
+{%- when Type::Char %}
+{% include "builtins/Char.go" %}
+
+{%- when Type::Int8 %}
+{% include "builtins/Int8.go" %}
+
+{%- when Type::Int16 %}
+{% include "builtins/Int16.go" %}
+
+{%- when Type::Int32 %}
+{% include "builtins/Int32.go" %}
+
+{%- when Type::Int64 %}
+{% include "builtins/Int64.go" %}
+
+{%- when Type::Int128 %}
+{% include "builtins/Int128.go" %}
+
+{%- when Type::UInt8 %}
+{% include "builtins/UInt8.go" %}
+
+{%- when Type::UInt16 %}
+{% include "builtins/UInt16.go" %}
+
+{%- when Type::UInt32 %}
+{% include "builtins/UInt32.go" %}
+
+{%- when Type::UInt64 %}
+{% include "builtins/UInt64.go" %}
+
+{%- when Type::UInt128 %}
+{% include "builtins/UInt128.go" %}
+
+{%- when Type::Float32 %}
+{% include "builtins/Float32.go" %}
+
+{%- when Type::Float64 %}
+{% include "builtins/Float64.go" %}
+
+{%- when Type::String %}
+{% include "builtins/String.go" %}
+
+{%- when Type::Bytes %}
+{% include "builtins/Bytes.go" %}
+
+{%- when Type::Timestamp %}
+{% include "builtins/Timestamp.go" %}
+
+{%- when Type::Duration %}
+{% include "builtins/Duration.go" %}
+
+{%- else %}
+{# The pipeline only creates simple type definitions for the builtins above #}
+{%- endmatch %}
+
+{%- when TypeDefinition::Box(box_) %}
+{% include "BoxTemplate.go" %}
+
+{%- when TypeDefinition::Optional(opt) %}
+{% include "OptionalTemplate.go" %}
+
+{%- when TypeDefinition::Sequence(seq) %}
+{% include "SequenceTemplate.go" %}
+
+{%- when TypeDefinition::Array(array) %}
+{% include "ArrayTemplate.go" %}
+
+{%- when TypeDefinition::Map(map) %}
+{% include "MapTemplate.go" %}
+
+{%- when TypeDefinition::Set(set) %}
+{% include "SetTemplate.go" %}
+
+{%- when TypeDefinition::Tuple(tuple) %}
+{% include "TupleTemplate.go" %}
+
+{%- when TypeDefinition::Enum(e) %}
+{#- Enums used as errors implement the `error` interface, other enums are plain Go types. #}
+{%- if e.self_type.is_used_as_error %}
+{% include "ErrorTemplate.go" %}
+{%- else %}
+{% include "EnumTemplate.go" %}
+{%- endif %}
+
+{%- when TypeDefinition::Record(rec) %}
+{% include "RecordTemplate.go" %}
+
+{%- when TypeDefinition::Interface(int) %}
+{% include "ObjectTemplate.go" %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{% include "CallbackInterfaceTemplate.go" %}
+
+{%- when TypeDefinition::Custom(custom) %}
+{% include "CustomTypeTemplate.go" %}
+
+{%- else %}
+{#- External, stream, iterator and closure types are rejected by the Go pipeline #}
+{%- endmatch %}
+{%- endfor %}
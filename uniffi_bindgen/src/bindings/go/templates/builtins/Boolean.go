@@ -0,0 +1,22 @@
+
+// Booleans are passed across the FFI as an int8
+type {{ type_node.ffi_converter_name }} struct{}
+
+func ({{ type_node.ffi_converter_name }}) Lift(value C.int8_t) bool {
+	return value != 0
+}
+
+func ({{ type_node.ffi_converter_name }}) Lower(value bool) C.int8_t {
+	if value {
+		return 1
+	}
+	return 0
+}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) bool {
+	return uniffiRead[int8](reader) != 0
+}
+
+func (c {{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value bool) {
+	uniffiWrite(writer, int8(c.Lower(value)))
+}
@@ -0,0 +1,10 @@
+{%- call go::rust_buffer_converter(type_node) %}{% endcall %}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) []byte {
+	return uniffiReadBytes(reader, uniffiReadLength(reader))
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value []byte) {
+	uniffiWriteLength(writer, len(value))
+	writer.Write(value)
+}
@@ -0,0 +1,19 @@
+
+// Chars are passed across the FFI as the uint32 code point
+type {{ type_node.ffi_converter_name }} struct{}
+
+func ({{ type_node.ffi_converter_name }}) Lift(value C.uint32_t) rune {
+	return rune(value)
+}
+
+func ({{ type_node.ffi_converter_name }}) Lower(value rune) C.uint32_t {
+	return C.uint32_t(value)
+}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) rune {
+	return rune(uniffiRead[uint32](reader))
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value rune) {
+	uniffiWrite(writer, uint32(value))
+}
@@ -0,0 +1,21 @@
+
+// Durations are serialized as unsigned seconds and nanoseconds.  `time.Duration` is a signed
+// number of nanoseconds, so it can't hold durations longer than about 292 years.
+{%- call go::rust_buffer_converter(type_node) %}{% endcall %}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) time.Duration {
+	seconds := uniffiRead[uint64](reader)
+	nanoseconds := uniffiRead[uint32](reader)
+	if seconds > uint64(math.MaxInt64/time.Second) {
+		panic(&InternalError{Message: fmt.Sprintf("duration too large: %ds", seconds)})
+	}
+	return time.Duration(seconds)*time.Second + time.Duration(nanoseconds)
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value time.Duration) {
+	if value < 0 {
+		panic(&InternalError{Message: fmt.Sprintf("negative duration: %v", value)})
+	}
+	uniffiWrite(writer, uint64(value/time.Second))
+	uniffiWrite(writer, uint32(value%time.Second))
+}
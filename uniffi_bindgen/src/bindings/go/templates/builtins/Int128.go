@@ -0,0 +1,26 @@
+
+// 128-bit integers don't fit in a C type, so they're passed in a RustBuffer as 16 big-endian
+// bytes, in two's complement.
+{%- call go::rust_buffer_converter(type_node) %}{% endcall %}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) *big.Int {
+	b := uniffiReadBytes(reader, 16)
+	value := new(big.Int).SetBytes(b)
+	if b[0]&0x80 != 0 {
+		value.Sub(value, new(big.Int).Lsh(big.NewInt(1), 128))
+	}
+	return value
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value *big.Int) {
+	// Values in range have a bit length of at most 127, except for the minimum value -2^127
+	minValue := new(big.Int).Neg(new(big.Int).Lsh(big.NewInt(1), 127))
+	if value.BitLen() > 127 && value.Cmp(minValue) != 0 {
+		panic(&InternalError{Message: fmt.Sprintf("value out of range for i128: %v", value)})
+	}
+	unsigned := value
+	if value.Sign() < 0 {
+		unsigned = new(big.Int).Add(value, new(big.Int).Lsh(big.NewInt(1), 128))
+	}
+	writer.Write(unsigned.FillBytes(make([]byte, 16)))
+}
@@ -0,0 +1,20 @@
+
+// Go strings are already UTF-8, so they're copied into a RustBuffer as-is, without a length
+// prefix.
+type {{ type_node.ffi_converter_name }} struct{}
+
+func ({{ type_node.ffi_converter_name }}) Lift(value C.RustBuffer) string {
+	return string(uniffiRustBufferConsumeBytes(value))
+}
+
+func ({{ type_node.ffi_converter_name }}) Lower(value string) C.RustBuffer {
+	return uniffiRustBufferFromBytes([]byte(value))
+}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) string {
+	return uniffiReadString(reader)
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value string) {
+	uniffiWriteString(writer, value)
+}
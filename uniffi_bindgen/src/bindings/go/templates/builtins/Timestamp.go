@@ -0,0 +1,24 @@
+
+// Timestamps are serialized as seconds and nanoseconds since the Unix epoch.  The sign of the
+// seconds applies to the entire value.
+{%- call go::rust_buffer_converter(type_node) %}{% endcall %}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) time.Time {
+	seconds := uniffiRead[int64](reader)
+	nanoseconds := int64(uniffiRead[uint32](reader))
+	if seconds < 0 {
+		return time.Unix(seconds, -nanoseconds)
+	}
+	return time.Unix(seconds, nanoseconds)
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value time.Time) {
+	seconds := value.Unix()
+	nanoseconds := int64(value.Nanosecond())
+	if seconds < 0 && nanoseconds > 0 {
+		seconds += 1
+		nanoseconds = 1_000_000_000 - nanoseconds
+	}
+	uniffiWrite(writer, seconds)
+	uniffiWrite(writer, uint32(nanoseconds))
+}
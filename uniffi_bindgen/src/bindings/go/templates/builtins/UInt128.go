@@ -0,0 +1,15 @@
+
+// `*big.Int` has no cgo equivalent, so 128-bit integers are passed in a RustBuffer as 16 big-endian
+// bytes.
+{%- call go::rust_buffer_converter(type_node) %}{% endcall %}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) *big.Int {
+	return new(big.Int).SetBytes(uniffiReadBytes(reader, 16))
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value *big.Int) {
+	if value.Sign() < 0 || value.BitLen() > 128 {
+		panic(&InternalError{Message: fmt.Sprintf("value out of range for u128: %v", value)})
+	}
+	writer.Write(value.FillBytes(make([]byte, 16)))
+}
@@ -0,0 +1 @@
+{%- call go::primitive_converter(type_node) %}{% endcall %}
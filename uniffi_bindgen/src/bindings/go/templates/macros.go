@@ -0,0 +1,248 @@
+{#
+// Template macros that are shared between the Go templates.
+#}
+
+{#- Converter for numeric types, which cgo passes as the matching C scalar type #}
+{%- macro primitive_converter(type_node) %}
+type {{ type_node.ffi_converter_name }} struct{}
+
+func ({{ type_node.ffi_converter_name }}) Lift(value {{ type_node.ffi_type.type_name }}) {{ type_node.type_name }} {
+	return {{ type_node.type_name }}(value)
+}
+
+func ({{ type_node.ffi_converter_name }}) Lower(value {{ type_node.type_name }}) {{ type_node.ffi_type.type_name }} {
+	return {{ type_node.ffi_type.type_name }}(value)
+}
+
+func ({{ type_node.ffi_converter_name }}) Read(reader *bytes.Reader) {{ type_node.type_name }} {
+	return uniffiRead[{{ type_node.type_name }}](reader)
+}
+
+func ({{ type_node.ffi_converter_name }}) Write(writer *bytes.Buffer, value {{ type_node.type_name }}) {
+	uniffiWrite(writer, value)
+}
+{%- endmacro %}
+
+{#- Converter struct with `Lift` and `Lower` for types that are passed across the FFI in a
+	RustBuffer.  The template defines `Read` and `Write`. #}
+{%- macro rust_buffer_converter(type_node) %}
+type {{ type_node.ffi_converter_name }} struct{}
+
+func (c {{ type_node.ffi_converter_name }}) Lift(value C.RustBuffer) {{ type_node.type_name }} {
+	return uniffiLiftFromRustBuffer(value, c.Read)
+}
+
+func (c {{ type_node.ffi_converter_name }}) Lower(value {{ type_node.type_name }}) C.RustBuffer {
+	return uniffiLowerIntoRustBuffer(value, c.Write)
+}
+{%- endmacro %}
+
+{#- Parameter list for a Go function declaration #}
+{%- macro arg_list_decl(callable) %}
+	{%- for arg in callable.arguments -%}
+	{{ arg.name }} {{ arg.ty.type_name }}
+	{%- if !loop.last %}, {% endif %}
+	{%- endfor %}
+{%- endmacro %}
+
+{#- Arguments for the cgo call to a callable's FFI function, `self_expr` is lowered for the
+	receiver of methods.  Async calls don't take a `RustCallStatus`. #}
+{%- macro ffi_call_args(callable, self_expr) %}
+	{%- let self_arg = callable.ffi_self_arg(self_expr) %}
+	{%- if let Some(self_arg) = self_arg %}
+	{{- self_arg }}
+	{%- if !callable.arguments.is_empty() || !callable.is_async() %}, {% endif %}
+	{%- endif %}
+	{%- for arg in callable.arguments %}
+	{{- arg.lower_arg() }}
+	{%- if !loop.last || !callable.is_async() %}, {% endif %}
+	{%- endfor %}
+	{%- if !callable.is_async() %}&uniffiStatus{% endif %}
+{%- endmacro %}
+
+{#- Error handler argument for `uniffiCheckCallStatusWithError` and `uniffiRustCallAsync` #}
+{%- macro error_handler(callable) %}
+	{%- match callable.throws_type.error %}
+	{%- when Some(error) %}{{ error.lift_expr }}
+	{%- when None %}nil
+	{%- endmatch %}
+{%- endmacro %}
+
+{#- Return `uniffiErr` from a function that throws #}
+{%- macro return_error(callable) %}
+		{%- match callable.return_type.ty %}
+		{%- when Some(return_type) %}
+		var uniffiZero {{ return_type.type_name }}
+		return uniffiZero, uniffiErr
+		{%- when None %}
+		return uniffiErr
+		{%- endmatch %}
+{%- endmacro %}
+
+{#- Body of a Go function or method: make the cgo call, check the status and return the
+	lifted result #}
+{%- macro call_body(callable, self_expr) %}
+	{%- match callable.async_data %}
+	{%- when None %}
+	var uniffiStatus C.RustCallStatus
+	{% if callable.return_type.ty.is_some() %}uniffiResult := {% endif -%}
+	C.{{ callable.ffi_func.0 }}({% call ffi_call_args(callable, self_expr) %}{% endcall %})
+	{%- if callable.throws() %}
+	if uniffiErr := uniffiCheckCallStatusWithError(uniffiStatus, {% call error_handler(callable) %}{% endcall %}); uniffiErr != nil {
+		{%- call return_error(callable) %}{% endcall %}
+	}
+	{%- else %}
+	uniffiCheckCallStatus(uniffiStatus)
+	{%- endif %}
+	{%- when Some(async_data) %}
+	{% if callable.return_type.ty.is_some() %}uniffiResult{% else %}_{% endif %}, {% if callable.throws() %}uniffiErr{% else %}_{% endif %}
+	{%- if callable.return_type.ty.is_some() || callable.throws() %} := {% else %} = {% endif -%}
+	uniffiRustCallAsync(
+		C.{{ callable.ffi_func.0 }}({% call ffi_call_args(callable, self_expr) %}{% endcall %}),
+		func(handle C.uint64_t, callback C.UniffiRustFutureContinuationCallback, data C.uint64_t) {
+			C.{{ async_data.ffi_rust_future_poll.0 }}(handle, callback, data)
+		},
+		func(handle C.uint64_t, status *C.RustCallStatus) {{ callable.ffi_return_type_name() }} {
+			{%- if callable.return_type.ty.is_some() %}
+			return C.{{ async_data.ffi_rust_future_complete.0 }}(handle, status)
+			{%- else %}
+			C.{{ async_data.ffi_rust_future_complete.0 }}(handle, status)
+			return struct{}{}
+			{%- endif %}
+		},
+		func(handle C.uint64_t) {
+			C.{{ async_data.ffi_rust_future_free.0 }}(handle)
+		},
+		{% call error_handler(callable) %}{% endcall %},
+	)
+	{%- if callable.throws() %}
+	if uniffiErr != nil {
+		{%- call return_error(callable) %}{% endcall %}
+	}
+	{%- endif %}
+	{%- endmatch %}
+	{%- match callable.return_type.ty %}
+	{%- when Some(return_type) %}
+	return {{ return_type.lift("uniffiResult") }}{% if callable.throws() %}, nil{% endif %}
+	{%- when None %}
+	{%- if callable.throws() %}
+	return nil
+	{%- endif %}
+	{%- endmatch %}
+{%- endmacro %}
+
+{#- A function declaration for a top-level function, method, constructor or static method.
+	`receiver` is the method receiver and `self_param` is the first parameter for functions that
+	implement methods, these are empty if they aren't needed. #}
+{%- macro func_decl(receiver, self_param, meth) %}
+{%- let callable = meth.callable %}
+{% call docstring(meth, 0) %}{% endcall %}
+func {{ receiver }}{{ callable.name }}({{ self_param }}
+{%- if !self_param.is_empty() && !callable.arguments.is_empty() %}, {% endif %}
+{%- call arg_list_decl(callable) %}{% endcall %}){{ callable.return_type_decl() }} {
+{%- call call_body(callable, "_self") %}{% endcall %}
+}
+{%- endmacro %}
+
+{%- macro docstring_value(maybe_docstring, indent) %}
+{%- match maybe_docstring %}
+{%- when Some(docstring) %}
+{{ docstring|docstring(indent) }}
+{%- else %}
+{%- endmatch %}
+{%- endmacro %}
+
+{%- macro docstring(defn, indent) %}
+{%- call docstring_value(defn.docstring, indent) %}{% endcall %}
+{%- endmacro %}
+
+{#- Implementations of exported Rust traits, `receiver` is the receiver for the methods #}
+{%- macro uniffi_trait_impls(uniffi_trait_methods, receiver) %}
+{%- if let Some(fmt) = uniffi_trait_methods.display_fmt.clone().or(uniffi_trait_methods.debug_fmt.clone()) %}
+
+// String calls the Rust `Display` implementation, or `Debug` if there's no `Display`
+func {{ receiver }}String() string {
+{%- call call_body(fmt.callable, "_self") %}{% endcall %}
+}
+{%- endif %}
+{%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+
+// Equals calls the Rust `Eq` implementation
+func {{ receiver }}Equals({% call arg_list_decl(eq.callable) %}{% endcall %}){{ eq.callable.return_type_decl() }} {
+{%- call call_body(eq.callable, "_self") %}{% endcall %}
+}
+{%- endif %}
+{%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
+
+// Hash calls the Rust `Hash` implementation
+func {{ receiver }}Hash(){{ hash.callable.return_type_decl() }} {
+{%- call call_body(hash.callable, "_self") %}{% endcall %}
+}
+{%- endif %}
+{%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+
+// Compare calls the Rust `Ord` implementation
+func {{ receiver }}Compare({% call arg_list_decl(cmp.callable) %}{% endcall %}){{ cmp.callable.return_type_decl() }} {
+{%- call call_body(cmp.callable, "_self") %}{% endcall %}
+}
+{%- endif %}
+{%- endmacro %}
+
+{#- Method declarations for the Go interface of an object or callback interface #}
+{%- macro interface_method_decls(methods) %}
+	{%- for meth in methods.iter() %}
+	{%- let callable = meth.callable %}
+	{%- call docstring(meth, 1) %}{% endcall %}
+	{{ callable.name }}({% call arg_list_decl(callable) %}{% endcall %}){{ callable.return_type_decl() }}
+	{%- endfor %}
+{%- endmacro %}
+
+{#- Call a Go callback interface method from one of the functions exported for the VTable.
+	The return value is stored in `uniffiReturnValue` and the error in `uniffiErr`. #}
+{%- macro callback_method_call(callable, ffi_converter_name) %}
+	uniffiObj := {{ ffi_converter_name }}HandleMap.get(uint64(uniffiHandle))
+	{% if callable.return_type.ty.is_some() %}uniffiReturnValue{% if callable.throws() %}, {% endif %}{% endif %}
+	{%- if callable.throws() %}uniffiErr{% endif %}
+	{%- if callable.return_type.ty.is_some() || callable.throws() %} := {% endif -%}
+	uniffiObj.{{ callable.name }}(
+	{%- for arg in callable.arguments %}
+	{{- arg.ty.lift(arg.name) }}{% if !loop.last %}, {% endif %}
+	{%- endfor -%}
+	)
+{%- endmacro %}
+
+{#- Report an error returned by a callback interface method back to Rust, `status` is an
+	expression for the `RustCallStatus` #}
+{%- macro callback_handle_error(callable, status) %}
+	{%- if callable.throws() %}
+	if uniffiErr != nil {
+		{%- if let Some(error) = callable.throws_type.error %}
+		var uniffiError {{ error.type_name }}
+		if errors.As(uniffiErr, &uniffiError) {
+			{{ status }}.code = uniffiCallStatusError
+			{{ status }}.errorBuf = {{ error.lower_expr }}
+			return
+		}
+		{%- endif %}
+		{{ status }}.code = uniffiCallStatusUnexpectedError
+		{{ status }}.errorBuf = uniffiRustBufferFromBytes([]byte(uniffiErr.Error()))
+		return
+	}
+	{%- endif %}
+{%- endmacro %}
+
+{#- Method declarations for the exported Rust traits, for the Go interface of an enum #}
+{%- macro uniffi_trait_decls(uniffi_trait_methods) %}
+	{%- if uniffi_trait_methods.display_fmt.is_some() || uniffi_trait_methods.debug_fmt.is_some() %}
+	String() string
+	{%- endif %}
+	{%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
+	Equals({% call arg_list_decl(eq.callable) %}{% endcall %}){{ eq.callable.return_type_decl() }}
+	{%- endif %}
+	{%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
+	Hash(){{ hash.callable.return_type_decl() }}
+	{%- endif %}
+	{%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
+	Compare({% call arg_list_decl(cmp.callable) %}{% endcall %}){{ cmp.callable.return_type_decl() }}
+	{%- endif %}
+{%- endmacro %}
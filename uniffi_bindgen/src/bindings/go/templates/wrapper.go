@@ -0,0 +1,32 @@
+// Code generated by uniffi. DO NOT EDIT.
+{% call go::docstring_value(docstring, 0) %}{% endcall %}
+package {{ package_name }}
+
+// #cgo LDFLAGS: -l{{ cdylib_name }}
+// #include "{{ package_name }}.h"
+import "C"
+
+import (
+{%- for import in imports %}
+	"{{ import }}"
+{%- endfor %}
+)
+
+{% include "RustBufferTemplate.go" %}
+
+{% include "Helpers.go" %}
+
+{% include "NamespaceLibraryTemplate.go" %}
+{%- if has_callback_definitions || has_async_fns %}
+
+{% include "HandleMap.go" %}
+{%- endif %}
+{%- if has_async_fns %}
+
+{% include "Async.go" %}
+{%- endif %}
+
+{%- include "Types.go" %}
+
+{%- include "TopLevelFunctions.go" %}
+{% import "macros.go" as go %}
@@ -0,0 +1,83 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+License, v. 2.0. If a copy of the MPL was not distributed with this
+* file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use crate::bindings::{generate, GenerateOptions, RunScriptOptions, TargetLanguage};
+use anyhow::{bail, Context, Result};
+use camino::Utf8Path;
+use fs_err as fs;
+use std::process::Command;
+use uniffi_testing::UniFFITestHelper;
+
+/// Run Go tests for a UniFFI test fixture
+pub fn run_test(tmp_dir: &str, fixture_name: &str, script_file: &str) -> Result<()> {
+    run_script(
+        tmp_dir,
+        fixture_name,
+        script_file,
+        vec![],
+        &RunScriptOptions::default(),
+    )
+}
+
+/// Run a Go test script
+///
+/// The script is a `_test.go` file in `package test`, which imports the generated packages as
+/// `uniffi/<package>`.  It gets copied into a module together with the generated bindings, then
+/// run with `go test`.
+pub fn run_script(
+    tmp_dir: &str,
+    crate_name: &str,
+    script_file: &str,
+    args: Vec<String>,
+    options: &RunScriptOptions,
+) -> Result<()> {
+    let script_path = Utf8Path::new(script_file).canonicalize_utf8()?;
+    let test_helper = UniFFITestHelper::new(crate_name)?;
+    let out_dir = test_helper.create_out_dir(tmp_dir, &script_path)?;
+    let cdylib_path = test_helper.copy_cdylib_to_out_dir(&out_dir)?;
+
+    generate(GenerateOptions {
+        languages: vec![TargetLanguage::Go],
+        source: cdylib_path.to_path_buf(),
+        out_dir: out_dir.to_path_buf(),
+        ..GenerateOptions::default()
+    })?;
+    fs::write(out_dir.join("go.mod"), "module uniffi\n\ngo 1.21\n")?;
+    let test_dir = out_dir.join("test");
+    fs::create_dir_all(&test_dir)?;
+    let Some(script_stem) = script_path.file_stem() else {
+        bail!("Invalid script path: {script_path}");
+    };
+    fs::copy(
+        &script_path,
+        test_dir.join(format!("{script_stem}_test.go")),
+    )?;
+
+    let mut command = Command::new("go");
+    command
+        .current_dir(&out_dir)
+        .env("CGO_ENABLED", "1")
+        .env("CGO_LDFLAGS", format!("-L{out_dir}"))
+        .env("LD_LIBRARY_PATH", &out_dir)
+        .env("DYLD_LIBRARY_PATH", &out_dir)
+        .arg("test")
+        .arg("-count=1");
+    if options.show_compiler_messages {
+        command.arg("-v");
+    }
+    command.arg("./test");
+    if !args.is_empty() {
+        command.arg("-args").args(args);
+    }
+
+    let status = command
+        .spawn()
+        .context("Failed to spawn `go` to run Go script")?
+        .wait()
+        .context("Failed to wait for `go` when running Go script")?;
+    if !status.success() {
+        bail!("running `go` failed")
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// Template filters for Askama
+///
+/// In general, prefer adding fields using a pipeline pass to writing filters.
+/// That's allows devs to use the `pipeline` command to follow what's going on.
+///
+/// We currently only use filter functions for display logic that depends on where the
+/// template renders something, like the indentation for the `docstring` filter.
+use askama::Result;
+
+/// Get the idiomatic Kotlin rendering of docstring
+#[askama::filter_fn]
+pub fn docstring<S: AsRef<str>>(
+    docstring: S,
+    _: &dyn askama::Values,
+    spaces: &i32,
+) -> Result<String> {
+    let middle = textwrap::indent(&textwrap::dedent(docstring.as_ref()), " * ");
+    let wrapped = format!("/**\n{middle}\n */");
+
+    let spaces = usize::try_from(*spaces).unwrap_or_default();
+    Ok(textwrap::indent(&wrapped, &" ".repeat(spaces)))
+}
+
+/// Remove the "`" chars we put around function/variable names
+///
+/// These are used to avoid name clashes with kotlin identifiers, but sometimes you want to
+/// render the name unquoted.  One example is the message property for errors where we want to
+/// display the name for the user.
+#[askama::filter_fn]
+pub fn unquote<S: AsRef<str>>(nm: S, _: &dyn askama::Values) -> Result<String> {
+    Ok(nm.as_ref().trim_matches('`').to_string())
+}
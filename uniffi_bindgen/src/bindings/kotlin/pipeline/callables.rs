@@ -0,0 +1,26 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+fn is_borrowed_bytes(arg: &general::Argument) -> bool {
+    arg.by_ref && matches!(arg.ty.ty, Type::Bytes)
+}
+
+pub fn lower_type_name(arg: &general::Argument, context: &Context) -> Result<String> {
+    if is_borrowed_bytes(arg) {
+        Ok("java.nio.ByteBuffer".to_string())
+    } else {
+        types::type_name(&arg.ty.ty, context)
+    }
+}
+
+/// Lower borrowed `Bytes` through `FfiConverterByRefBytes`, which is zero-copy.
+pub fn lower_fn(arg: &general::Argument) -> String {
+    if is_borrowed_bytes(arg) {
+        "FfiConverterByRefBytes.lower".to_string()
+    } else {
+        format!("{}.lower", types::ffi_converter_name(&arg.ty))
+    }
+}
@@ -0,0 +1,231 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub cdylib: Option<String>,
+    pub current_config: Option<KotlinConfig>,
+    pub module_namespace: Option<String>,
+    /// Maps namespace names to Kotlin package names
+    pub package_names: HashMap<String, String>,
+    /// Maps namespace names to crate names
+    pub crate_names: HashMap<String, String>,
+    /// Names of types used as errors in any namespace.
+    ///
+    /// Kotlin renames these classes to use the `Exception` suffix, which needs to happen
+    /// consistently across namespaces.
+    pub error_type_names: HashSet<String>,
+    /// Maps (namespace, name) pairs for user-defined types to the types they directly contain.
+    ///
+    /// This is used to determine which types contain object references.
+    pub type_dependencies: HashMap<(String, String), Vec<Type>>,
+    /// FFI function types for the current namespace, keyed by their original name
+    pub ffi_function_types: HashMap<String, general::FfiFunctionType>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.cdylib = root.cdylib.clone();
+        for namespace in root.namespaces.values() {
+            let config = match &namespace.config_toml {
+                Some(toml) => KotlinConfig::from_uniffi_toml(toml)?,
+                None => KotlinConfig::default(),
+            };
+            self.package_names.insert(
+                namespace.name.clone(),
+                config
+                    .package_name
+                    .unwrap_or_else(|| format!("uniffi.{}", namespace.name)),
+            );
+            self.crate_names
+                .insert(namespace.name.clone(), namespace.crate_name.clone());
+            namespace.visit(|type_node: &general::TypeNode| {
+                if type_node.is_used_as_error {
+                    if let Some(name) = type_node.ty.name() {
+                        self.error_type_names.insert(name.to_string());
+                    }
+                }
+            });
+        }
+        root.visit(|rec: &general::Record| {
+            self.add_type_dependencies(&rec.self_type, rec);
+        });
+        root.visit(|en: &general::Enum| {
+            self.add_type_dependencies(&en.self_type, en);
+        });
+        root.visit(|int: &general::Interface| {
+            self.add_type_dependencies(&int.self_type, int);
+        });
+        root.visit(|cbi: &general::CallbackInterface| {
+            self.add_type_dependencies(&cbi.self_type, cbi);
+        });
+        Ok(())
+    }
+
+    fn add_type_dependencies(&mut self, self_type: &general::TypeNode, node: &impl Node) {
+        let (Some(namespace), Some(name)) = (self_type.ty.namespace(), self_type.ty.name()) else {
+            return;
+        };
+        let mut dependencies = vec![];
+        node.visit(|ty: &Type| {
+            if ty != &self_type.ty {
+                dependencies.push(ty.clone());
+            }
+        });
+        self.type_dependencies
+            .insert((namespace.to_string(), name.to_string()), dependencies);
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => KotlinConfig::from_uniffi_toml(toml)?,
+            None => KotlinConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        self.ffi_function_types = namespace
+            .ffi_definitions
+            .iter()
+            .filter_map(|def| match def {
+                general::FfiDefinition::FunctionType(func_type) => {
+                    Some((func_type.name.0.clone(), func_type.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        Ok(())
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&KotlinConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    pub fn package_name(&self) -> Result<String> {
+        self.package_names
+            .get(self.module_namespace()?)
+            .cloned()
+            .ok_or_else(|| anyhow!("Context.package_names not set"))
+    }
+
+    pub fn cdylib(&self) -> Result<String> {
+        Ok(match &self.config()?.cdylib_name {
+            Some(cdylib) => cdylib.clone(),
+            None => match &self.cdylib {
+                Some(cdylib) => cdylib.clone(),
+                None => format!("uniffi_{}", self.module_namespace()?),
+            },
+        })
+    }
+
+    /// Get the package name for an external namespace
+    ///
+    /// Returns `None` for the current namespace.
+    pub fn external_package_name(&self, namespace: &str) -> Result<Option<String>> {
+        if namespace == self.module_namespace()? {
+            return Ok(None);
+        }
+        // Config overrides are keyed by the crate name
+        let crate_name = self.crate_names.get(namespace);
+        let config_package_name =
+            crate_name.and_then(|crate_name| self.config().ok()?.external_packages.get(crate_name));
+        Ok(Some(match config_package_name {
+            Some(package_name) => package_name.clone(),
+            None => match self.package_names.get(namespace) {
+                Some(package_name) => package_name.clone(),
+                None => format!("uniffi.{namespace}"),
+            },
+        }))
+    }
+
+    /// Get the Kotlin class name for a type name.
+    ///
+    /// This converts the name to UpperCamelCase and handles renaming error classes.
+    pub fn class_name(&self, name: &str) -> String {
+        let class_name = names::class_name(name);
+        if self.error_type_names.contains(name) {
+            names::convert_error_suffix(&class_name)
+        } else {
+            class_name
+        }
+    }
+
+    /// Config for a custom type
+    ///
+    /// Kotlin configs have always been keyed by the renamed type name, but fall back to the
+    /// original name for configs written against the Rust name.
+    pub fn custom_type_config(
+        &self,
+        custom: &general::CustomType,
+    ) -> Result<Option<CustomTypeConfig>> {
+        let custom_types = &self.config()?.custom_types;
+        Ok(custom_types
+            .get(&custom.name)
+            .or_else(|| custom_types.get(&custom.orig_name))
+            .cloned())
+    }
+
+    /// Does a type contain any object references, either directly or through other types?
+    pub fn contains_object_references(&self, ty: &Type) -> bool {
+        let mut seen = HashSet::new();
+        self.contains_object_references_inner(ty, &mut seen)
+    }
+
+    fn contains_object_references_inner(
+        &self,
+        ty: &Type,
+        seen: &mut HashSet<(String, String)>,
+    ) -> bool {
+        match ty {
+            Type::Interface { .. } => true,
+            Type::Record {
+                namespace, name, ..
+            }
+            | Type::Enum {
+                namespace, name, ..
+            }
+            | Type::CallbackInterface {
+                namespace, name, ..
+            } => {
+                let key = (namespace.clone(), name.clone());
+                if !seen.insert(key.clone()) {
+                    return false;
+                }
+                self.type_dependencies
+                    .get(&key)
+                    .into_iter()
+                    .flatten()
+                    .any(|ty| self.contains_object_references_inner(ty, seen))
+            }
+            Type::Custom { builtin, .. } => self.contains_object_references_inner(builtin, seen),
+            Type::Optional { inner_type }
+            | Type::Sequence { inner_type }
+            | Type::Array { inner_type, .. }
+            | Type::Set { inner_type, .. }
+            | Type::Box { inner_type } => self.contains_object_references_inner(inner_type, seen),
+            Type::Map {
+                key_type,
+                value_type,
+                ..
+            } => {
+                self.contains_object_references_inner(key_type, seen)
+                    || self.contains_object_references_inner(value_type, seen)
+            }
+            Type::Tuple { inner_types } => inner_types
+                .iter()
+                .any(|ty| self.contains_object_references_inner(ty, seen)),
+            _ => false,
+        }
+    }
+}
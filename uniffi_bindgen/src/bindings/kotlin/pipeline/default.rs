@@ -0,0 +1,206 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Render a default value for a field/argument of type `ty`
+pub fn render_default(
+    default: &general::DefaultValue,
+    ty: &Type,
+    context: &Context,
+) -> Result<String> {
+    match ty {
+        Type::Boolean => render_primitive_default(default, "false"),
+        Type::String => render_primitive_default(default, "\"\""),
+        Type::Bytes => render_primitive_default(default, "byteArrayOf()"),
+        Type::Int8 => render_primitive_default(default, "0.toByte()"),
+        Type::Int16 | Type::Int32 => render_primitive_default(default, "0"),
+        Type::Int64 => render_primitive_default(default, "0L"),
+        Type::UInt8 | Type::UInt16 | Type::UInt32 => render_primitive_default(default, "0U"),
+        Type::UInt64 => render_primitive_default(default, "0UL"),
+        Type::Float32 => render_primitive_default(default, "0.0f"),
+        Type::Float64 => render_primitive_default(default, "0.0"),
+        Type::Char => render_primitive_default(default, "\"\\u0000\""),
+        Type::Int128 | Type::UInt128 => match default {
+            general::DefaultValue::Default(_) => Ok("java.math.BigInteger.ZERO".to_string()),
+            general::DefaultValue::Literal(general::Literal::Int(i, _, _)) => {
+                Ok(format!("java.math.BigInteger(\"{i}\")"))
+            }
+            general::DefaultValue::Literal(general::Literal::UInt(i, _, _)) => {
+                Ok(format!("java.math.BigInteger(\"{i}\")"))
+            }
+            _ => bail!(
+                "Invalid literal for {} type: {default:?}",
+                types::canonical_name(ty)
+            ),
+        },
+        Type::Box { inner_type } => render_default(default, inner_type, context),
+        Type::Optional { inner_type } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(general::Literal::None) => Ok("null".to_string()),
+            general::DefaultValue::Literal(general::Literal::Some { inner }) => {
+                render_default(inner, inner_type, context)
+            }
+            _ => bail!("Invalid literal for Optional type: {default:?}"),
+        },
+        Type::Sequence { .. } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(general::Literal::EmptySequence) => {
+                Ok("listOf()".to_string())
+            }
+            _ => bail!("Invalid literal for List type: {default:?}"),
+        },
+        Type::Map { .. } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(general::Literal::EmptyMap) => {
+                Ok("mapOf()".to_string())
+            }
+            _ => bail!("Invalid literal for Map type: {default:?}"),
+        },
+        Type::Set { .. } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(
+                general::Literal::EmptySequence | general::Literal::EmptySet,
+            ) => Ok("setOf()".to_string()),
+            _ => bail!("Invalid literal for Set type: {default:?}"),
+        },
+        Type::Array { .. } => {
+            bail!("Default values are not supported for fixed-size arrays: {default:?}")
+        }
+        Type::Tuple { .. } => {
+            bail!("Default values are not supported for tuple types: {default:?}")
+        }
+        Type::Stream { .. } => {
+            bail!("Default values are not supported for stream types: {default:?}")
+        }
+        Type::Iterator { .. } => {
+            bail!("Default values are not supported for iterator types: {default:?}")
+        }
+        Type::Closure { .. } => {
+            bail!("Default values are not supported for closure types: {default:?}")
+        }
+        Type::Enum { name, .. } => match default {
+            general::DefaultValue::Literal(general::Literal::Enum(variant, _)) => Ok(format!(
+                "{}.{}",
+                context.class_name(name),
+                names::enum_variant_name(variant)
+            )),
+            _ => bail!("Invalid literal for enum type: {default:?}"),
+        },
+        Type::Record { name, .. } => match default {
+            general::DefaultValue::Default(_) => Ok(format!("{}()", context.class_name(name))),
+            general::DefaultValue::Literal(general::Literal::Record { fields, .. }) => {
+                let fields = fields
+                    .iter()
+                    .map(|f| {
+                        let value = render_default(
+                            &general::DefaultValue::Literal(f.value.clone()),
+                            &f.ty.ty,
+                            context,
+                        )?;
+                        Ok(format!("{} = {value}", names::var_name(&f.name)))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(format!(
+                    "{}({})",
+                    context.class_name(name),
+                    fields.join(", ")
+                ))
+            }
+            _ => bail!("Invalid literal for record type: {default:?}"),
+        },
+        Type::Custom { builtin, .. } => render_default(default, builtin, context).map_err(|_| {
+            anyhow!(
+                "Unsupported default value for {}",
+                types::type_name(ty, context).unwrap_or_default()
+            )
+        }),
+        // Default for other named types is to assume a ctor exists.
+        Type::Interface { .. }
+        | Type::CallbackInterface { .. }
+        | Type::Timestamp
+        | Type::Duration => match default {
+            general::DefaultValue::Default(_) => {
+                Ok(format!("{}()", types::type_name(ty, context)?))
+            }
+            general::DefaultValue::Literal(_) => {
+                bail!("Literals for named types are not supported")
+            }
+        },
+    }
+}
+
+fn render_primitive_default(default: &general::DefaultValue, default_str: &str) -> Result<String> {
+    match default {
+        general::DefaultValue::Default(_) => Ok(default_str.to_string()),
+        general::DefaultValue::Literal(literal) => render_literal(literal),
+    }
+}
+
+fn render_literal(literal: &general::Literal) -> Result<String> {
+    fn typed_number(ty: &Type, num_str: String) -> Result<String> {
+        let unwrapped_type = match ty {
+            Type::Optional { inner_type } => inner_type,
+            t => t,
+        };
+        Ok(match unwrapped_type {
+            // Bytes, Shorts and Ints can all be inferred from the type.
+            Type::Int8 | Type::Int16 | Type::Int32 => num_str,
+            Type::Int64 => format!("{num_str}L"),
+            Type::UInt8 | Type::UInt16 | Type::UInt32 => format!("{num_str}u"),
+            Type::UInt64 => format!("{num_str}uL"),
+            Type::Float32 => format!("{num_str}f"),
+            Type::Float64 => num_str,
+            _ => bail!("Unexpected literal: {num_str} for type: {ty:?}"),
+        })
+    }
+
+    match literal {
+        general::Literal::Boolean(v) => Ok(format!("{v}")),
+        general::Literal::String(s) => Ok(format!("\"{s}\"")),
+        // Chars are strings in Kotlin, escape the UTF-16 code units to handle any code point.
+        general::Literal::Char(c) => Ok(format!(
+            "\"{}\"",
+            c.encode_utf16(&mut [0; 2])
+                .iter()
+                .map(|unit| format!("\\u{unit:04X}"))
+                .collect::<String>()
+        )),
+        general::Literal::Int(i, radix, ty) => typed_number(
+            &ty.ty,
+            match radix {
+                Radix::Octal | Radix::Hexadecimal => format!("{i:#x}"),
+                Radix::Decimal => format!("{i}"),
+            },
+        ),
+        general::Literal::UInt(i, radix, ty) => typed_number(
+            &ty.ty,
+            match radix {
+                Radix::Octal | Radix::Hexadecimal => format!("{i:#x}"),
+                Radix::Decimal => format!("{i}"),
+            },
+        ),
+        general::Literal::Float(string, ty) => typed_number(&ty.ty, string.clone()),
+        _ => bail!("Invalid literal {literal:?}"),
+    }
+}
+
+/// Kotlin only allows `const val` for primitives and strings, other constants use `val`.
+pub fn is_const_val(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Int8
+            | Type::UInt8
+            | Type::Int16
+            | Type::UInt16
+            | Type::Int32
+            | Type::UInt32
+            | Type::Int64
+            | Type::UInt64
+            | Type::Float32
+            | Type::Float64
+            | Type::Boolean
+            | Type::String
+    )
+}
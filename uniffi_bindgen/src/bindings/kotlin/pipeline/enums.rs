@@ -0,0 +1,65 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_variants(
+    variants: Vec<general::Variant>,
+    is_flat: bool,
+    shape: &EnumShape,
+    discr_type: &Type,
+    context: &Context,
+) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            Ok(Variant {
+                name: if shape.is_error() {
+                    names::error_variant_name(&v.name)
+                } else if is_flat {
+                    names::enum_variant_name(&v.name)
+                } else {
+                    context.class_name(&v.name)
+                },
+                discr: discr_literal(&v.discr, discr_type)?,
+                fields_kind: v.fields_kind,
+                fields: map_variant_fields(v.fields, context)?,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Map variant fields, naming any unnamed fields `v1`, `v2`, etc.
+fn map_variant_fields(fields: Vec<general::Field>, context: &Context) -> Result<Vec<Field>> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let unnamed = field.name.is_empty();
+            let mut field: Field = field.map_node(context)?;
+            if unnamed {
+                field.name = format!("v{}", i + 1);
+            }
+            Ok(field)
+        })
+        .collect()
+}
+
+/// Render a discriminant literal
+///
+/// Kotlin doesn't convert between signed and unsigned by default so we need to make sure we
+/// define the literal with the correct signedness.
+fn discr_literal(discr: &general::Literal, discr_type: &Type) -> Result<String> {
+    let value = match discr {
+        general::Literal::UInt(v, _, _) => v.to_string(),
+        general::Literal::Int(v, _, _) => v.to_string(),
+        _ => bail!("Only ints are supported: {discr:?}"),
+    };
+    Ok(match discr_type {
+        Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64 => value,
+        Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 => format!("{value}u"),
+        _ => bail!("Only ints are supported for enum literals: {discr_type:?}"),
+    })
+}
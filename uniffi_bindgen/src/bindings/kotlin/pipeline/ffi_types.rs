@@ -0,0 +1,157 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn ffi_type_name(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        // Note that unsigned integers in Kotlin are currently experimental, but java.nio.ByteBuffer does not
+        // support them yet. Thus, we use the signed variants to represent both signed and unsigned
+        // types from the component API.
+        FfiType::Int8 | FfiType::UInt8 => "Byte".to_string(),
+        FfiType::Int16 | FfiType::UInt16 => "Short".to_string(),
+        FfiType::Int32 | FfiType::UInt32 => "Int".to_string(),
+        FfiType::Int64 | FfiType::UInt64 => "Long".to_string(),
+        FfiType::Float32 => "Float".to_string(),
+        FfiType::Float64 => "Double".to_string(),
+        FfiType::Handle(_) => "Long".to_string(),
+        FfiType::RustBuffer(Some(namespace)) => match context.external_package_name(namespace)? {
+            None => "RustBuffer".to_string(),
+            Some(package_name) => format!("{package_name}.RustBuffer"),
+        },
+        FfiType::RustBuffer(None) => "RustBuffer".to_string(),
+        FfiType::RustCallStatus => "UniffiRustCallStatus.ByValue".to_string(),
+        FfiType::ForeignBytes => "ForeignBytes.ByValue".to_string(),
+        FfiType::Function(name) => name.clone().map_node(context)?.0,
+        FfiType::Struct(name) => name.clone().map_node(context)?.0,
+        FfiType::Reference(inner) | FfiType::MutReference(inner) => {
+            ffi_type_name_by_reference(inner, context)?
+        }
+        FfiType::VoidPointer => "Pointer".to_string(),
+    })
+}
+
+fn ffi_type_name_by_reference(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::Int8
+        | FfiType::UInt8
+        | FfiType::Int16
+        | FfiType::UInt16
+        | FfiType::Int32
+        | FfiType::UInt32
+        | FfiType::Int64
+        | FfiType::UInt64
+        | FfiType::Float32
+        | FfiType::Float64
+        | FfiType::Handle(_) => format!("{}ByReference", ffi_type_name(ffi_type, context)?),
+        // JNA structs default to ByReference
+        FfiType::RustBuffer(_) | FfiType::Struct(_) => ffi_type_name(ffi_type, context)?,
+        _ => bail!("{ffi_type:?} by reference is not implemented"),
+    })
+}
+
+pub fn ffi_type_name_by_value(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::RustBuffer(_) => format!("{}.ByValue", ffi_type_name(ffi_type, context)?),
+        FfiType::Struct(_) => format!("{}.UniffiByValue", ffi_type_name(ffi_type, context)?),
+        _ => ffi_type_name(ffi_type, context)?,
+    })
+}
+
+/// FFI type name to use inside structs
+///
+/// The main requirement here is that all types must have default values or else the struct
+/// won't work in some JNA contexts.
+pub fn ffi_type_name_for_ffi_struct(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        // Make callbacks function pointers nullable. This matches the semantics of a C
+        // function pointer better and allows for `null` as a default value.
+        FfiType::Function(_) => format!("{}?", ffi_type_name(ffi_type, context)?),
+        _ => ffi_type_name_by_value(ffi_type, context)?,
+    })
+}
+
+/// Kotlin/JNA direct mapping can mis-handle unsigned 8/16-bit direct return values
+/// on some runtimes, so widen the raw carrier to Int and let the generated
+/// converters lift it back into the public UByte/UShort API types.
+pub fn ffi_type_name_for_direct_return(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::UInt8 | FfiType::UInt16 => "Int".to_string(),
+        _ => ffi_type_name_by_value(ffi_type, context)?,
+    })
+}
+
+/// Default values for FFI
+///
+/// This is used to:
+///   - Set a default return value for error results
+///   - Set a default for structs, which JNA sometimes requires
+pub fn ffi_default_value(ffi_type: &FfiType, context: &Context) -> Result<String> {
+    Ok(match ffi_type {
+        FfiType::UInt8 | FfiType::Int8 => "0.toByte()".to_string(),
+        FfiType::UInt16 | FfiType::Int16 => "0.toShort()".to_string(),
+        FfiType::UInt32 | FfiType::Int32 => "0".to_string(),
+        FfiType::UInt64 | FfiType::Int64 => "0.toLong()".to_string(),
+        FfiType::Float32 => "0.0f".to_string(),
+        FfiType::Float64 => "0.0".to_string(),
+        FfiType::Handle(_) => "0.toLong()".to_string(),
+        FfiType::RustBuffer(_) => format!("{}()", ffi_type_name_by_value(ffi_type, context)?),
+        FfiType::Function(_) => "null".to_string(),
+        FfiType::RustCallStatus => "UniffiRustCallStatus.ByValue()".to_string(),
+        _ => bail!("No FFI default value for {ffi_type:?}"),
+    })
+}
+
+/// Default value for a VTable method to return when the call fails
+pub fn ffi_default_return_value(
+    return_type: &general::ReturnType,
+    context: &Context,
+) -> Result<Option<String>> {
+    return_type
+        .ty
+        .as_ref()
+        .map(|ty| ffi_default_value(&ty.ffi_type, context))
+        .transpose()
+}
+
+/// Lookup the FFI function type for a VTable method
+pub fn vtable_method_callback(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<FfiFunctionType> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context
+        .ffi_function_types
+        .get(&name.0)
+        .cloned()
+        .ok_or_else(|| anyhow!("FFI function type not found: {}", name.0))?
+        .map_node(context)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unsigned_direct_returns_use_int_carriers() {
+        let context = Context::default();
+        for ffi_type in [FfiType::UInt8, FfiType::UInt16] {
+            assert_eq!(
+                ffi_type_name_for_direct_return(&ffi_type, &context).unwrap(),
+                "Int"
+            );
+        }
+        // Arguments and struct fields still use the regular JNA types
+        assert_eq!(
+            ffi_type_name_by_value(&FfiType::UInt16, &context).unwrap(),
+            "Short"
+        );
+        assert_eq!(
+            ffi_type_name_for_direct_return(&FfiType::Int16, &context).unwrap(),
+            "Short"
+        );
+    }
+}
@@ -0,0 +1,70 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Interface and class names for objects
+//!
+//! For a regular `struct Foo` or `trait Foo`, there's `FooInterface` with `Foo` as the name of the
+//! (Rust implemented) object. But if it's a foreign trait:
+//!   * The name `Foo` is the name of the interface used by a the Kotlin implementation of the trait.
+//!   * The Rust implemented object is `FooImpl`.
+//!
+//! This all impacts what types `FfiConverter.lower()` inputs.  If it's a "foreign trait" `lower`
+//! must lower anything that implements the interface (ie, a kotlin implementation).  If not, then
+//! lower only lowers the concrete class (ie, our simple instance with the handle).
+
+use super::*;
+
+pub fn interface_name(int: &general::Interface, context: &Context) -> String {
+    let class_name = context.class_name(&int.name);
+    if int.imp.has_callback_interface() {
+        class_name
+    } else {
+        format!("{class_name}Interface")
+    }
+}
+
+pub fn impl_class_name(int: &general::Interface, context: &Context) -> String {
+    let class_name = context.class_name(&int.name);
+    if int.imp.has_callback_interface() {
+        format!("{class_name}Impl")
+    } else {
+        class_name
+    }
+}
+
+pub fn trait_interface_names(int: &general::Interface, context: &Context) -> Result<Vec<String>> {
+    int.trait_impls
+        .iter()
+        .map(|t| {
+            Ok(match &t.trait_ty.ty {
+                Type::Interface { name, imp, .. } => {
+                    let class_name = context.class_name(name);
+                    if imp.has_callback_interface() {
+                        class_name
+                    } else {
+                        format!("{class_name}Interface")
+                    }
+                }
+                Type::CallbackInterface { name, .. } => context.class_name(name),
+                ty => bail!("Invalid trait_type: {ty:?}"),
+            })
+        })
+        .collect()
+}
+
+pub fn map_constructors(
+    interface_name: &str,
+    constructors: Vec<general::Constructor>,
+    context: &Context,
+) -> Result<Vec<Constructor>> {
+    constructors
+        .into_iter()
+        .map(|c| {
+            if c.callable.is_primary_constructor() && c.callable.is_async() {
+                bail!("Async primary constructors not supported but {interface_name} has one");
+            }
+            c.map_node(context)
+        })
+        .collect()
+}
@@ -0,0 +1,195 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{BTreeSet, HashSet};
+
+use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?.clone();
+
+    let has_stream_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Stream(_))
+    });
+    let has_iterator_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Iterator(_))
+    });
+    let has_closure_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Closure(_))
+    });
+    let has_interface_types = namespace.has_descendant(|_: &general::Interface| true);
+    let cleaner =
+        (has_interface_types || has_iterator_types || has_stream_types || has_closure_types).then(
+            || {
+                if config.disable_java_cleaner {
+                    ObjectCleaner::Jna
+                } else if config.android_cleaner() {
+                    ObjectCleaner::Android
+                } else {
+                    ObjectCleaner::Jvm
+                }
+            },
+        );
+    let has_async_fns = has_stream_types
+        || namespace.has_descendant(|callable: &general::Callable| callable.is_async());
+
+    let mut module = Module {
+        name: namespace.name.clone(),
+        crate_name: namespace.crate_name.clone(),
+        package_name: context.package_name()?,
+        cdylib_name: context.cdylib()?,
+        omit_checksums: config.omit_checksums,
+        use_enum_entries: config.use_enum_entries()?,
+        cleaner,
+        has_async_fns,
+        has_callback_definitions: namespace.has_descendant(|_: &general::CallbackInterface| true)
+            || namespace
+                .has_descendant(|int: &general::Interface| int.imp.has_callback_interface()),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        has_iterator_types,
+        has_closure_types,
+        imports: vec![],
+        initialization_fns: vec![],
+        tuple_class_arities: tuple_class_arities(&namespace),
+        docstring: namespace.docstring,
+        functions: namespace.functions.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        ffi_definitions: dedup_ffi_definitions(namespace.ffi_definitions).map_node(context)?,
+        checksums: namespace.checksums.map_node(context)?,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        correct_contract_version: namespace.correct_contract_version,
+    };
+    // Generate these after mapping everything else, so that we can use the Kotlin names.
+    module.imports = module_imports(&module, has_stream_types);
+    module.initialization_fns = initialization_fns(&module);
+    Ok(module)
+}
+
+fn has_type_definition(
+    namespace: &general::Namespace,
+    f: impl Fn(&general::TypeDefinition) -> bool,
+) -> bool {
+    namespace.type_definitions.iter().any(f)
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+fn tuple_class_arities(namespace: &general::Namespace) -> Vec<u32> {
+    namespace
+        .type_definitions
+        .iter()
+        .filter_map(|td| match td {
+            general::TypeDefinition::Tuple(tuple) if tuple.inners.len() > 3 => {
+                Some(tuple.inners.len() as u32)
+            }
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Remove FFI definitions with duplicate names
+///
+/// This can happen for the `rust_future` functions, since several FFI types share the same
+/// function.  Kotlin only needs to declare each one once.
+fn dedup_ffi_definitions(
+    ffi_definitions: IndexSet<general::FfiDefinition>,
+) -> IndexSet<general::FfiDefinition> {
+    let mut seen = HashSet::new();
+    ffi_definitions
+        .into_iter()
+        .filter(|def| {
+            let name = match def {
+                general::FfiDefinition::RustFunction(func) => func.name.0.clone(),
+                general::FfiDefinition::FunctionType(func_type) => func_type.name.0.clone(),
+                general::FfiDefinition::Struct(st) => st.name.0.clone(),
+            };
+            seen.insert(name)
+        })
+        .collect()
+}
+
+fn module_imports(module: &Module, has_stream_types: bool) -> Vec<String> {
+    let mut imports = BTreeSet::new();
+    if module.has_descendant(|_: &Interface| true) || has_stream_types {
+        imports.insert("java.util.concurrent.atomic.AtomicBoolean".to_string());
+    }
+    if has_stream_types {
+        imports.insert("kotlinx.coroutines.flow.Flow".to_string());
+        imports.insert("kotlinx.coroutines.flow.flow".to_string());
+    }
+    module.visit(|custom: &CustomType| {
+        if let Some(custom_config) = &custom.config {
+            imports.extend(custom_config.imports.iter().flatten().cloned());
+        }
+    });
+    module.visit(|ext: &ExternalType| {
+        imports.insert(format!("{}.{}", ext.package_name, ext.name));
+        imports.insert(format!(
+            "{}.{}",
+            ext.package_name, ext.self_type.ffi_converter_name
+        ));
+    });
+    if module.cleaner == Some(ObjectCleaner::Android) {
+        imports.insert("android.os.Build".to_string());
+        imports.insert("androidx.annotation.RequiresApi".to_string());
+    }
+    if module.has_async_fns {
+        imports.extend(
+            [
+                "kotlin.coroutines.resume",
+                "kotlinx.coroutines.launch",
+                "kotlinx.coroutines.suspendCancellableCoroutine",
+                "kotlinx.coroutines.CancellableContinuation",
+                "kotlinx.coroutines.DelicateCoroutinesApi",
+                "kotlinx.coroutines.Job",
+                "kotlinx.coroutines.GlobalScope",
+            ]
+            .map(str::to_string),
+        );
+    }
+    imports.into_iter().collect()
+}
+
+fn initialization_fns(module: &Module) -> Vec<String> {
+    let mut init_fns = vec![];
+    // Register the VTables for callback interfaces and trait interfaces
+    module.visit(|vtable: &VTable| {
+        init_fns.push(format!(
+            "uniffiCallbackInterface{}.register(this)",
+            vtable.interface_name
+        ));
+    });
+    // The foreign closure VTable is shared by all closure types
+    if module.has_closure_types {
+        init_fns.push("uniffiForeignClosure.register(this)".to_string());
+    }
+    // Also call global initialization function for any external type we use.
+    // For example, we need to make sure that all callback interface vtables are registered
+    // (#2343).
+    let mut extern_module_init_fns = BTreeSet::new();
+    module.visit(|ext: &ExternalType| {
+        extern_module_init_fns.insert(format!("{}.uniffiEnsureInitialized()", ext.package_name));
+    });
+    init_fns.extend(extern_module_init_fns);
+    init_fns
+}
@@ -0,0 +1,68 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+
+use super::*;
+
+pub fn map_ffi_function_type_name(
+    name: FfiFunctionTypeName,
+    _: &Context,
+) -> Result<FfiFunctionTypeName> {
+    Ok(FfiFunctionTypeName(ffi_callback_name(&name.0)))
+}
+
+pub fn map_ffi_struct_name(name: FfiStructName, _: &Context) -> Result<FfiStructName> {
+    Ok(FfiStructName(ffi_struct_name(&name.0)))
+}
+
+/// Get the idiomatic Kotlin rendering of a class name (for enums, records, errors, etc).
+///
+/// Use `Context::class_name` for type names, since that handles renaming error classes.
+pub fn class_name(name: &str) -> String {
+    name.to_upper_camel_case()
+}
+
+/// Replace the `Error` suffix for exception classes
+pub fn convert_error_suffix(name: &str) -> String {
+    match name.strip_suffix("Error") {
+        None => name.to_string(),
+        Some(stripped) => format!("{stripped}Exception"),
+    }
+}
+
+/// Get the idiomatic Kotlin rendering of a function name.
+pub fn fn_name(name: &str) -> String {
+    format!("`{}`", name.to_lower_camel_case())
+}
+
+/// Get the idiomatic Kotlin rendering of a variable name.
+pub fn var_name(name: &str) -> String {
+    format!("`{}`", name.to_lower_camel_case())
+}
+
+/// `var_name` without the backticks.  Useful for using in `@Structure.FieldOrder`.
+pub fn var_name_raw(name: &str) -> String {
+    name.to_lower_camel_case()
+}
+
+/// Get the idiomatic Kotlin rendering of an individual enum variant.
+pub fn enum_variant_name(name: &str) -> String {
+    name.to_shouty_snake_case()
+}
+
+/// Get the idiomatic Kotlin rendering of an error variant
+pub fn error_variant_name(name: &str) -> String {
+    convert_error_suffix(&name.to_upper_camel_case())
+}
+
+/// Get the idiomatic Kotlin rendering of an FFI callback function name
+pub fn ffi_callback_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
+
+/// Get the idiomatic Kotlin rendering of an FFI struct name
+pub fn ffi_struct_name(name: &str) -> String {
+    format!("Uniffi{}", name.to_upper_camel_case())
+}
@@ -0,0 +1,683 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::kotlin::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(
+    general::FfiFunctionTypeName,
+    names::map_ffi_function_type_name
+);
+use_prev_node!(general::FfiStructName, names::map_ffi_struct_name);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(from(namespaces))]
+    pub modules: IndexMap<String, Module>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "kt", escape = "none", path = "wrapper.kt")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    pub crate_name: String,
+    pub package_name: String,
+    pub cdylib_name: String,
+    pub omit_checksums: bool,
+    /// Use `entries` rather than `values()` for flat enums (Kotlin 1.9+)
+    pub use_enum_entries: bool,
+    /// Cleaner implementation, `None` if nothing in the module needs a cleaner
+    pub cleaner: Option<ObjectCleaner>,
+    pub has_async_fns: bool,
+    pub has_callback_definitions: bool,
+    pub has_async_callback_method: bool,
+    pub has_iterator_types: bool,
+    pub has_closure_types: bool,
+    /// Sorted and de-duplicated import statements
+    pub imports: Vec<String>,
+    /// Code to run when the `UniffiLib` object is initialized
+    pub initialization_fns: Vec<String>,
+    /// Arities of the `TupleN` classes we need to define
+    pub tuple_class_arities: Vec<u32>,
+    pub docstring: Option<String>,
+    pub functions: Vec<Function>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_alloc: RustFfiFunctionName,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_rustbuffer_reserve: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    // Correct contract version value
+    pub correct_contract_version: String,
+}
+
+/// Which `UniffiCleaner` implementation to generate
+#[derive(Debug, Clone, Copy, Node, PartialEq, Eq)]
+pub enum ObjectCleaner {
+    /// JNA's cleaner, used when the Java cleaner is disabled
+    Jna,
+    /// `android.system.SystemCleaner`, falling back to JNA on older devices
+    Android,
+    /// `java.lang.ref.Cleaner`, falling back to JNA on older JVMs
+    Jvm,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Array(ArrayType),
+    Map(MapType),
+    Set(SetType),
+    Tuple(TupleType),
+    Stream(StreamType),
+    Iterator(IteratorType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub default_ffi_func: Option<RustFfiFunctionName>,
+    pub docstring: Option<String>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::fn_name(&self.name))]
+    pub name: String,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub checksum: Option<u16>,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method { self_type: TypeNode },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    VTableMethod {
+        self_type: TypeNode,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    // FFI types for async Rust functions
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    // FFI types for async foreign functions
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    pub ffi_foreign_future_result: FfiStructName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ReturnType))]
+#[map_node(types::map_return_type)]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+    /// Kotlin type name, `Unit` for functions without a return type
+    pub type_name: String,
+    /// Package name for return values that are lowered into an external `RustBuffer`.
+    ///
+    /// Each package defines its own `RustBuffer` class, so these need to be converted when
+    /// completing async calls.
+    pub external_rust_buffer_package: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ThrowsType))]
+pub struct ThrowsType {
+    /// Is the error type defined in another package?
+    ///
+    /// External errors are lifted through a `{ClassName}ExternalErrorHandler`, since the
+    /// `ErrorHandler` companion object inputs that package's `RustBuffer`.
+    #[map_node(types::is_external_error(&self, context)?)]
+    pub is_external: bool,
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Type name used when passing the argument into Rust
+    ///
+    /// Borrowed `Bytes` (`&[u8]`) arguments input a `java.nio.ByteBuffer`, the only Kotlin type
+    /// JNA can expose a native pointer to.
+    #[map_node(callables::lower_type_name(&self, context)?)]
+    pub lower_type_name: String,
+    /// Function to lower the argument
+    #[map_node(callables::lower_fn(&self))]
+    pub lower_fn: String,
+    #[map_node(match &self.default {
+        Some(default) => Some(default::render_default(default, &self.ty.ty, context)?),
+        None => None,
+    })]
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub by_ref: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(default::render_default(
+        &general::DefaultValue::Literal(self.value.clone()),
+        &self.ty.ty,
+        context
+    )?)]
+    pub value: String,
+    /// Can this be declared with `const val`?
+    #[map_node(default::is_const_val(&self.ty.ty))]
+    pub is_const_val: bool,
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(context.class_name(&self.name))]
+    pub name: String,
+    #[map_node(context.config()?.is_record_immutable(&self.name))]
+    pub is_immutable: bool,
+    #[map_node(context.contains_object_references(&self.self_type.ty))]
+    pub contains_object_references: bool,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Field))]
+pub struct Field {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Type name with user-defined types qualified by their package
+    ///
+    /// This is used for enum variant fields, where the variant class name can shadow the type
+    /// name.
+    #[map_node(types::qualified_type_name(&self.ty.ty, context)?)]
+    pub qualified_type_name: String,
+    #[map_node(match &self.default {
+        Some(default) => Some(default::render_default(default, &self.ty.ty, context)?),
+        None => None,
+    })]
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+pub struct Enum {
+    #[map_node(context.class_name(&self.name))]
+    pub name: String,
+    #[map_node(context.contains_object_references(&self.self_type.ty))]
+    pub contains_object_references: bool,
+    #[map_node(enums::map_variants(
+        self.variants,
+        self.is_flat,
+        &self.shape,
+        &self.discr_type.ty,
+        context
+    )?)]
+    pub variants: Vec<Variant>,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    pub discr_type: TypeNode,
+    /// Was `discr_type` specified with a `#[repr]` attribute?
+    ///
+    /// Flat enums with a `discr_type` store the discriminant in a `value` property.
+    pub discr_type_specified: bool,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    pub name: String,
+    /// The discriminant, rendered as a Kotlin literal
+    pub discr: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(context.class_name(&self.name))]
+    pub name: String,
+    /// Name of the Kotlin interface for this object
+    #[map_node(interfaces::interface_name(&self, context))]
+    pub interface_name: String,
+    /// Name of the Kotlin class that implements the interface by calling into Rust
+    #[map_node(interfaces::impl_class_name(&self, context))]
+    pub impl_class_name: String,
+    /// Kotlin interfaces for the traits this object implements
+    #[map_node(interfaces::trait_interface_names(&self, context)?)]
+    pub trait_interface_names: Vec<String>,
+    #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
+    pub constructors: Vec<Constructor>,
+    pub docstring: Option<String>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+impl Interface {
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn alternate_constructors(&self) -> Vec<&Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+            .collect()
+    }
+
+    pub fn has_callback_interface(&self) -> bool {
+        self.imp.has_callback_interface()
+    }
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(context.class_name(&self.name))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    pub struct_type: FfiTypeNode,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    pub clone_fn_type: FfiFunctionTypeName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// FFI callback type for the method
+    #[map_node(ffi_types::vtable_method_callback(&self, context)?)]
+    pub ffi_callback: FfiFunctionType,
+    /// Default value to return when an async method fails
+    #[map_node(ffi_types::ffi_default_return_value(&self.callable.return_type, context)?)]
+    pub ffi_default_value: Option<String>,
+    pub callable: Callable,
+    pub ffi_type: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(context.custom_type_config(&self)?)]
+    pub config: Option<CustomTypeConfig>,
+    #[map_node(context.class_name(&self.name))]
+    pub name: String,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    #[map_node(types::tuple_class_name(self.inners.len()))]
+    pub class_name: String,
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::IteratorType))]
+pub struct IteratorType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: Option<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    #[map_node(context.class_name(&self.name))]
+    pub name: String,
+    #[map_node(types::external_package_name(&self.namespace, context)?)]
+    pub package_name: String,
+    pub namespace: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    #[map_node(types::type_name(&self.ty, context)?)]
+    pub type_name: String,
+    #[map_node(types::ffi_converter_name(&self))]
+    pub ffi_converter_name: String,
+    #[map_node(types::canonical_name(&self.ty))]
+    pub canonical_name: String,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+///
+/// The JNA type for an FFI type depends on where it's used, so we render each variation here.
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    #[map_node(ffi_types::ffi_type_name(&self, context)?)]
+    pub type_name: String,
+    /// Type name used for arguments and callback return values
+    #[map_node(ffi_types::ffi_type_name_by_value(&self, context)?)]
+    pub type_name_by_value: String,
+    /// Type name used for fields in `Structure` classes
+    #[map_node(ffi_types::ffi_type_name_for_ffi_struct(&self, context)?)]
+    pub type_name_for_ffi_struct: String,
+    /// Type name used for return values from `UniffiLib` functions
+    #[map_node(ffi_types::ffi_type_name_for_direct_return(&self, context)?)]
+    pub type_name_for_direct_return: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    pub name: RustFfiFunctionName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    pub name: FfiStructName,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Name without backticks, for `@Structure.FieldOrder`
+    #[map_node(names::var_name_raw(&self.name))]
+    pub name_raw: String,
+    #[map_node(ffi_types::ffi_default_value(&self.ty, context)?)]
+    pub default_value: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+
+    /// Type of `this` for methods
+    pub fn self_type(&self) -> Option<&TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type } | CallableKind::VTableMethod { self_type, .. } => {
+                Some(self_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Is this a method on an interface?
+    ///
+    /// These are called with `callWithHandle`, rather than by lowering `this`.
+    pub fn self_is_interface(&self) -> bool {
+        matches!(
+            self.self_type(),
+            Some(TypeNode {
+                ty: Type::Interface { .. },
+                ..
+            })
+        )
+    }
+}
+
+impl UniffiTraitMethods {
+    /// Are there any trait methods that go in the companion object?
+    pub fn has_static_methods(&self) -> bool {
+        self.default_default.is_some() || self.from_str_from_str.is_some()
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+}
+
+impl FfiFunction {
+    /// Is this a function called from the `IntegrityCheckingUniffiLib` object?
+    pub fn is_integrity_check(&self) -> bool {
+        matches!(
+            self.kind,
+            FfiFunctionKind::Checksum | FfiFunctionKind::UniffiContractVersion
+        )
+    }
+}
@@ -0,0 +1,265 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Kotlin-specific canonical name for a type
+///
+/// This differs from the general canonical name in a few ways: primitives use the Kotlin class
+/// names and boxed types use the inner type directly.
+pub fn canonical_name(ty: &Type) -> String {
+    match ty {
+        Type::UInt8 => "UByte".to_string(),
+        Type::Int8 => "Byte".to_string(),
+        Type::UInt16 => "UShort".to_string(),
+        Type::Int16 => "Short".to_string(),
+        Type::UInt32 => "UInt".to_string(),
+        Type::Int32 => "Int".to_string(),
+        Type::UInt64 => "ULong".to_string(),
+        Type::Int64 => "Long".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Int128 => "Int128".to_string(),
+        Type::Float32 => "Float".to_string(),
+        Type::Float64 => "Double".to_string(),
+        Type::Boolean => "Boolean".to_string(),
+        Type::Char => "Char".to_string(),
+        Type::String => "String".to_string(),
+        Type::Bytes => "ByteArray".to_string(),
+        Type::Timestamp => "Timestamp".to_string(),
+        Type::Duration => "Duration".to_string(),
+        Type::Interface { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::Custom { name, .. } => format!("Type{name}"),
+        Type::Box { inner_type } => canonical_name(inner_type),
+        Type::Optional { inner_type } => format!("Optional{}", canonical_name(inner_type)),
+        Type::Sequence { inner_type } => format!("Sequence{}", canonical_name(inner_type)),
+        Type::Array { inner_type, len } => format!("Array{len}{}", canonical_name(inner_type)),
+        Type::Map {
+            key_type,
+            value_type,
+            ordered,
+        } => format!(
+            "{}Map{}{}",
+            if *ordered { "Ordered" } else { "" },
+            canonical_name(key_type),
+            canonical_name(value_type),
+        ),
+        Type::Set {
+            inner_type,
+            ordered,
+        } => format!(
+            "{}Set{}",
+            if *ordered { "Ordered" } else { "" },
+            canonical_name(inner_type)
+        ),
+        Type::Tuple { inner_types } => format!(
+            "Tuple{}{}",
+            inner_types.len(),
+            inner_types
+                .iter()
+                .map(canonical_name)
+                .collect::<Vec<_>>()
+                .join(""),
+        ),
+        Type::Stream { item_type } => format!("Stream{}", canonical_name(item_type)),
+        Type::Iterator { item_type } => format!("Iterator{}", canonical_name(item_type)),
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "Closure{}{}To{}",
+            argument_types.len(),
+            argument_types
+                .iter()
+                .map(canonical_name)
+                .collect::<Vec<_>>()
+                .join(""),
+            match return_type {
+                Some(return_type) => canonical_name(return_type),
+                None => "Unit".to_string(),
+            },
+        ),
+    }
+}
+
+pub fn map_return_type(return_type: general::ReturnType, context: &Context) -> Result<ReturnType> {
+    Ok(match return_type.ty {
+        Some(ty) => {
+            let external_rust_buffer_package = match &ty.ffi_type {
+                FfiType::RustBuffer(Some(namespace)) => context.external_package_name(namespace)?,
+                _ => None,
+            };
+            let ty = ty.map_node(context)?;
+            ReturnType {
+                type_name: ty.type_name.clone(),
+                ty: Some(ty),
+                external_rust_buffer_package,
+            }
+        }
+        None => ReturnType {
+            ty: None,
+            type_name: "Unit".to_string(),
+            external_rust_buffer_package: None,
+        },
+    })
+}
+
+pub fn is_external_error(throws_type: &general::ThrowsType, context: &Context) -> Result<bool> {
+    Ok(
+        match throws_type.ty.as_ref().and_then(|ty| ty.ty.namespace()) {
+            Some(namespace) => namespace != context.module_namespace()?,
+            None => false,
+        },
+    )
+}
+
+/// Package name for a type, which may be in the current package or an external one
+pub fn external_package_name(namespace: &str, context: &Context) -> Result<String> {
+    match context.external_package_name(namespace)? {
+        Some(package_name) => Ok(package_name),
+        None => context.package_name(),
+    }
+}
+
+/// The Kotlin class used for a tuple with `arity` elements.
+///
+/// Tuples with 2 or 3 elements map to the builtin `Pair` and `Triple` classes, for anything
+/// larger we generate our own `TupleN` data class.
+pub fn tuple_class_name(arity: usize) -> String {
+    match arity {
+        2 => "Pair".into(),
+        3 => "Triple".into(),
+        _ => format!("Tuple{arity}"),
+    }
+}
+
+pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Boolean => "kotlin.Boolean".to_string(),
+        Type::String | Type::Char => "kotlin.String".to_string(),
+        Type::Bytes => "kotlin.ByteArray".to_string(),
+        Type::Int8 => "kotlin.Byte".to_string(),
+        Type::Int16 => "kotlin.Short".to_string(),
+        Type::Int32 => "kotlin.Int".to_string(),
+        Type::Int64 => "kotlin.Long".to_string(),
+        Type::UInt8 => "kotlin.UByte".to_string(),
+        Type::UInt16 => "kotlin.UShort".to_string(),
+        Type::UInt32 => "kotlin.UInt".to_string(),
+        Type::UInt64 => "kotlin.ULong".to_string(),
+        Type::Int128 | Type::UInt128 => "java.math.BigInteger".to_string(),
+        Type::Float32 => "kotlin.Float".to_string(),
+        Type::Float64 => "kotlin.Double".to_string(),
+        Type::Timestamp => "java.time.Instant".to_string(),
+        Type::Duration => "java.time.Duration".to_string(),
+        // External types are imported, so we can use the class name directly
+        Type::Interface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => context.class_name(name),
+        Type::Box { inner_type } => type_name(inner_type, context)?,
+        Type::Optional { inner_type } => format!("{}?", type_name(inner_type, context)?),
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => {
+            format!("List<{}>", type_name(inner_type, context)?)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+            ..
+        } => format!(
+            "Map<{}, {}>",
+            type_name(key_type, context)?,
+            type_name(value_type, context)?
+        ),
+        Type::Set { inner_type, .. } => format!("Set<{}>", type_name(inner_type, context)?),
+        Type::Tuple { inner_types } => format!(
+            "{}<{}>",
+            tuple_class_name(inner_types.len()),
+            inner_types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Stream { item_type } => format!("Flow<{}>", type_name(item_type, context)?),
+        Type::Iterator { item_type } => format!("Sequence<{}>", type_name(item_type, context)?),
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "({}) -> {}",
+            argument_types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", "),
+            match return_type {
+                Some(return_type) => type_name(return_type, context)?,
+                None => "Unit".to_string(),
+            }
+        ),
+    })
+}
+
+/// Type name, with user-defined types qualified by their package
+///
+/// This is used for sealed class variant fields, where the variant class name can shadow the
+/// type name.
+pub fn qualified_type_name(ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Optional { inner_type } => format!("{}?", qualified_type_name(inner_type, context)?),
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => {
+            format!("List<{}>", qualified_type_name(inner_type, context)?)
+        }
+        Type::Map {
+            key_type,
+            value_type,
+            ..
+        } => format!(
+            "Map<{}, {}>",
+            qualified_type_name(key_type, context)?,
+            qualified_type_name(value_type, context)?
+        ),
+        Type::Set { inner_type, .. } => {
+            format!("Set<{}>", qualified_type_name(inner_type, context)?)
+        }
+        Type::Tuple { inner_types } => format!(
+            "{}<{}>",
+            tuple_class_name(inner_types.len()),
+            inner_types
+                .iter()
+                .map(|ty| qualified_type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Interface {
+            namespace, name, ..
+        }
+        | Type::Record {
+            namespace, name, ..
+        }
+        | Type::Enum {
+            namespace, name, ..
+        }
+        | Type::CallbackInterface {
+            namespace, name, ..
+        }
+        | Type::Custom {
+            namespace, name, ..
+        } => {
+            format!(
+                "{}.{}",
+                external_package_name(namespace, context)?,
+                context.class_name(name)
+            )
+        }
+        _ => type_name(ty, context)?,
+    })
+}
+
+pub fn ffi_converter_name(ty: &general::TypeNode) -> String {
+    format!("FfiConverter{}", canonical_name(&ty.ty))
+}
@@ -0,0 +1,34 @@
+{%- let inner_type_name = array.inner.type_name %}
+{%- let ffi_converter_name = array.self_type.ffi_converter_name %}
+{%- let len = array.len %}
+
+/**
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<List<{{ inner_type_name }}>> {
+    override fun read(buf: ByteBuffer): List<{{ inner_type_name }}> {
+        val len = buf.getInt()
+        if (len != {{ len }}) {
+            throw RuntimeException("Expected an array of length {{ len }}, got $len items")
+        }
+        return List<{{ inner_type_name }}>(len) {
+            {{ array.inner.ffi_converter_name }}.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<{{ inner_type_name }}>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { {{ array.inner.ffi_converter_name }}.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<{{ inner_type_name }}>, buf: ByteBuffer) {
+        require(value.size == {{ len }}) {
+            "Expected an array of length {{ len }}, got ${value.size} items"
+        }
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            {{ array.inner.ffi_converter_name }}.write(it, buf)
+        }
+    }
+}
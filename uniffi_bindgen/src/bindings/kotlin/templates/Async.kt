@@ -15,6 +15,7 @@ internal object uniffiRustFutureContinuationCallbackImpl: UniffiRustFutureContin
 internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
     rustFuture: Long,
     pollFunc: (Long, UniffiRustFutureContinuationCallback, Long) -> Unit,
+    cancelFunc: (Long) -> Unit,
     completeFunc: (Long, UniffiRustCallStatus) -> F,
     freeFunc: (Long) -> Unit,
     liftFunc: (F) -> T,
@@ -23,6 +24,9 @@ internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
     try {
         do {
             val pollResult = suspendCancellableCoroutine<Byte> { continuation ->
+                // If the coroutine is cancelled, cancel the Rust future so that it gets dropped
+                // promptly.  The coroutine then resumes with a `CancellationException`.
+                continuation.invokeOnCancellation { cancelFunc(rustFuture) }
                 pollFunc(
                     rustFuture,
                     uniffiRustFutureContinuationCallbackImpl,
@@ -39,7 +43,7 @@ internal suspend fun<T, F, E: kotlin.Exception> uniffiRustCallAsync(
     }
 }
 
-{%- if ci.has_async_callback_interface_definition() %}
+{%- if has_async_callback_method %}
 internal inline fun<T> uniffiTraitInterfaceCallAsync(
     crossinline makeCall: suspend () -> T,
     crossinline handleSuccess: (T) -> Unit,
@@ -67,7 +71,7 @@ internal inline fun<T> uniffiTraitInterfaceCallAsync(
             handleError(
                 UniffiRustCallStatus.create(
                     UNIFFI_CALL_UNEXPECTED_ERROR,
-                    {{ Type::String.borrow()|lower_fn }}(e.toString()),
+                    FfiConverterString.lower(e.toString()),
                 )
             )
             return@coroutineBlock
@@ -104,7 +108,7 @@ internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallAsyncWithEr
                 handleError(
                     UniffiRustCallStatus.create(
                         UNIFFI_CALL_UNEXPECTED_ERROR,
-                        {{ Type::String.borrow()|lower_fn }}(e.toString()),
+                        FfiConverterString.lower(e.toString()),
                     )
                 )
             }
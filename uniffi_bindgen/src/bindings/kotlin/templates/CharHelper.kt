@@ -0,0 +1,29 @@
+/**
+ * @suppress
+ */
+public object FfiConverterChar: FfiConverter<String, Int> {
+    override fun lift(value: Int): String {
+        return String(Character.toChars(value))
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: String): Int {
+        require(value.codePointCount(0, value.length) == 1) {
+            "char requires a string containing a single code point, got \"$value\""
+        }
+        val codePoint = value.codePointAt(0)
+        require(codePoint !in 0xD800..0xDFFF) {
+            "char can't be a surrogate code point"
+        }
+        return codePoint
+    }
+
+    override fun allocationSize(value: String) = 4UL
+
+    override fun write(value: String, buf: ByteBuffer) {
+        buf.putInt(lower(value))
+    }
+}
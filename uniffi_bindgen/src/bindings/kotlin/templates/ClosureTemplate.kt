@@ -0,0 +1,59 @@
+{%- let type_name = closure.self_type.type_name %}
+{%- let ffi_converter_name = closure.self_type.ffi_converter_name %}
+/**
+ * Converts between Kotlin functions and Rust closures
+ *
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverter<{{ type_name }}, Long> {
+    override fun lift(value: Long): {{ type_name }} {
+        val rustClosure = UniffiRustClosure(value)
+        return { {% for arg_type in closure.arguments %}arg{{ loop.index0 }}: {{ arg_type.type_name }}{% if !loop.last %}, {% endif %}{% endfor %} ->
+            val args = uniffiLowerClosureBuffer(
+                0UL{% for arg_type in closure.arguments %} + {{ arg_type.ffi_converter_name }}.allocationSize(arg{{ loop.index0 }}){% endfor %}
+            ) { {% if closure.arguments.is_empty() %}_{% else %}buf{% endif %} ->
+                {%- for arg_type in closure.arguments %}
+                {{ arg_type.ffi_converter_name }}.write(arg{{ loop.index0 }}, buf)
+                {%- endfor %}
+            }
+            uniffiLiftClosureBuffer(rustClosure.call(args)) { {% if closure.return_type.is_some() %}buf{% else %}_{% endif %} ->
+                {%- match closure.return_type %}
+                {%- when Some(return_type) %}
+                {{ return_type.ffi_converter_name }}.read(buf)
+                {%- when None %}
+                Unit
+                {%- endmatch %}
+            }
+        }
+    }
+
+    override fun read(buf: ByteBuffer): {{ type_name }} {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: {{ type_name }}): Long {
+        return uniffiForeignClosure.handleMap.insert { args ->
+            {% if closure.return_type.is_some() %}val returnValue = {% endif %}uniffiLiftClosureBuffer(args) { {% if closure.arguments.is_empty() %}_{% else %}buf{% endif %} ->
+                value(
+                    {%- for arg_type in closure.arguments %}
+                    {{ arg_type.ffi_converter_name }}.read(buf),
+                    {%- endfor %}
+                )
+            }
+            {%- match closure.return_type %}
+            {%- when Some(return_type) %}
+            uniffiLowerClosureBuffer({{ return_type.ffi_converter_name }}.allocationSize(returnValue)) { buf ->
+                {{ return_type.ffi_converter_name }}.write(returnValue, buf)
+            }
+            {%- when None %}
+            RustBuffer.ByValue()
+            {%- endmatch %}
+        }
+    }
+
+    override fun allocationSize(value: {{ type_name }}) = 8UL
+
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        buf.putLong(lower(value))
+    }
+}
@@ -1,58 +1,52 @@
-{%- let docstring = ci.get_custom_type_definition(name.as_str()).and_then(CustomType::docstring) %}
-{%- match config.custom_types.get(name.as_str())  %}
+{%- let type_name = custom.self_type.type_name %}
+{%- let ffi_converter_name = custom.self_type.ffi_converter_name %}
+{%- let builtin = custom.builtin %}
+{%- match custom.config %}
 {%- when None %}
 {#- Define the type using typealiases to the builtin #}
-{%- call kt::docstring_value(docstring, 0) %}{% endcall %}
-public typealias {{ type_name }} = {{ builtin|type_name(ci) }}
-public typealias {{ ffi_converter_name }} = {{ builtin|ffi_converter_name }}
+{%- call kt::docstring_value(custom.docstring, 0) %}{% endcall %}
+public typealias {{ type_name }} = {{ builtin.type_name }}
+public typealias {{ ffi_converter_name }} = {{ builtin.ffi_converter_name }}
 
 {%- when Some(config) %}
 
-{%- let ffi_type_name=builtin|ffi_type|ref|ffi_type_name_by_value(ci) %}
+{%- let ffi_type_name = builtin.ffi_type.type_name_by_value %}
 
 {# When the config specifies a different type name, create a typealias for it #}
 {%- match config.type_name %}
 {%- when Some(concrete_type_name) %}
-{%- call kt::docstring_value(docstring, 0) %}{% endcall %}
+{%- call kt::docstring_value(custom.docstring, 0) %}{% endcall %}
 public typealias {{ type_name }} = {{ concrete_type_name }}
 {%- else %}
 {%- endmatch %}
 
-{%- match config.imports %}
-{%- when Some(imports) %}
-{%- for import_name in imports %}
-{{ self.add_import(import_name) }}
-{%- endfor %}
-{%- else %}
-{%- endmatch %}
-
 /**
  * @suppress
  */
 public object {{ ffi_converter_name }}: FfiConverter<{{ type_name }}, {{ ffi_type_name }}> {
     override fun lift(value: {{ ffi_type_name }}): {{ type_name }} {
-        val builtinValue = {{ builtin|lift_fn }}(value)
+        val builtinValue = {{ builtin.ffi_converter_name }}.lift(value)
         return {{ config.lift("builtinValue") }}
     }
 
     override fun lower(value: {{ type_name }}): {{ ffi_type_name }} {
         val builtinValue = {{ config.lower("value") }}
-        return {{ builtin|lower_fn }}(builtinValue)
+        return {{ builtin.ffi_converter_name }}.lower(builtinValue)
     }
 
     override fun read(buf: ByteBuffer): {{ type_name }} {
-        val builtinValue = {{ builtin|read_fn }}(buf)
+        val builtinValue = {{ builtin.ffi_converter_name }}.read(buf)
         return {{ config.lift("builtinValue") }}
     }
 
     override fun allocationSize(value: {{ type_name }}): ULong {
         val builtinValue = {{ config.lower("value") }}
-        return {{ builtin|allocation_size_fn }}(builtinValue)
+        return {{ builtin.ffi_converter_name }}.allocationSize(builtinValue)
     }
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
         val builtinValue = {{ config.lower("value") }}
-        {{ builtin|write_fn }}(builtinValue, buf)
+        {{ builtin.ffi_converter_name }}.write(builtinValue, buf)
     }
 }
 {%- endmatch %}
@@ -4,6 +4,7 @@
 internal const val UNIFFI_CALL_SUCCESS = 0.toByte()
 internal const val UNIFFI_CALL_ERROR = 1.toByte()
 internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
+internal const val UNIFFI_CALL_CANCELLED = 3.toByte()
 
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
@@ -24,6 +25,10 @@ internal open class UniffiRustCallStatus : Structure() {
         return code == UNIFFI_CALL_UNEXPECTED_ERROR
     }
 
+    fun isCancelled(): Boolean {
+        return code == UNIFFI_CALL_CANCELLED
+    }
+
     companion object {
         fun create(code: Byte, errorBuf: RustBuffer.ByValue): UniffiRustCallStatus.ByValue {
             val callStatus = UniffiRustCallStatus.ByValue()
@@ -68,10 +73,12 @@ private fun<E: kotlin.Exception> uniffiCheckCallStatus(errorHandler: UniffiRustC
         // with the message.  but if that code panics, then it just sends back
         // an empty buffer.
         if (status.error_buf.len > 0) {
-            throw InternalException({{ Type::String.borrow()|lift_fn }}(status.error_buf))
+            throw InternalException(FfiConverterString.lift(status.error_buf))
         } else {
             throw InternalException("Rust panic")
         }
+    } else if (status.isCancelled()) {
+        throw kotlin.coroutines.cancellation.CancellationException("Rust future was cancelled")
     } else {
         throw InternalException("Unknown rust call status: $status.code")
     }
@@ -104,7 +111,7 @@ internal inline fun<T> uniffiTraitInterfaceCall(
     } catch(e: kotlin.Exception) {
         val err = try { e.stackTraceToString() } catch(_: Throwable) { "" }
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
-        callStatus.error_buf = {{ Type::String.borrow()|lower_fn }}(err)
+        callStatus.error_buf = FfiConverterString.lower(err)
     }
 }
 
@@ -123,7 +130,7 @@ internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
         } else {
             val err = try { e.stackTraceToString() } catch(_: Throwable) { "" }
             callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
-            callStatus.error_buf = {{ Type::String.borrow()|lower_fn }}(err)
+            callStatus.error_buf = FfiConverterString.lower(err)
         }
     }
 }
@@ -0,0 +1,25 @@
+/**
+ * @suppress
+ */
+public object FfiConverterInt128: FfiConverterRustBuffer<java.math.BigInteger> {
+    override fun read(buf: ByteBuffer): java.math.BigInteger {
+        val bytes = ByteArray(16)
+        buf.get(bytes)
+        return java.math.BigInteger(bytes)
+    }
+
+    override fun allocationSize(value: java.math.BigInteger) = 16UL
+
+    override fun write(value: java.math.BigInteger, buf: ByteBuffer) {
+        if (value.bitLength() > 127) {
+            throw IllegalArgumentException("i128 requires -2^127 <= value < 2^127, got $value")
+        }
+        // `toByteArray()` returns the minimal two's-complement representation, so sign-extend it
+        // to 16 bytes.
+        val bytes = value.toByteArray()
+        val len = minOf(bytes.size, 16)
+        val padded = ByteArray(16) { (if (value.signum() < 0) -1 else 0).toByte() }
+        System.arraycopy(bytes, bytes.size - len, padded, 16 - len, len)
+        buf.put(padded)
+    }
+}
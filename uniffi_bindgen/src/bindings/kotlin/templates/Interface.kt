@@ -2,12 +2,21 @@
 public interface {{ interface_name }} {
     {% for meth in methods.iter() -%}
     {%- call kt::docstring(meth, 4) %}{% endcall %}
-    {% if meth.is_async() -%}suspend {% endif -%}
-    fun {{ meth.name()|fn_name }}({% call kt::arg_list(meth, true) %}{% endcall %})
-    {%- match meth.return_type() -%}
-    {%- when Some(return_type) %}: {{ return_type|type_name(ci) -}}
+    {% if meth.callable.is_async() -%}suspend {% endif -%}
+    fun {{ meth.callable.name }}({% call kt::arg_list(meth.callable, true) %}{% endcall %})
+    {%- match meth.callable.return_type.ty -%}
+    {%- when Some(return_type) %}: {{ return_type.type_name -}}
     {%- else -%}
     {%- endmatch %}
+    {%- if let Some(default_ffi_func) = meth.default_ffi_func %} {
+        {%- match meth.callable.return_type.ty %}
+        {%- when Some(return_type) %}
+        return {{ return_type.ffi_converter_name }}.lift({% call kt::default_method_call(meth.callable, default_ffi_func) %}{% endcall %})
+        {%- when None %}
+        {% call kt::default_method_call(meth.callable, default_ffi_func) %}{% endcall %}
+        {%- endmatch %}
+    }
+    {%- endif %}
     {% endfor %}
     companion object
 }
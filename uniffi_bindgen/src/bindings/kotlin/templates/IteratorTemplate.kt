@@ -0,0 +1,43 @@
+{%- let item_type_name = iterator.item.type_name %}
+{%- let ffi_converter_name = iterator.self_type.ffi_converter_name %}
+
+/**
+ * Lifts Rust iterators into a `Sequence`
+ *
+ * Items are fetched from Rust in chunks, the Rust iterator is freed once the sequence is exhausted
+ * or garbage collected.  The sequence can only be iterated once.
+ *
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverter<Sequence<{{ item_type_name }}>, Long> {
+    override fun lift(value: Long): Sequence<{{ item_type_name }}> {
+        return UniffiRustIterator(value) { liftChunk(it) }.asSequence()
+    }
+
+    // Lift the `Vec` returned by `rust_iter_next_chunk`, an empty list signals the end of the iterator
+    private fun liftChunk(rbuf: RustBuffer.ByValue): List<{{ item_type_name }}> {
+        val buf = rbuf.asByteBuffer()!!
+        try {
+            val len = buf.getInt()
+            return List<{{ item_type_name }}>(len) {
+                {{ iterator.item.ffi_converter_name }}.read(buf)
+            }
+        } finally {
+            RustBuffer.free(rbuf)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): Sequence<{{ item_type_name }}> {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: Sequence<{{ item_type_name }}>): Long {
+        throw RuntimeException("Iterators can't be passed to Rust")
+    }
+
+    override fun allocationSize(value: Sequence<{{ item_type_name }}>) = 8UL
+
+    override fun write(value: Sequence<{{ item_type_name }}>, buf: ByteBuffer) {
+        throw RuntimeException("Iterators can't be passed to Rust")
+    }
+}
@@ -1,5 +1,6 @@
-{%- let key_type_name = key_type|type_name(ci) %}
-{%- let value_type_name = value_type|type_name(ci) %}
+{%- let key_type_name = map.key.type_name %}
+{%- let value_type_name = map.value.type_name %}
+{%- let ffi_converter_name = map.self_type.ffi_converter_name %}
 
 /**
  * @suppress
@@ -7,10 +8,15 @@
 public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_name }}, {{ value_type_name }}>> {
     override fun read(buf: ByteBuffer): Map<{{ key_type_name }}, {{ value_type_name }}> {
         val len = buf.getInt()
+        {%- if map.ordered %}
+        // Rust sent us an ordered map, use a `LinkedHashMap` to keep the entries in that order.
+        return LinkedHashMap<{{ key_type_name }}, {{ value_type_name }}>(len).apply {
+        {%- else %}
         return buildMap<{{ key_type_name }}, {{ value_type_name }}>(len) {
+        {%- endif %}
             repeat(len) {
-                val k = {{ key_type|read_fn }}(buf)
-                val v = {{ value_type|read_fn }}(buf)
+                val k = {{ map.key.ffi_converter_name }}.read(buf)
+                val v = {{ map.value.ffi_converter_name }}.read(buf)
                 this[k] = v
             }
         }
@@ -19,8 +25,8 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_n
     override fun allocationSize(value: Map<{{ key_type_name }}, {{ value_type_name }}>): ULong {
         val spaceForMapSize = 4UL
         val spaceForChildren = value.map { (k, v) ->
-            {{ key_type|allocation_size_fn }}(k) +
-            {{ value_type|allocation_size_fn }}(v)
+            {{ map.key.ffi_converter_name }}.allocationSize(k) +
+            {{ map.value.ffi_converter_name }}.allocationSize(v)
         }.sum()
         return spaceForMapSize + spaceForChildren
     }
@@ -31,8 +37,8 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Map<{{ key_type_n
         // which is important for compatibility with older android devices.
         // Ref https://blog.danlew.net/2017/03/16/kotlin-puzzler-whose-line-is-it-anyways/
         value.forEach { (k, v) ->
-            {{ key_type|write_fn }}(k, buf)
-            {{ value_type|write_fn }}(v, buf)
+            {{ map.key.ffi_converter_name }}.write(k, buf)
+            {{ map.value.ffi_converter_name }}.write(v, buf)
         }
     }
 }
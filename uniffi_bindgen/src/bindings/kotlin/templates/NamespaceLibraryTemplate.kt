@@ -4,57 +4,55 @@ private fun findLibraryName(componentName: String): String {
     if (libOverride != null) {
         return libOverride
     }
-    return "{{ config.cdylib_name() }}"
+    return "{{ cdylib_name }}"
 }
 
 // Define FFI callback types
-{%- for def in ci.ffi_definitions() %}
+{%- for def in ffi_definitions %}
 {%- match def %}
-{%- when FfiDefinition::CallbackFunction(callback) %}
-internal interface {{ callback.name()|ffi_callback_name }} : com.sun.jna.Callback {
+{%- when FfiDefinition::FunctionType(callback) %}
+internal interface {{ callback.name.0 }} : com.sun.jna.Callback {
     fun callback(
-        {%- for arg in callback.arguments() -%}
-        {{ arg.name().borrow()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_by_value(ci) }},
+        {%- for arg in callback.arguments -%}
+        {{ arg.name }}: {{ arg.ty.type_name_by_value }},
         {%- endfor -%}
-        {%- if callback.has_rust_call_status_arg() -%}
+        {%- if callback.has_rust_call_status_arg -%}
         uniffiCallStatus: UniffiRustCallStatus,
         {%- endif -%}
     )
-    {%- if let Some(return_type) = callback.return_type() %}
-    : {{ return_type|ffi_type_name_by_value(ci) }}
+    {%- if let Some(return_type) = callback.return_type.ty %}
+    : {{ return_type.type_name_by_value }}
     {%- endif %}
 }
 {%- when FfiDefinition::Struct(ffi_struct) %}
-@Structure.FieldOrder({% for field in ffi_struct.fields() %}"{{ field.name()|var_name_raw }}"{% if !loop.last %}, {% endif %}{% endfor %})
-internal open class {{ ffi_struct.name()|ffi_struct_name }}(
-    {%- for field in ffi_struct.fields() %}
-    @JvmField internal var {{ field.name()|var_name }}: {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(ci) }} = {{ field.type_()|ffi_default_value }},
+@Structure.FieldOrder({% for field in ffi_struct.fields %}"{{ field.name_raw }}"{% if !loop.last %}, {% endif %}{% endfor %})
+internal open class {{ ffi_struct.name.0 }}(
+    {%- for field in ffi_struct.fields %}
+    @JvmField internal var {{ field.name }}: {{ field.ty.type_name_for_ffi_struct }} = {{ field.default_value }},
     {%- endfor %}
 ) : Structure() {
     class UniffiByValue(
-        {%- for field in ffi_struct.fields() %}
-        {{ field.name()|var_name }}: {{ field.type_().borrow()|ffi_type_name_for_ffi_struct(ci) }} = {{ field.type_()|ffi_default_value }},
+        {%- for field in ffi_struct.fields %}
+        {{ field.name }}: {{ field.ty.type_name_for_ffi_struct }} = {{ field.default_value }},
         {%- endfor %}
-    ): {{ ffi_struct.name()|ffi_struct_name }}({%- for field in ffi_struct.fields() %}{{ field.name()|var_name }}, {%- endfor %}), Structure.ByValue
+    ): {{ ffi_struct.name.0 }}({%- for field in ffi_struct.fields %}{{ field.name }}, {%- endfor %}), Structure.ByValue
 
-   internal fun uniffiSetValue(other: {{ ffi_struct.name()|ffi_struct_name }}) {
-        {%- for field in ffi_struct.fields() %}
-        {{ field.name()|var_name }} = other.{{ field.name()|var_name }}
+   internal fun uniffiSetValue(other: {{ ffi_struct.name.0 }}) {
+        {%- for field in ffi_struct.fields %}
+        {{ field.name }} = other.{{ field.name }}
         {%- endfor %}
     }
 
 }
-{%- when FfiDefinition::Function(_) %}
+{%- when FfiDefinition::RustFunction(_) %}
 {#- functions are handled below #}
 {%- endmatch %}
 {%- endfor %}
 
-{%- macro decl_kotlin_functions(func_list) -%}
-{% for func in func_list -%}
-external fun {{ func.name() }}(
+{%- macro decl_kotlin_function(func) -%}
+external fun {{ func.name.0 }}(
     {%- call kt::arg_list_ffi_decl(func) %}{% endcall %}
-): {% match func.return_type() %}{% when Some with (return_type) %}{{ return_type.borrow()|ffi_type_name_for_direct_return(ci) }}{% when None %}Unit{% endmatch %}
-{% endfor %}
+): {% match func.return_type.ty %}{% when Some(return_type) %}{{ return_type.type_name_for_direct_return }}{% when None %}Unit{% endmatch %}
 {%- endmacro %}
 
 // A JNA Library to expose the extern-C FFI definitions.
@@ -75,19 +73,23 @@ external fun {{ func.name() }}(
 // We now use JNA's "direct mapping" - unclear if same considerations apply exactly.
 internal object IntegrityCheckingUniffiLib {
     init {
-        Native.register(IntegrityCheckingUniffiLib::class.java, findLibraryName(componentName = "{{ ci.namespace() }}"))
+        Native.register(IntegrityCheckingUniffiLib::class.java, findLibraryName(componentName = "{{ name }}"))
         uniffiCheckContractApiVersion(this)
-{%- if !config.omit_checksums %}
+{%- if !omit_checksums %}
         uniffiCheckApiChecksums(this)
 {%- endif %}
     }
-    {% filter indent(4) %}
-    {%- call decl_kotlin_functions(ci.iter_ffi_function_integrity_checks()) %}{% endcall %}
-    {% endfilter %}
+    {%- for def in ffi_definitions %}
+    {%- if let FfiDefinition::RustFunction(func) = def %}
+    {%- if func.is_integrity_check() %}
+    {% call decl_kotlin_function(func) %}{% endcall %}
+    {%- endif %}
+    {%- endif %}
+    {%- endfor %}
 }
 
 internal object UniffiLib {
-    {% if ci.contains_object_types() %}
+    {% if cleaner.is_some() %}
     // The Cleaner for the whole library
     internal val CLEANER: UniffiCleaner by lazy {
         UniffiCleaner.create()
@@ -95,32 +97,35 @@ internal object UniffiLib {
     {% endif %}
 
     init {
-        Native.register(UniffiLib::class.java, findLibraryName(componentName = "{{ ci.namespace() }}"))
-        {% for fn_item in self.initialization_fns(ci) -%}
+        Native.register(UniffiLib::class.java, findLibraryName(componentName = "{{ name }}"))
+        {% for fn_item in initialization_fns -%}
         {{ fn_item }}
         {% endfor %}
     }
-    {#- XXX - this `filter indent` doesn't seem to work, even though the one above does? #}
-    {% filter indent(4) %}
-    {%- call decl_kotlin_functions(ci.iter_ffi_function_definitions_excluding_integrity_checks()) %}{% endcall %}
-    {% endfilter %}
+    {%- for def in ffi_definitions %}
+    {%- if let FfiDefinition::RustFunction(func) = def %}
+    {%- if !func.is_integrity_check() %}
+    {% call decl_kotlin_function(func) %}{% endcall %}
+    {%- endif %}
+    {%- endif %}
+    {%- endfor %}
 }
 
 private fun uniffiCheckContractApiVersion(lib: IntegrityCheckingUniffiLib) {
     // Get the bindings contract version from our ComponentInterface
-    val bindings_contract_version = {{ ci.uniffi_contract_version() }}
+    val bindings_contract_version = {{ correct_contract_version }}
     // Get the scaffolding contract version by calling the into the dylib
-    val scaffolding_contract_version = lib.{{ ci.ffi_uniffi_contract_version().name() }}()
+    val scaffolding_contract_version = lib.{{ ffi_uniffi_contract_version.0 }}()
     if (bindings_contract_version != scaffolding_contract_version) {
         throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
     }
 }
 
-{%- if !config.omit_checksums %}
+{%- if !omit_checksums %}
 @Suppress("UNUSED_PARAMETER")
 private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
-    {%- for (name, expected_checksum) in ci.iter_checksums() %}
-    if (lib.{{ name }}() != {{ expected_checksum }}) {
+    {%- for checksum in checksums %}
+    if (lib.{{ checksum.fn_name.0 }}() != {{ checksum.checksum }}) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     {%- endfor %}
@@ -33,16 +33,20 @@ private class UniffiJnaCleanable(
     override fun clean() = cleanable.clean()
 }
 
-{% if config.disable_java_cleaner() %}
+{%- match cleaner %}
+{%- when Some(ObjectCleaner::Jna) %}
 private fun UniffiCleaner.Companion.create(): UniffiCleaner = UniffiJnaCleaner()
-{% else %}
+{%- when Some(ObjectCleaner::Android) %}
 // We decide at uniffi binding generation time whether we were
 // using Android or not.
 // There are further runtime checks to chose the correct implementation
 // of the cleaner.
-{% if config.android_cleaner() %}
-{%-   include "ObjectCleanerHelperAndroid.kt" %}
-{%- else %}
-{%-   include "ObjectCleanerHelperJvm.kt" %}
-{%- endif %}
-{%- endif %}
+{%   include "ObjectCleanerHelperAndroid.kt" %}
+{%- when Some(ObjectCleaner::Jvm) %}
+// We decide at uniffi binding generation time whether we were
+// using Android or not.
+// There are further runtime checks to chose the correct implementation
+// of the cleaner.
+{%   include "ObjectCleanerHelperJvm.kt" %}
+{%- when None %}
+{%- endmatch %}
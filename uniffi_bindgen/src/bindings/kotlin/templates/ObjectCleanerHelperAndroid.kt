@@ -1,5 +1,3 @@
-{{- self.add_import("android.os.Build") }}
-{{- self.add_import("androidx.annotation.RequiresApi") }}
 
 private fun UniffiCleaner.Companion.create(): UniffiCleaner =
     if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.UPSIDE_DOWN_CAKE) {
@@ -92,26 +92,25 @@
 // [1] https://stackoverflow.com/questions/24376768/can-java-finalize-an-object-when-it-is-still-in-scope/24380219
 //
 
-{{ self.add_import("java.util.concurrent.atomic.AtomicBoolean") }}
 
-{%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let interface_name = self::object_interface_name(ci, obj) %}
-{%- let impl_class_name = self::object_impl_name(ci, obj) %}
-{%- let methods = obj.methods() %}
-{%- let uniffi_trait_methods = obj.uniffi_trait_methods() %}
-{%- let interface_docstring = obj.docstring() %}
-{%- let is_error = ci.is_name_used_as_error(name) %}
-{%- let ffi_converter_name = obj|ffi_converter_name %}
+{%- let interface_name = int.interface_name %}
+{%- let impl_class_name = int.impl_class_name %}
+{%- let methods = int.methods %}
+{%- let uniffi_trait_methods = int.uniffi_trait_methods %}
+{%- let interface_docstring = int.docstring %}
+{%- let is_error = int.self_type.is_used_as_error %}
+{%- let type_name = int.self_type.type_name %}
+{%- let ffi_converter_name = int.self_type.ffi_converter_name %}
 
 {%- include "Interface.kt" %}
 
-{%- call kt::docstring(obj, 0) %}{% endcall %}
+{%- call kt::docstring(int, 0) %}{% endcall %}
 {% if (is_error) %}
 open class {{ impl_class_name }} : kotlin.Exception, Disposable, AutoCloseable, {{ interface_name }} {
 {% else -%}
 open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }}
-{%- for t in obj.trait_impls() %}
-, {{ self::trait_interface_name(ci, t.trait_ty)? }}
+{%- for trait_interface_name in int.trait_interface_names %}
+, {{ trait_interface_name }}
 {% endfor %}
 {%- if uniffi_trait_methods.ord_cmp.is_some() %}
 , Comparable<{{ impl_class_name }}>
@@ -141,10 +140,10 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
         this.cleanable = null
     }
 
-    {%- if let Some(cons) = obj.primary_constructor() %}
+    {%- if let Some(cons) = int.primary_constructor() %}
     {%- call kt::docstring(cons, 4) %}{% endcall %}
-    constructor({% call kt::arg_list(cons, true) -%}{% endcall -%}) :
-        this(UniffiWithHandle, {% call kt::to_ffi_call(cons) %}{% endcall %})
+    constructor({% call kt::arg_list(cons.callable, true) -%}{% endcall -%}) :
+        this(UniffiWithHandle, {% call kt::to_ffi_call(cons.callable) %}{% endcall %})
     {%- endif %}
 
     protected val handle: Long
@@ -206,7 +205,7 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
                 return;
             }
             uniffiRustCall { status ->
-                UniffiLib.{{ obj.ffi_object_free().name() }}(handle, status)
+                UniffiLib.{{ int.ffi_func_free.0 }}(handle, status)
             }
         }
     }
@@ -219,7 +218,7 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
             throw InternalException("uniffiCloneHandle() called on NoHandle object");
         }
         return uniffiRustCall() { status ->
-            UniffiLib.{{ obj.ffi_object_clone().name() }}(handle, status)
+            UniffiLib.{{ int.ffi_func_clone.0 }}(handle, status)
         }
     }
 
@@ -228,13 +227,18 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
     {% endfor %}
 
     {% call kt::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
+    {% call kt::uniffi_trait_inherent_methods(uniffi_trait_methods, false) %}{% endcall %}
 
     {# XXX - "companion object" confusion? How to have alternate constructors *and* be an error? #}
-    {% if !obj.alternate_constructors().is_empty() -%}
+    {% if !int.alternate_constructors().is_empty() -%}
     companion object {
-        {% for cons in obj.alternate_constructors() -%}
+        {% for cons in int.alternate_constructors() -%}
         {% call kt::func_decl("", cons, 4) %}{% endcall %}
         {% endfor %}
+        {% for meth in int.static_methods -%}
+        {% call kt::func_decl("", meth, 8) %}{% endcall %}
+        {% endfor %}
+        {%- call kt::uniffi_trait_static_methods(uniffi_trait_methods) %}{% endcall %}
     }
     {% else if is_error %}
     companion object ErrorHandler : UniffiRustCallStatusErrorHandler<{{ impl_class_name }}> {
@@ -246,16 +250,20 @@ open class {{ impl_class_name }}: Disposable, AutoCloseable, {{ interface_name }
             }
             return {{ ffi_converter_name }}.read(bb)
         }
+        {% for meth in int.static_methods -%}
+        {% call kt::func_decl("", meth, 8) %}{% endcall %}
+        {% endfor %}
+        {%- call kt::uniffi_trait_static_methods(uniffi_trait_methods) %}{% endcall %}
     }
     {% else %}
     /**
      * @suppress
      */
-    companion object
+    {%- call kt::companion_object(int.static_methods, uniffi_trait_methods) %}{% endcall %}
     {% endif %}
 }
 
-{%- if !obj.has_callback_interface() %}
+{%- if !int.has_callback_interface() %}
 {# Simple case: the interface can only be implemented in Rust #}
 
 /**
@@ -286,9 +294,7 @@ public object {{ ffi_converter_name }}: FfiConverter<{{ type_name }}, Long> {
  # * Generate a callback interface implementation to handle the Kotlin side
  # * In the FfiConverter, check which side a handle came from to know how to handle correctly.
 #}
-{%- let vtable = obj.vtable().expect("trait interface should have a vtable") %}
-{%- let vtable_methods = obj.vtable_methods() %}
-{%- let ffi_init_callback = obj.ffi_init_callback() %}
+{%- let vtable = int.vtable.as_ref().expect("trait interface should have a vtable") %}
 {% include "CallbackInterfaceImpl.kt" %}
 
 /**
@@ -1,4 +1,5 @@
-{%- let inner_type_name = inner_type|type_name(ci) %}
+{%- let inner_type_name = opt.inner.type_name %}
+{%- let ffi_converter_name = opt.self_type.ffi_converter_name %}
 
 /**
  * @suppress
@@ -8,14 +9,14 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_nam
         if (buf.get().toInt() == 0) {
             return null
         }
-        return {{ inner_type|read_fn }}(buf)
+        return {{ opt.inner.ffi_converter_name }}.read(buf)
     }
 
     override fun allocationSize(value: {{ inner_type_name }}?): ULong {
         if (value == null) {
             return 1UL
         } else {
-            return 1UL + {{ inner_type|allocation_size_fn }}(value)
+            return 1UL + {{ opt.inner.ffi_converter_name }}.allocationSize(value)
         }
     }
 
@@ -24,7 +25,7 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ inner_type_nam
             buf.put(0)
         } else {
             buf.put(1)
-            {{ inner_type|write_fn }}(value, buf)
+            {{ opt.inner.ffi_converter_name }}.write(value, buf)
         }
     }
 }
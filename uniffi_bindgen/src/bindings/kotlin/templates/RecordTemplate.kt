@@ -1,15 +1,17 @@
-{%- let rec = ci.get_record_definition(name).unwrap() %}
+{%- let type_name = rec.self_type.type_name %}
+{%- let ffi_converter_name = rec.self_type.ffi_converter_name %}
+{%- let contains_object_references = rec.contains_object_references %}
 
-{%- if rec.has_fields() %}
+{%- if !rec.fields.is_empty() %}
 {%- call kt::docstring(rec, 0) %}{% endcall %}
 data class {{ type_name }} (
-    {%- for field in rec.fields() %}
+    {%- for field in rec.fields %}
     {%- call kt::docstring(field, 4) %}{% endcall %}
-    {% if config.is_record_immutable(name) %}val{% else %}var{% endif %} {{ field.name()|var_name }}: {{ field|type_name(ci) -}}
-    {%- if let Some(default) = field.default_value() %} = {{ default|render_default(field, ci) }} {% endif %}
+    {% if rec.is_immutable %}val{% else %}var{% endif %} {{ field.name }}: {{ field.ty.type_name -}}
+    {%- if let Some(default) = field.default %} = {{ default }} {% endif %}
     {% if !loop.last %}, {% endif %}
     {%- endfor %}
-    {%- let uniffi_trait_methods = rec.uniffi_trait_methods() %}
+    {%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
     {%- let comparable = uniffi_trait_methods.ord_cmp.is_some() %}
 )
 {%- if comparable && contains_object_references %}: Disposable, Comparable<{{ type_name }}>
@@ -17,18 +19,19 @@ data class {{ type_name }} (
 {%- elif comparable  %}: Comparable<{{ type_name }}>
 {% endif -%}
 {
-    {% for meth in rec.methods() -%}
+    {% for meth in rec.methods -%}
     {%- call kt::func_decl("", meth, 4) %}{% endcall %}
     {% endfor %}
 
     {% call kt::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
+    {%- call kt::uniffi_trait_inherent_methods(uniffi_trait_methods, false) %}{% endcall %}
     {% if contains_object_references %}
     @Suppress("UNNECESSARY_SAFE_CALL") // codegen is much simpler if we unconditionally emit safe calls here
     override fun destroy() {
         {% call kt::destroy_fields(rec) %}{% endcall %}
     }
     {% endif %}
-    companion object
+    {%- call kt::companion_object(rec.static_methods, uniffi_trait_methods) %}{% endcall %}
 }
 {%- else -%}
 {%- call kt::docstring(rec, 0) %}{% endcall %}
@@ -41,19 +44,19 @@ class {{ type_name }} {
         return javaClass.hashCode()
     }
 
-    companion object
+    {%- call kt::companion_object(rec.static_methods, rec.uniffi_trait_methods) %}{% endcall %}
 }
 {%- endif %}
 
 /**
  * @suppress
  */
-public object {{ rec|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
     override fun read(buf: ByteBuffer): {{ type_name }} {
-        {%- if rec.has_fields() %}
+        {%- if !rec.fields.is_empty() %}
         return {{ type_name }}(
-        {%- for field in rec.fields() %}
-            {{ field|read_fn }}(buf),
+        {%- for field in rec.fields %}
+            {{ field.ty.ffi_converter_name }}.read(buf),
         {%- endfor %}
         )
         {%- else %}
@@ -61,15 +64,15 @@ public object {{ rec|ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name
         {%- endif %}
     }
 
-    override fun allocationSize(value: {{ type_name }}) = {%- if rec.has_fields() %} (
-        {%- for field in rec.fields() %}
-            {{ field|allocation_size_fn }}(value.{{ field.name()|var_name }}){% if !loop.last %} +{% endif %}
+    override fun allocationSize(value: {{ type_name }}) = {%- if !rec.fields.is_empty() %} (
+        {%- for field in rec.fields %}
+            {{ field.ty.ffi_converter_name }}.allocationSize(value.{{ field.name }}){% if !loop.last %} +{% endif %}
         {%- endfor %}
     ) {%- else %} 0UL {%- endif %}
 
     override fun write(value: {{ type_name }}, buf: ByteBuffer) {
-        {%- for field in rec.fields() %}
-            {{ field|write_fn }}(value.{{ field.name()|var_name }}, buf)
+        {%- for field in rec.fields %}
+            {{ field.ty.ffi_converter_name }}.write(value.{{ field.name }}, buf)
         {%- endfor %}
     }
 }
@@ -0,0 +1,108 @@
+/**
+ * Closures are type-erased: the arguments and return value are serialized into `RustBuffer`s.
+ *
+ * Kotlin functions passed to Rust are stored in a handle map as functions that input the
+ * serialized arguments and return the serialized return value.  Rust calls them through the
+ * `VTableForeignClosure` VTable.  Rust closures are wrapped in `UniffiRustClosure`.
+ *
+ * @suppress
+ */
+internal object uniffiForeignClosure {
+    internal val handleMap = UniffiHandleMap<(RustBuffer.ByValue) -> RustBuffer.ByValue>()
+
+    internal object uniffiFree: UniffiForeignClosureFree {
+        override fun callback(handle: Long) {
+            handleMap.remove(handle)
+        }
+    }
+
+    internal object uniffiClone: UniffiForeignClosureClone {
+        override fun callback(handle: Long): Long {
+            return handleMap.clone(handle)
+        }
+    }
+
+    internal object call: UniffiForeignClosureCall {
+        override fun callback(
+            handle: Long,
+            args: RustBuffer.ByValue,
+            uniffiOutReturn: RustBuffer,
+            uniffiCallStatus: UniffiRustCallStatus,
+        ) {
+            val erased = handleMap.get(handle)
+            uniffiTraitInterfaceCall(
+                uniffiCallStatus,
+                { erased(args) },
+                { value: RustBuffer.ByValue -> uniffiOutReturn.setValue(value) },
+            )
+        }
+    }
+
+    internal var vtable = UniffiVTableForeignClosure.UniffiByValue(
+        uniffiFree,
+        uniffiClone,
+        call,
+    )
+
+    // Registers the foreign closure VTable with the Rust side.
+    internal fun register(lib: UniffiLib) {
+        lib.ffi_{{ crate_name }}_foreign_closure_init_vtable(vtable)
+    }
+}
+
+/**
+ * Rust closure, which is freed once this object is garbage collected
+ *
+ * @suppress
+ */
+internal class UniffiRustClosure(private val handle: Long) {
+    private val cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+
+    fun call(args: RustBuffer.ByValue): RustBuffer.ByValue {
+        return uniffiRustCall { status ->
+            UniffiLib.ffi_{{ crate_name }}_rust_closure_call(handle, args, status)
+        }
+    }
+
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            UniffiLib.ffi_{{ crate_name }}_rust_closure_free(handle)
+        }
+    }
+}
+
+// Serialize closure arguments or return values into a `RustBuffer`
+internal inline fun uniffiLowerClosureBuffer(
+    allocationSize: ULong,
+    write: (ByteBuffer) -> Unit,
+): RustBuffer.ByValue {
+    val rbuf = RustBuffer.alloc(allocationSize)
+    try {
+        val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+            it.order(ByteOrder.BIG_ENDIAN)
+        }
+        write(bbuf)
+        rbuf.writeField("len", bbuf.position().toLong())
+        return rbuf
+    } catch (e: Throwable) {
+        RustBuffer.free(rbuf)
+        throw e
+    }
+}
+
+// Deserialize closure arguments or return values from a `RustBuffer`, then free it
+internal inline fun <T> uniffiLiftClosureBuffer(
+    rbuf: RustBuffer.ByValue,
+    read: (ByteBuffer) -> T,
+): T {
+    val buf = rbuf.asByteBuffer() ?: ByteBuffer.allocate(0)
+    try {
+        val value = read(buf)
+        if (buf.hasRemaining()) {
+            throw RuntimeException("junk remaining in closure buffer, something is very wrong!!")
+        }
+        return value
+    } finally {
+        RustBuffer.free(rbuf)
+    }
+}
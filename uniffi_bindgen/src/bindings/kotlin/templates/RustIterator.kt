@@ -0,0 +1,56 @@
+internal const val UNIFFI_RUST_ITER_CHUNK_SIZE = 64
+
+/**
+ * Iterator over the items of a Rust iterator
+ *
+ * Items are fetched in chunks with `rust_iter_next_chunk`, an empty chunk signals the end of the
+ * iterator.  The Rust iterator is freed once it's exhausted, when fetching a chunk fails or when
+ * this object is garbage collected.
+ *
+ * @suppress
+ */
+internal class UniffiRustIterator<T>(
+    private val handle: Long,
+    private val liftChunk: (RustBuffer.ByValue) -> List<T>,
+) : Iterator<T> {
+    private val cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    private var items: Iterator<T> = emptyList<T>().iterator()
+    private var exhausted = false
+
+    override fun hasNext(): Boolean {
+        if (!items.hasNext() && !exhausted) {
+            fetchChunk()
+        }
+        return items.hasNext()
+    }
+
+    override fun next(): T {
+        if (!hasNext()) {
+            throw NoSuchElementException()
+        }
+        return items.next()
+    }
+
+    private fun fetchChunk() {
+        val chunk = try {
+            liftChunk(uniffiRustCall { status ->
+                UniffiLib.ffi_{{ crate_name }}_rust_iter_next_chunk(handle, UNIFFI_RUST_ITER_CHUNK_SIZE, status)
+            })
+        } catch (e: Throwable) {
+            exhausted = true
+            cleanable.clean()
+            throw e
+        }
+        if (chunk.isEmpty()) {
+            exhausted = true
+            cleanable.clean()
+        }
+        items = chunk.iterator()
+    }
+
+    private class UniffiCleanAction(private val handle: Long) : Runnable {
+        override fun run() {
+            UniffiLib.ffi_{{ crate_name }}_rust_iter_free(handle)
+        }
+    }
+}
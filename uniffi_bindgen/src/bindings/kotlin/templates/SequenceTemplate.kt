@@ -1,4 +1,5 @@
-{%- let inner_type_name = inner_type|type_name(ci) %}
+{%- let inner_type_name = seq.inner.type_name %}
+{%- let ffi_converter_name = seq.self_type.ffi_converter_name %}
 
 /**
  * @suppress
@@ -7,20 +8,20 @@ public object {{ ffi_converter_name }}: FfiConverterRustBuffer<List<{{ inner_typ
     override fun read(buf: ByteBuffer): List<{{ inner_type_name }}> {
         val len = buf.getInt()
         return List<{{ inner_type_name }}>(len) {
-            {{ inner_type|read_fn }}(buf)
+            {{ seq.inner.ffi_converter_name }}.read(buf)
         }
     }
 
     override fun allocationSize(value: List<{{ inner_type_name }}>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { {{ inner_type|allocation_size_fn }}(it) }.sum()
+        val sizeForItems = value.map { {{ seq.inner.ffi_converter_name }}.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
     override fun write(value: List<{{ inner_type_name }}>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            {{ inner_type|write_fn }}(it, buf)
+            {{ seq.inner.ffi_converter_name }}.write(it, buf)
         }
     }
 }
@@ -1,4 +1,5 @@
-{%- let inner_type_name = inner_type|type_name(ci) %}
+{%- let inner_type_name = set.inner.type_name %}
+{%- let ffi_converter_name = set.self_type.ffi_converter_name %}
 
 /**
  * @suppress
@@ -6,23 +7,28 @@
 public object {{ ffi_converter_name }}: FfiConverterRustBuffer<Set<{{ inner_type_name }}>> {
     override fun read(buf: ByteBuffer): Set<{{ inner_type_name }}> {
         val len = buf.getInt()
+        {%- if set.ordered %}
+        // Rust sent us an ordered set, use a `LinkedHashSet` to keep the items in that order.
+        return LinkedHashSet<{{ inner_type_name }}>(len).apply {
+        {%- else %}
         return buildSet<{{ inner_type_name }}>(len) {
+        {%- endif %}
             repeat(len) {
-                add({{ inner_type|read_fn }}(buf))
+                add({{ set.inner.ffi_converter_name }}.read(buf))
             }
         }
     }
 
     override fun allocationSize(value: Set<{{ inner_type_name }}>): ULong {
         val spaceForLength = 4UL
-        val spaceForItems = value.map { {{ inner_type|allocation_size_fn }}(it) }.sum()
+        val spaceForItems = value.map { {{ set.inner.ffi_converter_name }}.allocationSize(it) }.sum()
         return spaceForLength + spaceForItems
     }
 
     override fun write(value: Set<{{ inner_type_name }}>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            {{ inner_type|write_fn }}(it, buf)
+            {{ set.inner.ffi_converter_name }}.write(it, buf)
         }
     }
 }
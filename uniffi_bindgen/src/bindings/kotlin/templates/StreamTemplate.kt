@@ -0,0 +1,71 @@
+{%- let item_type_name = stream.item.type_name %}
+{%- let ffi_converter_name = stream.self_type.ffi_converter_name %}
+
+/**
+ * Lifts Rust streams into a `Flow`
+ *
+ * Each item is fetched by awaiting a Rust future, the Rust stream is freed once the flow
+ * completes, its collector is cancelled or the flow is garbage collected without being collected.
+ * The flow can only be collected once.
+ *
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverter<Flow<{{ item_type_name }}>, Long> {
+    override fun lift(value: Long): Flow<{{ item_type_name }}> {
+        val holder = UniffiStreamHolder(value)
+        return flow {
+            check(!holder.collected.getAndSet(true)) { "Rust streams can only be collected once" }
+            try {
+                while (true) {
+                    val next = uniffiRustCallAsync(
+                        UniffiLib.ffi_{{ crate_name }}_rust_stream_next(holder.handle),
+                        { future, callback, continuation -> UniffiLib.ffi_{{ crate_name }}_rust_future_poll_rust_buffer(future, callback, continuation) },
+                        { future -> UniffiLib.ffi_{{ crate_name }}_rust_future_cancel_rust_buffer(future) },
+                        { future, continuation -> UniffiLib.ffi_{{ crate_name }}_rust_future_complete_rust_buffer(future, continuation) },
+                        { future -> UniffiLib.ffi_{{ crate_name }}_rust_future_free_rust_buffer(future) },
+                        { liftNext(it) },
+                        UniffiNullRustCallStatusErrorHandler,
+                    ) ?: break
+                    emit(next.getOrThrow())
+                }
+            } finally {
+                holder.cleanable.clean()
+            }
+        }
+    }
+
+    // Owns the Rust stream handle.  The `flow` block captures the holder and reads the handle
+    // from it for every item, so the holder stays reachable while the flow is being collected and
+    // the cleaner can only run once the flow can't be collected anymore.
+    private class UniffiStreamHolder(val handle: Long) {
+        val collected = AtomicBoolean(false)
+        val cleanable = UniffiLib.CLEANER.register(this, UniffiCleanAction(handle))
+    }
+
+    // Lift the `Option` returned by a `rust_stream_next` future, `null` signals the end of the stream
+    private fun liftNext(rbuf: RustBuffer.ByValue): Result<{{ item_type_name }}>? {
+        val buf = rbuf.asByteBuffer()!!
+        try {
+            if (buf.get().toInt() == 0) {
+                return null
+            }
+            return Result.success({{ stream.item.ffi_converter_name }}.read(buf))
+        } finally {
+            RustBuffer.free(rbuf)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): Flow<{{ item_type_name }}> {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: Flow<{{ item_type_name }}>): Long {
+        throw RuntimeException("Streams can't be passed to Rust")
+    }
+
+    override fun allocationSize(value: Flow<{{ item_type_name }}>) = 8UL
+
+    override fun write(value: Flow<{{ item_type_name }}>, buf: ByteBuffer) {
+        throw RuntimeException("Streams can't be passed to Rust")
+    }
+}
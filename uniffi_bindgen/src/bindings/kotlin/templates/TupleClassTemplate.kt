@@ -0,0 +1,17 @@
+{%- let type_params = ["A", "B", "C", "D", "E", "F"] %}
+{%- let field_names = ["first", "second", "third", "fourth", "fifth", "sixth"] %}
+
+/**
+ * A tuple of {{ arity }} values, used for Rust tuples that don't fit in a `Pair` or `Triple`.
+ */
+public data class Tuple{{ arity }}<
+    {%- for i in 0..(*arity as usize) %}out {{ type_params[i] }}{% if !loop.last %}, {% endif %}{% endfor -%}
+>(
+    {%- for i in 0..(*arity as usize) %}
+    public val {{ field_names[i] }}: {{ type_params[i] }},
+    {%- endfor %}
+) {
+    override fun toString(): String = "(
+        {%- for i in 0..(*arity as usize) %}${{ "{" }}{{ field_names[i] }}{{ "}" }}{% if !loop.last %}, {% endif %}{% endfor -%}
+    )"
+}
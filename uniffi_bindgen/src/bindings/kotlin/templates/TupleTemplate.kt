@@ -0,0 +1,27 @@
+{%- let type_name = tuple.self_type.type_name %}
+{%- let ffi_converter_name = tuple.self_type.ffi_converter_name %}
+
+/**
+ * @suppress
+ */
+public object {{ ffi_converter_name }}: FfiConverterRustBuffer<{{ type_name }}> {
+    override fun read(buf: ByteBuffer): {{ type_name }} {
+        return {{ tuple.class_name }}(
+            {%- for inner_type in tuple.inners %}
+            {{ inner_type.ffi_converter_name }}.read(buf),
+            {%- endfor %}
+        )
+    }
+
+    override fun allocationSize(value: {{ type_name }}) = (
+        {%- for inner_type in tuple.inners %}
+        {{ inner_type.ffi_converter_name }}.allocationSize(value.component{{ loop.index }}()){% if !loop.last %} +{% endif %}
+        {%- endfor %}
+    )
+
+    override fun write(value: {{ type_name }}, buf: ByteBuffer) {
+        {%- for inner_type in tuple.inners %}
+        {{ inner_type.ffi_converter_name }}.write(value.component{{ loop.index }}(), buf)
+        {%- endfor %}
+    }
+}
@@ -76,129 +76,138 @@ object UniffiWithHandle
  * */
 object NoHandle
 
-{%- if ci.has_callback_definitions() %}
+{%- if has_callback_definitions %}
 {%- include "CallbackInterfaceRuntime.kt" %}
 {%- endif %}
 
-{%- if ci.has_object_definitions() %}
+{%- if cleaner.is_some() %}
 {%- include "ObjectCleanerHelper.kt" %}
 {%- endif %}
 
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name(ci) %}
-{%- let ffi_converter_name = type_|ffi_converter_name %}
-{%- let canonical_type_name = type_|canonical_name %}
-{%- let contains_object_references = ci.item_contains_object_references(type_) %}
-
-{#
- # Map `Type` instances to an include statement for that type.
- #
- # There is a companion match in `KotlinCodeOracle::create_code_type()` which performs a similar function for the
- # Rust code.
- #
- #   - When adding additional types here, make sure to also add a match arm to that function.
- #   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
- #}
-{%- match type_ %}
+{%- if has_iterator_types %}
+{%- include "RustIterator.kt" %}
+{%- endif %}
+
+{%- if has_closure_types %}
+{%- include "RustClosure.kt" %}
+{%- endif %}
+
+{%- for type_def in type_definitions %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
 
 {%- when Type::Boolean %}
-{%- include "BooleanHelper.kt" %}
+{% include "BooleanHelper.kt" %}
+
+{%- when Type::Char %}
+{% include "CharHelper.kt" %}
 
 {%- when Type::Int8 %}
-{%- include "Int8Helper.kt" %}
+{% include "Int8Helper.kt" %}
 
 {%- when Type::Int16 %}
-{%- include "Int16Helper.kt" %}
+{% include "Int16Helper.kt" %}
 
 {%- when Type::Int32 %}
-{%- include "Int32Helper.kt" %}
+{% include "Int32Helper.kt" %}
 
 {%- when Type::Int64 %}
-{%- include "Int64Helper.kt" %}
+{% include "Int64Helper.kt" %}
 
 {%- when Type::UInt8 %}
-{%- include "UInt8Helper.kt" %}
+{% include "UInt8Helper.kt" %}
 
 {%- when Type::UInt16 %}
-{%- include "UInt16Helper.kt" %}
+{% include "UInt16Helper.kt" %}
 
 {%- when Type::UInt32 %}
-{%- include "UInt32Helper.kt" %}
+{% include "UInt32Helper.kt" %}
 
 {%- when Type::UInt64 %}
-{%- include "UInt64Helper.kt" %}
+{% include "UInt64Helper.kt" %}
+
+{%- when Type::UInt128 %}
+{% include "UInt128Helper.kt" %}
+
+{%- when Type::Int128 %}
+{% include "Int128Helper.kt" %}
 
 {%- when Type::Float32 %}
-{%- include "Float32Helper.kt" %}
+{% include "Float32Helper.kt" %}
 
 {%- when Type::Float64 %}
-{%- include "Float64Helper.kt" %}
+{% include "Float64Helper.kt" %}
 
 {%- when Type::String %}
-{%- include "StringHelper.kt" %}
+{% include "StringHelper.kt" %}
 
 {%- when Type::Bytes %}
-{%- include "ByteArrayHelper.kt" %}
+{% include "ByteArrayHelper.kt" %}
+
+{%- when Type::Timestamp %}
+{% include "TimestampHelper.kt" %}
+
+{%- when Type::Duration %}
+{% include "DurationHelper.kt" %}
+
+{%- else %}
+{%- endmatch %}
 
-{%- when Type::Enum { name, module_path } %}
-{%- let e = ci.get_enum_definition(name).unwrap() %}
-{%- if !ci.is_name_used_as_error(name) %}
+{%- when TypeDefinition::Enum(e) %}
+{%- if !e.self_type.is_used_as_error %}
 {% include "EnumTemplate.kt" %}
 {%- else %}
 {% include "ErrorTemplate.kt" %}
 {%- endif -%}
 
-{%- when Type::Object { module_path, name, .. } %}
+{%- when TypeDefinition::Interface(int) %}
 {% include "ObjectTemplate.kt" %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when TypeDefinition::Record(rec) %}
 {% include "RecordTemplate.kt" %}
 
-{%- when Type::Optional { inner_type } %}
+{%- when TypeDefinition::Optional(opt) %}
 {% include "OptionalTemplate.kt" %}
 
-{%- when Type::Sequence { inner_type } %}
+{%- when TypeDefinition::Sequence(seq) %}
 {% include "SequenceTemplate.kt" %}
 
-{%- when Type::Map { key_type, value_type } %}
+{%- when TypeDefinition::Array(array) %}
+{% include "ArrayTemplate.kt" %}
+
+{%- when TypeDefinition::Map(map) %}
 {% include "MapTemplate.kt" %}
 
-{%- when Type::Set { inner_type } %}
+{%- when TypeDefinition::Set(set) %}
 {% include "SetTemplate.kt" %}
 
-{%- when Type::CallbackInterface { module_path, name } %}
-{% include "CallbackInterfaceTemplate.kt" %}
+{%- when TypeDefinition::Tuple(tuple) %}
+{% include "TupleTemplate.kt" %}
 
-{%- when Type::Timestamp %}
-{% include "TimestampHelper.kt" %}
+{%- when TypeDefinition::Stream(stream) %}
+{% include "StreamTemplate.kt" %}
 
-{%- when Type::Duration %}
-{% include "DurationHelper.kt" %}
+{%- when TypeDefinition::Iterator(iterator) %}
+{% include "IteratorTemplate.kt" %}
 
-{%- when Type::Custom { module_path, name, builtin } %}
-{%- if ci.is_external(type_) %}
-{% include "ExternalTypeTemplate.kt" %}
-{%- else %}
+{%- when TypeDefinition::Closure(closure) %}
+{% include "ClosureTemplate.kt" %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{% include "CallbackInterfaceTemplate.kt" %}
+
+{%- when TypeDefinition::Custom(custom) %}
 {% include "CustomTypeTemplate.kt" %}
-{%- endif %}
+
+{%- when TypeDefinition::External(external) %}
+{% include "ExternalTypeTemplate.kt" %}
 
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
 
-{%- for type_ in ci.iter_external_types() %}
-{%- let name = type_.name().unwrap() %}
-{%- let module_path = type_.module_path().unwrap() %}
-{% include "ExternalTypeTemplate.kt" %}
+{%- for arity in tuple_class_arities %}
+{% include "TupleClassTemplate.kt" %}
 {%- endfor %}
-
-{%- if ci.has_async_fns() %}
-{# Import types needed for async support #}
-{{ self.add_import("kotlin.coroutines.resume") }}
-{{ self.add_import("kotlinx.coroutines.launch") }}
-{{ self.add_import("kotlinx.coroutines.suspendCancellableCoroutine") }}
-{{ self.add_import("kotlinx.coroutines.CancellableContinuation") }}
-{{ self.add_import("kotlinx.coroutines.DelicateCoroutinesApi") }}
-{{ self.add_import("kotlinx.coroutines.Job") }}
-{{ self.add_import("kotlinx.coroutines.GlobalScope") }}
-{%- endif %}
@@ -0,0 +1,25 @@
+/**
+ * @suppress
+ */
+public object FfiConverterUInt128: FfiConverterRustBuffer<java.math.BigInteger> {
+    override fun read(buf: ByteBuffer): java.math.BigInteger {
+        val bytes = ByteArray(16)
+        buf.get(bytes)
+        return java.math.BigInteger(1, bytes)
+    }
+
+    override fun allocationSize(value: java.math.BigInteger) = 16UL
+
+    override fun write(value: java.math.BigInteger, buf: ByteBuffer) {
+        if (value.signum() < 0 || value.bitLength() > 128) {
+            throw IllegalArgumentException("u128 requires 0 <= value < 2^128, got $value")
+        }
+        // `toByteArray()` returns the minimal two's-complement representation, which may have an
+        // extra leading zero byte.  Keep the low 16 bytes and zero-pad the rest.
+        val bytes = value.toByteArray()
+        val len = minOf(bytes.size, 16)
+        val padded = ByteArray(16)
+        System.arraycopy(bytes, bytes.size - len, padded, 16 - len, len)
+        buf.put(padded)
+    }
+}
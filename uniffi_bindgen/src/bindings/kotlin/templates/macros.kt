@@ -4,63 +4,83 @@
 // passed to rust via `arg_list_lowered`
 #}
 
-{%- macro to_ffi_call(func) -%}
-    {%- match func.self_type() %}
-    {%- when Some(Type::Object { .. }) %}
+{%- macro to_ffi_call(callable) -%}
+    {%- if callable.self_is_interface() %}
     callWithHandle {
-        {%- call to_raw_ffi_call(func) %}{% endcall %}
+        {%- call to_raw_ffi_call(callable) %}{% endcall %}
     }
     {% else %}
-        {%- call to_raw_ffi_call(func) %}{% endcall %}
-    {% endmatch %}
+        {%- call to_raw_ffi_call(callable) %}{% endcall %}
+    {% endif %}
 {%- endmacro %}
 
-{%- macro to_raw_ffi_call(func) -%}
-    {%- match func.throws_type() %}
+{%- macro to_raw_ffi_call(callable) -%}
+    {%- call rust_call_with_error(callable) %}{% endcall %} { _status ->
+    UniffiLib.{{ callable.ffi_func.0 }}(
+    {%- match callable.self_type() %}
+    {%- when Some(self_type) %}
+    {%- if callable.self_is_interface() %}
+        it,
+    {%- else %}
+        {{- self_type.ffi_converter_name }}.lower(this),
+    {%- endif %}
+    {%- when None %}
+    {% endmatch %}
+        {% call arg_list_lowered(callable) %}{% endcall -%}
+        _status)
+}
+{%- endmacro -%}
+
+{%- macro rust_call_with_error(callable) -%}
+    {%- match callable.throws_type.ty %}
     {%- when Some(e) %}
-    {%- if ci.is_external(e) %}
-    uniffiRustCallWithError({{ e|type_name(ci) }}ExternalErrorHandler)
+    {%- if callable.throws_type.is_external %}
+    uniffiRustCallWithError({{ e.type_name }}ExternalErrorHandler)
     {%- else %}
-    uniffiRustCallWithError({{ e|type_name(ci) }})
+    uniffiRustCallWithError({{ e.type_name }})
     {%- endif %}
     {%- else %}
     uniffiRustCall()
-    {%- endmatch %} { _status ->
-    UniffiLib.{{ func.ffi_func().name() }}(
-    {%- match func.self_type() %}
-    {%- when Some(Type::Object { .. }) %}
-        it,
-    {%- when Some(t) %}
-        {{- t|lower_fn }}(this),
-    {%- when None %}
-    {% endmatch %}
-        {% call arg_list_lowered(func) %}{% endcall -%}
+    {%- endmatch %}
+{%- endmacro -%}
+
+{#-
+// Call the Rust default implementation of a trait method, passing `this` as the receiver.
+-#}
+{%- macro default_method_call(callable, default_ffi_func) -%}
+    {%- call rust_call_with_error(callable) %}{% endcall %} { _status ->
+    UniffiLib.{{ default_ffi_func.0 }}(
+    {%- if let Some(self_type) = callable.self_type() %}
+        {{ self_type.ffi_converter_name }}.lower(this),
+    {%- endif %}
+        {% call arg_list_lowered(callable) %}{% endcall -%}
         _status)
 }
 {%- endmacro -%}
 
-{%- macro func_decl(func_decl, callable, indent) %}
-    {%- call docstring(callable, indent) %}{% endcall %}
+{%- macro func_decl(func_decl, item, indent) %}
+    {%- let callable = item.callable %}
+    {%- call docstring(item, indent) %}{% endcall %}
 
-    {%- match callable.throws_type() -%}
+    {%- match callable.throws_type.ty -%}
     {%-     when Some(throwable) %}
-    @Throws({{ throwable|type_name(ci) }}::class)
+    @Throws({{ throwable.type_name }}::class)
     {%-     else -%}
     {%- endmatch -%}
     {%- if callable.is_async() %}
     @Suppress("ASSIGNED_BUT_NEVER_ACCESSED_VARIABLE")
-    {{ func_decl }} suspend fun {{ callable.name()|fn_name }}(
+    {{ func_decl }} suspend fun {{ callable.name }}(
         {%- call arg_list(callable, callable.self_type().is_none()) %}{% endcall -%}
-    ){% match callable.return_type() %}{% when Some(return_type) %} : {{ return_type|type_name(ci) }}{% when None %}{%- endmatch %} {
+    ){% match callable.return_type.ty %}{% when Some(return_type) %} : {{ return_type.type_name }}{% when None %}{%- endmatch %} {
         return {% call call_async(callable) %}{% endcall %}
     }
     {%- else -%}
-    {{ func_decl }} fun {{ callable.name()|fn_name }}(
+    {{ func_decl }} fun {{ callable.name }}(
         {%- call arg_list(callable, callable.self_type().is_none()) %}{% endcall -%}
-    ){%- match callable.return_type() -%}
+    ){%- match callable.return_type.ty -%}
     {%-         when Some(return_type) -%}
-        : {{ return_type|type_name(ci) }} {
-            return {{ return_type|lift_fn }}({% call to_ffi_call(callable) %}{% endcall %})
+        : {{ return_type.type_name }} {
+            return {{ return_type.ffi_converter_name }}.lift({% call to_ffi_call(callable) %}{% endcall %})
     }
     {%-         when None %}
         = {% call to_ffi_call(callable) %}{% endcall %}
@@ -68,42 +88,65 @@
     {% endif %}
 {% endmacro %}
 
+{#-
+// Emits the `companion object` for a type, with any static methods declared inside it.
+-#}
+{%- macro companion_object(static_methods, uniffi_trait_methods) %}
+    {%- if static_methods.is_empty() && !uniffi_trait_methods.has_static_methods() %}
+    companion object
+    {%- else %}
+    companion object {
+        {% for meth in static_methods.iter() -%}
+        {%- call func_decl("", meth, 8) %}{% endcall %}
+        {% endfor %}
+        {%- call uniffi_trait_static_methods(uniffi_trait_methods) %}{% endcall %}
+    }
+    {%- endif %}
+{%- endmacro %}
+
 {%- macro call_async(callable) -%}
+    {%- let async_data = callable.async_data.as_ref().unwrap() %}
     uniffiRustCallAsync(
 
 {%- match callable.self_type() %}
-{%- when Some(Type::Object { .. }) %}
+{%- when Some(self_type) %}
+{%- if callable.self_is_interface() %}
         callWithHandle { uniffiHandle ->
-            UniffiLib.{{ callable.ffi_func().name() }}(
+            UniffiLib.{{ callable.ffi_func.0 }}(
                 uniffiHandle,
                 {% call arg_list_lowered(callable) %}{% endcall %}
             )
         },
-{%- when Some(t) %}
-        UniffiLib.{{ callable.ffi_func().name() }}(
-            {{- t|lower_fn }}(this),
+{%- else %}
+        UniffiLib.{{ callable.ffi_func.0 }}(
+            {{- self_type.ffi_converter_name }}.lower(this),
             {% call arg_list_lowered(callable) %}{% endcall %}
         ),
+{%- endif %}
 {%- else %}
-        UniffiLib.{{ callable.ffi_func().name() }}({% call arg_list_lowered(callable) %}{% endcall %}),
+        UniffiLib.{{ callable.ffi_func.0 }}({% call arg_list_lowered(callable) %}{% endcall %}),
 {%- endmatch %}
-        {{ callable|async_poll(ci) }},
-        {{ callable|async_complete(ci) }},
-        {{ callable|async_free(ci) }},
+        { future, callback, continuation -> UniffiLib.{{ async_data.ffi_rust_future_poll.0 }}(future, callback, continuation) },
+        { future -> UniffiLib.{{ async_data.ffi_rust_future_cancel.0 }}(future) },
+        { future, continuation -> UniffiLib.{{ async_data.ffi_rust_future_complete.0 }}(future, continuation)
+        {%- if let Some(package_name) = callable.return_type.external_rust_buffer_package -%}
+        .let { {{ package_name }}.RustBuffer.create(it.capacity.toULong(), it.len.toULong(), it.data) }
+        {%- endif %} },
+        { future -> UniffiLib.{{ async_data.ffi_rust_future_free.0 }}(future) },
         // lift function
-        {%- match callable.return_type() %}
+        {%- match callable.return_type.ty %}
         {%- when Some(return_type) %}
-        { {{ return_type|lift_fn }}(it) },
+        { {{ return_type.ffi_converter_name }}.lift(it) },
         {%- when None %}
         { Unit },
         {% endmatch %}
         // Error FFI converter
-        {%- match callable.throws_type() %}
+        {%- match callable.throws_type.ty %}
         {%- when Some(e) %}
-        {%- if ci.is_external(e) %}
-        {{ e|type_name(ci) }}ExternalErrorHandler,
+        {%- if callable.throws_type.is_external %}
+        {{ e.type_name }}ExternalErrorHandler,
         {%- else %}
-        {{ e|type_name(ci) }}.ErrorHandler,
+        {{ e.type_name }}.ErrorHandler,
         {%- endif %}
         {%- when None %}
         UniffiNullRustCallStatusErrorHandler,
@@ -111,9 +154,9 @@
     )
 {%- endmacro %}
 
-{%- macro arg_list_lowered(func) %}
-    {%- for arg in func.arguments() %}
-        {{ arg|lower_fn_for_arg }}({{ arg.name()|var_name }}),
+{%- macro arg_list_lowered(callable) %}
+    {%- for arg in callable.arguments %}
+        {{ arg.lower_fn }}({{ arg.name }}),
     {%- endfor %}
 {%- endmacro -%}
 
@@ -123,12 +166,12 @@
 // Note the var_name and type_name filters.
 -#}
 
-{% macro arg_list(func, is_decl) %}
-{%- for arg in func.arguments() -%}
-        {{ arg.name()|var_name }}: {{ arg|lower_type_name_for_arg(ci) }}
+{% macro arg_list(callable, is_decl) %}
+{%- for arg in callable.arguments -%}
+        {{ arg.name }}: {{ arg.lower_type_name }}
 {%-     if is_decl %}
-{%-         match arg.default_value() %}
-{%-             when Some(default) %} = {{ default|render_default(arg, ci) }}
+{%-         match arg.default %}
+{%-             when Some(default) %} = {{ default }}
 {%-             else %}
 {%-         endmatch %}
 {%-     endif %}
@@ -141,32 +184,32 @@
 // Note unfiltered name but ffi_type_name filters.
 -#}
 {%- macro arg_list_ffi_decl(func) %}
-    {%- for arg in func.arguments() %}
-        {{- arg.name()|var_name }}: {{ arg.type_().borrow()|ffi_type_name_by_value(ci) -}},
+    {%- for arg in func.arguments %}
+        {{- arg.name }}: {{ arg.ty.type_name_by_value -}},
     {%- endfor %}
-    {%- if func.has_rust_call_status_arg() %}uniffi_out_err: UniffiRustCallStatus, {% endif %}
+    {%- if func.has_rust_call_status_arg %}uniffi_out_err: UniffiRustCallStatus, {% endif %}
 {%- endmacro -%}
 
 {% macro field_name(field, field_num) %}
-{%- if field.name().is_empty() -%}
+{%- if field.name.is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name }}
+{{ field.name }}
 {%- endif -%}
 {%- endmacro %}
 
 {% macro field_name_unquoted(field, field_num) %}
-{%- if field.name().is_empty() -%}
+{%- if field.name.is_empty() -%}
 v{{- field_num -}}
 {%- else -%}
-{{ field.name()|var_name|unquote }}
+{{ field.name|unquote }}
 {%- endif -%}
 {%- endmacro %}
 
  // Macro for destroying fields
 {%- macro destroy_fields(member) %}
     Disposable.destroy(
-    {%- for field in member.fields() %}
+    {%- for field in member.fields %}
         this.{%- call field_name(field, loop.index) %}{% endcall -%}{% if loop.last %}{% else %},{% endif -%}
     {%- endfor %}
     )
@@ -181,35 +224,74 @@ v{{- field_num -}}
 {%- endmacro %}
 
 {%- macro docstring(defn, indent_spaces) %}
-{%- call docstring_value(defn.docstring(), indent_spaces) %}{% endcall %}
+{%- call docstring_value(defn.docstring, indent_spaces) %}{% endcall %}
 {%- endmacro %}
 
 // macro for uniffi_trait implementations.
 {% macro uniffi_trait_impls(uniffi_trait_methods) %}
 {# We have 2 display traits, kotlin has 1. Prefer `Display` but use `Debug` otherwise #}
-{%- if let Some(fmt) = uniffi_trait_methods.display_fmt.or(uniffi_trait_methods.debug_fmt.clone()) %}
+{%- if let Some(fmt) = uniffi_trait_methods.display_fmt.clone().or(uniffi_trait_methods.debug_fmt.clone()) %}
     // The local Rust `Display`/`Debug` implementation.
     override fun toString(): String {
-        return {{ fmt.return_type().unwrap()|lift_fn }}({% call to_ffi_call(fmt) %}{% endcall %})
+        return {{ fmt.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(fmt.callable) %}{% endcall %})
     }
 {%- endif %}
 {%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
     // The local Rust `Eq` implementation - only `eq` is used.
     override fun equals(other: Any?): Boolean {
-        if (other !is {{ eq.object_name()|class_name(ci) }}) return false
-        return {{ eq.return_type().unwrap()|lift_fn }}({% call to_ffi_call(eq) %}{% endcall %})
+        if (other !is {{ eq.callable.self_type().unwrap().type_name }}) return false
+        return {{ eq.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(eq.callable) %}{% endcall %})
     }
 {%- endif %}
 {%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
     // The local Rust `Hash` implementation
     override fun hashCode(): Int {
-        return {{ hash.return_type().unwrap()|lift_fn }}({%- call to_ffi_call(hash) %}{% endcall %}).toInt()
+        return {{ hash.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({%- call to_ffi_call(hash.callable) %}{% endcall %}).toInt()
     }
 {%- endif %}
 {%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
     // The local Rust `Ord` implementation
-    override fun compareTo(other: {{ cmp.object_name()|class_name(ci) }}): Int {
-        return {{ cmp.return_type().unwrap()|lift_fn }}({%- call to_ffi_call(cmp) %}{% endcall %}).toInt()
+    override fun compareTo(other: {{ cmp.callable.self_type().unwrap().type_name }}): Int {
+        return {{ cmp.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({%- call to_ffi_call(cmp.callable) %}{% endcall %}).toInt()
+    }
+{%- endif %}
+{%- endmacro %}
+
+// macro for uniffi_trait methods that don't override a Kotlin method.  Unlike `uniffi_trait_impls`,
+// this is only used on the outer class of an enum, since the variants inherit the methods.
+// `enum class` can't declare `clone()`, since `kotlin.Enum` already has a final one.
+{% macro uniffi_trait_inherent_methods(uniffi_trait_methods, is_enum_class) %}
+{%- if let Some(clone) = uniffi_trait_methods.clone_clone %}
+{%- if !is_enum_class %}
+    // Lifts the result of the Rust `Clone` implementation, so any custom clone logic in Rust runs.
+    fun clone(): {{ clone.callable.return_type.type_name }} {
+        return {{ clone.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(clone.callable) %}{% endcall %})
+    }
+{%- endif %}
+{%- endif %}
+{%- if let Some(partial_cmp) = uniffi_trait_methods.partial_ord_partial_cmp %}
+    // The local Rust `PartialOrd` implementation.  Returns `null` if the values can't be compared.
+    fun partialCompareTo(other: {{ partial_cmp.callable.self_type().unwrap().type_name }}): Int? {
+        return {{ partial_cmp.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({%- call to_ffi_call(partial_cmp.callable) %}{% endcall %})?.toInt()
     }
 {%- endif %}
 {%- endmacro %}
+
+// macro for uniffi_trait implementations that create new values.  These go in the companion object.
+{% macro uniffi_trait_static_methods(uniffi_trait_methods) %}
+{%- if let Some(default) = uniffi_trait_methods.default_default %}
+        // The local Rust `Default` implementation.
+        fun default(): {{ default.callable.return_type.type_name }} {
+            return {{ default.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(default.callable) %}{% endcall %})
+        }
+{%- endif %}
+{%- if let Some(from_str) = uniffi_trait_methods.from_str_from_str %}
+        // The local Rust `FromStr` implementation.
+        {%- if let Some(throwable) = from_str.callable.throws_type.ty %}
+        @Throws({{ throwable.type_name }}::class)
+        {%- endif %}
+        fun parse(s: String): {{ from_str.callable.return_type.type_name }} {
+            return {{ from_str.callable.return_type.ty.as_ref().unwrap().ffi_converter_name }}.lift({% call to_ffi_call(from_str.callable) %}{% endcall %})
+        }
+{%- endif %}
+{%- endmacro %}
@@ -1,11 +1,11 @@
 // This file was autogenerated by some hot garbage in the `uniffi` crate.
 // Trust me, you don't want to mess with it!
 
-{%- call kt::docstring_value(ci.namespace_docstring(), 0) %}{% endcall %}
+{%- call kt::docstring_value(docstring, 0) %}{% endcall %}
 
 @file:Suppress("NAME_SHADOWING")
 
-package {{ config.package_name() }}
+package {{ package_name }}
 
 // Common helper code.
 //
@@ -33,8 +33,8 @@ import java.nio.charset.CodingErrorAction
 import java.util.concurrent.atomic.AtomicLong
 import java.util.concurrent.ConcurrentHashMap
 
-{%- for req in self.imports() %}
-{{ req.render() }}
+{%- for import in imports %}
+import {{ import }}
 {%- endfor %}
 
 {% include "RustBufferTemplate.kt" %}
@@ -47,15 +47,20 @@ import java.util.concurrent.ConcurrentHashMap
 {% include "NamespaceLibraryTemplate.kt" %}
 
 // Async support
-{%- if ci.has_async_fns() %}
+{%- if has_async_fns %}
 {% include "Async.kt" %}
 {%- endif %}
 
 // Public interface members begin here.
-{{ type_helper_code }}
+{% include "Types.kt" %}
 
-{%- for func in ci.function_definitions() %}
-{%- include "TopLevelFunctionTemplate.kt" %}
+{%- for func in functions %}
+{% include "TopLevelFunctionTemplate.kt" %}
+{%- endfor %}
+
+{%- for constant in constants %}
+{%- call kt::docstring(constant, 0) %}{% endcall %}
+{% if constant.is_const_val %}const {% endif %}val {{ constant.name }}: {{ constant.ty.type_name }} = {{ constant.value }}
 {%- endfor %}
 
 {% import "macros.kt" as kt %}
@@ -13,16 +13,18 @@ use anyhow::Result;
 use camino::Utf8PathBuf;
 
 use crate::{BindgenLoader, BindgenPaths, GlobalConfig};
-mod kotlin;
+pub mod csharp;
+pub mod go;
+pub mod kotlin;
 pub mod python;
-mod ruby;
-mod swift;
+pub mod ruby;
+pub mod swift;
 pub use swift::{generate_swift_bindings, SwiftBindingsOptions};
 
 #[cfg(feature = "bindgen-tests")]
 pub use self::{
-    kotlin::test as kotlin_test, python::test as python_test, ruby::test as ruby_test,
-    swift::test as swift_test,
+    csharp::test as csharp_test, go::test as go_test, kotlin::test as kotlin_test,
+    python::test as python_test, ruby::test as ruby_test, swift::test as swift_test,
 };
 
 #[cfg(feature = "bindgen-tests")]
@@ -71,6 +73,9 @@ pub fn generate_with_bindgen_paths(
     let loader = BindgenLoader::new(paths, global_config);
     for language in options.languages.iter() {
         match language {
+            TargetLanguage::CSharp => {
+                csharp::generate(&loader, options.clone())?;
+            }
             TargetLanguage::Swift => {
                 swift::generate(&loader, options.clone())?;
             }
@@ -83,6 +88,9 @@ pub fn generate_with_bindgen_paths(
             TargetLanguage::Ruby => {
                 ruby::generate(&loader, options.clone())?;
             }
+            TargetLanguage::Go => {
+                go::generate(&loader, options.clone())?;
+            }
         }
     }
     Ok(())
@@ -113,6 +121,8 @@ pub struct GenerateOptions {
 
 #[derive(Clone, Debug)]
 pub enum TargetLanguage {
+    CSharp,
+    Go,
     Kotlin,
     Python,
     Ruby,
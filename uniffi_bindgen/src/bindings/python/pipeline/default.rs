@@ -8,6 +8,7 @@ pub fn can_use_literal(literal: &general::Literal) -> bool {
     match literal {
         general::Literal::Boolean(_)
         | general::Literal::String(_)
+        | general::Literal::Char(_)
         | general::Literal::UInt(_, _, _)
         | general::Literal::Int(_, _, _)
         | general::Literal::Float(_, _)
@@ -30,9 +31,12 @@ pub fn render_default(default: &general::DefaultValue, context: &Context) -> Res
             | Type::Int8
             | Type::Int16
             | Type::Int32
-            | Type::Int64 => "0".to_string(),
+            | Type::Int64
+            | Type::UInt128
+            | Type::Int128 => "0".to_string(),
             Type::Float32 | Type::Float64 => "0.0".to_string(),
             Type::Boolean => "False".to_string(),
+            Type::Char => "\"\\0\"".to_string(),
             Type::Bytes => "b\"\"".to_string(),
             Type::String => "\"\"".to_string(),
             Type::Record { .. }
@@ -41,7 +45,7 @@ pub fn render_default(default: &general::DefaultValue, context: &Context) -> Res
             | Type::CallbackInterface { .. } => format!("{}()", types::type_name(&tn.ty, context)?),
             Type::Optional { .. } => "None".to_string(),
             Type::Map { .. } => "{}".to_string(),
-            Type::Sequence { .. } => "[]".to_string(),
+            Type::Sequence { .. } | Type::Set { ordered: true, .. } => "[]".to_string(),
             Type::Set { .. } => "{*()}".to_string(),
             Type::Custom { builtin, .. } => {
                 return render_default(
@@ -64,6 +68,7 @@ pub fn render_literal(lit: &general::Literal, context: &Context) -> Result<Strin
         general::Literal::Boolean(true) => "True".to_string(),
         general::Literal::Boolean(false) => "False".to_string(),
         general::Literal::String(s) => format!("\"{s}\""),
+        general::Literal::Char(c) => format!("\"\\U{:08X}\"", u32::from(*c)),
         // https://docs.python.org/3/reference/lexical_analysis.html#integer-literals
         general::Literal::Int(i, radix, _) => match radix {
             Radix::Octal => format!("int(0o{i:o})"),
@@ -94,6 +99,21 @@ pub fn render_literal(lit: &general::Literal, context: &Context) -> Result<Strin
                 bail!("Invalid type for enum literal: {type_kind:?}")
             }
         },
+        general::Literal::Record { fields, ty } => match &ty.ty {
+            Type::Record { name, .. } => {
+                let fields = fields
+                    .iter()
+                    .map(|f| {
+                        let value = render_literal(&f.value, context)?;
+                        Ok(format!("{}={value}", names::var_name(&f.name)))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                format!("{name}({})", fields.join(", "))
+            }
+            type_kind => {
+                bail!("Invalid type for record literal: {type_kind:?}")
+            }
+        },
     })
 }
 
@@ -108,9 +128,12 @@ pub fn arg_literal(default: &general::DefaultValue, context: &Context) -> Result
             | Type::Int16
             | Type::Int32
             | Type::Int64
+            | Type::UInt128
+            | Type::Int128
             | Type::Float32
             | Type::Float64
             | Type::Boolean
+            | Type::Char
             | Type::Bytes
             | Type::String
             | Type::Optional { .. } => render_default(default, context)?,
@@ -13,7 +13,16 @@ pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result
 
     let mut module = Module {
         cdylib_name: context.cdylib()?,
-        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async()),
+        has_async_fns: namespace.has_descendant(|callable: &general::Callable| callable.is_async())
+            || namespace.has_descendant(|td: &general::TypeDefinition| {
+                matches!(td, general::TypeDefinition::Stream(_))
+            }),
+        has_iterator_types: namespace.has_descendant(|td: &general::TypeDefinition| {
+            matches!(td, general::TypeDefinition::Iterator(_))
+        }),
+        has_closure_types: namespace.has_descendant(|td: &general::TypeDefinition| {
+            matches!(td, general::TypeDefinition::Closure(_))
+        }),
         has_callback_interface: namespace.has_descendant(|_: &general::CallbackInterface| true),
         has_async_callback_method: has_async_callback_method(&namespace),
         imports: module_imports(&namespace, context.config()?),
@@ -23,6 +32,7 @@ pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result
         docstring: namespace.docstring.map_node(context)?,
         functions: namespace.functions.map_node(context)?,
         type_definitions: namespace.type_definitions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
         ffi_definitions: namespace.ffi_definitions.map_node(context)?,
         checksums: namespace.checksums.map_node(context)?,
         ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
@@ -44,6 +54,7 @@ pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result
         exported_names.push(i.protocol.name.clone());
     });
     module.visit(|c: &CallbackInterface| exported_names.push(c.protocol.name.clone()));
+    module.visit(|c: &Constant| exported_names.push(c.name.clone()));
     module.exported_names = exported_names;
     Ok(module)
 }
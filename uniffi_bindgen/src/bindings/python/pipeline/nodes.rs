@@ -64,6 +64,8 @@ pub struct BuiltinTypes {
 pub struct Module {
     pub cdylib_name: String,
     pub has_async_fns: bool,
+    pub has_iterator_types: bool,
+    pub has_closure_types: bool,
     pub has_callback_interface: bool,
     pub has_async_callback_method: bool,
     pub imports: Vec<String>,
@@ -73,6 +75,7 @@ pub struct Module {
     pub docstring: Option<String>,
     pub functions: Vec<Function>,
     pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
     pub ffi_definitions: IndexSet<FfiDefinition>,
     pub checksums: Vec<Checksum>,
     pub ffi_rustbuffer_alloc: RustFfiFunctionName,
@@ -110,8 +113,13 @@ pub enum TypeDefinition {
     Box(BoxedType),
     Optional(OptionalType),
     Sequence(SequenceType),
+    Array(ArrayType),
     Map(MapType),
     Set(SetType),
+    Tuple(TupleType),
+    Stream(StreamType),
+    Iterator(IteratorType),
+    Closure(ClosureType),
     /// User types that are defined in another crate
     External(ExternalType),
 }
@@ -127,6 +135,7 @@ pub struct Constructor {
 #[map_node(from(general::Method))]
 pub struct Method {
     pub callable: Callable,
+    pub default_ffi_func: Option<RustFfiFunctionName>,
     pub docstring: Option<String>,
 }
 
@@ -155,6 +164,8 @@ pub enum CallableKind {
     Method { self_type: TypeNode },
     /// Interface constructor
     Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
     /// Method inside a VTable or a CallbackInterface
     ///
     /// For trait interfaces this only applies to the Callables inside the `vtable.methods` field.
@@ -249,6 +260,7 @@ pub struct LiteralNode {
 pub enum Literal {
     Boolean(bool),
     String(String),
+    Char(char),
     // Integers are represented as the widest representation we can.
     // Number formatting vary with language and radix, so we avoid a lot of parsing and
     // formatting duplication by using only signed and unsigned variants.
@@ -270,6 +282,30 @@ pub enum Literal {
     Some {
         inner: Box<DefaultValue>,
     },
+    Record {
+        fields: Vec<FieldLiteral>,
+        ty: TypeNode,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FieldLiteral))]
+pub struct FieldLiteral {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub value: Literal,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    pub name: String,
+    #[map_node(types::type_annotation(&self.ty.ty, context)?)]
+    pub type_annotation: String,
+    pub ty: TypeNode,
+    pub value: LiteralNode,
+    pub docstring: Option<String>,
 }
 
 #[derive(Debug, Clone, Node, MapNode)]
@@ -284,6 +320,7 @@ pub struct Record {
     #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
     pub uniffi_trait_methods: UniffiTraitMethods,
     pub recursive: bool,
 }
@@ -316,6 +353,7 @@ pub struct Enum {
     #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
     pub uniffi_trait_methods: UniffiTraitMethods,
     pub recursive: bool,
 }
@@ -342,6 +380,7 @@ pub struct Interface {
     #[map_node(interfaces::map_constructors(&self.name, self.constructors, context)?)]
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
     pub uniffi_trait_methods: UniffiTraitMethods,
     pub trait_impls: Vec<ObjectTraitImpl>,
     pub imp: ObjectImpl,
@@ -439,11 +478,20 @@ pub struct SequenceType {
     pub self_type: TypeNode,
 }
 
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
 #[derive(Debug, Clone, Node, MapNode)]
 #[map_node(from(general::MapType))]
 pub struct MapType {
     pub key: TypeNode,
     pub value: TypeNode,
+    pub ordered: bool,
     pub self_type: TypeNode,
 }
 
@@ -451,6 +499,36 @@ pub struct MapType {
 #[map_node(from(general::SetType))]
 pub struct SetType {
     pub inner: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::IteratorType))]
+pub struct IteratorType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: Option<TypeNode>,
     pub self_type: TypeNode,
 }
 
@@ -497,6 +575,10 @@ pub struct UniffiTraitMethods {
     pub eq_ne: Option<Method>,
     pub hash_hash: Option<Method>,
     pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<Method>,
 }
 
 #[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
@@ -20,16 +20,34 @@ fn rename_type(ty: &mut Type) {
         }
         Type::Optional { inner_type }
         | Type::Sequence { inner_type }
+        | Type::Array { inner_type, .. }
         | Type::Box { inner_type } => {
             rename_type(inner_type);
         }
         Type::Map {
             key_type,
             value_type,
+            ..
         } => {
             rename_type(key_type);
             rename_type(value_type);
         }
+        Type::Tuple { inner_types } => {
+            for inner_type in inner_types {
+                rename_type(inner_type);
+            }
+        }
+        Type::Stream { item_type } | Type::Iterator { item_type } => {
+            rename_type(item_type);
+        }
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => {
+            for ty in argument_types.iter_mut().chain(return_type.as_deref_mut()) {
+                rename_type(ty);
+            }
+        }
         _ => (),
     }
 }
@@ -53,7 +71,7 @@ pub fn map_return_type(return_type: general::ReturnType, context: &Context) -> R
 pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
     Ok(match ty {
         Type::Boolean => "bool".to_string(),
-        Type::String => "str".to_string(),
+        Type::String | Type::Char => "str".to_string(),
         Type::Bytes => "bytes".to_string(),
         Type::Int8 => "int".to_string(),
         Type::Int16
@@ -62,7 +80,9 @@ pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
         | Type::UInt8
         | Type::UInt16
         | Type::UInt32
-        | Type::UInt64 => "int".to_string(),
+        | Type::UInt64
+        | Type::Int128
+        | Type::UInt128 => "int".to_string(),
         Type::Duration => "Duration".to_string(),
         Type::Timestamp => "Timestamp".to_string(),
         Type::Float32 | Type::Float64 => "float".to_string(),
@@ -90,21 +110,59 @@ pub fn type_name(ty: &Type, context: &Context) -> Result<String> {
         Type::Optional { inner_type } => {
             format!("typing.Optional[{}]", type_name(inner_type, context)?)
         }
-        Type::Sequence { inner_type } => {
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => {
             format!("typing.List[{}]", type_name(inner_type, context)?)
         }
+        // Python dicts preserve insertion order, so they work for ordered maps too.
         Type::Map {
             key_type,
             value_type,
+            ..
         } => format!(
             "dict[{}, {}]",
             type_name(key_type, context)?,
             type_name(value_type, context)?
         ),
         Type::Box { inner_type } => type_name(inner_type, context)?,
-        Type::Set { inner_type } => {
+        // There's no builtin ordered set, so use a list to preserve the order.
+        Type::Set {
+            inner_type,
+            ordered: true,
+        } => {
+            format!("typing.List[{}]", type_name(inner_type, context)?)
+        }
+        Type::Set { inner_type, .. } => {
             format!("typing.Set[{}]", type_name(inner_type, context)?)
         }
+        Type::Tuple { inner_types } => format!(
+            "typing.Tuple[{}]",
+            inner_types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        ),
+        Type::Stream { item_type } => {
+            format!("typing.AsyncIterator[{}]", type_name(item_type, context)?)
+        }
+        Type::Iterator { item_type } => {
+            format!("typing.Iterator[{}]", type_name(item_type, context)?)
+        }
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "typing.Callable[[{}], {}]",
+            argument_types
+                .iter()
+                .map(|ty| type_name(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", "),
+            match return_type {
+                Some(return_type) => type_name(return_type, context)?,
+                None => "None".to_string(),
+            }
+        ),
     })
 }
 
@@ -120,22 +178,38 @@ pub fn type_annotation(ty: &Type, context: &Context) -> Result<String> {
             "typing.Optional[{}]",
             type_annotation(inner_type, context)?
         )),
-        Type::Sequence { inner_type } => Ok(format!(
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => Ok(format!(
             "typing.List[{}]",
             type_annotation(inner_type, context)?
         )),
         Type::Map {
             key_type,
             value_type,
+            ..
         } => Ok(format!(
             "dict[{}, {}]",
             type_annotation(key_type, context)?,
             type_annotation(value_type, context)?
         )),
-        Type::Set { inner_type } => Ok(format!(
+        Type::Set {
+            inner_type,
+            ordered: true,
+        } => Ok(format!(
+            "typing.List[{}]",
+            type_annotation(inner_type, context)?
+        )),
+        Type::Set { inner_type, .. } => Ok(format!(
             "typing.Set[{}]",
             type_annotation(inner_type, context)?
         )),
+        Type::Tuple { inner_types } => Ok(format!(
+            "typing.Tuple[{}]",
+            inner_types
+                .iter()
+                .map(|ty| type_annotation(ty, context))
+                .collect::<Result<Vec<_>>>()?
+                .join(", ")
+        )),
         _ => type_name(ty, context),
     }
 }
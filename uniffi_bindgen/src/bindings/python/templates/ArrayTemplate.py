@@ -0,0 +1,23 @@
+class {{ array.self_type.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if len(value) != {{ array.len }}:
+            raise ValueError(f"Expected an array of length {{ array.len }}, found {len(value)}")
+        for item in value:
+            {{ array.inner.ffi_converter_name }}.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        buf.write_i32({{ array.len }})
+        for item in value:
+            {{ array.inner.ffi_converter_name }}.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count != {{ array.len }}:
+            raise InternalError(f"Expected an array of length {{ array.len }}, found {count}")
+
+        return [
+            {{ array.inner.ffi_converter_name }}.read(buf) for i in range(count)
+        ]
@@ -40,7 +40,7 @@ def _uniffi_set_future_result(future, poll_code):
     if not future.cancelled():
         future.set_result(poll_code)
 
-async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_complete, ffi_free, lift_func, error_ffi_converter):
+async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_cancel, ffi_complete, ffi_free, lift_func, error_ffi_converter):
     try:
         eventloop = _uniffi_get_event_loop()
 
@@ -52,7 +52,13 @@ async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_complete, ffi_free,
                 _uniffi_continuation_callback,
                 _UniffiContinuationHandleMap.insert((eventloop, future)),
             )
-            poll_code = await future
+            try:
+                poll_code = await future
+            except asyncio.CancelledError:
+                # The Python task was cancelled, cancel the Rust future so that it gets dropped
+                # promptly, then let the `CancelledError` propagate.
+                ffi_cancel(rust_future)
+                raise
             if poll_code == _UNIFFI_RUST_FUTURE_POLL_READY:
                 break
 
@@ -62,6 +68,51 @@ async def _uniffi_rust_call_async(rust_future, ffi_poll, ffi_complete, ffi_free,
     finally:
         ffi_free(rust_future)
 
+# Async iterator for streams returned by Rust functions
+#
+# Each item is fetched by creating a Rust future with `rust_stream_next` and awaiting it.  The
+# future returns a serialized `Option` value, where `None` signals the end of the stream.
+# `lift_func` lifts that into either a 1-tuple containing the item or `None`.
+class _UniffiRustStream:
+    def __init__(self, handle, lift_func):
+        self._handle = handle
+        self._lift_func = lift_func
+
+    def __aiter__(self):
+        return self
+
+    async def __anext__(self):
+        if self._handle is None:
+            raise StopAsyncIteration
+        try:
+            item = await _uniffi_rust_call_async(
+                _UniffiLib.ffi_{{ crate_name }}_rust_stream_next(self._handle),
+                _UniffiLib.ffi_{{ crate_name }}_rust_future_poll_rust_buffer,
+                _UniffiLib.ffi_{{ crate_name }}_rust_future_cancel_rust_buffer,
+                _UniffiLib.ffi_{{ crate_name }}_rust_future_complete_rust_buffer,
+                _UniffiLib.ffi_{{ crate_name }}_rust_future_free_rust_buffer,
+                self._lift_func,
+                None,
+            )
+        except BaseException:
+            self._free()
+            raise
+        if item is None:
+            self._free()
+            raise StopAsyncIteration
+        return item[0]
+
+    async def aclose(self):
+        self._free()
+
+    def _free(self):
+        if self._handle is not None:
+            handle, self._handle = self._handle, None
+            _UniffiLib.ffi_{{ crate_name }}_rust_stream_free(handle)
+
+    def __del__(self):
+        self._free()
+
 {%- if has_async_callback_method %}
 def _uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callback, handle_success, handle_error):
     async def make_call_and_call_callback():
@@ -60,6 +60,7 @@ return _uniffi_lift_return(_uniffi_ffi_result)
 return await _uniffi_rust_call_async(
     _UniffiLib.{{ callable.ffi_func.0 }}(*_uniffi_lowered_args),
     _UniffiLib.{{ async_data.ffi_rust_future_poll.0 }},
+    _UniffiLib.{{ async_data.ffi_rust_future_cancel.0 }},
     _UniffiLib.{{ async_data.ffi_rust_future_complete.0 }},
     _UniffiLib.{{ async_data.ffi_rust_future_free.0 }},
     _uniffi_lift_return,
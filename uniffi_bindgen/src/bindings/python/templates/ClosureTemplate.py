@@ -0,0 +1,57 @@
+class {{ closure.self_type.ffi_converter_name }}:
+    @staticmethod
+    def lift(handle: int):
+        rust_closure = _UniffiRustClosure(handle)
+        def call(*args):
+            if len(args) != {{ closure.arguments.len() }}:
+                raise TypeError(f"expected {{ closure.arguments.len() }} arguments, got {len(args)}")
+            {%- for arg in closure.arguments %}
+            {{ arg.ffi_converter_name }}.check_lower(args[{{ loop.index0 }}])
+            {%- endfor %}
+            with _UniffiRustBuffer.alloc_with_builder() as builder:
+                {%- for arg in closure.arguments %}
+                {{ arg.ffi_converter_name }}.write(args[{{ loop.index0 }}], builder)
+                {%- endfor %}
+                args_buf = builder.finalize()
+            with rust_closure.call(args_buf).consume_with_stream() as buf:
+                {%- match closure.return_type %}
+                {%- when Some(return_type) %}
+                return {{ return_type.ffi_converter_name }}.read(buf)
+                {%- when None %}
+                return None
+                {%- endmatch %}
+        # Remember the Rust closure, so that we can pass it back to Rust without wrapping it again
+        call._uniffi_rust_closure = rust_closure
+        return call
+
+    @classmethod
+    def read(cls, buf: _UniffiRustBuffer):
+        return cls.lift(buf.read_u64())
+
+    @staticmethod
+    def check_lower(value):
+        if not callable(value):
+            raise TypeError(f"Expected a callable, got {type(value).__name__}")
+
+    @staticmethod
+    def lower(value):
+        rust_closure = getattr(value, "_uniffi_rust_closure", None)
+        if rust_closure is not None:
+            return rust_closure.clone_handle()
+
+        def erased(args):
+            with args.consume_with_stream() as buf:
+                uniffi_args = ({% for arg in closure.arguments %}{{ arg.ffi_converter_name }}.read(buf), {% endfor %})
+            return_value = value(*uniffi_args)
+            with _UniffiRustBuffer.alloc_with_builder() as builder:
+                {%- match closure.return_type %}
+                {%- when Some(return_type) %}
+                {{ return_type.ffi_converter_name }}.write(return_value, builder)
+                {%- when None %}
+                {%- endmatch %}
+                return builder.finalize()
+        return _UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.insert(erased)
+
+    @classmethod
+    def write(cls, value, buf: _UniffiRustBuffer):
+        buf.write_u64(cls.lower(value))
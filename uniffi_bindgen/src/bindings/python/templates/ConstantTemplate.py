@@ -0,0 +1,3 @@
+
+{{ constant.name }}: {{ constant.type_annotation }} = {{ constant.value.py_lit }}
+{{ constant.docstring|docstring(0) -}}
@@ -0,0 +1,35 @@
+{#- Call the Rust default implementation of a trait method, passing `self` as the receiver -#}
+{%- for arg in callable.arguments %}
+{%- if let Some(default) = arg.default %}
+{%- if !default.is_arg_literal() %}
+if {{ arg.name }} is {{ default.arg_literal }}:
+    {{ arg.name }} = {{ default.py_default }}
+{%- endif %}
+{%- endif %}
+{{ arg.ffi_converter_name() }}.check_lower({{ arg.name }})
+{% endfor -%}
+
+{%- match callable.throws_type.ty %}
+{%- when Some(e) %}
+{%-    if callable.throws_type.from_interface %}
+_uniffi_error_converter = {{ e.ffi_converter_name }}__as_error
+{%-    else %}
+_uniffi_error_converter = {{ e.ffi_converter_name }}
+{%-    endif %}
+{%- when None %}
+_uniffi_error_converter = None
+{%- endmatch %}
+_uniffi_ffi_result = _uniffi_rust_call_with_error(
+    _uniffi_error_converter,
+    _UniffiLib.{{ default_ffi_func.0 }},
+    {{ ffi_converter_name }}.lower(self),
+    {%- for arg in callable.arguments %}
+    {{ arg.ffi_converter_name() }}.lower({{ arg.name }}),
+    {%- endfor %}
+)
+{%- match callable.return_type.ty %}
+{%- when Some(return_type) %}
+return {{ return_type.ffi_converter_name }}.lift(_uniffi_ffi_result)
+{%- when None %}
+return None
+{%- endmatch %}
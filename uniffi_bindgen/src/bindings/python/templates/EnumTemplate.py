@@ -24,6 +24,19 @@ class {{ type_name }}(enum.Enum):
         {%- include "CallableBody.py" %}
         {%- endfilter %}
     {%- endfor %}
+    {%- for meth in e.static_methods -%}
+    {%- let callable = meth.callable %}
+    @staticmethod
+    {% if callable.is_async() %}async {% endif %}def {{ callable.name }}({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+        {{ meth.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+    {%- endfor %}
+
+    {% filter indent(4) %}
+    {%- include "UniffiTraitStaticMethods.py" %}
+    {% endfilter %}
 {% else %}
 
 class {{ type_name }}:
@@ -135,6 +148,19 @@ class {{ type_name }}:
         {%- include "CallableBody.py" %}
         {%- endfilter %}
     {%- endfor %}
+    {%- for meth in e.static_methods -%}
+    {%- let callable = meth.callable %}
+    @staticmethod
+    {% if callable.is_async() %}async {% endif %}def {{ callable.name }}({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+        {{ meth.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+    {%- endfor %}
+
+    {% filter indent(4) %}
+    {%- include "UniffiTraitStaticMethods.py" %}
+    {% endfilter %}
 
 # Now, a little trick - we make each nested variant class be a subclass of the main
 # enum class, so that method calls and instance checks etc will work intuitively.
@@ -9,6 +9,16 @@ class {{ type_name }}(Exception):
     {{ e.docstring|docstring(4) -}}
     pass
 
+{%- for meth in e.static_methods -%}
+{%-     let callable = meth.callable %}
+    @staticmethod
+    {% if callable.is_async() %}async {% endif %}def {{ callable.name }}({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+        {{ meth.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+{%- endfor %}
+
 _UniffiTemp{{ type_name }} = {{ type_name }}
 
 class {{ type_name }}:  # type: ignore
@@ -17,6 +17,7 @@ class _UniffiRustCallStatus(ctypes.Structure):
     CALL_SUCCESS = 0
     CALL_ERROR = 1
     CALL_UNEXPECTED_ERROR = 2
+    CALL_CANCELLED = 3
 
     @staticmethod
     def default():
@@ -29,6 +30,8 @@ class _UniffiRustCallStatus(ctypes.Structure):
             return "_UniffiRustCallStatus(CALL_ERROR)"
         elif self.code == _UniffiRustCallStatus.CALL_UNEXPECTED_ERROR:
             return "_UniffiRustCallStatus(CALL_UNEXPECTED_ERROR)"
+        elif self.code == _UniffiRustCallStatus.CALL_CANCELLED:
+            return "_UniffiRustCallStatus(CALL_CANCELLED)"
         else:
             return "_UniffiRustCallStatus(<invalid code>)"
 
@@ -66,6 +69,10 @@ def _uniffi_check_call_status(error_ffi_converter, call_status):
         else:
             msg = "Unknown rust panic"
         raise InternalError(msg)
+    {%- if has_async_fns %}
+    elif call_status.code == _UniffiRustCallStatus.CALL_CANCELLED:
+        raise asyncio.CancelledError()
+    {%- endif %}
     else:
         raise InternalError("Invalid _UniffiRustCallStatus code: {}".format(
             call_status.code))
@@ -57,8 +57,19 @@ class {{ int.name }}({{ int.base_classes|join(", ") }}):
         {%- endfilter %}
 {%- endfor %}
 
+{%- for meth in int.static_methods -%}
+{%-     let callable = meth.callable %}
+    @staticmethod
+    {% if callable.is_async() %}async {% endif %}def {{ callable.name }}({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+        {{ meth.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+{%- endfor %}
+
 {%- let uniffi_trait_methods = int.uniffi_trait_methods %}
 {% filter indent(4) %}
+{% include "UniffiTraitStaticMethods.py" -%}
 {% include "UniffiTraitImpls.py" -%}
 {% endfilter %}
 
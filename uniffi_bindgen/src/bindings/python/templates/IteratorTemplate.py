@@ -0,0 +1,26 @@
+class {{ iterator.self_type.ffi_converter_name }}:
+    @classmethod
+    def lift(cls, value: int):
+        return _UniffiRustIterator(value, cls._uniffi_lift_chunk)
+
+    @staticmethod
+    def _uniffi_lift_chunk(rbuf):
+        with rbuf.consume_with_stream() as buf:
+            count = buf.read_i32()
+            return [{{ iterator.item.ffi_converter_name }}.read(buf) for _ in range(count)]
+
+    @classmethod
+    def read(cls, buf: _UniffiRustBuffer):
+        return cls.lift(buf.read_u64())
+
+    @staticmethod
+    def check_lower(value):
+        raise TypeError("Iterators can't be passed to Rust")
+
+    @staticmethod
+    def lower(value):
+        raise TypeError("Iterators can't be passed to Rust")
+
+    @classmethod
+    def write(cls, value, buf: _UniffiRustBuffer):
+        raise TypeError("Iterators can't be passed to Rust")
@@ -54,6 +54,12 @@ _DEFAULT = object() # type: typing.Any
 {%- if has_async_fns %}
 {%- include "Async.py" %}
 {%- endif %}
+{%- if has_iterator_types %}
+{%- include "RustIterator.py" %}
+{%- endif %}
+{%- if has_closure_types %}
+{%- include "RustClosure.py" %}
+{%- endif %}
 
 # Public interface members begin here.
 {% include "Types.py" %}
@@ -62,6 +68,12 @@ _DEFAULT = object() # type: typing.Any
 {%- include "TopLevelFunctionTemplate.py" %}
 {%- endfor %}
 
+{%- for constant in constants %}
+{%- if loop.first %}
+{% endif %}
+{%- include "ConstantTemplate.py" %}
+{%- endfor %}
+
 __all__ = [
     {%- for name in exported_names %}
     "{{ name }}",
@@ -5,7 +5,14 @@ class {{ protocol.name }}({{ protocol.base_classes|join(", ") }}):
     {%- let callable = meth.callable %}
     {% if callable.is_async() %}async {% endif %}def {{ meth.callable.name }}(self, {% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
         {{ meth.docstring|docstring(8) -}}
+        {%- match meth.default_ffi_func %}
+        {%- when Some(default_ffi_func) %}
+        {%- filter indent(8) %}
+        {%- include "DefaultMethodBody.py" %}
+        {%- endfilter %}
+        {%- when None %}
         raise NotImplementedError
+        {%- endmatch %}
     {%- else %}
     pass
     {%- endfor %}
@@ -27,6 +27,7 @@ class {{ rec.self_type.type_name }}:
     {%- let uniffi_trait_methods = rec.uniffi_trait_methods -%}
 
     {% filter indent(4) %}
+    {% include "UniffiTraitStaticMethods.py" %}
     {% include "UniffiTraitImpls.py" %}
     {% endfilter %}
 
@@ -39,6 +40,16 @@ class {{ rec.self_type.type_name }}:
         {%- endfilter %}
 {%- endfor %}
 
+{%- for meth in rec.static_methods -%}
+{%-     let callable = meth.callable %}
+    @staticmethod
+    {% if callable.is_async() %}async {% endif %}def {{ callable.name }}({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+        {{ meth.docstring|docstring(8) -}}
+        {%- filter indent(8) %}
+        {%- include "CallableBody.py" %}
+        {%- endfilter %}
+{%- endfor %}
+
     {# "builtin" methods not handled by a uniffi_trait #}
     {%- if uniffi_trait_methods.display_fmt.is_none() %}
     def __str__(self):
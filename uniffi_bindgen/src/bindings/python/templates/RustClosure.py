@@ -0,0 +1,53 @@
+# Support for closures
+#
+# Closures are type-erased: the arguments and return value are serialized into RustBuffers.
+# The FfiConverter for each closure type converts between Python callables and "erased" functions
+# that input the serialized arguments and return the serialized return value.
+#
+# Python callables are stored in `_UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP` as erased functions and Rust
+# calls them through the `VTableForeignClosure` VTable.  Rust closures are wrapped in
+# `_UniffiRustClosure`.
+
+_UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP = _UniffiHandleMap()
+
+class _UniffiForeignClosureVTable:
+    @_UNIFFI_FOREIGN_CLOSURE_FREE
+    def _uniffi_free(handle):
+        _UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.remove(handle)
+
+    @_UNIFFI_FOREIGN_CLOSURE_CLONE
+    def _uniffi_clone(handle):
+        return _UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.clone(handle)
+
+    @_UNIFFI_FOREIGN_CLOSURE_CALL
+    def _uniffi_call(handle, args, uniffi_out_return, uniffi_call_status_ptr):
+        erased = _UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.get(handle)
+        def write_return_value(v):
+            uniffi_out_return[0] = v
+        _uniffi_trait_interface_call(
+                uniffi_call_status_ptr.contents,
+                lambda: erased(args),
+                write_return_value,
+        )
+
+    _uniffi_vtable = _UniffiVTableForeignClosure(_uniffi_free, _uniffi_clone, _uniffi_call)
+    # Send Rust a pointer to the VTable.  Note: this means we need to keep the struct alive forever,
+    # or else bad things will happen when Rust tries to access it.
+    _UniffiLib.ffi_{{ crate_name }}_foreign_closure_init_vtable(ctypes.byref(_uniffi_vtable))
+
+class _UniffiRustClosure:
+    def __init__(self, handle):
+        self._handle = handle
+
+    def call(self, args):
+        return _uniffi_rust_call(
+            _UniffiLib.ffi_{{ crate_name }}_rust_closure_call,
+            self._handle,
+            args,
+        )
+
+    def clone_handle(self):
+        return _UniffiLib.ffi_{{ crate_name }}_rust_closure_clone(self._handle)
+
+    def __del__(self):
+        _UniffiLib.ffi_{{ crate_name }}_rust_closure_free(self._handle)
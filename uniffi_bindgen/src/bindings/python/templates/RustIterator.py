@@ -0,0 +1,52 @@
+# Iterator for iterators returned by Rust functions
+#
+# Items are fetched in chunks with `rust_iter_next_chunk`, which returns a serialized list of up
+# to `_UNIFFI_RUST_ITER_CHUNK_SIZE` items.  An empty list signals the end of the iterator.
+# `lift_chunk` lifts that into a Python list.
+_UNIFFI_RUST_ITER_CHUNK_SIZE = 64
+
+class _UniffiRustIterator:
+    def __init__(self, handle, lift_chunk):
+        self._handle = handle
+        self._lift_chunk = lift_chunk
+        # Items from the current chunk, in reverse order so that we can pop them from the end
+        self._items = []
+
+    def __iter__(self):
+        return self
+
+    def __next__(self):
+        if not self._items:
+            self._fetch_chunk()
+        if not self._items:
+            raise StopIteration
+        return self._items.pop()
+
+    def _fetch_chunk(self):
+        if self._handle is None:
+            return
+        try:
+            items = self._lift_chunk(_uniffi_rust_call(
+                _UniffiLib.ffi_{{ crate_name }}_rust_iter_next_chunk,
+                self._handle,
+                _UNIFFI_RUST_ITER_CHUNK_SIZE,
+            ))
+        except BaseException:
+            self._free()
+            raise
+        if not items:
+            self._free()
+        items.reverse()
+        self._items = items
+
+    def close(self):
+        self._items = []
+        self._free()
+
+    def _free(self):
+        if self._handle is not None:
+            handle, self._handle = self._handle, None
+            _UniffiLib.ffi_{{ crate_name }}_rust_iter_free(handle)
+
+    def __del__(self):
+        self._free()
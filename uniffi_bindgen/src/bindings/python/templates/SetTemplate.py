@@ -16,6 +16,12 @@ class {{ set.self_type.ffi_converter_name }}(_UniffiConverterRustBuffer):
         if count < 0:
             raise InternalError("Unexpected negative set size")
 
+        {% if set.ordered -%}
+        return [
+            {{ set.inner.ffi_converter_name }}.read(buf) for i in range(count)
+        ]
+        {%- else -%}
         return {
             {{ set.inner.ffi_converter_name }}.read(buf) for i in range(count)
         }
+        {%- endif %}
@@ -0,0 +1,27 @@
+class {{ stream.self_type.ffi_converter_name }}:
+    @classmethod
+    def lift(cls, value: int):
+        return _UniffiRustStream(value, cls._uniffi_lift_next)
+
+    @staticmethod
+    def _uniffi_lift_next(rbuf):
+        with rbuf.consume_with_stream() as buf:
+            if buf.read_u8() == 0:
+                return None
+            return ({{ stream.item.ffi_converter_name }}.read(buf),)
+
+    @classmethod
+    def read(cls, buf: _UniffiRustBuffer):
+        return cls.lift(buf.read_u64())
+
+    @staticmethod
+    def check_lower(value):
+        raise TypeError("Streams can't be passed to Rust")
+
+    @staticmethod
+    def lower(value):
+        raise TypeError("Streams can't be passed to Rust")
+
+    @classmethod
+    def write(cls, value, buf: _UniffiRustBuffer):
+        raise TypeError("Streams can't be passed to Rust")
@@ -0,0 +1,22 @@
+class {{ tuple.self_type.ffi_converter_name }}(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if len(value) != {{ tuple.inners.len() }}:
+            raise TypeError(f"Expected a tuple of {{ tuple.inners.len() }} elements, found {len(value)}")
+        {%- for inner in tuple.inners %}
+        {{ inner.ffi_converter_name }}.check_lower(value[{{ loop.index0 }}])
+        {%- endfor %}
+
+    @classmethod
+    def write(cls, value, buf):
+        {%- for inner in tuple.inners %}
+        {{ inner.ffi_converter_name }}.write(value[{{ loop.index0 }}], buf)
+        {%- endfor %}
+
+    @classmethod
+    def read(cls, buf):
+        return (
+            {%- for inner in tuple.inners %}
+            {{ inner.ffi_converter_name }}.read(buf),
+            {%- endfor %}
+        )
@@ -17,6 +17,9 @@
 {%- when Type::Boolean %}
 {%- include "builtins/Boolean.py" %}
 
+{%- when Type::Char %}
+{%- include "builtins/Char.py" %}
+
 {%- when Type::Int8 %}
 {%- include "builtins/Int8.py" %}
 
@@ -29,6 +32,9 @@
 {%- when Type::Int64 %}
 {%- include "builtins/Int64.py" %}
 
+{%- when Type::Int128 %}
+{%- include "builtins/Int128.py" %}
+
 {%- when Type::UInt8 %}
 {%- include "builtins/UInt8.py" %}
 
@@ -41,6 +47,9 @@
 {%- when Type::UInt64 %}
 {%- include "builtins/UInt64.py" %}
 
+{%- when Type::UInt128 %}
+{%- include "builtins/UInt128.py" %}
+
 {%- when Type::Float32 %}
 {%- include "builtins/Float32.py" %}
 
@@ -72,12 +81,27 @@
 {%- when TypeDefinition::Sequence(seq) %}
 {%- include "SequenceTemplate.py" %}
 
+{%- when TypeDefinition::Array(array) %}
+{%- include "ArrayTemplate.py" %}
+
 {%- when TypeDefinition::Map(map) %}
 {%- include "MapTemplate.py" %}
 
 {%- when TypeDefinition::Set(set) %}
 {%- include "SetTemplate.py" %}
 
+{%- when TypeDefinition::Tuple(tuple) %}
+{%- include "TupleTemplate.py" %}
+
+{%- when TypeDefinition::Stream(stream) %}
+{%- include "StreamTemplate.py" %}
+
+{%- when TypeDefinition::Iterator(iterator) %}
+{%- include "IteratorTemplate.py" %}
+
+{%- when TypeDefinition::Closure(closure) %}
+{%- include "ClosureTemplate.py" %}
+
 {%- when TypeDefinition::Enum(e) %}
 {# For enums, there are either an error *or* an enum, they can't be both. #}
 {%- if e.self_type.is_used_as_error %}
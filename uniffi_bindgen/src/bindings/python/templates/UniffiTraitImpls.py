@@ -70,3 +70,45 @@ def __gt__(self, other) -> bool:
 def __ge__(self, other) -> bool:
     return self.__rust_cmp__(other) >= 0
 {%- endif %}
+
+{%- if let Some(partial_cmp) = uniffi_trait_methods.partial_ord_partial_cmp %}
+{%-    let callable = partial_cmp.callable %}
+
+# The Rust `PartialOrd::partial_cmp` implementation, which returns `None` if the values can't be
+# compared.
+def __rust_partial_cmp__(self, other) -> {{ callable.return_type.type_name }}:
+    {% filter indent(4) -%}
+    {% include "CallableBody.py" -%}
+    {% endfilter %}
+{%-    if uniffi_trait_methods.ord_cmp.is_none() %}
+
+def __lt__(self, other) -> bool:
+    cmp = self.__rust_partial_cmp__(other)
+    return cmp is not None and cmp < 0
+
+def __le__(self, other) -> bool:
+    cmp = self.__rust_partial_cmp__(other)
+    return cmp is not None and cmp <= 0
+
+def __gt__(self, other) -> bool:
+    cmp = self.__rust_partial_cmp__(other)
+    return cmp is not None and cmp > 0
+
+def __ge__(self, other) -> bool:
+    cmp = self.__rust_partial_cmp__(other)
+    return cmp is not None and cmp >= 0
+{%-    endif %}
+{%- endif %}
+
+{%- if let Some(clone) = uniffi_trait_methods.clone_clone %}
+{%-    let callable = clone.callable %}
+
+# `copy.copy()` and `copy.deepcopy()` both call the Rust `Clone` implementation.
+def __copy__(self) -> {{ callable.return_type.type_name }}:
+    {% filter indent(4) -%}
+    {% include "CallableBody.py" -%}
+    {% endfilter %}
+
+def __deepcopy__(self, memo) -> {{ callable.return_type.type_name }}:
+    return self.__copy__()
+{%- endif %}
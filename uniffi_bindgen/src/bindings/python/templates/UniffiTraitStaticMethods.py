@@ -0,0 +1,22 @@
+{# expects var `uniffi_trait_methods` #}
+{%- if let Some(default) = uniffi_trait_methods.default_default %}
+{%-    let callable = default.callable %}
+
+# The Rust `Default::default` implementation.
+@staticmethod
+def default() -> {{ callable.return_type.type_name }}:
+    {% filter indent(4) -%}
+    {% include "CallableBody.py" -%}
+    {% endfilter -%}
+{%- endif %}
+
+{%- if let Some(from_str) = uniffi_trait_methods.from_str_from_str %}
+{%-    let callable = from_str.callable %}
+
+# The Rust `FromStr::from_str` implementation.
+@staticmethod
+def parse({% include "CallableArgs.py" %}) -> {{ callable.return_type.type_name }}:
+    {% filter indent(4) -%}
+    {% include "CallableBody.py" -%}
+    {% endfilter -%}
+{%- endif %}
@@ -0,0 +1,26 @@
+# Rust chars are Unicode scalar values, which we represent as a single-character `str`.
+class {{ type_node.ffi_converter_name }}:
+    @staticmethod
+    def check_lower(value):
+        if not isinstance(value, str):
+            raise TypeError("argument must be str, not {}".format(type(value).__name__))
+        if len(value) != 1:
+            raise ValueError("char requires a string of length 1, not {}".format(len(value)))
+        if 0xD800 <= ord(value) <= 0xDFFF:
+            raise ValueError("char can't be a surrogate code point")
+
+    @staticmethod
+    def lower(value):
+        return ord(value)
+
+    @staticmethod
+    def lift(value):
+        return chr(value)
+
+    @classmethod
+    def read(cls, buf):
+        return cls.lift(buf.read_u32())
+
+    @classmethod
+    def write(cls, value, buf):
+        buf.write_u32(cls.lower(value))
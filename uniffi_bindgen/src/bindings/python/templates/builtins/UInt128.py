@@ -0,0 +1,13 @@
+# 128-bit integers don't fit in a C type, so they're passed in a RustBuffer as 16 big-endian bytes.
+class {{ type_node.ffi_converter_name }}(_UniffiConverterRustBuffer, _UniffiConverterPrimitiveInt):
+    CLASS_NAME = "u128"
+    VALUE_MIN = 0
+    VALUE_MAX = 2**128
+
+    @staticmethod
+    def read(buf):
+        return int.from_bytes(buf.read(16), "big", signed=False)
+
+    @staticmethod
+    def write(value, buf):
+        buf.write(value.__index__().to_bytes(16, "big", signed=False))
@@ -0,0 +1,109 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// This module contains the serde structs to parse the `uniffi.toml` config.
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use uniffi_pipeline::Node;
+
+// These just exist so we can parse the entire `uniffi.toml` file, the codegen only uses the
+// `RubyConfig` part.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub bindings: BindingsConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BindingsConfig {
+    #[serde(default)]
+    pub ruby: RubyConfig,
+}
+
+/// Config options for the caller to customize the generated Ruby.
+///
+/// `exclude` and `rename` are also read from this table, but those are handled by the general
+/// pipeline.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RubyConfig {
+    pub(super) cdylib_name: Option<String>,
+    cdylib_path: Option<String>,
+    #[serde(default)]
+    pub custom_types: IndexMap<String, CustomTypeConfig>,
+}
+
+#[derive(Debug, Clone, Node, Default, Deserialize)]
+#[serde(default)]
+pub struct CustomTypeConfig {
+    pub type_name: Option<String>,
+    pub imports: Option<Vec<String>>,
+    pub into_custom: String, // b/w compat alias for lift
+    pub lift: String,
+    pub from_custom: String, // b/w compat alias for lower
+    pub lower: String,
+}
+
+impl RubyConfig {
+    pub fn from_uniffi_toml(toml: &str) -> Result<Self> {
+        let root: Config = toml::from_str(toml)?;
+        Ok(root.bindings.ruby)
+    }
+
+    /// Ruby expression for the path of the library to load, if one was configured.
+    ///
+    /// This is rendered as-is, so it can be something like `File.join(__dir__, 'libfoo.so')`.
+    pub fn cdylib_path(&self) -> Option<String> {
+        self.cdylib_path.clone()
+    }
+}
+
+// functions replace literal "{}" in strings with a specified value.
+impl CustomTypeConfig {
+    /// Produce a Ruby expression that lifts a raw-builtin value `nm` into the custom type.
+    pub fn lift(&self, name: &str) -> String {
+        let converter = if self.lift.is_empty() {
+            &self.into_custom
+        } else {
+            &self.lift
+        };
+        converter.replace("{}", name)
+    }
+
+    /// Produce a Ruby expression that lowers a value `nm` to its raw builtin.
+    pub fn lower(&self, name: &str) -> String {
+        let converter = if self.lower.is_empty() {
+            &self.from_custom
+        } else {
+            &self.lower
+        };
+        converter.replace("{}", name)
+    }
+
+    /// True if this config actually specifies conversion expressions.
+    pub fn has_conversion(&self) -> bool {
+        !self.lift.is_empty() || !self.into_custom.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cdylib_path() {
+        let config = RubyConfig::default();
+        assert_eq!(config.cdylib_path(), None);
+
+        let config = RubyConfig::from_uniffi_toml(
+            r#"
+            [bindings.ruby]
+            cdylib_path = "/foo/bar"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.cdylib_path().as_deref(), Some("/foo/bar"));
+    }
+}
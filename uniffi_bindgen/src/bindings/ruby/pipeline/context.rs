@@ -0,0 +1,112 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashMap;
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub cdylib: Option<String>,
+    pub current_config: Option<RubyConfig>,
+    pub module_namespace: Option<String>,
+    /// Maps FFI function type names to the VTable struct fields that use them
+    pub vtable_field_names: HashMap<String, String>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        self.cdylib = root.cdylib.clone();
+        Ok(())
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => RubyConfig::from_uniffi_toml(toml)?,
+            None => RubyConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        self.vtable_field_names.clear();
+        for def in namespace.ffi_definitions.iter() {
+            if let general::FfiDefinition::Struct(st) = def {
+                for field in st.fields.iter() {
+                    if let FfiType::Function(name) = &field.ty {
+                        self.vtable_field_names
+                            .insert(name.0.clone(), field.name.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&RubyConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    /// Name of the library for `ffi_lib` to load
+    pub fn cdylib(&self) -> Result<String> {
+        Ok(match &self.config()?.cdylib_name {
+            Some(cdylib) => cdylib.clone(),
+            None => match &self.cdylib {
+                Some(cdylib) => cdylib.clone(),
+                None => format!("uniffi_{}", self.module_namespace()?),
+            },
+        })
+    }
+
+    /// Config for a custom type
+    ///
+    /// Configs are keyed by the renamed type name, but fall back to the original name for configs
+    /// written against the Rust name.
+    pub fn custom_type_config(
+        &self,
+        name: &str,
+        orig_name: &str,
+    ) -> Result<Option<&CustomTypeConfig>> {
+        let custom_types = &self.config()?.custom_types;
+        Ok(custom_types
+            .get(name)
+            .or_else(|| custom_types.get(orig_name)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn context(cdylib: Option<&str>, toml: &str) -> Context {
+        Context {
+            cdylib: cdylib.map(str::to_string),
+            current_config: Some(RubyConfig::from_uniffi_toml(toml).unwrap()),
+            module_namespace: Some("todolist".to_string()),
+            ..Context::default()
+        }
+    }
+
+    #[test]
+    fn test_cdylib_name() {
+        assert_eq!(context(None, "").cdylib().unwrap(), "uniffi_todolist");
+        assert_eq!(
+            context(Some("todolist_lib"), "").cdylib().unwrap(),
+            "todolist_lib"
+        );
+        let toml = r#"
+            [bindings.ruby]
+            cdylib_name = "todolist"
+        "#;
+        assert_eq!(
+            context(Some("todolist_lib"), toml).cdylib().unwrap(),
+            "todolist"
+        );
+    }
+}
@@ -0,0 +1,99 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Render a default value for a field/argument
+pub fn render_default(default: &general::DefaultValue) -> Result<String> {
+    match default {
+        general::DefaultValue::Default(type_node) => render_zero_value(&type_node.ty),
+        general::DefaultValue::Literal(literal) => render_literal(literal),
+    }
+}
+
+/// Render the zero value for a type, used for `#[uniffi(default)]`
+fn render_zero_value(ty: &Type) -> Result<String> {
+    Ok(match ty {
+        Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::Int32
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64
+        | Type::Int128
+        | Type::UInt128 => "0".to_string(),
+        Type::Float32 | Type::Float64 => "0.0".to_string(),
+        Type::Boolean => "false".to_string(),
+        Type::Char => "\"\\0\"".to_string(),
+        Type::String => "\"\"".to_string(),
+        Type::Optional { .. } => "nil".to_string(),
+        Type::Sequence { .. } => "[]".to_string(),
+        Type::Bytes => "\"\".b".to_string(),
+        Type::Map { .. } => "{}".to_string(),
+        Type::Set { .. } => "Set.new".to_string(),
+        // Named types with no-arg constructors
+        Type::Record { name, .. } | Type::Interface { name, .. } => {
+            format!("{}.new", names::class_name(name))
+        }
+        Type::Box { inner_type } => render_zero_value(inner_type)?,
+        // Custom types delegate to their underlying builtin
+        Type::Custom { builtin, .. } => render_zero_value(builtin)?,
+        _ => bail!("No zero value for type {ty:?}"),
+    })
+}
+
+fn render_literal(literal: &general::Literal) -> Result<String> {
+    // https://docs.ruby-lang.org/en/2.0.0/syntax/literals_rdoc.html
+    fn int_str(
+        i: impl std::fmt::Display + std::fmt::Octal + std::fmt::LowerHex,
+        radix: &Radix,
+    ) -> String {
+        match radix {
+            Radix::Octal => format!("0o{i:o}"),
+            Radix::Decimal => format!("{i}"),
+            Radix::Hexadecimal => format!("{i:#x}"),
+        }
+    }
+
+    Ok(match literal {
+        general::Literal::Boolean(v) => format!("{v}"),
+        // use the double-quote form to match with the other languages, and quote escapes.
+        general::Literal::String(s) => format!("\"{s}\""),
+        general::Literal::Char(c) => format!("\"\\u{{{:X}}}\"", u32::from(*c)),
+        general::Literal::None => "nil".to_string(),
+        general::Literal::Some { inner } => render_default(inner)?,
+        general::Literal::EmptySequence => "[]".to_string(),
+        general::Literal::EmptyMap => "{}".to_string(),
+        general::Literal::EmptySet => "Set.new".to_string(),
+        general::Literal::Enum(variant, type_node) => match &type_node.ty {
+            Type::Enum { name, .. } => format!(
+                "{}::{}",
+                names::class_name(name),
+                names::const_name(variant)
+            ),
+            ty => bail!("Unexpected type in enum literal: {ty:?}"),
+        },
+        general::Literal::Int(i, radix, _) => int_str(i, radix),
+        general::Literal::UInt(i, radix, _) => int_str(i, radix),
+        general::Literal::Float(string, _) => string.clone(),
+        general::Literal::Record { fields, ty } => match &ty.ty {
+            Type::Record { name, .. } => {
+                let fields = fields
+                    .iter()
+                    .map(|f| {
+                        Ok(format!(
+                            "{}: {}",
+                            names::var_name(&f.name),
+                            render_literal(&f.value)?
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                format!("{}.new({})", names::class_name(name), fields.join(", "))
+            }
+            ty => bail!("Unexpected type in record literal: {ty:?}"),
+        },
+    })
+}
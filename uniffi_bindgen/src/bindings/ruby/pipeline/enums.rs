@@ -0,0 +1,41 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_variants(
+    variants: Vec<general::Variant>,
+    is_error: bool,
+    context: &Context,
+) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            Ok(Variant {
+                // Error variants are exception classes, other variants are constants or nested
+                // classes named like constants.
+                name: if is_error {
+                    names::class_name(&v.name)
+                } else {
+                    names::const_name(&v.name)
+                },
+                predicate_name: format!("{}?", names::var_name(&v.name)),
+                discr: discr_literal(&v.discr)?,
+                has_named_fields: v.fields.first().is_some_and(|f| !f.name.is_empty()),
+                fields_kind: v.fields_kind,
+                fields: v.fields.map_node(context)?,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Render a discriminant literal
+fn discr_literal(discr: &general::Literal) -> Result<String> {
+    match discr {
+        general::Literal::UInt(v, _, _) => Ok(v.to_string()),
+        general::Literal::Int(v, _, _) => Ok(v.to_string()),
+        _ => bail!("Only integer discriminants are supported: {discr:?}"),
+    }
+}
@@ -0,0 +1,102 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Get the Ruby-FFI type for an FFI type
+pub fn ffi_type_name(ffi_type: &FfiType) -> String {
+    match ffi_type {
+        FfiType::Int8 => ":int8".to_string(),
+        FfiType::UInt8 => ":uint8".to_string(),
+        FfiType::Int16 => ":int16".to_string(),
+        FfiType::UInt16 => ":uint16".to_string(),
+        FfiType::Int32 => ":int32".to_string(),
+        FfiType::UInt32 => ":uint32".to_string(),
+        FfiType::Int64 => ":int64".to_string(),
+        FfiType::UInt64 => ":uint64".to_string(),
+        FfiType::Float32 => ":float".to_string(),
+        FfiType::Float64 => ":double".to_string(),
+        FfiType::Handle(_) => ":uint64".to_string(),
+        FfiType::RustBuffer(_) => "RustBuffer.by_value".to_string(),
+        FfiType::RustCallStatus => "RustCallStatus".to_string(),
+        FfiType::ForeignBytes => "ForeignBytes".to_string(),
+        FfiType::Function(name) => format!(":{}", name.0),
+        FfiType::Struct(name) => format!("{}.by_value", name.0),
+        FfiType::Reference(inner) | FfiType::MutReference(inner) => match &**inner {
+            FfiType::Struct(name) => format!("{}.by_ref", name.0),
+            _ => ":pointer".to_string(),
+        },
+        FfiType::VoidPointer => ":pointer".to_string(),
+    }
+}
+
+/// Get the `FFI::Pointer` method that writes a value returned by a callback method
+///
+/// Returns `None` for `RustBuffer` returns, which are copied field-by-field instead.
+pub fn ffi_write_method(return_type: &general::ReturnType) -> Result<Option<String>> {
+    let Some(ty) = &return_type.ty else {
+        return Ok(None);
+    };
+    Ok(Some(match &ty.ffi_type {
+        FfiType::Int8 => "write_int8".to_string(),
+        FfiType::UInt8 => "write_uint8".to_string(),
+        FfiType::Int16 => "write_int16".to_string(),
+        FfiType::UInt16 => "write_uint16".to_string(),
+        FfiType::Int32 => "write_int32".to_string(),
+        FfiType::UInt32 => "write_uint32".to_string(),
+        FfiType::Int64 => "write_int64".to_string(),
+        FfiType::UInt64 => "write_uint64".to_string(),
+        FfiType::Float32 => "write_float".to_string(),
+        FfiType::Float64 => "write_double".to_string(),
+        FfiType::Handle(_) => "write_uint64".to_string(),
+        FfiType::RustBuffer(_) => return Ok(None),
+        ffi_type => bail!("Unsupported FFI return type for callback: {ffi_type:?}"),
+    }))
+}
+
+/// Default values for FFI types
+///
+/// Used to set a default return value when an async callback method returns an error.
+pub fn ffi_default_value(return_type: &general::ReturnType) -> Result<Option<String>> {
+    let Some(ty) = &return_type.ty else {
+        return Ok(None);
+    };
+    Ok(Some(match &ty.ffi_type {
+        FfiType::UInt8
+        | FfiType::Int8
+        | FfiType::UInt16
+        | FfiType::Int16
+        | FfiType::UInt32
+        | FfiType::Int32
+        | FfiType::UInt64
+        | FfiType::Int64
+        | FfiType::Handle(_) => "0".to_string(),
+        FfiType::Float32 | FfiType::Float64 => "0.0".to_string(),
+        FfiType::RustBuffer(_) => "RustBuffer.new".to_string(),
+        ffi_type => bail!("Unsupported FFI return type for callback: {ffi_type:?}"),
+    }))
+}
+
+/// Get the name of the struct for a VTable
+pub fn vtable_struct_name(vtable: &general::VTable) -> Result<String> {
+    match &vtable.struct_type {
+        FfiType::Struct(name) => Ok(name.0.clone()),
+        ffi_type => bail!("Invalid VTable struct type: {ffi_type:?}"),
+    }
+}
+
+/// Lookup the name of the VTable struct field for a VTable method
+pub fn vtable_method_field_name(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<String> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context
+        .vtable_field_names
+        .get(&name.0)
+        .cloned()
+        .ok_or_else(|| anyhow!("VTable field not found: {}", name.0))
+}
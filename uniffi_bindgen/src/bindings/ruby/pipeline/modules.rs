@@ -0,0 +1,89 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+use super::*;
+
+pub fn map_namespace(namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?.clone();
+
+    let has_stream_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Stream(_))
+    });
+    let has_iterator_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Iterator(_))
+    });
+    let has_closure_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Closure(_))
+    });
+    let has_async_fns = has_stream_types
+        || namespace.has_descendant(|callable: &general::Callable| callable.is_async());
+
+    Ok(Module {
+        class_name: names::class_name(&namespace.name),
+        cdylib_name: context.cdylib()?,
+        cdylib_path: config.cdylib_path(),
+        name: namespace.name.clone(),
+        crate_name: namespace.crate_name.clone(),
+        has_async_fns,
+        has_stream_types,
+        has_iterator_types,
+        has_closure_types,
+        has_callback_definitions: namespace.has_descendant(|_: &general::CallbackInterface| true)
+            || namespace
+                .has_descendant(|int: &general::Interface| int.imp.has_callback_interface()),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        docstring: namespace.docstring,
+        functions: namespace.functions.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        ffi_definitions: dedup_ffi_definitions(namespace.ffi_definitions).map_node(context)?,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+    })
+}
+
+fn has_type_definition(
+    namespace: &general::Namespace,
+    f: impl Fn(&general::TypeDefinition) -> bool,
+) -> bool {
+    namespace.type_definitions.iter().any(f)
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+/// Remove FFI definitions with duplicate names
+///
+/// Several FFI types share the same `rust_future` functions, but `attach_function` only needs to
+/// be called once for each.
+fn dedup_ffi_definitions(
+    ffi_definitions: IndexSet<general::FfiDefinition>,
+) -> IndexSet<general::FfiDefinition> {
+    let mut seen = HashSet::new();
+    ffi_definitions
+        .into_iter()
+        .filter(|def| {
+            let name = match def {
+                general::FfiDefinition::RustFunction(func) => func.name.0.clone(),
+                general::FfiDefinition::FunctionType(func_type) => func_type.name.0.clone(),
+                general::FfiDefinition::Struct(st) => st.name.0.clone(),
+            };
+            seen.insert(name)
+        })
+        .collect()
+}
@@ -0,0 +1,64 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+
+const RESERVED_WORDS: &[&str] = &[
+    "alias", "and", "BEGIN", "begin", "break", "case", "class", "def", "defined?", "do", "else",
+    "elsif", "END", "end", "ensure", "false", "for", "if", "module", "next", "nil", "not", "or",
+    "redo", "rescue", "retry", "return", "self", "super", "then", "true", "undef", "unless",
+    "until", "when", "while", "yield", "__FILE__", "__LINE__",
+];
+
+pub fn is_reserved_word(word: &str) -> bool {
+    RESERVED_WORDS.contains(&word)
+}
+
+/// Get the idiomatic Ruby rendering of a class name (for enums, records, errors, etc).
+pub fn class_name(nm: &str) -> String {
+    nm.to_upper_camel_case()
+}
+
+/// Get the idiomatic Ruby rendering of a function name.
+pub fn fn_name(nm: &str) -> String {
+    nm.to_snake_case()
+}
+
+/// Get the idiomatic Ruby rendering of a variable name.
+///
+/// Reserved words get a `_` prefix.
+pub fn var_name(nm: &str) -> String {
+    let snake = nm.to_snake_case();
+    if is_reserved_word(&snake) {
+        format!("_{snake}")
+    } else {
+        snake
+    }
+}
+
+/// Get the idiomatic Ruby rendering of a constant name.
+///
+/// This is also used for the variants of non-error enums.
+pub fn const_name(nm: &str) -> String {
+    nm.to_shouty_snake_case()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reserved_words() {
+        assert!(is_reserved_word("end"));
+        assert!(!is_reserved_word("ruby"));
+        assert_eq!(var_name("end"), "_end");
+        assert_eq!(var_name("theEnd"), "the_end");
+    }
+
+    #[test]
+    fn test_class_name() {
+        assert_eq!(class_name("Example"), "Example");
+        assert_eq!(class_name("my_example"), "MyExample");
+    }
+}
@@ -0,0 +1,626 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::pipeline::general;
+
+use super::*;
+
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(general::FfiFunctionTypeName);
+use_prev_node!(general::FfiStructName);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(from(namespaces))]
+    pub modules: IndexMap<String, Module>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "rb", escape = "none", path = "wrapper.rb")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    /// Namespace name, as a Ruby module name
+    pub class_name: String,
+    pub crate_name: String,
+    /// Name of the library for `ffi_lib` to load
+    pub cdylib_name: String,
+    /// Ruby expression for the library path, this overrides `cdylib_name`
+    pub cdylib_path: Option<String>,
+    pub has_async_fns: bool,
+    pub has_stream_types: bool,
+    pub has_iterator_types: bool,
+    pub has_closure_types: bool,
+    pub has_callback_definitions: bool,
+    pub has_async_callback_method: bool,
+    pub docstring: Option<String>,
+    pub functions: Vec<Function>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub ffi_rustbuffer_alloc: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_rustbuffer_reserve: RustFfiFunctionName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Array(ArrayType),
+    Map(MapType),
+    Set(SetType),
+    Tuple(TupleType),
+    Stream(StreamType),
+    Iterator(IteratorType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::fn_name(&self.name))]
+    pub name: String,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method { self_type: TypeNode },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    VTableMethod {
+        self_type: TypeNode,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    // FFI types for async Rust functions
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    // FFI types for async foreign functions
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    pub ffi_foreign_future_result: FfiStructName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ReturnType))]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ThrowsType))]
+#[map_node(types::map_throws_type)]
+pub struct ThrowsType {
+    pub ty: Option<TypeNode>,
+    /// Error class that's raised for this type
+    ///
+    /// `None` if the function doesn't throw or throws a type that can't be raised in Ruby.
+    pub error: Option<ErrorClass>,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct ErrorClass {
+    /// Class for the errors, this is passed to `rust_call_with_error`
+    pub name: String,
+    /// Expression that lowers a raised error, `e`, when returning it from a callback
+    pub lower_expr: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    #[map_node(match &self.default {
+        Some(default) => Some(default::render_default(default)?),
+        None => None,
+    })]
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub by_ref: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(default::render_default(&general::DefaultValue::Literal(self.value.clone()))?)]
+    pub value: String,
+    #[map_node(names::const_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Field))]
+pub struct Field {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    #[map_node(match &self.default {
+        Some(default) => Some(default::render_default(default)?),
+        None => None,
+    })]
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+pub struct Enum {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    #[map_node(enums::map_variants(self.variants, self.self_type.is_used_as_error, context)?)]
+    pub variants: Vec<Variant>,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    pub discr_type: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    /// Name of the nested class for the variant, or the constant for flat enums
+    pub name: String,
+    /// Name of the `variant?` method that checks for this variant
+    pub predicate_name: String,
+    /// The discriminant, rendered as a Ruby literal
+    pub discr: String,
+    /// Are the fields passed as keyword arguments?
+    ///
+    /// Variants with unnamed fields store them in a `values` array instead.
+    pub has_named_fields: bool,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub constructors: Vec<Constructor>,
+    pub docstring: Option<String>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+impl Interface {
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn alternate_constructors(&self) -> Vec<&Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+            .collect()
+    }
+
+    pub fn has_callback_interface(&self) -> bool {
+        self.imp.has_callback_interface()
+    }
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    #[map_node(ffi_types::vtable_struct_name(&self)?)]
+    pub struct_name: String,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// Name of the VTable struct field for this method
+    #[map_node(ffi_types::vtable_method_field_name(&self, context)?)]
+    pub ffi_field_name: String,
+    /// Name of the constant that stores the callback `Proc`
+    #[map_node(format!("{}_CALLBACK", names::const_name(&self.callable.name)))]
+    pub callback_name: String,
+    /// `FFI::Pointer` method that writes the lowered return value
+    ///
+    /// `None` for methods without a return value or that return a `RustBuffer`.
+    #[map_node(ffi_types::ffi_write_method(&self.callable.return_type)?)]
+    pub ffi_write_method: Option<String>,
+    /// Default value to return when an async method fails
+    #[map_node(ffi_types::ffi_default_value(&self.callable.return_type)?)]
+    pub ffi_default_value: Option<String>,
+    pub callable: Callable,
+    pub ffi_type: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(context.custom_type_config(&self.name, &self.orig_name)?.cloned())]
+    pub config: Option<CustomTypeConfig>,
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::IteratorType))]
+pub struct IteratorType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: Option<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub namespace: String,
+    /// Config for external custom types
+    ///
+    /// The type itself is defined by the other module, but this one still needs to `require`
+    /// any imports that the config lists.
+    #[map_node(match &self.self_type.ty {
+        Type::Custom { name, orig_name, .. } => context.custom_type_config(name, orig_name)?.cloned(),
+        _ => None,
+    })]
+    pub custom_config: Option<CustomTypeConfig>,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    #[map_node(types::canonical_name(&self.ty))]
+    pub canonical_name: String,
+    /// Expression that lowers a value, see `TypeNode::lower`
+    #[map_node(types::lower(types::NAME_PLACEHOLDER, &self.ty, context)?)]
+    pub lower_expr: String,
+    /// Expression that lifts a value, see `TypeNode::lift`
+    #[map_node(types::lift(types::NAME_PLACEHOLDER, &self.ty, context)?)]
+    pub lift_expr: String,
+    /// Expression that coerces a value, see `TypeNode::coerce`
+    #[map_node(types::coerce(types::NAME_PLACEHOLDER, &self.ty, context)?)]
+    pub coerce_expr: String,
+    /// Statement that checks a value before lowering, see `TypeNode::check_lower`
+    #[map_node(types::check_lower(types::NAME_PLACEHOLDER, &self.ty, context)?)]
+    pub check_lower_expr: String,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// Ruby-FFI type
+    #[map_node(ffi_types::ffi_type_name(&self))]
+    pub type_name: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    pub name: RustFfiFunctionName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    pub name: FfiStructName,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+
+    /// Type of `self` for methods
+    pub fn self_type(&self) -> Option<&TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type } | CallableKind::VTableMethod { self_type, .. } => {
+                Some(self_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Expression that lowers `self` for methods on records and enums
+    pub fn lower_self(&self) -> String {
+        self.self_type()
+            .map(|self_type| self_type.lower("self"))
+            .unwrap_or_default()
+    }
+}
+
+impl ReturnType {
+    /// Expression that lifts the returned value, `nm`
+    ///
+    /// This is `nil` for callables without a return type.
+    pub fn lift(&self, nm: &str) -> String {
+        match &self.ty {
+            Some(ty) => ty.lift(nm),
+            None => "nil".to_string(),
+        }
+    }
+}
+
+impl TypeNode {
+    /// Expression that lowers `nm` so that it can be passed across the FFI
+    pub fn lower(&self, nm: &str) -> String {
+        self.lower_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+
+    /// Expression that lifts `nm` after it was returned from the FFI
+    pub fn lift(&self, nm: &str) -> String {
+        self.lift_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+
+    /// Expression that coerces a user-supplied `nm` into the expected type
+    pub fn coerce(&self, nm: &str) -> String {
+        self.coerce_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+
+    /// Statement that raises an exception if `nm` can't be lowered
+    pub fn check_lower(&self, nm: &str) -> String {
+        self.check_lower_expr.replace(types::NAME_PLACEHOLDER, nm)
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+}
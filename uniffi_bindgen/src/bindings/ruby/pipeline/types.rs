@@ -0,0 +1,441 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Ruby expressions for converting values
+//!
+//! Ruby doesn't have FFI converter classes like the other languages.  Instead, values are
+//! converted with inline expressions, which call the `RustBuffer` helpers for types that are
+//! serialized.  These are generated with the `NAME_PLACEHOLDER` in place of the value and
+//! `TypeNode::lower()`, etc. fill in the actual value when rendering.
+
+use heck::ToUpperCamelCase;
+
+use super::*;
+
+/// Placeholder for the value being converted in the expressions stored on `TypeNode`
+pub const NAME_PLACEHOLDER: &str = "{nm}";
+
+/// Get the canonical, unique-within-this-component name for a type.
+///
+/// This is used to name the helper methods for each type, for example
+/// `RustBuffer.alloc_from_OptionalSequenceTypeExample` or `RustBufferStream#read_u32`.
+pub fn canonical_name(ty: &Type) -> String {
+    match ty {
+        // Builtin primitive types, with plain old names.
+        Type::Int8 => "i8".into(),
+        Type::UInt8 => "u8".into(),
+        Type::Int16 => "i16".into(),
+        Type::UInt16 => "u16".into(),
+        Type::Int32 => "i32".into(),
+        Type::UInt32 => "u32".into(),
+        Type::Int64 => "i64".into(),
+        Type::UInt64 => "u64".into(),
+        Type::Int128 => "i128".into(),
+        Type::UInt128 => "u128".into(),
+        Type::Float32 => "f32".into(),
+        Type::Float64 => "f64".into(),
+        Type::String => "string".into(),
+        Type::Bytes => "bytes".into(),
+        Type::Boolean => "bool".into(),
+        Type::Char => "char".into(),
+        // API defined types.
+        // We add a prefix to the name to guard against pathological cases like a record named
+        // `SequenceRecord` interfering with `sequence<Record>`.
+        Type::Interface { name, .. }
+        | Type::Enum { name, .. }
+        | Type::Record { name, .. }
+        | Type::Custom { name, .. } => format!("Type{name}"),
+        Type::CallbackInterface { name, .. } => format!("CallbackInterface{name}"),
+        Type::Timestamp => "Timestamp".into(),
+        Type::Duration => "Duration".into(),
+        // Recursive types.
+        // These add a prefix to the name of the underlying type.
+        Type::Optional { inner_type } => format!("Optional{}", canonical_name(inner_type)),
+        Type::Sequence { inner_type } => format!("Sequence{}", canonical_name(inner_type)),
+        Type::Array { inner_type, len } => format!("Array{len}{}", canonical_name(inner_type)),
+        // Ruby's `Hash` and `Set` preserve insertion order, so ordered collections use the same
+        // classes.  They still need their own names, since they're distinct types on the Rust side.
+        Type::Set {
+            inner_type,
+            ordered,
+        } => format!(
+            "{}Set{}",
+            if *ordered { "Ordered" } else { "" },
+            canonical_name(inner_type)
+        ),
+        Type::Map {
+            key_type,
+            value_type,
+            ordered,
+        } => format!(
+            "{}Map{}{}",
+            if *ordered { "Ordered" } else { "" },
+            canonical_name(key_type).to_upper_camel_case(),
+            canonical_name(value_type).to_upper_camel_case()
+        ),
+        Type::Tuple { inner_types } => format!(
+            "Tuple{}{}",
+            inner_types.len(),
+            inner_types
+                .iter()
+                .map(|t| canonical_name(t).to_upper_camel_case())
+                .collect::<String>()
+        ),
+        Type::Stream { item_type } => format!("Stream{}", canonical_name(item_type)),
+        Type::Iterator { item_type } => format!("Iterator{}", canonical_name(item_type)),
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "Closure{}{}To{}",
+            argument_types.len(),
+            argument_types
+                .iter()
+                .map(|t| canonical_name(t).to_upper_camel_case())
+                .collect::<String>(),
+            return_type
+                .as_ref()
+                .map(|t| canonical_name(t).to_upper_camel_case())
+                .unwrap_or_else(|| "Unit".to_string())
+        ),
+        Type::Box { inner_type } => canonical_name(inner_type),
+    }
+}
+
+fn custom_type_config<'a>(ty: &Type, context: &'a Context) -> Result<Option<&'a CustomTypeConfig>> {
+    match ty {
+        Type::Custom {
+            name, orig_name, ..
+        } => context.custom_type_config(name, orig_name),
+        _ => Ok(None),
+    }
+}
+
+/// Expression that coerces a user-supplied value into the type that's expected, raising an
+/// exception if that's not possible.
+pub fn coerce(nm: &str, ty: &Type, context: &Context) -> Result<String> {
+    let ns = names::class_name(context.module_namespace()?);
+    Ok(match ty {
+        Type::Int8 => format!("::{ns}::uniffi_in_range({nm}, \"i8\", -2**7, 2**7)"),
+        Type::Int16 => format!("::{ns}::uniffi_in_range({nm}, \"i16\", -2**15, 2**15)"),
+        Type::Int32 => format!("::{ns}::uniffi_in_range({nm}, \"i32\", -2**31, 2**31)"),
+        Type::Int64 => format!("::{ns}::uniffi_in_range({nm}, \"i64\", -2**63, 2**63)"),
+        Type::UInt8 => format!("::{ns}::uniffi_in_range({nm}, \"u8\", 0, 2**8)"),
+        Type::UInt16 => format!("::{ns}::uniffi_in_range({nm}, \"u16\", 0, 2**16)"),
+        Type::UInt32 => format!("::{ns}::uniffi_in_range({nm}, \"u32\", 0, 2**32)"),
+        Type::UInt64 => format!("::{ns}::uniffi_in_range({nm}, \"u64\", 0, 2**64)"),
+        Type::Int128 => format!("::{ns}::uniffi_in_range({nm}, \"i128\", -2**127, 2**127)"),
+        Type::UInt128 => format!("::{ns}::uniffi_in_range({nm}, \"u128\", 0, 2**128)"),
+        Type::Float32
+        | Type::Float64
+        | Type::Interface { .. }
+        | Type::Enum { .. }
+        | Type::Record { .. }
+        | Type::Timestamp
+        | Type::Duration
+        | Type::CallbackInterface { .. }
+        | Type::Stream { .. }
+        | Type::Iterator { .. }
+        | Type::Closure { .. } => nm.to_string(),
+        Type::Boolean => format!("{nm} ? true : false"),
+        Type::Char => format!("::{ns}::uniffi_char({nm})"),
+        Type::String => format!("::{ns}::uniffi_utf8({nm})"),
+        Type::Bytes => format!("::{ns}::uniffi_bytes({nm})"),
+        Type::Optional { inner_type } => {
+            format!("({nm} ? {} : nil)", coerce(nm, inner_type, context)?)
+        }
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => {
+            let coerce_code = coerce("v", inner_type, context)?;
+            if coerce_code == "v" {
+                nm.to_string()
+            } else {
+                format!("{nm}.map {{ |v| {coerce_code} }}")
+            }
+        }
+        Type::Set { inner_type, .. } => {
+            let coerce_code = coerce("v", inner_type, context)?;
+            if coerce_code == "v" {
+                nm.to_string()
+            } else {
+                format!("{nm}.map {{ |v| {coerce_code} }}.to_set")
+            }
+        }
+        Type::Map {
+            key_type,
+            value_type,
+            ..
+        } => {
+            let k_coerce_code = coerce("k", key_type, context)?;
+            let v_coerce_code = coerce("v", value_type, context)?;
+
+            if k_coerce_code == "k" && v_coerce_code == "v" {
+                nm.to_string()
+            } else {
+                format!(
+                    "{nm}.each.with_object({{}}) {{ |(k, v), res| res[{k_coerce_code}] = {v_coerce_code} }}"
+                )
+            }
+        }
+        Type::Tuple { inner_types } => {
+            let coerce_codes = inner_types
+                .iter()
+                .enumerate()
+                .map(|(i, t)| coerce(&format!("{nm}[{i}]"), t, context))
+                .collect::<Result<Vec<_>>>()?;
+            if coerce_codes
+                .iter()
+                .enumerate()
+                .all(|(i, code)| *code == format!("{nm}[{i}]"))
+            {
+                nm.to_string()
+            } else {
+                format!("[{}]", coerce_codes.join(", "))
+            }
+        }
+        Type::Box { inner_type } => coerce(nm, inner_type, context)?,
+        Type::Custom { builtin, .. } => {
+            // For config-backed custom types, the user passes a custom-typed values;
+            // skip builtin coercion (the lower expression handles conversion).
+            if custom_type_config(ty, context)?.is_some() {
+                nm.to_string()
+            } else {
+                coerce(nm, builtin, context)?
+            }
+        }
+    })
+}
+
+/// Statement that checks if a value can be lowered, raising an exception if it can't.
+///
+/// This is empty for types that don't need a check.
+pub fn check_lower(nm: &str, ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Interface { name, .. } => {
+            format!("({}.uniffi_check_lower {nm})", names::class_name(name))
+        }
+        Type::Enum { .. }
+        | Type::Record { .. }
+        | Type::Optional { .. }
+        | Type::Sequence { .. }
+        | Type::Array { .. }
+        | Type::Set { .. }
+        | Type::Tuple { .. }
+        | Type::Map { .. } => {
+            format!("RustBuffer.check_lower_{}({nm})", canonical_name(ty))
+        }
+        Type::Box { inner_type } => check_lower(nm, inner_type, context)?,
+        Type::Custom { .. } => match custom_type_config(ty, context)? {
+            Some(CustomTypeConfig {
+                type_name: Some(type_name),
+                ..
+            }) => format!(
+                "raise TypeError, \"Expected {type_name}, got #{{{nm}.class}}\" unless {nm}.is_a?({type_name})"
+            ),
+            _ => "".to_string(),
+        },
+        _ => "".to_string(),
+    })
+}
+
+/// Expression that lowers a value so that it can be passed across the FFI
+pub fn lower(nm: &str, ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::Int32
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64
+        | Type::Float32
+        | Type::Float64 => nm.to_string(),
+        Type::Boolean => format!("({nm} ? 1 : 0)"),
+        Type::Char => format!("{nm}.ord"),
+        Type::Interface { name, .. } => {
+            format!("({}.uniffi_lower {nm})", names::class_name(name))
+        }
+        Type::CallbackInterface { .. } => {
+            format!("({}FfiConverter.lower {nm})", canonical_name(ty))
+        }
+        Type::Enum { .. }
+        | Type::Record { .. }
+        | Type::Optional { .. }
+        | Type::Sequence { .. }
+        | Type::Array { .. }
+        | Type::Set { .. }
+        | Type::Tuple { .. }
+        | Type::Int128
+        | Type::UInt128
+        | Type::Timestamp
+        | Type::String
+        | Type::Bytes
+        | Type::Duration
+        | Type::Map { .. } => {
+            format!("RustBuffer.alloc_from_{}({nm})", canonical_name(ty))
+        }
+        // Streams and iterators are only ever returned from Rust
+        Type::Stream { .. } => {
+            "raise(InternalError, \"Streams can't be passed to Rust\")".to_string()
+        }
+        Type::Iterator { .. } => {
+            "raise(InternalError, \"Iterators can't be passed to Rust\")".to_string()
+        }
+        Type::Closure { .. } => {
+            let name = canonical_name(ty);
+            format!(
+                "UniffiRustClosure.lower({nm}, Proc.new {{ |buf| buf.consume_into_{name}_args }}, Proc.new {{ |ret| RustBuffer.alloc_from_{name}_return(ret) }})"
+            )
+        }
+        Type::Box { inner_type } => lower(nm, inner_type, context)?,
+        Type::Custom { builtin, .. } => match custom_type_config(ty, context)? {
+            // Apply the configured `lower` expression, then lower the resulting builtin.
+            Some(config) => lower(&config.lower(nm), builtin, context)?,
+            None => lower(nm, builtin, context)?,
+        },
+    })
+}
+
+/// Expression that lifts a value that was returned from the FFI
+pub fn lift(nm: &str, ty: &Type, context: &Context) -> Result<String> {
+    Ok(match ty {
+        Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::Int32
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64 => format!("{nm}.to_i"),
+        Type::Float32 | Type::Float64 => format!("{nm}.to_f"),
+        Type::Boolean => format!("1 == {nm}"),
+        Type::Char => format!("{nm}.chr(Encoding::UTF_8)"),
+        Type::Interface { name, .. } => {
+            format!("{}.uniffi_lift({nm})", names::class_name(name))
+        }
+        Type::CallbackInterface { .. } => {
+            format!("({}FfiConverter.lift {nm})", canonical_name(ty))
+        }
+        Type::Enum { .. }
+        | Type::Record { .. }
+        | Type::Optional { .. }
+        | Type::Sequence { .. }
+        | Type::Array { .. }
+        | Type::Set { .. }
+        | Type::Tuple { .. }
+        | Type::Int128
+        | Type::UInt128
+        | Type::Timestamp
+        | Type::String
+        | Type::Bytes
+        | Type::Duration
+        | Type::Map { .. } => format!("{nm}.consume_into_{}", canonical_name(ty)),
+        Type::Stream { .. } => format!(
+            "UniffiRustStream.new({nm}, Proc.new {{ |v| v.consume_into_{}_next }})",
+            canonical_name(ty)
+        ),
+        Type::Iterator { .. } => format!(
+            "UniffiRustIterator.new({nm}, Proc.new {{ |v| v.consume_into_{}_chunk }})",
+            canonical_name(ty)
+        ),
+        Type::Closure { argument_types, .. } => {
+            let name = canonical_name(ty);
+            format!(
+                "UniffiRustClosure.lift({nm}, {}, Proc.new {{ |args| RustBuffer.alloc_from_{name}_args(args) }}, Proc.new {{ |buf| buf.consume_into_{name}_return }})",
+                argument_types.len()
+            )
+        }
+        Type::Box { inner_type } => lift(nm, inner_type, context)?,
+        Type::Custom { builtin, .. } => {
+            // First lift the raw builtin value, then apply the configured lift expression.
+            let lifted = lift(nm, builtin, context)?;
+            match custom_type_config(ty, context)? {
+                Some(config) => config.lift(&lifted),
+                None => lifted,
+            }
+        }
+    })
+}
+
+/// Class that's raised for errors of this type
+///
+/// This is the enum or interface class, which is also used for custom types that wrap those.
+/// Returns `None` for other types, these are handled as unexpected errors.
+pub fn error_class(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Enum { name, .. } | Type::Interface { name, .. } => Some(names::class_name(name)),
+        Type::Custom { builtin, .. } => match &**builtin {
+            Type::Enum { name, .. } | Type::Interface { name, .. } => Some(names::class_name(name)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+pub fn map_throws_type(throws_type: general::ThrowsType, context: &Context) -> Result<ThrowsType> {
+    let ty: Option<TypeNode> = throws_type.ty.map_node(context)?;
+    let error = match &ty {
+        Some(ty) => match error_class(&ty.ty) {
+            Some(name) => Some(ErrorClass {
+                name,
+                lower_expr: match &ty.ty {
+                    // Custom errors are lowered as their builtin type
+                    Type::Custom { builtin, .. } => lower("e", builtin, context)?,
+                    _ => ty.lower("e"),
+                },
+            }),
+            None => None,
+        },
+        None => None,
+    };
+    Ok(ThrowsType { ty, error })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_canonical_names() {
+        // Non-exhaustive, but gives a bit of a flavour of what we want.
+        assert_eq!(canonical_name(&Type::UInt8), "u8");
+        assert_eq!(canonical_name(&Type::String), "string");
+        assert_eq!(canonical_name(&Type::Bytes), "bytes");
+        assert_eq!(
+            canonical_name(&Type::Optional {
+                inner_type: Box::new(Type::Sequence {
+                    inner_type: Box::new(Type::Interface {
+                        namespace: "anything".to_string(),
+                        name: "Example".to_string(),
+                        orig_name: "Example".to_string(),
+                        imp: ObjectImpl::Struct,
+                    })
+                })
+            }),
+            "OptionalSequenceTypeExample"
+        );
+
+        let map = Type::Map {
+            key_type: Box::new(Type::UInt32),
+            value_type: Box::new(Type::UInt32),
+            ordered: false,
+        };
+        assert_eq!(canonical_name(&map), "MapU32U32");
+
+        let ordered_map = Type::Map {
+            key_type: Box::new(Type::UInt32),
+            value_type: Box::new(Type::UInt32),
+            ordered: true,
+        };
+        assert_eq!(canonical_name(&ordered_map), "OrderedMapU32U32");
+        assert_eq!(
+            canonical_name(&Type::Enum {
+                namespace: "foo".to_string(),
+                name: "HTMLError".to_string(),
+                orig_name: "HTMLError".to_string(),
+            }),
+            "TypeHTMLError"
+        );
+    }
+}
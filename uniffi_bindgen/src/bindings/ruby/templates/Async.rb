@@ -62,9 +62,9 @@ def self.uniffi_rust_call_async(rust_future, poll_fn, cancel_fn, complete_fn, fr
     end
 
     result = if error_ffi_converter.nil?
-      ::{{ ci.namespace()|class_name_rb }}.rust_call(complete_fn, rust_future)
+      ::{{ class_name }}.rust_call(complete_fn, rust_future)
     else
-      ::{{ ci.namespace()|class_name_rb }}.rust_call_with_error(error_ffi_converter, complete_fn, rust_future)
+      ::{{ class_name }}.rust_call_with_error(error_ffi_converter, complete_fn, rust_future)
     end
 
     lift_func.call(result)
@@ -93,7 +93,64 @@ def self.uniffi_rust_call_async(rust_future, poll_fn, cancel_fn, complete_fn, fr
   end
 end
 
-{%- if ci.has_async_callback_interface_definition() %}
+{%- if has_stream_types %}
+# Enumerable wrapper for streams returned by Rust functions.
+#
+# Each item is fetched by polling the Rust future returned by `rust_stream_next`, the same way as
+# async function calls.  That future returns a serialized `Option`, which `lift_func` converts
+# into either the item wrapped in an array or nil at the end of the stream.
+#
+# The Rust stream is freed once the first iteration finishes, even if it was interrupted, or when
+# this object is garbage collected.
+class UniffiRustStream
+  include Enumerable
+
+  def initialize(handle, lift_func)
+    @state = { handle: handle }
+    @lift_func = lift_func
+    ObjectSpace.define_finalizer(self, self.class.uniffi_define_finalizer(@state))
+  end
+
+  # N.B. the finalizer must not capture a reference to the stream itself, otherwise it will never
+  # be garbage collected.
+  def self.uniffi_define_finalizer(state)
+    Proc.new { |_id| uniffi_free(state) }
+  end
+
+  def self.uniffi_free(state)
+    handle = state.delete(:handle)
+    UniFFILib.ffi_{{ crate_name }}_rust_stream_free(handle) unless handle.nil?
+  end
+
+  def each
+    return enum_for(:each) unless block_given?
+
+    begin
+      loop do
+        handle = @state[:handle]
+        break if handle.nil?
+
+        item = ::{{ class_name }}.uniffi_rust_call_async(
+          UniFFILib.ffi_{{ crate_name }}_rust_stream_next(handle),
+          :ffi_{{ crate_name }}_rust_future_poll_rust_buffer,
+          :ffi_{{ crate_name }}_rust_future_cancel_rust_buffer,
+          :ffi_{{ crate_name }}_rust_future_complete_rust_buffer,
+          :ffi_{{ crate_name }}_rust_future_free_rust_buffer,
+          @lift_func,
+          nil
+        )
+        break if item.nil?
+
+        yield item[0]
+      end
+    ensure
+      self.class.uniffi_free(@state)
+    end
+  end
+end
+
+{% endif %}
+{%- if has_async_callback_method %}
 # Exception raised when a foreign future is canceled.
 class UniffiInternalCancelled < RuntimeError; end
 
@@ -149,10 +206,10 @@ def self.uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callbac
       rescue Exception => e # We have to catch all errors to prevent Rust future from hanging forever.
         next unless once.claim!
 
-        if !error_type.nil? && ::{{ ci.namespace()|class_name_rb }}.uniffi_is_error_type?(e, error_type)
+        if !error_type.nil? && ::{{ class_name }}.uniffi_is_error_type?(e, error_type)
           handle_error.call(UNIFFI_CALLBACK_ERROR, lower_error.call(e))
         else
-          handle_error.call(UNIFFI_CALLBACK_UNEXPECTED_ERROR, {{ "e.inspect"|lower_rb(&Type::String, config) }})
+          handle_error.call(UNIFFI_CALLBACK_UNEXPECTED_ERROR, RustBuffer.alloc_from_string(e.inspect))
         end
         next
       end
@@ -167,7 +224,7 @@ def self.uniffi_trait_interface_call_async(make_call, uniffi_out_dropped_callbac
       # once was already claimed, so only attempt this if we can still claim (e.g. lowering failed
       # before handle_error was called due to short-circuit evaluation).
       begin
-        handle_error.call(UNIFFI_CALLBACK_UNEXPECTED_ERROR, {{ "e.inspect"|lower_rb(&Type::String, config) }})
+        handle_error.call(UNIFFI_CALLBACK_UNEXPECTED_ERROR, RustBuffer.alloc_from_string(e.inspect))
       rescue Exception
         # If even this fails, Rust will hang. Nothing more we can do.
       end
@@ -1,16 +1,16 @@
-{%- let cbi = ci.get_callback_interface_definition(name).unwrap() %}
-{%- let cbi_name = name %}
-{%- let interface_name = cbi.name() %}
-
-class {{ interface_name }}
-  {% for meth in cbi.methods() -%}
-  def {{ meth.name()|fn_name_rb }}(**_args)
+class {{ cbi.name }}
+  {% for meth in cbi.methods -%}
+  def {{ meth.callable.name }}(**_args)
     raise NoMethodError, 'method should be implemented in concrete class'
   end
   {% endfor %}
 end
 
-# The FfiConverter for the {{ name }} callback interface.
-{{ self::canonical_name(cbi.as_type().borrow()) }}FfiConverter = CallbackInterfaceFfiConverter.new
+# The FfiConverter for the {{ cbi.name }} callback interface.
+{{ cbi.self_type.canonical_name }}FfiConverter = CallbackInterfaceFfiConverter.new
 
+# Callback interface vtable implementation for {{ cbi.name }}.
+{%- let vtable = cbi.vtable %}
+{%- let lift_handle = "{}FfiConverter.lift uniffi_handle"|format(cbi.self_type.canonical_name) %}
+{%- let handle_map = "{}FfiConverter.handle_map"|format(cbi.self_type.canonical_name) %}
 {% include "CallbackInterfaceImpl.rb" %}
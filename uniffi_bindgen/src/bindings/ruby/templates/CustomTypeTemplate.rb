@@ -1,10 +1,10 @@
-{%- match config.custom_types.get(name.as_str()) %}
+{%- match custom.config %}
 {%- when None %}
-# Custom type `{{ name }}` - no binding config, backed by builtin `{{ self::canonical_name(builtin) }}`.
+# Custom type `{{ custom.name }}` - no binding config, backed by builtin `{{ custom.builtin.canonical_name }}`.
 # Values crosss the FFI as the builtin type unchanged.
 
 {%- when Some(cfg) %}
-# Custom type `{{ name }}` - binding config supplied, backed by builtin `{{ self::canonical_name(builtin) }}`.
+# Custom type `{{ custom.name }}` - binding config supplied, backed by builtin `{{ custom.builtin.canonical_name }}`.
 {%- if cfg.has_conversion() %}
 #   lift expression: {{ cfg.lift("raw_value") }}
 #   lower expression: {{ cfg.lower("custom_value") }}
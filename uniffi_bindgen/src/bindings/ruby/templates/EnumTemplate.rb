@@ -1,47 +1,52 @@
-{% if e.is_flat() %}
+{% if e.is_flat %}
 
-class {{ e.name()|class_name_rb }}
-  {% for variant in e.variants() -%}
-  {{ variant.name()|enum_name_rb }} = {{ e|variant_discr_literal(loop.index0) }}
+class {{ e.name }}
+  {% for variant in e.variants -%}
+  {{ variant.name }} = {{ variant.discr }}
   {% endfor %}
+
+  {%- let static_methods = e.static_methods %}
+  {%- include "StaticMethodImpls.rb" %}
 end
 
 {% else %}
 
-class {{ e.name()|class_name_rb }}
+class {{ e.name }}
   def initialize
-    raise RuntimeError, '{{ e.name()|class_name_rb }} cannot be instantiated directly'
+    raise RuntimeError, '{{ e.name }} cannot be instantiated directly'
   end
 
-  {%- let methods = e.methods() %}
+  {%- let methods = e.methods %}
   {%- include "MethodImpls.rb" %}
 
+  {%- let static_methods = e.static_methods %}
+  {%- include "StaticMethodImpls.rb" %}
+
   # Each enum variant is a nested class of the enum itself.
-  {% for variant in e.variants() -%}
-  class {{ variant.name()|enum_name_rb }} < {{ e.name()|class_name_rb }}
-    {%- let named_fields = variant.has_fields() && !variant.fields()[0].name().is_empty() %}
+  {% for variant in e.variants -%}
+  class {{ variant.name }} < {{ e.name }}
     {% if variant.has_fields() %}
-    {%- if named_fields %}
-      attr_reader {% for field in variant.fields() %}:{{ field.name()|var_name_rb }}{% if loop.last %}{% else %}, {% endif %}{%- endfor %}
+    {%- if variant.has_named_fields %}
+      attr_reader {% for field in variant.fields %}:{{ field.name }}{% if loop.last %}{% else %}, {% endif %}{%- endfor %}
     {%- else %}
       attr_reader :values
     {%- endif %}
     {% endif %}
-    {%- if named_fields %}
-    def initialize({% for field in variant.fields() %}{{ field.name()|var_name_rb -}}:
-      {%- match field.default_value() %}
-      {%- when Some(default) %} {{ field|field_default_rb }}
+    {%- if variant.has_named_fields %}
+    def initialize({% for field in variant.fields %}{{ field.name -}}:
+      {%- match field.default %}
+      {%- when Some(default) %} {{ default }}
       {%- else %}
       {% endmatch %}
       {%- if loop.last %}{% else %}, {% endif -%}{% endfor %})
-        {%- for field in variant.fields() %}
-        @{{ field.name()|var_name_rb }} = {{ field.name()|var_name_rb }}
+        {%- for field in variant.fields %}
+        @{{ field.name }} = {{ field.name }}
         {%- endfor %}
     end
     {%- else %}
-    def initialize({% for field in variant.fields() %}v{{ loop.index }}{% if loop.last %}{% else %}, {% endif %}{% endfor %})
+    def initialize({% for field in variant.fields %}v{{ loop.index }}{% if loop.last %}{% else %}, {% endif %}{% endfor %})
       {% if variant.has_fields() %}
-        @values = [{% for field in variant.fields() %}v{{ loop.index }}{% if loop.last %}{% else %}, {% endif %}{% endfor %}]
+        @values = [{% for field in variant.fields %}v{{ loop.index }}{% if loop.last %}{% else %}, {% endif %}{% endfor %}]
       {% else %}
       {% endif %}
     end
@@ -51,21 +56,21 @@ class {{ e.name()|class_name_rb }}
     end
     {% endif %}
 
-    {%- let trait_methods = e.uniffi_trait_methods() %}
+    {%- let trait_methods = e.uniffi_trait_methods %}
     {%- if trait_methods.display_fmt.is_none() %}
     def to_s
-      "{{ e.name()|class_name_rb }}::{{ variant.name()|enum_name_rb }}"
+      "{{ e.name }}::{{ variant.name }}"
     end
     {%- endif %}
     {%- include "UniffiTraitImpls.rb" %}
 
     {%- if trait_methods.eq_eq.is_none() %}
     def ==(other)
-      return false unless other.respond_to?(:{{variant.name()|var_name_rb}}?)
-      return false unless other.{{ variant.name()|var_name_rb }}?
-      {%- if named_fields %}
-      {%- for field in variant.fields() %}
-        return false if @{{ field.name()|var_name_rb }} != other.{{ field.name()|var_name_rb }}
+      return false unless other.respond_to?(:{{ variant.predicate_name }})
+      return false unless other.{{ variant.predicate_name }}
+      {%- if variant.has_named_fields %}
+      {%- for field in variant.fields %}
+        return false if @{{ field.name }} != other.{{ field.name }}
       {%- endfor %}
       {%- else %}
       {%- if variant.has_fields() %}
@@ -78,9 +83,9 @@ class {{ e.name()|class_name_rb }}
 
     # For each variant, we have an `NAME?` method for easily checking
     # whether an instance is that variant.
-    {% for variant in e.variants() %}
-    def {{ variant.name()|var_name_rb }}?
-      instance_of? {{ e.name()|class_name_rb }}::{{ variant.name()|enum_name_rb }}
+    {% for variant in e.variants %}
+    def {{ variant.predicate_name }}
+      instance_of? {{ e.name }}::{{ variant.name }}
     end
     {% endfor %}
   end
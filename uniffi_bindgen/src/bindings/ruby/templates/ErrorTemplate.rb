@@ -19,37 +19,37 @@ end
 CALL_SUCCESS = 0
 CALL_ERROR = 1
 CALL_PANIC = 2
-{%- for e in ci.enum_definitions() %}
-{% if ci.is_name_used_as_error(e.name()) %}
-{% if e.is_flat() %}
-class {{ e.name()|class_name_rb }}
-    {%- for variant in e.variants() %}
-    {{ variant.name()|class_name_rb }} = Class.new StandardError
+{%- for type_def in type_definitions %}
+{%- if let TypeDefinition::Enum(e) = type_def %}
+{% if e.self_type.is_used_as_error %}
+{% if e.is_flat %}
+class {{ e.name }}
+    {%- for variant in e.variants %}
+    {{ variant.name }} = Class.new StandardError
     {%- endfor %}
 {% else %}
-module {{ e.name()|class_name_rb }}
-  {%- for variant in e.variants() %}
-  class {{ variant.name()|class_name_rb }} < StandardError
-    {%- let named_fields = variant.has_fields() && !variant.fields()[0].name().is_empty() %}
-    {%- if named_fields %}
-    def initialize({% for field in variant.fields() %}{{ field.name()|var_name_rb }}:{% if !loop.last %}, {% endif %}{% endfor %})
-        {% for field in variant.fields() %}
-        @{{ field.name()|var_name_rb }} = {{ field.name()|var_name_rb }}
+module {{ e.name }}
+  {%- for variant in e.variants %}
+  class {{ variant.name }} < StandardError
+    {%- if variant.has_named_fields %}
+    def initialize({% for field in variant.fields %}{{ field.name }}:{% if !loop.last %}, {% endif %}{% endfor %})
+        {% for field in variant.fields %}
+        @{{ field.name }} = {{ field.name }}
         {% endfor %}
         super()
     end
     {% else %}
-    def initialize({% for field in variant.fields() %}v{{ loop.index }}{% if !loop.last %}, {% endif %}{% endfor %})
+    def initialize({% for field in variant.fields %}v{{ loop.index }}{% if !loop.last %}, {% endif %}{% endfor %})
         {% if variant.has_fields() %}
-        @values = [{% for field in variant.fields() %}v{{ loop.index }}{% if !loop.last %}, {% endif %}{% endfor %}]
+        @values = [{% for field in variant.fields %}v{{ loop.index }}{% if !loop.last %}, {% endif %}{% endfor %}]
         {% endif %}
         super()
     end
     {% endif %}
     {%- if variant.has_fields() %}
-    {%- if named_fields %}
+    {%- if variant.has_named_fields %}
 
-    attr_reader {% for field in variant.fields() %}:{{ field.name()|var_name_rb }}{% if !loop.last %}, {% endif %}{% endfor %}
+    attr_reader {% for field in variant.fields %}:{{ field.name }}{% if !loop.last %}, {% endif %}{% endfor %}
     {%- else %}
 
     attr_reader :values
@@ -61,8 +61,8 @@ module {{ e.name()|class_name_rb }}
     {% endif %}
 
     def to_s
-      {%- if named_fields %}
-        "#{self.class.name}({% for field in variant.fields() %}{{ field.name()|var_name_rb }}=#{@{{ field.name()|var_name_rb }}.inspect}{% if !loop.last %}, {% endif %}{% endfor %})"
+      {%- if variant.has_named_fields %}
+        "#{self.class.name}({% for field in variant.fields %}{{ field.name }}=#{@{{ field.name }}.inspect}{% if !loop.last %}, {% endif %}{% endfor %})"
 
       {%- else %}
       {%- if variant.has_fields() %}
@@ -78,19 +78,23 @@ module {{ e.name()|class_name_rb }}
 {% endif %}
 end
 {% endif %}
+{%- endif %}
 {%- endfor %}
 
 # Map error modules to the RustBuffer method name that reads them
 ERROR_MODULE_TO_READER_METHOD = {
-{% for e in ci.enum_definitions() %}
-{%- if ci.is_name_used_as_error(e.name()) -%}
-  {{ e.name()|class_name_rb }} => :read_{{ self::canonical_name(e.as_type().borrow()) }},
+{% for type_def in type_definitions %}
+{%- match type_def %}
+{%- when TypeDefinition::Enum(e) %}
+{%- if e.self_type.is_used_as_error -%}
+  {{ e.name }} => :read_{{ e.self_type.canonical_name }},
 {% endif %}
-{%- endfor -%}
-{% for obj in ci.object_definitions() %}
-{%- if ci.is_name_used_as_error(obj.name()) -%}
-  '{{ obj.name()|class_name_rb }}' => :read_{{ self::canonical_name(obj.as_type().borrow()) }},
+{%- when TypeDefinition::Interface(obj) %}
+{%- if obj.self_type.is_used_as_error -%}
+  '{{ obj.name }}' => :read_{{ obj.self_type.canonical_name }},
 {% endif %}
+{%- else %}
+{%- endmatch %}
 {%- endfor -%}
 }
 
@@ -142,7 +146,7 @@ def self.rust_call_with_error(error_module, fn_name, *args)
     # with the message.  But if that code panics, then it just sends back
     # an empty buffer.
     if status.error_buf.len > 0
-      raise InternalError, {{ "status.error_buf"|lift_rb(&Type::String, config) }}
+      raise InternalError, status.error_buf.consume_into_string
     else
       raise InternalError, "Rust panic"
     end
@@ -12,6 +12,12 @@ def self.uniffi_utf8(v)
   v
 end
 
+def self.uniffi_char(v)
+  v = uniffi_utf8(v)
+  raise ArgumentError, "char requires a string of length 1, got #{v.length}" unless v.length == 1
+  v
+end
+
 def self.uniffi_bytes(v)
   raise TypeError, "no implicit conversion of #{v} into String" unless v.respond_to?(:to_str)
   v.to_str
@@ -35,7 +41,7 @@ def self.uniffi_trait_interface_call(call_status, make_call, write_return_value,
       lower_error.call e
     else
       call_status[:code] = UNIFFI_CALLBACK_UNEXPECTED_ERROR
-      {{ "e.inspect"|lower_rb(&Type::String, config) }}
+      RustBuffer.alloc_from_string(e.inspect)
     end
 
     error_buf = call_status[:error_buf]
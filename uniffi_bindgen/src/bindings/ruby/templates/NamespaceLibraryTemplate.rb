@@ -3,35 +3,36 @@
 module UniFFILib
   extend FFI::Library
 
-  {% if config.custom_cdylib_path() %}
-  ffi_lib {{ config.cdylib_path() }}
-  {% else %}
-  ffi_lib '{{ config.cdylib_name() }}'
-  {% endif %}
+  {% match cdylib_path %}
+  {%- when Some(cdylib_path) %}
+  ffi_lib {{ cdylib_path }}
+  {% when None %}
+  ffi_lib '{{ cdylib_name }}'
+  {% endmatch %}
 
   # Define FFI callback types and structs (vtables, etc.)
-  {% for def in ci.ffi_definitions() %}
+  {% for def in ffi_definitions %}
   {%- match def -%}
 
-  {%- when FfiDefinition::CallbackFunction(cb_fn) %}
-  callback :{{ cb_fn.name() }},
-    [{%- for arg in cb_fn.arguments() %}{{ arg.type_().borrow()|type_ffi }}, {% endfor -%}
-    {%- if cb_fn.has_rust_call_status_arg() -%}RustCallStatus.by_ref{% endif -%}],
-    {% match cb_fn.return_type() %}{% when Some with (type_) %}{{ type_|type_ffi }}{% when None %}:void{% endmatch %}
+  {%- when FfiDefinition::FunctionType(cb_fn) %}
+  callback :{{ cb_fn.name.0 }},
+    [{%- for arg in cb_fn.arguments %}{{ arg.ty.type_name }}, {% endfor -%}
+    {%- if cb_fn.has_rust_call_status_arg -%}RustCallStatus.by_ref{% endif -%}],
+    {% match cb_fn.return_type.ty %}{% when Some(ty) %}{{ ty.type_name }}{% when None %}:void{% endmatch %}
 
   {%- when FfiDefinition::Struct(ffi_struct) %}
-  class {{ ffi_struct.name() }} < FFI::Struct
+  class {{ ffi_struct.name.0 }} < FFI::Struct
     layout(
-      {%- for field in ffi_struct.fields() %}
-      :{{ field.name() }}, {{ field.type_().borrow()|type_ffi }}{% if !loop.last %},{% endif %}
+      {%- for field in ffi_struct.fields %}
+      :{{ field.name }}, {{ field.ty.type_name }}{% if !loop.last %},{% endif %}
       {%- endfor %}
     )
   end
 
-  {%- when FfiDefinition::Function(func) %}
-  attach_function :{{ func.name() }},
+  {%- when FfiDefinition::RustFunction(func) %}
+  attach_function :{{ func.name.0 }},
     {%- call rb::arg_list_ffi_decl(func) %}{% endcall %},
-    {% match func.return_type() %}{% when Some with (type_) %}{{ type_|type_ffi }}{% when None %}:void{% endmatch %}
+    {% match func.return_type.ty %}{% when Some(ty) %}{{ ty.type_name }}{% when None %}:void{% endmatch %}
   {%- endmatch %}
   {% endfor %}
 end
@@ -1,4 +1,4 @@
-class {{ obj.name()|class_name_rb }}{% if ci.is_name_used_as_error(obj.name()) %} < StandardError{% endif %}
+class {{ obj.name }}{% if obj.self_type.is_used_as_error %} < StandardError{% endif %}
 
   # A private helper for initializing instances of the class from a raw handle,
   # bypassing any initialization logic and ensuring they are GC'd properly.
@@ -14,8 +14,8 @@ class {{ obj.name()|class_name_rb }}{% if ci.is_name_used_as_error(obj.name()) %
   # to the actual instance, only its underlying handle.
   def self.uniffi_define_finalizer_by_handle(handle, object_id)
     Proc.new do |_id|
-      ::{{ ci.namespace()|class_name_rb }}.rust_call(
-        :{{ obj.ffi_object_free().name() }},
+      ::{{ class_name }}.rust_call(
+        :{{ obj.ffi_func_free.0 }},
         handle
       )
     end
@@ -32,16 +32,16 @@ class {{ obj.name()|class_name_rb }}{% if ci.is_name_used_as_error(obj.name()) %
   # For trait interfaces: check that the object is either a Rust-backed instance or
   # responds to the required interface methods.
   def self.uniffi_check_lower(inst)
-    {%- for method in obj.methods() %}
-    if !inst.respond_to?(:{{ method.name()|fn_name_rb }})
-      raise TypeError.new "Expected a {{ obj.name()|class_name_rb }} instance or an object implementing the interface, got #{inst}"
+    {%- for meth in obj.methods %}
+    if !inst.respond_to?(:{{ meth.callable.name }})
+      raise TypeError.new "Expected a {{ obj.name }} instance or an object implementing the interface, got #{inst}"
     end
     {%- endfor %}
   end
 
   def uniffi_clone_handle
-    return ::{{ci.namespace()|class_name_rb }}.rust_call(
-      :{{ obj.ffi_object_clone().name() }},
+    return ::{{ class_name }}.rust_call(
+      :{{ obj.ffi_func_clone.0 }},
       @handle
     )
   end
@@ -70,13 +70,13 @@ class {{ obj.name()|class_name_rb }}{% if ci.is_name_used_as_error(obj.name()) %
   # object in a place where this type is expected, could lead to memory unsafety.
   def self.uniffi_check_lower(inst)
     if !inst.is_a? self
-      raise TypeError.new "Expected a {{ obj.name()|class_name_rb }} instance, got #{inst}"
+      raise TypeError.new "Expected a {{ obj.name }} instance, got #{inst}"
     end
   end
 
   def uniffi_clone_handle()
-    return ::{{ ci.namespace()|class_name_rb }}.rust_call(
-      :{{ obj.ffi_object_clone().name() }},
+    return ::{{ class_name }}.rust_call(
+      :{{ obj.ffi_func_clone.0 }},
       @handle
     )
   end
@@ -90,77 +90,81 @@ class {{ obj.name()|class_name_rb }}{% if ci.is_name_used_as_error(obj.name()) %
   end
   {%- endif %}
 
-  {%- match obj.primary_constructor() %}
-  {%- when Some with (cons) %}
-  {%- if cons.is_async() %}
-  def initialize({% call rb::arg_list_decl(cons) %}{% endcall %})
-    {%- call rb::setup_args_extra_indent(cons) %}{% endcall %}
-    handle = {% call rb::to_ffi_call_async_constructor(cons) %}{% endcall %}
+  {%- if let Some(cons) = obj.primary_constructor() %}
+  {%- let callable = cons.callable %}
+  {%- if callable.is_async() %}
+  def initialize({% call rb::arg_list_decl(callable) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+    handle = {% call rb::to_ffi_call_async_constructor(callable) %}{% endcall %}
     @handle = handle
     ObjectSpace.define_finalizer(self, self.class.uniffi_define_finalizer_by_handle(handle, self.object_id))
   end
   {%- else %}
-  def initialize({% call rb::arg_list_decl(cons) %}{% endcall -%})
-    {%- call rb::setup_args_extra_indent(cons) %}{% endcall %}
-    handle = {% call rb::to_ffi_call(cons) %}{% endcall %}
+  def initialize({% call rb::arg_list_decl(callable) %}{% endcall -%})
+    {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+    handle = {% call rb::to_ffi_call(callable) %}{% endcall %}
     @handle = handle
     ObjectSpace.define_finalizer(self, self.class.uniffi_define_finalizer_by_handle(handle, self.object_id))
   end
   {%- endif %}
-  {%- when None %}
-  {%- endmatch %}
+  {%- endif %}
 
   {% for cons in obj.alternate_constructors() -%}
-  {%- if cons.is_async() %}
-  def self.{{ cons.name()|fn_name_rb }}({% call rb::arg_list_decl(cons) %}{% endcall %})
-    {%- call rb::setup_args_extra_indent(cons) %}{% endcall %}
+  {%- let callable = cons.callable %}
+  {%- if callable.is_async() %}
+  def self.{{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
     # Call the (fallible) async function before creating any half-baked object instances.
-    return uniffi_allocate({% call rb::to_ffi_call_async_constructor(cons) %}{% endcall %})
+    return uniffi_allocate({% call rb::to_ffi_call_async_constructor(callable) %}{% endcall %})
   end
   {%- else %}
-  def self.{{ cons.name()|fn_name_rb }}({% call rb::arg_list_decl(cons) %}{% endcall %})
-    {%- call rb::setup_args_extra_indent(cons) %}{% endcall %}
+  def self.{{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
     # Call the (fallible) function before creating any half-baked object instances.
     # Lightly yucky way to bypass the usual "initialize" logic
     # and just create a new instance with the required handle.
-    return uniffi_allocate({% call rb::to_ffi_call(cons) %}{% endcall %})
+    return uniffi_allocate({% call rb::to_ffi_call(callable) %}{% endcall %})
   end
   {%- endif %}
   {% endfor %}
 
-  {% for meth in obj.methods() -%}
-  {%- if meth.is_async() %}
-  def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}{% endcall %})
-    {%- call rb::setup_args_extra_indent(meth) %}{% endcall %}
-    {% call rb::to_ffi_call_with_prefix_async("uniffi_clone_handle()", meth) %}{% endcall %}
+  {% for meth in obj.methods -%}
+  {%- let callable = meth.callable %}
+  {%- if callable.is_async() %}
+  def {{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+    {% call rb::to_ffi_call_with_prefix_async("uniffi_clone_handle()", callable) %}{% endcall %}
   end
   {%- else %}
-  {%- match meth.return_type() -%}
+  {%- match callable.return_type.ty -%}
 
-  {%- when Some with (return_type) -%}
-  def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}{% endcall %})
-    {%- call rb::setup_args_extra_indent(meth) %}{% endcall %}
-    result = {% call rb::to_ffi_call_with_prefix("uniffi_clone_handle()", meth) %}{% endcall %}
-    return {{ "result"|lift_rb(return_type, config) }}
+  {%- when Some(return_type) -%}
+  def {{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+    {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+    result = {% call rb::to_ffi_call_with_prefix("uniffi_clone_handle()", callable) %}{% endcall %}
+    return {{ return_type.lift("result") }}
   end
 
   {%- when None -%}
-  def {{ meth.name()|fn_name_rb }}({% call rb::arg_list_decl(meth) %}{% endcall %})
-      {%- call rb::setup_args_extra_indent(meth) %}{% endcall %}
-      {% call rb::to_ffi_call_with_prefix("uniffi_clone_handle()", meth) %}{% endcall %}
+  def {{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+      {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+      {% call rb::to_ffi_call_with_prefix("uniffi_clone_handle()", callable) %}{% endcall %}
   end
   {% endmatch %}
   {%- endif %}
   {% endfor %}
-  {%- let trait_methods = obj.uniffi_trait_methods() %}
+
+  {%- let static_methods = obj.static_methods %}
+  {%- include "StaticMethodImpls.rb" %}
+  {%- let trait_methods = obj.uniffi_trait_methods %}
   {%- include "UniffiTraitImpls.rb" %}
 end
 
-{%- if obj.has_callback_interface() %}
-{# For trait interfaces, generate and register the vtable. #}
-{%- let vtable = obj.vtable().expect("trait interface must have a vtable") %}
-{%- let vtable_methods = obj.vtable_methods() %}
-{%- let ffi_init_callback = obj.ffi_init_callback() %}
-{%- let cbi_name = obj.name() %}
-{%- include "TraitInterfaceVtable.rb" %}
+{%- if let Some(vtable) = obj.vtable %}
+{#- For trait interfaces, generate and register the vtable. #}
+
+# Trait interface vtable implementation for {{ obj.name }}.
+{%- let lift_handle = "{}.uniffi_handle_map.get(uniffi_handle)"|format(obj.name) %}
+{%- let handle_map = "{}.uniffi_handle_map"|format(obj.name) %}
+{%- include "CallbackInterfaceImpl.rb" %}
 {%- endif %}
@@ -1,26 +1,29 @@
-# Record type {{ rec.name() }}
-class {{ rec.name()|class_name_rb }}
-  attr_reader {% for field in rec.fields() %}:{{ field.name()|var_name_rb }}{% if loop.last %}{% else %}, {% endif %}{%- endfor %}
+# Record type {{ rec.name }}
+class {{ rec.name }}
+  attr_reader {% for field in rec.fields %}:{{ field.name }}{% if loop.last %}{% else %}, {% endif %}{%- endfor %}
 
-  def initialize({% for field in rec.fields() %}{{ field.name()|var_name_rb -}}:
-        {%- match field.default_value() %}
-        {%- when Some(_) %} {{ field|field_default_rb }}
+  def initialize({% for field in rec.fields %}{{ field.name -}}:
+        {%- match field.default %}
+        {%- when Some(default) %} {{ default }}
         {%- else %}
         {%- endmatch %}
   {%- if loop.last %}{% else %}, {% endif -%}{% endfor %})
-    {%- for field in rec.fields() %}
-    @{{ field.name()|var_name_rb }} = {{ field.name()|var_name_rb }}
+    {%- for field in rec.fields %}
+    @{{ field.name }} = {{ field.name }}
     {%- endfor %}
   end
 
-  {%- let methods = rec.methods() %}
+  {%- let methods = rec.methods %}
   {%- include "MethodImpls.rb" %}
 
-  {%- let trait_methods = rec.uniffi_trait_methods() %}
+  {%- let static_methods = rec.static_methods %}
+  {%- include "StaticMethodImpls.rb" %}
+
+  {%- let trait_methods = rec.uniffi_trait_methods %}
   {%- if trait_methods.eq_eq.is_none() %}
   def ==(other)
-    {%- for field in rec.fields() %}
-    if @{{ field.name()|var_name_rb }} != other.{{ field.name()|var_name_rb }}
+    {%- for field in rec.fields %}
+    if @{{ field.name }} != other.{{ field.name }}
       return false
     end
     {%- endfor %}
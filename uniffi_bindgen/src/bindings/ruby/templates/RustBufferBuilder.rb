@@ -27,66 +27,91 @@ class RustBufferBuilder
     end
   end
 
-  {% for typ in ci.iter_local_types() -%}
-  {%- let canonical_type_name = self::canonical_name(typ) -%}
-  {%- match typ -%}
+  def write_string(v)
+    v = ::{{ class_name }}::uniffi_utf8(v)
+    pack_into 4, 'l>', v.bytes.size
+    write v
+  end
+
+  {% for type_def in type_definitions -%}
+  {%- match type_def -%}
+
+  {% when TypeDefinition::Simple(type_node) -%}
+  {%- let canonical_type_name = type_node.canonical_name -%}
+  {%- match type_node.ty -%}
 
   {% when Type::Int8 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "i8", -2**7, 2**7)
+    v = ::{{ class_name }}::uniffi_in_range(v, "i8", -2**7, 2**7)
     pack_into(1, 'c', v)
   end
 
   {% when Type::UInt8 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "u8", 0, 2**8)
+    v = ::{{ class_name }}::uniffi_in_range(v, "u8", 0, 2**8)
     pack_into(1, 'c', v)
   end
 
   {% when Type::Int16 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "i16", -2**15, 2**15)
+    v = ::{{ class_name }}::uniffi_in_range(v, "i16", -2**15, 2**15)
     pack_into(2, 's>', v)
   end
 
   {% when Type::UInt16 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "u16", 0, 2**16)
+    v = ::{{ class_name }}::uniffi_in_range(v, "u16", 0, 2**16)
     pack_into(2, 'S>', v)
   end
 
   {% when Type::Int32 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "i32", -2**31, 2**31)
+    v = ::{{ class_name }}::uniffi_in_range(v, "i32", -2**31, 2**31)
     pack_into(4, 'l>', v)
   end
 
   {% when Type::UInt32 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "u32", 0, 2**32)
+    v = ::{{ class_name }}::uniffi_in_range(v, "u32", 0, 2**32)
     pack_into(4, 'L>', v)
   end
 
   {% when Type::Int64 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "i64", -2**63, 2**63)
+    v = ::{{ class_name }}::uniffi_in_range(v, "i64", -2**63, 2**63)
     pack_into(8, 'q>', v)
   end
 
   {% when Type::UInt64 -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_in_range(v, "u64", 0, 2**64)
+    v = ::{{ class_name }}::uniffi_in_range(v, "u64", 0, 2**64)
     pack_into(8, 'Q>', v)
   end
 
+  {% when Type::Int128 -%}
+
+  def write_{{ canonical_type_name }}(v)
+    v = ::{{ class_name }}::uniffi_in_range(v, "i128", -2**127, 2**127)
+    pack_into(8, 'q>', v >> 64)
+    pack_into(8, 'Q>', v & (2**64 - 1))
+  end
+
+  {% when Type::UInt128 -%}
+
+  def write_{{ canonical_type_name }}(v)
+    v = ::{{ class_name }}::uniffi_in_range(v, "u128", 0, 2**128)
+    pack_into(8, 'Q>', v >> 64)
+    pack_into(8, 'Q>', v & (2**64 - 1))
+  end
+
   {% when Type::Float32 -%}
 
   def write_{{ canonical_type_name }}(v)
@@ -105,18 +130,17 @@ class RustBufferBuilder
     pack_into(1, 'c', v ? 1 : 0)
   end
 
-  {% when Type::String -%}
+  {% when Type::Char -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_utf8(v)
-    pack_into 4, 'l>', v.bytes.size
-    write v
+    v = ::{{ class_name }}::uniffi_char(v)
+    pack_into(4, 'L>', v.ord)
   end
 
   {% when Type::Bytes -%}
 
   def write_{{ canonical_type_name }}(v)
-    v = ::{{ ci.namespace()|class_name_rb }}::uniffi_bytes(v)
+    v = ::{{ class_name }}::uniffi_bytes(v)
     pack_into 4, 'l>', v.bytes.size
     write v
   end
@@ -159,58 +183,64 @@ class RustBufferBuilder
     pack_into 4, 'L>', nanoseconds
   end
 
-  {% when Type::Object with { name: object_name, .. } -%}
-  # The Object type {{ object_name }}.
+  {% else -%}
+  {%- endmatch -%}
+
+  {% when TypeDefinition::Interface(obj) -%}
+  # The Object type {{ obj.name }}.
 
-  def write_{{ canonical_type_name }}(obj)
-    handle = {{ object_name|class_name_rb}}.uniffi_lower obj
+  def write_{{ obj.self_type.canonical_name }}(obj)
+    handle = {{ obj.name }}.uniffi_lower obj
     pack_into(8, 'Q>', handle)
   end
 
-  {% when Type::Enum { name: enum_name, .. } -%}
-  {% if !ci.is_name_used_as_error(enum_name) %}
-  {%- let e = ci.get_enum_definition(enum_name).unwrap() -%}
-  # The Enum type {{ enum_name }}.
+  {% when TypeDefinition::Enum(e) -%}
+  {%- let canonical_type_name = e.self_type.canonical_name -%}
+  {% if !e.self_type.is_used_as_error %}
+  # The Enum type {{ e.name }}.
 
   def write_{{ canonical_type_name }}(v)
-    {%- if e.is_flat() %}
-    {%- for variant in e.variants() %}
-    if v == {{ enum_name|class_name_rb }}::{{ variant.name()|enum_name_rb }}
+    {%- if e.is_flat %}
+    {%- for variant in e.variants %}
+    if v == {{ e.name }}::{{ variant.name }}
       pack_into(4, 'l>', {{ loop.index }})
     end
     {%- endfor %}
     {%- else -%}
-    {%- for variant in e.variants() %}
-    if v.{{ variant.name()|var_name_rb }}?
+    {%- for variant in e.variants %}
+    if v.{{ variant.predicate_name }}
       pack_into(4, 'l>', {{ loop.index }})
-      {%- for field in variant.fields() %}
-        self.write_{{ self::canonical_name(field.as_type().borrow()) }}(v.{% call rb::field_name(field, loop.index) %}{% endcall %})
+      {%- for field in variant.fields %}
+      {%- if variant.has_named_fields %}
+        self.write_{{ field.ty.canonical_name }}(v.{{ field.name }})
+      {%- else %}
+        self.write_{{ field.ty.canonical_name }}(v.values[{{ loop.index0 }}])
+      {%- endif %}
       {%- endfor %}
     end
     {%- endfor %}
     {%- endif %}
  end
   {% else %}
-  {%- let e = ci.get_enum_definition(enum_name).unwrap() -%}
-  # The Error type {{ enum_name }} - write for callback error returns.
+  # The Error type {{ e.name }} - write for callback error returns.
 
   def write_{{ canonical_type_name }}(v)
-    {%- if e.is_flat() %}
-    {%- for variant in e.variants() %}
-    if v.is_a?({{ enum_name|class_name_rb }}::{{ variant.name()|class_name_rb }})
+    {%- if e.is_flat %}
+    {%- for variant in e.variants %}
+    if v.is_a?({{ e.name }}::{{ variant.name }})
       pack_into 4, 'l>', {{ loop.index }}
       return
     end
     {%- endfor %}
     {%- else -%}
-    {%- for variant in e.variants() %}
-    if v.is_a?({{ enum_name|class_name_rb }}::{{ variant.name()|class_name_rb }})
+    {%- for variant in e.variants %}
+    if v.is_a?({{ e.name }}::{{ variant.name }})
       pack_into 4, 'l>', {{ loop.index }}
-      {%- for field in variant.fields() %}
-        {%- if field.name().is_empty() %}
-        self.write_{{ self::canonical_name(field.as_type().borrow()) }}(v[{{ loop.index0 }}])
+      {%- for field in variant.fields %}
+        {%- if variant.has_named_fields %}
+        self.write_{{ field.ty.canonical_name }}(v.{{ field.name }})
         {%- else %}
-        self.write_{{ self::canonical_name(field.as_type().borrow()) }}(v.{{ field.name()|var_name_rb }})
+        self.write_{{ field.ty.canonical_name }}(v[{{ loop.index0 }}])
         {%- endif %}
       {%- endfor %}
       return
@@ -220,95 +250,130 @@ class RustBufferBuilder
   end
   {% endif %}
 
-  {% when Type::Record { name: record_name, .. } -%}
-  {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
-  # The Record type {{ record_name }}.
+  {% when TypeDefinition::Record(rec) -%}
+  # The Record type {{ rec.name }}.
 
-  def write_{{ canonical_type_name }}(v)
-    {%- for field in rec.fields() %}
-    self.write_{{ self::canonical_name(field.as_type().borrow()) }}(v.{{ field.name()|var_name_rb }})
+  def write_{{ rec.self_type.canonical_name }}(v)
+    {%- for field in rec.fields %}
+    self.write_{{ field.ty.canonical_name }}(v.{{ field.name }})
     {%- endfor %}
   end
 
-  {% when Type::Optional { inner_type } -%}
-  # The Optional<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Optional(opt) -%}
+  # The Optional<T> type for {{ opt.inner.canonical_name }}.
 
-  def write_{{ canonical_type_name }}(v)
+  def write_{{ opt.self_type.canonical_name }}(v)
     if v.nil?
       pack_into(1, 'c', 0)
     else
       pack_into(1, 'c', 1)
-      self.write_{{ self::canonical_name(inner_type) }}(v)
+      self.write_{{ opt.inner.canonical_name }}(v)
     end
   end
 
-  {% when Type::Sequence { inner_type } -%}
-  # The Sequence<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Sequence(seq) -%}
+  # The Sequence<T> type for {{ seq.inner.canonical_name }}.
 
-  def write_{{ canonical_type_name }}(items)
+  def write_{{ seq.self_type.canonical_name }}(items)
     pack_into(4, 'l>', items.size)
 
     items.each do |item|
-      self.write_{{ self::canonical_name(inner_type) }}(item)
+      self.write_{{ seq.inner.canonical_name }}(item)
     end
   end
 
-  {% when Type::Set { inner_type } -%}
-  # The Set<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Array(array) -%}
+  # The fixed-size array type for {{ array.self_type.canonical_name }}.
 
-  def write_{{ canonical_type_name }}(items)
+  def write_{{ array.self_type.canonical_name }}(items)
     pack_into(4, 'l>', items.size)
 
     items.each do |item|
-      self.write_{{ self::canonical_name(inner_type) }}(item)
+      self.write_{{ array.inner.canonical_name }}(item)
     end
   end
 
-  {% when Type::Map { key_type: k, value_type: v } -%}
-  # The Map<T> type for {{ canonical_type_name }}.
+  {% when TypeDefinition::Set(set) -%}
+  # The Set<T> type for {{ set.inner.canonical_name }}.
 
-  def write_{{ canonical_type_name }}(items)
+  def write_{{ set.self_type.canonical_name }}(items)
+    pack_into(4, 'l>', items.size)
+
+    items.each do |item|
+      self.write_{{ set.inner.canonical_name }}(item)
+    end
+  end
+
+  {% when TypeDefinition::Tuple(tuple) -%}
+  # The tuple type for {{ tuple.self_type.canonical_name }}.
+
+  def write_{{ tuple.self_type.canonical_name }}(items)
+    {%- for inner in tuple.inners %}
+    self.write_{{ inner.canonical_name }}(items[{{ loop.index0 }}])
+    {%- endfor %}
+  end
+
+  {% when TypeDefinition::Map(map) -%}
+  # The Map<T> type for {{ map.self_type.canonical_name }}.
+
+  def write_{{ map.self_type.canonical_name }}(items)
     pack_into(4, 'l>', items.size)
 
     items.each do |k, v|
-      self.write_{{ self::canonical_name(k) }}(k)
-      self.write_{{ self::canonical_name(v) }}(v)
+      self.write_{{ map.key.canonical_name }}(k)
+      self.write_{{ map.value.canonical_name }}(v)
     end
   end
 
-  {% when Type::Custom { name, builtin, .. } -%}
-  {%- match config.custom_types.get(name.as_str()) %}
+  {% when TypeDefinition::Stream(stream) -%}
+  # This type is not yet supported in the Ruby backend.
+  def write_{{ stream.self_type.canonical_name }}(v)
+    raise InternalError('RustBufferStream.write() not implemented yet for {{ stream.self_type.canonical_name }}')
+  end
+
+  {% when TypeDefinition::Iterator(iter) -%}
+  # This type is not yet supported in the Ruby backend.
+  def write_{{ iter.self_type.canonical_name }}(v)
+    raise InternalError('RustBufferStream.write() not implemented yet for {{ iter.self_type.canonical_name }}')
+  end
+
+  {% when TypeDefinition::Custom(custom) -%}
+  {%- let canonical_type_name = custom.self_type.canonical_name -%}
+  {%- match custom.config %}
   {%- when Some(cfg) %}{%- if cfg.has_conversion() %}
-  # Custom type {{ name }}: applies lower, then writes builtin `{{ self::canonical_name(builtin) }}`
+  # Custom type {{ custom.name }}: applies lower, then writes builtin `{{ custom.builtin.canonical_name }}`
   def write_{{ canonical_type_name }}(v)
-    write_{{ self::canonical_name(builtin) }}({{ cfg.lower("v") }})
+    write_{{ custom.builtin.canonical_name }}({{ cfg.lower("v") }})
   end
   {%- else %}
-  # The Custom type {{ name }} delegates serialization to its builtin type.
+  # The Custom type {{ custom.name }} delegates serialization to its builtin type.
   def write_{{ canonical_type_name }}(v)
-    write_{{ self::canonical_name(builtin) }}(v)
+    write_{{ custom.builtin.canonical_name }}(v)
   end
   {%- endif %}
   {%- when None %}
-  # The Custom type {{ name }} delegates serialization to its builtin type.
+  # The Custom type {{ custom.name }} delegates serialization to its builtin type.
   def write_{{ canonical_type_name }}(v)
-    write_{{ self::canonical_name(builtin) }}(v)
+    write_{{ custom.builtin.canonical_name }}(v)
   end
   {%- endmatch %}
 
-  {% when Type::CallbackInterface { name, .. } -%}
-  # The CallbackInterface type {{ name }}: write a uint64 handle.
-  def write_{{ canonical_type_name }}(v)
-    handle = {{ self::canonical_name(typ) }}FfiConverter.lower(v)
+  {% when TypeDefinition::CallbackInterface(cbi) -%}
+  # The CallbackInterface type {{ cbi.name }}: write a uint64 handle.
+  def write_{{ cbi.self_type.canonical_name }}(v)
+    handle = {{ cbi.self_type.canonical_name }}FfiConverter.lower(v)
     pack_into 8, 'Q>', handle
   end
 
-  {%- else -%}
-  # This type is not yet supported in the Ruby backend.
-  def write_{{ canonical_type_name }}(v)
-    raise InternalError('RustBufferStream.write() not implemented yet for {{ canonical_type_name }}')
+
+  {% when TypeDefinition::Closure(closure) -%}
+  # The {{ closure.self_type.canonical_name }} type: write a uint64 handle.
+  def write_{{ closure.self_type.canonical_name }}(v)
+    handle = {{ closure.self_type.lower("v") }}
+    pack_into 8, 'Q>', handle
   end
 
+  {% else -%}
   {%- endmatch -%}
   {%- endfor %}
 
@@ -21,73 +21,106 @@ class RustBufferStream
     data
   end
 
-  {% for typ in ci.iter_local_types() -%}
-  {%- let canonical_type_name = self::canonical_name(typ) -%}
-  {%- match typ -%}
+  def read_string
+    size = unpack_from 4, 'l>'
+
+    raise InternalError, 'Unexpected negative string length' if size.negative?
+
+    read(size).force_encoding(Encoding::UTF_8)
+  end
+
+  {% for type_def in type_definitions -%}
+  {%- match type_def -%}
+
+  {% when TypeDefinition::Simple(type_node) -%}
+  {%- let canonical_type_name = type_node.canonical_name -%}
+  {%- match type_node.ty -%}
 
   {% when Type::Int8 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 1, 'c'
   end
 
   {% when Type::UInt8 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 1, 'c'
   end
 
   {% when Type::Int16 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 2, 's>'
   end
 
   {% when Type::UInt16 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 2, 'S>'
   end
 
   {% when Type::Int32 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 4, 'l>'
   end
 
   {% when Type::UInt32 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 4, 'L>'
   end
 
   {% when Type::Int64 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 8, 'q>'
   end
 
   {% when Type::UInt64 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 8, 'Q>'
   end
 
+  {% when Type::Int128 -%}
+
+  def read_{{ canonical_type_name }}
+    high = unpack_from 8, 'q>'
+    low = unpack_from 8, 'Q>'
+    (high << 64) | low
+  end
+
+  {% when Type::UInt128 -%}
+
+  def read_{{ canonical_type_name }}
+    high = unpack_from 8, 'Q>'
+    low = unpack_from 8, 'Q>'
+    (high << 64) | low
+  end
+
   {% when Type::Float32 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 4, 'g'
   end
 
   {% when Type::Float64 -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     unpack_from 8, 'G'
   end
 
+  {% when Type::Char -%}
+
+  def read_{{ canonical_type_name }}
+    unpack_from(4, 'L>').chr(Encoding::UTF_8)
+  end
+
   {% when Type::Boolean -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     v = unpack_from 1, 'c'
 
     return false if v == 0
@@ -96,19 +129,9 @@ class RustBufferStream
     raise InternalError, 'Unexpected byte for Boolean type'
   end
 
-  {% when Type::String -%}
-
-  def read_{{ self::canonical_name(typ) }}
-    size = unpack_from 4, 'l>'
-
-    raise InternalError, 'Unexpected negative string length' if size.negative?
-
-    read(size).force_encoding(Encoding::UTF_8)
-  end
-
   {% when Type::Bytes -%}
 
-  def read_{{ self::canonical_name(typ) }}
+  def read_{{ canonical_type_name }}
     size = unpack_from 4, 'l>'
 
     raise InternalError, 'Unexpected negative byte string length' if size.negative?
@@ -140,7 +163,7 @@ class RustBufferStream
     Time.at(seconds, nanoseconds, :nanosecond, in: '+00:00').utc
   end
 
-  {% when Type::Duration -%}
+    {% when Type::Duration -%}
   # The Duration type.
 
   def read_{{ canonical_type_name }}
@@ -150,42 +173,43 @@ class RustBufferStream
     Time.at(seconds, nanoseconds, :nanosecond, in: '+00:00').utc
   end
 
-  {% when Type::Object with { name: object_name, .. } -%}
-  # The Object type {{ object_name }}.
+  {% else -%}
+  {%- endmatch -%}
 
-  def read_{{ canonical_type_name }}
+  {% when TypeDefinition::Interface(obj) -%}
+  # The Object type {{ obj.name }}.
+
+  def read_{{ obj.self_type.canonical_name }}
     handle = unpack_from 8, 'Q>'
-    return {{ object_name|class_name_rb }}.uniffi_lift(handle)
+    return {{ obj.name }}.uniffi_lift(handle)
   end
 
-  {% when Type::Enum { name, .. } -%}
-  {%- let e = ci.get_enum_definition(name).unwrap() -%}
-  {% if !ci.is_name_used_as_error(name) %}
-  {% let enum_name = name %}
-  # The Enum type {{ enum_name }}.
+  {% when TypeDefinition::Enum(e) -%}
+  {%- let canonical_type_name = e.self_type.canonical_name -%}
+  {% if !e.self_type.is_used_as_error %}
+  # The Enum type {{ e.name }}.
 
   def read_{{ canonical_type_name }}
     variant = unpack_from 4, 'l>'
-    {% if e.is_flat() -%}
-    {%- for variant in e.variants() %}
+    {% if e.is_flat -%}
+    {%- for variant in e.variants %}
     if variant == {{ loop.index }}
-      return {{ enum_name|class_name_rb }}::{{ variant.name()|enum_name_rb }}
+      return {{ e.name }}::{{ variant.name }}
     end
     {%- endfor %}
 
     raise InternalError, 'Unexpected variant tag for {{ canonical_type_name }}'
     {%- else -%}
-    {%- for variant in e.variants() %}
+    {%- for variant in e.variants %}
     if variant == {{ loop.index }}
         {%- if variant.has_fields() %}
-        {%- let named_fields = !variant.fields()[0].name().is_empty() %}
-        return {{ enum_name|class_name_rb }}::{{ variant.name()|enum_name_rb }}.new(
-            {%- for field in variant.fields() %}
-            {% if named_fields %}{{ field.name()|var_name_rb }}: {% endif %}self.read_{{ self::canonical_name(field.as_type().borrow()) }}(){% if loop.last %}{% else %},{% endif %}
+        return {{ e.name }}::{{ variant.name }}.new(
+            {%- for field in variant.fields %}
+            {% if variant.has_named_fields %}{{ field.name }}: {% endif %}self.read_{{ field.ty.canonical_name }}(){% if loop.last %}{% else %},{% endif %}
             {%- endfor %}
         )
         {%- else %}
-        return {{ enum_name|class_name_rb }}::{{ variant.name()|enum_name_rb }}.new
+        return {{ e.name }}::{{ variant.name }}.new
         {% endif %}
     end
     {%- endfor %}
@@ -195,34 +219,31 @@ class RustBufferStream
 
   {% else %}
 
-  {% let error_name = name %}
-
-  # The Error type {{ error_name }}
+  # The Error type {{ e.name }}
 
   def read_{{ canonical_type_name }}
     variant = unpack_from 4, 'l>'
-    {% if e.is_flat() -%}
-    {%- for variant in e.variants() %}
+    {% if e.is_flat -%}
+    {%- for variant in e.variants %}
     if variant == {{ loop.index }}
-      return {{ error_name|class_name_rb }}::{{ variant.name()|class_name_rb }}.new(
-        read_{{ self::canonical_name(&Type::String) }}()
+      return {{ e.name }}::{{ variant.name }}.new(
+        read_string()
       )
     end
     {%- endfor %}
 
     raise InternalError, 'Unexpected variant tag for {{ canonical_type_name }}'
     {%- else -%}
-    {%- for variant in e.variants() %}
+    {%- for variant in e.variants %}
     if variant == {{ loop.index }}
         {%- if variant.has_fields() %}
-        {%- let named_fields = !variant.fields()[0].name().is_empty() %}
-        return {{ error_name|class_name_rb }}::{{ variant.name()|class_name_rb }}.new(
-            {%- for field in variant.fields() %}
-            {% if named_fields %}{{ field.name()|var_name_rb }}: {% endif %}read_{{ self::canonical_name(&field.as_type()) }}(){% if loop.last %}{% else %},{% endif %}
+        return {{ e.name }}::{{ variant.name }}.new(
+            {%- for field in variant.fields %}
+            {% if variant.has_named_fields %}{{ field.name }}: {% endif %}read_{{ field.ty.canonical_name }}(){% if loop.last %}{% else %},{% endif %}
             {%- endfor %}
         )
         {%- else %}
-        return {{ error_name|class_name_rb }}::{{ variant.name()|class_name_rb }}.new
+        return {{ e.name }}::{{ variant.name }}.new
         {%- endif %}
     end
     {%- endfor %}
@@ -232,20 +253,20 @@ class RustBufferStream
   end
   {% endif %}
 
-  {% when Type::Record { name: record_name, .. } -%}
-  {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
-  # The Record type {{ record_name }}.
+  {% when TypeDefinition::Record(rec) -%}
+  # The Record type {{ rec.name }}.
 
-  def read_{{ canonical_type_name }}
-    {{ rec.name()|class_name_rb }}.new(
-      {%- for field in rec.fields() %}
-      {{ field.name()|var_name_rb }}: read_{{ self::canonical_name(field.as_type().borrow()) }}{% if loop.last %}{% else %},{% endif %}
+  def read_{{ rec.self_type.canonical_name }}
+    {{ rec.name }}.new(
+      {%- for field in rec.fields %}
+      {{ field.name }}: read_{{ field.ty.canonical_name }}{% if loop.last %}{% else %},{% endif %}
       {%- endfor %}
     )
   end
 
-  {% when Type::Optional { inner_type } %}
-  # The Optional<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Optional(opt) %}
+  {%- let canonical_type_name = opt.self_type.canonical_name -%}
+  # The Optional<T> type for {{ opt.inner.canonical_name }}.
 
   def read_{{ canonical_type_name }}
     flag = unpack_from 1, 'c'
@@ -253,16 +274,16 @@ class RustBufferStream
     if flag == 0
       return nil
     elsif flag == 1
-      return read_{{ self::canonical_name(inner_type) }}
+      return read_{{ opt.inner.canonical_name }}
     else
       raise InternalError, 'Unexpected flag byte for {{ canonical_type_name }}'
     end
   end
 
-  {% when Type::Sequence { inner_type } -%}
-  # The Sequence<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Sequence(seq) -%}
+  # The Sequence<T> type for {{ seq.inner.canonical_name }}.
 
-  def read_{{ canonical_type_name }}
+  def read_{{ seq.self_type.canonical_name }}
     count = unpack_from 4, 'l>'
 
     raise InternalError, 'Unexpected negative sequence length' if count.negative?
@@ -270,16 +291,33 @@ class RustBufferStream
     items = []
 
     count.times do
-      items.append read_{{ self::canonical_name(inner_type) }}
+      items.append read_{{ seq.inner.canonical_name }}
     end
 
     items
   end
 
-  {% when Type::Set { inner_type } -%}
-  # The Set<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Array(array) -%}
+  # The fixed-size array type for {{ array.self_type.canonical_name }}.
 
-  def read_{{ canonical_type_name }}
+  def read_{{ array.self_type.canonical_name }}
+    count = unpack_from 4, 'l>'
+
+    raise InternalError, "Expected an array of length {{ array.len }}, got #{count} items" unless count == {{ array.len }}
+
+    items = []
+
+    count.times do
+      items.append read_{{ array.inner.canonical_name }}
+    end
+
+    items
+  end
+
+  {% when TypeDefinition::Set(set) -%}
+  # The Set<T> type for {{ set.inner.canonical_name }}.
+
+  def read_{{ set.self_type.canonical_name }}
     count = unpack_from 4, 'l>'
 
     raise InternalError, 'Unexpected negative set size' if count.negative?
@@ -287,63 +325,94 @@ class RustBufferStream
     items = Set.new
 
     count.times do
-      items.add read_{{ self::canonical_name(inner_type) }}
+      items.add read_{{ set.inner.canonical_name }}
     end
 
     items
   end
 
-  {% when Type::Map { key_type: k, value_type: v } -%}
-  # The Map<T> type for {{ canonical_type_name }}.
+  {% when TypeDefinition::Stream(stream) -%}
+  # The {{ stream.self_type.canonical_name }} type: read a uint64 handle.
 
-  def read_{{ canonical_type_name }}
+  def read_{{ stream.self_type.canonical_name }}
+    handle = unpack_from 8, 'Q>'
+    {{ stream.self_type.lift("handle") }}
+  end
+
+  {% when TypeDefinition::Iterator(iter) -%}
+  # The {{ iter.self_type.canonical_name }} type: read a uint64 handle.
+
+  def read_{{ iter.self_type.canonical_name }}
+    handle = unpack_from 8, 'Q>'
+    {{ iter.self_type.lift("handle") }}
+  end
+
+  {% when TypeDefinition::Closure(closure) -%}
+  # The {{ closure.self_type.canonical_name }} type: read a uint64 handle.
+
+  def read_{{ closure.self_type.canonical_name }}
+    handle = unpack_from 8, 'Q>'
+    {{ closure.self_type.lift("handle") }}
+  end
+
+  {% when TypeDefinition::Tuple(tuple) -%}
+  # The tuple type for {{ tuple.self_type.canonical_name }}.
+
+  def read_{{ tuple.self_type.canonical_name }}
+    [
+      {%- for inner in tuple.inners %}
+      read_{{ inner.canonical_name }}{% if !loop.last %},{% endif %}
+      {%- endfor %}
+    ]
+  end
+
+  {% when TypeDefinition::Map(map) -%}
+  # The Map<T> type for {{ map.self_type.canonical_name }}.
+
+  def read_{{ map.self_type.canonical_name }}
     count = unpack_from 4, 'l>'
     raise InternalError, 'Unexpected negative map size' if count.negative?
 
     items = {}
     count.times do
-      key = read_{{ self::canonical_name(k) }}
-      items[key] = read_{{ self::canonical_name(v) }}
+      key = read_{{ map.key.canonical_name }}
+      items[key] = read_{{ map.value.canonical_name }}
     end
 
     items
   end
 
-  {% when Type::Custom { name, builtin, .. } -%}
-  {%- match config.custom_types.get(name.as_str()) %}
+  {% when TypeDefinition::Custom(custom) -%}
+  {%- let canonical_type_name = custom.self_type.canonical_name -%}
+  {%- match custom.config %}
   {%- when Some(cfg) %}{%- if cfg.has_conversion() %}
-  # Custom type {{ name }}: reads builtin `{{ self::canonical_name(builtin) }}`, then applies lift.
+  # Custom type {{ custom.name }}: reads builtin `{{ custom.builtin.canonical_name }}`, then applies lift.
   def read_{{ canonical_type_name }}
-    raw = read_{{ self::canonical_name(builtin) }}
+    raw = read_{{ custom.builtin.canonical_name }}
     {{ cfg.lift("raw") }}
   end
   {%- else %}
-  # The Custom type {{ name }} delegates deserialization to its builtin type.
+  # The Custom type {{ custom.name }} delegates deserialization to its builtin type.
   def read_{{ canonical_type_name }}
-    read_{{ self::canonical_name(builtin) }}
+    read_{{ custom.builtin.canonical_name }}
   end
   {%- endif %}
   {%- when None %}
-  # The Custom type {{ name }} delegates deserialization to its builtin type.
+  # The Custom type {{ custom.name }} delegates deserialization to its builtin type.
   def read_{{ canonical_type_name }}
-    read_{{ self::canonical_name(builtin) }}
+    read_{{ custom.builtin.canonical_name }}
   end
   {%- endmatch %}
 
-  {% when Type::CallbackInterface { name, .. } -%}
+  {% when TypeDefinition::CallbackInterface(cbi) -%}
 
-  # The CallbackInterface type {{ name }}: read a uint64 handle.
-  def read_{{ canonical_type_name }}
+  # The CallbackInterface type {{ cbi.name }}: read a uint64 handle.
+  def read_{{ cbi.self_type.canonical_name }}
     handle = unpack_from 8, 'Q>'
-    {{ self::canonical_name(typ) }}FfiConverter.lift handle
-  end
-
-  {%- else -%}
-  # This type is not yet supported in the Ruby backend.
-  def read_{{ canonical_type_name }}
-    raise InternalError, 'RustBufferStream.read not implemented yet for {{ canonical_type_name }}'
+    {{ cbi.self_type.canonical_name }}FfiConverter.lift handle
   end
 
+  {% else -%}
   {%- endmatch -%}
   {%- endfor %}
 
@@ -4,15 +4,15 @@ class RustBuffer < FFI::Struct
          :data,     :pointer
 
   def self.alloc(size)
-    return ::{{ ci.namespace()|class_name_rb }}.rust_call(:{{ ci.ffi_rustbuffer_alloc().name() }}, size)
+    return ::{{ class_name }}.rust_call(:{{ ffi_rustbuffer_alloc.0 }}, size)
   end
 
   def self.reserve(rbuf, additional)
-    return ::{{ ci.namespace()|class_name_rb }}.rust_call(:{{ ci.ffi_rustbuffer_reserve().name() }}, rbuf, additional)
+    return ::{{ class_name }}.rust_call(:{{ ffi_rustbuffer_reserve.0 }}, rbuf, additional)
   end
 
   def free
-    ::{{ ci.namespace()|class_name_rb }}.rust_call(:{{ ci.ffi_rustbuffer_free().name() }}, self)
+    ::{{ class_name }}.rust_call(:{{ ffi_rustbuffer_free.0 }}, self)
   end
 
   def capacity
@@ -60,32 +60,33 @@ class RustBuffer < FFI::Struct
     free
   end
 
-  {%- for typ in ci.iter_local_types() -%}
-  {%- let canonical_type_name = self::canonical_name(typ) -%}
-  {%- match typ -%}
-
-  {% when Type::String -%}
   # The primitive String type.
 
-  def self.alloc_from_{{ canonical_type_name }}(value)
+  def self.alloc_from_string(value)
     RustBuffer.allocWithBuilder do |builder|
       builder.write value.encode('utf-8')
       return builder.finalize
     end
   end
 
-  def consume_into_{{ canonical_type_name }}
+  def consume_into_string
     consumeWithStream do |stream|
       return stream.read(stream.remaining).force_encoding(Encoding::UTF_8)
     end
   end
 
-  {% when Type::Bytes -%}
-  # The primitive Bytes type.
+  {% for type_def in type_definitions -%}
+  {%- match type_def -%}
+
+  {% when TypeDefinition::Simple(type_node) -%}
+  {%- let canonical_type_name = type_node.canonical_name -%}
+  {%- match type_node.ty -%}
+  {% when Type::Bytes | Type::Int128 | Type::UInt128 | Type::Timestamp | Type::Duration -%}
+  # The {{ canonical_type_name }} type.
 
-  def self.alloc_from_{{ canonical_type_name }}(value)
+  def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
-      builder.write_{{ canonical_type_name }}(value)
+      builder.write_{{ canonical_type_name }}(v)
       return builder.finalize
     end
   end
@@ -96,7 +97,20 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Timestamp -%}
+  {% else -%}
+  {%- endmatch -%}
+
+  {% when TypeDefinition::Record(rec) -%}
+  {%- let canonical_type_name = rec.self_type.canonical_name -%}
+  # The Record type {{ rec.name }}.
+
+  def self.check_lower_{{ canonical_type_name }}(v)
+    {%- for field in rec.fields %}
+    {%- let item = "v.{}"|format(field.name) %}
+    {{ field.ty.check_lower(item.as_str()) }}
+    {%- endfor %}
+  end
+
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
@@ -110,7 +124,30 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Duration -%}
+  {% when TypeDefinition::Enum(e) -%}
+  {%- let canonical_type_name = e.self_type.canonical_name -%}
+  {% if !e.self_type.is_used_as_error %}
+  # The Enum type {{ e.name }}.
+
+  def self.check_lower_{{ canonical_type_name }}(v)
+    {%- if !e.is_flat %}
+    {%- for variant in e.variants %}
+    if v.{{ variant.predicate_name }}
+      {%- for field in variant.fields %}
+      {%- if variant.has_named_fields %}
+      {%- let item = "v.{}"|format(field.name) %}
+        {{ field.ty.check_lower(item.as_str()) }}
+      {%- else %}
+      {%- let item = "v.values[{}]"|format(loop.index0) %}
+        {{ field.ty.check_lower(item.as_str()) }}
+      {%- endif %}
+      {%- endfor %}
+      return
+    end
+    {%- endfor %}
+    {%- endif %}
+  end
+
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
@@ -123,17 +160,8 @@ class RustBuffer < FFI::Struct
       return stream.read_{{ canonical_type_name }}
     end
   end
-
-  {% when Type::Record { name: record_name, .. } -%}
-  {%- let rec = ci.get_record_definition(record_name).unwrap() -%}
-  # The Record type {{ record_name }}.
-
-  def self.check_lower_{{ canonical_type_name }}(v)
-    {%- for field in rec.fields() %}
-    {{ "v.{}"|format(field.name()|var_name_rb)|check_lower_rb(field.as_type().borrow(), config) }}
-    {%- endfor %}
-  end
-
+  {% else %}
+  # Error enum - generate alloc_from for callback error serialization
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
@@ -141,32 +169,22 @@ class RustBuffer < FFI::Struct
     end
   end
 
+  # Enum used as error - generate consume_into_ for use as a return value
   def consume_into_{{ canonical_type_name }}
     consumeWithStream do |stream|
       return stream.read_{{ canonical_type_name }}
     end
   end
+  {% endif %}
 
-  {% when Type::Enum { name: enum_name, .. }  -%}
-  {% if !ci.is_name_used_as_error(enum_name) %}
-  {%- let e = ci.get_enum_definition(enum_name).unwrap() -%}
-  # The Enum type {{ enum_name }}.
+  {% when TypeDefinition::Optional(opt) -%}
+  {%- let canonical_type_name = opt.self_type.canonical_name -%}
+  # The Optional<T> type for {{ opt.inner.canonical_name }}.
 
   def self.check_lower_{{ canonical_type_name }}(v)
-    {%- if !e.is_flat() %}
-    {%- for variant in e.variants() %}
-    if v.{{ variant.name()|var_name_rb }}?
-      {%- for field in variant.fields() %}
-      {%- if field.name().is_empty() %}
-        {{ "v.values[{}]"|format(loop.index0)|check_lower_rb(field.as_type().borrow(), config) }}
-      {%- else %}
-        {{ "v.{}"|format(field.name())|check_lower_rb(field.as_type().borrow(), config) }}
-      {%- endif %}
-      {%- endfor %}
-      return
+    if !v.nil?
+      {{ opt.inner.check_lower("v") }}
     end
-    {%- endfor %}
-    {%- endif %}
   end
 
   def self.alloc_from_{{ canonical_type_name }}(v)
@@ -181,9 +199,17 @@ class RustBuffer < FFI::Struct
       return stream.read_{{ canonical_type_name }}
     end
   end
-  {% else %}
-  {%- let e = ci.get_enum_definition(enum_name).unwrap() -%}
-  # Error enum - generate alloc_from for callback error serialization
+
+  {% when TypeDefinition::Sequence(seq) -%}
+  {%- let canonical_type_name = seq.self_type.canonical_name -%}
+  # The Sequence<T> type for {{ seq.inner.canonical_name }}.
+
+  def self.check_lower_{{ canonical_type_name }}(v)
+    v.each do |item|
+      {{ seq.inner.check_lower("item") }}
+    end
+  end
+
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
@@ -191,27 +217,27 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  # Enum used as error - generate consume_into_ for use as a return value
   def consume_into_{{ canonical_type_name }}
     consumeWithStream do |stream|
       return stream.read_{{ canonical_type_name }}
     end
   end
-  {% endif %}
 
-  {% when Type::Optional { inner_type } -%}
-  # The Optional<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Array(array) -%}
+  {%- let canonical_type_name = array.self_type.canonical_name -%}
+  # The fixed-size array type for {{ canonical_type_name }}.
 
   def self.check_lower_{{ canonical_type_name }}(v)
-    if !v.nil?
-      {{ "v"|check_lower_rb(inner_type.borrow(), config) }}
+    raise ArgumentError, "Expected an array of length {{ array.len }}, got #{v.size}" unless v.size == {{ array.len }}
+    v.each do |item|
+      {{ array.inner.check_lower("item") }}
     end
   end
 
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
-      return builder.finalize()
+      return builder.finalize
     end
   end
 
@@ -221,19 +247,20 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Sequence { inner_type } -%}
-  # The Sequence<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Set(set) -%}
+  {%- let canonical_type_name = set.self_type.canonical_name -%}
+  # The Set<T> type for {{ set.inner.canonical_name }}.
 
   def self.check_lower_{{ canonical_type_name }}(v)
     v.each do |item|
-      {{ "item"|check_lower_rb(inner_type.borrow(), config) }}
+      {{ set.inner.check_lower("item") }}
     end
   end
 
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
-      return builder.finalize()
+      return builder.finalize
     end
   end
 
@@ -243,19 +270,105 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Set { inner_type } -%}
-  # The Set<T> type for {{ self::canonical_name(inner_type) }}.
+  {% when TypeDefinition::Stream(stream) -%}
+  {%- let canonical_type_name = stream.self_type.canonical_name -%}
+  # The next item for {{ canonical_type_name }}.
+  # Returns the item wrapped in an array, or nil at the end of the stream.
 
-  def self.check_lower_{{ canonical_type_name }}(v)
-    v.each do |item|
-      {{ "item"|check_lower_rb(inner_type.borrow(), config) }}
+  def consume_into_{{ canonical_type_name }}_next
+    consumeWithStream do |stream|
+      flag = stream.unpack_from 1, 'c'
+
+      if flag == 0
+        return nil
+      elsif flag == 1
+        return [stream.read_{{ stream.item.canonical_name }}]
+      else
+        raise InternalError, 'Unexpected flag byte for {{ canonical_type_name }}'
+      end
+    end
+  end
+
+  {% when TypeDefinition::Iterator(iter) -%}
+  {%- let canonical_type_name = iter.self_type.canonical_name -%}
+  # The next chunk of items for {{ canonical_type_name }}.
+  # Returns an array of items, which is empty at the end of the iterator.
+
+  def consume_into_{{ canonical_type_name }}_chunk
+    consumeWithStream do |stream|
+      count = stream.unpack_from 4, 'l>'
+
+      raise InternalError, 'Unexpected negative chunk size' if count.negative?
+
+      items = []
+
+      count.times do
+        items.append stream.read_{{ iter.item.canonical_name }}
+      end
+
+      return items
     end
   end
 
+  {% when TypeDefinition::Closure(closure) -%}
+  {%- let canonical_type_name = closure.self_type.canonical_name -%}
+  # The {{ canonical_type_name }} closure type: arguments and return values are serialized.
+
+  def self.alloc_from_{{ canonical_type_name }}_args(args)
+    RustBuffer.allocWithBuilder do |builder|
+      {%- for arg_type in closure.arguments %}
+      builder.write_{{ arg_type.canonical_name }}(args[{{ loop.index0 }}])
+      {%- endfor %}
+      return builder.finalize
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}_args
+    consumeWithStream do |stream|
+      return [
+        {%- for arg_type in closure.arguments %}
+        stream.read_{{ arg_type.canonical_name }}{% if !loop.last %},{% endif %}
+        {%- endfor %}
+      ]
+    end
+  end
+
+  def self.alloc_from_{{ canonical_type_name }}_return(v)
+    RustBuffer.allocWithBuilder do |builder|
+      {%- if let Some(return_type) = closure.return_type %}
+      builder.write_{{ return_type.canonical_name }}(v)
+      {%- endif %}
+      return builder.finalize
+    end
+  end
+
+  def consume_into_{{ canonical_type_name }}_return
+    consumeWithStream do |stream|
+      {%- match closure.return_type %}
+      {%- when Some(return_type) %}
+      return stream.read_{{ return_type.canonical_name }}
+      {%- when None %}
+      return nil
+      {%- endmatch %}
+    end
+  end
+
+  {% when TypeDefinition::Tuple(tuple) -%}
+  {%- let canonical_type_name = tuple.self_type.canonical_name -%}
+  # The tuple type for {{ canonical_type_name }}.
+
+  def self.check_lower_{{ canonical_type_name }}(v)
+    raise TypeError, "Expected a tuple of {{ tuple.inners.len() }} elements, got #{v.size}" unless v.size == {{ tuple.inners.len() }}
+    {%- for inner in tuple.inners %}
+    {%- let item = "v[{}]"|format(loop.index0) %}
+    {{ inner.check_lower(item.as_str()) }}
+    {%- endfor %}
+  end
+
   def self.alloc_from_{{ canonical_type_name }}(v)
     RustBuffer.allocWithBuilder do |builder|
       builder.write_{{ canonical_type_name }}(v)
-      return builder.finalize()
+      return builder.finalize
     end
   end
 
@@ -265,13 +378,14 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {% when Type::Map { key_type: k, value_type: v } %}
+  {% when TypeDefinition::Map(map) %}
+  {%- let canonical_type_name = map.self_type.canonical_name -%}
   # The Map<T> type for {{ canonical_type_name }}.
 
   def self.check_lower_{{ canonical_type_name }}(v)
     v.each do |k, v|
-      {{ "k"|check_lower_rb(k.borrow(), config) }}
-      {{ "v"|check_lower_rb(v.borrow(), config) }}
+      {{ map.key.check_lower("k") }}
+      {{ map.value.check_lower("v") }}
     end
   end
 
@@ -288,7 +402,7 @@ class RustBuffer < FFI::Struct
     end
   end
 
-  {%- else -%}
+  {% else -%}
   {#- No code emitted for types that don't lower into a RustBuffer -#}
   {%- endmatch -%}
   {%- endfor %}
@@ -0,0 +1,105 @@
+# Support for closures.
+#
+# Closures are type-erased: the arguments and return value are serialized into RustBuffers.
+#
+# Ruby callables passed to Rust are stored in `UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP` and Rust calls
+# them through the `VTableForeignClosure` VTable.  Rust closures are wrapped in
+# `UniffiRustClosure` and handed to Ruby code as lambdas.
+UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP = UniffiHandleMap.new
+
+private_constant :UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP
+
+module UniffiForeignClosure
+  UNIFFI_FREE_CALLBACK = Proc.new do |uniffi_handle|
+    UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.remove uniffi_handle
+  end
+
+  UNIFFI_CLONE_CALLBACK = Proc.new do |uniffi_handle|
+    UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.clone_handle uniffi_handle
+  end
+
+  UNIFFI_CALL_CALLBACK = Proc.new do |uniffi_handle, args, uniffi_out_return, uniffi_call_status|
+    erased = UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.get uniffi_handle
+    make_call = Proc.new { erased.call args }
+    write_return_value = Proc.new do |v|
+      out_buf = RustBuffer.new uniffi_out_return
+      out_buf[:capacity] = v[:capacity]
+      out_buf[:len] = v[:len]
+      out_buf[:data] = v[:data]
+    end
+    ::{{ class_name }}.uniffi_trait_interface_call(
+      uniffi_call_status,
+      make_call,
+      write_return_value,
+    )
+  end
+
+  # Create the VTable struct instance.  The constant keeps it alive for as long as Rust uses it.
+  UNIFFI_VTABLE = UniFFILib::VTableForeignClosure.new
+  UNIFFI_VTABLE[:uniffi_free] = UNIFFI_FREE_CALLBACK
+  UNIFFI_VTABLE[:uniffi_clone] = UNIFFI_CLONE_CALLBACK
+  UNIFFI_VTABLE[:call] = UNIFFI_CALL_CALLBACK
+
+  # Register the VTable with Rust.
+  UniFFILib.ffi_{{ crate_name }}_foreign_closure_init_vtable(UNIFFI_VTABLE)
+end
+
+private_constant :UniffiForeignClosure
+
+class UniffiRustClosure
+  def initialize(handle)
+    @state = { handle: handle }
+    ObjectSpace.define_finalizer(self, self.class.uniffi_define_finalizer(@state))
+  end
+
+  # N.B. the finalizer must not capture a reference to the closure itself, otherwise it will never
+  # be garbage collected.
+  def self.uniffi_define_finalizer(state)
+    Proc.new { |_id| UniFFILib.ffi_{{ crate_name }}_rust_closure_free(state[:handle]) }
+  end
+
+  def call(args)
+    ::{{ class_name }}.rust_call(
+      :ffi_{{ crate_name }}_rust_closure_call,
+      @state[:handle],
+      args
+    )
+  end
+
+  def clone_handle
+    UniFFILib.ffi_{{ crate_name }}_rust_closure_clone(@state[:handle])
+  end
+
+  # Wrap a Rust closure handle in a lambda.
+  #
+  # `lower_args` serializes the argument array and `lift_return` deserializes the return value.
+  def self.lift(handle, arity, lower_args, lift_return)
+    rust_closure = new(handle)
+    callable = lambda do |*args|
+      unless args.size == arity
+        raise ArgumentError, "wrong number of arguments (given #{args.size}, expected #{arity})"
+      end
+
+      lift_return.call(rust_closure.call(lower_args.call(args)))
+    end
+    # Remember the Rust closure, so that we can pass it back to Rust without wrapping it again
+    callable.instance_variable_set(:@uniffi_rust_closure, rust_closure)
+    callable
+  end
+
+  # Convert a Ruby callable into a closure handle.
+  #
+  # `lift_args` deserializes the argument array and `lower_return` serializes the return value.
+  def self.lower(callable, lift_args, lower_return)
+    raise TypeError, "Expected a callable, got #{callable.class}" unless callable.respond_to?(:call)
+
+    if callable.instance_variable_defined?(:@uniffi_rust_closure)
+      return callable.instance_variable_get(:@uniffi_rust_closure).clone_handle
+    end
+
+    erased = Proc.new { |args| lower_return.call(callable.call(*lift_args.call(args))) }
+    UNIFFI_FOREIGN_CLOSURE_HANDLE_MAP.insert erased
+  end
+end
+
+private_constant :UniffiRustClosure
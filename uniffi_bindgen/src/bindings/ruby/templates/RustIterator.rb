@@ -0,0 +1,51 @@
+UNIFFI_RUST_ITER_CHUNK_SIZE = 64
+
+# Enumerable wrapper for iterators returned by Rust functions.
+#
+# Items are fetched in chunks with `rust_iter_next_chunk`, which returns a serialized `Vec` that
+# `lift_chunk` converts into an array.  An empty chunk signals the end of the iterator.
+#
+# The Rust iterator is freed once the first iteration finishes, even if it was interrupted, or when
+# this object is garbage collected.
+class UniffiRustIterator
+  include Enumerable
+
+  def initialize(handle, lift_chunk)
+    @state = { handle: handle }
+    @lift_chunk = lift_chunk
+    ObjectSpace.define_finalizer(self, self.class.uniffi_define_finalizer(@state))
+  end
+
+  # N.B. the finalizer must not capture a reference to the iterator itself, otherwise it will never
+  # be garbage collected.
+  def self.uniffi_define_finalizer(state)
+    Proc.new { |_id| uniffi_free(state) }
+  end
+
+  def self.uniffi_free(state)
+    handle = state.delete(:handle)
+    UniFFILib.ffi_{{ crate_name }}_rust_iter_free(handle) unless handle.nil?
+  end
+
+  def each
+    return enum_for(:each) unless block_given?
+
+    begin
+      loop do
+        handle = @state[:handle]
+        break if handle.nil?
+
+        chunk = @lift_chunk.call(::{{ class_name }}.rust_call(
+          :ffi_{{ crate_name }}_rust_iter_next_chunk,
+          handle,
+          UNIFFI_RUST_ITER_CHUNK_SIZE
+        ))
+        break if chunk.empty?
+
+        chunk.each { |item| yield item }
+      end
+    ensure
+      self.class.uniffi_free(@state)
+    end
+  end
+end
@@ -0,0 +1,30 @@
+{# Shared template for generating static methods on Objects, Records and Enums.
+# The caller must bind `static_methods` in the scope before including this file, e.g.:
+#   {% let static_methods = rec.static_methods %}
+#   {% include "StaticMethodImpls.rb" %}
+#}
+{% for meth in static_methods -%}
+{%- let callable = meth.callable %}
+{%- if callable.is_async() %}
+def self.{{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+  {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+  {% call rb::to_ffi_call_async(callable) %}{% endcall %}
+end
+{%- else %}
+{%- match callable.return_type.ty -%}
+
+{%- when Some(return_type) %}
+def self.{{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+  {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+  result = {% call rb::to_ffi_call(callable) %}{% endcall %}
+  return {{ return_type.lift("result") }}
+end
+
+{%- when None %}
+def self.{{ callable.name }}({% call rb::arg_list_decl(callable) %}{% endcall %})
+  {%- call rb::setup_args_extra_indent(callable) %}{% endcall %}
+  {% call rb::to_ffi_call(callable) %}{% endcall %}
+end
+{%- endmatch %}
+{%- endif %}
+{% endfor %}
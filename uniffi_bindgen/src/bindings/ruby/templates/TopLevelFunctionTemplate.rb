@@ -1,25 +1,25 @@
-{%- if func.is_async() %}
+{%- if callable.is_async() %}
 
-def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) %}{% endcall -%})
-  {%- call rb::setup_args(func) %}{% endcall %}
-  {% call rb::to_ffi_call_async(func) %}{% endcall %}
+def self.{{ callable.name }}({%- call rb::arg_list_decl(callable) %}{% endcall -%})
+  {%- call rb::setup_args(callable) %}{% endcall %}
+  {% call rb::to_ffi_call_async(callable) %}{% endcall %}
 end
 {%- else %}
 
-{%- match func.return_type() -%}
-{%- when Some with (return_type) %}
+{%- match callable.return_type.ty -%}
+{%- when Some(return_type) %}
 
-def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) %}{% endcall -%})
-  {%- call rb::setup_args(func) %}{% endcall %}
-  result = {% call rb::to_ffi_call(func) %}{% endcall %}
-  return {{ "result"|lift_rb(return_type, config) }}
+def self.{{ callable.name }}({%- call rb::arg_list_decl(callable) %}{% endcall -%})
+  {%- call rb::setup_args(callable) %}{% endcall %}
+  result = {% call rb::to_ffi_call(callable) %}{% endcall %}
+  return {{ return_type.lift("result") }}
 end
 
 {% when None %}
 
-def self.{{ func.name()|fn_name_rb }}({%- call rb::arg_list_decl(func) %}{% endcall -%})
-  {%- call rb::setup_args(func) %}{% endcall %}
-  {% call rb::to_ffi_call(func) %}{% endcall %}
+def self.{{ callable.name }}({%- call rb::arg_list_decl(callable) %}{% endcall -%})
+  {%- call rb::setup_args(callable) %}{% endcall %}
+  {% call rb::to_ffi_call(callable) %}{% endcall %}
 end
 {% endmatch %}
 {%- endif %}
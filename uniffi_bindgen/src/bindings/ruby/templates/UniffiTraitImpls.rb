@@ -1,27 +1,27 @@
 {# Template to generate standard Rust trait method implementations for a Ruby classes.
 # Expects `trait_methods` to be a bound in the including template's scope.
-# (e.g. `{%- let trait_methods = obj.uniffi_trait_methods() %}`)
+# (e.g. `{%- let trait_methods = obj.uniffi_trait_methods %}`)
 #}
 
 {%- if let Some(display_fmt) = trait_methods.display_fmt %}
 # The Rust `Display::fmt` implementation.
 def to_s
-  result = ::{{ ci.namespace()|class_name_rb }}.rust_call(
-    :{{ display_fmt.ffi_func().name() }},
-    {{ display_fmt|lower_method_self_rb(config) }}
+  result = ::{{ class_name }}.rust_call(
+    :{{ display_fmt.callable.ffi_func.0 }},
+    {{ display_fmt.callable.lower_self() }}
   )
-  {{ "result"|lift_rb(display_fmt.return_type().unwrap(), config) }}
+  {{ display_fmt.callable.return_type.lift("result") }}
 end
 {%- endif %}
 
 {%- if let Some(debug_fmt) = trait_methods.debug_fmt %}
 # The Rust `Debug::fmt` implementation.
 def inspect
-  result = ::{{ ci.namespace()|class_name_rb }}.rust_call(
-    :{{ debug_fmt.ffi_func().name() }},
-    {{ debug_fmt|lower_method_self_rb(config) }}
+  result = ::{{ class_name }}.rust_call(
+    :{{ debug_fmt.callable.ffi_func.0 }},
+    {{ debug_fmt.callable.lower_self() }}
   )
-  {{ "result"|lift_rb(debug_fmt.return_type().unwrap(), config) }}
+  {{ debug_fmt.callable.return_type.lift("result") }}
 end
 {%- endif %}
 
@@ -29,23 +29,23 @@ end
 # The Rust `Eq::eq` implementation.
 def ==(other)
   return false unless other.is_a?(self.class)
-  result = ::{{ ci.namespace()|class_name_rb }}.rust_call(
-    :{{ eq.ffi_func().name() }},
-    {{ eq|lower_method_self_rb(config) }},
-    {{ "other"|lower_rb(eq.arguments()[0].as_type().borrow(), config) }}
+  result = ::{{ class_name }}.rust_call(
+    :{{ eq.callable.ffi_func.0 }},
+    {{ eq.callable.lower_self() }},
+    {{ eq.callable.arguments[0].ty.lower("other") }}
   )
-  {{ "result"|lift_rb(eq.return_type().unwrap(), config) }}
+  {{ eq.callable.return_type.lift("result") }}
 end
 {%- endif %}
 
 {%- if let Some(hash) = trait_methods.hash_hash %}
 # The Rust `Hash::hash` implementation.
 def hash
-  result = ::{{ ci.namespace()|class_name_rb }}.rust_call(
-    :{{ hash.ffi_func().name() }},
-    {{ hash|lower_method_self_rb(config) }}
+  result = ::{{ class_name }}.rust_call(
+    :{{ hash.callable.ffi_func.0 }},
+    {{ hash.callable.lower_self() }}
   )
-  {{ "result"|lift_rb(hash.return_type().unwrap(), config) }}
+  {{ hash.callable.return_type.lift("result") }}
 end
 
 def eql?(other)
@@ -60,13 +60,84 @@ include Comparable
 def <=>(other)
   # do we need this?
   # return nil unless other.is_a?(self.class)
-  result = ::{{ ci.namespace()|class_name_rb }}.rust_call(
-    :{{ cmp.ffi_func().name() }},
-    {{ cmp|lower_method_self_rb(config) }},
-    {{ "other"|lower_rb(cmp.arguments()[0].as_type().borrow(), config) }}
+  result = ::{{ class_name }}.rust_call(
+    :{{ cmp.callable.ffi_func.0 }},
+    {{ cmp.callable.lower_self() }},
+    {{ cmp.callable.arguments[0].ty.lower("other") }}
   )
-  {{ "result"|lift_rb(cmp.return_type().unwrap(), config) }}
+  {{ cmp.callable.return_type.lift("result") }}
 rescue
   nil
 end
 {%- endif %}
+
+{%- if let Some(partial_cmp) = trait_methods.partial_ord_partial_cmp %}
+# The Rust `PartialOrd::partial_cmp` implementation.  Returns `nil` if the values can't be compared.
+def partial_cmp(other)
+  result = ::{{ class_name }}.rust_call(
+    :{{ partial_cmp.callable.ffi_func.0 }},
+    {{ partial_cmp.callable.lower_self() }},
+    {{ partial_cmp.callable.arguments[0].ty.lower("other") }}
+  )
+  {{ partial_cmp.callable.return_type.lift("result") }}
+end
+{%- if trait_methods.ord_cmp.is_none() %}
+
+def <=>(other)
+  partial_cmp(other)
+end
+
+# `Comparable` would raise for values that can't be compared, so define the operators directly.
+def <(other)
+  cmp = partial_cmp(other)
+  !cmp.nil? && cmp < 0
+end
+
+def <=(other)
+  cmp = partial_cmp(other)
+  !cmp.nil? && cmp <= 0
+end
+
+def >(other)
+  cmp = partial_cmp(other)
+  !cmp.nil? && cmp > 0
+end
+
+def >=(other)
+  cmp = partial_cmp(other)
+  !cmp.nil? && cmp >= 0
+end
+{%- endif %}
+{%- endif %}
+
+{%- if let Some(clone) = trait_methods.clone_clone %}
+# Overrides `Object#clone` with the Rust `Clone` implementation, rather than a shallow Ruby copy.
+def clone
+  result = ::{{ class_name }}.rust_call(
+    :{{ clone.callable.ffi_func.0 }},
+    {{ clone.callable.lower_self() }}
+  )
+  {{ clone.callable.return_type.lift("result") }}
+end
+
+def dup
+  clone
+end
+{%- endif %}
+
+{%- if let Some(default) = trait_methods.default_default %}
+# The Rust `Default::default` implementation.
+def self.default
+  result = {% call rb::to_ffi_call(default.callable) %}{% endcall %}
+  {{ default.callable.return_type.lift("result") }}
+end
+{%- endif %}
+
+{%- if let Some(from_str) = trait_methods.from_str_from_str %}
+# The Rust `FromStr::from_str` implementation.
+def self.parse(s)
+  {%- call rb::setup_args_extra_indent(from_str.callable) %}{% endcall %}
+  result = {% call rb::to_ffi_call(from_str.callable) %}{% endcall %}
+  {{ from_str.callable.return_type.lift("result") }}
+end
+{%- endif %}
@@ -1,79 +1,58 @@
 {#
 // Template to call into rust. Used in several places.
 // Variable names in `arg_list_decl` should match up with arg lists
-// passed to rust via `_arg_list_ffi_call` (we use  `var_name_rb` in `lower_rb`)
+// passed to rust via `_arg_list_ffi_call`
 #}
 
-{#
-// Returns the Ruby name for an enum variant field.
-// For tuple-style fields (empty name is uniffi metadata) we generate a 
-// positional name like v1, v2, ... using the 1-based loop index.
-#}
-{%- macro field_name(field, field_num) -%}
-{%- if field.name().is_empty() -%}
-values[{{- field_num - 1 -}}]
-{%- else -%}
-{{ field.name()|var_name_rb }}
-{%- endif -%}
-{%- endmacro -%}
-
 {#- Helper: emit the opening of a rust_call or rust_call_with_error call. -#}
-{%- macro rust_call_head(func) -%}
-    {%- match func.throws_type() -%}
-    {%- when Some(Type::Custom { builtin, .. }) -%}
-      {%- match builtin.borrow() -%}
-      {%- when Type::Enum { name, .. } | Type::Object { name, .. } -%}
-      ::{{ ci.namespace()|class_name_rb }}.rust_call_with_error({{ name|class_name_rb }},
-      {%- else -%}
-      ::{{ ci.namespace()|class_name_rb }}.rust_call
-      {%- endmatch -%}
-    {%- when Some(Type::Enum { name, .. }) | Some(Type::Object { name, .. }) -%}
-      ::{{ ci.namespace()|class_name_rb }}.rust_call_with_error({{ name|class_name_rb }},
-    {%- else -%}
-      ::{{ ci.namespace()|class_name_rb }}.rust_call(
+{%- macro rust_call_head(callable) -%}
+    {%- match callable.throws_type.error -%}
+    {%- when Some(error) -%}
+      ::{{ class_name }}.rust_call_with_error({{ error.name }},
+    {%- when None -%}
+      ::{{ class_name }}.rust_call(
     {%- endmatch -%}
 {%- endmacro -%}
-  
-{%- macro to_ffi_call(func) -%}
-    {%- call rust_call_head(func) %}{% endcall -%}
-    :{{ func.ffi_func().name() }},
-    {%- call _arg_list_ffi_call(func) %}{% endcall -%}
+
+{%- macro to_ffi_call(callable) -%}
+    {%- call rust_call_head(callable) %}{% endcall -%}
+    :{{ callable.ffi_func.0 }},
+    {%- call _arg_list_ffi_call(callable) %}{% endcall -%}
 )
 {%- endmacro -%}
 
-{%- macro to_ffi_call_with_prefix(prefix, func) -%}
-    {%- call rust_call_head(func) %}{% endcall -%}
-    :{{ func.ffi_func().name() }},
+{%- macro to_ffi_call_with_prefix(prefix, callable) -%}
+    {%- call rust_call_head(callable) %}{% endcall -%}
+    :{{ callable.ffi_func.0 }},
     {{- prefix }},
-    {%- call _arg_list_ffi_call(func) %}{% endcall -%}
+    {%- call _arg_list_ffi_call(callable) %}{% endcall -%}
 )
 {%- endmacro -%}
 
-{%- macro to_ffi_call_with_lower_self(func) -%}
-    {%- call rust_call_head(func) %}{% endcall -%}
-    :{{ func.ffi_func().name() }},
-    {{ func|lower_method_self_rb(config) }},
-    {%- call _arg_list_ffi_call(func) %}{% endcall -%}
+{%- macro to_ffi_call_with_lower_self(callable) -%}
+    {%- call rust_call_head(callable) %}{% endcall -%}
+    :{{ callable.ffi_func.0 }},
+    {{ callable.lower_self() }},
+    {%- call _arg_list_ffi_call(callable) %}{% endcall -%}
 )
 {%- endmacro -%}
 
-{%- macro _arg_list_ffi_call(func) %}
-    {%- for arg in func.arguments() %}
-        {{- arg.name()|var_name_rb|lower_rb(arg.as_type().borrow(), config) }}
+{%- macro _arg_list_ffi_call(callable) %}
+    {%- for arg in callable.arguments %}
+        {{- arg.ty.lower(arg.name.as_str()) }}
         {%- if !loop.last %},{% endif %}
     {%- endfor %}
 {%- endmacro -%}
 
 {#-
 // Arglist as used in Ruby declarations of methods, functions and constructors.
-// Note the var_name_rb and type_rb filters.
 -#}
 
-{% macro arg_list_decl(func) %}
-    {%- for arg in func.arguments() -%}
-        {{ arg.name()|var_name_rb }}
-        {%- match arg.default_value() %}
-        {%- when Some(_) %} = {{ arg|arg_default_rb }}
+{% macro arg_list_decl(callable) %}
+    {%- for arg in callable.arguments -%}
+        {{ arg.name }}
+        {%- match arg.default %}
+        {%- when Some(default) %} = {{ default }}
         {%- else %}
         {%- endmatch %}
         {%- if !loop.last %}, {% endif -%}
@@ -82,81 +61,78 @@ values[{{- field_num - 1 -}}]
 
 {#-
 // Arglist as used in the UniFFILib function declarations.
-// Note unfiltered name but type_ffi filters.
+// Note unfiltered name but Ruby-FFI types.
 -#}
 {%- macro arg_list_ffi_decl(func) %}
-    [{%- for arg in func.arguments() -%}{{ arg.type_().borrow()|type_ffi }}, {% endfor -%}
-    {%- if func.has_rust_call_status_arg() -%}RustCallStatus.by_ref{% endif -%}]
+    [{%- for arg in func.arguments -%}{{ arg.ty.type_name }}, {% endfor -%}
+    {%- if func.has_rust_call_status_arg -%}RustCallStatus.by_ref{% endif -%}]
 {%- endmacro -%}
 
 {#- Helper: emit the error class name or nil for async rust calls. -#}
-{%- macro throws_error_class_expr(func) %}
-    {%- match func.throws_type() %}
-    {%- when Some(Type::Custom { builtin, .. }) %}
-      {%- match builtin.borrow() %}
-      {%- when Type::Enum { name, .. } | Type::Object { name, .. } %}
-    {{ name|class_name_rb }}
-      {%- else %}
-    nil
-      {%- endmatch %}
-    {%- when Some(Type::Enum { name, .. }) | Some(Type::Object { name, .. }) %}
-    {{ name|class_name_rb }}
-    {%- else %}
+{%- macro throws_error_class_expr(callable) %}
+    {%- match callable.throws_type.error %}
+    {%- when Some(error) %}
+    {{ error.name }}
+    {%- when None %}
     nil
     {%- endmatch %}
 {%- endmacro %}
 
-{%- macro to_ffi_call_async(func, prefix = "") -%}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_rust_call_async(
-      UniFFILib.{{ func.ffi_func().name() }}(
+{%- macro to_ffi_call_async(callable, prefix = "") -%}
+    {%- if let Some(async_data) = callable.async_data %}
+    ::{{ class_name }}.uniffi_rust_call_async(
+      UniFFILib.{{ callable.ffi_func.0 }}(
         {%- if !prefix.is_empty() %}{{- prefix }},{% endif %}
-        {%- call _arg_list_ffi_call(func) %}{% endcall -%}
+        {%- call _arg_list_ffi_call(callable) %}{% endcall -%}
       ),
-      :{{ func.ffi_rust_future_poll(ci) }},
-      :{{ func.ffi_rust_future_cancel(ci) }},
-      :{{ func.ffi_rust_future_complete(ci) }},
-      :{{ func.ffi_rust_future_free(ci) }},
-      {%- match func.return_type() %}
-      {%- when Some with (return_type) %}
-      Proc.new { |v| {{ "v"|lift_rb(return_type, config) }} },
+      :{{ async_data.ffi_rust_future_poll.0 }},
+      :{{ async_data.ffi_rust_future_cancel.0 }},
+      :{{ async_data.ffi_rust_future_complete.0 }},
+      :{{ async_data.ffi_rust_future_free.0 }},
+      {%- match callable.return_type.ty %}
+      {%- when Some(return_type) %}
+      Proc.new { |v| {{ return_type.lift("v") }} },
       {%- when None %}
       Proc.new { |v| nil },
       {%- endmatch %}
-      {%- call throws_error_class_expr(func) %}{% endcall %}
+      {%- call throws_error_class_expr(callable) %}{% endcall %}
     )
+    {%- endif %}
 {%- endmacro %}
 
 {#- Async constructor variant: uses identity lift to return raw handle for uniffi_allocate -#}
-{%- macro to_ffi_call_async_constructor(func) %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_rust_call_async(
-      UniFFILib.{{ func.ffi_func().name() }}(
-        {%- call _arg_list_ffi_call(func) %}{% endcall -%}
+{%- macro to_ffi_call_async_constructor(callable) %}
+    {%- if let Some(async_data) = callable.async_data %}
+    ::{{ class_name }}.uniffi_rust_call_async(
+      UniFFILib.{{ callable.ffi_func.0 }}(
+        {%- call _arg_list_ffi_call(callable) %}{% endcall -%}
       ),
-      :{{ func.ffi_rust_future_poll(ci) }},
-      :{{ func.ffi_rust_future_cancel(ci) }},
-      :{{ func.ffi_rust_future_complete(ci) }},
-      :{{ func.ffi_rust_future_free(ci) }},
+      :{{ async_data.ffi_rust_future_poll.0 }},
+      :{{ async_data.ffi_rust_future_cancel.0 }},
+      :{{ async_data.ffi_rust_future_complete.0 }},
+      :{{ async_data.ffi_rust_future_free.0 }},
       Proc.new { |v| v },
-      {%- call throws_error_class_expr(func) %}{% endcall %}
+      {%- call throws_error_class_expr(callable) %}{% endcall %}
     )
+    {%- endif %}
 {%- endmacro %}
 
 {#- Thin wrapper: delegates to to_ffi_call_async with an explicit prefix. -#}
-{%- macro to_ffi_call_with_prefix_async(prefix, func) -%}
-    {%- call to_ffi_call_async(func, prefix) %}{% endcall %}
+{%- macro to_ffi_call_with_prefix_async(prefix, callable) -%}
+    {%- call to_ffi_call_async(callable, prefix) %}{% endcall %}
 {%- endmacro %}
 
-{%- macro setup_args(func) %}
-    {%- for arg in func.arguments() %}
-    {{ arg.name()|var_name_rb }} = {{ arg.name()|var_name_rb|coerce_rb(ci.namespace()|class_name_rb, arg.as_type().borrow(), config) }}
-    {{ arg.name()|var_name_rb|check_lower_rb(arg.as_type().borrow(), config) }}
+{%- macro setup_args(callable) %}
+    {%- for arg in callable.arguments %}
+    {{ arg.name }} = {{ arg.ty.coerce(arg.name.as_str()) }}
+    {{ arg.ty.check_lower(arg.name.as_str()) }}
     {% endfor -%}
 {%- endmacro -%}
 
-{%- macro setup_args_extra_indent(meth) %}
-        {%- for arg in meth.arguments() %}
-        {{ arg.name()|var_name_rb }} = {{ arg.name()|var_name_rb|coerce_rb(ci.namespace()|class_name_rb, arg.as_type().borrow(), config) }}
-        {{ arg.name()|var_name_rb|check_lower_rb(arg.as_type().borrow(), config) }}
+{%- macro setup_args_extra_indent(callable) %}
+        {%- for arg in callable.arguments %}
+        {{ arg.name }} = {{ arg.ty.coerce(arg.name.as_str()) }}
+        {{ arg.ty.check_lower(arg.name.as_str()) }}
         {%- endfor %}
 {%- endmacro -%}
 
@@ -164,11 +140,11 @@ values[{{- field_num - 1 -}}]
 // Build the `make_call` Proc for callback/trait-interface methods (sync and async).
 // Requires `uniffi_obj` to be in caller scope.
 -#}
-{%- macro make_call_proc(method) %}
+{%- macro make_call_proc(callable) %}
     make_call = Proc.new do
-      uniffi_obj.{{ method.name()|fn_name_rb }}(
-        {%- for arg in method.arguments() %}
-        {{ arg.name()|lift_rb(arg.as_type().borrow(), config) }}{% if !loop.last %},{% endif %}
+      uniffi_obj.{{ callable.name }}(
+        {%- for arg in callable.arguments %}
+        {{ arg.ty.lift(arg.name.as_str()) }}{% if !loop.last %},{% endif %}
         {%- endfor %}
       )
     end
@@ -178,30 +154,33 @@ values[{{- field_num - 1 -}}]
 // Build the `handle_success` Proc for async callback/trait-interface methods.
 // Requires `uniffi_future_callback` and `uniffi_callback_data` in the caller scope.
 -#}
-{%- macro async_handle_success_proc(method) %}
+{%- macro async_handle_success_proc(callable) %}
+    {%- if let Some(async_data) = callable.async_data %}
     handle_success = Proc.new do |return_value|
-      result_struct = UniFFILib::{{ method|foreign_future_result_rb }}.new
-      {%- match method.return_type() %}
-      {%- when Some with (return_type) %}
-      result_struct[:return_value] = {{ "return_value"|lower_rb(return_type, config) }}
+      result_struct = UniFFILib::{{ async_data.ffi_foreign_future_result.0 }}.new
+      {%- match callable.return_type.ty %}
+      {%- when Some(return_type) %}
+      result_struct[:return_value] = {{ return_type.lower("return_value") }}
       result_struct[:call_status] = RustCallStatus.new
       {%- when None %}
       result_struct[:call_status] = RustCallStatus.new
       {%- endmatch %}
       uniffi_future_callback.call(uniffi_callback_data, result_struct)
     end
+    {%- endif %}
 {%- endmacro %}
 
 {#-
 // Build the `handle_error` Proc for async callback/trait-interface methods.
 // Requires `uniffi_future_callback` and `uniffi_callback_data` in the caller scope.
 -#}
-{%- macro async_handle_error_proc(method) %}
+{%- macro async_handle_error_proc(vtable_meth) %}
+    {%- if let Some(async_data) = vtable_meth.callable.async_data %}
     handle_error = Proc.new do |status_code, error_buf|
-      result_struct = UniFFILib::{{ method|foreign_future_result_rb }}.new
-      {%- match method.return_type() %}
-      {%- when Some with (return_type) %}
-      result_struct[:return_value] = {{ return_type|ffi_default_value_rb }}
+      result_struct = UniFFILib::{{ async_data.ffi_foreign_future_result.0 }}.new
+      {%- match vtable_meth.ffi_default_value %}
+      {%- when Some(ffi_default_value) %}
+      result_struct[:return_value] = {{ ffi_default_value }}
       {%- when None %}
       {%- endmatch %}
 
@@ -213,6 +192,7 @@ values[{{- field_num - 1 -}}]
 
       uniffi_future_callback.call(uniffi_callback_data, result_struct)
     end
+    {%- endif %}
 {%- endmacro %}
 
 
@@ -220,21 +200,21 @@ values[{{- field_num - 1 -}}]
 // Build the `write_return_value` Proc for sync callback/trait-interface methods.
 // Requires `uniffi_out_return` in the caller scope.
 -#}
-{%- macro write_return_value_proc(method) %}
-    {%- match method.return_type() %}
-    {%- when Some with (return_type) %}
+{%- macro write_return_value_proc(vtable_meth) %}
+    {%- match vtable_meth.callable.return_type.ty %}
+    {%- when Some(return_type) %}
     write_return_value = Proc.new do |v|
-      lowered = {{ "v"|lower_rb(return_type, config) }}
-      {%- let ffi_type_name = return_type|ffi_write_return_rb %}
-      {%- if ffi_type_name == "rustbuffer" %}
+      lowered = {{ return_type.lower("v") }}
+      {%- match vtable_meth.ffi_write_method %}
+      {%- when Some(ffi_write_method) %}
+      uniffi_out_return.{{ ffi_write_method }}(lowered)
+      {%- when None %}
       # Write a RustBuffer struct into the out pointer
       out_buf = RustBuffer.new uniffi_out_return
       out_buf[:capacity] = lowered[:capacity]
       out_buf[:len] = lowered[:len]
       out_buf[:data] = lowered[:data]
-      {%- else %}
-      uniffi_out_return.{{ ffi_type_name }}(lowered)
-      {%- endif %}
+      {%- endmatch %}
     end
     {%- when None %}
     # No return value, so do nothing
@@ -246,97 +226,46 @@ values[{{- field_num - 1 -}}]
 // Dispatch the throws type for a sync callback/trait-interface method.
 // Caller must have in scope: uniffi_call_status, make_call, write_return_value.
 -#}
-{%- macro sync_throws_dispatch(method) %}
-    {%- match method.throws_type() %}
-    {%- when None %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call(
-      uniffi_call_status,
-      make_call,
-      write_return_value,
-    )
-    {%- when Some with (error_type) %}
-    {%- match error_type %}
-    {%- when Type::Enum { name, .. } | Type::Object { name, .. } %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call(
+{%- macro sync_throws_dispatch(callable) %}
+    {%- match callable.throws_type.error %}
+    {%- when Some(error) %}
+    ::{{ class_name }}.uniffi_trait_interface_call(
       uniffi_call_status,
       make_call,
       write_return_value,
-      {{ name|class_name_rb }},
-      Proc.new { |e| {{ "e"|lower_rb(error_type, config) }} }
+      {{ error.name }},
+      Proc.new { |e| {{ error.lower_expr }} }
     )
-    {%- when Type::Custom { builtin, .. } %}
-    {%- match builtin.borrow() %}
-    {%- when Type::Enum { name, .. } | Type::Object { name, .. } %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call(
+    {%- when None %}
+    ::{{ class_name }}.uniffi_trait_interface_call(
       uniffi_call_status,
       make_call,
       write_return_value,
-      {{ name|class_name_rb }},
-      Proc.new { |e| {{ "e"|lower_rb(builtin, config) }} }
-    )
-    {%- else %}
-    raise RuntimeError, "Unsupported custom error type"
-    {%- endmatch %}
-    {%- else %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call(
-      uniffi_call_status,
-      make_call,
-      write_return_value
     )
     {%- endmatch %}
-    {%- endmatch %}
 {%- endmacro %}
 
 {#-
 // Dispatch the throws type for an async callback/trait-interface method.
 // Caller must have in scope: make_call, uniffi_out_dropped_callback, handle_success, handle_error.
 -#}
-{%- macro async_throws_dispatch(method) %}
-    {%- match method.throws_type() %}
-    {%- when None %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call_async(
-      make_call,
-      uniffi_out_dropped_callback,
-      handle_success,
-      handle_error,
-    )
-    {%- when Some with (error_type) %}
-    {%- match error_type %}
-    {%- when Type::Enum { name, .. } | Type::Object { name, .. } %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call_async(
-      make_call,
-      uniffi_out_dropped_callback,
-      handle_success,
-      handle_error,
-      {{ name|class_name_rb }},
-      Proc.new { |e| {{ "e"|lower_rb(error_type, config) }} }
-    )
-    {%- when Type::Custom { builtin, .. } %}
-    {%- match builtin.borrow() %}
-    {%- when Type::Enum { name, .. } | Type::Object { name, .. } %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call_async(
-      make_call,
-      uniffi_out_dropped_callback,
-      handle_success,
-      handle_error,
-      {{ name|class_name_rb }},
-      Proc.new { |e| {{ "e"|lower_rb(builtin, config) }} }
-    )
-    {%- else %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call_async(
+{%- macro async_throws_dispatch(callable) %}
+    {%- match callable.throws_type.error %}
+    {%- when Some(error) %}
+    ::{{ class_name }}.uniffi_trait_interface_call_async(
       make_call,
       uniffi_out_dropped_callback,
       handle_success,
       handle_error,
+      {{ error.name }},
+      Proc.new { |e| {{ error.lower_expr }} }
     )
-    {%- endmatch %}
-    {%- else %}
-    ::{{ ci.namespace()|class_name_rb }}.uniffi_trait_interface_call_async(
+    {%- when None %}
+    ::{{ class_name }}.uniffi_trait_interface_call_async(
       make_call,
       uniffi_out_dropped_callback,
       handle_success,
       handle_error,
     )
     {%- endmatch %}
-    {%- endmatch %}
 {%- endmacro %}
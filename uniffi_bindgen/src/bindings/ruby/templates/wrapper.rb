@@ -16,17 +16,14 @@
 require 'ffi'
 require 'set'
 
-{%- if ci.has_callback_definitions() || ci.has_async_fns() %}
+{%- if has_callback_definitions || has_async_fns || has_closure_types %}
 require 'monitor'
 {%- endif %}
 
-module {{ ci.namespace()|class_name_rb }}
+module {{ class_name }}
   {% include "Helpers.rb" %}
 
-  {%- if ci.has_callback_definitions() %}
-  {% include "HandleMap.rb" %}
-  {%- endif %}
-  {%- if ci.has_async_fns() && !ci.has_callback_definitions() %}
+  {%- if has_callback_definitions || has_async_fns || has_closure_types %}
   {% include "HandleMap.rb" %}
   {%- endif %}
   {% include "RustBufferTemplate.rb" %}
@@ -37,33 +34,33 @@ module {{ ci.namespace()|class_name_rb }}
   {% include "ErrorTemplate.rb" %}
 
   {% include "NamespaceLibraryTemplate.rb" %}
-  {%- if ci.has_async_fns() %}
+  {%- if has_async_fns %}
   {% include "Async.rb" %}
   {%- endif %}
-  {%- if ci.has_callback_definitions() %}
+  {%- if has_iterator_types %}
+  {% include "RustIterator.rb" %}
+  {%- endif %}
+  {%- if has_closure_types %}
+  {% include "RustClosure.rb" %}
+  {%- endif %}
+  {%- if has_callback_definitions %}
   {% include "CallbackInterfaceRuntime.rb" %}
   {%- endif %}
 
   # Custom type definitions.
 
-  {%- for type_ in ci.iter_local_types() -%}
-  {%- match type_ -%}
-  {%- when Type::Custom { name, builtin, .. } -%}
-  {%- if !ci.is_external(type_) %}
+  {%- for type_def in type_definitions -%}
+  {%- match type_def -%}
+  {%- when TypeDefinition::Custom(custom) %}
   {% include "CustomTypeTemplate.rb" %}
-  {%- else -%}
-  {%- match config.custom_types.get(name.as_str()) %}
-  {%- when Some(cfg) %}
-  {%- match cfg.imports %}
-  {%- when Some(imports) %}
-  # External custom type `{{ name }}`: importing configured dependencies.
+  {%- when TypeDefinition::External(ext) -%}
+  {%- if let Some(custom_config) = ext.custom_config %}
+  {%- if let Some(imports) = custom_config.imports %}
+  # External custom type `{{ ext.name }}`: importing configured dependencies.
   {%- for import_name in imports %}
   require '{{ import_name }}'
   {%- endfor %}
-  {%- when None %}
-  {%- endmatch %}
-  {%- when None %}
-  {%- endmatch %}
+  {%- endif %}
   {%- endif %}
   {%- else -%}
   {%- endmatch %}
@@ -71,28 +68,40 @@ module {{ ci.namespace()|class_name_rb }}
 
   # Public interface members begin here.
 
-  {% for e in ci.enum_definitions() %}
-  {% if !ci.is_name_used_as_error(e.name()) %}
+  {% for type_def in type_definitions %}
+  {%- if let TypeDefinition::Enum(e) = type_def %}
+  {%- if !e.self_type.is_used_as_error %}
   {% include "EnumTemplate.rb" %}
-  {% endif %}
+  {%- endif %}
+  {%- endif %}
   {%- endfor -%}
 
-  {%- for rec in ci.record_definitions() %}
+  {%- for type_def in type_definitions %}
+  {%- if let TypeDefinition::Record(rec) = type_def %}
   {% include "RecordTemplate.rb" %}
-  {% endfor %}
+  {%- endif %}
+  {%- endfor %}
 
-  {% for func in ci.function_definitions() %}
+  {% for func in functions %}
+  {%- let callable = func.callable %}
   {% include "TopLevelFunctionTemplate.rb" %}
   {% endfor %}
 
-  {% for obj in ci.object_definitions() %}
+  {%- for constant in constants %}
+  {{ constant.name }} = {{ constant.value }}
+  {%- endfor %}
+
+  {% for type_def in type_definitions %}
+  {%- if let TypeDefinition::Interface(obj) = type_def %}
   {% include "ObjectTemplate.rb" %}
-  {% endfor %}
+  {%- endif %}
+  {%- endfor %}
 
-  {% for cbi in ci.callback_interface_definitions() %}
-  {%- let name = cbi.name() %}
+  {% for type_def in type_definitions %}
+  {%- if let TypeDefinition::CallbackInterface(cbi) = type_def %}
   {% include "CallbackInterfaceTemplate.rb" %}
-  {% endfor %}
+  {%- endif %}
+  {%- endfor %}
 end
 
 {% import "macros.rb" as rb %}
@@ -4,8 +4,8 @@
 
 //! # Swift bindings backend for UniFFI
 //!
-//! This module generates Swift bindings from a [`crate::ComponentInterface`] definition,
-//! using Swift's builtin support for loading C header files.
+//! This module generates Swift bindings from the bindings IR [`pipeline`], using Swift's builtin
+//! support for loading C header files.
 //!
 //! Conceptually, the generated bindings are split into two Swift modules, one for the low-level
 //! C FFI layer and one for the higher-level Swift bindings. For a UniFFI component named "example"
@@ -17,7 +17,7 @@
 //!   * A Swift source file `example.swift` that imports the `exampleFFI` module and wraps it
 //!     to provide the higher-level Swift API.
 //!
-//! Most of the concepts in a [`crate::ComponentInterface`] have an obvious counterpart in Swift,
+//! Most of the concepts in the bindings IR have an obvious counterpart in Swift,
 //! with the details documented in inline comments where appropriate.
 //!
 //! To handle lifting/lowering/serializing types across the FFI boundary, the Swift code
@@ -29,107 +29,90 @@
 //!  * How to read from and write into a byte buffer.
 //!
 
-use crate::{
-    bindings::GenerateOptions,
-    interface::{apply_exclusions, rename},
-    BindgenLoader, BindgenPaths, Component, ComponentInterface, GlobalConfig,
-};
-use anyhow::{bail, Result};
-use camino::Utf8PathBuf;
+use crate::{bindings::GenerateOptions, BindgenLoader, BindgenPaths, GlobalConfig};
+use anyhow::{bail, Context, Result};
+use askama::Template;
+use camino::{Utf8Path, Utf8PathBuf};
 use fs_err as fs;
-use std::collections::HashMap;
 use std::process::Command;
 
-mod gen_swift;
-use gen_swift::{generate_bindings, generate_header, generate_modulemap, generate_swift, Config};
+pub mod filters;
+mod pipeline;
+pub use pipeline::{pipeline, Root};
+use pipeline::{BridgingHeader, Module, ModuleMap};
 
 #[cfg(feature = "bindgen-tests")]
 pub mod test;
 
-/// The Swift bindings generated from a [`crate::ComponentInterface`].
-///
-struct Bindings {
-    /// The contents of the generated `.swift` file, as a string.
-    library: String,
-    /// The contents of the generated `.h` file, as a string.
-    header: String,
-    /// The contents of the generated `.modulemap` file, as a string.
-    modulemap: Option<String>,
-}
-
 /// Generate Swift bindings
 ///
-/// Returns the components generated
-pub fn generate(
-    loader: &BindgenLoader,
-    options: GenerateOptions,
-) -> Result<Vec<Component<Config>>> {
+/// Returns the generated modules
+pub fn generate(loader: &BindgenLoader, options: GenerateOptions) -> Result<Vec<Module>> {
     let metadata = loader.load_metadata(&options.source)?;
     if let Some(crate_filter) = &options.crate_filter {
         if !metadata.contains_key(crate_filter) {
             bail!("No UniFFI metadata found for crate {crate_filter}");
         }
     }
-    let cis = loader.load_cis(metadata)?;
-    let mut components = loader.load_components(cis, parse_config)?;
-    apply_renames(&mut components);
-    for c in components.iter_mut() {
-        // Call derive_ffi_functions after `apply_renames`
-        c.ci.derive_ffi_funcs()?;
-    }
+    let root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    let swift_root = pipeline().execute(root)?;
 
-    for Component { ci, config, .. } in components.iter_mut() {
+    for module in swift_root.modules.values() {
         if let Some(crate_filter) = &options.crate_filter {
-            if ci.crate_name() != crate_filter {
+            if &module.crate_name != crate_filter {
                 continue;
             }
         }
-        let Bindings {
-            header,
-            library,
-            modulemap,
-        } = generate_bindings(config, ci)?;
-
         let source_file = options
             .out_dir
-            .join(format!("{}.swift", config.module_name()));
-        fs::write(&source_file, library)?;
-
-        let header_file = options.out_dir.join(config.header_filename());
-        fs::write(header_file, header)?;
-
-        if let Some(modulemap) = modulemap {
-            let modulemap_file = options.out_dir.join(config.modulemap_filename());
+            .join(format!("{}.swift", module.module_name));
+        fs::write(&source_file, render_swift(module)?)?;
+
+        let header_file = options.out_dir.join(&module.header_filename);
+        fs::write(header_file, render_header(module)?)?;
+
+        if module.generate_module_map {
+            let modulemap = render_modulemap(
+                module.ffi_module_name.clone(),
+                vec![module.header_filename.clone()],
+                false,
+                module.link_frameworks.clone(),
+            )?;
+            let modulemap_file = options.out_dir.join(&module.modulemap_filename);
             fs::write(modulemap_file, modulemap)?;
         }
 
         if options.format {
-            let commands_to_try = [
-                // Available in Xcode 16.
-                vec!["xcrun", "swift-format"],
-                // The official swift-format command name.
-                vec!["swift-format"],
-                // Shortcut for the swift-format command.
-                vec!["swift", "format"],
-                vec!["swiftformat"],
-            ];
-
-            let successful_output = commands_to_try.into_iter().find_map(|command| {
-                Command::new(command[0])
-                    .args(&command[1..])
-                    .arg(source_file.as_str())
-                    .output()
-                    .ok()
-            });
-            if successful_output.is_none() {
-                println!(
-                    "Warning: Unable to auto-format {} using swift-format. Please make sure it is installed.",
-                    source_file.as_str()
-                );
-            }
+            format_source(&source_file);
         }
     }
-    Ok(components)
+    Ok(swift_root.modules.into_values().collect())
+}
+
+fn format_source(source_file: &Utf8Path) {
+    let commands_to_try = [
+        // Available in Xcode 16.
+        vec!["xcrun", "swift-format"],
+        // The official swift-format command name.
+        vec!["swift-format"],
+        // Shortcut for the swift-format command.
+        vec!["swift", "format"],
+        vec!["swiftformat"],
+    ];
+
+    let successful_output = commands_to_try.into_iter().find_map(|command| {
+        Command::new(command[0])
+            .args(&command[1..])
+            .arg(source_file.as_str())
+            .output()
+            .ok()
+    });
+    if successful_output.is_none() {
+        println!(
+            "Warning: Unable to auto-format {} using swift-format. Please make sure it is installed.",
+            source_file.as_str()
+        );
+    }
 }
 
 /// Generate Swift bindings (specialized version)
@@ -162,25 +145,20 @@ pub fn generate_swift_bindings(options: SwiftBindingsOptions) -> Result<()> {
 
     let loader = BindgenLoader::new(paths, global_config);
     let metadata = loader.load_metadata(&options.source)?;
-    let cis = loader.load_cis(metadata)?;
-    let mut components = loader.load_components(cis, parse_config)?;
-    apply_renames(&mut components);
-    // Call derive_ffi_funcs after apply_renames()
-    for Component { ci, .. } in components.iter_mut() {
-        ci.derive_ffi_funcs()?;
-    }
+    let root = loader.load_pipeline_initial_root(&options.source, metadata)?;
+    let swift_root = pipeline().execute(root)?;
 
-    for Component { ci, config } in &components {
+    for module in swift_root.modules.values() {
         if options.generate_swift_sources {
             let source_file = options
                 .out_dir
-                .join(format!("{}.swift", config.module_name()));
-            fs::write(&source_file, generate_swift(config, ci)?)?;
+                .join(format!("{}.swift", module.module_name));
+            fs::write(&source_file, render_swift(module)?)?;
         }
 
         if options.generate_headers {
-            let header_file = options.out_dir.join(config.header_filename());
-            fs::write(header_file, generate_header(config, ci)?)?;
+            let header_file = options.out_dir.join(&module.header_filename);
+            fs::write(header_file, render_header(module)?)?;
         }
     }
 
@@ -195,12 +173,13 @@ pub fn generate_swift_bindings(options: SwiftBindingsOptions) -> Result<()> {
         .unwrap_or_else(|| format!("{source_basename}.modulemap"));
 
     if options.generate_modulemap {
-        let mut header_filenames: Vec<_> = components
-            .iter()
-            .map(|Component { config, .. }| config.header_filename())
+        let mut header_filenames: Vec<_> = swift_root
+            .modules
+            .values()
+            .map(|module| module.header_filename.clone())
             .collect();
         header_filenames.sort();
-        let modulemap_source = generate_modulemap(
+        let modulemap_source = render_modulemap(
             module_name,
             header_filenames,
             options.xcframework,
@@ -213,15 +192,33 @@ pub fn generate_swift_bindings(options: SwiftBindingsOptions) -> Result<()> {
     Ok(())
 }
 
-fn parse_config(ci: &ComponentInterface, root_toml: toml::Value) -> Result<Config> {
-    let mut config: Config = match root_toml.get("bindings").and_then(|b| b.get("swift")) {
-        Some(v) => v.clone().try_into()?,
-        None => Default::default(),
-    };
-    config
-        .module_name
-        .get_or_insert_with(|| ci.namespace().into());
-    Ok(config)
+/// Render the Swift source for a module
+fn render_swift(module: &Module) -> Result<String> {
+    module.render().context("failed to render Swift library")
+}
+
+/// Render the bridging header for a module
+fn render_header(module: &Module) -> Result<String> {
+    BridgingHeader { module }
+        .render()
+        .context("failed to render Swift bridging header")
+}
+
+/// Render a modulemap for the bridging headers of one or more modules
+fn render_modulemap(
+    module_name: String,
+    header_filenames: Vec<String>,
+    xcframework: bool,
+    link_frameworks: Vec<String>,
+) -> Result<String> {
+    ModuleMap {
+        module_name,
+        header_filenames,
+        xcframework,
+        link_frameworks,
+    }
+    .render()
+    .context("failed to render Swift modulemap")
 }
 
 #[derive(Debug, Default)]
@@ -238,27 +235,3 @@ pub struct SwiftBindingsOptions {
     pub link_frameworks: Vec<String>,
     pub config: Option<Utf8PathBuf>,
 }
-
-// A helper for renaming items.
-fn apply_renames(components: &mut Vec<Component<Config>>) {
-    // Remove excluded items, this happens before renaming
-    for c in components.iter_mut() {
-        apply_exclusions(&mut c.ci, &c.config.exclude);
-    }
-
-    let mut module_renames = HashMap::new();
-    // Collect all rename configurations from all components, keyed by module_path
-    for c in components.iter() {
-        if !c.config.rename.is_empty() {
-            let module_path = c.ci.crate_name().to_string();
-            module_renames.insert(module_path, c.config.rename.clone());
-        }
-    }
-
-    // Apply rename configurations to all components
-    if !module_renames.is_empty() {
-        for c in &mut *components {
-            rename(&mut c.ci, &module_renames);
-        }
-    }
-}
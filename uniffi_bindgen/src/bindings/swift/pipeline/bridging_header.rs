@@ -0,0 +1,607 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! FFI definitions for the bridging header
+//!
+//! The header declares every builtin FFI definition, whether or not the module uses it, followed
+//! by the callback function types, the VTable structs and finally the FFI functions.  This way
+//! the header only changes when the exported API changes.
+
+use std::collections::HashMap;
+
+use heck::ToUpperCamelCase;
+
+use super::modules::UserTypes;
+use super::*;
+use general::{
+    FfiArgument, FfiField, FfiFunctionKind, FfiFunctionTypeName, FfiReturnType, FfiStructName,
+    FfiType,
+};
+
+/// FFI return types that have a `ForeignFutureResult` struct and `rust_future` functions
+const RETURN_TYPES: [(Option<FfiType>, &str); 12] = [
+    (Some(FfiType::UInt8), "u8"),
+    (Some(FfiType::Int8), "i8"),
+    (Some(FfiType::UInt16), "u16"),
+    (Some(FfiType::Int16), "i16"),
+    (Some(FfiType::UInt32), "u32"),
+    (Some(FfiType::Int32), "i32"),
+    (Some(FfiType::UInt64), "u64"),
+    (Some(FfiType::Int64), "i64"),
+    (Some(FfiType::Float32), "f32"),
+    (Some(FfiType::Float64), "f64"),
+    (Some(FfiType::RustBuffer(None)), "rust_buffer"),
+    (None, "void"),
+];
+
+pub fn ffi_definitions(
+    namespace: &general::Namespace,
+    checksums: &[Checksum],
+    context: &Context,
+) -> Vec<general::FfiDefinition> {
+    let defined: HashMap<&str, &general::FfiDefinition> = namespace
+        .ffi_definitions
+        .iter()
+        .map(|def| (def.name(), def))
+        .collect();
+    let UserTypes {
+        interfaces,
+        callback_interfaces,
+        records,
+        enums,
+    } = UserTypes::new(namespace);
+    let mut ffi_defs = builtin_ffi_definitions();
+
+    // Callback function types and VTables, for callback interfaces and then trait interfaces.
+    // Trait interfaces that can't be implemented in Swift still get these, they're just unused.
+    let mut vtable_structs = vec![];
+    for cbi in callback_interfaces.iter() {
+        for meth in cbi.vtable.methods.iter() {
+            ffi_defs.extend(find_function_type(&defined, &meth.ffi_type));
+        }
+        vtable_structs.extend(find_vtable_struct(&defined, &cbi.vtable, context));
+    }
+    for int in interfaces.iter() {
+        match &int.vtable {
+            Some(vtable) => {
+                for meth in vtable.methods.iter() {
+                    ffi_defs.extend(find_function_type(&defined, &meth.ffi_type));
+                }
+                vtable_structs.extend(find_vtable_struct(&defined, vtable, context));
+            }
+            None if int.imp.is_trait_interface() => {
+                for (i, meth) in int.methods.iter().enumerate() {
+                    ffi_defs.push(trait_method_function_type(&int.name, i, &meth.callable));
+                }
+                vtable_structs.push(trait_vtable_struct(&int.name, &int.methods));
+            }
+            None => (),
+        }
+    }
+    ffi_defs.extend(vtable_structs);
+
+    // FFI functions for the exported API, along with the callable for ones that have one
+    let mut rust_functions: Vec<(&RustFfiFunctionName, Option<&general::Callable>)> = vec![];
+    for int in interfaces.iter() {
+        rust_functions.extend([(&int.ffi_func_clone, None), (&int.ffi_func_free, None)]);
+        rust_functions.extend(int.vtable.as_ref().map(|vtable| (&vtable.init_fn, None)));
+        rust_functions.extend(
+            int.constructors
+                .iter()
+                .map(|cons| callable_ffi_func(&cons.callable)),
+        );
+        rust_functions.extend(
+            int.methods
+                .iter()
+                .map(|meth| callable_ffi_func(&meth.callable)),
+        );
+        rust_functions.extend(int.methods.iter().filter_map(|meth| {
+            meth.default_ffi_func
+                .as_ref()
+                .map(|ffi_func| (ffi_func, Some(&meth.callable)))
+        }));
+        rust_functions.extend(
+            int.static_methods
+                .iter()
+                .map(|meth| callable_ffi_func(&meth.callable)),
+        );
+        rust_functions.extend(
+            uniffi_trait_callables(&int.uniffi_trait_methods)
+                .into_iter()
+                .map(callable_ffi_func),
+        );
+    }
+    rust_functions.extend(
+        callback_interfaces
+            .iter()
+            .map(|cbi| (&cbi.vtable.init_fn, None)),
+    );
+    for (constructors, methods, static_methods, uniffi_trait_methods) in enums
+        .iter()
+        .map(|en| {
+            (
+                &en.constructors,
+                &en.methods,
+                &en.static_methods,
+                &en.uniffi_trait_methods,
+            )
+        })
+        .chain(records.iter().map(|rec| {
+            (
+                &rec.constructors,
+                &rec.methods,
+                &rec.static_methods,
+                &rec.uniffi_trait_methods,
+            )
+        }))
+    {
+        rust_functions.extend(
+            constructors
+                .iter()
+                .map(|cons| callable_ffi_func(&cons.callable)),
+        );
+        rust_functions.extend(methods.iter().map(|meth| callable_ffi_func(&meth.callable)));
+        rust_functions.extend(
+            static_methods
+                .iter()
+                .map(|meth| callable_ffi_func(&meth.callable)),
+        );
+        rust_functions.extend(
+            uniffi_trait_callables(uniffi_trait_methods)
+                .into_iter()
+                .map(callable_ffi_func),
+        );
+    }
+    rust_functions.extend(
+        namespace
+            .functions
+            .iter()
+            .map(|func| callable_ffi_func(&func.callable)),
+    );
+    rust_functions.extend([
+        (&namespace.ffi_rustbuffer_alloc, None),
+        (&namespace.ffi_rustbuffer_from_bytes, None),
+        (&namespace.ffi_rustbuffer_free, None),
+        (&namespace.ffi_rustbuffer_reserve, None),
+    ]);
+    ffi_defs.extend(rust_functions.into_iter().filter_map(|(name, callable)| {
+        defined
+            .get(name.0.as_str())
+            .map(|def| with_header_argument_names((*def).clone(), callable))
+    }));
+
+    // Builtin FFI functions
+    ffi_defs.extend(builtin_ffi_functions(&namespace.crate_name));
+    ffi_defs.extend(checksums.iter().map(|checksum| {
+        rust_function(
+            &checksum.fn_name.0,
+            vec![],
+            Some(FfiType::UInt16),
+            false,
+            FfiFunctionKind::Checksum,
+        )
+    }));
+    ffi_defs.push(rust_function(
+        &namespace.ffi_uniffi_contract_version.0,
+        vec![],
+        Some(FfiType::UInt32),
+        false,
+        FfiFunctionKind::UniffiContractVersion,
+    ));
+    ffi_defs
+}
+
+/// Use the argument names that the bridging header has always used
+///
+/// Object handles are named `handle` for the clone/free functions and `ptr` for methods.  The
+/// other arguments are named after the callable arguments, including any renames.
+fn with_header_argument_names(
+    mut def: general::FfiDefinition,
+    callable: Option<&general::Callable>,
+) -> general::FfiDefinition {
+    if let general::FfiDefinition::RustFunction(func) = &mut def {
+        let handle_name = match func.kind {
+            FfiFunctionKind::ObjectClone | FfiFunctionKind::ObjectFree => "handle",
+            _ => "ptr",
+        };
+        let callable_args = callable.map(|c| c.arguments.as_slice()).unwrap_or_default();
+        let first_callable_arg = func.arguments.len().saturating_sub(callable_args.len());
+        for (i, arg) in func.arguments.iter_mut().enumerate() {
+            if i >= first_callable_arg {
+                arg.name = callable_args[i - first_callable_arg].name.clone();
+            } else if arg.name == "ptr" || arg.name == "uniffi_self" {
+                arg.name = handle_name.to_owned();
+            }
+        }
+    }
+    def
+}
+
+fn find_function_type(
+    defined: &HashMap<&str, &general::FfiDefinition>,
+    ffi_type: &FfiType,
+) -> Option<general::FfiDefinition> {
+    match ffi_type {
+        FfiType::Function(name) => defined.get(name.0.as_str()).map(|def| (*def).clone()),
+        _ => None,
+    }
+}
+
+/// Find the VTable struct for a callback interface or trait interface
+///
+/// The method fields are named after the methods, including any renames.
+fn find_vtable_struct(
+    defined: &HashMap<&str, &general::FfiDefinition>,
+    vtable: &general::VTable,
+    context: &Context,
+) -> Option<general::FfiDefinition> {
+    let FfiType::Struct(name) = &vtable.struct_type else {
+        return None;
+    };
+    let mut def = (*defined.get(name.0.as_str())?).clone();
+    if let general::FfiDefinition::Struct(st) = &mut def {
+        for field in st.fields.iter_mut() {
+            if let FfiType::Function(fn_type) = &field.ty {
+                if let Some(field_name) = context.vtable_field_names.get(&fn_type.0) {
+                    field.name = field_name.clone();
+                }
+            }
+        }
+    }
+    Some(def)
+}
+
+fn callable_ffi_func(
+    callable: &general::Callable,
+) -> (&RustFfiFunctionName, Option<&general::Callable>) {
+    (&callable.ffi_func, Some(callable))
+}
+
+fn uniffi_trait_callables(methods: &general::UniffiTraitMethods) -> Vec<&general::Callable> {
+    [
+        &methods.debug_fmt,
+        &methods.display_fmt,
+        &methods.eq_eq,
+        &methods.eq_ne,
+        &methods.hash_hash,
+        &methods.ord_cmp,
+        &methods.clone_clone,
+        &methods.default_default,
+        &methods.partial_ord_partial_cmp,
+        &methods.from_str_from_str,
+    ]
+    .into_iter()
+    .flatten()
+    .map(|meth| &meth.callable)
+    .collect()
+}
+
+fn builtin_ffi_definitions() -> Vec<general::FfiDefinition> {
+    let mut ffi_defs = vec![
+        function_type(
+            "RustFutureContinuationCallback",
+            vec![
+                FfiArgument::new("data", FfiType::UInt64),
+                FfiArgument::new("poll_result", FfiType::Int8),
+            ],
+            None,
+            false,
+        ),
+        function_type(
+            "ForeignFutureDroppedCallback",
+            vec![FfiArgument::new("handle", FfiType::UInt64)],
+            None,
+            false,
+        ),
+        function_type(
+            "CallbackInterfaceFree",
+            vec![FfiArgument::new("handle", FfiType::UInt64)],
+            None,
+            false,
+        ),
+        function_type(
+            "CallbackInterfaceClone",
+            vec![FfiArgument::new("handle", FfiType::UInt64)],
+            Some(FfiType::UInt64),
+            false,
+        ),
+        function_type(
+            "ForeignClosureCall",
+            vec![
+                FfiArgument::new("handle", FfiType::UInt64),
+                FfiArgument::new("args", FfiType::RustBuffer(None)),
+                FfiArgument::new(
+                    "uniffi_out_return",
+                    FfiType::MutReference(Box::new(FfiType::RustBuffer(None))),
+                ),
+            ],
+            None,
+            true,
+        ),
+        ffi_struct(
+            "VTableForeignClosure",
+            vec![
+                FfiField::new("uniffi_free", callback_type("CallbackInterfaceFree")),
+                FfiField::new("uniffi_clone", callback_type("CallbackInterfaceClone")),
+                FfiField::new("call", callback_type("ForeignClosureCall")),
+            ],
+        ),
+        ffi_struct(
+            "ForeignFutureDroppedCallbackStruct",
+            vec![
+                FfiField::new("handle", FfiType::UInt64),
+                FfiField::new("free", callback_type("ForeignFutureDroppedCallback")),
+            ],
+        ),
+    ];
+    for (return_type, return_type_name) in RETURN_TYPES {
+        let return_type_name = return_type_name.to_upper_camel_case();
+        let result_struct = format!("ForeignFutureResult{return_type_name}");
+        ffi_defs.push(ffi_struct(
+            &result_struct,
+            match return_type {
+                Some(return_type) => vec![
+                    FfiField::new("return_value", return_type),
+                    FfiField::new("call_status", FfiType::RustCallStatus),
+                ],
+                // `()` is a ZST in Rust, so there's no `return_value` field
+                None => vec![FfiField::new("call_status", FfiType::RustCallStatus)],
+            },
+        ));
+        ffi_defs.push(function_type(
+            &format!("ForeignFutureComplete{return_type_name}"),
+            vec![
+                FfiArgument::new("callback_data", FfiType::UInt64),
+                FfiArgument::new(
+                    "result",
+                    FfiType::Struct(FfiStructName(result_struct.clone())),
+                ),
+            ],
+            None,
+            false,
+        ));
+    }
+    ffi_defs
+}
+
+/// Callback function type for a method of a trait interface that isn't exported to Swift
+fn trait_method_function_type(
+    interface_name: &str,
+    index: usize,
+    callable: &general::Callable,
+) -> general::FfiDefinition {
+    let return_type = callable
+        .return_type
+        .ty
+        .as_ref()
+        .map(|ty| ty.ffi_type.clone());
+    let mut arguments = vec![FfiArgument::new("uniffi_handle", FfiType::UInt64)];
+    arguments.extend(
+        callable
+            .arguments
+            .iter()
+            .map(|arg| FfiArgument::new(arg.name.clone(), arg.ty.ffi_type.clone())),
+    );
+    let has_rust_call_status_arg = !callable.is_async();
+    if callable.is_async() {
+        let return_type_name = return_type_name(return_type.as_ref()).to_upper_camel_case();
+        arguments.extend([
+            FfiArgument::new(
+                "uniffi_future_callback",
+                callback_type(&format!("ForeignFutureComplete{return_type_name}")),
+            ),
+            FfiArgument::new("uniffi_callback_data", FfiType::UInt64),
+            FfiArgument::new(
+                "uniffi_out_dropped_callback",
+                FfiType::MutReference(Box::new(FfiType::Struct(FfiStructName(
+                    "ForeignFutureDroppedCallbackStruct".to_owned(),
+                )))),
+            ),
+        ]);
+    } else {
+        arguments.push(FfiArgument::new(
+            "uniffi_out_return",
+            match return_type {
+                Some(return_type) => FfiType::MutReference(Box::new(return_type)),
+                None => FfiType::VoidPointer,
+            },
+        ));
+    }
+    function_type(
+        &format!("CallbackInterface{interface_name}Method{index}"),
+        arguments,
+        None,
+        has_rust_call_status_arg,
+    )
+}
+
+/// VTable struct for a trait interface that isn't exported to Swift
+fn trait_vtable_struct(
+    interface_name: &str,
+    methods: &[general::Method],
+) -> general::FfiDefinition {
+    let mut fields = vec![
+        FfiField::new("uniffi_free", callback_type("CallbackInterfaceFree")),
+        FfiField::new("uniffi_clone", callback_type("CallbackInterfaceClone")),
+    ];
+    fields.extend(methods.iter().enumerate().map(|(i, meth)| {
+        FfiField::new(
+            meth.callable.name.clone(),
+            callback_type(&format!("CallbackInterface{interface_name}Method{i}")),
+        )
+    }));
+    ffi_struct(&format!("VTableCallbackInterface{interface_name}"), fields)
+}
+
+/// FFI functions for iterators, closures and futures
+fn builtin_ffi_functions(crate_name: &str) -> Vec<general::FfiDefinition> {
+    let handle = || FfiArgument::new("handle", FfiType::UInt64);
+    let mut ffi_defs = vec![
+        rust_function(
+            &format!("ffi_{crate_name}_rust_iter_next_chunk"),
+            vec![handle(), FfiArgument::new("max_items", FfiType::UInt32)],
+            Some(FfiType::RustBuffer(None)),
+            true,
+            FfiFunctionKind::RustIterNextChunk,
+        ),
+        rust_function(
+            &format!("ffi_{crate_name}_rust_iter_free"),
+            vec![handle()],
+            None,
+            false,
+            FfiFunctionKind::RustIterFree,
+        ),
+        rust_function(
+            &format!("ffi_{crate_name}_rust_closure_call"),
+            vec![
+                handle(),
+                FfiArgument::new("args", FfiType::RustBuffer(None)),
+            ],
+            Some(FfiType::RustBuffer(None)),
+            true,
+            FfiFunctionKind::RustClosureCall,
+        ),
+        rust_function(
+            &format!("ffi_{crate_name}_rust_closure_clone"),
+            vec![handle()],
+            Some(FfiType::UInt64),
+            false,
+            FfiFunctionKind::RustClosureClone,
+        ),
+        rust_function(
+            &format!("ffi_{crate_name}_rust_closure_free"),
+            vec![handle()],
+            None,
+            false,
+            FfiFunctionKind::RustClosureFree,
+        ),
+        rust_function(
+            &format!("ffi_{crate_name}_foreign_closure_init_vtable"),
+            vec![FfiArgument::new(
+                "vtable",
+                FfiType::Reference(Box::new(FfiType::Struct(FfiStructName(
+                    "VTableForeignClosure".to_owned(),
+                )))),
+            )],
+            None,
+            false,
+            FfiFunctionKind::ForeignClosureVtableInit,
+        ),
+    ];
+    for (return_type, return_type_name) in RETURN_TYPES {
+        ffi_defs.extend([
+            rust_function(
+                &format!("ffi_{crate_name}_rust_future_poll_{return_type_name}"),
+                vec![
+                    handle(),
+                    FfiArgument::new("callback", callback_type("RustFutureContinuationCallback")),
+                    FfiArgument::new("callback_data", FfiType::UInt64),
+                ],
+                None,
+                false,
+                FfiFunctionKind::RustFuturePoll,
+            ),
+            rust_function(
+                &format!("ffi_{crate_name}_rust_future_cancel_{return_type_name}"),
+                vec![handle()],
+                None,
+                false,
+                FfiFunctionKind::RustFutureCancel,
+            ),
+            rust_function(
+                &format!("ffi_{crate_name}_rust_future_free_{return_type_name}"),
+                vec![handle()],
+                None,
+                false,
+                FfiFunctionKind::RustFutureFree,
+            ),
+            rust_function(
+                &format!("ffi_{crate_name}_rust_future_complete_{return_type_name}"),
+                vec![handle()],
+                return_type,
+                true,
+                FfiFunctionKind::RustFutureComplete,
+            ),
+        ]);
+    }
+    ffi_defs.extend([
+        rust_function(
+            &format!("ffi_{crate_name}_rust_stream_next"),
+            vec![handle()],
+            Some(FfiType::UInt64),
+            false,
+            FfiFunctionKind::RustStreamNext,
+        ),
+        rust_function(
+            &format!("ffi_{crate_name}_rust_stream_free"),
+            vec![handle()],
+            None,
+            false,
+            FfiFunctionKind::RustStreamFree,
+        ),
+    ]);
+    ffi_defs
+}
+
+fn return_type_name(return_type: Option<&FfiType>) -> &'static str {
+    match return_type {
+        Some(FfiType::UInt8) => "u8",
+        Some(FfiType::Int8) => "i8",
+        Some(FfiType::UInt16) => "u16",
+        Some(FfiType::Int16) => "i16",
+        Some(FfiType::UInt32) => "u32",
+        Some(FfiType::Int32) => "i32",
+        Some(FfiType::UInt64 | FfiType::Handle(_)) => "u64",
+        Some(FfiType::Int64) => "i64",
+        Some(FfiType::Float32) => "f32",
+        Some(FfiType::Float64) => "f64",
+        Some(_) => "rust_buffer",
+        None => "void",
+    }
+}
+
+fn callback_type(name: &str) -> FfiType {
+    FfiType::Function(FfiFunctionTypeName(name.to_owned()))
+}
+
+fn function_type(
+    name: &str,
+    arguments: Vec<FfiArgument>,
+    return_type: Option<FfiType>,
+    has_rust_call_status_arg: bool,
+) -> general::FfiDefinition {
+    general::FfiFunctionType {
+        name: FfiFunctionTypeName(name.to_owned()),
+        arguments,
+        return_type: FfiReturnType { ty: return_type },
+        has_rust_call_status_arg,
+    }
+    .into()
+}
+
+fn ffi_struct(name: &str, fields: Vec<FfiField>) -> general::FfiDefinition {
+    general::FfiStruct {
+        name: FfiStructName(name.to_owned()),
+        fields,
+    }
+    .into()
+}
+
+fn rust_function(
+    name: &str,
+    arguments: Vec<FfiArgument>,
+    return_type: Option<FfiType>,
+    has_rust_call_status_arg: bool,
+    kind: FfiFunctionKind,
+) -> general::FfiDefinition {
+    general::FfiFunction {
+        name: RustFfiFunctionName(name.to_owned()),
+        async_data: None,
+        arguments,
+        return_type: FfiReturnType { ty: return_type },
+        has_rust_call_status_arg,
+        kind,
+    }
+    .into()
+}
@@ -0,0 +1,34 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn is_borrowed_bytes(arg: &general::Argument) -> bool {
+    arg.by_ref && matches!(arg.ty.ty, Type::Bytes)
+}
+
+/// Type name for function arguments
+///
+/// Closures are marked `@escaping`, since the lowered closure outlives the call.
+pub fn arg_type_name(arg: &general::Argument) -> String {
+    let type_name = types::type_name(&arg.ty.ty);
+    match arg.ty.ty {
+        Type::Closure { .. } => format!("@escaping {type_name}"),
+        _ => type_name,
+    }
+}
+
+/// Swift expression to emit at an argument's position in an FFI call.
+///
+/// For borrowed `Bytes` (`&[u8]`), this is a bare reference to the `{name}Fb` variable bound by
+/// the outer `FfiConverterByRefBytes.lower` scope (see `to_ffi_call`). For every other argument,
+/// this is the standard `{FfiConverter}.lower({name})` expression.
+pub fn arg_expr(arg: &general::Argument) -> String {
+    let name = names::var_name(&arg.name);
+    if is_borrowed_bytes(arg) {
+        format!("{name}Fb")
+    } else {
+        format!("{}({name})", types::lower_fn(&arg.ty.ty))
+    }
+}
@@ -0,0 +1,148 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+/// This module contains the serde structs to parse the `uniffi.toml` config.
+use std::collections::HashSet;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde::Deserialize;
+
+use uniffi_pipeline::Node;
+
+// These just exist so we can parse the entire `uniffi.toml` file, the codegen only uses the
+// `SwiftConfig` part.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub bindings: BindingsConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BindingsConfig {
+    #[serde(default)]
+    pub swift: SwiftConfig,
+}
+
+/// Config options for the caller to customize the generated Swift.
+///
+/// `exclude` and `rename` are also read from this table, but those are handled by the general
+/// pipeline.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SwiftConfig {
+    module_name: Option<String>,
+    ffi_module_name: Option<String>,
+    ffi_module_filename: Option<String>,
+    generate_module_map: Option<bool>,
+    #[serde(default)]
+    pub omit_checksums: bool,
+    omit_argument_labels: Option<bool>,
+    generate_immutable_records: Option<bool>,
+    #[serde(default)]
+    mutable_records: HashSet<String>,
+    omit_localized_error_conformance: Option<bool>,
+    generate_case_iterable_conformance: Option<bool>,
+    generate_codable_conformance: Option<bool>,
+    #[serde(default)]
+    pub custom_types: IndexMap<String, CustomTypeConfig>,
+    #[serde(default)]
+    pub link_frameworks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Node, Default, Deserialize)]
+#[serde(default)]
+pub struct CustomTypeConfig {
+    pub imports: Option<Vec<String>>,
+    pub type_name: Option<String>,
+    pub into_custom: String, // b/w compat alias for lift
+    pub lift: String,
+    pub from_custom: String, // b/w compat alias for lower
+    pub lower: String,
+}
+
+impl SwiftConfig {
+    pub fn from_uniffi_toml(toml: &str) -> Result<Self> {
+        let root: Config = toml::from_str(toml)?;
+        Ok(root.bindings.swift)
+    }
+
+    /// The name of the Swift module containing the high-level foreign-language bindings.
+    ///
+    /// Defaults to the namespace name.
+    pub fn module_name(&self, namespace: &str) -> String {
+        match &self.module_name {
+            Some(name) => name.clone(),
+            None => namespace.to_string(),
+        }
+    }
+
+    /// The name of the lower-level C module containing the FFI declarations.
+    pub fn ffi_module_name(&self, namespace: &str) -> String {
+        match &self.ffi_module_name {
+            Some(name) => name.clone(),
+            None => format!("{}FFI", self.module_name(namespace)),
+        }
+    }
+
+    /// The filename stem for the lower-level C module containing the FFI declarations.
+    pub fn ffi_module_filename(&self, namespace: &str) -> String {
+        match &self.ffi_module_filename {
+            Some(name) => name.clone(),
+            None => self.ffi_module_name(namespace),
+        }
+    }
+
+    /// Whether to generate a `.modulemap` file for the lower-level C module with FFI declarations.
+    pub fn generate_module_map(&self) -> bool {
+        self.generate_module_map.unwrap_or(true)
+    }
+
+    /// Whether to omit argument labels in Swift function definitions.
+    pub fn omit_argument_labels(&self) -> bool {
+        self.omit_argument_labels.unwrap_or(false)
+    }
+
+    /// Whether a specific record should be generated with immutable fields.
+    /// A record is immutable only if `generate_immutable_records` is enabled
+    /// and the record is not listed in `mutable_records`.
+    pub fn is_record_immutable(&self, name: &str) -> bool {
+        self.generate_immutable_records.unwrap_or(false) && !self.mutable_records.contains(name)
+    }
+
+    /// Whether to make generated error types conform to `LocalizedError`. Default: false.
+    pub fn omit_localized_error_conformance(&self) -> bool {
+        self.omit_localized_error_conformance.unwrap_or(false)
+    }
+
+    /// Whether to make simple generated enum and error types conform to `CaseIterable`. Default: false.
+    pub fn generate_case_iterable_conformance(&self) -> bool {
+        self.generate_case_iterable_conformance.unwrap_or(false)
+    }
+
+    /// Whether to make generated records, enums and errors conform to `Codable`. Default: false.
+    pub fn generate_codable_conformance(&self) -> bool {
+        self.generate_codable_conformance.unwrap_or(false)
+    }
+}
+
+// functions replace literal "{}" in strings with a specified value.
+impl CustomTypeConfig {
+    pub fn lift(&self, name: &str) -> String {
+        let converter = if self.lift.is_empty() {
+            &self.into_custom
+        } else {
+            &self.lift
+        };
+        converter.replace("{}", name)
+    }
+
+    pub fn lower(&self, name: &str) -> String {
+        let converter = if self.lower.is_empty() {
+            &self.from_custom
+        } else {
+            &self.lower
+        };
+        converter.replace("{}", name)
+    }
+}
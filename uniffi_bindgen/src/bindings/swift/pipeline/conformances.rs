@@ -0,0 +1,108 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Protocol conformances for generated types
+//!
+//! We auto-generate `Equatable, Hashable`, but only if the type has no object references. We
+//! could do better - see #2409.  Objects can't be `Codable` at the moment, so we can't derive
+//! `Codable` conformance for those types either.
+
+use super::*;
+
+fn common_conformances(
+    uniffi_trait_methods: &general::UniffiTraitMethods,
+    contains_object_references: bool,
+) -> Vec<String> {
+    let mut conformances = vec![];
+    if !contains_object_references || uniffi_trait_methods.eq_eq.is_some() {
+        conformances.push("Equatable");
+    }
+    if !contains_object_references || uniffi_trait_methods.hash_hash.is_some() {
+        conformances.push("Hashable");
+    }
+    if uniffi_trait_methods.ord_cmp.is_some() {
+        conformances.push("Comparable");
+    }
+    conformances.into_iter().map(str::to_string).collect()
+}
+
+fn string_conformances(uniffi_trait_methods: &general::UniffiTraitMethods) -> Vec<String> {
+    let mut conformances = vec![];
+    if uniffi_trait_methods.debug_fmt.is_some() {
+        conformances.push("CustomDebugStringConvertible".to_string());
+    }
+    if uniffi_trait_methods.display_fmt.is_some() {
+        conformances.push("CustomStringConvertible".to_string());
+    }
+    conformances
+}
+
+fn has_variant_fields(en: &general::Enum) -> bool {
+    en.variants.iter().any(|v| !v.fields.is_empty())
+}
+
+pub fn record_conformances(rec: &general::Record, context: &Context) -> Result<Vec<String>> {
+    let config = context.config()?;
+    let contains_object_references = context.contains_object_references(&rec.self_type.ty);
+    let mut conformances =
+        common_conformances(&rec.uniffi_trait_methods, contains_object_references);
+    conformances.extend(string_conformances(&rec.uniffi_trait_methods));
+    if !contains_object_references && config.generate_codable_conformance() {
+        conformances.push("Codable".to_string());
+    }
+    Ok(conformances)
+}
+
+/// Conformances for an enum
+///
+/// For errors, this lists the conformances in addition to `Swift.Error`.
+pub fn enum_conformances(en: &general::Enum, context: &Context) -> Result<Vec<String>> {
+    let config = context.config()?;
+    let contains_object_references = context.contains_object_references(&en.self_type.ty);
+    let mut conformances =
+        common_conformances(&en.uniffi_trait_methods, contains_object_references);
+    if en.self_type.is_used_as_error {
+        if !contains_object_references && config.generate_codable_conformance() {
+            conformances.push("Codable".to_string());
+        }
+        if !config.omit_localized_error_conformance() {
+            conformances.push("Foundation.LocalizedError".to_string());
+        }
+        if config.generate_case_iterable_conformance() && !en.is_flat && !has_variant_fields(en) {
+            conformances.push("CaseIterable".to_string());
+        }
+    } else {
+        conformances.extend(string_conformances(&en.uniffi_trait_methods));
+        if !contains_object_references && config.generate_codable_conformance() {
+            conformances.push("Codable".to_string());
+        }
+        if config.generate_case_iterable_conformance() && !has_variant_fields(en) {
+            conformances.push("CaseIterable".to_string());
+        }
+    }
+    Ok(conformances)
+}
+
+pub fn interface_conformances(int: &general::Interface, context: &Context) -> Result<Vec<String>> {
+    let config = context.config()?;
+    let mut conformances = vec!["@unchecked Sendable".to_string()];
+    if int.self_type.is_used_as_error {
+        conformances.push("Swift.Error".to_string());
+        if !config.omit_localized_error_conformance() {
+            conformances.push("Foundation.LocalizedError".to_string());
+        }
+    }
+    let uniffi_trait_methods = &int.uniffi_trait_methods;
+    if uniffi_trait_methods.eq_eq.is_some() {
+        conformances.push("Equatable".to_string());
+    }
+    if uniffi_trait_methods.hash_hash.is_some() {
+        conformances.push("Hashable".to_string());
+    }
+    if uniffi_trait_methods.ord_cmp.is_some() {
+        conformances.push("Comparable".to_string());
+    }
+    conformances.extend(string_conformances(uniffi_trait_methods));
+    Ok(conformances)
+}
@@ -0,0 +1,190 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+
+#[derive(Default, Clone)]
+pub struct Context {
+    pub current_config: Option<SwiftConfig>,
+    pub module_namespace: Option<String>,
+    /// Maps namespace names to crate names
+    pub crate_names: HashMap<String, String>,
+    /// Maps (namespace, name) pairs for user-defined types to the types they directly contain.
+    ///
+    /// This is used to determine which types contain object references.
+    pub type_dependencies: HashMap<(String, String), Vec<Type>>,
+    /// FFI function types for the current namespace, keyed by their original name
+    pub ffi_function_types: HashMap<String, general::FfiFunctionType>,
+    /// Maps FFI function type names to the VTable struct fields that use them
+    pub vtable_field_names: HashMap<String, String>,
+    /// Swift names for the vtable FFI types, keyed by their original name
+    ///
+    /// The Swift bindings don't include the namespace in these names and use a single clone/free
+    /// function type for all vtables.
+    pub vtable_ffi_names: HashMap<String, String>,
+}
+
+impl Context {
+    pub fn update_from_root(&mut self, root: &general::Root) -> Result<()> {
+        for namespace in root.namespaces.values() {
+            self.crate_names
+                .insert(namespace.name.clone(), namespace.crate_name.clone());
+        }
+        root.visit(|rec: &general::Record| {
+            self.add_type_dependencies(&rec.self_type, rec);
+        });
+        root.visit(|en: &general::Enum| {
+            self.add_type_dependencies(&en.self_type, en);
+        });
+        root.visit(|int: &general::Interface| {
+            self.add_type_dependencies(&int.self_type, int);
+        });
+        root.visit(|cbi: &general::CallbackInterface| {
+            self.add_type_dependencies(&cbi.self_type, cbi);
+        });
+        Ok(())
+    }
+
+    fn add_type_dependencies(&mut self, self_type: &general::TypeNode, node: &impl Node) {
+        let (Some(namespace), Some(name)) = (self_type.ty.namespace(), self_type.ty.name()) else {
+            return;
+        };
+        let mut dependencies = vec![];
+        node.visit(|ty: &Type| {
+            if ty != &self_type.ty {
+                dependencies.push(ty.clone());
+            }
+        });
+        self.type_dependencies
+            .insert((namespace.to_string(), name.to_string()), dependencies);
+    }
+
+    pub fn update_from_namespace(&mut self, namespace: &general::Namespace) -> Result<()> {
+        self.current_config = Some(match &namespace.config_toml {
+            Some(toml) => SwiftConfig::from_uniffi_toml(toml)?,
+            None => SwiftConfig::default(),
+        });
+        self.module_namespace = Some(namespace.name.clone());
+        self.ffi_function_types.clear();
+        self.vtable_field_names.clear();
+        self.vtable_ffi_names.clear();
+        namespace.visit(|vtable: &general::VTable| {
+            let interface_name = &vtable.interface_name;
+            if let FfiType::Struct(name) = &vtable.struct_type {
+                self.vtable_ffi_names.insert(
+                    name.0.clone(),
+                    format!("VTableCallbackInterface{interface_name}"),
+                );
+            }
+            self.vtable_ffi_names.insert(
+                vtable.clone_fn_type.0.clone(),
+                "CallbackInterfaceClone".to_string(),
+            );
+            self.vtable_ffi_names.insert(
+                vtable.free_fn_type.0.clone(),
+                "CallbackInterfaceFree".to_string(),
+            );
+            for (i, meth) in vtable.methods.iter().enumerate() {
+                if let FfiType::Function(name) = &meth.ffi_type {
+                    self.vtable_ffi_names.insert(
+                        name.0.clone(),
+                        format!("CallbackInterface{interface_name}Method{i}"),
+                    );
+                    // The struct fields are named after the method, including any renames.
+                    self.vtable_field_names
+                        .insert(name.0.clone(), meth.callable.name.clone());
+                }
+            }
+        });
+        for def in namespace.ffi_definitions.iter() {
+            if let general::FfiDefinition::FunctionType(func_type) = def {
+                self.ffi_function_types
+                    .insert(func_type.name.0.clone(), func_type.clone());
+            }
+        }
+        Ok(())
+    }
+
+    pub fn module_namespace(&self) -> Result<&str> {
+        self.module_namespace
+            .as_deref()
+            .ok_or_else(|| anyhow!("Context.module_namespace not set"))
+    }
+
+    pub fn config(&self) -> Result<&SwiftConfig> {
+        self.current_config
+            .as_ref()
+            .ok_or_else(|| anyhow!("Context.config not set"))
+    }
+
+    /// Config for a custom type
+    ///
+    /// Configs are keyed by the renamed type name, but fall back to the original name for configs
+    /// written against the Rust name.
+    pub fn custom_type_config(
+        &self,
+        custom: &general::CustomType,
+    ) -> Result<Option<CustomTypeConfig>> {
+        let custom_types = &self.config()?.custom_types;
+        Ok(custom_types
+            .get(&custom.name)
+            .or_else(|| custom_types.get(&custom.orig_name))
+            .cloned())
+    }
+
+    /// Does a type contain any object references, either directly or through other types?
+    ///
+    /// Tuples and ordered maps are treated like object references, since Swift can't
+    /// synthesize `Equatable`/`Hashable`/`Codable` for them either.
+    pub fn contains_object_references(&self, ty: &Type) -> bool {
+        let mut seen = HashSet::new();
+        self.contains_object_references_inner(ty, &mut seen)
+    }
+
+    fn contains_object_references_inner(
+        &self,
+        ty: &Type,
+        seen: &mut HashSet<(String, String)>,
+    ) -> bool {
+        match ty {
+            Type::Interface { .. } | Type::Tuple { .. } | Type::Map { ordered: true, .. } => true,
+            Type::Record {
+                namespace, name, ..
+            }
+            | Type::Enum {
+                namespace, name, ..
+            }
+            | Type::CallbackInterface {
+                namespace, name, ..
+            } => {
+                let key = (namespace.clone(), name.clone());
+                if !seen.insert(key.clone()) {
+                    return false;
+                }
+                self.type_dependencies
+                    .get(&key)
+                    .into_iter()
+                    .flatten()
+                    .any(|ty| self.contains_object_references_inner(ty, seen))
+            }
+            Type::Custom { builtin, .. } => self.contains_object_references_inner(builtin, seen),
+            Type::Optional { inner_type }
+            | Type::Sequence { inner_type }
+            | Type::Array { inner_type, .. }
+            | Type::Set { inner_type, .. }
+            | Type::Box { inner_type } => self.contains_object_references_inner(inner_type, seen),
+            Type::Map {
+                key_type,
+                value_type,
+                ..
+            } => {
+                self.contains_object_references_inner(key_type, seen)
+                    || self.contains_object_references_inner(value_type, seen)
+            }
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Render a default value for a field/argument of type `ty`
+pub fn render_default(default: &general::DefaultValue, ty: &Type) -> Result<String> {
+    match ty {
+        Type::Boolean => render_primitive_default(default, "false"),
+        Type::String => render_primitive_default(default, "\"\""),
+        Type::Bytes => render_primitive_default(default, "Data([])"),
+        Type::Int8 => render_primitive_default(default, "Int8(0)"),
+        Type::Int16 => render_primitive_default(default, "Int16(0)"),
+        Type::Int32 => render_primitive_default(default, "0"),
+        Type::Int64 => render_primitive_default(default, "Int64(0)"),
+        Type::UInt8 => render_primitive_default(default, "UInt8(0)"),
+        Type::UInt16 => render_primitive_default(default, "UInt16(0)"),
+        Type::UInt32 => render_primitive_default(default, "UInt32(0)"),
+        Type::UInt64 => render_primitive_default(default, "UInt64(0)"),
+        Type::Float32 => render_primitive_default(default, "Float(0)"),
+        Type::Float64 => render_primitive_default(default, "Double(0)"),
+        Type::Char => render_primitive_default(default, "\"\\0\""),
+        Type::Int128 | Type::UInt128 => {
+            let type_name = types::type_name(ty);
+            match default {
+                general::DefaultValue::Default(_) => Ok(format!("{type_name}(0)")),
+                general::DefaultValue::Literal(general::Literal::Int(i, _, _)) => {
+                    Ok(format!("{type_name}({i})"))
+                }
+                general::DefaultValue::Literal(general::Literal::UInt(i, _, _)) => {
+                    Ok(format!("{type_name}({i})"))
+                }
+                _ => bail!("Invalid literal for {type_name} type: {default:?}"),
+            }
+        }
+        Type::Box { inner_type } => render_default(default, inner_type),
+        Type::Optional { inner_type } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(general::Literal::None) => Ok("nil".to_string()),
+            general::DefaultValue::Literal(general::Literal::Some { inner }) => {
+                render_default(inner, inner_type)
+            }
+            _ => bail!("Invalid literal for Optional type: {default:?}"),
+        },
+        Type::Sequence { .. } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(general::Literal::EmptySequence) => {
+                Ok("[]".to_string())
+            }
+            _ => bail!("Invalid literal for sequence type: {default:?}"),
+        },
+        Type::Map { ordered, .. } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(general::Literal::EmptyMap) => {
+                Ok(if *ordered { "[]" } else { "[:]" }.to_string())
+            }
+            _ => bail!("Invalid literal for map type: {default:?}"),
+        },
+        Type::Set { ordered, .. } => match default {
+            general::DefaultValue::Default(_)
+            | general::DefaultValue::Literal(
+                general::Literal::EmptySequence | general::Literal::EmptySet,
+            ) => Ok(if *ordered { "[]" } else { "Set()" }.to_string()),
+            _ => bail!("Invalid literal for set type: {default:?}"),
+        },
+        Type::Array { .. } => {
+            bail!("Default values are not supported for fixed-size arrays: {default:?}")
+        }
+        Type::Tuple { .. } => {
+            bail!("Default values are not supported for tuple types: {default:?}")
+        }
+        Type::Stream { .. } => {
+            bail!("Default values are not supported for stream types: {default:?}")
+        }
+        Type::Iterator { .. } => {
+            bail!("Default values are not supported for iterator types: {default:?}")
+        }
+        Type::Closure { .. } => {
+            bail!("Default values are not supported for closure types: {default:?}")
+        }
+        Type::Enum { .. } => match default {
+            general::DefaultValue::Literal(general::Literal::Enum(variant, _)) => {
+                Ok(format!(".{}", names::enum_variant_name(variant)))
+            }
+            _ => bail!("Invalid literal for enum type: {default:?}"),
+        },
+        Type::Record { .. } => match default {
+            general::DefaultValue::Default(_) => Ok(format!("{}()", types::type_name(ty))),
+            general::DefaultValue::Literal(general::Literal::Record { fields, .. }) => {
+                let fields = fields
+                    .iter()
+                    .map(|f| {
+                        let value = render_default(
+                            &general::DefaultValue::Literal(f.value.clone()),
+                            &f.ty.ty,
+                        )?;
+                        Ok(format!("{}: {value}", names::var_name(&f.name)))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(format!("{}({})", types::type_name(ty), fields.join(", ")))
+            }
+            _ => bail!("Invalid literal for record type: {default:?}"),
+        },
+        Type::Custom { builtin, .. } => render_default(default, builtin)
+            .map_err(|_| anyhow!("Unsupported default value for {}", types::type_name(ty))),
+        // Default for other named types is to assume a ctor exists.
+        Type::Interface { .. }
+        | Type::CallbackInterface { .. }
+        | Type::Timestamp
+        | Type::Duration => match default {
+            general::DefaultValue::Default(_) => Ok(format!("{}()", types::type_name(ty))),
+            general::DefaultValue::Literal(_) => {
+                bail!("Literals for named types are not supported")
+            }
+        },
+    }
+}
+
+fn render_primitive_default(default: &general::DefaultValue, default_str: &str) -> Result<String> {
+    match default {
+        general::DefaultValue::Default(_) => Ok(default_str.to_string()),
+        general::DefaultValue::Literal(literal) => render_literal(literal),
+    }
+}
+
+fn render_literal(literal: &general::Literal) -> Result<String> {
+    fn typed_number(ty: &Type, num_str: String) -> Result<String> {
+        let unwrapped_type = match ty {
+            Type::Optional { inner_type } => inner_type,
+            t => t,
+        };
+        Ok(match unwrapped_type {
+            // special case Int32.
+            Type::Int32 => num_str,
+            // otherwise use constructor e.g. UInt8(x)
+            Type::Int8
+            | Type::UInt8
+            | Type::Int16
+            | Type::UInt16
+            | Type::UInt32
+            | Type::Int64
+            | Type::UInt64
+            | Type::Float32
+            | Type::Float64 => format!("{}({num_str})", types::type_name(ty)),
+            _ => bail!("Unexpected literal: {num_str} for type: {ty:?}"),
+        })
+    }
+
+    fn int_str(
+        i: impl std::fmt::Display + std::fmt::Octal + std::fmt::LowerHex,
+        radix: &Radix,
+    ) -> String {
+        match radix {
+            Radix::Octal => format!("0o{i:o}"),
+            Radix::Decimal => format!("{i}"),
+            Radix::Hexadecimal => format!("{i:#x}"),
+        }
+    }
+
+    match literal {
+        general::Literal::Boolean(v) => Ok(format!("{v}")),
+        general::Literal::String(s) => Ok(format!("\"{s}\"")),
+        // `Unicode.Scalar` can be initialized from a string literal with a single scalar.
+        general::Literal::Char(c) => Ok(format!("\"\\u{{{:X}}}\"", u32::from(*c))),
+        general::Literal::Int(i, radix, ty) => typed_number(&ty.ty, int_str(i, radix)),
+        general::Literal::UInt(i, radix, ty) => typed_number(&ty.ty, int_str(i, radix)),
+        general::Literal::Float(string, ty) => typed_number(&ty.ty, string.clone()),
+        _ => bail!("Invalid literal: {literal:?}"),
+    }
+}
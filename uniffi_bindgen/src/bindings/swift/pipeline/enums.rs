@@ -0,0 +1,56 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+pub fn map_variants(
+    variants: Vec<general::Variant>,
+    shape: &EnumShape,
+    context: &Context,
+) -> Result<Vec<Variant>> {
+    variants
+        .into_iter()
+        .map(|v| {
+            Ok(Variant {
+                name: if shape.is_error() {
+                    names::error_variant_name(&v.name)
+                } else {
+                    names::enum_variant_name(&v.name)
+                },
+                discr: discr_literal(&v.discr)?,
+                has_nameless_fields: v.fields.iter().any(|f| f.name.is_empty()),
+                fields_kind: v.fields_kind,
+                fields: map_variant_fields(v.fields, context)?,
+                docstring: v.docstring,
+            })
+        })
+        .collect()
+}
+
+/// Map variant fields, giving any unnamed fields the `v1`, `v2`, etc. bindings used when
+/// destructuring the variant.
+fn map_variant_fields(fields: Vec<general::Field>, context: &Context) -> Result<Vec<Field>> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let mut field: Field = field.map_node(context)?;
+            if field.name.is_empty() {
+                field.binding_name = format!("v{}", i + 1);
+            }
+            Ok(field)
+        })
+        .collect()
+}
+
+/// Render a discriminant literal
+///
+/// Swift enums with a raw type can use plain integer literals for either signedness.
+fn discr_literal(discr: &general::Literal) -> Result<String> {
+    match discr {
+        general::Literal::UInt(v, _, _) => Ok(v.to_string()),
+        general::Literal::Int(v, _, _) => Ok(v.to_string()),
+        _ => bail!("Only ints are supported: {discr:?}"),
+    }
+}
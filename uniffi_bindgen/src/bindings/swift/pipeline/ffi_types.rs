@@ -0,0 +1,126 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Get the Swift type for an FFI type
+pub fn ffi_type_name(ffi_type: &FfiType, context: &Context) -> String {
+    match ffi_type {
+        FfiType::Int8 => "Int8".into(),
+        FfiType::UInt8 => "UInt8".into(),
+        FfiType::Int16 => "Int16".into(),
+        FfiType::UInt16 => "UInt16".into(),
+        FfiType::Int32 => "Int32".into(),
+        FfiType::UInt32 => "UInt32".into(),
+        FfiType::Int64 => "Int64".into(),
+        FfiType::UInt64 => "UInt64".into(),
+        FfiType::Float32 => "Float".into(),
+        FfiType::Float64 => "Double".into(),
+        FfiType::Handle(_) => "UInt64".into(),
+        FfiType::RustBuffer(_) => "RustBuffer".into(),
+        FfiType::RustCallStatus => "RustCallStatus".into(),
+        FfiType::ForeignBytes => "ForeignBytes".into(),
+        // Note: @escaping is required for Swift versions before 5.7 for callbacks passed into
+        // async functions. Swift 5.7 and later does not require it.  We should probably remove
+        // it once we upgrade our minimum requirement to 5.7 or later.
+        FfiType::Function(name) => {
+            format!("@escaping {}", names::ffi_callback_name(&name.0, context))
+        }
+        FfiType::Struct(name) => names::ffi_struct_name(&name.0, context),
+        FfiType::Reference(inner) => format!("UnsafePointer<{}>", ffi_type_name(inner, context)),
+        FfiType::MutReference(inner) => {
+            format!("UnsafeMutablePointer<{}>", ffi_type_name(inner, context))
+        }
+        FfiType::VoidPointer => "UnsafeMutableRawPointer".into(),
+    }
+}
+
+/// Like `ffi_type_name`, but for `BridgingHeaderTemplate.h` which uses the C names.
+pub fn header_ffi_type_name(ffi_type: &FfiType, context: &Context) -> String {
+    match ffi_type {
+        FfiType::Int8 => "int8_t".into(),
+        FfiType::UInt8 => "uint8_t".into(),
+        FfiType::Int16 => "int16_t".into(),
+        FfiType::UInt16 => "uint16_t".into(),
+        FfiType::Int32 => "int32_t".into(),
+        FfiType::UInt32 => "uint32_t".into(),
+        FfiType::Int64 => "int64_t".into(),
+        FfiType::UInt64 => "uint64_t".into(),
+        FfiType::Float32 => "float".into(),
+        FfiType::Float64 => "double".into(),
+        FfiType::Handle(_) => "uint64_t".into(),
+        FfiType::RustBuffer(_) => "RustBuffer".into(),
+        FfiType::RustCallStatus => "RustCallStatus".into(),
+        FfiType::ForeignBytes => "ForeignBytes".into(),
+        FfiType::Function(name) => {
+            format!("{} _Nonnull", names::ffi_callback_name(&name.0, context))
+        }
+        FfiType::Struct(name) => names::ffi_struct_name(&name.0, context),
+        FfiType::Reference(inner) => {
+            format!("const {}* _Nonnull", header_ffi_type_name(inner, context))
+        }
+        FfiType::MutReference(inner) => {
+            format!("{}* _Nonnull", header_ffi_type_name(inner, context))
+        }
+        FfiType::VoidPointer => "void* _Nonnull".into(),
+    }
+}
+
+/// Default values for FFI types
+///
+/// Used to set a default return value when a callback method returns an error.
+/// When we need to use a value for void returns, we use a `u8` placeholder.
+pub fn ffi_default_value(return_type: &general::ReturnType) -> Result<String> {
+    let Some(ty) = &return_type.ty else {
+        return Ok("0".to_string());
+    };
+    Ok(match &ty.ffi_type {
+        FfiType::UInt8
+        | FfiType::Int8
+        | FfiType::UInt16
+        | FfiType::Int16
+        | FfiType::UInt32
+        | FfiType::Int32
+        | FfiType::UInt64
+        | FfiType::Int64
+        | FfiType::Handle(_) => "0".to_string(),
+        FfiType::Float32 | FfiType::Float64 => "0.0".to_string(),
+        FfiType::RustBuffer(_) => "RustBuffer.empty()".to_string(),
+        ffi_type => bail!("No FFI default value for {ffi_type:?}"),
+    })
+}
+
+/// Lookup the FFI function type for a VTable method
+pub fn vtable_method_callback(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<FfiFunctionType> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context
+        .ffi_function_types
+        .get(&name.0)
+        .cloned()
+        .ok_or_else(|| anyhow!("FFI function type not found: {}", name.0))?
+        .map_node(context)
+}
+
+/// Lookup the name of the VTable struct field for a VTable method
+///
+/// The closures that implement the VTable are passed to the struct initializer with labels, which
+/// need to match the field names declared in the bridging header.
+pub fn vtable_method_field_name(
+    vtable_method: &general::VTableMethod,
+    context: &Context,
+) -> Result<String> {
+    let FfiType::Function(name) = &vtable_method.ffi_type else {
+        bail!("Invalid VTable method type: {:?}", vtable_method.ffi_type);
+    };
+    context
+        .vtable_field_names
+        .get(&name.0)
+        .map(|field_name| names::var_name(field_name))
+        .ok_or_else(|| anyhow!("VTable field not found: {}", name.0))
+}
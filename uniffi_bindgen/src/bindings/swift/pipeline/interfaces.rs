@@ -0,0 +1,55 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Protocol and class names for objects
+//!
+//! For a regular `struct Foo` or `trait Foo`, there's `FooProtocol` with `Foo` as the name of the
+//! (Rust implemented) class. But if it's a foreign trait:
+//!   * The name `Foo` is the name of the protocol used by the Swift implementation of the trait.
+//!   * The Rust implemented class is `FooImpl`.
+
+use super::*;
+
+pub fn protocol_name(int: &general::Interface) -> String {
+    let class_name = names::class_name(&int.name);
+    if int.imp.has_callback_interface() {
+        class_name
+    } else {
+        format!("{class_name}Protocol")
+    }
+}
+
+pub fn impl_class_name(int: &general::Interface) -> String {
+    let class_name = names::class_name(&int.name);
+    if int.imp.has_callback_interface() {
+        format!("{class_name}Impl")
+    } else {
+        class_name
+    }
+}
+
+pub fn trait_protocol_names(int: &general::Interface) -> Result<Vec<String>> {
+    int.trait_impls
+        .iter()
+        .map(|t| {
+            Ok(match &t.trait_ty.ty {
+                Type::Interface { name, imp, .. } => {
+                    let class_name = names::class_name(name);
+                    if imp.has_callback_interface() {
+                        class_name
+                    } else {
+                        format!("{class_name}Protocol")
+                    }
+                }
+                Type::CallbackInterface { name, .. } => names::class_name(name),
+                ty => bail!("Invalid trait_type: {ty:?}"),
+            })
+        })
+        .collect()
+}
+
+/// Name of the function that registers the VTable for a callback interface or trait interface
+pub fn callback_init_fn(name: &str) -> String {
+    format!("uniffiCallbackInit{name}")
+}
@@ -0,0 +1,378 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::BTreeSet;
+
+use super::*;
+
+pub fn map_namespace(mut namespace: general::Namespace, context: &Context) -> Result<Module> {
+    let mut child_context = context.clone();
+    let context = &mut child_context;
+    context.update_from_namespace(&namespace)?;
+    let config = context.config()?.clone();
+
+    let has_stream_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Stream(_))
+    });
+    let has_iterator_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Iterator(_))
+    });
+    let has_closure_types = has_type_definition(&namespace, |td| {
+        matches!(td, general::TypeDefinition::Closure(_))
+    });
+    let has_async_fns = has_stream_types
+        || namespace.has_descendant(|callable: &general::Callable| callable.is_async());
+    let ffi_module_filename = config.ffi_module_filename(&namespace.name);
+    // Functions are listed in metadata order, which sorts them by module path, then by name.
+    namespace
+        .functions
+        .sort_by(|a, b| a.module_path.cmp(&b.module_path));
+    let checksums = checksums(&namespace);
+    let ffi_definitions = IndexSet::from_iter(bridging_header::ffi_definitions(
+        &namespace, &checksums, context,
+    ));
+
+    let mut module = Module {
+        module_name: config.module_name(&namespace.name),
+        ffi_module_name: config.ffi_module_name(&namespace.name),
+        header_filename: format!("{ffi_module_filename}.h"),
+        modulemap_filename: format!("{ffi_module_filename}.modulemap"),
+        generate_module_map: config.generate_module_map(),
+        link_frameworks: config.link_frameworks.clone(),
+        omit_checksums: config.omit_checksums,
+        omit_argument_labels: config.omit_argument_labels(),
+        omit_localized_error_conformance: config.omit_localized_error_conformance(),
+        ensure_init_fn_name: names::ensure_init_fn_name(&namespace.crate_name),
+        class_name: names::class_name(&namespace.name),
+        name: namespace.name.clone(),
+        crate_name: namespace.crate_name.clone(),
+        has_async_fns,
+        has_stream_types,
+        has_iterator_types,
+        has_closure_types,
+        has_callback_definitions: namespace.has_descendant(|_: &general::CallbackInterface| true)
+            || namespace
+                .has_descendant(|int: &general::Interface| int.imp.has_callback_interface()),
+        has_async_callback_method: has_async_callback_method(&namespace),
+        imports: vec![],
+        initialization_fns: vec![],
+        docstring: namespace.docstring,
+        functions: namespace.functions.map_node(context)?,
+        type_definitions: namespace.type_definitions.map_node(context)?,
+        constants: namespace.constants.map_node(context)?,
+        ffi_definitions: ffi_definitions.map_node(context)?,
+        checksums,
+        ffi_rustbuffer_alloc: namespace.ffi_rustbuffer_alloc,
+        ffi_rustbuffer_from_bytes: namespace.ffi_rustbuffer_from_bytes,
+        ffi_rustbuffer_free: namespace.ffi_rustbuffer_free,
+        ffi_rustbuffer_reserve: namespace.ffi_rustbuffer_reserve,
+        ffi_uniffi_contract_version: namespace.ffi_uniffi_contract_version,
+        correct_contract_version: namespace.correct_contract_version,
+    };
+    module
+        .type_definitions
+        .sort_by_cached_key(|type_def| type_order_key(&type_def.self_type().ty, context));
+    // Generate these after mapping everything else, so that we can use the Swift names.
+    module.imports = module_imports(&module);
+    module.initialization_fns = initialization_fns(&module, context);
+    // External types are defined in their own module and boxed types are represented by their
+    // inner type, so neither needs a definition here.
+    module.type_definitions.retain(|type_def| {
+        !matches!(
+            type_def,
+            TypeDefinition::External(_) | TypeDefinition::Box(_)
+        )
+    });
+    Ok(module)
+}
+
+/// Key to sort type definitions by
+///
+/// Swift doesn't care about the order of type definitions, so we use the `uniffi_meta::Type`
+/// ordering rather than the dependency order from the general pipeline.  This keeps the generated
+/// code stable when types are added or removed.
+fn type_order_key(ty: &Type, context: &Context) -> uniffi_meta::Type {
+    let module_path = |namespace: &String| {
+        context
+            .crate_names
+            .get(namespace)
+            .unwrap_or(namespace)
+            .clone()
+    };
+    let boxed = |ty: &Type| Box::new(type_order_key(ty, context));
+    match ty {
+        Type::UInt8 => uniffi_meta::Type::UInt8,
+        Type::Int8 => uniffi_meta::Type::Int8,
+        Type::UInt16 => uniffi_meta::Type::UInt16,
+        Type::Int16 => uniffi_meta::Type::Int16,
+        Type::UInt32 => uniffi_meta::Type::UInt32,
+        Type::Int32 => uniffi_meta::Type::Int32,
+        Type::UInt64 => uniffi_meta::Type::UInt64,
+        Type::Int64 => uniffi_meta::Type::Int64,
+        Type::UInt128 => uniffi_meta::Type::UInt128,
+        Type::Int128 => uniffi_meta::Type::Int128,
+        Type::Float32 => uniffi_meta::Type::Float32,
+        Type::Float64 => uniffi_meta::Type::Float64,
+        Type::Boolean => uniffi_meta::Type::Boolean,
+        Type::Char => uniffi_meta::Type::Char,
+        Type::String => uniffi_meta::Type::String,
+        Type::Bytes => uniffi_meta::Type::Bytes,
+        Type::Timestamp => uniffi_meta::Type::Timestamp,
+        Type::Duration => uniffi_meta::Type::Duration,
+        Type::Interface {
+            namespace,
+            name,
+            imp,
+            ..
+        } => uniffi_meta::Type::Object {
+            module_path: module_path(namespace),
+            name: name.clone(),
+            imp: *imp,
+        },
+        Type::Record {
+            namespace, name, ..
+        } => uniffi_meta::Type::Record {
+            module_path: module_path(namespace),
+            name: name.clone(),
+        },
+        Type::Enum {
+            namespace, name, ..
+        } => uniffi_meta::Type::Enum {
+            module_path: module_path(namespace),
+            name: name.clone(),
+        },
+        Type::CallbackInterface {
+            namespace, name, ..
+        } => uniffi_meta::Type::CallbackInterface {
+            module_path: module_path(namespace),
+            name: name.clone(),
+        },
+        Type::Custom {
+            namespace,
+            name,
+            builtin,
+            ..
+        } => uniffi_meta::Type::Custom {
+            module_path: module_path(namespace),
+            name: name.clone(),
+            builtin: boxed(builtin),
+        },
+        // Boxed types are represented by their inner type
+        Type::Box { inner_type } => type_order_key(inner_type, context),
+        Type::Optional { inner_type } => uniffi_meta::Type::Optional {
+            inner_type: boxed(inner_type),
+        },
+        Type::Sequence { inner_type } => uniffi_meta::Type::Sequence {
+            inner_type: boxed(inner_type),
+        },
+        Type::Array { inner_type, len } => uniffi_meta::Type::Array {
+            inner_type: boxed(inner_type),
+            len: *len,
+        },
+        Type::Map {
+            key_type,
+            value_type,
+            ordered,
+        } => uniffi_meta::Type::Map {
+            key_type: boxed(key_type),
+            value_type: boxed(value_type),
+            ordered: *ordered,
+        },
+        Type::Set {
+            inner_type,
+            ordered,
+        } => uniffi_meta::Type::Set {
+            inner_type: boxed(inner_type),
+            ordered: *ordered,
+        },
+        Type::Tuple { inner_types } => uniffi_meta::Type::Tuple {
+            inner_types: inner_types
+                .iter()
+                .map(|ty| type_order_key(ty, context))
+                .collect(),
+        },
+        Type::Stream { item_type } => uniffi_meta::Type::Stream {
+            item_type: boxed(item_type),
+        },
+        Type::Iterator { item_type } => uniffi_meta::Type::Iterator {
+            item_type: boxed(item_type),
+        },
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => uniffi_meta::Type::Closure {
+            argument_types: argument_types
+                .iter()
+                .map(|ty| type_order_key(ty, context))
+                .collect(),
+            return_type: return_type.as_deref().map(boxed),
+        },
+    }
+}
+
+/// Checksums to check when the module is initialized
+///
+/// These are listed by kind: functions, then methods, constructors and static methods, then
+/// callback interface methods.
+fn checksums(namespace: &general::Namespace) -> Vec<Checksum> {
+    // The checksum symbol mirrors the scaffolding function symbol, which is built from the
+    // metadata names rather than the renamed ones.
+    let fn_prefix = format!("uniffi_{}_fn_", namespace.crate_name);
+    let mut checksums = vec![];
+    let mut push = |callable: &general::Callable| {
+        let Some(checksum) = callable.checksum else {
+            return;
+        };
+        let Some(suffix) = callable.ffi_func.0.strip_prefix(&fn_prefix) else {
+            return;
+        };
+        checksums.push(Checksum {
+            fn_name: RustFfiFunctionName(format!(
+                "uniffi_{}_checksum_{suffix}",
+                namespace.crate_name
+            )),
+            checksum,
+        });
+    };
+    let UserTypes {
+        interfaces,
+        callback_interfaces,
+        records,
+        enums,
+    } = UserTypes::new(namespace);
+
+    for func in namespace.functions.iter() {
+        push(&func.callable);
+    }
+    for int in interfaces.iter() {
+        for meth in int.methods.iter() {
+            push(&meth.callable);
+        }
+    }
+    for int in interfaces.iter() {
+        for cons in int.constructors.iter() {
+            push(&cons.callable);
+        }
+    }
+    let static_methods = interfaces
+        .iter()
+        .map(|int| &int.static_methods)
+        .chain(records.iter().map(|rec| &rec.static_methods))
+        .chain(enums.iter().map(|en| &en.static_methods));
+    for methods in static_methods {
+        for meth in methods.iter() {
+            push(&meth.callable);
+        }
+    }
+    for cbi in callback_interfaces.iter() {
+        for meth in cbi.vtable.methods.iter() {
+            push(&meth.callable);
+        }
+    }
+    checksums
+}
+
+/// User-defined types in a namespace, listed in metadata order
+///
+/// The metadata sorts these by module path, then by name.
+pub struct UserTypes<'a> {
+    pub interfaces: Vec<&'a general::Interface>,
+    pub callback_interfaces: Vec<&'a general::CallbackInterface>,
+    pub records: Vec<&'a general::Record>,
+    pub enums: Vec<&'a general::Enum>,
+}
+
+impl<'a> UserTypes<'a> {
+    pub fn new(namespace: &'a general::Namespace) -> Self {
+        let mut user_types = Self {
+            interfaces: vec![],
+            callback_interfaces: vec![],
+            records: vec![],
+            enums: vec![],
+        };
+        for type_def in namespace.type_definitions.iter() {
+            match type_def {
+                general::TypeDefinition::Interface(int) => user_types.interfaces.push(int),
+                general::TypeDefinition::CallbackInterface(cbi) => {
+                    user_types.callback_interfaces.push(cbi)
+                }
+                general::TypeDefinition::Record(rec) => user_types.records.push(rec),
+                general::TypeDefinition::Enum(en) => user_types.enums.push(en),
+                _ => (),
+            }
+        }
+        user_types
+            .interfaces
+            .sort_by(|a, b| (&a.module_path, &a.orig_name).cmp(&(&b.module_path, &b.orig_name)));
+        user_types
+            .callback_interfaces
+            .sort_by(|a, b| (&a.module_path, &a.orig_name).cmp(&(&b.module_path, &b.orig_name)));
+        user_types
+            .records
+            .sort_by(|a, b| (&a.module_path, &a.orig_name).cmp(&(&b.module_path, &b.orig_name)));
+        user_types
+            .enums
+            .sort_by(|a, b| (&a.module_path, &a.orig_name).cmp(&(&b.module_path, &b.orig_name)));
+        user_types
+    }
+}
+
+fn has_type_definition(
+    namespace: &general::Namespace,
+    f: impl Fn(&general::TypeDefinition) -> bool,
+) -> bool {
+    namespace.type_definitions.iter().any(f)
+}
+
+fn has_async_callback_method(namespace: &general::Namespace) -> bool {
+    let callback_interface_async = namespace.has_descendant(|cbi: &general::CallbackInterface| {
+        cbi.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    let trait_interface_async = namespace.has_descendant(|int: &general::Interface| {
+        int.imp.has_callback_interface()
+            && int.has_descendant(|callable: &general::Callable| callable.is_async())
+    });
+    callback_interface_async || trait_interface_async
+}
+
+fn module_imports(module: &Module) -> Vec<String> {
+    let mut imports = BTreeSet::new();
+    module.visit(|custom: &CustomType| {
+        if let Some(custom_config) = &custom.config {
+            imports.extend(custom_config.imports.iter().flatten().cloned());
+        }
+    });
+    imports.into_iter().collect()
+}
+
+fn initialization_fns(module: &Module, context: &Context) -> Vec<String> {
+    let mut init_fns = vec![];
+    // Register the VTables for callback interfaces and trait interfaces
+    for type_def in module.type_definitions.iter() {
+        match type_def {
+            TypeDefinition::CallbackInterface(cbi) => init_fns.push(cbi.callback_init_fn.clone()),
+            TypeDefinition::Interface(int) if int.has_callback_interface() => {
+                init_fns.push(int.callback_init_fn.clone())
+            }
+            _ => (),
+        }
+    }
+    // The foreign closure VTable is shared by all closure types
+    if module.has_closure_types {
+        init_fns.push("uniffiForeignClosureInit".to_string());
+    }
+    // Also call global initialization function for any external type we use.
+    // For example, we need to make sure that all callback interface vtables are registered
+    // (#2343).
+    let mut extern_module_init_fns = BTreeSet::new();
+    module.visit(|ext: &ExternalType| {
+        let crate_name = context
+            .crate_names
+            .get(&ext.namespace)
+            .unwrap_or(&ext.namespace);
+        extern_module_init_fns.insert(names::ensure_init_fn_name(crate_name));
+    });
+    init_fns.extend(extern_module_init_fns);
+    init_fns
+}
@@ -0,0 +1,227 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+use heck::{ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
+use once_cell::sync::Lazy;
+
+use super::*;
+
+/// From <https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure/#Keywords-and-Punctuation>
+static KEYWORDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    [
+        // Keywords used in declarations:
+        "associatedtype",
+        "class",
+        "deinit",
+        "enum",
+        "extension",
+        "fileprivate",
+        "func",
+        "import",
+        "init",
+        "inout",
+        "internal",
+        "let",
+        "open",
+        "operator",
+        "private",
+        "precedencegroup",
+        "protocol",
+        "public",
+        "rethrows",
+        "static",
+        "struct",
+        "subscript",
+        "typealias",
+        "var",
+        // Keywords used in statements:
+        "break",
+        "case",
+        "catch",
+        "continue",
+        "default",
+        "defer",
+        "do",
+        "else",
+        "fallthrough",
+        "for",
+        "guard",
+        "if",
+        "in",
+        "repeat",
+        "return",
+        "throw",
+        "switch",
+        "where",
+        "while",
+        // Keywords used in expressions and types:
+        "Any",
+        "as",
+        "await",
+        "false",
+        "is",
+        "nil",
+        "self",
+        "Self",
+        "super",
+        "throws",
+        "true",
+        "try",
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect::<HashSet<_>>()
+});
+
+/// Per <https://docs.swift.org/swift-book/documentation/the-swift-programming-language/lexicalstructure/#Keywords-and-Punctuation> subset of keywords which need quoting in arg context.
+static ARG_KEYWORDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    ["inout", "var", "let"]
+        .iter()
+        .map(ToString::to_string)
+        .collect::<HashSet<_>>()
+});
+
+pub fn map_ffi_function_type_name(
+    name: FfiFunctionTypeName,
+    context: &Context,
+) -> Result<FfiFunctionTypeName> {
+    Ok(FfiFunctionTypeName(ffi_callback_name(&name.0, context)))
+}
+
+pub fn map_ffi_struct_name(name: FfiStructName, context: &Context) -> Result<FfiStructName> {
+    Ok(FfiStructName(ffi_struct_name(&name.0, context)))
+}
+
+/// Swift name for the `ForeignFutureResult`/`ForeignFutureComplete` FFI types
+///
+/// These are named after the FFI return type only, without the crate that owns the RustBuffer.
+fn foreign_future_name(name: &str, prefix: &str) -> Option<String> {
+    let rest = name.strip_prefix(prefix)?;
+    [
+        "u8",
+        "i8",
+        "u16",
+        "i16",
+        "u32",
+        "i32",
+        "u64",
+        "i64",
+        "f32",
+        "f64",
+        "rust_buffer",
+        "void",
+    ]
+    .into_iter()
+    .find(|return_type_name| {
+        rest.ends_with(return_type_name) || rest.ends_with(&return_type_name.to_upper_camel_case())
+    })
+    .map(|return_type_name| format!("{prefix}{}", return_type_name.to_upper_camel_case()))
+}
+
+/// Quote a name for use in a context where keywords must be quoted
+pub fn quote_general_keyword(nm: String) -> String {
+    if KEYWORDS.contains(&nm) {
+        format!("`{nm}`")
+    } else {
+        nm
+    }
+}
+
+/// Quote a name for use in arg context where fewer keywords must be quoted
+pub fn quote_arg_keyword(nm: String) -> String {
+    if ARG_KEYWORDS.contains(&nm) {
+        format!("`{nm}`")
+    } else {
+        nm
+    }
+}
+
+/// Get the idiomatic Swift rendering of a class name (for enums, records, errors, etc).
+pub fn class_name(nm: &str) -> String {
+    nm.to_upper_camel_case()
+}
+
+/// Get the idiomatic Swift rendering of a function name.
+pub fn fn_name(nm: &str) -> String {
+    quote_general_keyword(nm.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of a variable name.
+pub fn var_name(nm: &str) -> String {
+    quote_general_keyword(nm.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an argument label.
+///
+/// This is the same as the var name, but fewer keywords need quoting.
+pub fn arg_label(nm: &str) -> String {
+    quote_arg_keyword(nm.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an individual enum variant.
+pub fn enum_variant_name(nm: &str) -> String {
+    quote_general_keyword(nm.to_lower_camel_case())
+}
+
+/// Get the idiomatic Swift rendering of an error variant, which uses class names.
+pub fn error_variant_name(nm: &str) -> String {
+    quote_general_keyword(class_name(nm))
+}
+
+/// Get the idiomatic Swift rendering of an FFI callback function name
+pub fn ffi_callback_name(nm: &str, context: &Context) -> String {
+    format!(
+        "Uniffi{}",
+        ffi_callback_base_name(nm, context).to_upper_camel_case()
+    )
+}
+
+/// Get the idiomatic Swift rendering of an FFI struct name
+pub fn ffi_struct_name(nm: &str, context: &Context) -> String {
+    format!(
+        "Uniffi{}",
+        ffi_struct_base_name(nm, context).to_upper_camel_case()
+    )
+}
+
+/// Name of an FFI function type, before the `Uniffi` prefix is added
+fn ffi_callback_base_name(nm: &str, context: &Context) -> String {
+    match context.vtable_ffi_names.get(nm) {
+        Some(vtable_name) => vtable_name.clone(),
+        None => foreign_future_name(nm, "ForeignFutureComplete").unwrap_or_else(|| nm.to_string()),
+    }
+}
+
+/// Name of an FFI struct, before the `Uniffi` prefix is added
+fn ffi_struct_base_name(nm: &str, context: &Context) -> String {
+    match context.vtable_ffi_names.get(nm) {
+        Some(vtable_name) => vtable_name.clone(),
+        None => foreign_future_name(nm, "ForeignFutureResult").unwrap_or_else(|| nm.to_string()),
+    }
+}
+
+/// Get the header guard for an FFI function
+pub fn if_guard_name(nm: &str) -> String {
+    format!("UNIFFI_FFIDEF_{}", nm.to_shouty_snake_case())
+}
+
+/// Get the header guard for an FFI function type
+pub fn ffi_callback_if_guard_name(nm: &str, context: &Context) -> String {
+    if_guard_name(&ffi_callback_base_name(nm, context))
+}
+
+/// Get the header guard for an FFI struct
+pub fn ffi_struct_if_guard_name(nm: &str, context: &Context) -> String {
+    if_guard_name(&ffi_struct_base_name(nm, context))
+}
+
+/// Name of the function that other modules call to make sure this one is initialized
+pub fn ensure_init_fn_name(crate_name: &str) -> String {
+    format!(
+        "uniffiEnsure{}Initialized",
+        crate_name.to_upper_camel_case()
+    )
+}
@@ -0,0 +1,734 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use askama::Template;
+
+use uniffi_pipeline::{MapNode, Node};
+
+use crate::{bindings::swift::filters, pipeline::general};
+
+use super::*;
+
+use_prev_node!(general::Checksum);
+use_prev_node!(general::EnumShape);
+use_prev_node!(general::FieldsKind);
+use_prev_node!(general::FfiFunctionKind);
+use_prev_node!(
+    general::FfiFunctionTypeName,
+    names::map_ffi_function_type_name
+);
+use_prev_node!(general::FfiStructName, names::map_ffi_struct_name);
+use_prev_node!(general::FfiType);
+use_prev_node!(general::HandleKind);
+use_prev_node!(general::ObjectImpl);
+use_prev_node!(general::Radix);
+use_prev_node!(general::RustFfiFunctionName);
+use_prev_node!(general::TraitKind);
+use_prev_node!(general::Type);
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Root))]
+#[map_node(update_context(context.update_from_root(&self)?))]
+pub struct Root {
+    /// In library mode, the library path the user passed to us
+    pub cdylib: Option<String>,
+    #[map_node(from(namespaces))]
+    pub modules: IndexMap<String, Module>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Template)]
+#[template(syntax = "swift", escape = "none", path = "wrapper.swift")]
+#[map_node(from(general::Namespace))]
+#[map_node(modules::map_namespace)]
+pub struct Module {
+    pub name: String,
+    /// Namespace name, as a Swift class name
+    pub class_name: String,
+    pub crate_name: String,
+    /// Name of the Swift module, used for the `.swift` filename
+    pub module_name: String,
+    /// Name of the lower-level C module with the FFI declarations
+    pub ffi_module_name: String,
+    /// Filename of the `.h` file for the lower-level C module
+    pub header_filename: String,
+    /// Filename of the `.modulemap` file for the lower-level C module
+    pub modulemap_filename: String,
+    pub generate_module_map: bool,
+    pub link_frameworks: Vec<String>,
+    pub omit_checksums: bool,
+    pub omit_argument_labels: bool,
+    pub omit_localized_error_conformance: bool,
+    /// Public function that other modules call to ensure this one is initialized
+    pub ensure_init_fn_name: String,
+    pub has_async_fns: bool,
+    pub has_stream_types: bool,
+    pub has_iterator_types: bool,
+    pub has_closure_types: bool,
+    pub has_callback_definitions: bool,
+    pub has_async_callback_method: bool,
+    /// Sorted and de-duplicated modules to import
+    pub imports: Vec<String>,
+    /// Functions to call when the module is initialized
+    pub initialization_fns: Vec<String>,
+    pub docstring: Option<String>,
+    pub functions: Vec<Function>,
+    pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
+    pub ffi_definitions: IndexSet<FfiDefinition>,
+    pub checksums: Vec<Checksum>,
+    pub ffi_rustbuffer_alloc: RustFfiFunctionName,
+    pub ffi_rustbuffer_from_bytes: RustFfiFunctionName,
+    pub ffi_rustbuffer_free: RustFfiFunctionName,
+    pub ffi_rustbuffer_reserve: RustFfiFunctionName,
+    pub ffi_uniffi_contract_version: RustFfiFunctionName,
+    // Correct contract version value
+    pub correct_contract_version: String,
+}
+
+/// Template for generating the `.h` file that defines the low-level C FFI.
+///
+/// This file defines only the low-level structs and functions that are exposed
+/// by the compiled Rust code. It gets wrapped into a higher-level API by the
+/// Swift code from [`Module`].
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "BridgingHeaderTemplate.h")]
+pub struct BridgingHeader<'a> {
+    pub module: &'a Module,
+}
+
+/// Template for generating the `.modulemap` file that exposes the low-level C FFI.
+///
+/// This file defines how the low-level C FFI from [`BridgingHeader`] gets exposed
+/// as a Swift module that can be called by other Swift code. In our case, its only
+/// job is to define the *name* of the Swift module that will contain the FFI functions
+/// so that it can be imported by the higher-level code from [`Module`].
+#[derive(Template)]
+#[template(syntax = "c", escape = "none", path = "ModuleMapTemplate.modulemap")]
+pub struct ModuleMap {
+    pub module_name: String,
+    pub header_filenames: Vec<String>,
+    pub xcframework: bool,
+    pub link_frameworks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Function))]
+pub struct Function {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeDefinition))]
+pub enum TypeDefinition {
+    Interface(Interface),
+    CallbackInterface(CallbackInterface),
+    Record(Record),
+    Enum(Enum),
+    Custom(CustomType),
+    /// Type that doesn't contain any other type
+    Simple(TypeNode),
+    /// Compound types
+    Box(BoxedType),
+    Optional(OptionalType),
+    Sequence(SequenceType),
+    Array(ArrayType),
+    Map(MapType),
+    Set(SetType),
+    Tuple(TupleType),
+    Stream(StreamType),
+    Iterator(IteratorType),
+    Closure(ClosureType),
+    /// User types that are defined in another crate
+    External(ExternalType),
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constructor))]
+pub struct Constructor {
+    pub callable: Callable,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Method))]
+pub struct Method {
+    pub callable: Callable,
+    pub default_ffi_func: Option<RustFfiFunctionName>,
+    pub docstring: Option<String>,
+}
+
+/// Common data from Function/Method/Constructor
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Callable))]
+pub struct Callable {
+    #[map_node(names::fn_name(&self.name))]
+    pub name: String,
+    pub async_data: Option<AsyncData>,
+    pub kind: CallableKind,
+    pub arguments: Vec<Argument>,
+    pub return_type: ReturnType,
+    pub throws_type: ThrowsType,
+    pub checksum: Option<u16>,
+    pub ffi_func: RustFfiFunctionName,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallableKind))]
+pub enum CallableKind {
+    /// Toplevel function
+    Function,
+    /// Interface/Trait interface method
+    Method { self_type: TypeNode },
+    /// Interface constructor
+    Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
+    /// Method inside a VTable or a CallbackInterface
+    VTableMethod {
+        self_type: TypeNode,
+        for_callback_interface: bool,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::AsyncData))]
+pub struct AsyncData {
+    // FFI types for async Rust functions
+    pub ffi_rust_future_poll: RustFfiFunctionName,
+    pub ffi_rust_future_cancel: RustFfiFunctionName,
+    pub ffi_rust_future_free: RustFfiFunctionName,
+    pub ffi_rust_future_complete: RustFfiFunctionName,
+    // FFI types for async foreign functions
+    pub ffi_foreign_future_complete: FfiFunctionTypeName,
+    pub ffi_foreign_future_result: FfiStructName,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ReturnType))]
+#[map_node(types::map_return_type)]
+pub struct ReturnType {
+    pub ty: Option<TypeNode>,
+    /// Swift type name, `()` for functions without a return type
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ThrowsType))]
+pub struct ThrowsType {
+    pub ty: Option<TypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Argument))]
+pub struct Argument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Label for the argument when calling a Swift function
+    #[map_node(names::arg_label(&self.name))]
+    pub label: String,
+    /// Type name for the argument in Swift declarations
+    #[map_node(callables::arg_type_name(&self))]
+    pub type_name: String,
+    /// Expression for the argument in an FFI call
+    #[map_node(callables::arg_expr(&self))]
+    pub arg_expr: String,
+    /// Is this a `&[u8]` argument?
+    ///
+    /// These are passed across the FFI without copying, using a `FfiConverterByRefBytes.lower`
+    /// closure.
+    #[map_node(callables::is_borrowed_bytes(&self))]
+    pub is_borrowed_bytes: bool,
+    #[map_node(match &self.default {
+        Some(default) => Some(default::render_default(default, &self.ty.ty)?),
+        None => None,
+    })]
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub by_ref: bool,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Constant))]
+pub struct Constant {
+    #[map_node(default::render_default(
+        &general::DefaultValue::Literal(self.value.clone()),
+        &self.ty.ty,
+    )?)]
+    pub value: String,
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Record))]
+pub struct Record {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    #[map_node(context.config()?.is_record_immutable(&self.name))]
+    pub is_immutable: bool,
+    #[map_node(conformances::record_conformances(&self, context)?)]
+    pub conformances: Vec<String>,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Field))]
+pub struct Field {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Label for the field when calling a Swift initializer
+    #[map_node(names::arg_label(&self.name))]
+    pub label: String,
+    /// Name to bind the field to when destructuring an enum variant
+    ///
+    /// For unnamed fields, this is set to `v1`, `v2`, etc. when mapping the variant.
+    #[map_node(names::var_name(&self.name))]
+    pub binding_name: String,
+    #[map_node(match &self.default {
+        Some(default) => Some(default::render_default(default, &self.ty.ty)?),
+        None => None,
+    })]
+    pub default: Option<String>,
+    pub ty: TypeNode,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Enum))]
+pub struct Enum {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    /// Protocol conformances
+    ///
+    /// For errors, this doesn't include `Swift.Error`, which is always listed first.
+    #[map_node(conformances::enum_conformances(&self, context)?)]
+    pub conformances: Vec<String>,
+    #[map_node(enums::map_variants(self.variants, &self.shape, context)?)]
+    pub variants: Vec<Variant>,
+    /// Is this a "flat" enum -- one with no associated data
+    pub is_flat: bool,
+    pub shape: EnumShape,
+    pub discr_type: TypeNode,
+    /// Was `discr_type` specified with a `#[repr]` attribute?
+    ///
+    /// These are generated as Swift enums with a raw value type.
+    pub discr_type_specified: bool,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub recursive: bool,
+}
+
+#[derive(Debug, Clone, Node)]
+pub struct Variant {
+    pub name: String,
+    /// The discriminant, rendered as a Swift literal
+    pub discr: String,
+    /// Are the fields declared without labels?
+    pub has_nameless_fields: bool,
+    pub fields_kind: FieldsKind,
+    pub fields: Vec<Field>,
+    pub docstring: Option<String>,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::Interface))]
+pub struct Interface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    /// Name of the Swift protocol for this object
+    #[map_node(interfaces::protocol_name(&self))]
+    pub protocol_name: String,
+    /// Name of the Swift class that implements the protocol by calling into Rust
+    #[map_node(interfaces::impl_class_name(&self))]
+    pub impl_class_name: String,
+    /// Swift protocols for the traits this object implements
+    #[map_node(interfaces::trait_protocol_names(&self)?)]
+    pub trait_protocol_names: Vec<String>,
+    #[map_node(conformances::interface_conformances(&self, context)?)]
+    pub conformances: Vec<String>,
+    /// Function that registers the VTable, for interfaces that can be implemented in Swift
+    #[map_node(interfaces::callback_init_fn(&self.name))]
+    pub callback_init_fn: String,
+    pub constructors: Vec<Constructor>,
+    pub docstring: Option<String>,
+    pub methods: Vec<Method>,
+    pub static_methods: Vec<Method>,
+    pub uniffi_trait_methods: UniffiTraitMethods,
+    pub imp: ObjectImpl,
+    pub self_type: TypeNode,
+    pub vtable: Option<VTable>,
+    pub ffi_func_clone: RustFfiFunctionName,
+    pub ffi_func_free: RustFfiFunctionName,
+}
+
+impl Interface {
+    pub fn primary_constructor(&self) -> Option<&Constructor> {
+        self.constructors
+            .iter()
+            .find(|c| c.callable.is_primary_constructor())
+    }
+
+    pub fn alternate_constructors(&self) -> Vec<&Constructor> {
+        self.constructors
+            .iter()
+            .filter(|c| !c.callable.is_primary_constructor())
+            .collect()
+    }
+
+    pub fn has_callback_interface(&self) -> bool {
+        self.imp.has_callback_interface()
+    }
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CallbackInterface))]
+pub struct CallbackInterface {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    /// Function that registers the VTable
+    #[map_node(interfaces::callback_init_fn(&self.name))]
+    pub callback_init_fn: String,
+    pub docstring: Option<String>,
+    pub vtable: VTable,
+    pub methods: Vec<Method>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTable))]
+pub struct VTable {
+    pub struct_type: FfiTypeNode,
+    pub interface_name: String,
+    pub init_fn: RustFfiFunctionName,
+    pub clone_fn_type: FfiFunctionTypeName,
+    pub free_fn_type: FfiFunctionTypeName,
+    pub methods: Vec<VTableMethod>,
+}
+
+/// Single method in a vtable
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::VTableMethod))]
+pub struct VTableMethod {
+    /// FFI callback type for the method
+    #[map_node(ffi_types::vtable_method_callback(&self, context)?)]
+    pub ffi_callback: FfiFunctionType,
+    /// Name of the VTable struct field for this method
+    #[map_node(ffi_types::vtable_method_field_name(&self, context)?)]
+    pub ffi_field_name: String,
+    /// Default value to return when the method fails
+    #[map_node(ffi_types::ffi_default_value(&self.callable.return_type)?)]
+    pub ffi_default_value: String,
+    pub callable: Callable,
+    pub ffi_type: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::CustomType))]
+pub struct CustomType {
+    #[map_node(context.custom_type_config(&self)?)]
+    pub config: Option<CustomTypeConfig>,
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub builtin: TypeNode,
+    pub docstring: Option<String>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::BoxedType))]
+pub struct BoxedType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::OptionalType))]
+pub struct OptionalType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SequenceType))]
+pub struct SequenceType {
+    pub inner: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ArrayType))]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::MapType))]
+pub struct MapType {
+    pub key: TypeNode,
+    pub value: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::SetType))]
+pub struct SetType {
+    pub inner: TypeNode,
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TupleType))]
+pub struct TupleType {
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::StreamType))]
+pub struct StreamType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::IteratorType))]
+pub struct IteratorType {
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ClosureType))]
+pub struct ClosureType {
+    pub arguments: Vec<TypeNode>,
+    pub return_type: Option<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::ExternalType))]
+pub struct ExternalType {
+    #[map_node(names::class_name(&self.name))]
+    pub name: String,
+    pub namespace: String,
+    pub self_type: TypeNode,
+}
+
+/// Wrap `Type` so that we can add extra fields that are set for all variants.
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::TypeNode))]
+pub struct TypeNode {
+    #[map_node(types::type_name(&self.ty))]
+    pub type_name: String,
+    #[map_node(types::canonical_name(&self.ty))]
+    pub canonical_name: String,
+    #[map_node(types::ffi_converter_name(&self.ty))]
+    pub ffi_converter_name: String,
+    /// FFI converter used to lift this type when it's thrown as an error
+    #[map_node(types::ffi_error_converter_name(&self.ty))]
+    pub ffi_error_converter_name: String,
+    /// Function to lower values
+    ///
+    /// For named types this is the public `{FfiConverter}_lower` function, which also works for
+    /// types defined in other modules.
+    #[map_node(types::lower_fn(&self.ty))]
+    pub lower_fn: String,
+    /// Function to lift values, see `lower_fn`
+    #[map_node(types::lift_fn(&self.ty))]
+    pub lift_fn: String,
+    pub ty: Type,
+    pub is_used_as_error: bool,
+    pub ffi_type: FfiTypeNode,
+}
+
+/// Like `TypeNode` but for FFI types.
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(FfiType))]
+pub struct FfiTypeNode {
+    /// Swift type name
+    #[map_node(ffi_types::ffi_type_name(&self, context))]
+    pub type_name: String,
+    /// C type name, used in the bridging header
+    #[map_node(ffi_types::header_ffi_type_name(&self, context))]
+    pub header_type_name: String,
+    #[map_node(self.map_node(context)?)]
+    pub ty: FfiType,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(general::UniffiTraitMethods))]
+pub struct UniffiTraitMethods {
+    pub debug_fmt: Option<Method>,
+    pub display_fmt: Option<Method>,
+    pub eq_eq: Option<Method>,
+    pub eq_ne: Option<Method>,
+    pub hash_hash: Option<Method>,
+    pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<Method>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, Eq, PartialEq, Hash)]
+#[map_node(from(general::FfiDefinition))]
+pub enum FfiDefinition {
+    /// FFI Function exported in the Rust library
+    RustFunction(FfiFunction),
+    /// FFI Function definition used in the interface, language, for example a callback interface method.
+    FunctionType(FfiFunctionType),
+    /// Struct definition used in the interface, for example a callback interface Vtable.
+    Struct(FfiStruct),
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunction))]
+pub struct FfiFunction {
+    #[map_node(names::if_guard_name(&self.name.0))]
+    pub if_guard_name: String,
+    pub name: RustFfiFunctionName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+    pub kind: FfiFunctionKind,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiFunctionType))]
+pub struct FfiFunctionType {
+    #[map_node(names::ffi_callback_if_guard_name(&self.name.0, context))]
+    pub if_guard_name: String,
+    pub name: FfiFunctionTypeName,
+    pub arguments: Vec<FfiArgument>,
+    pub return_type: FfiReturnType,
+    pub has_rust_call_status_arg: bool,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiReturnType))]
+pub struct FfiReturnType {
+    pub ty: Option<FfiTypeNode>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiStruct))]
+pub struct FfiStruct {
+    #[map_node(names::ffi_struct_if_guard_name(&self.name.0, context))]
+    pub if_guard_name: String,
+    pub name: FfiStructName,
+    pub fields: Vec<FfiField>,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiField))]
+pub struct FfiField {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    pub ty: FfiTypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode, PartialEq, Eq, Hash)]
+#[map_node(from(general::FfiArgument))]
+pub struct FfiArgument {
+    #[map_node(names::var_name(&self.name))]
+    pub name: String,
+    /// Name for the argument in the bridging header
+    #[map_node(self.name.clone())]
+    pub header_name: String,
+    pub ty: FfiTypeNode,
+}
+
+impl Callable {
+    pub fn is_async(&self) -> bool {
+        self.async_data.is_some()
+    }
+
+    pub fn throws(&self) -> bool {
+        self.throws_type.ty.is_some()
+    }
+
+    pub fn is_primary_constructor(&self) -> bool {
+        matches!(self.kind, CallableKind::Constructor { primary: true, .. })
+    }
+
+    /// Type of `self` for methods
+    pub fn self_type(&self) -> Option<&TypeNode> {
+        match &self.kind {
+            CallableKind::Method { self_type } | CallableKind::VTableMethod { self_type, .. } => {
+                Some(self_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Is this a method on an interface?
+    ///
+    /// These pass `self` by cloning the handle, rather than by lowering it.
+    pub fn self_is_interface(&self) -> bool {
+        matches!(
+            self.self_type(),
+            Some(TypeNode {
+                ty: Type::Interface { .. },
+                ..
+            })
+        )
+    }
+
+    /// Arguments that are passed across the FFI without copying
+    pub fn borrowed_bytes_arguments(&self) -> Vec<&Argument> {
+        self.arguments
+            .iter()
+            .filter(|arg| arg.is_borrowed_bytes)
+            .collect()
+    }
+}
+
+impl TypeDefinition {
+    pub fn self_type(&self) -> &TypeNode {
+        match self {
+            Self::Interface(int) => &int.self_type,
+            Self::CallbackInterface(cbi) => &cbi.self_type,
+            Self::Record(rec) => &rec.self_type,
+            Self::Enum(en) => &en.self_type,
+            Self::Custom(custom) => &custom.self_type,
+            Self::Simple(type_node) => type_node,
+            Self::Box(boxed) => &boxed.self_type,
+            Self::Optional(opt) => &opt.self_type,
+            Self::Sequence(seq) => &seq.self_type,
+            Self::Array(array) => &array.self_type,
+            Self::Map(map) => &map.self_type,
+            Self::Set(set) => &set.self_type,
+            Self::Tuple(tuple) => &tuple.self_type,
+            Self::Stream(stream) => &stream.self_type,
+            Self::Iterator(iterator) => &iterator.self_type,
+            Self::Closure(closure) => &closure.self_type,
+            Self::External(ext) => &ext.self_type,
+        }
+    }
+}
+
+impl Variant {
+    pub fn has_fields(&self) -> bool {
+        !self.fields.is_empty()
+    }
+}
@@ -0,0 +1,212 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::*;
+
+/// Swift-specific canonical name for a type
+///
+/// This differs from the general canonical name in a few ways: primitives use the Swift type
+/// names, boxed types use the inner type directly and maps are called dictionaries.
+pub fn canonical_name(ty: &Type) -> String {
+    match ty {
+        Type::Char => "Char".to_string(),
+        Type::Timestamp => "Timestamp".to_string(),
+        Type::Duration => "Duration".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Int128 => "Int128".to_string(),
+        Type::Interface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::Custom { name, .. } => format!("Type{name}"),
+        Type::CallbackInterface { name, .. } => {
+            format!("CallbackInterface{}", names::class_name(name))
+        }
+        Type::Box { inner_type } => canonical_name(inner_type),
+        Type::Optional { inner_type } => format!("Option{}", canonical_name(inner_type)),
+        Type::Sequence { inner_type } => format!("Sequence{}", canonical_name(inner_type)),
+        Type::Array { inner_type, len } => format!("Array{len}{}", canonical_name(inner_type)),
+        Type::Map {
+            key_type,
+            value_type,
+            ordered,
+        } => format!(
+            "{}Dictionary{}{}",
+            if *ordered { "Ordered" } else { "" },
+            canonical_name(key_type),
+            canonical_name(value_type),
+        ),
+        Type::Set {
+            inner_type,
+            ordered,
+        } => format!(
+            "{}Set{}",
+            if *ordered { "Ordered" } else { "" },
+            canonical_name(inner_type)
+        ),
+        Type::Tuple { inner_types } => format!(
+            "Tuple{}{}",
+            inner_types.len(),
+            inner_types
+                .iter()
+                .map(canonical_name)
+                .collect::<Vec<_>>()
+                .join(""),
+        ),
+        Type::Stream { item_type } => format!("Stream{}", canonical_name(item_type)),
+        Type::Iterator { item_type } => format!("Iterator{}", canonical_name(item_type)),
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "Closure{}{}To{}",
+            argument_types.len(),
+            argument_types
+                .iter()
+                .map(canonical_name)
+                .collect::<Vec<_>>()
+                .join(""),
+            match return_type {
+                Some(return_type) => canonical_name(return_type),
+                None => "Unit".to_string(),
+            },
+        ),
+        _ => type_name(ty),
+    }
+}
+
+pub fn type_name(ty: &Type) -> String {
+    match ty {
+        Type::Boolean => "Bool".to_string(),
+        Type::String => "String".to_string(),
+        Type::Bytes => "Data".to_string(),
+        Type::Int8 => "Int8".to_string(),
+        Type::Int16 => "Int16".to_string(),
+        Type::Int32 => "Int32".to_string(),
+        Type::Int64 => "Int64".to_string(),
+        Type::UInt8 => "UInt8".to_string(),
+        Type::UInt16 => "UInt16".to_string(),
+        Type::UInt32 => "UInt32".to_string(),
+        Type::UInt64 => "UInt64".to_string(),
+        // 128-bit integers use generated structs, see `UInt128Helper.swift`.
+        Type::UInt128 => "UniffiUInt128".to_string(),
+        Type::Int128 => "UniffiInt128".to_string(),
+        Type::Float32 => "Float".to_string(),
+        Type::Float64 => "Double".to_string(),
+        Type::Char => "Unicode.Scalar".to_string(),
+        Type::Timestamp => "Date".to_string(),
+        Type::Duration => "TimeInterval".to_string(),
+        Type::Interface { name, .. }
+        | Type::Record { name, .. }
+        | Type::Enum { name, .. }
+        | Type::CallbackInterface { name, .. }
+        | Type::Custom { name, .. } => names::class_name(name),
+        Type::Box { inner_type } => type_name(inner_type),
+        Type::Optional { inner_type } => format!("{}?", type_name(inner_type)),
+        Type::Sequence { inner_type } | Type::Array { inner_type, .. } => {
+            format!("[{}]", type_name(inner_type))
+        }
+        // `Dictionary` doesn't preserve order, so ordered maps are an array of key/value pairs.
+        // This is the same element type that you get when iterating over a `Dictionary`.
+        Type::Map {
+            key_type,
+            value_type,
+            ordered: true,
+        } => format!(
+            "[(key: {}, value: {})]",
+            type_name(key_type),
+            type_name(value_type)
+        ),
+        Type::Map {
+            key_type,
+            value_type,
+            ordered: false,
+        } => format!("[{}: {}]", type_name(key_type), type_name(value_type)),
+        // `Set` doesn't preserve order, so ordered sets are an array.
+        Type::Set {
+            inner_type,
+            ordered: true,
+        } => format!("[{}]", type_name(inner_type)),
+        Type::Set {
+            inner_type,
+            ordered: false,
+        } => format!("Set<{}>", type_name(inner_type)),
+        Type::Tuple { inner_types } => format!(
+            "({})",
+            inner_types
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Type::Stream { item_type } => {
+            format!("AsyncThrowingStream<{}, Error>", type_name(item_type))
+        }
+        Type::Iterator { item_type } => format!("AnyIterator<{}>", type_name(item_type)),
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "({}) -> {}",
+            argument_types
+                .iter()
+                .map(type_name)
+                .collect::<Vec<_>>()
+                .join(", "),
+            match return_type {
+                Some(return_type) => type_name(return_type),
+                None => "Void".to_string(),
+            }
+        ),
+    }
+}
+
+pub fn map_return_type(return_type: general::ReturnType, context: &Context) -> Result<ReturnType> {
+    Ok(match return_type.ty {
+        Some(ty) => {
+            let ty = ty.map_node(context)?;
+            ReturnType {
+                type_name: ty.type_name.clone(),
+                ty: Some(ty),
+            }
+        }
+        None => ReturnType {
+            ty: None,
+            type_name: "()".to_string(),
+        },
+    })
+}
+
+pub fn ffi_converter_name(ty: &Type) -> String {
+    format!("FfiConverter{}", canonical_name(ty))
+}
+
+/// Name of the converter used to lift errors
+///
+/// Objects are passed as handles, but errors are always lifted from a `RustBuffer`, so they get
+/// a separate converter.
+pub fn ffi_error_converter_name(ty: &Type) -> String {
+    let name = ffi_converter_name(ty);
+    match ty {
+        Type::Interface { .. } => format!("{name}__as_error"),
+        _ => name,
+    }
+}
+
+// To better support external types, we always call the "public" lift and lower functions for
+// "named" types, regardless of whether they are being called from a type in the same crate
+// (ie, a "local" type) or from a different crate (ie, an "external" type)
+pub fn lower_fn(ty: &Type) -> String {
+    match ty.name() {
+        Some(_) => format!("{}_lower", ffi_converter_name(ty)),
+        None => format!("{}.lower", ffi_converter_name(ty)),
+    }
+}
+
+// See above re lower_fn - we always use the public version for named types.
+pub fn lift_fn(ty: &Type) -> String {
+    match ty.name() {
+        Some(_) => format!("{}_lift", ffi_converter_name(ty)),
+        None => format!("{}.lift", ffi_converter_name(ty)),
+    }
+}
@@ -0,0 +1,32 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+    typealias SwiftType = {{ type_name }}
+
+    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+        // Lowering can't throw, so a wrong length traps before anything is sent to Rust.
+        precondition(
+            value.count == {{ len }},
+            "\(UniffiInternalError.unexpectedArrayLength): expected {{ len }} items, got \(value.count)"
+        )
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            {{ inner_type.ffi_converter_name }}.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        let len: Int32 = try readInt(&buf)
+        if len != {{ len }} {
+            throw UniffiInternalError.unexpectedArrayLength
+        }
+        var seq = {{ type_name }}()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try {{ inner_type.ffi_converter_name }}.read(from: &buf))
+        }
+        return seq
+    }
+}
@@ -6,6 +6,7 @@ fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation
 fileprivate func uniffiRustCallAsync<F, T>(
     rustFutureFunc: () -> UInt64,
     pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    cancelFunc: @escaping @Sendable (UInt64) -> (),
     completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
     freeFunc: (UInt64) -> (),
     liftFunc: (F) throws -> T,
@@ -18,18 +19,28 @@ fileprivate func uniffiRustCallAsync<F, T>(
     defer {
         freeFunc(rustFuture)
     }
-    var pollResult: Int8;
-    repeat {
-        pollResult = await withUnsafeContinuation {
-            pollFunc(
-                rustFuture,
-                { handle, pollResult in
-                    uniffiFutureContinuationCallback(handle: handle, pollResult: pollResult)
-                },
-                uniffiContinuationHandleMap.insert(obj: $0)
-            )
-        }
-    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
+    // If the task is cancelled, cancel the Rust future so that it gets dropped promptly.  This
+    // causes the poll loop to finish and `completeFunc` to return `CALL_CANCELLED`, which is
+    // thrown as a `CancellationError`.
+    //
+    // Non-throwing functions call this with `try!`, so cancelling their task traps.  They have no
+    // value to return for a future that didn't complete.
+    await withTaskCancellationHandler {
+        var pollResult: Int8;
+        repeat {
+            pollResult = await withUnsafeContinuation {
+                pollFunc(
+                    rustFuture,
+                    { handle, pollResult in
+                        uniffiFutureContinuationCallback(handle: handle, pollResult: pollResult)
+                    },
+                    uniffiContinuationHandleMap.insert(obj: $0)
+                )
+            }
+        } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
+    } onCancel: {
+        cancelFunc(rustFuture)
+    }
 
     return try liftFunc(makeRustCall(
         { completeFunc(rustFuture, $0) },
@@ -47,7 +58,64 @@ fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: In
     }
 }
 
-{%- if ci.has_async_callback_interface_definition() %}
+{%- if has_stream_types %}
+// Handle to a Rust stream
+//
+// Each item is fetched by awaiting a Rust future that returns an `Optional` item, where `nil`
+// signals the end of the stream.  The Rust stream is freed once it's exhausted, when fetching an
+// item fails or when the handle is deinitialized.
+fileprivate final class UniffiRustStreamHandle: @unchecked Sendable {
+    private let lock = NSLock()
+    private var handle: UInt64?
+
+    init(_ handle: UInt64) {
+        self.handle = handle
+    }
+
+    deinit {
+        free()
+    }
+
+    func next<T>(liftFunc: @escaping (RustBuffer) throws -> T?) async throws -> T? {
+        let currentHandle = lock.withLock { self.handle }
+        guard let handle = currentHandle else {
+            return nil
+        }
+        let item: T?
+        do {
+            item = try await uniffiRustCallAsync(
+                rustFutureFunc: { ffi_{{ crate_name }}_rust_stream_next(handle) },
+                pollFunc: ffi_{{ crate_name }}_rust_future_poll_rust_buffer,
+                cancelFunc: ffi_{{ crate_name }}_rust_future_cancel_rust_buffer,
+                completeFunc: ffi_{{ crate_name }}_rust_future_complete_rust_buffer,
+                freeFunc: ffi_{{ crate_name }}_rust_future_free_rust_buffer,
+                liftFunc: liftFunc,
+                errorHandler: nil
+            )
+        } catch {
+            free()
+            throw error
+        }
+        if item == nil {
+            free()
+        }
+        return item
+    }
+
+    private func free() {
+        let handle = lock.withLock { () -> UInt64? in
+            let handle = self.handle
+            self.handle = nil
+            return handle
+        }
+        if let handle = handle {
+            ffi_{{ crate_name }}_rust_stream_free(handle)
+        }
+    }
+}
+
+{% endif %}
+{%- if has_async_callback_method %}
 private func uniffiTraitInterfaceCallAsync<T>(
     makeCall: @escaping () async throws -> T,
     handleSuccess: @escaping (T) -> (),
@@ -67,7 +135,7 @@ private func uniffiTraitInterfaceCallAsync<T>(
         do {
             callResult = try await makeCall()
         } catch {
-            handleError(CALL_UNEXPECTED_ERROR, {{ Type::String.borrow()|lower_fn }}(String(describing: error)))
+            handleError(CALL_UNEXPECTED_ERROR, FfiConverterString.lower(String(describing: error)))
             return
         }
         handleSuccess(callResult)
@@ -96,7 +164,7 @@ private func uniffiTraitInterfaceCallAsyncWithError<T, E>(
             handleError(CALL_ERROR, lowerError(error))
             return
         } catch {
-            handleError(CALL_UNEXPECTED_ERROR, {{ Type::String.borrow()|lower_fn }}(String(describing: error)))
+            handleError(CALL_UNEXPECTED_ERROR, FfiConverterString.lower(String(describing: error)))
             return
         }
         handleSuccess(callResult)
@@ -135,7 +203,7 @@ private func uniffiForeignFutureDroppedCallback(handle: UInt64) {
 }
 
 // For testing
-public func uniffiForeignFutureHandleCount{{ ci.namespace()|class_name }}() -> Int {
+public func uniffiForeignFutureHandleCount{{ class_name }}() -> Int {
     UNIFFI_FOREIGN_FUTURE_HANDLE_MAP.count
 }
 
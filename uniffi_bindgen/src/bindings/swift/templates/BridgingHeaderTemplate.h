@@ -45,37 +45,41 @@ typedef struct RustCallStatus {
 // ⚠️ increment the version suffix in all instances of UNIFFI_SHARED_HEADER_V4 in this file.           ⚠️
 #endif // def UNIFFI_SHARED_H
 
-{%- for def in ci.ffi_definitions() %}
-#ifndef {{ def.name()|if_guard_name }}
-#define {{ def.name()|if_guard_name }}
+{%- for def in module.ffi_definitions %}
 {%- match def %}
-{% when FfiDefinition::CallbackFunction(callback) %}
+{% when FfiDefinition::FunctionType(callback) %}
+#ifndef {{ callback.if_guard_name }}
+#define {{ callback.if_guard_name }}
 typedef
-    {%- match callback.return_type() %}{% when Some(return_type) %} {{ return_type|header_ffi_type_name }} {% when None %} void {% endmatch -%}
-    (*{{ callback.name()|ffi_callback_name }})(
-        {%- for arg in callback.arguments() -%}
-        {{ arg.type_().borrow()|header_ffi_type_name }}
-        {%- if !loop.last || callback.has_rust_call_status_arg() %}, {% endif %}
+    {%- match callback.return_type.ty %}{% when Some(return_type) %} {{ return_type.header_type_name }} {% when None %} void {% endmatch -%}
+    (*{{ callback.name.0 }})(
+        {%- for arg in callback.arguments -%}
+        {{ arg.ty.header_type_name }}
+        {%- if !loop.last || callback.has_rust_call_status_arg %}, {% endif %}
         {%- endfor -%}
-        {%- if callback.has_rust_call_status_arg() %}
+        {%- if callback.has_rust_call_status_arg %}
         RustCallStatus *_Nonnull uniffiCallStatus
         {%- endif %}
     );
 {% when FfiDefinition::Struct(struct_item) %}
-typedef struct {{ struct_item.name()|ffi_struct_name }} {
-    {%- for field in struct_item.fields() %}
-    {{ field.type_().borrow()|header_ffi_type_name }} {{ field.name()|var_name }};
+#ifndef {{ struct_item.if_guard_name }}
+#define {{ struct_item.if_guard_name }}
+typedef struct {{ struct_item.name.0 }} {
+    {%- for field in struct_item.fields %}
+    {{ field.ty.header_type_name }} {{ field.name }};
     {%- endfor %}
-} {{ struct_item.name()|ffi_struct_name }};
-{% when FfiDefinition::Function(func) %}
-{% match func.return_type() -%}{%- when Some(type_) %}{{ type_|header_ffi_type_name }}{% when None %}void{% endmatch %} {{ func.name() }}(
-    {%- if func.arguments().len() > 0 %}
-        {%- for arg in func.arguments() %}
-            {{- arg.type_().borrow()|header_ffi_type_name }} {{ arg.name() -}}{% if !loop.last || func.has_rust_call_status_arg() %}, {% endif %}
+} {{ struct_item.name.0 }};
+{% when FfiDefinition::RustFunction(func) %}
+#ifndef {{ func.if_guard_name }}
+#define {{ func.if_guard_name }}
+{% match func.return_type.ty -%}{%- when Some(type_) %}{{ type_.header_type_name }}{% when None %}void{% endmatch %} {{ func.name.0 }}(
+    {%- if !func.arguments.is_empty() %}
+        {%- for arg in func.arguments %}
+            {{- arg.ty.header_type_name }} {{ arg.header_name -}}{% if !loop.last || func.has_rust_call_status_arg %}, {% endif %}
         {%- endfor %}
-        {%- if func.has_rust_call_status_arg() %}RustCallStatus *_Nonnull out_status{% endif %}
+        {%- if func.has_rust_call_status_arg %}RustCallStatus *_Nonnull out_status{% endif %}
     {%- else %}
-        {%- if func.has_rust_call_status_arg() %}RustCallStatus *_Nonnull out_status{%- else %}void{% endif %}
+        {%- if func.has_rust_call_status_arg %}RustCallStatus *_Nonnull out_status{%- else %}void{% endif %}
     {% endif %}
 );
 {%- endmatch %}
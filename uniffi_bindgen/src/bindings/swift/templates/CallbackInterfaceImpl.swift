@@ -1,4 +1,4 @@
-{%- let trait_impl=format!("UniffiCallbackInterface{}", name) %}
+{%- let trait_impl = format!("UniffiCallbackInterface{}", vtable.interface_name) %}
 
 // Put the implementation in a struct so we don't pollute the top-level namespace
 fileprivate struct {{ trait_impl }} {
@@ -7,51 +7,51 @@ fileprivate struct {{ trait_impl }} {
     // Swift automatically converts these into C callback functions.
     //
     // Store the vtable directly.
-    static let vtable: {{ vtable|ffi_type_name }} = {{ vtable|ffi_type_name }}(
+    static let vtable: {{ vtable.struct_type.type_name }} = {{ vtable.struct_type.type_name }}(
         uniffiFree: { (uniffiHandle: UInt64) -> () in
             do {
                 try {{ ffi_converter_name }}.handleMap.remove(handle: uniffiHandle)
             } catch {
-                print("Uniffi callback interface {{ name }}: handle missing in uniffiFree")
+                print("Uniffi callback interface {{ vtable.interface_name }}: handle missing in uniffiFree")
             }
         },
         uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
             do {
                 return try {{ ffi_converter_name }}.handleMap.clone(handle: uniffiHandle)
             } catch {
-                fatalError("Uniffi callback interface {{ name }}: handle missing in uniffiClone")
+                fatalError("Uniffi callback interface {{ vtable.interface_name }}: handle missing in uniffiClone")
             }
         },
-        {%- for (ffi_callback, meth) in vtable_methods %}
-        {{ meth.name()|fn_name }}: { (
-            {%- for arg in ffi_callback.arguments() %}
-            {{ arg.name()|var_name }}: {{ arg.type_().borrow()|ffi_type_name }}{% if !loop.last || ffi_callback.has_rust_call_status_arg() %},{% endif %}
+        {%- for vtable_meth in vtable.methods %}{% let ffi_callback = vtable_meth.ffi_callback %}{% let meth = vtable_meth.callable %}
+        {{ vtable_meth.ffi_field_name }}: { (
+            {%- for arg in ffi_callback.arguments %}
+            {{ arg.name }}: {{ arg.ty.type_name }}{% if !loop.last || ffi_callback.has_rust_call_status_arg %},{% endif %}
             {%- endfor -%}
-            {%- if ffi_callback.has_rust_call_status_arg() %}
+            {%- if ffi_callback.has_rust_call_status_arg %}
             uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
             {%- endif %}
         ) in
             let makeCall = {
-                () {% if meth.is_async() %}async {% endif %}throws -> {% match meth.return_type() %}{% when Some(t) %}{{ t|type_name }}{% when None %}(){% endmatch %} in
+                () {% if meth.is_async() %}async {% endif %}throws -> {{ meth.return_type.type_name }} in
                 guard let uniffiObj = try? {{ ffi_converter_name }}.handleMap.get(handle: uniffiHandle) else {
                     throw UniffiInternalError.unexpectedStaleHandle
                 }
-                return {% if meth.throws() %}try {% endif %}{% if meth.is_async() %}await {% endif %}uniffiObj.{{ meth.name()|fn_name }}(
-                    {%- for arg in meth.arguments() %}
-                    {% if !config.omit_argument_labels() %} {{ arg.name()|arg_name }}: {% endif %}try {{ arg|lift_fn }}({{ arg.name()|var_name }}){% if !loop.last %},{% endif %}
+                return {% if meth.throws() %}try {% endif %}{% if meth.is_async() %}await {% endif %}uniffiObj.{{ meth.name }}(
+                    {%- for arg in meth.arguments %}
+                    {% if !omit_argument_labels %} {{ arg.label }}: {% endif %}try {{ arg.ty.lift_fn }}({{ arg.name }}){% if !loop.last %},{% endif %}
                     {%- endfor %}
                 )
             }
             {%- if !meth.is_async() %}
 
-            {% match meth.return_type() %}
+            {% match meth.return_type.ty %}
             {%- when Some(t) %}
-            let writeReturn = { uniffiOutReturn.pointee = {{ t|lower_fn }}($0) }
+            let writeReturn = { uniffiOutReturn.pointee = {{ t.lower_fn }}($0) }
             {%- when None %}
             let writeReturn = { () }
             {%- endmatch %}
 
-            {%- match meth.throws_type() %}
+            {%- match meth.throws_type.ty %}
             {%- when None %}
             uniffiTraitInterfaceCall(
                 callStatus: uniffiCallStatus,
@@ -63,17 +63,17 @@ fileprivate struct {{ trait_impl }} {
                 callStatus: uniffiCallStatus,
                 makeCall: makeCall,
                 writeReturn: writeReturn,
-                lowerError: {{ error_type|lower_fn }}
+                lowerError: {{ error_type.lower_fn }}
             )
             {%- endmatch %}
             {%- else %}
 
-            let uniffiHandleSuccess = { (returnValue: {{ meth.return_type()|return_type_name }}) in
+            let uniffiHandleSuccess = { (returnValue: {{ meth.return_type.type_name }}) in
                 uniffiFutureCallback(
                     uniffiCallbackData,
-                    {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}(
-                        {%- if let Some(return_type) = meth.return_type() %}
-                        returnValue: {{ return_type|lower_fn }}(returnValue),
+                    {{ meth.async_data.as_ref().unwrap().ffi_foreign_future_result.0 }}(
+                        {%- if let Some(return_type) = meth.return_type.ty %}
+                        returnValue: {{ return_type.lower_fn }}(returnValue),
                         {%- endif %}
                         callStatus: RustCallStatus()
                     )
@@ -82,16 +82,16 @@ fileprivate struct {{ trait_impl }} {
             let uniffiHandleError = { (statusCode, errorBuf) in
                 uniffiFutureCallback(
                     uniffiCallbackData,
-                    {{ meth.foreign_future_ffi_result_struct().name()|ffi_struct_name }}(
-                        {%- if let Some(return_type) = meth.return_type() %}
-                        returnValue: {{ meth.return_type().map(FfiType::from)|ffi_default_value }},
+                    {{ meth.async_data.as_ref().unwrap().ffi_foreign_future_result.0 }}(
+                        {%- if meth.return_type.ty.is_some() %}
+                        returnValue: {{ vtable_meth.ffi_default_value }},
                         {%- endif %}
                         callStatus: RustCallStatus(code: statusCode, errorBuf: errorBuf)
                     )
                 )
             }
 
-            {%- match meth.throws_type() %}
+            {%- match meth.throws_type.ty %}
             {%- when None %}
             uniffiTraitInterfaceCallAsync(
                 makeCall: makeCall,
@@ -104,7 +104,7 @@ fileprivate struct {{ trait_impl }} {
                 makeCall: makeCall,
                 handleSuccess: uniffiHandleSuccess,
                 handleError: uniffiHandleError,
-                lowerError: {{ error_type|lower_fn }},
+                lowerError: {{ error_type.lower_fn }},
                 droppedCallback: uniffiOutDroppedCallback
             )
             {%- endmatch %}
@@ -119,13 +119,13 @@ fileprivate struct {{ trait_impl }} {
     // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
     // This is safe because the pointee is initialized once during static init
     // and never mutated by either side of the FFI.  Its fields are C function pointers.
-    nonisolated(unsafe) static let vtablePtr: UnsafePointer<{{ vtable|ffi_type_name }}> = {
-        let ptr = UnsafeMutablePointer<{{ vtable|ffi_type_name }}>.allocate(capacity: 1)
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<{{ vtable.struct_type.type_name }}> = {
+        let ptr = UnsafeMutablePointer<{{ vtable.struct_type.type_name }}>.allocate(capacity: 1)
         ptr.initialize(to: vtable)
         return UnsafePointer(ptr)
     }()
 }
 
 private func {{ callback_init }}() {
-    {{ ffi_init_callback.name() }}({{ trait_impl }}.vtablePtr)
+    {{ vtable.init_fn.0 }}({{ trait_impl }}.vtablePtr)
 }
@@ -1,12 +1,8 @@
-{%- let cbi = ci.get_callback_interface_definition(name).unwrap() %}
-{%- let callback_handler = format!("uniffiCallbackHandler{}", name) %}
-{%- let callback_init = format!("uniffiCallbackInit{}", name) %}
-{%- let methods = cbi.methods() %}
-{%- let protocol_name = type_name.clone() %}
-{%- let protocol_docstring = cbi.docstring() %}
-{%- let vtable = cbi.vtable() %}
-{%- let vtable_methods = cbi.vtable_methods() %}
-{%- let ffi_init_callback = cbi.ffi_init_callback() %}
+{%- let callback_init = cbi.callback_init_fn %}
+{%- let methods = cbi.methods %}
+{%- let protocol_name = type_name %}
+{%- let protocol_docstring = cbi.docstring %}
+{%- let vtable = cbi.vtable %}
 
 {% include "Protocol.swift" %}
 {% include "CallbackInterfaceImpl.swift" %}
@@ -0,0 +1,26 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterChar: FfiConverter {
+    typealias FfiType = UInt32
+    typealias SwiftType = Unicode.Scalar
+
+    public static func lift(_ value: UInt32) throws -> Unicode.Scalar {
+        guard let scalar = Unicode.Scalar(value) else {
+            throw UniffiInternalError.invalidUnicodeScalar
+        }
+        return scalar
+    }
+
+    public static func lower(_ value: Unicode.Scalar) -> UInt32 {
+        return value.value
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Unicode.Scalar {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Unicode.Scalar, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
@@ -0,0 +1,55 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct {{ ffi_converter_name }}: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = {{ type_name }}
+
+    public static func lift(_ handle: UInt64) throws -> {{ type_name }} {
+        let rustClosure = UniffiRustClosureHandle(handle)
+        // Closures can't throw, calls only fail if the Rust code panics
+        return { {% for arg_type in argument_types %}arg{{ loop.index0 }}{% if !loop.last %}, {% endif %}{% endfor %}{% if argument_types.is_empty() %}(){% endif %} in
+            var writer = createWriter()
+            {%- for arg_type in argument_types %}
+            {{ arg_type.ffi_converter_name }}.write(arg{{ loop.index0 }}, into: &writer)
+            {%- endfor %}
+            return try! uniffiLiftClosureBuffer(rustClosure.call(RustBuffer(bytes: writer))) { {% if return_type.is_some() %}reader{% else %}_{% endif %} in
+                {%- match return_type %}
+                {%- when Some(return_type) %}
+                try {{ return_type.ffi_converter_name }}.read(from: &reader)
+                {%- when None %}
+                ()
+                {%- endmatch %}
+            }
+        }
+    }
+
+    public static func lower(_ value: @escaping {{ type_name }}) -> UInt64 {
+        return uniffiForeignClosureHandleMap.insert(obj: { args in
+            {% if return_type.is_some() %}let returnValue = {% endif %}try uniffiLiftClosureBuffer(args) { {% if argument_types.is_empty() %}_{% else %}reader{% endif %} in
+                value(
+                    {%- for arg_type in argument_types %}
+                    try {{ arg_type.ffi_converter_name }}.read(from: &reader){% if !loop.last %},{% endif %}
+                    {%- endfor %}
+                )
+            }
+            {%- match return_type %}
+            {%- when Some(return_type) %}
+            var writer = createWriter()
+            {{ return_type.ffi_converter_name }}.write(returnValue, into: &writer)
+            return RustBuffer(bytes: writer)
+            {%- when None %}
+            return RustBuffer.empty()
+            {%- endmatch %}
+        })
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: @escaping {{ type_name }}, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
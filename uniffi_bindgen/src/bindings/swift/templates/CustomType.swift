@@ -1,29 +1,30 @@
-{%- let ffi_type_name=builtin|ffi_type|ref|ffi_type_name %}
-{%- let docstring = ci.get_custom_type_definition(name.as_str()).and_then(CustomType::docstring) %}
-{%- match config.custom_types.get(name.as_str())  %}
+{%- let builtin = custom.builtin %}
+{%- let ffi_type_name = builtin.ffi_type.type_name %}
+{%- let docstring = custom.docstring %}
+{%- match custom.config %}
 {%- when None %}
 {#- No config, just forward all methods to our builtin type #}
 {%- call swift::docstring_value(docstring, 0) %}{% endcall %}
-public typealias {{ type_name }} = {{ builtin|type_name }}
+public typealias {{ type_name }} = {{ builtin.type_name }}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterType{{ name }}: FfiConverter {
+public struct {{ ffi_converter_name }}: FfiConverter {
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
-        return try {{ builtin|read_fn }}(from: &buf)
+        return try {{ builtin.ffi_converter_name }}.read(from: &buf)
     }
 
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
-        return {{ builtin|write_fn }}(value, into: &buf)
+        return {{ builtin.ffi_converter_name }}.write(value, into: &buf)
     }
 
     public static func lift(_ value: {{ ffi_type_name }}) throws -> {{ type_name }} {
-        return try {{ builtin|lift_fn }}(value)
+        return try {{ builtin.lift_fn }}(value)
     }
 
     public static func lower(_ value: {{ type_name }}) -> {{ ffi_type_name }} {
-        return {{ builtin|lower_fn }}(value)
+        return {{ builtin.lower_fn }}(value)
     }
 }
 
@@ -39,34 +40,34 @@ public typealias {{ type_name }} = {{ concrete_type_name }}
 
 {%- if let Some(imports) = config.imports %}
 {%- for import_name in imports %}
-{{ self.add_import(import_name) }}
+{# Imports are rendered with the module imports #}
 {%- endfor %}
 {%- endif %}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterType{{ name }}: FfiConverter {
+public struct {{ ffi_converter_name }}: FfiConverter {
     {#- Custom type config supplied, use it to convert the builtin type #}
 
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
-        let builtinValue = try {{ builtin|read_fn }}(from: &buf)
+        let builtinValue = try {{ builtin.ffi_converter_name }}.read(from: &buf)
         return {{ config.lift("builtinValue") }}
     }
 
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
         let builtinValue = {{ config.lower("value") }}
-        return {{ builtin|write_fn }}(builtinValue, into: &buf)
+        return {{ builtin.ffi_converter_name }}.write(builtinValue, into: &buf)
     }
 
     public static func lift(_ value: {{ ffi_type_name }}) throws -> {{ type_name }} {
-        let builtinValue = try {{ builtin|lift_fn }}(value)
+        let builtinValue = try {{ builtin.lift_fn }}(value)
         return {{ config.lift("builtinValue") }}
     }
 
     public static func lower(_ value: {{ type_name }}) -> {{ ffi_type_name }} {
         let builtinValue = {{ config.lower("value") }}
-        return {{ builtin|lower_fn }}(builtinValue)
+        return {{ builtin.lower_fn }}(builtinValue)
     }
 }
 
@@ -79,14 +80,14 @@ an external type by another crate.
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterType{{ name }}_lift(_ value: {{ ffi_type_name }}) throws -> {{ type_name }} {
-    return try FfiConverterType{{ name }}.lift(value)
+public func {{ ffi_converter_name }}_lift(_ value: {{ ffi_type_name }}) throws -> {{ type_name }} {
+    return try {{ ffi_converter_name }}.lift(value)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterType{{ name }}_lower(_ value: {{ type_name }}) -> {{ ffi_type_name }} {
-    return FfiConverterType{{ name }}.lower(value)
+public func {{ ffi_converter_name }}_lower(_ value: {{ type_name }}) -> {{ ffi_type_name }} {
+    return {{ ffi_converter_name }}.lower(value)
 }
 
@@ -1,34 +1,37 @@
 {%- call swift::docstring(e, 0) %}{% endcall %}
-{%- let uniffi_trait_methods = e.uniffi_trait_methods() %}
-{% match e.variant_discr_type() %}
-{% when None %}
-public {% if ci.is_recursive(e.name()) %}indirect {% endif %}
-{%- if config.enum_has_conformances(e, contains_object_references) -%}
-enum {{ type_name }}: {{ config.conformance_list_for_enum(e, contains_object_references) }} {
+{%- let uniffi_trait_methods = e.uniffi_trait_methods %}
+{% if !e.discr_type_specified %}
+public {% if e.recursive %}indirect {% endif %}
+{%- if !e.conformances.is_empty() -%}
+enum {{ type_name }}: {{ e.conformances|join(", ") }} {
 {%- else -%}
 enum {{ type_name }} {
 {%- endif %}
-    {% for variant in e.variants() %}
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|enum_variant_swift_quoted }}{% if variant.fields().len() > 0 %}(
-        {%- call swift::field_list_decl(variant, variant.has_nameless_fields()) %}{% endcall %}
+    case {{ variant.name }}{% if variant.has_fields() %}(
+        {%- call swift::field_list_decl(variant, variant.has_nameless_fields) %}{% endcall %}
     ){% endif -%}
     {% endfor %}
-{% when Some(variant_discr_type) %}
-public {% if ci.is_recursive(e.name()) %}indirect {% endif -%}
-enum {{ type_name }}: {{ variant_discr_type|type_name }}, {{ config.conformance_list_for_enum(e, contains_object_references) }} {
-    {% for variant in e.variants() %}
+{% else %}
+public {% if e.recursive %}indirect {% endif -%}
+enum {{ type_name }}: {{ e.discr_type.type_name }}, {{ e.conformances|join(", ") }} {
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|enum_variant_swift_quoted }} = {{ e|variant_discr_literal(loop.index0) }}{% if variant.fields().len() > 0 %}(
-        {%- call swift::field_list_decl(variant, variant.has_nameless_fields()) %}{% endcall %}
+    case {{ variant.name }} = {{ variant.discr }}{% if variant.has_fields() %}(
+        {%- call swift::field_list_decl(variant, variant.has_nameless_fields) %}{% endcall %}
     ){% endif -%}
     {% endfor %}
-{% endmatch %}
+{% endif %}
 
-{% for meth in e.methods() -%}
+{% for meth in e.methods -%}
 {%- call swift::func_decl("public func", meth, 4) %}{% endcall %}
 {% endfor %}
 
+{% for meth in e.static_methods -%}
+{%- call swift::func_decl("public static func", meth, 4) %}{% endcall %}
+{% endfor %}
+
 {% call swift::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
 }
 
@@ -45,13 +48,13 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-        {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|enum_variant_swift_quoted }}{% if variant.has_fields() %}(
-            {%- for field in variant.fields() %}
-            {%- if variant.has_nameless_fields() -%}
-            try {{ field|read_fn }}(from: &buf)
+        {% for variant in e.variants %}
+        case {{ loop.index }}: return .{{ variant.name }}{% if variant.has_fields() %}(
+            {%- for field in variant.fields %}
+            {%- if variant.has_nameless_fields -%}
+            try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%- else -%}
-            {{ field.name()|arg_name }}: try {{ field|read_fn }}(from: &buf)
+            {{ field.label }}: try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%- endif -%}
             {%- if !loop.last %}, {% endif %}
             {%- endfor %}
@@ -63,15 +66,15 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
 
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
         switch value {
-        {% for variant in e.variants() %}
+        {% for variant in e.variants %}
         {% if variant.has_fields() %}
-        case let .{{ variant.name()|enum_variant_swift_quoted }}({% for field in variant.fields() %}{%- call swift::field_name(field, loop.index) %}{% endcall -%}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
+        case let .{{ variant.name }}({% for field in variant.fields %}{{- field.binding_name -}}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
             writeInt(&buf, Int32({{ loop.index }}))
-            {% for field in variant.fields() -%}
-            {{ field|write_fn }}({% call swift::field_name(field, loop.index) %}{% endcall %}, into: &buf)
+            {% for field in variant.fields -%}
+            {{ field.ty.ffi_converter_name }}.write({{ field.binding_name }}, into: &buf)
             {% endfor -%}
         {% else %}
-        case .{{ variant.name()|enum_variant_swift_quoted }}:
+        case .{{ variant.name }}:
             writeInt(&buf, Int32({{ loop.index }}))
         {% endif %}
         {%- endfor %}
@@ -1,34 +1,38 @@
 {%- call swift::docstring(e, 0) %}{% endcall %}
-public {% if ci.is_recursive(e.name()) %}indirect {% endif %}
-{%- if config.error_has_additional_conformances(e, contains_object_references) %}
-enum {{ type_name }}: Swift.Error, {{ config.additional_conformance_list_for_error(e, contains_object_references) }} {
+public {% if e.recursive %}indirect {% endif %}
+{%- if !e.conformances.is_empty() %}
+enum {{ type_name }}: Swift.Error, {{ e.conformances|join(", ") }} {
 {%- else %}
 enum {{ type_name }}: Swift.Error {
 {%- endif %}
 
-    {% if e.is_flat() %}
-    {% for variant in e.variants() %}
+    {% if e.is_flat %}
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|class_name }}(message: String)
+    case {{ variant.name }}(message: String)
     {% endfor %}
 
     {%- else %}
-    {% for variant in e.variants() %}
+    {% for variant in e.variants %}
     {%- call swift::docstring(variant, 4) %}{% endcall %}
-    case {{ variant.name()|class_name }}{% if variant.fields().len() > 0 %}(
-        {%- call swift::field_list_decl(variant, variant.has_nameless_fields()) %}{% endcall %}
+    case {{ variant.name }}{% if variant.has_fields() %}(
+        {%- call swift::field_list_decl(variant, variant.has_nameless_fields) %}{% endcall %}
     ){% endif -%}
     {% endfor %}
 
     {%- endif %}
 
-    {% for meth in e.methods() -%}
+    {% for meth in e.methods -%}
     {%- call swift::func_decl("public func", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {% call swift::uniffi_trait_impls(e.uniffi_trait_methods()) %}{% endcall %}
+    {% for meth in e.static_methods -%}
+    {%- call swift::func_decl("public static func", meth, 4) %}{% endcall %}
+    {% endfor %}
+
+    {% call swift::uniffi_trait_impls(e.uniffi_trait_methods) %}{% endcall %}
 
-    {% if !config.omit_localized_error_conformance() %}
+    {% if !omit_localized_error_conformance %}
     public var errorDescription: String? {
         String(reflecting: self)
     }
@@ -49,23 +53,23 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         let variant: Int32 = try readInt(&buf)
         switch variant {
 
-        {% if e.is_flat() %}
+        {% if e.is_flat %}
 
-        {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|class_name }}(
-            message: try {{ Type::String.borrow()|read_fn }}(from: &buf)
+        {% for variant in e.variants %}
+        case {{ loop.index }}: return .{{ variant.name }}(
+            message: try FfiConverterString.read(from: &buf)
         )
         {% endfor %}
 
         {% else %}
 
-        {% for variant in e.variants() %}
-        case {{ loop.index }}: return .{{ variant.name()|error_variant_swift_quoted }}{% if variant.has_fields() %}(
-            {% for field in variant.fields() -%}
-            {%-     if variant.has_nameless_fields() -%}
-            try {{ field|read_fn }}(from: &buf)
+        {% for variant in e.variants %}
+        case {{ loop.index }}: return .{{ variant.name }}{% if variant.has_fields() %}(
+            {% for field in variant.fields -%}
+            {%-     if variant.has_nameless_fields -%}
+            try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%-     else -%}
-            {{ field.name()|var_name }}: try {{ field|read_fn }}(from: &buf)
+            {{ field.name }}: try {{ field.ty.ffi_converter_name }}.read(from: &buf)
             {%-     endif -%}
             {%- if !loop.last %}, {% endif %}
             {% endfor -%}
@@ -80,24 +84,24 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
         switch value {
 
-        {% if e.is_flat() %}
+        {% if e.is_flat %}
 
-        {% for variant in e.variants() %}
-        case .{{ variant.name()|class_name }}(_ /* message is ignored*/):
+        {% for variant in e.variants %}
+        case .{{ variant.name }}(_ /* message is ignored*/):
             writeInt(&buf, Int32({{ loop.index }}))
         {%- endfor %}
 
         {% else %}
 
-        {% for variant in e.variants() %}
+        {% for variant in e.variants %}
         {% if variant.has_fields() %}
-        case let .{{ variant.name()|error_variant_swift_quoted }}({% for field in variant.fields() %}{%- call swift::field_name(field, loop.index) %}{% endcall -%}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
+        case let .{{ variant.name }}({% for field in variant.fields %}{{- field.binding_name -}}{%- if loop.last -%}{%- else -%},{%- endif -%}{% endfor %}):
             writeInt(&buf, Int32({{ loop.index }}))
-            {% for field in variant.fields() -%}
-            {{ field|write_fn }}({% call swift::field_name(field, loop.index) %}{% endcall %}, into: &buf)
+            {% for field in variant.fields -%}
+            {{ field.ty.ffi_converter_name }}.write({{ field.binding_name }}, into: &buf)
             {% endfor -%}
         {% else %}
-        case .{{ variant.name()|class_name }}:
+        case .{{ variant.name }}:
             writeInt(&buf, Int32({{ loop.index }}))
         {% endif %}
         {%- endfor %}
@@ -9,6 +9,8 @@ fileprivate enum UniffiInternalError: LocalizedError {
     case unexpectedRustCallStatusCode
     case unexpectedRustCallError
     case unexpectedStaleHandle
+    case unexpectedArrayLength
+    case invalidUnicodeScalar
     case rustPanic(_ message: String)
 
     public var errorDescription: String? {
@@ -21,6 +23,8 @@ fileprivate enum UniffiInternalError: LocalizedError {
         case .unexpectedRustCallStatusCode: return "Unexpected RustCallStatus code"
         case .unexpectedRustCallError: return "CALL_ERROR but no errorClass specified"
         case .unexpectedStaleHandle: return "The object in the handle map has been dropped already"
+        case .unexpectedArrayLength: return "Fixed-size array has an unexpected number of elements"
+        case .invalidUnicodeScalar: return "Raw value is not a valid Unicode scalar value"
         case let .rustPanic(message): return message
         }
     }
@@ -95,14 +99,14 @@ private func uniffiCheckCallStatus<E: Swift.Error>(
             // with the message.  But if that code panics, then it just sends back
             // an empty buffer.
             if callStatus.errorBuf.len > 0 {
-                throw UniffiInternalError.rustPanic(try {{ Type::String.borrow()|lift_fn }}(callStatus.errorBuf))
+                throw UniffiInternalError.rustPanic(try FfiConverterString.lift(callStatus.errorBuf))
             } else {
                 callStatus.errorBuf.deallocate()
                 throw UniffiInternalError.rustPanic("Rust panic")
             }
 
         case CALL_CANCELLED:
-            fatalError("Cancellation not supported yet")
+            throw CancellationError()
 
         default:
             throw UniffiInternalError.unexpectedRustCallStatusCode
@@ -118,7 +122,7 @@ private func uniffiTraitInterfaceCall<T>(
         try writeReturn(makeCall())
     } catch let error {
         callStatus.pointee.code = CALL_UNEXPECTED_ERROR
-        callStatus.pointee.errorBuf = {{ Type::String.borrow()|lower_fn }}(String(describing: error))
+        callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
 }
 
@@ -135,6 +139,158 @@ private func uniffiTraitInterfaceCallWithError<T, E>(
         callStatus.pointee.errorBuf = lowerError(error)
     } catch {
         callStatus.pointee.code = CALL_UNEXPECTED_ERROR
-        callStatus.pointee.errorBuf = {{ Type::String.borrow()|lower_fn }}(String(describing: error))
+        callStatus.pointee.errorBuf = FfiConverterString.lower(String(describing: error))
     }
 }
+{%- if has_iterator_types %}
+
+fileprivate let UNIFFI_RUST_ITER_CHUNK_SIZE: UInt32 = 64
+
+// Handle to a Rust iterator
+//
+// Items are fetched in chunks with `rust_iter_next_chunk`, an empty chunk signals the end of the
+// iterator.  The Rust iterator is freed once it's exhausted, when fetching a chunk fails or when
+// the handle is deinitialized.
+fileprivate final class UniffiRustIteratorHandle<T>: @unchecked Sendable {
+    private let lock = NSLock()
+    private var handle: UInt64?
+    private var items: [T] = []
+    private var index = 0
+    private let liftChunk: (RustBuffer) throws -> [T]
+
+    init(_ handle: UInt64, liftChunk: @escaping (RustBuffer) throws -> [T]) {
+        self.handle = handle
+        self.liftChunk = liftChunk
+    }
+
+    deinit {
+        free()
+    }
+
+    func next() throws -> T? {
+        try lock.withLock { () throws -> T? in
+            if index == items.count {
+                guard let handle = self.handle else {
+                    return nil
+                }
+                do {
+                    items = try liftChunk(try rustCall {
+                        ffi_{{ crate_name }}_rust_iter_next_chunk(handle, UNIFFI_RUST_ITER_CHUNK_SIZE, $0)
+                    })
+                } catch {
+                    items = []
+                    freeLocked()
+                    throw error
+                }
+                index = 0
+                if items.isEmpty {
+                    freeLocked()
+                    return nil
+                }
+            }
+            let item = items[index]
+            index += 1
+            return item
+        }
+    }
+
+    private func free() {
+        lock.withLock { freeLocked() }
+    }
+
+    private func freeLocked() {
+        if let handle = self.handle {
+            self.handle = nil
+            ffi_{{ crate_name }}_rust_iter_free(handle)
+        }
+    }
+}
+{%- endif %}
+{%- if has_closure_types %}
+
+// Closures are type-erased: the arguments and return value are serialized into `RustBuffer`s.
+//
+// Swift closures passed to Rust are stored in `uniffiForeignClosureHandleMap` as closures that
+// input the serialized arguments and return the serialized return value.  Rust calls them through
+// the `VTableForeignClosure` VTable.  Rust closures are wrapped in `UniffiRustClosureHandle`.
+fileprivate let uniffiForeignClosureHandleMap = UniffiHandleMap<(RustBuffer) throws -> RustBuffer>()
+
+fileprivate struct UniffiForeignClosure {
+    static let vtable: UniffiVTableForeignClosure = UniffiVTableForeignClosure(
+        uniffiFree: { (handle: UInt64) -> () in
+            do {
+                try uniffiForeignClosureHandleMap.remove(handle: handle)
+            } catch {
+                print("Uniffi foreign closure: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (handle: UInt64) -> UInt64 in
+            do {
+                return try uniffiForeignClosureHandleMap.clone(handle: handle)
+            } catch {
+                fatalError("Uniffi foreign closure: handle missing in uniffiClone")
+            }
+        },
+        call: { (
+            handle: UInt64,
+            args: RustBuffer,
+            uniffiOutReturn: UnsafeMutablePointer<RustBuffer>,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: { try uniffiForeignClosureHandleMap.get(handle: handle)(args) },
+                writeReturn: { uniffiOutReturn.pointee = $0 }
+            )
+        }
+    )
+
+    // Rust stores this pointer for future calls, so it must live for the process lifetime.
+    //
+    // `nonisolated(unsafe)` is needed under Swift 6 strict concurrency.
+    // This is safe because the pointee is initialized once during static init
+    // and never mutated by either side of the FFI.  Its fields are C function pointers.
+    nonisolated(unsafe) static let vtablePtr: UnsafePointer<UniffiVTableForeignClosure> = {
+        let ptr = UnsafeMutablePointer<UniffiVTableForeignClosure>.allocate(capacity: 1)
+        ptr.initialize(to: vtable)
+        return UnsafePointer(ptr)
+    }()
+}
+
+private func uniffiForeignClosureInit() {
+    ffi_{{ crate_name }}_foreign_closure_init_vtable(UniffiForeignClosure.vtablePtr)
+}
+
+// Handle to a Rust closure, which is freed when the handle is deinitialized
+fileprivate final class UniffiRustClosureHandle: @unchecked Sendable {
+    private let handle: UInt64
+
+    init(_ handle: UInt64) {
+        self.handle = handle
+    }
+
+    deinit {
+        ffi_{{ crate_name }}_rust_closure_free(handle)
+    }
+
+    func call(_ args: RustBuffer) throws -> RustBuffer {
+        try rustCall { ffi_{{ crate_name }}_rust_closure_call(self.handle, args, $0) }
+    }
+}
+
+// Deserialize closure arguments or return values from a `RustBuffer`, then free it
+fileprivate func uniffiLiftClosureBuffer<T>(
+    _ rbuf: RustBuffer,
+    _ read: (inout (data: Data, offset: Data.Index)) throws -> T
+) throws -> T {
+    defer {
+        rbuf.deallocate()
+    }
+    var reader = createReader(data: rbuf.data == nil ? Data() : Data(rustBuffer: rbuf))
+    let value = try read(&reader)
+    if hasRemaining(reader) {
+        throw UniffiInternalError.incompleteData
+    }
+    return value
+}
+{%- endif %}
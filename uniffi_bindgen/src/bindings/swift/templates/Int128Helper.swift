@@ -0,0 +1,74 @@
+/// A 128-bit signed integer, stored as its high and low 64-bit words in two's complement.
+///
+/// Swift's native `Int128` needs a Swift 6 toolchain and a recent OS, so we can't use it
+/// unconditionally.  Where it's available, convert with `init(_:)` and `value`.
+public struct UniffiInt128: Equatable, Hashable, Codable, CustomStringConvertible, ExpressibleByIntegerLiteral {
+    public var high: Int64
+    public var low: UInt64
+
+    public init(high: Int64, low: UInt64) {
+        self.high = high
+        self.low = low
+    }
+
+    public init(_ value: Int64) {
+        self.init(high: value < 0 ? -1 : 0, low: UInt64(bitPattern: value))
+    }
+
+    public init(integerLiteral value: Int64) {
+        self.init(value)
+    }
+
+    public var description: String {
+        // Work with the magnitude, negating the two's complement value if needed
+        var high = UInt64(bitPattern: self.high)
+        var low = self.low
+        if self.high < 0 {
+            low = ~low &+ 1
+            high = ~high &+ (low == 0 ? 1 : 0)
+        }
+        // Render the magnitude in decimal by repeatedly dividing by 10
+        var digits: [Character] = []
+        repeat {
+            let (highQuotient, highRemainder) = high.quotientAndRemainder(dividingBy: 10)
+            let (lowQuotient, remainder) = UInt64(10).dividingFullWidth((highRemainder, low))
+            high = highQuotient
+            low = lowQuotient
+            digits.append(Character(String(remainder)))
+        } while high != 0 || low != 0
+        return (self.high < 0 ? "-" : "") + String(digits.reversed())
+    }
+}
+
+#if compiler(>=6)
+extension UniffiInt128: Sendable {}
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension UniffiInt128 {
+    public init(_ value: Int128) {
+        self.init(high: Int64(truncatingIfNeeded: value >> 64), low: UInt64(truncatingIfNeeded: value))
+    }
+
+    public var value: Int128 {
+        return Int128(high) << 64 | Int128(low)
+    }
+}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt128: FfiConverterRustBuffer {
+    typealias SwiftType = UniffiInt128
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UniffiInt128 {
+        let high: Int64 = try readInt(&buf)
+        let low: UInt64 = try readInt(&buf)
+        return UniffiInt128(high: high, low: low)
+    }
+
+    public static func write(_ value: UniffiInt128, into buf: inout [UInt8]) {
+        writeInt(&buf, value.high)
+        writeInt(&buf, value.low)
+    }
+}
@@ -0,0 +1,46 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct {{ ffi_converter_name }}: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = {{ type_name }}
+
+    public static func lift(_ handle: UInt64) throws -> {{ type_name }} {
+        let iterator = UniffiRustIteratorHandle(handle, liftChunk: liftChunk)
+        // Fetching items only fails if the Rust code panics
+        return AnyIterator {
+            try! iterator.next()
+        }
+    }
+
+    // Lift the `Vec` returned by `rust_iter_next_chunk`, an empty array signals the end of the iterator
+    fileprivate static func liftChunk(_ rbuf: RustBuffer) throws -> [{{ item_type.type_name }}] {
+        var reader = createReader(data: Data(rustBuffer: rbuf))
+        defer {
+            rbuf.deallocate()
+        }
+        let len: Int32 = try readInt(&reader)
+        var items = [{{ item_type.type_name }}]()
+        items.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            items.append(try {{ item_type.ffi_converter_name }}.read(from: &reader))
+        }
+        if hasRemaining(reader) {
+            throw UniffiInternalError.incompleteData
+        }
+        return items
+    }
+
+    public static func lower(_ value: {{ type_name }}) -> UInt64 {
+        fatalError("Iterators can't be passed to Rust")
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+        fatalError("Iterators can't be passed to Rust")
+    }
+}
@@ -6,8 +6,8 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for (key, value) in value {
-            {{ key_type|write_fn }}(key, into: &buf)
-            {{ value_type|write_fn }}(value, into: &buf)
+            {{ key_type.ffi_converter_name }}.write(key, into: &buf)
+            {{ value_type.ffi_converter_name }}.write(value, into: &buf)
         }
     }
 
@@ -16,9 +16,13 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         var dict = {{ type_name }}()
         dict.reserveCapacity(Int(len))
         for _ in 0..<len {
-            let key = try {{ key_type|read_fn }}(from: &buf)
-            let value = try {{ value_type|read_fn }}(from: &buf)
+            let key = try {{ key_type.ffi_converter_name }}.read(from: &buf)
+            let value = try {{ value_type.ffi_converter_name }}.read(from: &buf)
+            {%- if ordered %}
+            dict.append((key: key, value: value))
+            {%- else %}
             dict[key] = value
+            {%- endif %}
         }
         return dict
     }
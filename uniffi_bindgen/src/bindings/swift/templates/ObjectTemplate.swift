@@ -1,14 +1,14 @@
-{%- let obj = ci.get_object_definition(name).unwrap() %}
-{%- let (protocol_name, impl_class_name) = obj|object_names %}
-{%- let methods = obj.methods() %}
-{%- let protocol_docstring = obj.docstring() %}
+{%- let protocol_name = obj.protocol_name %}
+{%- let impl_class_name = obj.impl_class_name %}
+{%- let methods = obj.methods %}
+{%- let protocol_docstring = obj.docstring %}
 
-{%- let is_error = ci.is_name_used_as_error(name) %}
+{%- let is_error = obj.self_type.is_used_as_error %}
 
 {% include "Protocol.swift" %}
 
 {%- call swift::docstring(obj, 0) %}{% endcall %}
-open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_list_for_object(obj, is_error) }} {
+open class {{ impl_class_name }}: {{ protocol_name }}, {{ obj.conformances|join(", ") }} {
     fileprivate let handle: UInt64
 
     /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
@@ -45,7 +45,7 @@ open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_lis
     @_documentation(visibility: private)
 #endif
     public func uniffiCloneHandle() -> UInt64 {
-        return try! rustCall { {{ obj.ffi_object_clone().name() }}(self.handle, $0) }
+        return try! rustCall { {{ obj.ffi_func_clone.0 }}(self.handle, $0) }
     }
 
     {%- match obj.primary_constructor() %}
@@ -61,21 +61,25 @@ open class {{ impl_class_name }}: {{ protocol_name }}, {{ config.conformance_lis
             return
         }
 
-        try! rustCall { {{ obj.ffi_object_free().name() }}(handle, $0) }
+        try! rustCall { {{ obj.ffi_func_free.0 }}(handle, $0) }
     }
 
     {% for cons in obj.alternate_constructors() %}
     {%- call swift::func_decl("public static func", cons, 4) %}{% endcall %}
     {% endfor %}
 
-    {% for meth in obj.methods() -%}
+    {% for meth in obj.methods -%}
     {%- call swift::func_decl("open func", meth, 4) %}{% endcall %}
     {% endfor %}
 
-    {% call swift::uniffi_trait_impls(obj.uniffi_trait_methods()) %}{% endcall %}
+    {% for meth in obj.static_methods -%}
+    {%- call swift::func_decl("public static func", meth, 4) %}{% endcall %}
+    {% endfor %}
+
+    {% call swift::uniffi_trait_impls(obj.uniffi_trait_methods) %}{% endcall %}
 
     {%- if is_error %}
-    {% if !config.omit_localized_error_conformance() %}
+    {% if !omit_localized_error_conformance %}
     public var errorDescription: String? {
         String(reflecting: self)
     }
@@ -116,11 +120,8 @@ public struct {{ ffi_converter_name }}: FfiConverter {
  # * Generate a callback interface implementation to handle the Swift side
  # * In the FfiConverter, check which side a handle came from to know how to handle correctly.
 #}
-{%- let callback_handler = format!("uniffiCallbackInterface{}", name) %}
-{%- let callback_init = format!("uniffiCallbackInit{}", name) %}
-{%- let vtable = obj.vtable().expect("trait interface should have a vtable") %}
-{%- let vtable_methods = obj.vtable_methods() %}
-{%- let ffi_init_callback = obj.ffi_init_callback() %}
+{%- let callback_init = obj.callback_init_fn %}
+{%- let vtable = obj.vtable.as_ref().expect("trait interface should have a vtable") %}
 {% include "CallbackInterfaceImpl.swift" %}
 
 #if swift(>=5.8)
@@ -165,8 +166,8 @@ public struct {{ ffi_converter_name }}: FfiConverter {
 
 {%- endif %}
 
-{%- for t in obj.trait_impls() %}
-extension {{impl_class_name}}: {{ self::trait_protocol_name(ci, t.trait_ty)? }} {}
+{%- for trait_protocol_name in obj.trait_protocol_names %}
+extension {{impl_class_name}}: {{ trait_protocol_name }} {}
 {% endfor %}
 
 {#
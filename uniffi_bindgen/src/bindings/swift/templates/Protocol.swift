@@ -1,11 +1,31 @@
 {%- call swift::docstring_value(protocol_docstring, 0) %}{% endcall %}
 public protocol {{ protocol_name }}: AnyObject, Sendable {
     {% for meth in methods.iter() -%}
+    {%- let callable = meth.callable %}
     {%- call swift::docstring(meth, 4) %}{% endcall %}
-    func {{ meth.name()|fn_name }}({% call swift::arg_list_protocol(meth) %}{% endcall %}) {% call swift::is_async(meth) -%}{% endcall %}{% call swift::throws(meth) %}{% endcall -%}
-    {%- match meth.return_type() -%}
-    {%- when Some with (return_type) %} -> {{ return_type|type_name -}}
+    func {{ callable.name }}({% call swift::arg_list_protocol(callable) %}{% endcall %}) {% call swift::is_async(callable) -%}{% endcall %}{% call swift::throws(callable) %}{% endcall -%}
+    {%- match callable.return_type.ty -%}
+    {%- when Some with (return_type) %} -> {{ return_type.type_name -}}
     {%- else -%}
     {%- endmatch %}
     {% endfor %}
 }
+{%- for meth in methods.iter() %}
+{%- let callable = meth.callable %}
+{%- if let Some(default_ffi_func) = meth.default_ffi_func %}
+
+// Default implementation of `{{ callable.name }}`, which calls the Rust default.
+extension {{ protocol_name }} {
+    public func {{ callable.name }}({% call swift::arg_list_protocol(callable) %}{% endcall %}) {% call swift::throws(callable) %}{% endcall -%}
+    {%- match callable.return_type.ty -%}
+    {%- when Some with (return_type) %} -> {{ return_type.type_name }} {
+        return {% call swift::is_try(callable) %}{% endcall %} {{ return_type.lift_fn }}({% call swift::default_method_call(callable, default_ffi_func) %}{% endcall %})
+    }
+    {%- else %} {
+        {% call swift::default_method_call(callable, default_ffi_func) %}{% endcall %}
+    }
+    {%- endmatch %}
+}
+{%- endif %}
+{%- endfor %}
+
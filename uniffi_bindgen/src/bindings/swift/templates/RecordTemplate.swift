@@ -1,28 +1,31 @@
-{%- let rec = ci.get_record_definition(name).unwrap() %}
-{%- let uniffi_trait_methods = rec.uniffi_trait_methods() %}
+{%- let uniffi_trait_methods = rec.uniffi_trait_methods %}
 {%- call swift::docstring(rec, 0) %}{% endcall %}
-{%- if config.record_has_conformances(rec, contains_object_references) %}
-public struct {{ type_name }}: {{ config.conformance_list_for_record(rec, contains_object_references) }} {
+{%- if !rec.conformances.is_empty() %}
+public struct {{ type_name }}: {{ rec.conformances|join(", ") }} {
 {%- else %}
 public struct {{ type_name }} {
 {%- endif %}
-    {%- for field in rec.fields() %}
+    {%- for field in rec.fields %}
     {%- call swift::docstring(field, 4) %}{% endcall %}
-    public {% if config.is_record_immutable(name) %}let{% else %}var{% endif %} {{ field.name()|var_name }}: {{ field|type_name }}
+    public {% if rec.is_immutable %}let{% else %}var{% endif %} {{ field.name }}: {{ field.ty.type_name }}
     {%- endfor %}
 
     // Default memberwise initializers are never public by default, so we
     // declare one manually.
     public init({% call swift::field_list_decl(rec, false) %}{% endcall %}) {
-        {%- for field in rec.fields() %}
-        self.{{ field.name()|var_name }} = {{ field.name()|var_name }}
+        {%- for field in rec.fields %}
+        self.{{ field.name }} = {{ field.name }}
         {%- endfor %}
     }
 
-    {% for meth in rec.methods() -%}
+    {% for meth in rec.methods -%}
     {%- call swift::func_decl("public func", meth, 4) %}{% endcall %}
     {% endfor %}
 
+    {% for meth in rec.static_methods -%}
+    {%- call swift::func_decl("public static func", meth, 4) %}{% endcall %}
+    {% endfor %}
+
     {% call swift::uniffi_trait_impls(uniffi_trait_methods) %}{% endcall %}
 }
 
@@ -35,10 +38,10 @@ extension {{ type_name }}: Sendable {}
 #endif
 public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
-        return {%- if rec.has_fields() %}
+        return {%- if !rec.fields.is_empty() %}
             try {{ type_name }}(
-            {%- for field in rec.fields() %}
-                {{ field.name()|arg_name }}: {{ field|read_fn }}(from: &buf)
+            {%- for field in rec.fields %}
+                {{ field.label }}: {{ field.ty.ffi_converter_name }}.read(from: &buf)
                 {%- if !loop.last %}, {% endif %}
             {%- endfor %}
         )
@@ -48,8 +51,8 @@ public struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
     }
 
     public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
-        {%- for field in rec.fields() %}
-        {{ field|write_fn }}(value.{{ field.name()|var_name }}, into: &buf)
+        {%- for field in rec.fields %}
+        {{ field.ty.ffi_converter_name }}.write(value.{{ field.name }}, into: &buf)
         {%- endfor %}
     }
 }
@@ -8,7 +8,7 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            {{ inner_type|write_fn }}(item, into: &buf)
+            {{ inner_type.ffi_converter_name }}.write(item, into: &buf)
         }
     }
 
@@ -17,7 +17,7 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         var seq = {{ type_name }}()
         seq.reserveCapacity(Int(len))
         for _ in 0 ..< len {
-            seq.append(try {{ inner_type|read_fn }}(from: &buf))
+            seq.append(try {{ inner_type.ffi_converter_name }}.read(from: &buf))
         }
         return seq
     }
@@ -6,7 +6,7 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         let len = Int32(value.count)
         writeInt(&buf, len)
         for item in value {
-            {{ inner_type|write_fn }}(item, into: &buf)
+            {{ inner_type.ffi_converter_name }}.write(item, into: &buf)
         }
     }
 
@@ -15,8 +15,12 @@ fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
         var set = {{ type_name }}()
         set.reserveCapacity(Int(len))
         for _ in 0..<len {
-            let item = try {{ inner_type|read_fn }}(from: &buf)
+            let item = try {{ inner_type.ffi_converter_name }}.read(from: &buf)
+            {%- if ordered %}
+            set.append(item)
+            {%- else %}
             set.insert(item)
+            {%- endif %}
         }
         return set
     }
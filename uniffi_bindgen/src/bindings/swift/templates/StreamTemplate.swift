@@ -0,0 +1,45 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct {{ ffi_converter_name }}: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = {{ type_name }}
+
+    public static func lift(_ handle: UInt64) throws -> {{ type_name }} {
+        let stream = UniffiRustStreamHandle(handle)
+        return AsyncThrowingStream(unfolding: {
+            try await stream.next(liftFunc: liftNext)
+        })
+    }
+
+    // Lift the `Option` returned by a `rust_stream_next` future, `nil` signals the end of the stream
+    fileprivate static func liftNext(_ rbuf: RustBuffer) throws -> Optional<{{ item_type.type_name }}> {
+        var reader = createReader(data: Data(rustBuffer: rbuf))
+        defer {
+            rbuf.deallocate()
+        }
+        let next: Optional<{{ item_type.type_name }}>
+        switch try readInt(&reader) as Int8 {
+        case 0: next = .none
+        case 1: next = .some(try {{ item_type.ffi_converter_name }}.read(from: &reader))
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+        if hasRemaining(reader) {
+            throw UniffiInternalError.incompleteData
+        }
+        return next
+    }
+
+    public static func lower(_ value: {{ type_name }}) -> UInt64 {
+        fatalError("Streams can't be passed to Rust")
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+        fatalError("Streams can't be passed to Rust")
+    }
+}
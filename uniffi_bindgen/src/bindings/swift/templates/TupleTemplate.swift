@@ -0,0 +1,18 @@
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct {{ ffi_converter_name }}: FfiConverterRustBuffer {
+    public static func write(_ value: {{ type_name }}, into buf: inout [UInt8]) {
+        {%- for inner_type in inner_types %}
+        {{ inner_type.ffi_converter_name }}.write(value.{{ loop.index0 }}, into: &buf)
+        {%- endfor %}
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> {{ type_name }} {
+        return (
+            {%- for inner_type in inner_types %}
+            try {{ inner_type.ffi_converter_name }}.read(from: &buf){% if !loop.last %},{% endif %}
+            {%- endfor %}
+        )
+    }
+}
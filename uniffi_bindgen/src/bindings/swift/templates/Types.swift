@@ -1,29 +1,27 @@
 {%- import "macros.swift" as swift %}
 
-{%- if ci.has_callback_definitions() %}
+{%- if has_callback_definitions %}
 {%- include "CallbackInterfaceRuntime.swift" %}
 {%- endif %}
 
-{%- for type_ in ci.iter_local_types() %}
-{%- let type_name = type_|type_name %}
-{%- let ffi_converter_name = type_|ffi_converter_name %}
-{%- let canonical_type_name = type_|canonical_name %}
-{%- let contains_object_references = ci.item_contains_object_references(type_) %}
+{%- for type_def in type_definitions %}
 
 {#
- # Map `Type` instances to an include statement for that type.
+ # Map `TypeDefinition` instances to an include statement for that type.
  #
- # There is a companion match in `KotlinCodeOracle::create_code_type()` which performs a similar function for the
- # Rust code.
- #
- #   - When adding additional types here, make sure to also add a match arm to that function.
- #   - To keep things manageable, let's try to limit ourselves to these 2 mega-matches
+ # Each type definition is separated by a blank line.
  #}
-{%- match type_ %}
+{%- match type_def %}
+
+{%- when TypeDefinition::Simple(type_node) %}
+{%- match type_node.ty %}
 
 {%- when Type::Boolean %}
 {%- include "BooleanHelper.swift" %}
 
+{%- when Type::Char %}
+{%- include "CharHelper.swift" %}
+
 {%- when Type::String %}
 {%- include "StringHelper.swift" %}
 
@@ -54,6 +52,12 @@
 {%- when Type::UInt64 %}
 {%- include "UInt64Helper.swift" %}
 
+{%- when Type::UInt128 %}
+{%- include "UInt128Helper.swift" %}
+
+{%- when Type::Int128 %}
+{%- include "Int128Helper.swift" %}
+
 {%- when Type::Float32 %}
 {%- include "Float32Helper.swift" %}
 
@@ -66,40 +70,97 @@
 {%- when Type::Duration %}
 {%- include "DurationHelper.swift" %}
 
-{%- when Type::CallbackInterface { name, module_path } %}
+{%- else %}
+{%- endmatch %}
+
+{%- when TypeDefinition::CallbackInterface(cbi) %}
+{%- let type_name = cbi.self_type.type_name %}
+{%- let ffi_converter_name = cbi.self_type.ffi_converter_name %}
 {%- include "CallbackInterfaceTemplate.swift" %}
 
-{%- when Type::Custom { name, module_path, builtin } %}
-{%- if !ci.is_external(type_) %}
+{%- when TypeDefinition::Custom(custom) %}
+{%- let type_name = custom.self_type.type_name %}
+{%- let ffi_converter_name = custom.self_type.ffi_converter_name %}
 {%- include "CustomType.swift" %}
-{%- endif %}
 
-{%- when Type::Enum { name, module_path } %}
-{%- let e = ci.get_enum_definition(name).unwrap() %}
-{%- if ci.is_name_used_as_error(name) %}
+{%- when TypeDefinition::Enum(e) %}
+{%- let type_name = e.self_type.type_name %}
+{%- let ffi_converter_name = e.self_type.ffi_converter_name %}
+{%- if e.self_type.is_used_as_error %}
 {%- include "ErrorTemplate.swift" %}
 {%- else %}
 {%- include "EnumTemplate.swift" %}
 {% endif %}
 
-{%- when Type::Object{ name, module_path, .. } %}
+{%- when TypeDefinition::Interface(obj) %}
+{%- let type_name = obj.self_type.type_name %}
+{%- let ffi_converter_name = obj.self_type.ffi_converter_name %}
 {%- include "ObjectTemplate.swift" %}
 
-{%- when Type::Record { name, module_path } %}
+{%- when TypeDefinition::Record(rec) %}
+{%- let type_name = rec.self_type.type_name %}
+{%- let ffi_converter_name = rec.self_type.ffi_converter_name %}
 {%- include "RecordTemplate.swift" %}
 
-{%- when Type::Optional { inner_type } %}
+{%- when TypeDefinition::Optional(opt) %}
+{%- let type_name = opt.self_type.type_name %}
+{%- let ffi_converter_name = opt.self_type.ffi_converter_name %}
+{%- let inner_type = opt.inner %}
 {%- include "OptionalTemplate.swift" %}
 
-{%- when Type::Sequence { inner_type } %}
+{%- when TypeDefinition::Sequence(seq) %}
+{%- let type_name = seq.self_type.type_name %}
+{%- let ffi_converter_name = seq.self_type.ffi_converter_name %}
+{%- let inner_type = seq.inner %}
 {%- include "SequenceTemplate.swift" %}
 
-{%- when Type::Map { key_type, value_type } %}
+{%- when TypeDefinition::Array(array) %}
+{%- let type_name = array.self_type.type_name %}
+{%- let ffi_converter_name = array.self_type.ffi_converter_name %}
+{%- let inner_type = array.inner %}
+{%- let len = array.len %}
+{%- include "ArrayTemplate.swift" %}
+
+{%- when TypeDefinition::Map(map) %}
+{%- let type_name = map.self_type.type_name %}
+{%- let ffi_converter_name = map.self_type.ffi_converter_name %}
+{%- let key_type = map.key %}
+{%- let value_type = map.value %}
+{%- let ordered = map.ordered %}
 {%- include "MapTemplate.swift" %}
 
-{%- when Type::Set { inner_type } %}
+{%- when TypeDefinition::Set(set) %}
+{%- let type_name = set.self_type.type_name %}
+{%- let ffi_converter_name = set.self_type.ffi_converter_name %}
+{%- let inner_type = set.inner %}
+{%- let ordered = set.ordered %}
 {%- include "SetTemplate.swift" %}
 
+{%- when TypeDefinition::Tuple(tuple) %}
+{%- let type_name = tuple.self_type.type_name %}
+{%- let ffi_converter_name = tuple.self_type.ffi_converter_name %}
+{%- let inner_types = tuple.inners %}
+{%- include "TupleTemplate.swift" %}
+
+{%- when TypeDefinition::Stream(stream) %}
+{%- let type_name = stream.self_type.type_name %}
+{%- let ffi_converter_name = stream.self_type.ffi_converter_name %}
+{%- let item_type = stream.item %}
+{%- include "StreamTemplate.swift" %}
+
+{%- when TypeDefinition::Iterator(iterator) %}
+{%- let type_name = iterator.self_type.type_name %}
+{%- let ffi_converter_name = iterator.self_type.ffi_converter_name %}
+{%- let item_type = iterator.item %}
+{%- include "IteratorTemplate.swift" %}
+
+{%- when TypeDefinition::Closure(closure) %}
+{%- let type_name = closure.self_type.type_name %}
+{%- let ffi_converter_name = closure.self_type.ffi_converter_name %}
+{%- let argument_types = closure.arguments %}
+{%- let return_type = closure.return_type %}
+{%- include "ClosureTemplate.swift" %}
+
 {%- else %}
 {%- endmatch %}
 {%- endfor %}
@@ -0,0 +1,69 @@
+/// A 128-bit unsigned integer, stored as its high and low 64-bit words.
+///
+/// Swift's native `UInt128` needs a Swift 6 toolchain and a recent OS, so we can't use it
+/// unconditionally.  Where it's available, convert with `init(_:)` and `value`.
+public struct UniffiUInt128: Equatable, Hashable, Codable, CustomStringConvertible, ExpressibleByIntegerLiteral {
+    public var high: UInt64
+    public var low: UInt64
+
+    public init(high: UInt64, low: UInt64) {
+        self.high = high
+        self.low = low
+    }
+
+    public init(_ value: UInt64) {
+        self.init(high: 0, low: value)
+    }
+
+    public init(integerLiteral value: UInt64) {
+        self.init(value)
+    }
+
+    public var description: String {
+        // Render the value in decimal by repeatedly dividing by 10
+        var high = self.high
+        var low = self.low
+        var digits: [Character] = []
+        repeat {
+            let (highQuotient, highRemainder) = high.quotientAndRemainder(dividingBy: 10)
+            let (lowQuotient, remainder) = UInt64(10).dividingFullWidth((highRemainder, low))
+            high = highQuotient
+            low = lowQuotient
+            digits.append(Character(String(remainder)))
+        } while high != 0 || low != 0
+        return String(digits.reversed())
+    }
+}
+
+#if compiler(>=6)
+extension UniffiUInt128: Sendable {}
+
+@available(macOS 15.0, iOS 18.0, watchOS 11.0, tvOS 18.0, visionOS 2.0, *)
+extension UniffiUInt128 {
+    public init(_ value: UInt128) {
+        self.init(high: UInt64(truncatingIfNeeded: value >> 64), low: UInt64(truncatingIfNeeded: value))
+    }
+
+    public var value: UInt128 {
+        return UInt128(high) << 64 | UInt128(low)
+    }
+}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt128: FfiConverterRustBuffer {
+    typealias SwiftType = UniffiUInt128
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UniffiUInt128 {
+        let high: UInt64 = try readInt(&buf)
+        let low: UInt64 = try readInt(&buf)
+        return UniffiUInt128(high: high, low: low)
+    }
+
+    public static func write(_ value: UniffiUInt128, into buf: inout [UInt8]) {
+        writeInt(&buf, value.high)
+        writeInt(&buf, value.low)
+    }
+}
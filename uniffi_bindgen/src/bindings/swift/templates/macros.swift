@@ -4,10 +4,10 @@
 // passed to rust via `arg_list_lowered`
 #}
 
-{%- macro to_ffi_call(func) -%}
-    {%- call is_try(func) %}{% endcall -%}
-    {%- if let(Some(e)) = func.throws_type() -%}
-        rustCallWithError({{ e|ffi_error_converter_name }}_lift) {
+{%- macro to_ffi_call(callable) -%}
+    {%- call is_try(callable) %}{% endcall -%}
+    {%- if let Some(e) = callable.throws_type.ty -%}
+        rustCallWithError({{ e.ffi_error_converter_name }}_lift) {
     {%- else -%}
         rustCall() {
     {%- endif %}
@@ -18,46 +18,69 @@
     {#- Open nested `FfiConverterByRefBytes.lower` scopes for any `&[u8]`
         args. The `ForeignBytes` value is only guaranteed valid inside the
         scope, so the full FFI call runs inside the innermost closure. #}
-    {%- for arg in func.arguments() -%}
-    {%-     if arg|is_borrowed_bytes %}
-        FfiConverterByRefBytes.lower({{ arg.name()|var_name }}) { {{ arg.name()|var_name }}Fb in
-    {%-     endif %}
+    {%- for arg in callable.borrowed_bytes_arguments() %}
+        FfiConverterByRefBytes.lower({{ arg.name }}) { {{ arg.name }}Fb in
     {%- endfor %}
-    {{ func.ffi_func().name() }}(
-        {%- match func.self_type() %}
-        {%-     when Some(Type::Object { .. }) %}
+    {{ callable.ffi_func.0 }}(
+        {%- if callable.self_is_interface() %}
             self.uniffiCloneHandle(),
-        {%-     when Some(t) %}
-            {{ t|lower_fn }}(self),
-        {%-     when None %}
-        {%- endmatch %}
-        {%- call arg_list_lowered(func) %}{% endcall -%}
+        {%- else if let Some(t) = callable.self_type() %}
+            {{ t.lower_fn }}(self),
+        {%- endif %}
+        {%- call arg_list_lowered(callable) %}{% endcall -%}
          uniffiCallStatus
     )
     {#- Close nested `withUnsafeBytes` closures. #}
-    {%- for arg in func.arguments() -%}
-    {%-     if arg|is_borrowed_bytes %}
+    {%- for arg in callable.borrowed_bytes_arguments() %}
+        }
+    {%- endfor %}
+}
+{%- endmacro -%}
+
+{#-
+// Call the Rust default implementation of a trait method, passing `self` as the receiver.
+-#}
+{%- macro default_method_call(callable, default_ffi_func) -%}
+    {%- call is_try(callable) %}{% endcall -%}
+    {%- if let Some(e) = callable.throws_type.ty -%}
+        rustCallWithError({{ e.ffi_error_converter_name }}_lift) {
+    {%- else -%}
+        rustCall() {
+    {%- endif %}
+        uniffiCallStatus in
+    {%- for arg in callable.borrowed_bytes_arguments() %}
+        FfiConverterByRefBytes.lower({{ arg.name }}) { {{ arg.name }}Fb in
+    {%- endfor %}
+    {{ default_ffi_func.0 }}(
+        {%- if let Some(self_type) = callable.self_type() %}
+            {{ self_type.lower_fn }}(self),
+        {%- endif %}
+        {%- call arg_list_lowered(callable) %}{% endcall -%}
+         uniffiCallStatus
+    )
+    {%- for arg in callable.borrowed_bytes_arguments() %}
         }
-    {%-     endif %}
     {%- endfor %}
 }
 {%- endmacro -%}
 
 // eg, `public func foo_bar() { body }`
-{%- macro func_decl(func_decl, callable, indent) %}
-{%- call docstring(callable, indent) %}{% endcall %}
-{{ func_decl }} {{ callable.name()|fn_name }}(
+{%- macro func_decl(func_decl, item, indent) %}
+{%- let callable = item.callable %}
+{%- call docstring(item, indent) %}{% endcall %}
+{{ func_decl }} {{ callable.name }}(
     {%- call arg_list_decl(callable) %}{% endcall -%})
     {%- call is_async(callable) %}{% endcall %}
     {%- call throws(callable) %}{% endcall %}
-    {%- if let Some(return_type) = callable.return_type() %} -> {{ return_type|type_name }} {%- endif %}  {
+    {%- if let Some(return_type) = callable.return_type.ty %} -> {{ return_type.type_name }} {%- endif %}  {
     {%- call call_body(callable) %}{% endcall %}
 }
 {%- endmacro %}
 
 // primary ctor - no name, no return-type.
-{%- macro ctor_decl(callable, indent) %}
-{%- call docstring(callable, indent) %}{% endcall %}
+{%- macro ctor_decl(item, indent) %}
+{%- let callable = item.callable %}
+{%- call docstring(item, indent) %}{% endcall %}
 public convenience init(
     {%- call arg_list_decl(callable) %}{% endcall -%}) {%- call is_async(callable) %}{% endcall %} {%- call throws(callable) %}{% endcall %} {
     {%- if callable.is_async() %}
@@ -80,9 +103,9 @@ public convenience init(
 {%- if callable.is_async() %}
     return {%- call call_async(callable) %}{% endcall %}
 {%- else %}
-{%-     match callable.return_type() -%}
+{%-     match callable.return_type.ty -%}
 {%-         when Some(return_type) %}
-    return {% call is_try(callable) %}{% endcall %} {{ return_type|lift_fn }}({% call to_ffi_call(callable) %}{% endcall %})
+    return {% call is_try(callable) %}{% endcall %} {{ return_type.lift_fn }}({% call to_ffi_call(callable) %}{% endcall %})
 {%-         when None %}
 {%-             call to_ffi_call(callable) %}{% endcall %}
 {%-     endmatch %}
@@ -91,107 +114,93 @@ public convenience init(
 {%- endmacro %}
 
 {%- macro call_async(callable) %}
+{%- let async_data = callable.async_data.as_ref().unwrap() %}
         {% call is_try(callable) %}{% endcall %} await uniffiRustCallAsync(
             rustFutureFunc: {
-                {{ callable.ffi_func().name() }}(
-                    {%- match callable.self_type() %}
-                    {%-     when Some(Type::Object { .. }) %}
-                        self.uniffiCloneHandle(){% if !callable.arguments().is_empty() %},{% endif %}
-                    {%-     when Some(t) %}
-                        {{ t|lower_fn }}(self){% if !callable.arguments().is_empty() %},{% endif %}
-                    {%-     when None %}
-                    {%- endmatch %}
-                    {%- for arg in callable.arguments() -%}
-                    {{ arg|lower_fn }}({{ arg.name()|var_name }}){% if !loop.last %},{% endif %}
+                {{ callable.ffi_func.0 }}(
+                    {%- if callable.self_is_interface() %}
+                        self.uniffiCloneHandle(){% if !callable.arguments.is_empty() %},{% endif %}
+                    {%- else if let Some(t) = callable.self_type() %}
+                        {{ t.lower_fn }}(self){% if !callable.arguments.is_empty() %},{% endif %}
+                    {%- endif %}
+                    {%- for arg in callable.arguments -%}
+                    {{ arg.ty.lower_fn }}({{ arg.name }}){% if !loop.last %},{% endif %}
                     {%- endfor %}
                 )
             },
-            pollFunc: {{ callable.ffi_rust_future_poll(ci) }},
-            completeFunc: {{ callable.ffi_rust_future_complete(ci) }},
-            freeFunc: {{ callable.ffi_rust_future_free(ci) }},
-            {%- match callable.return_type() %}
+            pollFunc: {{ async_data.ffi_rust_future_poll.0 }},
+            cancelFunc: {{ async_data.ffi_rust_future_cancel.0 }},
+            completeFunc: {{ async_data.ffi_rust_future_complete.0 }},
+            freeFunc: {{ async_data.ffi_rust_future_free.0 }},
+            {%- match callable.return_type.ty %}
             {%- when Some(return_type) %}
-            liftFunc: {{ return_type|lift_fn }},
+            liftFunc: {{ return_type.lift_fn }},
             {%- when None %}
             liftFunc: { $0 },
             {%- endmatch %}
-            {%- match callable.throws_type() %}
+            {%- match callable.throws_type.ty %}
             {%- when Some with (e) %}
-            errorHandler: {{ e|ffi_error_converter_name }}_lift
+            errorHandler: {{ e.ffi_error_converter_name }}_lift
             {%- else %}
             errorHandler: nil
             {% endmatch %}
         )
 {%- endmacro %}
 
-{%- macro arg_list_lowered(func) %}
-    {%- for arg in func.arguments() %}
-        {{ arg|arg_expr }},
+{%- macro arg_list_lowered(callable) %}
+    {%- for arg in callable.arguments %}
+        {{ arg.arg_expr }},
     {%- endfor %}
 {%- endmacro -%}
 
 {#-
 // Arglist as used in Swift declarations of methods, functions and constructors.
-// Note the var_name and type_name filters.
 -#}
 
-{% macro arg_list_decl(func) %}
-    {%- for arg in func.arguments() -%}
-        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {{ arg|type_name -}}
-        {%- match arg.default_value() %}
-        {%- when Some with(default) %} = {{ default|default_swift(arg) }}
-        {%- else %}
-        {%- endmatch %}
+{% macro arg_list_decl(callable) %}
+    {%- for arg in callable.arguments -%}
+        {% if omit_argument_labels %}_ {% endif %}{{ arg.name }}: {{ arg.type_name -}}
+        {%- if let Some(default) = arg.default %} = {{ default }}
+        {%- endif %}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 
 {#-
 // Field lists as used in Swift declarations of Records and Enums.
-// Note the var_name and type_name filters.
 -#}
 {% macro field_list_decl(item, has_nameless_fields) %}
-    {%- for field in item.fields() -%}
+    {%- for field in item.fields -%}
         {%- call docstring(field, 8) %}{% endcall %}
         {%- if has_nameless_fields %}
-        {{- field|type_name -}}
+        {{- field.ty.type_name -}}
         {%- if !loop.last -%}, {%- endif -%}
         {%- else -%}
-        {{ field.name()|var_name }}: {{ field|type_name -}}
-        {%- match field.default_value() %}
-            {%- when Some with(default) %} = {{ default|default_swift(field) }}
-            {%- else %}
-        {%- endmatch -%}
+        {{ field.name }}: {{ field.ty.type_name -}}
+        {%- if let Some(default) = field.default %} = {{ default }}
+        {%- endif -%}
         {% if !loop.last %}, {% endif %}
         {%- endif -%}
     {%- endfor %}
 {%- endmacro %}
 
-{% macro field_name(field, field_num) %}
-{%- if field.name().is_empty() -%}
-v{{- field_num -}}
-{%- else -%}
-{{ field.name()|var_name }}
-{%- endif -%}
-{%- endmacro %}
-
-{% macro arg_list_protocol(func) %}
-    {%- for arg in func.arguments() -%}
-        {% if config.omit_argument_labels() %}_ {% endif %}{{ arg.name()|var_name }}: {{ arg|type_name -}}
+{% macro arg_list_protocol(callable) %}
+    {%- for arg in callable.arguments -%}
+        {% if omit_argument_labels %}_ {% endif %}{{ arg.name }}: {{ arg.type_name -}}
         {%- if !loop.last %}, {% endif -%}
     {%- endfor %}
 {%- endmacro %}
 
-{%- macro is_async(func) %}
-{%- if func.is_async() %}async {% endif %}
+{%- macro is_async(callable) %}
+{%- if callable.is_async() %}async {% endif %}
 {%- endmacro -%}
 
-{%- macro throws(func) %}
-{%- if func.throws() %}throws {% endif %}
+{%- macro throws(callable) %}
+{%- if callable.throws() %}throws {% endif %}
 {%- endmacro -%}
 
-{%- macro is_try(func) %}
-{%- if func.throws() %}try {% else %}try! {% endif %}
+{%- macro is_try(callable) %}
+{%- if callable.throws() %}try {% else %}try! {% endif %}
 {%- endmacro -%}
 
 {%- macro docstring_value(maybe_docstring, indent_spaces) %}
@@ -203,7 +212,7 @@ v{{- field_num -}}
 {%- endmacro %}
 
 {%- macro docstring(defn, indent_spaces) %}
-{%- call docstring_value(defn.docstring(), indent_spaces) %}{% endcall %}
+{%- call docstring_value(defn.docstring, indent_spaces) %}{% endcall %}
 {%- endmacro %}
 
 // macro for uniffi_trait implementations.
@@ -211,42 +220,88 @@ v{{- field_num -}}
 {%- if let Some(fmt) = uniffi_trait_methods.debug_fmt %}
 // The local Rust `Debug` implementation.
 public var debugDescription: String {
-    return {% call is_try(fmt) %}{% endcall %} {{ fmt.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(fmt) %}{% endcall %}
+    return {% call is_try(fmt.callable) %}{% endcall %} {{ fmt.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(fmt.callable) %}{% endcall %}
     )
 }
 {%- endif %}
 {%- if let Some(fmt) = uniffi_trait_methods.display_fmt %}
 // The local Rust `Display` implementation.
 public var description: String {
-    return {% call is_try(fmt) %}{% endcall %} {{ fmt.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(fmt) %}{% endcall %}
+    return {% call is_try(fmt.callable) %}{% endcall %} {{ fmt.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(fmt.callable) %}{% endcall %}
     )
 }
 {%- endif %}
 {%- if let Some(eq) = uniffi_trait_methods.eq_eq %}
 // The local Rust `Eq` implementation - only `eq` is used.
-public static func == (self: {{ eq.object_name() | class_name }}, other: {{ eq.object_name() | class_name }}) -> Bool {
-    return {% call is_try(eq) %}{% endcall %} {{ eq.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(eq) %}{% endcall %}
+public static func == (self: {{ eq.callable.self_type().unwrap().type_name }}, other: {{ eq.callable.self_type().unwrap().type_name }}) -> Bool {
+    return {% call is_try(eq.callable) %}{% endcall %} {{ eq.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(eq.callable) %}{% endcall %}
     )
 }
 {%- endif %}
 {%- if let Some(hash) = uniffi_trait_methods.hash_hash %}
 // The local Rust `Hash` implementation
 public func hash(into hasher: inout Hasher) {
-    let val = {% call is_try(hash) %}{% endcall %} {{ hash.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(hash) %}{% endcall %}
+    let val = {% call is_try(hash.callable) %}{% endcall %} {{ hash.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(hash.callable) %}{% endcall %}
     )
     hasher.combine(val)
 }
 {%- endif %}
 {%- if let Some(cmp) = uniffi_trait_methods.ord_cmp %}
 // The local Rust `Ord` implementation
-public static func < (self: {{ cmp.object_name() | class_name }}, other: {{ cmp.object_name() | class_name }}) -> Bool {
-    return {% call is_try(cmp) %}{% endcall %} {{ cmp.return_type().unwrap()|lift_fn }}(
-        {% call to_ffi_call(cmp) %}{% endcall %}
+public static func < (self: {{ cmp.callable.self_type().unwrap().type_name }}, other: {{ cmp.callable.self_type().unwrap().type_name }}) -> Bool {
+    return {% call is_try(cmp.callable) %}{% endcall %} {{ cmp.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(cmp.callable) %}{% endcall %}
     ) < 0
 }
 {%- endif %}
+{%- if let Some(partial_cmp) = uniffi_trait_methods.partial_ord_partial_cmp %}
+// The local Rust `PartialOrd` implementation.  Returns `nil` if the values can't be compared.
+public func partialCompare(other: {{ partial_cmp.callable.self_type().unwrap().type_name }}) -> Int8? {
+    return {% call is_try(partial_cmp.callable) %}{% endcall %} {{ partial_cmp.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(partial_cmp.callable) %}{% endcall %}
+    )
+}
+{%- if uniffi_trait_methods.ord_cmp.is_none() %}
+public static func < (lhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}, rhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}) -> Bool {
+    return lhs.partialCompare(other: rhs).map { $0 < 0 } ?? false
+}
+public static func <= (lhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}, rhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}) -> Bool {
+    return lhs.partialCompare(other: rhs).map { $0 <= 0 } ?? false
+}
+public static func > (lhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}, rhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}) -> Bool {
+    return lhs.partialCompare(other: rhs).map { $0 > 0 } ?? false
+}
+public static func >= (lhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}, rhs: {{ partial_cmp.callable.self_type().unwrap().type_name }}) -> Bool {
+    return lhs.partialCompare(other: rhs).map { $0 >= 0 } ?? false
+}
+{%- endif %}
+{%- endif %}
+{%- if let Some(clone) = uniffi_trait_methods.clone_clone %}
+// The local Rust `Clone` implementation.
+public func clone() -> {{ clone.callable.return_type.ty.as_ref().unwrap().type_name }} {
+    return {% call is_try(clone.callable) %}{% endcall %} {{ clone.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(clone.callable) %}{% endcall %}
+    )
+}
+{%- endif %}
+{%- if let Some(default) = uniffi_trait_methods.default_default %}
+// The local Rust `Default` implementation.
+public static func `default`() -> {{ default.callable.return_type.ty.as_ref().unwrap().type_name }} {
+    return {% call is_try(default.callable) %}{% endcall %} {{ default.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(default.callable) %}{% endcall %}
+    )
+}
+{%- endif %}
+{%- if let Some(from_str) = uniffi_trait_methods.from_str_from_str %}
+// The local Rust `FromStr` implementation.
+public static func parse(_ s: String) {% call throws(from_str.callable) %}{% endcall %}-> {{ from_str.callable.return_type.ty.as_ref().unwrap().type_name }} {
+    return {% call is_try(from_str.callable) %}{% endcall %} {{ from_str.callable.return_type.ty.as_ref().unwrap().lift_fn }}(
+        {% call to_ffi_call(from_str.callable) %}{% endcall %}
+    )
+}
+{%- endif %}
 {%- endmacro %}
@@ -3,19 +3,19 @@
 
 // swiftlint:disable all
 
-{%- call swift::docstring_value(ci.namespace_docstring(), 0) %}{% endcall %}
+{%- call swift::docstring_value(docstring, 0) %}{% endcall %}
 
 {%- import "macros.swift" as swift %}
 import Foundation
-{%- for imported_class in self.imports() %}
+{%- for imported_class in imports %}
 import {{ imported_class }}
 {%- endfor %}
 
 // Depending on the consumer's build setup, the low-level FFI code
 // might be in a separate module, or it might be compiled inline into
 // this module. This is a bit of light hackery to work with both.
-#if canImport({{ config.ffi_module_name() }})
-import {{ config.ffi_module_name() }}
+#if canImport({{ ffi_module_name }})
+import {{ ffi_module_name }}
 #endif
 
 {% include "RustBufferTemplate.swift" %}
@@ -23,16 +23,21 @@ import {{ config.ffi_module_name() }}
 {% include "HandleMap.swift" %}
 
 // Public interface members begin here.
-{{ type_helper_code }}
+{% include "Types.swift" %}
 
-{%- if ci.has_async_fns() %}
+{%- if has_async_fns %}
 {% include "Async.swift" %}
 {%- endif %}
 
-{%- for func in ci.function_definitions() %}
+{%- for func in functions %}
 {%- include "TopLevelFunctionTemplate.swift" %}
 {%- endfor %}
 
+{%- for constant in constants %}
+{%- call swift::docstring(constant, 0) %}{% endcall %}
+public let {{ constant.name }}: {{ constant.ty.type_name }} = {{ constant.value }}
+{%- endfor %}
+
 private enum InitializationResult {
     case ok
     case contractVersionMismatch
@@ -42,22 +47,22 @@ private enum InitializationResult {
 // the code inside is only computed once.
 private let initializationResult: InitializationResult = {
     // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = {{ ci.uniffi_contract_version() }}
+    let bindings_contract_version = {{ correct_contract_version }}
     // Get the scaffolding contract version by calling the into the dylib
-    let scaffolding_contract_version = {{ ci.ffi_uniffi_contract_version().name() }}()
+    let scaffolding_contract_version = {{ ffi_uniffi_contract_version.0 }}()
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
 
-{%- if !config.omit_checksums %}
-    {%- for (name, expected_checksum) in ci.iter_checksums() %}
-    if ({{ name }}() != {{ expected_checksum }}) {
+{%- if !omit_checksums %}
+    {%- for checksum in checksums %}
+    if ({{ checksum.fn_name.0 }}() != {{ checksum.checksum }}) {
         return InitializationResult.apiChecksumMismatch
     }
     {%- endfor %}
 {%- endif %}
 
-    {% for fn_item in self.initialization_fns() -%}
+    {% for fn_item in initialization_fns -%}
     {{ fn_item }}()
     {% endfor -%}
 
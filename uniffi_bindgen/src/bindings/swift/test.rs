@@ -152,11 +152,12 @@ impl GeneratedSources {
             },
         )?;
 
-        let main_source = sources
+        let main_module = sources
             .iter()
-            .find(|s| s.ci.crate_name() == crate_name)
-            .unwrap();
-        let main_module = main_source.config.module_name();
+            .find(|module| module.crate_name == crate_name)
+            .unwrap()
+            .module_name
+            .clone();
         let modulemap_glob = glob(&out_dir.join("*.modulemap"))?;
         let module_map = match modulemap_glob.len() {
             0 => bail!("No modulemap files found in {out_dir}"),
@@ -165,8 +165,8 @@ use uniffi_meta::{Checksum, EnumShape};
 use super::function::Callable;
 use super::record::Field;
 use super::{
-    AsType, Constructor, FfiFunction, Literal, Method, Type, TypeIterator, UniffiTrait,
-    UniffiTraitMethods,
+    AsType, Constructor, FfiFunction, Literal, Method, StaticMethod, Type, TypeIterator,
+    UniffiTrait, UniffiTraitMethods,
 };
 
 /// Represents an enum with named variants, each of which may have named
@@ -185,6 +185,7 @@ pub struct Enum {
     pub(super) non_exhaustive: bool,
     pub(super) constructors: Vec<Constructor>,
     pub(super) methods: Vec<Method>,
+    pub(super) static_methods: Vec<StaticMethod>,
     // The "uniffi trait" methods - eg, `Eq`, `Display` etc.
     uniffi_traits: Vec<UniffiTrait>,
     #[checksum_ignore]
@@ -216,6 +217,10 @@ impl Enum {
         &self.methods
     }
 
+    pub fn static_methods(&self) -> &[StaticMethod] {
+        &self.static_methods
+    }
+
     // Get the literal value to use for the specified variant's discriminant.
     // Follows Rust's rules when mixing specified and unspecified values; please
     // file a bug if you find a case where it does not.
@@ -278,7 +283,12 @@ impl Enum {
                 .iter()
                 .flat_map(Variant::iter_types)
                 .chain(self.constructors.iter().flat_map(Constructor::iter_types))
-                .chain(self.methods.iter().flat_map(Method::iter_types)),
+                .chain(self.methods.iter().flat_map(Method::iter_types))
+                .chain(
+                    self.static_methods
+                        .iter()
+                        .flat_map(StaticMethod::iter_types),
+                ),
         )
     }
 
@@ -305,6 +315,9 @@ impl Enum {
         for m in self.methods.iter_mut() {
             m.derive_ffi_func()?;
         }
+        for m in self.static_methods.iter_mut() {
+            m.derive_ffi_func();
+        }
         for ut in self.uniffi_traits.iter_mut() {
             ut.derive_ffi_func()?;
         }
@@ -316,18 +329,8 @@ impl Enum {
             .iter()
             .map(|f| &f.ffi_func)
             .chain(self.methods.iter().map(|f| &f.ffi_func))
-            .chain(
-                self.uniffi_traits
-                    .iter()
-                    .flat_map(|ut| match ut {
-                        UniffiTrait::Display { fmt: m }
-                        | UniffiTrait::Debug { fmt: m }
-                        | UniffiTrait::Hash { hash: m }
-                        | UniffiTrait::Ord { cmp: m } => vec![m],
-                        UniffiTrait::Eq { eq, ne } => vec![eq, ne],
-                    })
-                    .map(|m| &m.ffi_func),
-            )
+            .chain(self.static_methods.iter().map(|f| &f.ffi_func))
+            .chain(self.uniffi_traits.iter().flat_map(UniffiTrait::ffi_funcs))
     }
 }
 
@@ -349,6 +352,7 @@ impl TryFrom<uniffi_meta::EnumMetadata> for Enum {
             non_exhaustive: meta.non_exhaustive,
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             uniffi_traits: vec![],
             docstring: meta.docstring.clone(),
         })
@@ -751,6 +755,7 @@ mod test {
             non_exhaustive: false,
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             uniffi_traits: vec![],
             docstring: None,
         };
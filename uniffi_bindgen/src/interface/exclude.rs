@@ -6,7 +6,9 @@
 
 use std::collections::HashSet;
 
-use crate::interface::{AsType, ComponentInterface, Constructor, Function, Method, TypeUniverse};
+use crate::interface::{
+    AsType, ComponentInterface, Constructor, Function, Method, StaticMethod, TypeUniverse,
+};
 
 pub fn apply_exclusions(ci: &mut ComponentInterface, exclusions: &[String]) {
     let exclusions: HashSet<&str> = exclusions.iter().map(String::as_str).collect();
@@ -20,14 +22,17 @@ pub fn apply_exclusions(ci: &mut ComponentInterface, exclusions: &[String]) {
     for o in ci.objects.iter_mut() {
         remove_constructors(&exclusions, &o.name, &mut o.constructors);
         remove_methods(&exclusions, &o.name, &mut o.methods);
+        remove_static_methods(&exclusions, &o.name, &mut o.static_methods);
     }
     for rec in ci.records.iter_mut() {
         remove_constructors(&exclusions, &rec.name, &mut rec.constructors);
         remove_methods(&exclusions, &rec.name, &mut rec.methods);
+        remove_static_methods(&exclusions, &rec.name, &mut rec.static_methods);
     }
     for en in ci.enums.iter_mut() {
         remove_constructors(&exclusions, &en.name, &mut en.constructors);
         remove_methods(&exclusions, &en.name, &mut en.methods);
+        remove_static_methods(&exclusions, &en.name, &mut en.static_methods);
     }
     for cbi in ci.callback_interfaces.iter_mut() {
         remove_methods(&exclusions, &cbi.name, &mut cbi.methods);
@@ -77,3 +82,11 @@ fn remove_constructors(
 fn remove_methods(excludes: &HashSet<&str>, type_name: &str, methods: &mut Vec<Method>) {
     methods.retain(|meth| !excludes.contains(format!("{type_name}.{}", meth.name).as_str()));
 }
+
+fn remove_static_methods(
+    excludes: &HashSet<&str>,
+    type_name: &str,
+    methods: &mut Vec<StaticMethod>,
+) {
+    methods.retain(|meth| !excludes.contains(format!("{type_name}.{}", meth.name).as_str()));
+}
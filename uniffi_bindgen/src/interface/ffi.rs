@@ -112,8 +112,12 @@ impl From<&Type> for FfiType {
             Type::Int64 => FfiType::Int64,
             Type::Float32 => FfiType::Float32,
             Type::Float64 => FfiType::Float64,
+            // There's no portable C type for 128-bit integers, so they're serialized.
+            Type::UInt128 | Type::Int128 => FfiType::RustBuffer(None),
             // Booleans lower into an Int8, to work around a bug in JNA.
             Type::Boolean => FfiType::Int8,
+            // Chars are passed as their code point.
+            Type::Char => FfiType::UInt32,
             // Strings are always owned rust values.
             // We might add a separate type for borrowed strings in future.
             Type::String => FfiType::RustBuffer(None),
@@ -124,6 +128,8 @@ impl From<&Type> for FfiType {
             Type::Object { .. } => FfiType::Handle,
             // Callback interfaces are passed as opaque integer handles.
             Type::CallbackInterface { .. } => FfiType::UInt64,
+            // Streams and iterators are handles to a type-erased Rust stream/iterator.
+            Type::Stream { .. } | Type::Iterator { .. } | Type::Closure { .. } => FfiType::Handle,
             // Other types are serialized into a bytebuffer and deserialized on the other side.
             Type::Enum { name, module_path } | Type::Record { name, module_path } => {
                 FfiType::RustBuffer(Some(ExternalFfiMetadata {
@@ -133,8 +139,10 @@ impl From<&Type> for FfiType {
             }
             Type::Optional { .. }
             | Type::Sequence { .. }
+            | Type::Array { .. }
             | Type::Map { .. }
             | Type::Set { .. }
+            | Type::Tuple { .. }
             | Type::Timestamp
             | Type::Duration => FfiType::RustBuffer(None),
             Type::Custom {
@@ -58,6 +58,8 @@ use universe::{TypeIterator, TypeUniverse};
 
 mod callbacks;
 pub use callbacks::CallbackInterface;
+mod constant;
+pub use constant::Constant;
 mod custom_type;
 pub use custom_type::CustomType;
 mod enum_;
@@ -65,7 +67,7 @@ pub use enum_::{Enum, Variant};
 mod function;
 pub use function::{Argument, Callable, Function, ResultType};
 mod object;
-pub use object::{Constructor, Method, Object, UniffiTrait, UniffiTraitMethods};
+pub use object::{Constructor, Method, Object, StaticMethod, UniffiTrait, UniffiTraitMethods};
 mod record;
 pub use record::{Field, Record};
 
@@ -82,8 +84,8 @@ pub use ffi::{
 pub use uniffi_meta::Radix;
 use uniffi_meta::{
     ConstructorMetadata, DefaultValueMetadata, LiteralMetadata, MethodMetadata, NamespaceMetadata,
-    ObjectMetadata, ObjectTraitImplMetadata, TraitMethodMetadata, UniffiTraitMetadata,
-    UNIFFI_CONTRACT_VERSION,
+    ObjectMetadata, ObjectTraitImplMetadata, StaticMethodMetadata, TraitMethodMetadata,
+    UniffiTraitMetadata, UNIFFI_CONTRACT_VERSION,
 };
 pub type Literal = LiteralMetadata;
 pub type DefaultValue = DefaultValueMetadata;
@@ -102,6 +104,7 @@ pub struct ComponentInterface {
     functions: Vec<Function>,
     objects: Vec<Object>,
     custom_types: Vec<CustomType>,
+    constants: Vec<Constant>,
     pub(crate) callback_interfaces: Vec<CallbackInterface>,
     // Type names which were seen used as an error.
     errors: HashSet<String>,
@@ -241,6 +244,11 @@ impl ComponentInterface {
         self.functions.iter().find(|f| f.name == name)
     }
 
+    /// Get the definitions for every exported constant in the interface.
+    pub fn constant_definitions(&self) -> &[Constant] {
+        &self.constants
+    }
+
     /// Returns `true` if we have any objects in this interface.
     pub fn has_object_definitions(&self) -> bool {
         !self.objects.is_empty()
@@ -323,6 +331,16 @@ impl ComponentInterface {
                     .map(|c| c as &dyn Callable)
                     .chain(o.methods().into_iter().map(|m| m as &dyn Callable))
             }))
+            .chain(self.iter_static_methods().map(|m| m as &dyn Callable))
+    }
+
+    /// Iterate over the static methods of all objects, records and enums
+    pub fn iter_static_methods(&self) -> impl Iterator<Item = &StaticMethod> {
+        self.objects
+            .iter()
+            .flat_map(|o| o.static_methods.iter())
+            .chain(self.records.iter().flat_map(|r| r.static_methods.iter()))
+            .chain(self.enums.iter().flat_map(|e| e.static_methods.iter()))
     }
 
     /// Should we generate read (and lift) functions for errors?
@@ -418,6 +436,18 @@ impl ComponentInterface {
             .any(|t| matches!(t, Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64))
     }
 
+    /// Check whether the given item contains any (possibly nested) tuple types
+    pub fn item_contains_tuple_types(&self, item: &Type) -> bool {
+        self.iter_types_in_item(item)
+            .any(|t| matches!(t, Type::Tuple { .. }))
+    }
+
+    /// Check whether the given item contains any (possibly nested) ordered map types
+    pub fn item_contains_ordered_map_types(&self, item: &Type) -> bool {
+        self.iter_types_in_item(item)
+            .any(|t| matches!(t, Type::Map { ordered: true, .. }))
+    }
+
     /// Check whether the interface contains any optional types
     pub fn contains_optional_types(&self) -> bool {
         self.types
@@ -633,6 +663,146 @@ impl ComponentInterface {
         }
     }
 
+    /// Builtin FFI function to create a Rust future for the next item in a stream.
+    ///
+    /// The future always returns a `RustBuffer` containing an `Option<T>`.
+    pub fn ffi_rust_stream_next(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_stream_next", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: Some(FfiType::Handle),
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function for freeing a Rust stream
+    pub fn ffi_rust_stream_free(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_stream_free", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: None,
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function to get the next chunk of items from a Rust iterator.
+    ///
+    /// This returns a `RustBuffer` containing a `Vec<T>` of up to `max_items` items, where an
+    /// empty `Vec` signals the end of the iterator.
+    pub fn ffi_rust_iter_next_chunk(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_iter_next_chunk", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![
+                FfiArgument {
+                    name: "handle".to_owned(),
+                    type_: FfiType::Handle,
+                },
+                FfiArgument {
+                    name: "max_items".to_owned(),
+                    type_: FfiType::UInt32,
+                },
+            ],
+            return_type: Some(FfiType::RustBuffer(None)),
+            has_rust_call_status_arg: true,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function for freeing a Rust iterator
+    pub fn ffi_rust_iter_free(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_iter_free", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: None,
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function to call a Rust closure.
+    ///
+    /// `args` is a `RustBuffer` containing the serialized arguments.  This returns a `RustBuffer`
+    /// containing the serialized return value.
+    pub fn ffi_rust_closure_call(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_closure_call", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![
+                FfiArgument {
+                    name: "handle".to_owned(),
+                    type_: FfiType::Handle,
+                },
+                FfiArgument {
+                    name: "args".to_owned(),
+                    type_: FfiType::RustBuffer(None),
+                },
+            ],
+            return_type: Some(FfiType::RustBuffer(None)),
+            has_rust_call_status_arg: true,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function for cloning a Rust closure handle
+    pub fn ffi_rust_closure_clone(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_closure_clone", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: Some(FfiType::Handle),
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function for freeing a Rust closure
+    pub fn ffi_rust_closure_free(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_rust_closure_free", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "handle".to_owned(),
+                type_: FfiType::Handle,
+            }],
+            return_type: None,
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
+    /// Builtin FFI function to register the VTable for foreign closures
+    pub fn ffi_foreign_closure_init_vtable(&self) -> FfiFunction {
+        FfiFunction {
+            name: format!("ffi_{}_foreign_closure_init_vtable", self.ffi_namespace()),
+            is_async: false,
+            arguments: vec![FfiArgument {
+                name: "vtable".to_owned(),
+                type_: FfiType::Struct("VTableForeignClosure".to_owned()).reference(),
+            }],
+            return_type: None,
+            has_rust_call_status_arg: false,
+            is_object_free_function: false,
+        }
+    }
+
     fn rust_future_ffi_fn_name(&self, base_name: &str, return_ffi_type: Option<FfiType>) -> String {
         let namespace = self.ffi_namespace();
         let return_type_name = FfiType::return_type_name(return_ffi_type.as_ref());
@@ -640,12 +810,33 @@ impl ComponentInterface {
     }
 
     /// Does this interface contain async functions?
+    ///
+    /// Streams are driven with Rust futures, so they count as well.
     pub fn has_async_fns(&self) -> bool {
         self.iter_ffi_function_definitions().any(|f| f.is_async())
             || self
                 .callback_interfaces
                 .iter()
                 .any(CallbackInterface::has_async_method)
+            || self.has_stream_types()
+    }
+
+    /// Does this interface use any stream types?
+    pub fn has_stream_types(&self) -> bool {
+        self.iter_local_types()
+            .any(|t| matches!(t, Type::Stream { .. }))
+    }
+
+    /// Does this interface use any iterator types?
+    pub fn has_iterator_types(&self) -> bool {
+        self.iter_local_types()
+            .any(|t| matches!(t, Type::Iterator { .. }))
+    }
+
+    /// Does this interface use any closure types?
+    pub fn has_closure_types(&self) -> bool {
+        self.iter_local_types()
+            .any(|t| matches!(t, Type::Closure { .. }))
     }
 
     /// Iterate over `T` parameters of the `FutureCallback<T>` callbacks in this interface
@@ -719,6 +910,35 @@ impl ComponentInterface {
                 has_rust_call_status_arg: false,
             }
             .into(),
+            FfiCallbackFunction {
+                name: "ForeignClosureCall".to_owned(),
+                arguments: vec![
+                    FfiArgument::new("handle", FfiType::UInt64),
+                    FfiArgument::new("args", FfiType::RustBuffer(None)),
+                    FfiArgument::new(
+                        "uniffi_out_return",
+                        FfiType::RustBuffer(None).mut_reference(),
+                    ),
+                ],
+                return_type: None,
+                has_rust_call_status_arg: true,
+            }
+            .into(),
+            FfiStruct {
+                name: "VTableForeignClosure".to_owned(),
+                fields: vec![
+                    FfiField::new(
+                        "uniffi_free",
+                        FfiType::Callback("CallbackInterfaceFree".to_owned()),
+                    ),
+                    FfiField::new(
+                        "uniffi_clone",
+                        FfiType::Callback("CallbackInterfaceClone".to_owned()),
+                    ),
+                    FfiField::new("call", FfiType::Callback("ForeignClosureCall".to_owned())),
+                ],
+            }
+            .into(),
             FfiStruct {
                 name: "ForeignFutureDroppedCallbackStruct".to_owned(),
                 fields: vec![
@@ -765,6 +985,8 @@ impl ComponentInterface {
             .iter_user_ffi_function_definitions()
             .cloned()
             .chain(self.iter_rust_buffer_ffi_function_definitions())
+            .chain(self.iter_rust_iter_ffi_function_definitions())
+            .chain(self.iter_closure_ffi_function_definitions())
             .chain(self.iter_futures_ffi_function_definitions());
 
         // Conditionally determine if the checksums should be included or not.
@@ -789,6 +1011,8 @@ impl ComponentInterface {
         self.iter_user_ffi_function_definitions()
             .cloned()
             .chain(self.iter_rust_buffer_ffi_function_definitions())
+            .chain(self.iter_rust_iter_ffi_function_definitions())
+            .chain(self.iter_closure_ffi_function_definitions())
             .chain(self.iter_ffi_function_integrity_checks())
     }
 
@@ -856,6 +1080,22 @@ impl ComponentInterface {
         .into_iter()
     }
 
+    /// List all FFI functions definitions for iterators.
+    pub fn iter_rust_iter_ffi_function_definitions(&self) -> impl Iterator<Item = FfiFunction> {
+        [self.ffi_rust_iter_next_chunk(), self.ffi_rust_iter_free()].into_iter()
+    }
+
+    /// List all FFI functions definitions for closures.
+    pub fn iter_closure_ffi_function_definitions(&self) -> impl Iterator<Item = FfiFunction> {
+        [
+            self.ffi_rust_closure_call(),
+            self.ffi_rust_closure_clone(),
+            self.ffi_rust_closure_free(),
+            self.ffi_foreign_closure_init_vtable(),
+        ]
+        .into_iter()
+    }
+
     /// List all FFI functions definitions for async functionality.
     pub fn iter_futures_ffi_function_definitions(&self) -> impl Iterator<Item = FfiFunction> + '_ {
         self.all_possible_return_ffi_types()
@@ -867,6 +1107,7 @@ impl ComponentInterface {
                     self.ffi_rust_future_complete(return_type),
                 ]
             })
+            .chain([self.ffi_rust_stream_next(), self.ffi_rust_stream_free()])
     }
 
     /// List all API checksums to check
@@ -887,6 +1128,9 @@ impl ComponentInterface {
                 .into_iter()
                 .map(|c| (c.checksum_fn_name(), c.checksum()))
         });
+        let static_method_checksums = self
+            .iter_static_methods()
+            .map(|m| (m.checksum_fn_name(), m.checksum()));
         let callback_method_checksums = self.callback_interfaces.iter().flat_map(|cbi| {
             cbi.methods().into_iter().filter_map(|m| {
                 if m.checksum_fn_name().is_empty() {
@@ -900,6 +1144,7 @@ impl ComponentInterface {
         func_checksums
             .chain(method_checksums)
             .chain(constructor_checksums)
+            .chain(static_method_checksums)
             .chain(callback_method_checksums)
             .map(|(fn_name, checksum)| (fn_name.to_string(), checksum))
     }
@@ -989,6 +1234,17 @@ impl ComponentInterface {
         Ok(())
     }
 
+    pub(super) fn add_constant_definition(&mut self, defn: Constant) -> Result<()> {
+        if self.constants.iter().any(|c| c.name == defn.name) {
+            bail!("duplicate constant definition: \"{}\"", defn.name);
+        }
+        self.types
+            .add_known_types(defn.iter_types())
+            .with_context(|| format!("adding constant {defn:?}"))?;
+        self.constants.push(defn);
+        Ok(())
+    }
+
     pub(super) fn add_constructor_meta(&mut self, meta: ConstructorMetadata) -> Result<()> {
         let self_name = &meta.self_name;
 
@@ -1059,6 +1315,28 @@ impl ComponentInterface {
         Ok(())
     }
 
+    pub(super) fn add_static_method_meta(&mut self, meta: StaticMethodMetadata) -> Result<()> {
+        let self_name = meta.self_name.clone();
+        let static_method = StaticMethod::from(meta);
+        self.types
+            .add_known_types(static_method.iter_types())
+            .with_context(|| format!("adding static method {static_method:?}"))?;
+        static_method
+            .throws_name()
+            .map(|n| self.errors.insert(n.to_string()));
+
+        if let Some(object) = get_object(&mut self.objects, &self_name) {
+            object.static_methods.push(static_method);
+        } else if let Some(record) = self.records.iter_mut().find(|r| r.name == self_name) {
+            record.static_methods.push(static_method);
+        } else if let Some(enum_) = self.enums.iter_mut().find(|e| e.name == self_name) {
+            enum_.static_methods.push(static_method);
+        } else {
+            bail!("add_static_method_meta: type {} not found", self_name);
+        }
+        Ok(())
+    }
+
     pub(super) fn add_uniffitrait_meta(&mut self, meta: UniffiTraitMetadata) -> Result<()> {
         let self_name = meta.self_name().to_string();
         let self_type = self
@@ -1069,6 +1347,7 @@ impl ComponentInterface {
         self.types
             .add_known_types(ut.iter_types())
             .with_context(|| format!("adding builtin trait {ut:?}"))?;
+        ut.throws_name().map(|n| self.errors.insert(n.to_string()));
         if let Some(object) = get_object(&mut self.objects, &self_name) {
             object.uniffi_traits.push(ut);
         } else if let Some(enum_) = self.enums.iter_mut().find(|o| o.name == self_name) {
@@ -1427,15 +1706,18 @@ fn type_names_in_type(ty: &Type) -> Vec<String> {
         Type::Box { inner_type }
         | Type::Optional { inner_type }
         | Type::Sequence { inner_type }
-        | Type::Set { inner_type } => type_names_in_type(inner_type),
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type, .. } => type_names_in_type(inner_type),
         Type::Map {
             key_type,
             value_type,
+            ..
         } => {
             let mut names = type_names_in_type(key_type);
             names.extend(type_names_in_type(value_type));
             names
         }
+        Type::Tuple { inner_types } => inner_types.iter().flat_map(type_names_in_type).collect(),
         _ => vec![],
     }
 }
@@ -1501,6 +1783,7 @@ existing definition: Enum {
     non_exhaustive: false,
     constructors: [],
     methods: [],
+    static_methods: [],
     uniffi_traits: [],
     docstring: None,
 },
@@ -1529,6 +1812,7 @@ new definition: Enum {
     non_exhaustive: false,
     constructors: [],
     methods: [],
+    static_methods: [],
     uniffi_traits: [],
     docstring: None,
 }",
@@ -1597,7 +1881,8 @@ new definition: Enum {
             .types
             .add_known_type(&Type::Map {
                 key_type: Box::new(Type::String),
-                value_type: Box::new(Type::Boolean)
+                value_type: Box::new(Type::Boolean),
+                ordered: false,
             })
             .is_ok());
         assert!(ci.contains_map_types());
@@ -1675,6 +1960,7 @@ new definition: Enum {
             remote: false,
             constructors: Default::default(),
             methods: Default::default(),
+            static_methods: Default::default(),
             uniffi_traits: Default::default(),
             ffi_func_clone: Default::default(),
             trait_impls: Default::default(),
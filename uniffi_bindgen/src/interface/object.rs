@@ -88,6 +88,7 @@ pub struct Object {
     pub(super) remote: bool,
     pub(super) constructors: Vec<Constructor>,
     pub(super) methods: Vec<Method>,
+    pub(super) static_methods: Vec<StaticMethod>,
     // The "trait" methods - they have a (presumably "well known") name, and
     // a regular method (albeit with a generated name)
     // XXX - this should really be a HashSet, but not enough transient types support hash to make it worthwhile now.
@@ -172,6 +173,10 @@ impl Object {
         self.methods.iter().collect()
     }
 
+    pub fn static_methods(&self) -> Vec<&StaticMethod> {
+        self.static_methods.iter().collect()
+    }
+
     pub fn get_method(&self, name: &str) -> Method {
         let matches: Vec<_> = self.methods.iter().filter(|m| m.name() == name).collect();
         match matches.len() {
@@ -222,17 +227,12 @@ impl Object {
             .chain(self.constructors.iter().map(|f| &f.ffi_func))
             .chain(self.methods.iter().map(|f| &f.ffi_func))
             .chain(
-                self.uniffi_traits
+                self.methods
                     .iter()
-                    .flat_map(|ut| match ut {
-                        UniffiTrait::Display { fmt: m }
-                        | UniffiTrait::Debug { fmt: m }
-                        | UniffiTrait::Hash { hash: m }
-                        | UniffiTrait::Ord { cmp: m } => vec![m],
-                        UniffiTrait::Eq { eq, ne } => vec![eq, ne],
-                    })
-                    .map(|m| &m.ffi_func),
+                    .filter_map(|f| f.default_ffi_func.as_ref()),
             )
+            .chain(self.static_methods.iter().map(|f| &f.ffi_func))
+            .chain(self.uniffi_traits.iter().flat_map(UniffiTrait::ffi_funcs))
     }
 
     pub fn derive_ffi_funcs(&mut self) -> Result<()> {
@@ -263,6 +263,9 @@ impl Object {
         for meth in self.methods.iter_mut() {
             meth.derive_ffi_func()?;
         }
+        for meth in self.static_methods.iter_mut() {
+            meth.derive_ffi_func();
+        }
         for ut in self.uniffi_traits.iter_mut() {
             ut.derive_ffi_func()?;
         }
@@ -310,6 +313,7 @@ impl Object {
             self.methods
                 .iter()
                 .map(Method::iter_types)
+                .chain(self.static_methods.iter().map(StaticMethod::iter_types))
                 .chain(self.uniffi_traits.iter().map(UniffiTrait::iter_types))
                 .chain(self.constructors.iter().map(Constructor::iter_types))
                 .flatten(),
@@ -338,6 +342,7 @@ impl From<uniffi_meta::ObjectMetadata> for Object {
             remote: meta.remote,
             constructors: Default::default(),
             methods: Default::default(),
+            static_methods: Default::default(),
             uniffi_traits: Default::default(),
             trait_impls: Default::default(),
             ffi_func_clone: FfiFunction {
@@ -510,6 +515,10 @@ pub struct Method {
     pub(super) docstring: Option<String>,
     pub(super) throws: Option<Type>,
     pub(super) takes_self_by_arc: bool,
+    // FFI function that calls the Rust default implementation of a trait method.  Like `ffi_func`,
+    // this is entirely determined by the other fields.
+    #[checksum_ignore]
+    pub(super) default_ffi_func: Option<FfiFunction>,
     pub(super) checksum_fn_name: String,
     // Force a checksum value, or we'll fallback to the trait.
     #[checksum_ignore]
@@ -588,12 +597,29 @@ impl Method {
         self.takes_self_by_arc
     }
 
+    /// Does the trait provide a default implementation for this method?
+    pub fn has_default(&self) -> bool {
+        self.default_ffi_func.is_some()
+    }
+
+    /// FFI function to call the Rust default implementation of this trait method, if it has one
+    pub fn default_ffi_func(&self) -> Option<&FfiFunction> {
+        self.default_ffi_func.as_ref()
+    }
+
     pub fn derive_ffi_func(&mut self) -> Result<()> {
         assert!(!self.ffi_func.name().is_empty());
         self.ffi_func.init(
             self.return_type.as_ref().map(Into::into),
             self.full_arguments().iter().map(Into::into),
         );
+        if let Some(mut default_ffi_func) = self.default_ffi_func.take() {
+            default_ffi_func.init(
+                self.return_type.as_ref().map(Into::into),
+                self.full_arguments().iter().map(Into::into),
+            );
+            self.default_ffi_func = Some(default_ffi_func);
+        }
         Ok(())
     }
 
@@ -606,6 +632,10 @@ impl Method {
     pub fn from_metadata(meta: uniffi_meta::MethodMetadata, receiver: Type) -> Self {
         let ffi_name = meta.ffi_symbol_name();
         let checksum_fn_name = meta.checksum_symbol_name();
+        let default_ffi_func = meta.default_ffi_symbol_name().map(|name| FfiFunction {
+            name,
+            ..FfiFunction::default()
+        });
         let arguments = meta.inputs.into_iter().map(Into::into).collect();
 
         let ffi_func = FfiFunction {
@@ -624,6 +654,7 @@ impl Method {
             docstring: meta.docstring.clone(),
             throws: meta.throws,
             takes_self_by_arc: meta.takes_self_by_arc,
+            default_ffi_func,
             checksum_fn_name,
             checksum: meta.checksum,
         }
@@ -635,6 +666,138 @@ impl Method {
     }
 }
 
+// Represents a static method, an associated function without a receiver.
+//
+// These can be defined on objects, records and enums.  The FFI will represent this as a standalone
+// function, the bindings group it with the type it's defined on.
+#[derive(Debug, Clone, Checksum)]
+pub struct StaticMethod {
+    pub(super) name: String,
+    // Ignore `self_name` for the checksum, it's already part of the checksum symbol name.
+    #[checksum_ignore]
+    pub(super) self_name: String,
+    pub(super) is_async: bool,
+    pub(super) arguments: Vec<Argument>,
+    pub(super) return_type: Option<Type>,
+    // We don't include the FFIFunc in the hash calculation, because:
+    //  - it is entirely determined by the other fields,
+    //    so excluding it is safe.
+    //  - its `name` property includes a checksum derived from  the very
+    //    hash value we're trying to calculate here, so excluding it
+    //    avoids a weird circular dependency in the calculation.
+    #[checksum_ignore]
+    pub(super) ffi_func: FfiFunction,
+    #[checksum_ignore]
+    pub(super) docstring: Option<String>,
+    pub(super) throws: Option<Type>,
+    pub(super) checksum_fn_name: String,
+    // Force a checksum value, or we'll fallback to the trait.
+    #[checksum_ignore]
+    pub(super) checksum: Option<u16>,
+}
+
+impl StaticMethod {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn rename(&mut self, new_name: String) {
+        self.name = new_name;
+    }
+
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    /// Name of the object, record or enum this static method is defined on
+    pub fn self_name(&self) -> &str {
+        &self.self_name
+    }
+
+    pub fn arguments(&self) -> Vec<&Argument> {
+        self.arguments.iter().collect()
+    }
+
+    pub fn full_arguments(&self) -> Vec<Argument> {
+        self.arguments.to_vec()
+    }
+
+    pub fn return_type(&self) -> Option<&Type> {
+        self.return_type.as_ref()
+    }
+
+    pub fn ffi_func(&self) -> &FfiFunction {
+        &self.ffi_func
+    }
+
+    pub fn checksum_fn_name(&self) -> &str {
+        &self.checksum_fn_name
+    }
+
+    pub fn checksum(&self) -> u16 {
+        self.checksum.unwrap_or_else(|| uniffi_meta::checksum(self))
+    }
+
+    pub fn throws(&self) -> bool {
+        self.throws.is_some()
+    }
+
+    pub fn throws_name(&self) -> Option<&str> {
+        super::throws_name(&self.throws)
+    }
+
+    pub fn throws_type(&self) -> Option<&Type> {
+        self.throws.as_ref()
+    }
+
+    pub fn docstring(&self) -> Option<&str> {
+        self.docstring.as_deref()
+    }
+
+    pub fn iter_types(&self) -> TypeIterator<'_> {
+        Callable::iter_types(self)
+    }
+
+    pub fn derive_ffi_func(&mut self) {
+        assert!(!self.ffi_func.name().is_empty());
+        self.ffi_func.init(
+            self.return_type.as_ref().map(Into::into),
+            self.arguments.iter().map(Into::into),
+        );
+    }
+
+    pub fn checksum_from_metadata(meta: uniffi_meta::StaticMethodMetadata) -> u16 {
+        uniffi_meta::checksum(&Self::from(meta))
+    }
+}
+
+impl From<uniffi_meta::StaticMethodMetadata> for StaticMethod {
+    fn from(meta: uniffi_meta::StaticMethodMetadata) -> Self {
+        let ffi_name = meta.ffi_symbol_name();
+        let checksum_fn_name = meta.checksum_symbol_name();
+        let arguments = meta.inputs.into_iter().map(Into::into).collect();
+
+        let ffi_func = FfiFunction {
+            name: ffi_name,
+            is_async: meta.is_async,
+            ..FfiFunction::default()
+        };
+
+        Self {
+            name: meta.name,
+            self_name: meta.self_name,
+            is_async: meta.is_async,
+            arguments,
+            return_type: meta.return_type,
+            ffi_func,
+            docstring: meta.docstring.clone(),
+            throws: meta.throws,
+            checksum_fn_name,
+            checksum: meta.checksum,
+        }
+    }
+}
+
 /// The list of traits we support generating helper methods for.
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Checksum)]
@@ -644,6 +807,10 @@ pub enum UniffiTrait {
     Eq { eq: Method, ne: Method },
     Hash { hash: Method },
     Ord { cmp: Method },
+    Clone { clone: Method },
+    Default { default: StaticMethod },
+    PartialOrd { partial_cmp: Method },
+    FromStr { from_str: StaticMethod },
 }
 
 impl UniffiTrait {
@@ -653,26 +820,60 @@ impl UniffiTrait {
                 UniffiTrait::Display { fmt: m }
                 | UniffiTrait::Debug { fmt: m }
                 | UniffiTrait::Hash { hash: m }
-                | UniffiTrait::Ord { cmp: m } => vec![m.iter_types()],
+                | UniffiTrait::Ord { cmp: m }
+                | UniffiTrait::Clone { clone: m }
+                | UniffiTrait::PartialOrd { partial_cmp: m } => vec![m.iter_types()],
                 UniffiTrait::Eq { eq, ne } => vec![eq.iter_types(), ne.iter_types()],
+                UniffiTrait::Default { default: m } | UniffiTrait::FromStr { from_str: m } => {
+                    vec![m.iter_types()]
+                }
             }
             .into_iter()
             .flatten(),
         )
     }
 
+    /// The FFI functions for the trait's methods
+    pub fn ffi_funcs(&self) -> Vec<&FfiFunction> {
+        match self {
+            UniffiTrait::Display { fmt: m }
+            | UniffiTrait::Debug { fmt: m }
+            | UniffiTrait::Hash { hash: m }
+            | UniffiTrait::Ord { cmp: m }
+            | UniffiTrait::Clone { clone: m }
+            | UniffiTrait::PartialOrd { partial_cmp: m } => vec![&m.ffi_func],
+            UniffiTrait::Eq { eq, ne } => vec![&eq.ffi_func, &ne.ffi_func],
+            UniffiTrait::Default { default: m } | UniffiTrait::FromStr { from_str: m } => {
+                vec![&m.ffi_func]
+            }
+        }
+    }
+
+    /// The error type thrown by the trait's methods, if any
+    pub fn throws_name(&self) -> Option<&str> {
+        match self {
+            UniffiTrait::FromStr { from_str } => from_str.throws_name(),
+            _ => None,
+        }
+    }
+
     pub fn derive_ffi_func(&mut self) -> Result<()> {
         match self {
             UniffiTrait::Display { fmt: m }
             | UniffiTrait::Debug { fmt: m }
             | UniffiTrait::Hash { hash: m }
-            | UniffiTrait::Ord { cmp: m } => {
+            | UniffiTrait::Ord { cmp: m }
+            | UniffiTrait::Clone { clone: m }
+            | UniffiTrait::PartialOrd { partial_cmp: m } => {
                 m.derive_ffi_func()?;
             }
             UniffiTrait::Eq { eq, ne } => {
                 eq.derive_ffi_func()?;
                 ne.derive_ffi_func()?;
             }
+            UniffiTrait::Default { default: m } | UniffiTrait::FromStr { from_str: m } => {
+                m.derive_ffi_func();
+            }
         }
         Ok(())
     }
@@ -696,6 +897,20 @@ impl UniffiTrait {
             uniffi_meta::UniffiTraitMetadata::Ord { cmp } => UniffiTrait::Ord {
                 cmp: Method::from_metadata(cmp, receiver),
             },
+            uniffi_meta::UniffiTraitMetadata::Clone { clone } => UniffiTrait::Clone {
+                clone: Method::from_metadata(clone, receiver),
+            },
+            uniffi_meta::UniffiTraitMetadata::Default { default } => UniffiTrait::Default {
+                default: default.into(),
+            },
+            uniffi_meta::UniffiTraitMetadata::PartialOrd { partial_cmp } => {
+                UniffiTrait::PartialOrd {
+                    partial_cmp: Method::from_metadata(partial_cmp, receiver),
+                }
+            }
+            uniffi_meta::UniffiTraitMetadata::FromStr { from_str } => UniffiTrait::FromStr {
+                from_str: from_str.into(),
+            },
         }
     }
 }
@@ -709,6 +924,10 @@ pub struct UniffiTraitMethods {
     pub eq_ne: Option<Method>,
     pub hash_hash: Option<Method>,
     pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    pub default_default: Option<StaticMethod>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    pub from_str_from_str: Option<StaticMethod>,
 }
 
 impl UniffiTraitMethods {
@@ -724,10 +943,21 @@ impl UniffiTraitMethods {
                 }
                 UniffiTrait::Hash { hash } => new.hash_hash = Some(hash),
                 UniffiTrait::Ord { cmp } => new.ord_cmp = Some(cmp),
+                UniffiTrait::Clone { clone } => new.clone_clone = Some(clone),
+                UniffiTrait::Default { default } => new.default_default = Some(default),
+                UniffiTrait::PartialOrd { partial_cmp } => {
+                    new.partial_ord_partial_cmp = Some(partial_cmp)
+                }
+                UniffiTrait::FromStr { from_str } => new.from_str_from_str = Some(from_str),
             }
         }
         new
     }
+
+    /// Do any of the traits generate static methods on the type?
+    pub fn has_static_methods(&self) -> bool {
+        self.default_default.is_some() || self.from_str_from_str.is_some()
+    }
 }
 
 impl Callable for Constructor {
@@ -786,6 +1016,32 @@ impl Callable for Method {
     }
 }
 
+impl Callable for StaticMethod {
+    fn arguments(&self) -> Vec<&Argument> {
+        self.arguments()
+    }
+
+    fn return_type(&self) -> Option<&Type> {
+        self.return_type()
+    }
+
+    fn throws_type(&self) -> Option<&Type> {
+        self.throws_type()
+    }
+
+    fn docstring(&self) -> Option<&str> {
+        self.docstring()
+    }
+
+    fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    fn ffi_func(&self) -> &FfiFunction {
+        &self.ffi_func
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::super::ComponentInterface;
@@ -49,8 +49,8 @@ use uniffi_meta::Checksum;
 
 use super::function::Callable;
 use super::{
-    AsType, Constructor, DefaultValue, FfiFunction, Method, Type, TypeIterator, UniffiTrait,
-    UniffiTraitMethods,
+    AsType, Constructor, DefaultValue, FfiFunction, Method, StaticMethod, Type, TypeIterator,
+    UniffiTrait, UniffiTraitMethods,
 };
 
 /// Represents a "data class" style object, for passing around complex values.
@@ -66,6 +66,7 @@ pub struct Record {
     pub(super) fields: Vec<Field>,
     pub(super) constructors: Vec<Constructor>,
     pub(super) methods: Vec<Method>,
+    pub(super) static_methods: Vec<StaticMethod>,
     pub uniffi_traits: Vec<UniffiTrait>,
     #[checksum_ignore]
     pub(super) docstring: Option<String>,
@@ -96,6 +97,10 @@ impl Record {
         &self.methods
     }
 
+    pub fn static_methods(&self) -> &[StaticMethod] {
+        &self.static_methods
+    }
+
     pub fn docstring(&self) -> Option<&str> {
         self.docstring.as_deref()
     }
@@ -106,7 +111,12 @@ impl Record {
                 .iter()
                 .flat_map(Field::iter_types)
                 .chain(self.constructors.iter().flat_map(Constructor::iter_types))
-                .chain(self.methods.iter().flat_map(Method::iter_types)),
+                .chain(self.methods.iter().flat_map(Method::iter_types))
+                .chain(
+                    self.static_methods
+                        .iter()
+                        .flat_map(StaticMethod::iter_types),
+                ),
         )
     }
 
@@ -129,6 +139,9 @@ impl Record {
         for m in self.methods.iter_mut() {
             m.derive_ffi_func()?;
         }
+        for m in self.static_methods.iter_mut() {
+            m.derive_ffi_func();
+        }
         for ut in self.uniffi_traits.iter_mut() {
             ut.derive_ffi_func()?;
         }
@@ -140,18 +153,8 @@ impl Record {
             .iter()
             .map(|f| &f.ffi_func)
             .chain(self.methods.iter().map(|f| &f.ffi_func))
-            .chain(
-                self.uniffi_traits
-                    .iter()
-                    .flat_map(|ut| match ut {
-                        UniffiTrait::Display { fmt: m }
-                        | UniffiTrait::Debug { fmt: m }
-                        | UniffiTrait::Hash { hash: m }
-                        | UniffiTrait::Ord { cmp: m } => vec![m],
-                        UniffiTrait::Eq { eq, ne } => vec![eq, ne],
-                    })
-                    .map(|m| &m.ffi_func),
-            )
+            .chain(self.static_methods.iter().map(|f| &f.ffi_func))
+            .chain(self.uniffi_traits.iter().flat_map(UniffiTrait::ffi_funcs))
     }
 }
 
@@ -179,6 +182,7 @@ impl TryFrom<uniffi_meta::RecordMetadata> for Record {
                 .collect::<Result<_>>()?,
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             uniffi_traits: vec![],
             docstring: meta.docstring.clone(),
         })
@@ -129,6 +129,19 @@ impl VisitMut for TomlRenamer<'_> {
         }
     }
 
+    fn visit_static_method(&self, self_name: &str, method: &mut StaticMethod) {
+        let method_name = format!("{}.{}", self_name, method.name());
+        if let Some(new_name) = self.new_name(&self.this_module_path, &method_name) {
+            method.name = new_name;
+        }
+        for arg in &mut method.arguments {
+            let arg_path = format!("{}.{}", method_name, arg.name);
+            if let Some(new_name) = self.new_name(&self.this_module_path, &arg_path) {
+                arg.name = new_name;
+            }
+        }
+    }
+
     fn visit_function(&self, function: &mut Function) {
         let original_function_name = function.name.clone();
         if let Some(new_name) = self.new_name(&self.this_module_path, &function.name) {
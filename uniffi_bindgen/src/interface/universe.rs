@@ -136,9 +136,12 @@ fn normalize_type_module_path(ty: &Type) -> Type {
         | Type::Int32
         | Type::UInt64
         | Type::Int64
+        | Type::UInt128
+        | Type::Int128
         | Type::Float32
         | Type::Float64
         | Type::Boolean
+        | Type::Char
         | Type::String
         | Type::Bytes
         | Type::Timestamp
@@ -170,15 +173,46 @@ fn normalize_type_module_path(ty: &Type) -> Type {
         Type::Sequence { inner_type } => Type::Sequence {
             inner_type: Box::new(normalize_type_module_path(inner_type)),
         },
+        Type::Array { inner_type, len } => Type::Array {
+            inner_type: Box::new(normalize_type_module_path(inner_type)),
+            len: *len,
+        },
         Type::Map {
             key_type,
             value_type,
+            ordered,
         } => Type::Map {
             key_type: Box::new(normalize_type_module_path(key_type)),
             value_type: Box::new(normalize_type_module_path(value_type)),
+            ordered: *ordered,
         },
-        Type::Set { inner_type } => Type::Set {
+        Type::Set {
+            inner_type,
+            ordered,
+        } => Type::Set {
             inner_type: Box::new(normalize_type_module_path(inner_type)),
+            ordered: *ordered,
+        },
+        Type::Tuple { inner_types } => Type::Tuple {
+            inner_types: inner_types.iter().map(normalize_type_module_path).collect(),
+        },
+        Type::Stream { item_type } => Type::Stream {
+            item_type: Box::new(normalize_type_module_path(item_type)),
+        },
+        Type::Iterator { item_type } => Type::Iterator {
+            item_type: Box::new(normalize_type_module_path(item_type)),
+        },
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => Type::Closure {
+            argument_types: argument_types
+                .iter()
+                .map(normalize_type_module_path)
+                .collect(),
+            return_type: return_type
+                .as_ref()
+                .map(|t| Box::new(normalize_type_module_path(t))),
         },
         Type::Custom {
             name,
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use crate::interface::FfiDefinition;
+use crate::interface::{FfiDefinition, StaticMethod};
 use crate::{ComponentInterface, VisitMut};
 use std::collections::BTreeSet;
 use uniffi_meta::Type;
@@ -33,6 +33,7 @@ impl ComponentInterface {
         let errors_clone = self.errors.clone();
         for enum_item in self.enums.iter_mut() {
             let is_error = errors_clone.contains(enum_item.name());
+            let enum_name = enum_item.name().to_string();
 
             visitor.visit_enum(is_error, enum_item);
 
@@ -44,15 +45,25 @@ impl ComponentInterface {
                     visitor.visit_type(&mut field.type_);
                 }
             }
+
+            for method in enum_item.static_methods.iter_mut() {
+                visit_static_method(visitor, &enum_name, method);
+            }
         }
 
         for record_item in self.records.iter_mut() {
+            let record_name = record_item.name().to_string();
+
             visitor.visit_record(record_item);
 
             for field in &mut record_item.fields {
                 visitor.visit_field(field);
                 visitor.visit_type(&mut field.type_);
             }
+
+            for method in record_item.static_methods.iter_mut() {
+                visit_static_method(visitor, &record_name, method);
+            }
         }
 
         for function in self.functions.iter_mut() {
@@ -111,6 +122,10 @@ impl ComponentInterface {
                     visitor.visit_type(&mut argument.type_);
                 }
             }
+
+            for method in object.static_methods.iter_mut() {
+                visit_static_method(visitor, &object_name, method);
+            }
         }
 
         for callback_interface in self.callback_interfaces.iter_mut() {
@@ -157,3 +172,20 @@ impl ComponentInterface {
             .collect()
     }
 }
+
+fn visit_static_method<V: VisitMut>(visitor: &V, self_name: &str, method: &mut StaticMethod) {
+    visitor.visit_static_method(self_name, method);
+
+    for argument in method.arguments.iter_mut() {
+        visitor.visit_argument(argument);
+        visitor.visit_type(&mut argument.type_);
+    }
+
+    if let Some(return_type) = &mut method.return_type {
+        visitor.visit_type(return_type);
+    }
+
+    if let Some(error_type) = &mut method.throws {
+        visitor.visit_type(error_type);
+    }
+}
@@ -117,7 +117,7 @@ pub mod cargo_metadata;
 use crate::interface::CallbackInterface;
 use crate::interface::{
     Argument, Constructor, Enum, FfiArgument, FfiField, Field, Function, Method, Object, Record,
-    Variant,
+    StaticMethod, Variant,
 };
 pub use bindgen_paths::{BindgenPaths, BindgenPathsLayer};
 pub use global_config::{CrateRootsLayer, GlobalConfig};
@@ -234,6 +234,10 @@ pub trait VisitMut {
     /// adjust it to language specific naming conventions.
     fn visit_method(&self, object_name: &str, method: &mut Method);
 
+    /// Go through each `StaticMethod` of an `Object`, `Record` or `Enum` and
+    /// adjust it to language specific naming conventions.
+    fn visit_static_method(&self, _self_name: &str, _method: &mut StaticMethod) {}
+
     /// Go through each `Argument` of a `Function` and
     /// adjust it to language specific naming conventions.
     fn visit_argument(&self, _argument: &mut Argument) {}
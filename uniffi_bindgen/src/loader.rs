@@ -110,8 +110,11 @@ impl BindgenLoader {
                     cons.checksum =
                         Some(interface::Constructor::checksum_from_metadata(cons.clone()))
                 }
-                // Note: UDL-based callbacks don't have checksum functions, don't set the
-                // checksum for those.
+                Metadata::TraitMethod(meth) if meth.checksum.is_none() => {
+                    meth.checksum = Some(interface::Method::checksum_from_metadata(
+                        meth.clone().into(),
+                    ))
+                }
                 _ => (),
             }
             metadata_group.items.insert(meta);
@@ -4,7 +4,9 @@
 
 //! Extract common data from Function/Method/Constructor into Callable
 
-use super::ffi_async_data::{constructor_async_data, function_async_data, method_async_data};
+use super::ffi_async_data::{
+    constructor_async_data, function_async_data, method_async_data, static_method_async_data,
+};
 use super::*;
 
 pub fn function_callable(func: &initial::Function, context: &Context) -> Result<Callable> {
@@ -69,6 +71,50 @@ pub fn method_callable_with_kind(
     })
 }
 
+pub fn default_method_ffi_func(
+    meth: &initial::Method,
+    context: &Context,
+) -> Result<Option<RustFfiFunctionName>> {
+    if !meth.has_default {
+        return Ok(None);
+    }
+    Ok(Some(RustFfiFunctionName(
+        uniffi_meta::trait_default_method_symbol_name(
+            &context.crate_name()?,
+            &context.current_type_name()?,
+            &meth.name,
+        ),
+    )))
+}
+
+pub fn static_method_callable(meth: &initial::StaticMethod, context: &Context) -> Result<Callable> {
+    let self_type = context.self_type()?;
+    let ffi_func = RustFfiFunctionName(uniffi_meta::static_method_symbol_name(
+        &context.crate_name()?,
+        &context.current_type_name()?,
+        &meth.name,
+    ));
+    let arguments = map_method_args(&meth.inputs, &meth.name, context)?;
+    let name = rename::method(meth.name.clone(), context)?;
+
+    Ok(Callable {
+        id: meth.id,
+        name,
+        orig_name: meth.orig_name.clone(),
+        arguments,
+        return_type: ReturnType {
+            ty: meth.return_type.clone().map_node(context)?,
+        },
+        throws_type: ThrowsType {
+            ty: meth.throws.clone().map_node(context)?,
+        },
+        checksum: meth.checksum,
+        async_data: static_method_async_data(meth, context)?,
+        ffi_func,
+        kind: CallableKind::StaticMethod { self_type },
+    })
+}
+
 pub fn constructor_callable(cons: &initial::Constructor, context: &Context) -> Result<Callable> {
     let self_type = context.self_type()?;
     let ffi_func = RustFfiFunctionName(uniffi_meta::constructor_symbol_name(
@@ -40,6 +40,7 @@ pub fn vtable(methods: &[initial::Method], context: &Context) -> Result<VTable>
                         },
                         context,
                     )?,
+                    default_ffi_func: None,
                     docstring: meth.docstring.clone(),
                 };
                 Ok(VTableMethod {
@@ -300,7 +301,7 @@ fn vtable_method_ffi_defs(
                     ty: FfiType::Handle(HandleKind::ForeignFutureCallbackData),
                 },
                 FfiArgument {
-                    name: "uniffi_out_return".into(),
+                    name: "uniffi_out_dropped_callback".into(),
                     ty: FfiType::MutReference(Box::new(FfiType::Struct(FfiStructName(
                         "ForeignFutureDroppedCallbackStruct".to_owned(),
                     )))),
@@ -55,7 +55,29 @@ pub fn checksums(namespace: &initial::Namespace) -> Result<Vec<Checksum>> {
         Ok(())
     })?;
 
-    // Skip callback interfaces, since those don't get their checksums set currently.
+    namespace.try_visit(|type_def: &initial::TypeDefinition| {
+        let (type_name, static_methods) = match type_def {
+            initial::TypeDefinition::Interface(int) => (&int.name, &int.static_methods),
+            initial::TypeDefinition::Record(rec) => (&rec.name, &rec.static_methods),
+            initial::TypeDefinition::Enum(en) => (&en.name, &en.static_methods),
+            _ => return Ok(()),
+        };
+        for meth in static_methods {
+            checksums.push(Checksum {
+                checksum: meth
+                    .checksum
+                    .ok_or_else(|| anyhow!("Checksum not set for {}", meth.name))?,
+                fn_name: RustFfiFunctionName(uniffi_meta::static_method_checksum_symbol_name(
+                    &namespace.crate_name,
+                    type_name,
+                    &meth.name,
+                )),
+            });
+        }
+        Ok(())
+    })?;
+
+    // Skip callback interfaces, the general pipeline doesn't check those checksums.
 
     Ok(checksums)
 }
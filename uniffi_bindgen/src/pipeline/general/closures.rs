@@ -0,0 +1,114 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Closure-related FFI definitions
+
+use super::*;
+
+pub fn ffi_definitions(namespace: &initial::Namespace) -> Result<Vec<FfiDefinition>> {
+    if !namespace.has_descendant(|ty: &Type| matches!(ty, Type::Closure { .. })) {
+        return Ok(vec![]);
+    }
+
+    let crate_name = &namespace.crate_name;
+    let handle_type = FfiType::Handle(HandleKind::Closure);
+    Ok(vec![
+        FfiFunctionType {
+            name: FfiFunctionTypeName("ForeignClosureFree".to_owned()),
+            arguments: vec![FfiArgument::new("handle", handle_type.clone())],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: false,
+        }
+        .into(),
+        FfiFunctionType {
+            name: FfiFunctionTypeName("ForeignClosureClone".to_owned()),
+            arguments: vec![FfiArgument::new("handle", handle_type.clone())],
+            return_type: FfiReturnType {
+                ty: Some(handle_type.clone()),
+            },
+            has_rust_call_status_arg: false,
+        }
+        .into(),
+        FfiFunctionType {
+            name: FfiFunctionTypeName("ForeignClosureCall".to_owned()),
+            arguments: vec![
+                FfiArgument::new("handle", handle_type.clone()),
+                FfiArgument::new("args", FfiType::RustBuffer(None)),
+                FfiArgument::new(
+                    "uniffi_out_return",
+                    FfiType::MutReference(Box::new(FfiType::RustBuffer(None))),
+                ),
+            ],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: true,
+        }
+        .into(),
+        FfiStruct {
+            name: FfiStructName("VTableForeignClosure".to_owned()),
+            fields: vec![
+                FfiField::new(
+                    "uniffi_free",
+                    FfiType::Function(FfiFunctionTypeName("ForeignClosureFree".to_owned())),
+                ),
+                FfiField::new(
+                    "uniffi_clone",
+                    FfiType::Function(FfiFunctionTypeName("ForeignClosureClone".to_owned())),
+                ),
+                FfiField::new(
+                    "call",
+                    FfiType::Function(FfiFunctionTypeName("ForeignClosureCall".to_owned())),
+                ),
+            ],
+        }
+        .into(),
+        FfiFunction {
+            name: RustFfiFunctionName(format!("ffi_{crate_name}_foreign_closure_init_vtable")),
+            async_data: None,
+            arguments: vec![FfiArgument::new(
+                "vtable",
+                FfiType::Reference(Box::new(FfiType::Struct(FfiStructName(
+                    "VTableForeignClosure".to_owned(),
+                )))),
+            )],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: false,
+            kind: FfiFunctionKind::ForeignClosureVtableInit,
+        }
+        .into(),
+        FfiFunction {
+            name: RustFfiFunctionName(format!("ffi_{crate_name}_rust_closure_call")),
+            async_data: None,
+            arguments: vec![
+                FfiArgument::new("handle", handle_type.clone()),
+                FfiArgument::new("args", FfiType::RustBuffer(None)),
+            ],
+            return_type: FfiReturnType {
+                ty: Some(FfiType::RustBuffer(None)),
+            },
+            has_rust_call_status_arg: true,
+            kind: FfiFunctionKind::RustClosureCall,
+        }
+        .into(),
+        FfiFunction {
+            name: RustFfiFunctionName(format!("ffi_{crate_name}_rust_closure_clone")),
+            async_data: None,
+            arguments: vec![FfiArgument::new("handle", handle_type.clone())],
+            return_type: FfiReturnType {
+                ty: Some(handle_type.clone()),
+            },
+            has_rust_call_status_arg: false,
+            kind: FfiFunctionKind::RustClosureClone,
+        }
+        .into(),
+        FfiFunction {
+            name: RustFfiFunctionName(format!("ffi_{crate_name}_rust_closure_free")),
+            async_data: None,
+            arguments: vec![FfiArgument::new("handle", handle_type)],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: false,
+            kind: FfiFunctionKind::RustClosureFree,
+        }
+        .into(),
+    ])
+}
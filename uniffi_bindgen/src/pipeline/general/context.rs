@@ -120,6 +120,8 @@ impl Context {
         namespace.try_visit(|func: &initial::Function| self.update_from_throws(&func.throws))?;
         namespace.try_visit(|meth: &initial::Method| self.update_from_throws(&meth.throws))?;
         namespace.try_visit(|cons: &initial::Constructor| self.update_from_throws(&cons.throws))?;
+        namespace
+            .try_visit(|meth: &initial::StaticMethod| self.update_from_throws(&meth.throws))?;
         // Enums with `EnumShape::Error` are always considered errors, even if they're not directly
         // used as errors in the interface.  See the `FlatInner` error from the `error-types` fixture
         // for an example.  It's not totally clear that this is correct, but this is how things have
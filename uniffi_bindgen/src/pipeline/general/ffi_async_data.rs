@@ -32,6 +32,21 @@ pub fn method_async_data(meth: &initial::Method, context: &Context) -> Result<Op
     async_data(context, ffi_return_type.as_ref()).map(Some)
 }
 
+pub fn static_method_async_data(
+    meth: &initial::StaticMethod,
+    context: &Context,
+) -> Result<Option<AsyncData>> {
+    if !meth.is_async {
+        return Ok(None);
+    }
+    let ffi_return_type = meth
+        .return_type
+        .as_ref()
+        .map(|ty| ffi_types::ffi_type(ty, context))
+        .transpose()?;
+    async_data(context, ffi_return_type.as_ref()).map(Some)
+}
+
 pub fn constructor_async_data(
     cons: &initial::Constructor,
     interface_name: &str,
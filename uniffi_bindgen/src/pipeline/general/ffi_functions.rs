@@ -39,6 +39,23 @@ pub fn ffi_definitions(
         };
         int.try_visit(|meth: &initial::Method| {
             ffi_definitions.push(method_ffi_def(meth, &crate_name, &self_type, context)?);
+            if meth.has_default {
+                ffi_definitions.push(default_method_ffi_def(
+                    meth,
+                    &crate_name,
+                    &self_type,
+                    context,
+                )?);
+            }
+            Ok(())
+        })?;
+        int.try_visit(|meth: &initial::StaticMethod| {
+            ffi_definitions.push(static_method_ffi_def(
+                meth,
+                &crate_name,
+                &int.name,
+                context,
+            )?);
             Ok(())
         })?;
         int.try_visit(|cons: &initial::Constructor| {
@@ -62,6 +79,15 @@ pub fn ffi_definitions(
             ffi_definitions.push(method_ffi_def(meth, &crate_name, &self_type, context)?);
             Ok(())
         })?;
+        record.try_visit(|meth: &initial::StaticMethod| {
+            ffi_definitions.push(static_method_ffi_def(
+                meth,
+                &crate_name,
+                &record.name,
+                context,
+            )?);
+            Ok(())
+        })?;
         Ok(())
     })?;
     namespace.try_visit(|en: &initial::Enum| {
@@ -74,6 +100,10 @@ pub fn ffi_definitions(
             ffi_definitions.push(method_ffi_def(meth, &crate_name, &self_type, context)?);
             Ok(())
         })?;
+        en.try_visit(|meth: &initial::StaticMethod| {
+            ffi_definitions.push(static_method_ffi_def(meth, &crate_name, &en.name, context)?);
+            Ok(())
+        })?;
         Ok(())
     })?;
     Ok(ffi_definitions)
@@ -112,6 +142,44 @@ fn method_ffi_def(
     )
 }
 
+/// FFI definition for the function that calls the Rust default implementation of a trait method
+fn default_method_ffi_def(
+    meth: &initial::Method,
+    crate_name: &str,
+    receiver_ty: &Type,
+    context: &Context,
+) -> Result<FfiDefinition> {
+    let FfiDefinition::RustFunction(mut ffi_func) =
+        method_ffi_def(meth, crate_name, receiver_ty, context)?
+    else {
+        bail!("method_ffi_def returned an unexpected FfiDefinition");
+    };
+    let Type::Interface { name, .. } = receiver_ty else {
+        bail!("default method implementations are only supported for trait interfaces");
+    };
+    ffi_func.name = RustFfiFunctionName(uniffi_meta::trait_default_method_symbol_name(
+        crate_name, name, &meth.name,
+    ));
+    Ok(FfiDefinition::RustFunction(ffi_func))
+}
+
+fn static_method_ffi_def(
+    meth: &initial::StaticMethod,
+    crate_name: &str,
+    type_name: &str,
+    context: &Context,
+) -> Result<FfiDefinition> {
+    let name = uniffi_meta::static_method_symbol_name(crate_name, type_name, &meth.name);
+    let async_data = ffi_async_data::static_method_async_data(meth, context)?;
+    ffi_def(
+        name,
+        &meth.inputs,
+        meth.return_type.as_ref(),
+        async_data,
+        context,
+    )
+}
+
 /// Convert an `initial::Argument` into an `FfiArgument`.
 ///
 /// Handles the `Bytes + by_ref` → `ForeignBytes` selection, matching the
@@ -17,8 +17,12 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
         Type::Int64 => FfiType::Int64,
         Type::Float32 => FfiType::Float32,
         Type::Float64 => FfiType::Float64,
+        // There's no portable C type for 128-bit integers, so they're serialized.
+        Type::UInt128 | Type::Int128 => FfiType::RustBuffer(None),
         // Booleans lower into an Int8, to work around a bug in JNA.
         Type::Boolean => FfiType::Int8,
+        // Chars are passed as their code point.
+        Type::Char => FfiType::UInt32,
         // Strings are always owned rust values.
         // We might add a separate type for borrowed strings in future.
         Type::String => FfiType::RustBuffer(None),
@@ -32,6 +36,12 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
             imp,
             ..
         } => interface_ffi_type(namespace, name, imp)?,
+        // Streams are passed as handles to a type-erased Rust stream.
+        Type::Stream { .. } => FfiType::Handle(HandleKind::RustStream),
+        // Iterators are passed as handles to a type-erased Rust iterator.
+        Type::Iterator { .. } => FfiType::Handle(HandleKind::RustIter),
+        // Closures are passed as handles to a type-erased closure.
+        Type::Closure { .. } => FfiType::Handle(HandleKind::Closure),
         // Callback interfaces are passed as opaque integer handles.
         Type::CallbackInterface {
             namespace, name, ..
@@ -45,8 +55,10 @@ pub fn ffi_type(ty: &Type, context: &Context) -> Result<FfiType> {
         ),
         Type::Optional { .. }
         | Type::Sequence { .. }
+        | Type::Array { .. }
         | Type::Map { .. }
         | Type::Set { .. }
+        | Type::Tuple { .. }
         | Type::Timestamp
         | Type::Duration => FfiType::RustBuffer(None),
         Type::Custom {
@@ -127,15 +127,18 @@ fn type_names_in_type(ty: &Type) -> Vec<String> {
         Type::Box { inner_type }
         | Type::Optional { inner_type }
         | Type::Sequence { inner_type }
-        | Type::Set { inner_type } => type_names_in_type(inner_type),
+        | Type::Array { inner_type, .. }
+        | Type::Set { inner_type, .. } => type_names_in_type(inner_type),
         Type::Map {
             key_type,
             value_type,
+            ..
         } => {
             let mut names = type_names_in_type(key_type);
             names.extend(type_names_in_type(value_type));
             names
         }
+        Type::Tuple { inner_types } => inner_types.iter().flat_map(type_names_in_type).collect(),
         _ => vec![],
     }
 }
@@ -218,15 +221,18 @@ mod tests {
             orig_name: name.to_string(),
         });
         TypeDefinition::Enum(Enum {
+            module_path: "test".to_string(),
             name: name.to_string(),
             orig_name: name.to_string(),
             is_flat: false,
             self_type,
             discr_type: discr_type_node.clone(),
+            discr_type_specified: false,
             uniffi_trait_methods: UniffiTraitMethods::default(),
             shape: EnumShape::Enum,
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             docstring: None,
             recursive: false,
             variants: vec![Variant {
@@ -254,15 +260,18 @@ mod tests {
             orig_name: name.to_string(),
         });
         TypeDefinition::Enum(Enum {
+            module_path: "test".to_string(),
             name: name.to_string(),
             orig_name: name.to_string(),
             is_flat: true,
             self_type,
             discr_type: discr_type_node.clone(),
+            discr_type_specified: false,
             uniffi_trait_methods: UniffiTraitMethods::default(),
             shape: EnumShape::Enum,
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             docstring: None,
             recursive: false,
             variants: vec![Variant {
@@ -319,15 +328,18 @@ mod tests {
             })
             .collect();
         TypeDefinition::Enum(Enum {
+            module_path: "test".to_string(),
             name: name.to_string(),
             orig_name: name.to_string(),
             is_flat: false,
             self_type,
             discr_type: discr_type_node,
+            discr_type_specified: false,
             uniffi_trait_methods: UniffiTraitMethods::default(),
             shape: EnumShape::Enum,
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             docstring: None,
             recursive: false,
             variants,
@@ -341,6 +353,7 @@ mod tests {
             orig_name: name.to_string(),
         });
         TypeDefinition::Record(Record {
+            module_path: "test".to_string(),
             name: name.to_string(),
             orig_name: name.to_string(),
             self_type,
@@ -348,6 +361,7 @@ mod tests {
             uniffi_trait_methods: UniffiTraitMethods::default(),
             constructors: vec![],
             methods: vec![],
+            static_methods: vec![],
             docstring: None,
             recursive: false,
             fields: vec![Field {
@@ -424,6 +438,7 @@ mod tests {
                 Type::Map {
                     key_type: Box::new(Type::String),
                     value_type: Box::new(enum_type("Inner")),
+                    ordered: false,
                 },
             ),
             make_flat_enum("Inner"),
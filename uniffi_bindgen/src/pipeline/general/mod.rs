@@ -7,6 +7,7 @@
 mod callable;
 mod callback_interfaces;
 mod checksums;
+mod closures;
 mod context;
 mod default;
 mod enums;
@@ -23,16 +24,19 @@ mod rename;
 mod root;
 mod rust_buffer;
 mod rust_future;
+mod rust_iter;
 mod sort;
 mod type_definitions_from_api;
 mod types;
 mod uniffi_traits;
+mod unsupported;
 use super::initial;
 use anyhow::{anyhow, bail, Result};
 pub use context::Context;
 pub use indexmap::{IndexMap, IndexSet};
 pub use nodes::*;
 use uniffi_pipeline::{new_pipeline, use_prev_node, MapNode, Node, Pipeline};
+pub use unsupported::remove_unsupported_items;
 
 /// General IR pipeline
 ///
@@ -20,6 +20,8 @@ pub fn map_namespace(namespace: initial::Namespace, context: &Context) -> Result
                 objects::ffi_definitions(&namespace, context)?,
                 callback_interfaces::ffi_definitions(&namespace, context)?,
                 rust_future::ffi_definitions(&namespace)?,
+                rust_iter::ffi_definitions(&namespace)?,
+                closures::ffi_definitions(&namespace)?,
             ]
             .into_iter()
             .flatten(),
@@ -74,6 +76,17 @@ pub fn map_namespace(namespace: initial::Namespace, context: &Context) -> Result
                 },
             )
             .collect::<Result<Vec<_>>>()?,
+        constants: namespace
+            .constants
+            .into_iter()
+            .filter_map(
+                |c| match exclude::should_exclude_toplevel_item(&c.name, context) {
+                    Err(e) => Some(Err(e)),
+                    Ok(true) => None,
+                    Ok(false) => Some(c.map_node(context)),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?,
         name: namespace.name,
     })
 }
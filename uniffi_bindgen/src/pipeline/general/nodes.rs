@@ -53,6 +53,7 @@ pub struct Namespace {
     pub docstring: Option<String>,
     pub functions: Vec<Function>,
     pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
     pub ffi_definitions: IndexSet<FfiDefinition>,
     /// Checksum functions
     pub checksums: Vec<Checksum>,
@@ -71,6 +72,8 @@ pub struct Namespace {
 pub struct Function {
     #[map_node(callable::function_callable(&self, context)?)]
     pub callable: Callable,
+    /// Rust module that the item is defined in
+    pub module_path: String,
     pub docstring: Option<String>,
 }
 
@@ -93,9 +96,19 @@ pub enum TypeDefinition {
     #[map_node(added)]
     Sequence(SequenceType),
     #[map_node(added)]
+    Array(ArrayType),
+    #[map_node(added)]
     Map(MapType),
     #[map_node(added)]
     Set(SetType),
+    #[map_node(added)]
+    Tuple(TupleType),
+    #[map_node(added)]
+    Stream(StreamType),
+    #[map_node(added)]
+    Iterator(IteratorType),
+    #[map_node(added)]
+    Closure(ClosureType),
     /// User types that are defined in another crate
     #[map_node(added)]
     External(ExternalType),
@@ -114,6 +127,9 @@ pub struct Constructor {
 pub struct Method {
     #[map_node(callable::method_callable(&self, context)?)]
     pub callable: Callable,
+    /// FFI function that calls the Rust default implementation for trait methods that have one.
+    #[map_node(callable::default_method_ffi_func(&self, context)?)]
+    pub default_ffi_func: Option<RustFfiFunctionName>,
     pub docstring: Option<String>,
 }
 
@@ -140,6 +156,8 @@ pub enum CallableKind {
     Method { self_type: TypeNode },
     /// Interface constructor
     Constructor { self_type: TypeNode, primary: bool },
+    /// Associated function without a receiver on an Interface/Record/Enum
+    StaticMethod { self_type: TypeNode },
     /// Method inside a VTable or a CallbackInterface
     ///
     /// For trait interfaces this only applies to the Callables inside the `vtable.methods` field.
@@ -206,6 +224,7 @@ pub enum DefaultValue {
 pub enum Literal {
     Boolean(bool),
     String(String),
+    Char(char),
     // Integers are represented as the widest representation we can.
     // Number formatting vary with language and radix, so we avoid a lot of parsing and
     // formatting duplication by using only signed and unsigned variants.
@@ -221,7 +240,30 @@ pub enum Literal {
     EmptyMap,
     EmptySet,
     None,
-    Some { inner: Box<DefaultValue> },
+    Some {
+        inner: Box<DefaultValue>,
+    },
+    Record {
+        fields: Vec<FieldLiteral>,
+        ty: TypeNode,
+    },
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(initial::FieldLiteral))]
+pub struct FieldLiteral {
+    pub name: String,
+    pub ty: TypeNode,
+    pub value: Literal,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+#[map_node(from(initial::Constant))]
+pub struct Constant {
+    pub name: String,
+    pub ty: TypeNode,
+    pub value: Literal,
+    pub docstring: Option<String>,
 }
 
 #[derive(Debug, Clone, Node, MapNode)]
@@ -232,6 +274,7 @@ pub struct Record {
     pub fields_kind: FieldsKind,
     #[map_node(context.self_type()?)]
     pub self_type: TypeNode,
+    pub module_path: String,
     pub orig_name: String,
     #[map_node(rename::type_(&context.namespace_name()?, self.name, context)?)]
     pub name: String,
@@ -242,6 +285,8 @@ pub struct Record {
     pub constructors: Vec<Constructor>,
     #[map_node(objects::methods(self.methods, context)?)]
     pub methods: Vec<Method>,
+    #[map_node(objects::static_methods(self.static_methods, context)?)]
+    pub static_methods: Vec<Method>,
     pub docstring: Option<String>,
     #[map_node(false)]
     pub recursive: bool,
@@ -280,8 +325,12 @@ pub struct Enum {
     /// exactly the same type.
     #[map_node(enums::discr_type(&self, context)?)]
     pub discr_type: TypeNode,
+    /// Was the discriminant type specified in the source code, for example with `#[repr(u8)]`?
+    #[map_node(self.discr_type.is_some())]
+    pub discr_type_specified: bool,
     #[map_node(enums::map_variants(&self.discr_type, self.variants, context)?)]
     pub variants: Vec<Variant>,
+    pub module_path: String,
     pub orig_name: String,
     #[map_node(rename::type_(&context.namespace_name()?, self.name, context)?)]
     pub name: String,
@@ -293,6 +342,8 @@ pub struct Enum {
     pub constructors: Vec<Constructor>,
     #[map_node(objects::methods(self.methods, context)?)]
     pub methods: Vec<Method>,
+    #[map_node(objects::static_methods(self.static_methods, context)?)]
+    pub static_methods: Vec<Method>,
     pub docstring: Option<String>,
     #[map_node(false)]
     pub recursive: bool,
@@ -323,6 +374,7 @@ pub struct Interface {
     pub ffi_func_clone: RustFfiFunctionName,
     #[map_node(objects::ffi_free_name(&self.name, context)?)]
     pub ffi_func_free: RustFfiFunctionName,
+    pub module_path: String,
     pub orig_name: String,
     #[map_node(rename::type_(&context.namespace_name()?, self.name, context)?)]
     pub name: String,
@@ -334,6 +386,8 @@ pub struct Interface {
     pub constructors: Vec<Constructor>,
     #[map_node(objects::interface_methods(self.methods, context)?)]
     pub methods: Vec<Method>,
+    #[map_node(objects::static_methods(self.static_methods, context)?)]
+    pub static_methods: Vec<Method>,
     pub trait_impls: Vec<ObjectTraitImpl>,
     pub imp: ObjectImpl,
 }
@@ -346,6 +400,7 @@ pub struct CallbackInterface {
     pub vtable: VTable,
     #[map_node(context.self_type()?)]
     pub self_type: TypeNode,
+    pub module_path: String,
     pub orig_name: String,
     #[map_node(rename::type_(&context.namespace_name()?, self.name, context)?)]
     pub name: String,
@@ -416,16 +471,65 @@ pub struct SequenceType {
     pub self_type: TypeNode,
 }
 
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct ArrayType {
+    pub inner: TypeNode,
+    pub len: u32,
+    pub self_type: TypeNode,
+}
+
 #[derive(Debug, Clone, Node, MapNode)]
 pub struct MapType {
     pub key: TypeNode,
     pub value: TypeNode,
+    /// Should the bindings preserve the entry order?
+    pub ordered: bool,
     pub self_type: TypeNode,
 }
 
 #[derive(Debug, Clone, Node, MapNode)]
 pub struct SetType {
     pub inner: TypeNode,
+    /// Should the bindings preserve the item order?
+    pub ordered: bool,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct TupleType {
+    pub inners: Vec<TypeNode>,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct StreamType {
+    /// Item type.
+    ///
+    /// The `rust_stream_next` futures return an `Option<T>` for this type, where `None` signals the
+    /// end of the stream.
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct IteratorType {
+    /// Item type.
+    ///
+    /// `rust_iter_next_chunk` returns a `Vec<T>` for this type, where an empty `Vec` signals the
+    /// end of the iterator.
+    pub item: TypeNode,
+    pub self_type: TypeNode,
+}
+
+#[derive(Debug, Clone, Node, MapNode)]
+pub struct ClosureType {
+    /// Argument types.
+    ///
+    /// `rust_closure_call` and the foreign closure VTable pass these as a `RustBuffer` with the
+    /// serialized arguments.
+    pub arguments: Vec<TypeNode>,
+    /// Return type, serialized into a `RustBuffer`.
+    pub return_type: Option<TypeNode>,
     pub self_type: TypeNode,
 }
 
@@ -495,6 +599,14 @@ pub enum FfiFunctionKind {
     RustFutureComplete,
     RustFutureCancel,
     RustFutureFree,
+    RustStreamNext,
+    RustStreamFree,
+    RustIterNextChunk,
+    RustIterFree,
+    RustClosureCall,
+    RustClosureClone,
+    RustClosureFree,
+    ForeignClosureVtableInit,
     RustBufferFromBytes,
     RustBufferFree,
     RustBufferAlloc,
@@ -585,6 +697,10 @@ pub enum FfiType {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Node, MapNode)]
 pub enum HandleKind {
     RustFuture,
+    RustStream,
+    RustIter,
+    /// Closures can either be Rust closures or foreign closures
+    Closure,
     ForeignFuture,
     ForeignFutureCallbackData,
     // Interface, trait interface, or callback interface
@@ -617,6 +733,12 @@ pub struct UniffiTraitMethods {
     pub eq_ne: Option<Method>,
     pub hash_hash: Option<Method>,
     pub ord_cmp: Option<Method>,
+    pub clone_clone: Option<Method>,
+    /// Static method, with a `CallableKind::StaticMethod` callable
+    pub default_default: Option<Method>,
+    pub partial_ord_partial_cmp: Option<Method>,
+    /// Static method, with a `CallableKind::StaticMethod` callable
+    pub from_str_from_str: Option<Method>,
 }
 
 impl FfiDefinition {
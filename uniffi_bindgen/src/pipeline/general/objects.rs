@@ -112,6 +112,7 @@ pub fn methods_with_kind(
         }
         mapped.push(Method {
             callable: callable::method_callable_with_kind(&meth, kind.clone(), context)?,
+            default_ffi_func: callable::default_method_ffi_func(&meth, context)?,
             docstring: meth.docstring,
         })
     }
@@ -123,6 +124,24 @@ pub fn methods(methods: Vec<initial::Method>, context: &Context) -> Result<Vec<M
     methods_with_kind(methods, CallableKind::Method { self_type }, context)
 }
 
+pub fn static_methods(
+    static_methods: Vec<initial::StaticMethod>,
+    context: &Context,
+) -> Result<Vec<Method>> {
+    let mut mapped = Vec::with_capacity(static_methods.len());
+    for meth in static_methods {
+        if exclude::should_exclude_method(&meth.name, context)? {
+            continue;
+        }
+        mapped.push(Method {
+            callable: callable::static_method_callable(&meth, context)?,
+            default_ffi_func: None,
+            docstring: meth.docstring,
+        })
+    }
+    Ok(mapped)
+}
+
 pub fn interface_methods(methods: Vec<initial::Method>, context: &Context) -> Result<Vec<Method>> {
     let self_type = context.self_type()?;
     let kind = CallableKind::Method { self_type };
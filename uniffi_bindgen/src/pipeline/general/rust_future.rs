@@ -64,6 +64,12 @@ pub fn ffi_definitions(namespace: &initial::Namespace) -> Result<Vec<FfiDefiniti
         let free_name = format!("ffi_{crate_name}_rust_future_free_{return_type_name}");
         ffi_defs.push(ffi_rust_future_free(free_name));
     }
+    ffi_defs.push(ffi_rust_stream_next(format!(
+        "ffi_{crate_name}_rust_stream_next"
+    )));
+    ffi_defs.push(ffi_rust_stream_free(format!(
+        "ffi_{crate_name}_rust_stream_free"
+    )));
     Ok(ffi_defs)
 }
 
@@ -139,8 +145,43 @@ fn ffi_rust_future_free(symbol_name: String) -> FfiDefinition {
     .into()
 }
 
+fn ffi_rust_stream_next(symbol_name: String) -> FfiDefinition {
+    FfiFunction {
+        name: RustFfiFunctionName(symbol_name),
+        async_data: None,
+        arguments: vec![FfiArgument {
+            name: "handle".to_owned(),
+            ty: FfiType::Handle(HandleKind::RustStream),
+        }],
+        return_type: FfiReturnType {
+            ty: Some(FfiType::Handle(HandleKind::RustFuture)),
+        },
+        has_rust_call_status_arg: false,
+        kind: FfiFunctionKind::RustStreamNext,
+    }
+    .into()
+}
+
+fn ffi_rust_stream_free(symbol_name: String) -> FfiDefinition {
+    FfiFunction {
+        name: RustFfiFunctionName(symbol_name),
+        async_data: None,
+        arguments: vec![FfiArgument {
+            name: "handle".to_owned(),
+            ty: FfiType::Handle(HandleKind::RustStream),
+        }],
+        return_type: FfiReturnType { ty: None },
+        has_rust_call_status_arg: false,
+        kind: FfiFunctionKind::RustStreamFree,
+    }
+    .into()
+}
+
+// Streams are driven with Rust futures, so they need the async FFI functions as well.
 fn has_async_fns(namespace: &initial::Namespace) -> bool {
     namespace.has_descendant(|func: &initial::Function| func.is_async)
         || namespace.has_descendant(|meth: &initial::Method| meth.is_async)
         || namespace.has_descendant(|cons: &initial::Constructor| cons.is_async)
+        || namespace.has_descendant(|meth: &initial::StaticMethod| meth.is_async)
+        || namespace.has_descendant(|ty: &Type| matches!(ty, Type::Stream { .. }))
 }
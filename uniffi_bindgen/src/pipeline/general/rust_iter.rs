@@ -0,0 +1,43 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Iterator-related FFI functions
+
+use super::*;
+
+pub fn ffi_definitions(namespace: &initial::Namespace) -> Result<Vec<FfiDefinition>> {
+    if !namespace.has_descendant(|ty: &Type| matches!(ty, Type::Iterator { .. })) {
+        return Ok(vec![]);
+    }
+
+    let crate_name = &namespace.crate_name;
+    Ok(vec![
+        FfiFunction {
+            name: RustFfiFunctionName(format!("ffi_{crate_name}_rust_iter_next_chunk")),
+            async_data: None,
+            arguments: vec![
+                FfiArgument::new("handle", FfiType::Handle(HandleKind::RustIter)),
+                FfiArgument::new("max_items", FfiType::UInt32),
+            ],
+            return_type: FfiReturnType {
+                ty: Some(FfiType::RustBuffer(None)),
+            },
+            has_rust_call_status_arg: true,
+            kind: FfiFunctionKind::RustIterNextChunk,
+        }
+        .into(),
+        FfiFunction {
+            name: RustFfiFunctionName(format!("ffi_{crate_name}_rust_iter_free")),
+            async_data: None,
+            arguments: vec![FfiArgument::new(
+                "handle",
+                FfiType::Handle(HandleKind::RustIter),
+            )],
+            return_type: FfiReturnType { ty: None },
+            has_rust_call_status_arg: false,
+            kind: FfiFunctionKind::RustIterFree,
+        }
+        .into(),
+    ])
+}
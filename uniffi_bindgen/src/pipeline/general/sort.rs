@@ -139,8 +139,13 @@ impl DependencyLogic for TypeDefinitionDependencyLogic {
             | TypeDefinition::Box(BoxedType { self_type, .. })
             | TypeDefinition::Optional(OptionalType { self_type, .. })
             | TypeDefinition::Sequence(SequenceType { self_type, .. })
+            | TypeDefinition::Array(ArrayType { self_type, .. })
             | TypeDefinition::Map(MapType { self_type, .. })
             | TypeDefinition::Set(SetType { self_type, .. })
+            | TypeDefinition::Tuple(TupleType { self_type, .. })
+            | TypeDefinition::Stream(StreamType { self_type, .. })
+            | TypeDefinition::Iterator(IteratorType { self_type, .. })
+            | TypeDefinition::Closure(ClosureType { self_type, .. })
             | TypeDefinition::Record(Record { self_type, .. })
             | TypeDefinition::Enum(Enum { self_type, .. })
             | TypeDefinition::Interface(Interface { self_type, .. })
@@ -158,12 +163,30 @@ impl DependencyLogic for TypeDefinitionDependencyLogic {
             TypeDefinition::Box(BoxedType { inner, .. })
             | TypeDefinition::Optional(OptionalType { inner, .. })
             | TypeDefinition::Sequence(SequenceType { inner, .. })
+            | TypeDefinition::Array(ArrayType { inner, .. })
             | TypeDefinition::Set(SetType { inner, .. }) => {
                 vec![inner.canonical_name.clone()]
             }
             TypeDefinition::Map(MapType { key, value, .. }) => {
                 vec![key.canonical_name.clone(), value.canonical_name.clone()]
             }
+            TypeDefinition::Tuple(TupleType { inners, .. }) => inners
+                .iter()
+                .map(|inner| inner.canonical_name.clone())
+                .collect(),
+            TypeDefinition::Stream(StreamType { item, .. })
+            | TypeDefinition::Iterator(IteratorType { item, .. }) => {
+                vec![item.canonical_name.clone()]
+            }
+            TypeDefinition::Closure(ClosureType {
+                arguments,
+                return_type,
+                ..
+            }) => arguments
+                .iter()
+                .chain(return_type)
+                .map(|ty| ty.canonical_name.clone())
+                .collect(),
             TypeDefinition::Record(r) => r
                 .fields
                 .iter()
@@ -6,6 +6,8 @@
 //!
 //! Most bindings will need to define FFI converters for these.
 
+use std::collections::HashMap;
+
 use indexmap::IndexSet;
 
 use super::*;
@@ -14,12 +16,26 @@ pub fn type_definitions(
     namespace: &initial::Namespace,
     context: &Context,
 ) -> Result<Vec<TypeDefinition>> {
+    // Types that only appear in literals, like enum discriminants, aren't part of the API.
+    let mut literal_type_counts = HashMap::<Type, usize>::default();
+    let mut count_literal_type = |ty: &Type| {
+        *literal_type_counts.entry(ty.clone()).or_default() += 1;
+    };
+    namespace.visit(|arg: &initial::Argument| arg.default.visit(&mut count_literal_type));
+    namespace.visit(|field: &initial::Field| field.default.visit(&mut count_literal_type));
+    namespace.visit(|constant: &initial::Constant| constant.value.visit(&mut count_literal_type));
+    namespace.visit(|variant: &initial::Variant| variant.discr.visit(&mut count_literal_type));
+    namespace.visit(|en: &initial::Enum| en.discr_type.visit(&mut count_literal_type));
+
     // Map types to type definitions to add
     let mut all_types = IndexSet::<Type>::default();
     // String is used in the builtin functions, so force it to be present.
     all_types.insert(Type::String);
-    namespace.visit(|ty: &Type| {
-        all_types.insert(ty.clone());
+    namespace.visit(|ty: &Type| match literal_type_counts.get_mut(ty) {
+        Some(count) if *count > 0 => *count -= 1,
+        _ => {
+            all_types.insert(ty.clone());
+        }
     });
     let mut type_definitions = vec![];
     for ty in all_types {
@@ -33,9 +49,12 @@ pub fn type_definitions(
             | Type::Int32
             | Type::UInt64
             | Type::Int64
+            | Type::UInt128
+            | Type::Int128
             | Type::Float32
             | Type::Float64
             | Type::Boolean
+            | Type::Char
             | Type::String
             | Type::Bytes
             | Type::Timestamp
@@ -60,19 +79,60 @@ pub fn type_definitions(
                     self_type,
                 }));
             }
+            Type::Array { inner_type, len } => {
+                type_definitions.push(TypeDefinition::Array(ArrayType {
+                    inner: (*inner_type).map_node(context)?,
+                    len,
+                    self_type,
+                }));
+            }
             Type::Map {
                 key_type,
                 value_type,
+                ordered,
             } => {
                 type_definitions.push(TypeDefinition::Map(MapType {
                     key: (*key_type).map_node(context)?,
                     value: (*value_type).map_node(context)?,
+                    ordered,
                     self_type,
                 }));
             }
-            Type::Set { inner_type } => {
+            Type::Set {
+                inner_type,
+                ordered,
+            } => {
                 type_definitions.push(TypeDefinition::Set(SetType {
                     inner: (*inner_type).map_node(context)?,
+                    ordered,
+                    self_type,
+                }));
+            }
+            Type::Tuple { inner_types } => {
+                type_definitions.push(TypeDefinition::Tuple(TupleType {
+                    inners: inner_types.map_node(context)?,
+                    self_type,
+                }));
+            }
+            Type::Stream { item_type } => {
+                type_definitions.push(TypeDefinition::Stream(StreamType {
+                    item: (*item_type).map_node(context)?,
+                    self_type,
+                }));
+            }
+            Type::Iterator { item_type } => {
+                type_definitions.push(TypeDefinition::Iterator(IteratorType {
+                    item: (*item_type).map_node(context)?,
+                    self_type,
+                }));
+            }
+            Type::Closure {
+                argument_types,
+                return_type,
+            } => {
+                type_definitions.push(TypeDefinition::Closure(ClosureType {
+                    arguments: argument_types.map_node(context)?,
+                    return_type: return_type.map(|ty| (*ty).map_node(context)).transpose()?,
                     self_type,
                 }));
             }
@@ -24,9 +24,12 @@ pub fn canonical_name(ty: &Type) -> String {
         Type::Int32 => "Int32".to_string(),
         Type::UInt64 => "UInt64".to_string(),
         Type::Int64 => "Int64".to_string(),
+        Type::UInt128 => "UInt128".to_string(),
+        Type::Int128 => "Int128".to_string(),
         Type::Float32 => "Float32".to_string(),
         Type::Float64 => "Float64".to_string(),
         Type::Boolean => "Boolean".to_string(),
+        Type::Char => "Char".to_string(),
         Type::String => "String".to_string(),
         Type::Bytes => "Bytes".to_string(),
         Type::Timestamp => "Timestamp".to_string(),
@@ -42,21 +45,62 @@ pub fn canonical_name(ty: &Type) -> String {
         Type::Sequence { inner_type } => {
             format!("Sequence{}", canonical_name(inner_type))
         }
+        Type::Array { inner_type, len } => {
+            format!("Array{len}{}", canonical_name(inner_type))
+        }
         // Note: this is currently guaranteed to be unique because keys can only be primitive
         // types.  If we allowed user-defined types, there would be potential collisions.  For
         // example "MapTypeFooTypeTypeBar" could be "Foo" -> "TypeBar" or "FooType" -> "Bar".
         Type::Map {
             key_type,
             value_type,
+            ordered,
         } => format!(
-            "Map{}{}",
+            "{}Map{}{}",
+            if *ordered { "Ordered" } else { "" },
             canonical_name(key_type),
             canonical_name(value_type),
         ),
         Type::Box { inner_type } => format!("Box{}", canonical_name(inner_type)),
-        Type::Set { inner_type } => {
-            format!("Set{}", canonical_name(inner_type))
+        Type::Set {
+            inner_type,
+            ordered,
+        } => {
+            format!(
+                "{}Set{}",
+                if *ordered { "Ordered" } else { "" },
+                canonical_name(inner_type)
+            )
         }
+        // Note: the arity prefix keeps tuples of different lengths apart, but there's the same
+        // potential for collisions between user-defined type names as described for `Map` above.
+        Type::Tuple { inner_types } => format!(
+            "Tuple{}{}",
+            inner_types.len(),
+            inner_types
+                .iter()
+                .map(canonical_name)
+                .collect::<Vec<_>>()
+                .join(""),
+        ),
+        Type::Stream { item_type } => format!("Stream{}", canonical_name(item_type)),
+        Type::Iterator { item_type } => format!("Iterator{}", canonical_name(item_type)),
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => format!(
+            "Closure{}{}To{}",
+            argument_types.len(),
+            argument_types
+                .iter()
+                .map(canonical_name)
+                .collect::<Vec<_>>()
+                .join(""),
+            match return_type {
+                Some(return_type) => canonical_name(return_type),
+                None => "Unit".to_string(),
+            },
+        ),
     }
 }
 
@@ -98,15 +142,49 @@ pub fn map_type(mut ty: Type, context: &Context) -> Result<Type> {
         Type::Sequence { inner_type } => Type::Sequence {
             inner_type: Box::new(map_type(*inner_type, context)?),
         },
+        Type::Array { inner_type, len } => Type::Array {
+            inner_type: Box::new(map_type(*inner_type, context)?),
+            len,
+        },
         Type::Map {
             key_type,
             value_type,
+            ordered,
         } => Type::Map {
             key_type: Box::new(map_type(*key_type, context)?),
             value_type: Box::new(map_type(*value_type, context)?),
+            ordered,
         },
-        Type::Set { inner_type } => Type::Set {
+        Type::Set {
+            inner_type,
+            ordered,
+        } => Type::Set {
             inner_type: Box::new(map_type(*inner_type, context)?),
+            ordered,
+        },
+        Type::Tuple { inner_types } => Type::Tuple {
+            inner_types: inner_types
+                .into_iter()
+                .map(|ty| map_type(ty, context))
+                .collect::<Result<_>>()?,
+        },
+        Type::Stream { item_type } => Type::Stream {
+            item_type: Box::new(map_type(*item_type, context)?),
+        },
+        Type::Iterator { item_type } => Type::Iterator {
+            item_type: Box::new(map_type(*item_type, context)?),
+        },
+        Type::Closure {
+            argument_types,
+            return_type,
+        } => Type::Closure {
+            argument_types: argument_types
+                .into_iter()
+                .map(|ty| map_type(ty, context))
+                .collect::<Result<_>>()?,
+            return_type: return_type
+                .map(|ty| map_type(*ty, context).map(Box::new))
+                .transpose()?,
         },
         // All other types can be returned unchanged
         _ => ty,
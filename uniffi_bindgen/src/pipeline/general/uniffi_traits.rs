@@ -27,7 +27,27 @@ pub fn map_trait_vec(
             initial::UniffiTrait::Ord { cmp } => {
                 dest.ord_cmp = Some(cmp.map_node(context)?);
             }
+            initial::UniffiTrait::Clone { clone } => {
+                dest.clone_clone = Some(clone.map_node(context)?);
+            }
+            initial::UniffiTrait::Default { default } => {
+                dest.default_default = Some(map_static_method(default, context)?);
+            }
+            initial::UniffiTrait::PartialOrd { partial_cmp } => {
+                dest.partial_ord_partial_cmp = Some(partial_cmp.map_node(context)?);
+            }
+            initial::UniffiTrait::FromStr { from_str } => {
+                dest.from_str_from_str = Some(map_static_method(from_str, context)?);
+            }
         }
     }
     Ok(dest)
 }
+
+fn map_static_method(meth: initial::StaticMethod, context: &Context) -> Result<Method> {
+    Ok(Method {
+        callable: callable::static_method_callable(&meth, context)?,
+        default_ffi_func: None,
+        docstring: meth.docstring,
+    })
+}
@@ -0,0 +1,353 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Remove the items that a bindings generator can't render yet
+//!
+//! Rather than failing for the entire library, bindings that don't support some types yet use
+//! this to skip every item that uses one of them and print a warning for it.
+
+use std::collections::HashSet;
+
+use super::*;
+
+/// Remove the items that use types rejected by `is_supported`
+///
+/// `is_supported` is called with the name of the namespace that's being checked and a type that
+/// it uses.  Items that use an unsupported type are removed:
+///
+///   * Records, enums, custom types, callback interfaces and trait interfaces are removed as a
+///     whole, along with any item that uses them.
+///   * Functions, constructors, methods and constants are removed individually.
+///
+/// This works across all namespaces, so that types removed from one namespace are also removed
+/// from the namespaces that use them as external types.
+pub fn remove_unsupported_items(
+    mut namespaces: IndexMap<String, Namespace>,
+    language: &str,
+    is_supported: impl Fn(&str, &Type) -> bool,
+) -> IndexMap<String, Namespace> {
+    let mut removed = HashSet::new();
+    // Removing a type makes the types that use it unsupported, so repeat until nothing changes.
+    loop {
+        let mut changed = false;
+        for namespace in namespaces.values_mut() {
+            let checker = Checker {
+                namespace: &namespace.name,
+                is_supported: &is_supported,
+                removed: &removed,
+            };
+            let (kept, unsupported): (Vec<_>, Vec<_>) =
+                std::mem::take(&mut namespace.type_definitions)
+                    .into_iter()
+                    .partition(|type_def| checker.supports_type_definition(type_def));
+            namespace.type_definitions = kept;
+            for type_def in unsupported {
+                changed = true;
+                // Builtin, compound and external types don't need a warning, the warning for the
+                // items that use them is enough.
+                let ty = &type_def_self_type(&type_def).ty;
+                if let (Some(type_namespace), Some(name)) = (ty.namespace(), ty.name()) {
+                    if type_namespace == namespace.name {
+                        warn(language, name);
+                        removed.insert((type_namespace.to_string(), name.to_string()));
+                    }
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    for namespace in namespaces.values_mut() {
+        let checker = Checker {
+            namespace: &namespace.name,
+            is_supported: &is_supported,
+            removed: &removed,
+        };
+        checker.retain(language, &mut namespace.functions, |func| {
+            func.callable.name.clone()
+        });
+        checker.retain(language, &mut namespace.constants, |constant| {
+            constant.name.clone()
+        });
+        for type_def in namespace.type_definitions.iter_mut() {
+            let (name, constructors, methods, static_methods) = match type_def {
+                TypeDefinition::Interface(int) => (
+                    &int.name,
+                    &mut int.constructors,
+                    &mut int.methods,
+                    &mut int.static_methods,
+                ),
+                TypeDefinition::Record(rec) => (
+                    &rec.name,
+                    &mut rec.constructors,
+                    &mut rec.methods,
+                    &mut rec.static_methods,
+                ),
+                TypeDefinition::Enum(en) => (
+                    &en.name,
+                    &mut en.constructors,
+                    &mut en.methods,
+                    &mut en.static_methods,
+                ),
+                _ => continue,
+            };
+            checker.retain(language, constructors, |cons| {
+                format!("{name}.{}", cons.callable.name)
+            });
+            for methods in [methods, static_methods] {
+                checker.retain(language, methods, |meth| {
+                    format!("{name}.{}", meth.callable.name)
+                });
+            }
+        }
+    }
+    namespaces
+}
+
+struct Checker<'a, F> {
+    namespace: &'a str,
+    is_supported: &'a F,
+    removed: &'a HashSet<(String, String)>,
+}
+
+impl<F: Fn(&str, &Type) -> bool> Checker<'_, F> {
+    /// Check if all types that `node` uses are supported
+    fn supports<N: Node>(&self, node: &N) -> bool {
+        !node.has_descendant(|ty: &Type| {
+            let removed = match (ty.namespace(), ty.name()) {
+                (Some(namespace), Some(name)) => self
+                    .removed
+                    .contains(&(namespace.to_string(), name.to_string())),
+                _ => false,
+            };
+            removed || !(self.is_supported)(self.namespace, ty)
+        })
+    }
+
+    fn supports_type_definition(&self, type_def: &TypeDefinition) -> bool {
+        // Only check the parts of user types that can't be removed individually.
+        match type_def {
+            TypeDefinition::Record(rec) => self.supports(&rec.fields),
+            TypeDefinition::Enum(en) => self.supports(&en.variants),
+            // Foreign code implements all trait methods, so they all need to be supported.
+            TypeDefinition::Interface(int) => match &int.vtable {
+                Some(vtable) => self.supports(&vtable.methods),
+                None => true,
+            },
+            TypeDefinition::CallbackInterface(cbi) => self.supports(&cbi.methods),
+            TypeDefinition::Custom(custom) => self.supports(&custom.builtin),
+            _ => self.supports(type_def),
+        }
+    }
+
+    fn retain<N: Node>(&self, language: &str, items: &mut Vec<N>, name: impl Fn(&N) -> String) {
+        items.retain(|item| {
+            let supported = self.supports(item);
+            if !supported {
+                warn(language, &name(item));
+            }
+            supported
+        });
+    }
+}
+
+fn type_def_self_type(type_def: &TypeDefinition) -> &TypeNode {
+    match type_def {
+        TypeDefinition::Interface(int) => &int.self_type,
+        TypeDefinition::CallbackInterface(cbi) => &cbi.self_type,
+        TypeDefinition::Record(rec) => &rec.self_type,
+        TypeDefinition::Enum(en) => &en.self_type,
+        TypeDefinition::Custom(custom) => &custom.self_type,
+        TypeDefinition::Simple(type_node) => type_node,
+        TypeDefinition::Box(boxed) => &boxed.self_type,
+        TypeDefinition::Optional(opt) => &opt.self_type,
+        TypeDefinition::Sequence(seq) => &seq.self_type,
+        TypeDefinition::Array(array) => &array.self_type,
+        TypeDefinition::Map(map) => &map.self_type,
+        TypeDefinition::Set(set) => &set.self_type,
+        TypeDefinition::Tuple(tuple) => &tuple.self_type,
+        TypeDefinition::Stream(stream) => &stream.self_type,
+        TypeDefinition::Iterator(iter) => &iter.self_type,
+        TypeDefinition::Closure(closure) => &closure.self_type,
+        TypeDefinition::External(ext) => &ext.self_type,
+    }
+}
+
+fn warn(language: &str, name: &str) {
+    eprintln!(
+        "warning: {name} uses types that the {language} bindings don't support yet, skipping it"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_type_node(ty: Type) -> TypeNode {
+        TypeNode {
+            id: 0, // not used for these tests
+            canonical_name: "Test".to_string(),
+            is_used_as_error: false,
+            ffi_type: FfiType::RustBuffer(None),
+            ty,
+        }
+    }
+
+    fn record_type(name: &str) -> Type {
+        Type::Record {
+            namespace: "test".to_string(),
+            name: name.to_string(),
+            orig_name: name.to_string(),
+        }
+    }
+
+    fn stream_type() -> Type {
+        Type::Stream {
+            item_type: Box::new(Type::UInt8),
+        }
+    }
+
+    fn make_field(ty: Type) -> Field {
+        Field {
+            name: "value".to_string(),
+            orig_name: "value".to_string(),
+            ty: make_type_node(ty),
+            default: None,
+            docstring: None,
+        }
+    }
+
+    fn make_record(name: &str, field_ty: Type) -> TypeDefinition {
+        TypeDefinition::Record(Record {
+            fields_kind: FieldsKind::Named,
+            self_type: make_type_node(record_type(name)),
+            module_path: "test".to_string(),
+            orig_name: name.to_string(),
+            name: name.to_string(),
+            uniffi_trait_methods: UniffiTraitMethods::default(),
+            fields: vec![make_field(field_ty)],
+            constructors: vec![],
+            methods: vec![],
+            static_methods: vec![],
+            docstring: None,
+            recursive: false,
+        })
+    }
+
+    fn make_function(name: &str, argument_ty: Type) -> Function {
+        Function {
+            callable: Callable {
+                id: 0,
+                name: name.to_string(),
+                orig_name: name.to_string(),
+                async_data: None,
+                kind: CallableKind::Function,
+                arguments: vec![Argument {
+                    name: "arg".to_string(),
+                    orig_name: "arg".to_string(),
+                    ty: make_type_node(argument_ty),
+                    by_ref: false,
+                    optional: false,
+                    default: None,
+                }],
+                return_type: ReturnType { ty: None },
+                throws_type: ThrowsType { ty: None },
+                checksum: None,
+                ffi_func: RustFfiFunctionName(format!("uniffi_fn_{name}")),
+            },
+            module_path: "test".to_string(),
+            docstring: None,
+        }
+    }
+
+    fn make_namespace(
+        name: &str,
+        functions: Vec<Function>,
+        type_definitions: Vec<TypeDefinition>,
+    ) -> Namespace {
+        let ffi_name = |suffix: &str| RustFfiFunctionName(format!("ffi_{name}_{suffix}"));
+        Namespace {
+            name: name.to_string(),
+            crate_name: name.to_string(),
+            config_toml: None,
+            docstring: None,
+            functions,
+            type_definitions,
+            constants: vec![],
+            ffi_definitions: IndexSet::default(),
+            checksums: vec![],
+            ffi_rustbuffer_alloc: ffi_name("rustbuffer_alloc"),
+            ffi_rustbuffer_from_bytes: ffi_name("rustbuffer_from_bytes"),
+            ffi_rustbuffer_free: ffi_name("rustbuffer_free"),
+            ffi_rustbuffer_reserve: ffi_name("rustbuffer_reserve"),
+            ffi_uniffi_contract_version: ffi_name("uniffi_contract_version"),
+            correct_contract_version: "1".to_string(),
+        }
+    }
+
+    fn make_namespaces() -> IndexMap<String, Namespace> {
+        let test = make_namespace(
+            "test",
+            vec![
+                make_function("subscribe", stream_type()),
+                make_function("send_envelope", record_type("Envelope")),
+                make_function("send_plain", record_type("Plain")),
+            ],
+            vec![
+                make_record("Chunk", stream_type()),
+                // Uses `Chunk`, so it's removed once `Chunk` is.
+                make_record("Envelope", record_type("Chunk")),
+                make_record("Plain", Type::UInt32),
+                TypeDefinition::Stream(StreamType {
+                    item: make_type_node(Type::UInt8),
+                    self_type: make_type_node(stream_type()),
+                }),
+            ],
+        );
+        let other = make_namespace(
+            "other",
+            vec![
+                make_function("forward_envelope", record_type("Envelope")),
+                make_function("forward_plain", record_type("Plain")),
+            ],
+            vec![],
+        );
+        IndexMap::from([("test".to_string(), test), ("other".to_string(), other)])
+    }
+
+    fn function_names(namespace: &Namespace) -> Vec<&str> {
+        namespace
+            .functions
+            .iter()
+            .map(|func| func.callable.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn remove_items_using_unsupported_types() {
+        let namespaces = remove_unsupported_items(make_namespaces(), "Test", |_, ty| {
+            !matches!(ty, Type::Stream { .. })
+        });
+        assert_eq!(function_names(&namespaces["test"]), ["send_plain"]);
+        // Only `Plain` is left, the `Stream` type definition is removed too
+        assert_eq!(namespaces["test"].type_definitions.len(), 1);
+        // Types removed from one namespace are also removed where they're used as external types
+        assert_eq!(function_names(&namespaces["other"]), ["forward_plain"]);
+    }
+
+    #[test]
+    fn remove_items_using_external_types() {
+        let namespaces = remove_unsupported_items(make_namespaces(), "Test", |namespace, ty| {
+            ty.namespace().is_none() || ty.namespace() == Some(namespace)
+        });
+        assert_eq!(
+            function_names(&namespaces["test"]),
+            ["subscribe", "send_envelope", "send_plain"]
+        );
+        assert_eq!(namespaces["test"].type_definitions.len(), 4);
+        assert!(function_names(&namespaces["other"]).is_empty());
+    }
+}
@@ -25,6 +25,8 @@ pub struct Context {
     pub constructors:
         BTreeMap<(String, String), BTreeMap<String, uniffi_meta::ConstructorMetadata>>,
     pub methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::MethodMetadata>>,
+    pub static_methods:
+        BTreeMap<(String, String), BTreeMap<String, uniffi_meta::StaticMethodMetadata>>,
     pub trait_methods:
         BTreeMap<(String, String), BTreeMap<String, uniffi_meta::TraitMethodMetadata>>,
     pub uniffi_traits:
@@ -86,6 +88,25 @@ impl Context {
         }
     }
 
+    pub fn static_methods_for_type(
+        &self,
+        module_path: &str,
+        type_name: &str,
+    ) -> Result<Vec<StaticMethod>> {
+        let crate_name = module_path.split("::").next().unwrap();
+        let child_key = (crate_name.to_string(), type_name.to_string());
+
+        if let Some(static_methods) = self.static_methods.get(&child_key) {
+            static_methods
+                .values()
+                .cloned()
+                .map(|meth| meth.map_node(self))
+                .collect()
+        } else {
+            Ok(vec![])
+        }
+    }
+
     pub fn constructors_for_type(
         &self,
         module_path: &str,
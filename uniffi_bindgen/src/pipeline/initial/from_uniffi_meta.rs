@@ -28,10 +28,12 @@ pub struct UniffiMetaConverter {
     callback_interfaces: BTreeMap<String, BTreeMap<String, uniffi_meta::CallbackInterfaceMetadata>>,
     enums: BTreeMap<String, BTreeMap<String, uniffi_meta::EnumMetadata>>,
     custom_types: BTreeMap<String, BTreeMap<String, uniffi_meta::CustomTypeMetadata>>,
+    constants: BTreeMap<String, BTreeMap<String, uniffi_meta::ConstantMetadata>>,
     interfaces: BTreeMap<String, BTreeMap<String, uniffi_meta::ObjectMetadata>>,
     // Child items, keyed by module name + parent name
     constructors: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::ConstructorMetadata>>,
     methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::MethodMetadata>>,
+    static_methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::StaticMethodMetadata>>,
     trait_methods: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::TraitMethodMetadata>>,
     uniffi_traits: BTreeMap<(String, String), BTreeMap<String, uniffi_meta::UniffiTraitMetadata>>,
     trait_impls: BTreeMap<
@@ -87,6 +89,7 @@ impl UniffiMetaConverter {
                         name: namespace.name,
                         functions: vec![],
                         type_definitions: vec![],
+                        constants: vec![],
                     },
                 )?;
             }
@@ -156,6 +159,12 @@ impl UniffiMetaConverter {
                     }
                 }
             }
+            uniffi_meta::Metadata::Constant(constant) => {
+                self.constants
+                    .entry(module_path_to_crate_name(&constant.module_path))
+                    .or_default()
+                    .insert_unique(constant.name.clone(), constant)?;
+            }
             uniffi_meta::Metadata::Constructor(cons) => {
                 self.constructors
                     .entry((
@@ -174,6 +183,15 @@ impl UniffiMetaConverter {
                     .or_default()
                     .insert_unique(meth.name.clone(), meth)?;
             }
+            uniffi_meta::Metadata::StaticMethod(meth) => {
+                self.static_methods
+                    .entry((
+                        module_path_to_crate_name(&meth.module_path),
+                        meth.self_name.to_string(),
+                    ))
+                    .or_default()
+                    .insert_unique(meth.name.clone(), meth)?;
+            }
             uniffi_meta::Metadata::TraitMethod(meth) => {
                 self.trait_methods
                     .entry((
@@ -184,18 +202,10 @@ impl UniffiMetaConverter {
                     .insert_unique(meth.name.clone(), meth)?;
             }
             uniffi_meta::Metadata::UniffiTrait(ut) => {
-                let meth = match &ut {
-                    uniffi_meta::UniffiTraitMetadata::Debug { fmt } => fmt,
-                    uniffi_meta::UniffiTraitMetadata::Display { fmt } => fmt,
-                    uniffi_meta::UniffiTraitMetadata::Eq { eq, .. } => eq,
-                    uniffi_meta::UniffiTraitMetadata::Hash { hash } => hash,
-                    uniffi_meta::UniffiTraitMetadata::Ord { cmp } => cmp,
-                };
-
                 self.uniffi_traits
                     .entry((
-                        module_path_to_crate_name(&meth.module_path),
-                        meth.self_name.to_string(),
+                        module_path_to_crate_name(ut.module_path()),
+                        ut.self_name().to_string(),
                     ))
                     .or_default()
                     .insert_unique(ut.name().to_string(), ut)?;
@@ -260,6 +270,7 @@ impl UniffiMetaConverter {
             module_path_map: self.module_path_map.clone(),
             constructors: self.constructors,
             methods: self.methods,
+            static_methods: self.static_methods,
             trait_methods: self.trait_methods,
             uniffi_traits: self.uniffi_traits,
             trait_impls: self.trait_impls,
@@ -319,6 +330,12 @@ impl UniffiMetaConverter {
                     .push(TypeDefinition::Custom(custom.map_node(&context)?));
             }
         }
+        for (module_path, list) in self.constants {
+            let namespace = get_namespace(&self.module_path_map, &mut root, &module_path)?;
+            for constant in list.into_values() {
+                namespace.constants.push(constant.map_node(&context)?);
+            }
+        }
         // Collect child items for interfaces and callback interfaces
         for (module_path, list) in self.interfaces {
             let namespace = get_namespace(&self.module_path_map, &mut root, &module_path)?;
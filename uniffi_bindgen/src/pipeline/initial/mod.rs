@@ -38,6 +38,7 @@ impl Root {
         if let Some(crate_name) = crate_name {
             all_metadata.retain(|meta| meta.module_path().split("::").next() == Some(&crate_name));
         }
+        uniffi_meta::resolve_constant_literals(&mut all_metadata)?;
 
         let mut udl_to_load = vec![];
 
@@ -143,8 +144,13 @@ impl Root {
                         cons.clone(),
                     )));
                 }
-                // Note: UDL-based callbacks don't have checksum functions, don't set the
-                // checksum for those.
+                uniffi_meta::Metadata::TraitMethod(meth) if meth.checksum.is_none() => {
+                    let method_object = interface::Method::from_metadata(
+                        meth.clone().into(),
+                        uniffi_meta::Type::UInt8,
+                    );
+                    meth.checksum = Some(uniffi_meta::checksum(&method_object));
+                }
                 _ => (),
             }
             metadata_converter.add_metadata_item(meta)?;
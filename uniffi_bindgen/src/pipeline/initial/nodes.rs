@@ -28,6 +28,7 @@ pub struct Namespace {
     pub docstring: Option<String>,
     pub functions: Vec<Function>,
     pub type_definitions: Vec<TypeDefinition>,
+    pub constants: Vec<Constant>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
@@ -35,6 +36,7 @@ pub struct Namespace {
 pub struct Function {
     #[map_node(self.orig_name.unwrap_or_else(|| self.name.clone()))]
     pub orig_name: String,
+    pub module_path: String,
     #[map_node(context.new_callable_id())]
     pub id: u64,
     pub name: String,
@@ -74,6 +76,25 @@ pub struct Constructor {
 #[map_node(from(uniffi_meta::MethodMetadata))]
 #[map_node(from(uniffi_meta::TraitMethodMetadata))]
 pub struct Method {
+    #[map_node(self.orig_name.unwrap_or_else(|| self.name.clone()))]
+    pub orig_name: String,
+    #[map_node(context.new_callable_id())]
+    pub id: u64,
+    pub name: String,
+    pub is_async: bool,
+    pub inputs: Vec<Argument>,
+    pub return_type: Option<Type>,
+    pub throws: Option<Type>,
+    /// Trait method with a default implementation in Rust
+    pub has_default: bool,
+    pub checksum: Option<u16>,
+    pub docstring: Option<String>,
+}
+
+/// Associated function without a receiver
+#[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
+#[map_node(from(uniffi_meta::StaticMethodMetadata))]
+pub struct StaticMethod {
     #[map_node(self.orig_name.unwrap_or_else(|| self.name.clone()))]
     pub orig_name: String,
     #[map_node(context.new_callable_id())]
@@ -101,6 +122,7 @@ pub struct TraitMethod {
     pub inputs: Vec<Argument>,
     pub return_type: Option<Type>,
     pub throws: Option<Type>,
+    pub has_default: bool,
     pub checksum: Option<u16>,
     pub docstring: Option<String>,
 }
@@ -135,6 +157,7 @@ pub enum DefaultValue {
 pub enum Literal {
     Boolean(bool),
     String(String),
+    Char(char),
     // Integers are represented as the widest representation we can.
     // Number formatting vary with language and radix, so we avoid a lot of parsing and
     // formatting duplication by using only signed and unsigned variants.
@@ -151,14 +174,35 @@ pub enum Literal {
     EmptySet,
     None,
     Some { inner: Box<DefaultValue> },
+    Record { fields: Vec<FieldLiteral>, ty: Type },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
+#[map_node(from(uniffi_meta::FieldLiteralMetadata))]
+pub struct FieldLiteral {
+    pub name: String,
+    pub ty: Type,
+    pub value: Literal,
+}
+
+/// An exported `const` or `static` item
+#[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
+#[map_node(from(uniffi_meta::ConstantMetadata))]
+pub struct Constant {
+    pub name: String,
+    pub ty: Type,
+    pub value: Literal,
+    pub docstring: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
 #[map_node(from(uniffi_meta::RecordMetadata))]
 #[map_node(types::map_record)]
 pub struct Record {
+    pub module_path: String,
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<StaticMethod>,
     pub uniffi_traits: Vec<UniffiTrait>,
     pub name: String,
     pub orig_name: String,
@@ -181,8 +225,10 @@ pub struct Field {
 #[map_node(from(uniffi_meta::EnumMetadata))]
 #[map_node(types::map_enum)]
 pub struct Enum {
+    pub module_path: String,
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<StaticMethod>,
     pub uniffi_traits: Vec<UniffiTrait>,
     pub name: String,
     pub orig_name: String,
@@ -207,8 +253,10 @@ pub struct Variant {
 #[map_node(from(uniffi_meta::ObjectMetadata))]
 #[map_node(types::map_interface)]
 pub struct Interface {
+    pub module_path: String,
     pub constructors: Vec<Constructor>,
     pub methods: Vec<Method>,
+    pub static_methods: Vec<StaticMethod>,
     pub uniffi_traits: Vec<UniffiTrait>,
     pub trait_impls: Vec<ObjectTraitImpl>,
     pub name: String,
@@ -221,6 +269,7 @@ pub struct Interface {
 #[map_node(from(uniffi_meta::CallbackInterfaceMetadata))]
 #[map_node(types::map_callback_interface)]
 pub struct CallbackInterface {
+    pub module_path: String,
     pub methods: Vec<Method>,
     pub name: String,
     pub orig_name: String,
@@ -236,6 +285,10 @@ pub enum UniffiTrait {
     Eq { eq: Method, ne: Method },
     Hash { hash: Method },
     Ord { cmp: Method },
+    Clone { clone: Method },
+    Default { default: StaticMethod },
+    PartialOrd { partial_cmp: Method },
+    FromStr { from_str: StaticMethod },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Node, MapNode)]
@@ -268,9 +321,12 @@ pub enum Type {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     Boolean,
+    Char,
     String,
     Bytes,
     Timestamp,
@@ -284,12 +340,31 @@ pub enum Type {
     Sequence {
         inner_type: Box<Type>,
     },
+    Array {
+        inner_type: Box<Type>,
+        len: u32,
+    },
     Map {
         key_type: Box<Type>,
         value_type: Box<Type>,
+        ordered: bool,
     },
     Set {
         inner_type: Box<Type>,
+        ordered: bool,
+    },
+    Tuple {
+        inner_types: Vec<Type>,
+    },
+    Stream {
+        item_type: Box<Type>,
+    },
+    Iterator {
+        item_type: Box<Type>,
+    },
+    Closure {
+        argument_types: Vec<Type>,
+        return_type: Option<Box<Type>>,
     },
     // User defined types in the API
     Interface {
@@ -14,9 +14,12 @@ pub fn map_type(ty: uniffi_meta::Type, context: &Context) -> Result<Type> {
         uniffi_meta::Type::Int32 => Type::Int32,
         uniffi_meta::Type::UInt64 => Type::UInt64,
         uniffi_meta::Type::Int64 => Type::Int64,
+        uniffi_meta::Type::UInt128 => Type::UInt128,
+        uniffi_meta::Type::Int128 => Type::Int128,
         uniffi_meta::Type::Float32 => Type::Float32,
         uniffi_meta::Type::Float64 => Type::Float64,
         uniffi_meta::Type::Boolean => Type::Boolean,
+        uniffi_meta::Type::Char => Type::Char,
         uniffi_meta::Type::String => Type::String,
         uniffi_meta::Type::Bytes => Type::Bytes,
         uniffi_meta::Type::Timestamp => Type::Timestamp,
@@ -30,15 +33,41 @@ pub fn map_type(ty: uniffi_meta::Type, context: &Context) -> Result<Type> {
         uniffi_meta::Type::Sequence { inner_type } => Type::Sequence {
             inner_type: inner_type.map_node(context)?,
         },
+        uniffi_meta::Type::Array { inner_type, len } => Type::Array {
+            inner_type: inner_type.map_node(context)?,
+            len,
+        },
         uniffi_meta::Type::Map {
             key_type,
             value_type,
+            ordered,
         } => Type::Map {
             key_type: key_type.map_node(context)?,
             value_type: value_type.map_node(context)?,
+            ordered,
         },
-        uniffi_meta::Type::Set { inner_type } => Type::Set {
+        uniffi_meta::Type::Set {
+            inner_type,
+            ordered,
+        } => Type::Set {
             inner_type: inner_type.map_node(context)?,
+            ordered,
+        },
+        uniffi_meta::Type::Tuple { inner_types } => Type::Tuple {
+            inner_types: inner_types.map_node(context)?,
+        },
+        uniffi_meta::Type::Stream { item_type } => Type::Stream {
+            item_type: item_type.map_node(context)?,
+        },
+        uniffi_meta::Type::Iterator { item_type } => Type::Iterator {
+            item_type: item_type.map_node(context)?,
+        },
+        uniffi_meta::Type::Closure {
+            argument_types,
+            return_type,
+        } => Type::Closure {
+            argument_types: argument_types.map_node(context)?,
+            return_type: return_type.map_node(context)?,
         },
         uniffi_meta::Type::Object {
             module_path,
@@ -80,8 +109,10 @@ pub fn map_type(ty: uniffi_meta::Type, context: &Context) -> Result<Type> {
 
 pub fn map_record(input: uniffi_meta::RecordMetadata, context: &Context) -> Result<Record> {
     Ok(Record {
+        module_path: input.module_path.clone(),
         constructors: context.constructors_for_type(&input.module_path, &input.name)?,
         methods: context.methods_for_type(&input.module_path, &input.name)?,
+        static_methods: context.static_methods_for_type(&input.module_path, &input.name)?,
         uniffi_traits: context.uniffi_traits_for_type(&input.module_path, &input.name)?,
         orig_name: input.orig_name.unwrap_or_else(|| input.name.clone()),
         name: input.name,
@@ -92,8 +123,10 @@ pub fn map_record(input: uniffi_meta::RecordMetadata, context: &Context) -> Resu
 
 pub fn map_enum(input: uniffi_meta::EnumMetadata, context: &Context) -> Result<Enum> {
     Ok(Enum {
+        module_path: input.module_path.clone(),
         constructors: context.constructors_for_type(&input.module_path, &input.name)?,
         methods: context.methods_for_type(&input.module_path, &input.name)?,
+        static_methods: context.static_methods_for_type(&input.module_path, &input.name)?,
         uniffi_traits: context.uniffi_traits_for_type(&input.module_path, &input.name)?,
         orig_name: input.orig_name.unwrap_or_else(|| input.name.clone()),
         name: input.name,
@@ -106,8 +139,10 @@ pub fn map_enum(input: uniffi_meta::EnumMetadata, context: &Context) -> Result<E
 
 pub fn map_interface(input: uniffi_meta::ObjectMetadata, context: &Context) -> Result<Interface> {
     Ok(Interface {
+        module_path: input.module_path.clone(),
         constructors: context.constructors_for_type(&input.module_path, &input.name)?,
         methods: context.methods_for_type(&input.module_path, &input.name)?,
+        static_methods: context.static_methods_for_type(&input.module_path, &input.name)?,
         uniffi_traits: context.uniffi_traits_for_type(&input.module_path, &input.name)?,
         trait_impls: context.trait_impls_for_type(&input.module_path, &input.name)?,
         orig_name: input.orig_name.unwrap_or_else(|| input.name.clone()),
@@ -122,6 +157,7 @@ pub fn map_callback_interface(
     context: &Context,
 ) -> Result<CallbackInterface> {
     Ok(CallbackInterface {
+        module_path: input.module_path.clone(),
         methods: context.methods_for_type(&input.module_path, &input.name)?,
         // Renaming callback interfaces is not supported yet -- just copy name.
         orig_name: input.name.clone(),
@@ -54,9 +54,12 @@ mod filters {
             Type::UInt32 => "u32".into(),
             Type::Int64 => "i64".into(),
             Type::UInt64 => "u64".into(),
+            Type::Int128 => "i128".into(),
+            Type::UInt128 => "u128".into(),
             Type::Float32 => "f32".into(),
             Type::Float64 => "f64".into(),
             Type::Boolean => "bool".into(),
+            Type::Char => "char".into(),
             Type::String => "::std::string::String".into(),
             Type::Bytes => "::std::vec::Vec<u8>".into(),
             Type::Timestamp => "::std::time::SystemTime".into(),
@@ -75,20 +78,59 @@ mod filters {
             Type::Sequence { inner_type } => {
                 format!("std::vec::Vec<{}>", type_rs_inner(inner_type)?)
             }
+            Type::Array { inner_type, len } => {
+                format!("[{}; {len}]", type_rs_inner(inner_type)?)
+            }
             Type::Map {
                 key_type,
                 value_type,
+                ordered,
             } => format!(
-                "::std::collections::HashMap<{}, {}>",
+                "::std::collections::{}<{}, {}>",
+                if *ordered { "BTreeMap" } else { "HashMap" },
                 type_rs_inner(key_type)?,
                 type_rs_inner(value_type)?
             ),
-            Type::Set { inner_type } => {
+            Type::Set {
+                inner_type,
+                ordered,
+            } => {
                 format!(
-                    "::std::collections::HashSet<{}>",
+                    "::std::collections::{}<{}>",
+                    if *ordered { "BTreeSet" } else { "HashSet" },
                     type_rs_inner(inner_type)?
                 )
             }
+            Type::Tuple { inner_types } => {
+                let inner_types = inner_types
+                    .iter()
+                    .map(type_rs_inner)
+                    .collect::<Result<Vec<_>, _>>()?;
+                format!("({},)", inner_types.join(", "))
+            }
+            Type::Stream { item_type } => {
+                format!("::uniffi::RustStream<{}>", type_rs_inner(item_type)?)
+            }
+            Type::Iterator { item_type } => {
+                format!("::uniffi::Iter<{}>", type_rs_inner(item_type)?)
+            }
+            Type::Closure {
+                argument_types,
+                return_type,
+            } => {
+                let argument_types = argument_types
+                    .iter()
+                    .map(type_rs_inner)
+                    .collect::<Result<Vec<_>, _>>()?;
+                let return_type = match return_type {
+                    Some(t) => type_rs_inner(t)?,
+                    None => "()".to_owned(),
+                };
+                format!(
+                    "::std::boxed::Box<dyn Fn({}) -> {return_type} + Send + Sync>",
+                    argument_types.join(", ")
+                )
+            }
             Type::Custom { name, .. } => format!("r#{name}"),
         })
     }
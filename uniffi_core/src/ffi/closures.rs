@@ -0,0 +1,185 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! This module implements closures that are passed across the FFI.
+//!
+//! `Arc<dyn Fn(A, B) -> R + Send + Sync>` and `Box<dyn Fn(A, B) -> R + Send + Sync>` can be
+//! passed in either direction.  Unlike callback interfaces, there's no FFI code generated for each
+//! closure type.  Instead, closures are type-erased and the arguments and return value are
+//! serialized into `RustBuffer`s:
+//!
+//!   * Rust closures are lowered as a [Handle] to an [ErasedClosure].  The foreign code calls
+//!     them with the `rust_closure_call` scaffolding function, passing the serialized arguments
+//!     and getting back the serialized return value.  `rust_closure_clone` and
+//!     `rust_closure_free` manage the handle.
+//!   * Foreign closures are lowered as foreign handles.  Like for callback interfaces, the foreign
+//!     code registers a VTable that Rust uses to call, clone and free them.  There's a single
+//!     [ForeignClosureVTable] for each component, which the `setup_scaffolding!` macro stores and
+//!     exposes with [HasForeignClosureVTable].
+//!
+//! Callback interface VTables are generated per trait by `#[uniffi::export(callback_interface)]`,
+//! which is also where the init function that the foreign code calls is defined.  Closure types
+//! don't have a declaration like that, they're just used in signatures, so there's nowhere to
+//! generate a VTable for each closure type.  Instead, closures use a single VTable that follows
+//! the callback interface conventions: `uniffi_free` and `uniffi_clone` have the same signatures,
+//! `call` returns its result through an out pointer with a [RustCallStatus], and the VTable is
+//! stored in a [crate::UniffiForeignPointerCell].
+//!
+//! Closures can't return errors.  If the foreign closure throws, then the Rust code panics with an
+//! [UnexpectedUniFFICallbackError], like for callback interface methods that don't return a
+//! `Result`.
+
+use std::{mem::ManuallyDrop, sync::Arc};
+
+use anyhow::bail;
+use bytes::Buf;
+
+use crate::{
+    FfiConverter, Handle, Result, RustBuffer, RustCallStatus, RustCallStatusCode,
+    UnexpectedUniFFICallbackError,
+};
+
+/// VTable for foreign closures
+///
+/// This is registered by the foreign code for each component.
+#[repr(C)]
+pub struct ForeignClosureVTable {
+    pub uniffi_free: extern "C" fn(handle: u64),
+    pub uniffi_clone: extern "C" fn(handle: u64) -> u64,
+    pub call: extern "C" fn(
+        handle: u64,
+        args: RustBuffer,
+        out_return: &mut RustBuffer,
+        call_status: &mut RustCallStatus,
+    ),
+}
+
+/// Get the foreign closure VTable for a component
+///
+/// This is implemented for each component's `UniFfiTag` by `setup_scaffolding!`.
+pub trait HasForeignClosureVTable {
+    fn foreign_closure_vtable() -> &'static ForeignClosureVTable;
+}
+
+/// Type-erased closure, with the arguments and return value serialized into `RustBuffer`s
+#[doc(hidden)]
+pub trait ErasedClosure: Send + Sync {
+    fn call(&self, args: RustBuffer) -> Result<RustBuffer>;
+}
+
+/// Rust closure, which reads its arguments from a buffer and returns its serialized return value
+struct RustClosure<F>(F);
+
+impl<F> ErasedClosure for RustClosure<F>
+where
+    F: Fn(&mut &[u8]) -> Result<RustBuffer> + Send + Sync,
+{
+    fn call(&self, args: RustBuffer) -> Result<RustBuffer> {
+        let vec = args.destroy_into_vec();
+        let mut buf = vec.as_slice();
+        let return_value = (self.0)(&mut buf)?;
+        match Buf::remaining(&buf) {
+            0 => Ok(return_value),
+            n => bail!("junk data left in buffer after reading closure arguments (count: {n})"),
+        }
+    }
+}
+
+/// Closure implemented by the foreign code
+struct ForeignClosure {
+    handle: u64,
+    vtable: &'static ForeignClosureVTable,
+}
+
+impl ErasedClosure for ForeignClosure {
+    fn call(&self, args: RustBuffer) -> Result<RustBuffer> {
+        let mut call_status = RustCallStatus::default();
+        let mut return_value = RustBuffer::default();
+        (self.vtable.call)(self.handle, args, &mut return_value, &mut call_status);
+        match call_status.code {
+            RustCallStatusCode::Success => Ok(return_value),
+            RustCallStatusCode::UnexpectedError => {
+                let message = <String as FfiConverter<crate::UniFfiTag>>::try_lift(
+                    ManuallyDrop::into_inner(call_status.error_buf),
+                )
+                .unwrap_or_else(|e| format!("(Error lifting message: {e}"));
+                Err(UnexpectedUniFFICallbackError::new(message).into())
+            }
+            _ => {
+                ManuallyDrop::into_inner(call_status.error_buf).destroy();
+                Err(UnexpectedUniFFICallbackError::new("Foreign closure returned an error").into())
+            }
+        }
+    }
+}
+
+impl Drop for ForeignClosure {
+    fn drop(&mut self) {
+        (self.vtable.uniffi_free)(self.handle);
+    }
+}
+
+/// Lower a Rust closure
+///
+/// `f` reads the arguments from a buffer, calls the closure and returns the serialized return
+/// value.
+#[doc(hidden)]
+pub fn lower_closure(
+    f: impl Fn(&mut &[u8]) -> Result<RustBuffer> + Send + Sync + 'static,
+) -> Handle {
+    let closure: Arc<dyn ErasedClosure> = Arc::new(RustClosure(f));
+    Handle::from_arc(Arc::new(closure))
+}
+
+/// Lift a closure, which can either be a Rust closure that was previously lowered or a foreign
+/// closure.
+///
+/// # Safety
+///
+/// The handle must either be a foreign handle or one returned by [lower_closure].
+#[doc(hidden)]
+pub unsafe fn lift_closure<UT: HasForeignClosureVTable>(handle: Handle) -> Arc<dyn ErasedClosure> {
+    if handle.is_foreign() {
+        Arc::new(ForeignClosure {
+            handle: handle.as_raw(),
+            vtable: UT::foreign_closure_vtable(),
+        })
+    } else {
+        Arc::unwrap_or_clone(Handle::into_arc::<Arc<dyn ErasedClosure>>(handle))
+    }
+}
+
+// === Public FFI API ===
+
+/// Call a Rust closure
+///
+/// `args` contains the serialized arguments, the return value is the serialized return value.
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_closure_free]
+pub unsafe fn rust_closure_call(handle: Handle, args: RustBuffer) -> Result<RustBuffer> {
+    trace!("rust_closure_call: {handle:?}");
+    Handle::into_arc_borrowed::<Arc<dyn ErasedClosure>>(handle).call(args)
+}
+
+/// Clone a Rust closure handle
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_closure_free]
+pub unsafe fn rust_closure_clone(handle: Handle) -> Handle {
+    trace!("rust_closure_clone: {handle:?}");
+    handle.clone_arc_handle::<Arc<dyn ErasedClosure>>()
+}
+
+/// Free a Rust closure
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_closure_free]
+pub unsafe fn rust_closure_free(handle: Handle) {
+    trace!("rust_closure_free: {handle:?}");
+    drop(Handle::into_arc::<Arc<dyn ErasedClosure>>(handle))
+}
@@ -5,6 +5,7 @@
 //! Types that can cross the FFI boundary.
 
 pub mod callbackinterface;
+pub mod closures;
 pub mod ffidefault;
 #[cfg(feature = "scaffolding-ffi-buffer-fns")]
 pub mod ffiserialize;
@@ -15,8 +16,11 @@ pub mod handle;
 pub mod rustbuffer;
 pub mod rustcalls;
 pub mod rustfuture;
+pub mod rustiter;
+pub mod ruststream;
 
 pub use callbackinterface::*;
+pub use closures::*;
 pub use ffidefault::FfiDefault;
 #[cfg(feature = "scaffolding-ffi-buffer-fns")]
 pub use ffiserialize::FfiSerialize;
@@ -27,3 +31,5 @@ pub use handle::*;
 pub use rustbuffer::*;
 pub use rustcalls::*;
 pub use rustfuture::*;
+pub use rustiter::*;
+pub use ruststream::*;
@@ -0,0 +1,20 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use super::UniffiCompatibleFuture;
+
+/// Adapts the futures of exported async functions to an async runtime
+///
+/// Use this with `#[uniffi::export(async_runtime = path::to::Adapter)]`.  UniFFI passes each
+/// future through [AsyncRuntimeAdapter::wrap] before handing it to [super::rust_future_new].  The
+/// wrapped future is still polled by the foreign executor, so adapters usually enter a runtime
+/// context while polling the inner future, like `async_compat::Compat` does for tokio.
+///
+/// Futures from runtimes that drive their own reactor, like `async-std` and `smol`, can be
+/// polled from any executor and don't need an adapter.
+pub trait AsyncRuntimeAdapter {
+    fn wrap<F, T>(future: F) -> impl UniffiCompatibleFuture<T>
+    where
+        F: UniffiCompatibleFuture<T>;
+}
@@ -83,7 +83,7 @@ use crate::{try_rust_call, FfiDefault, LiftArgsError, RustCallResult, RustCallSt
 use std::{
     future, panic,
     pin::{pin, Pin},
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, TryLockError},
     task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
 };
 
@@ -191,7 +191,7 @@ impl<FfiType, Callback> RustFuture<FfiType, Callback>
 where
     Callback: RustFutureCallback,
 {
-    pub fn new<F, T, UT>(future: F, _tag: UT) -> Self
+    pub fn from_future<F, T, UT>(future: F) -> Self
     where
         F: UniffiCompatibleFuture<Result<T, LiftArgsError>> + 'static,
         T: FutureLowerReturn<UT, ReturnType = FfiType>,
@@ -207,7 +207,15 @@ where
         let ready = cancelled || {
             let mut locked = self.future.lock().unwrap();
             let waker = Arc::clone(&self).into_waker();
-            locked.poll(&mut Context::from_waker(&waker))
+            let ready = locked.poll(&mut Context::from_waker(&waker));
+            // `cancel()` doesn't wait for a poll that's in progress, so drop the future here if
+            // it was cancelled in the meantime.
+            if self.is_cancelled() {
+                locked.free();
+                true
+            } else {
+                ready
+            }
         };
         if ready {
             trace!("RustFuture::poll is ready (cancelled: {cancelled})");
@@ -223,6 +231,16 @@ where
 
     pub fn cancel(&self) {
         self.scheduler.cancel();
+        // Drop the inner future now, rather than waiting for `free()`.  This way any resources it
+        // holds are released as soon as the foreign task is cancelled.
+        //
+        // Don't block if `poll()` holds the lock, it drops the future once it sees the
+        // cancellation.  A poisoned lock means the future panicked, it can still be dropped.
+        match self.future.try_lock() {
+            Ok(mut locked) => locked.free(),
+            Err(TryLockError::Poisoned(e)) => e.into_inner().free(),
+            Err(TryLockError::WouldBlock) => (),
+        }
     }
 
     pub fn complete(&self, call_status: &mut RustCallStatus) -> FfiType
@@ -4,6 +4,7 @@
 
 use std::{future::Future, sync::Arc};
 
+mod adapter;
 mod future;
 mod scheduler;
 
@@ -11,6 +12,7 @@ mod scheduler;
 mod tests;
 
 use crate::{FfiDefault, Handle, LiftArgsError, LowerReturn, RustCallStatus};
+pub use adapter::AsyncRuntimeAdapter;
 pub(crate) use future::RustFuture;
 pub use scheduler::{RustFutureCallback, Scheduler};
 
@@ -111,14 +113,25 @@ impl<UT, LR> FutureLowerReturn<UT> for LR where LR: LowerReturn<UT> {}
 ///
 /// For each exported async function, UniFFI will create a scaffolding function that uses this to
 /// create the [Handle] to pass to the foreign code.
-pub fn rust_future_new<F, T, UT>(future: F, tag: UT) -> Handle
+pub fn rust_future_new<F, T, UT>(future: F, _tag: UT) -> Handle
 where
     F: UniffiCompatibleFuture<Result<T, LiftArgsError>> + 'static,
     T: FutureLowerReturn<UT> + 'static,
 {
-    let rust_future = Arc::new(RustFuture::<_, RustFutureContinuationBoundCallback>::new(
-        future, tag,
-    ));
+    rust_future_new_with_tag_type::<F, T, UT>(future)
+}
+
+/// Create a new [Handle] for a Rust future, when we only have the tag type rather than a value
+///
+/// This is used by generic code, like [crate::RustStream].
+pub(crate) fn rust_future_new_with_tag_type<F, T, UT>(future: F) -> Handle
+where
+    F: UniffiCompatibleFuture<Result<T, LiftArgsError>> + 'static,
+    T: FutureLowerReturn<UT> + 'static,
+{
+    let rust_future = Arc::new(
+        RustFuture::<_, RustFutureContinuationBoundCallback>::from_future::<F, T, UT>(future),
+    );
     let handle = Handle::from_arc(rust_future);
     trace_and_return!(handle, "rust_future_new: {handle:?}")
 }
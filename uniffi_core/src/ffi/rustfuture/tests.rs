@@ -4,7 +4,7 @@ use std::{
     mem::ManuallyDrop,
     panic,
     pin::Pin,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, Weak},
     task::{Context, Poll, Waker},
 };
 
@@ -78,7 +78,8 @@ fn channel() -> (Sender, Arc<RustFuture<RustBuffer>>) {
         result: None,
         waker: None,
     }));
-    let rust_future = RustFuture::new(Box::pin(Receiver(channel.clone())), crate::UniFfiTag);
+    let rust_future =
+        RustFuture::from_future::<_, _, crate::UniFfiTag>(Box::pin(Receiver(channel.clone())));
     (Sender(channel), Arc::new(rust_future))
 }
 
@@ -201,6 +202,50 @@ fn test_cancel() {
     assert_eq!(call_status.code, RustCallStatusCode::Cancelled);
 }
 
+// Once `cancel` is called, the inner future should be released, without waiting for `free`
+#[test]
+fn test_cancel_releases_future() {
+    let (sender, rust_future) = channel();
+    let channel_weak = Arc::downgrade(&sender.0);
+    drop(sender);
+
+    rust_future.cancel();
+    assert!(channel_weak.upgrade().is_none());
+
+    let (_, call_status) = complete(rust_future);
+    assert_eq!(call_status.code, RustCallStatusCode::Cancelled);
+}
+
+// `cancel` can be called while the future is being polled, for example from another thread.  It
+// shouldn't wait for the poll, the future is released once the poll sees the cancellation instead.
+#[test]
+fn test_cancel_during_poll() {
+    let rust_future_cell = Arc::new(OnceCell::<Weak<RustFuture<RustBuffer>>>::new());
+    let tracker = Arc::new(());
+    let tracker_weak = Arc::downgrade(&tracker);
+    let future = std::future::poll_fn({
+        let rust_future_cell = rust_future_cell.clone();
+        move |_| {
+            let _tracker = &tracker;
+            // Cancel on the polling thread, which would deadlock if `cancel` waited for the lock
+            rust_future_cell.get().unwrap().upgrade().unwrap().cancel();
+            Poll::<Result<String, LiftArgsError>>::Pending
+        }
+    });
+    let rust_future: Arc<RustFuture<RustBuffer>> =
+        Arc::new(RustFuture::from_future::<_, _, crate::UniFfiTag>(Box::pin(
+            future,
+        )));
+    rust_future_cell.set(Arc::downgrade(&rust_future)).unwrap();
+
+    let continuation_result = poll(&rust_future);
+    assert_eq!(continuation_result.get(), Some(&RustFuturePoll::Ready));
+    assert!(tracker_weak.upgrade().is_none());
+
+    let (_, call_status) = complete(rust_future);
+    assert_eq!(call_status.code, RustCallStatusCode::Cancelled);
+}
+
 // Once `free` is called, the inner future should be released, even if wakers still hold a
 // reference to the RustFuture
 #[test]
@@ -252,7 +297,9 @@ fn test_wake_during_poll() {
         }
     });
     let rust_future: Arc<RustFuture<RustBuffer>> =
-        Arc::new(RustFuture::new(Box::pin(future), crate::UniFfiTag));
+        Arc::new(RustFuture::from_future::<_, _, crate::UniFfiTag>(Box::pin(
+            future,
+        )));
     let continuation_result = poll(&rust_future);
     // The continuation function should called immediately
     assert_eq!(continuation_result.get(), Some(&RustFuturePoll::Wake));
@@ -0,0 +1,102 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! This module implements Rust iterators that are consumed by the foreign code.
+//!
+//!   * Exported functions that return `impl Iterator<Item = T>`, `Box<dyn Iterator<Item = T> + Send>`
+//!     or [Iter] wrap the iterator in an [Iter], which is lowered as a [Handle].
+//!   * The foreign code calls the `rust_iter_next_chunk` scaffolding function to fetch the next
+//!     items.  This returns a `RustBuffer` with a serialized `Vec<T>` of up to `max_items` items,
+//!     batching items together to reduce the number of FFI calls.  An empty `Vec` signals the end
+//!     of the iterator.
+//!   * When the foreign code is done with the iterator, either because it was exhausted or because
+//!     the foreign iterator was garbage collected, it calls `rust_iter_free` to drop the Rust
+//!     iterator.
+
+use std::{
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use crate::{Handle, Lower, RustBuffer};
+
+/// Iterator returned by an exported function
+///
+/// The scaffolding code creates these from `impl Iterator<Item = T>` and
+/// `Box<dyn Iterator<Item = T> + Send>` return values.  Exported functions can also return an
+/// `Iter<T>` directly.
+pub struct Iter<T> {
+    iter: Box<dyn Iterator<Item = T> + Send>,
+}
+
+impl<T> Iter<T> {
+    pub fn new(iter: impl Iterator<Item = T> + Send + 'static) -> Self {
+        Self {
+            iter: Box::new(iter),
+        }
+    }
+
+    /// Convert this iterator into a handle to pass to the foreign code
+    pub(crate) fn into_handle<UT>(self) -> Handle
+    where
+        T: Lower<UT> + 'static,
+        UT: 'static,
+    {
+        let state: Arc<dyn RustIterNextChunk> = Arc::new(RustIterState::<T, UT> {
+            iter: Mutex::new(self.iter),
+            _tag: PhantomData,
+        });
+        Handle::from_arc(Arc::new(state))
+    }
+}
+
+/// Type-erased iterator, this is what's stored behind the handle.
+trait RustIterNextChunk: Send + Sync {
+    /// Lower the next items into a `RustBuffer`
+    fn next_chunk(&self, max_items: u32) -> RustBuffer;
+}
+
+struct RustIterState<T, UT> {
+    iter: Mutex<Box<dyn Iterator<Item = T> + Send>>,
+    _tag: PhantomData<fn() -> UT>,
+}
+
+impl<T, UT> RustIterNextChunk for RustIterState<T, UT>
+where
+    T: Lower<UT> + 'static,
+    UT: 'static,
+{
+    fn next_chunk(&self, max_items: u32) -> RustBuffer {
+        // Always return at least 1 item, otherwise the foreign code can't tell a 0 `max_items`
+        // value apart from the end of the iterator.
+        let max_items = max_items.max(1) as usize;
+        let chunk: Vec<T> = self.iter.lock().unwrap().by_ref().take(max_items).collect();
+        <Vec<T> as Lower<UT>>::lower(chunk)
+    }
+}
+
+// === Public FFI API ===
+
+/// Get the next chunk of items from a Rust iterator
+///
+/// Returns a `RustBuffer` containing a serialized `Vec<T>` with up to `max_items` items.  An empty
+/// `Vec` signals the end of the iterator.
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_iter_free]
+pub unsafe fn rust_iter_next_chunk(handle: Handle, max_items: u32) -> RustBuffer {
+    trace!("rust_iter_next_chunk: {handle:?}");
+    Handle::into_arc_borrowed::<Arc<dyn RustIterNextChunk>>(handle).next_chunk(max_items)
+}
+
+/// Free a Rust iterator
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_iter_free]
+pub unsafe fn rust_iter_free(handle: Handle) {
+    trace!("rust_iter_free: {handle:?}");
+    drop(Handle::into_arc::<Arc<dyn RustIterNextChunk>>(handle))
+}
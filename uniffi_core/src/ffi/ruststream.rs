@@ -0,0 +1,136 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! This module implements Rust streams that are consumed by the foreign code.
+//!
+//! Streams are built on top of [crate::RustFuture]:
+//!
+//!   * Exported functions that return `impl Stream<Item = T>` wrap the stream in a [RustStream],
+//!     which is lowered as a [Handle].
+//!   * The foreign code calls the `rust_stream_next` scaffolding function, which creates a
+//!     [crate::RustFuture] for the next item.  That future is driven with the regular
+//!     `rust_future_*` functions for `RustBuffer` returns.  Its output is an `Option<T>`, where
+//!     `None` signals the end of the stream.
+//!   * When the foreign code is done with the stream, either because it was exhausted or because
+//!     the consuming task was cancelled, it calls `rust_stream_free` to drop the Rust stream.
+//!
+//! Any `next()` futures that are still alive hold a reference to the stream, so it's only dropped
+//! once those are freed as well.
+//!
+//! Functions exported with an `async_runtime` create their streams with
+//! [RustStream::with_runtime], which passes each `next()` future through the runtime adapter,
+//! the same as the futures returned by async functions.
+
+use std::{
+    future::poll_fn,
+    marker::PhantomData,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use futures_core::Stream;
+
+use crate::{
+    rustfuture::rust_future_new_with_tag_type, FutureLowerReturn, Handle, UniffiCompatibleFuture,
+};
+
+/// Future for the next item of a [RustStream]
+pub type RustStreamNextFuture<T> = Pin<Box<dyn UniffiCompatibleFuture<Option<T>>>>;
+
+/// Stream returned by an exported function
+///
+/// The scaffolding code creates these from `impl Stream<Item = T>` return values.
+pub struct RustStream<T> {
+    stream: Pin<Box<dyn Stream<Item = T> + Send>>,
+    wrap_next: fn(RustStreamNextFuture<T>) -> RustStreamNextFuture<T>,
+}
+
+impl<T> RustStream<T> {
+    pub fn new(stream: impl Stream<Item = T> + Send + 'static) -> Self {
+        Self::with_runtime(stream, |next| next)
+    }
+
+    /// Create a stream whose `next()` futures are passed through `wrap_next`
+    ///
+    /// The scaffolding code uses this to apply the `async_runtime` of the exported function.
+    pub fn with_runtime(
+        stream: impl Stream<Item = T> + Send + 'static,
+        wrap_next: fn(RustStreamNextFuture<T>) -> RustStreamNextFuture<T>,
+    ) -> Self {
+        Self {
+            stream: Box::pin(stream),
+            wrap_next,
+        }
+    }
+
+    /// Convert this stream into a handle to pass to the foreign code
+    pub(crate) fn into_handle<UT>(self) -> Handle
+    where
+        T: Send + 'static,
+        Option<T>: FutureLowerReturn<UT>,
+        UT: 'static,
+    {
+        let state: Arc<dyn RustStreamNext> = Arc::new(RustStreamState::<T, UT> {
+            stream: Mutex::new(self.stream),
+            wrap_next: self.wrap_next,
+            _tag: PhantomData,
+        });
+        Handle::from_arc(Arc::new(state))
+    }
+}
+
+/// Type-erased stream, this is what's stored behind the handle.
+trait RustStreamNext: Send + Sync {
+    /// Create a RustFuture that resolves to the next item
+    fn next(self: Arc<Self>) -> Handle;
+}
+
+struct RustStreamState<T, UT> {
+    // This Mutex should never block, since the foreign code waits for one `next()` future to
+    // complete before starting the next one.
+    stream: Mutex<Pin<Box<dyn Stream<Item = T> + Send>>>,
+    wrap_next: fn(RustStreamNextFuture<T>) -> RustStreamNextFuture<T>,
+    _tag: PhantomData<fn() -> UT>,
+}
+
+impl<T, UT> RustStreamNext for RustStreamState<T, UT>
+where
+    T: Send + 'static,
+    Option<T>: FutureLowerReturn<UT>,
+    UT: 'static,
+{
+    fn next(self: Arc<Self>) -> Handle {
+        let wrap_next = self.wrap_next;
+        let next = wrap_next(Box::pin(poll_fn(move |cx| {
+            self.stream.lock().unwrap().as_mut().poll_next(cx)
+        })));
+        rust_future_new_with_tag_type::<_, Option<T>, UT>(async move { Ok(next.await) })
+    }
+}
+
+// === Public FFI API ===
+
+/// Create a Rust future for the next item in a stream
+///
+/// The future should be polled/completed/freed with the `RustBuffer` versions of the
+/// `rust_future_*` functions.  It resolves to a serialized `Option<T>`.
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_stream_free]
+pub unsafe fn rust_stream_next(handle: Handle) -> Handle {
+    trace!("rust_stream_next: {handle:?}");
+    let state = Handle::into_arc_borrowed::<Arc<dyn RustStreamNext>>(handle);
+    Arc::clone(&*state).next()
+}
+
+/// Free a Rust stream
+///
+/// # Safety
+///
+/// The [Handle] must not previously have been passed to [rust_stream_free]
+pub unsafe fn rust_stream_free(handle: Handle) {
+    trace!("rust_stream_free: {handle:?}");
+    drop(Handle::into_arc::<Arc<dyn RustStreamNext>>(handle))
+}
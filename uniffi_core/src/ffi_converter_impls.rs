@@ -22,14 +22,18 @@
 /// consumer crates.  To do this, it defines blanket impls like `impl<UT> FFIConverter<UT> for u8`.
 /// "UT" means an arbitrary `UniFfiTag` type.
 use crate::{
-    check_remaining, derive_ffi_traits, ffi_converter_rust_buffer_lift_and_lower, metadata,
-    ConvertError, FfiConverter, Lift, LiftRef, LiftReturn, Lower, LowerError, LowerReturn,
-    MetadataBuffer, Result, RustBuffer, RustCallError, TypeId, UnexpectedUniFFICallbackError,
+    check_remaining, derive_ffi_traits, ffi_converter_rust_buffer_lift_and_lower, lift_closure,
+    lower_closure, metadata, ConvertError, FfiConverter, FfiConverterArc, FutureLowerReturn,
+    Handle, HasForeignClosureVTable, Iter, Lift, LiftRef, LiftReturn, Lower, LowerBorrowed,
+    LowerError, LowerReturn, MetadataBuffer, Result, RustBuffer, RustCallError, RustStream, TypeId,
+    UnexpectedUniFFICallbackError,
 };
 use anyhow::bail;
 use bytes::buf::{Buf, BufMut};
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     convert::TryFrom,
     fmt::{Debug, Display},
     sync::Arc,
@@ -109,6 +113,36 @@ unsafe impl<UT> FfiConverter<UT> for bool {
     const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_BOOL);
 }
 
+/// Support for passing chars via the FFI.
+///
+/// Chars are passed as their `u32` code point.  Not every `u32` is a valid Unicode scalar value,
+/// so lifting checks the value with `char::from_u32`.
+unsafe impl<UT> FfiConverter<UT> for char {
+    type FfiType = u32;
+
+    fn lower(obj: char) -> Self::FfiType {
+        u32::from(obj)
+    }
+
+    fn try_lift(v: Self::FfiType) -> Result<char> {
+        match char::from_u32(v) {
+            Some(c) => Ok(c),
+            None => bail!("invalid Unicode scalar value for char: {v:#x}"),
+        }
+    }
+
+    fn write(obj: char, buf: &mut Vec<u8>) {
+        buf.put_u32(<Self as FfiConverter<UT>>::lower(obj));
+    }
+
+    fn try_read(buf: &mut &[u8]) -> Result<char> {
+        check_remaining(buf, 4)?;
+        <Self as FfiConverter<UT>>::try_lift(buf.get_u32())
+    }
+
+    const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_CHAR);
+}
+
 /// Support for passing Strings via the FFI.
 ///
 /// Unlike many other implementations of `FfiConverter`, this passes a struct containing
@@ -241,6 +275,33 @@ unsafe impl<UT> FfiConverter<UT> for Duration {
     const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_DURATION);
 }
 
+/// Support for passing 128-bit integers via the FFI.
+///
+/// There's no C-compatible 128-bit integer type that works across all our foreign languages, so
+/// these are always passed by serializing to a buffer.  They are represented on the buffer as 16
+/// big-endian bytes, like the other integer types.
+macro_rules! impl_ffi_converter_for_128_bit_int {
+    ($T:ty, $type_code:expr, $get:ident, $put:ident) => {
+        unsafe impl<UT> FfiConverter<UT> for $T {
+            ffi_converter_rust_buffer_lift_and_lower!(UT);
+
+            fn write(obj: $T, buf: &mut Vec<u8>) {
+                buf.$put(obj);
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<$T> {
+                check_remaining(buf, 16)?;
+                Ok(buf.$get())
+            }
+
+            const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code($type_code);
+        }
+    };
+}
+
+impl_ffi_converter_for_128_bit_int!(u128, metadata::codes::TYPE_U128, get_u128, put_u128);
+impl_ffi_converter_for_128_bit_int!(i128, metadata::codes::TYPE_I128, get_i128, put_i128);
+
 // Support for passing optional values via the FFI.
 //
 // Optional values are currently always passed by serializing to a buffer.
@@ -380,6 +441,113 @@ impl<UT, T: TypeId<UT>> TypeId<UT> for Vec<T> {
         MetadataBuffer::from_code(metadata::codes::TYPE_VEC).concat(T::TYPE_ID_META);
 }
 
+/// Length of a fixed-size array, as written to the buffer and the metadata.
+///
+/// Arrays are limited to `i32::MAX` items.  This is checked when the constant is evaluated, so
+/// exporting a larger array is a compile-time error rather than a panic when lowering.
+struct ArrayLen<const N: usize>;
+
+impl<const N: usize> ArrayLen<N> {
+    const I32: i32 = {
+        assert!(
+            N <= i32::MAX as usize,
+            "Arrays are limited to i32::MAX items"
+        );
+        N as i32
+    };
+}
+
+/// Support for fixed-size arrays via the FFI.
+///
+/// Arrays are serialized the same way as `Vec<T>`: an `i32` item count followed by each item in
+/// turn.  The length is also part of the metadata, so the foreign side can check it before the
+/// call. We still write it to the buffer so that lifting can fail with a clear error if
+/// the foreign side sends the wrong number of items.
+unsafe impl<UT, T: Lower<UT>, const N: usize> Lower<UT> for [T; N] {
+    type FfiType = RustBuffer;
+
+    fn write(obj: [T; N], buf: &mut Vec<u8>) {
+        buf.put_i32(ArrayLen::<N>::I32);
+        for item in obj {
+            <T as Lower<UT>>::write(item, buf);
+        }
+    }
+
+    fn lower(obj: [T; N]) -> RustBuffer {
+        Self::lower_into_rust_buffer(obj)
+    }
+}
+
+unsafe impl<UT, T: Lift<UT>, const N: usize> Lift<UT> for [T; N] {
+    type FfiType = RustBuffer;
+
+    fn try_read(buf: &mut &[u8]) -> Result<[T; N]> {
+        check_remaining(buf, 4)?;
+        let len = usize::try_from(buf.get_i32())?;
+        if len != N {
+            bail!("Expected an array of length {N}, got {len} items");
+        }
+        let mut vec = Vec::with_capacity(N);
+        for _ in 0..N {
+            vec.push(<T as Lift<UT>>::try_read(buf)?)
+        }
+        // This can't fail, since we just read exactly `N` items.
+        match vec.try_into() {
+            Ok(array) => Ok(array),
+            Err(_) => bail!("Expected an array of length {N}"),
+        }
+    }
+
+    fn try_lift(buf: RustBuffer) -> Result<[T; N]> {
+        Self::try_lift_from_rust_buffer(buf)
+    }
+}
+
+impl<UT, T: TypeId<UT>, const N: usize> TypeId<UT> for [T; N] {
+    const TYPE_ID_META: MetadataBuffer = MetadataBuffer::from_code(metadata::codes::TYPE_ARRAY)
+        .concat_u32(ArrayLen::<N>::I32 as u32)
+        .concat(T::TYPE_ID_META);
+}
+
+// `derive_ffi_traits!` doesn't support const generics, so we write these out by hand.
+unsafe impl<UT, T, const N: usize> LowerReturn<UT> for [T; N]
+where
+    [T; N]: Lower<UT>,
+{
+    type ReturnType = <Self as Lower<UT>>::FfiType;
+
+    fn lower_return(obj: Self) -> Result<Self::ReturnType, RustCallError> {
+        Ok(<Self as Lower<UT>>::lower(obj))
+    }
+}
+
+unsafe impl<UT, T, const N: usize> LowerError<UT> for [T; N]
+where
+    [T; N]: Lower<UT>,
+{
+    fn lower_error(obj: Self) -> RustBuffer {
+        <Self as Lower<UT>>::lower_into_rust_buffer(obj)
+    }
+}
+
+unsafe impl<UT, T, const N: usize> LiftReturn<UT> for [T; N]
+where
+    [T; N]: Lift<UT>,
+{
+    type ReturnType = <Self as Lift<UT>>::FfiType;
+
+    fn try_lift_successful_return(v: Self::ReturnType) -> Result<Self> {
+        <Self as Lift<UT>>::try_lift(v)
+    }
+}
+
+unsafe impl<UT, T, const N: usize> LiftRef<UT> for [T; N]
+where
+    [T; N]: Lift<UT>,
+{
+    type LiftType = Self;
+}
+
 unsafe impl<K, V, UT> Lower<UT> for HashMap<K, V>
 where
     K: Lower<UT> + std::hash::Hash + Eq,
@@ -444,9 +612,12 @@ derive_ffi_traits!(blanket u32);
 derive_ffi_traits!(blanket i32);
 derive_ffi_traits!(blanket u64);
 derive_ffi_traits!(blanket i64);
+derive_ffi_traits!(blanket u128);
+derive_ffi_traits!(blanket i128);
 derive_ffi_traits!(blanket f32);
 derive_ffi_traits!(blanket f64);
 derive_ffi_traits!(blanket bool);
+derive_ffi_traits!(blanket char);
 derive_ffi_traits!(blanket String);
 derive_ffi_traits!(blanket Duration);
 derive_ffi_traits!(blanket SystemTime);
@@ -529,6 +700,198 @@ derive_ffi_traits!(impl<T, UT> LowerError<UT> for HashSet<T> where HashSet<T>: L
 derive_ffi_traits!(impl<T, UT> LiftReturn<UT> for HashSet<T> where HashSet<T>: Lift<UT>);
 derive_ffi_traits!(impl<T, UT> LiftRef<UT> for HashSet<T> where HashSet<T>: Lift<UT>);
 
+/// Support for ordered maps via the FFI.
+///
+/// These are serialized exactly like `HashMap`, but entries are written in the map's iteration
+/// order and inserted in the order they're read.  The `TYPE_ORDERED_MAP` code tells the bindings
+/// that the order is meaningful, so they should use a container that preserves it.
+macro_rules! impl_ffi_converter_for_ordered_map {
+    ($Map:ident, $($bounds:tt)+) => {
+        unsafe impl<K, V, UT> Lower<UT> for $Map<K, V>
+        where
+            K: Lower<UT> + $($bounds)+,
+            V: Lower<UT>,
+        {
+            type FfiType = RustBuffer;
+
+            fn write(obj: $Map<K, V>, buf: &mut Vec<u8>) {
+                let len = i32::try_from(obj.len()).unwrap();
+                buf.put_i32(len); // We limit maps to i32::MAX entries
+                for (key, value) in obj {
+                    <K as Lower<UT>>::write(key, buf);
+                    <V as Lower<UT>>::write(value, buf);
+                }
+            }
+
+            fn lower(obj: $Map<K, V>) -> RustBuffer {
+                Self::lower_into_rust_buffer(obj)
+            }
+        }
+
+        unsafe impl<K, V, UT> Lift<UT> for $Map<K, V>
+        where
+            K: Lift<UT> + $($bounds)+,
+            V: Lift<UT>,
+        {
+            type FfiType = RustBuffer;
+
+            fn try_read(buf: &mut &[u8]) -> Result<$Map<K, V>> {
+                check_remaining(buf, 4)?;
+                let len = usize::try_from(buf.get_i32())?;
+                let mut map = $Map::new();
+                for _ in 0..len {
+                    let key = <K as Lift<UT>>::try_read(buf)?;
+                    let value = <V as Lift<UT>>::try_read(buf)?;
+                    map.insert(key, value);
+                }
+                Ok(map)
+            }
+
+            fn try_lift(buf: RustBuffer) -> Result<$Map<K, V>> {
+                Self::try_lift_from_rust_buffer(buf)
+            }
+        }
+
+        impl<K, V, UT> TypeId<UT> for $Map<K, V>
+        where
+            K: TypeId<UT> + $($bounds)+,
+            V: TypeId<UT>,
+        {
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_ORDERED_MAP)
+                    .concat(K::TYPE_ID_META)
+                    .concat(V::TYPE_ID_META);
+        }
+
+        derive_ffi_traits!(impl<K, V, UT> LowerReturn<UT> for $Map<K, V> where $Map<K, V>: Lower<UT>);
+        derive_ffi_traits!(impl<K, V, UT> LowerError<UT> for $Map<K, V> where $Map<K, V>: Lower<UT>);
+        derive_ffi_traits!(impl<K, V, UT> LiftReturn<UT> for $Map<K, V> where $Map<K, V>: Lift<UT>);
+        derive_ffi_traits!(impl<K, V, UT> LiftRef<UT> for $Map<K, V> where $Map<K, V>: Lift<UT>);
+    };
+}
+
+/// Support for ordered sets via the FFI.
+///
+/// Like ordered maps, these use the same serialization as `HashSet` and are tagged with
+/// `TYPE_ORDERED_SET` so the bindings know to preserve the order.
+macro_rules! impl_ffi_converter_for_ordered_set {
+    ($Set:ident, $($bounds:tt)+) => {
+        unsafe impl<T, UT> Lower<UT> for $Set<T>
+        where
+            T: Lower<UT> + $($bounds)+,
+        {
+            type FfiType = RustBuffer;
+
+            fn lower(obj: $Set<T>) -> RustBuffer {
+                Self::lower_into_rust_buffer(obj)
+            }
+
+            fn write(obj: $Set<T>, buf: &mut Vec<u8>) {
+                let len = i32::try_from(obj.len()).unwrap();
+                buf.put_i32(len); // We limit sets to i32::MAX entries
+                for item in obj {
+                    <T as Lower<UT>>::write(item, buf);
+                }
+            }
+        }
+
+        unsafe impl<T, UT> Lift<UT> for $Set<T>
+        where
+            T: Lift<UT> + $($bounds)+,
+        {
+            type FfiType = RustBuffer;
+
+            fn try_lift(buf: RustBuffer) -> Result<$Set<T>> {
+                Self::try_lift_from_rust_buffer(buf)
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<$Set<T>> {
+                check_remaining(buf, 4)?;
+                let len = usize::try_from(buf.get_i32())?;
+                let mut set = $Set::new();
+                for _ in 0..len {
+                    set.insert(<T as Lift<UT>>::try_read(buf)?);
+                }
+                Ok(set)
+            }
+        }
+
+        impl<T, UT> TypeId<UT> for $Set<T>
+        where
+            T: TypeId<UT> + $($bounds)+,
+        {
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_ORDERED_SET).concat(T::TYPE_ID_META);
+        }
+
+        derive_ffi_traits!(impl<T, UT> LowerReturn<UT> for $Set<T> where $Set<T>: Lower<UT>);
+        derive_ffi_traits!(impl<T, UT> LowerError<UT> for $Set<T> where $Set<T>: Lower<UT>);
+        derive_ffi_traits!(impl<T, UT> LiftReturn<UT> for $Set<T> where $Set<T>: Lift<UT>);
+        derive_ffi_traits!(impl<T, UT> LiftRef<UT> for $Set<T> where $Set<T>: Lift<UT>);
+    };
+}
+
+impl_ffi_converter_for_ordered_map!(BTreeMap, Ord);
+impl_ffi_converter_for_ordered_set!(BTreeSet, Ord);
+
+#[cfg(feature = "indexmap")]
+impl_ffi_converter_for_ordered_map!(IndexMap, std::hash::Hash + Eq);
+#[cfg(feature = "indexmap")]
+impl_ffi_converter_for_ordered_set!(IndexSet, std::hash::Hash + Eq);
+
+/// Support for tuples via the FFI.
+///
+/// Tuples are passed by serializing to a buffer. Since the arity is part of the type, we don't
+/// write a count, just each element in turn.  The metadata is the `TYPE_TUPLE` code, followed by
+/// the number of elements, followed by the type of each element.
+macro_rules! impl_ffi_converter_for_tuple {
+    ($len:expr; $($T:ident),+) => {
+        unsafe impl<UT, $($T: Lower<UT>),+> Lower<UT> for ($($T,)+) {
+            type FfiType = RustBuffer;
+
+            fn lower(obj: ($($T,)+)) -> RustBuffer {
+                Self::lower_into_rust_buffer(obj)
+            }
+
+            #[allow(non_snake_case)]
+            fn write(obj: ($($T,)+), buf: &mut Vec<u8>) {
+                let ($($T,)+) = obj;
+                $(<$T as Lower<UT>>::write($T, buf);)+
+            }
+        }
+
+        unsafe impl<UT, $($T: Lift<UT>),+> Lift<UT> for ($($T,)+) {
+            type FfiType = RustBuffer;
+
+            fn try_lift(buf: RustBuffer) -> Result<($($T,)+)> {
+                Self::try_lift_from_rust_buffer(buf)
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<($($T,)+)> {
+                Ok(($(<$T as Lift<UT>>::try_read(buf)?,)+))
+            }
+        }
+
+        impl<UT, $($T: TypeId<UT>),+> TypeId<UT> for ($($T,)+) {
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_TUPLE)
+                    .concat_value($len)
+                    $(.concat($T::TYPE_ID_META))+;
+        }
+
+        derive_ffi_traits!(impl<$($T,)+ UT> LowerReturn<UT> for ($($T,)+) where ($($T,)+): Lower<UT>);
+        derive_ffi_traits!(impl<$($T,)+ UT> LowerError<UT> for ($($T,)+) where ($($T,)+): Lower<UT>);
+        derive_ffi_traits!(impl<$($T,)+ UT> LiftReturn<UT> for ($($T,)+) where ($($T,)+): Lift<UT>);
+        derive_ffi_traits!(impl<$($T,)+ UT> LiftRef<UT> for ($($T,)+) where ($($T,)+): Lift<UT>);
+    };
+}
+
+impl_ffi_converter_for_tuple!(2; A, B);
+impl_ffi_converter_for_tuple!(3; A, B, C);
+impl_ffi_converter_for_tuple!(4; A, B, C, D);
+impl_ffi_converter_for_tuple!(5; A, B, C, D, E);
+impl_ffi_converter_for_tuple!(6; A, B, C, D, E, F);
+
 // For Arc we derive all the traits, but have to write it all out because we need an unsized T bound
 derive_ffi_traits!(impl<T, UT> Lower<UT> for Arc<T> where Arc<T>: FfiConverter<UT>, T: ?Sized);
 derive_ffi_traits!(impl<T, UT> Lift<UT> for Arc<T> where Arc<T>: FfiConverter<UT>, T: ?Sized);
@@ -538,6 +901,196 @@ derive_ffi_traits!(impl<T, UT> LiftReturn<UT> for Arc<T> where Arc<T>: Lift<UT>,
 derive_ffi_traits!(impl<T, UT> LiftRef<UT> for Arc<T> where Arc<T>: Lift<UT>, T: ?Sized);
 derive_ffi_traits!(impl<T, UT> TypeId<UT> for Arc<T> where Arc<T>: FfiConverter<UT>, T: ?Sized);
 
+// Streams are lowered as handles and can only be returned from Rust to the foreign code.  Each
+// item is lowered as an `Option<T>`, see [crate::ruststream] for details.
+
+unsafe impl<T, UT> Lower<UT> for RustStream<T>
+where
+    T: Send + 'static,
+    Option<T>: FutureLowerReturn<UT>,
+    UT: 'static,
+{
+    type FfiType = Handle;
+
+    fn lower(obj: Self) -> Self::FfiType {
+        obj.into_handle::<UT>()
+    }
+
+    fn write(obj: Self, buf: &mut Vec<u8>) {
+        buf.put_u64(<Self as Lower<UT>>::lower(obj).as_raw())
+    }
+}
+
+derive_ffi_traits!(impl<T, UT> LowerReturn<UT> for RustStream<T> where RustStream<T>: Lower<UT>);
+
+impl<T, UT> TypeId<UT> for RustStream<T>
+where
+    T: TypeId<UT>,
+{
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_STREAM).concat(T::TYPE_ID_META);
+}
+
+// Iterators are lowered as handles and can only be returned from Rust to the foreign code.  Items
+// are lowered in chunks, see [crate::rustiter] for details.
+
+unsafe impl<T, UT> Lower<UT> for Iter<T>
+where
+    T: Lower<UT> + 'static,
+    UT: 'static,
+{
+    type FfiType = Handle;
+
+    fn lower(obj: Self) -> Self::FfiType {
+        obj.into_handle::<UT>()
+    }
+
+    fn write(obj: Self, buf: &mut Vec<u8>) {
+        buf.put_u64(<Self as Lower<UT>>::lower(obj).as_raw())
+    }
+}
+
+derive_ffi_traits!(impl<T, UT> LowerReturn<UT> for Iter<T> where Iter<T>: Lower<UT>);
+
+impl<T, UT> TypeId<UT> for Iter<T>
+where
+    T: TypeId<UT>,
+{
+    const TYPE_ID_META: MetadataBuffer =
+        MetadataBuffer::from_code(metadata::codes::TYPE_ITERATOR).concat(T::TYPE_ID_META);
+}
+
+// Closures are lowered as handles.  When calling them, the arguments and return value are
+// serialized into `RustBuffer`s, see [crate::closures] for details.
+//
+// `Arc<dyn Fn>` gets its FFI traits from `FfiConverterArc`, like trait interfaces.  `Box<dyn Fn>`
+// delegates to the `Arc` implementation.
+macro_rules! impl_ffi_converter_for_closure {
+    ($len:expr; $($A:ident $a:ident),*) => {
+        unsafe impl<UT, R $(, $A)*> FfiConverterArc<UT> for dyn Fn($($A),*) -> R + Send + Sync
+        where
+            UT: HasForeignClosureVTable + 'static,
+            R: Lower<UT> + Lift<UT> + TypeId<UT> + 'static,
+            $($A: Lower<UT> + Lift<UT> + TypeId<UT> + 'static,)*
+        {
+            type FfiType = Handle;
+
+            fn lower(obj: Arc<Self>) -> Handle {
+                lower_closure(move |_args: &mut &[u8]| {
+                    $(let $a = <$A as Lift<UT>>::try_read(_args)?;)*
+                    Ok(<R as Lower<UT>>::lower_into_rust_buffer(obj($($a),*)))
+                })
+            }
+
+            fn try_lift(handle: Handle) -> Result<Arc<Self>> {
+                // Safety: closure handles are only created by `lower_closure` or the foreign code
+                let closure = unsafe { lift_closure::<UT>(handle) };
+                let f: Arc<Self> = Arc::new(move |$($a: $A),*| {
+                    let mut _args = Vec::new();
+                    $(<$A as Lower<UT>>::write($a, &mut _args);)*
+                    closure
+                        .call(RustBuffer::from_vec(_args))
+                        .and_then(<R as Lift<UT>>::try_lift_from_rust_buffer)
+                        .unwrap_or_else(|e| panic!("Closure call failed: {e}"))
+                });
+                Ok(f)
+            }
+
+            fn write(obj: Arc<Self>, buf: &mut Vec<u8>) {
+                buf.put_u64(<Self as FfiConverterArc<UT>>::lower(obj).as_raw())
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<Arc<Self>> {
+                check_remaining(buf, 8)?;
+                <Self as FfiConverterArc<UT>>::try_lift(Handle::from_raw_unchecked(buf.get_u64()))
+            }
+
+            const TYPE_ID_META: MetadataBuffer =
+                MetadataBuffer::from_code(metadata::codes::TYPE_CLOSURE)
+                    .concat_value($len)
+                    $(.concat(<$A as TypeId<UT>>::TYPE_ID_META))*
+                    .concat(<R as TypeId<UT>>::TYPE_ID_META);
+        }
+
+        unsafe impl<UT, R $(, $A)*> Lower<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync>
+        where
+            dyn Fn($($A),*) -> R + Send + Sync: FfiConverterArc<UT, FfiType = Handle>,
+        {
+            type FfiType = Handle;
+
+            fn lower(obj: Self) -> Handle {
+                <dyn Fn($($A),*) -> R + Send + Sync as FfiConverterArc<UT>>::lower(Arc::from(obj))
+            }
+
+            fn write(obj: Self, buf: &mut Vec<u8>) {
+                <dyn Fn($($A),*) -> R + Send + Sync as FfiConverterArc<UT>>::write(Arc::from(obj), buf)
+            }
+        }
+
+        unsafe impl<UT, R $(, $A)*> Lift<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync>
+        where
+            dyn Fn($($A),*) -> R + Send + Sync: FfiConverterArc<UT, FfiType = Handle>,
+            R: 'static,
+            $($A: 'static,)*
+        {
+            type FfiType = Handle;
+
+            fn try_lift(handle: Handle) -> Result<Self> {
+                let f = <dyn Fn($($A),*) -> R + Send + Sync as FfiConverterArc<UT>>::try_lift(handle)?;
+                Ok(Box::new(move |$($a: $A),*| f($($a),*)))
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<Self> {
+                let f = <dyn Fn($($A),*) -> R + Send + Sync as FfiConverterArc<UT>>::try_read(buf)?;
+                Ok(Box::new(move |$($a: $A),*| f($($a),*)))
+            }
+        }
+
+        impl<UT, R $(, $A)*> TypeId<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync>
+        where
+            dyn Fn($($A),*) -> R + Send + Sync: FfiConverterArc<UT>,
+        {
+            const TYPE_ID_META: MetadataBuffer =
+                <dyn Fn($($A),*) -> R + Send + Sync as FfiConverterArc<UT>>::TYPE_ID_META;
+        }
+
+        derive_ffi_traits!(impl<UT, R $(, $A)*> LowerReturn<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync> where Box<dyn Fn($($A),*) -> R + Send + Sync>: Lower<UT>);
+        derive_ffi_traits!(impl<UT, R $(, $A)*> LiftReturn<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync> where Box<dyn Fn($($A),*) -> R + Send + Sync>: Lift<UT>);
+        derive_ffi_traits!(impl<UT, R $(, $A)*> LiftRef<UT> for Box<dyn Fn($($A),*) -> R + Send + Sync> where Box<dyn Fn($($A),*) -> R + Send + Sync>: Lift<UT>);
+    };
+}
+
+impl_ffi_converter_for_closure!(0;);
+impl_ffi_converter_for_closure!(1; A a);
+impl_ffi_converter_for_closure!(2; A a, B b);
+impl_ffi_converter_for_closure!(3; A a, B b, C c);
+impl_ffi_converter_for_closure!(4; A a, B b, C c, D d);
+impl_ffi_converter_for_closure!(5; A a, B b, C c, D d, E e);
+impl_ffi_converter_for_closure!(6; A a, B b, C c, D d, E e, F f);
+
+// The unit type is lowered as an empty value.  It's only used for the return value of closures,
+// which is always serialized into a `RustBuffer`.
+
+unsafe impl<UT> Lower<UT> for () {
+    type FfiType = ();
+
+    fn lower(_: ()) {}
+
+    fn write(_: (), _buf: &mut Vec<u8>) {}
+}
+
+unsafe impl<UT> Lift<UT> for () {
+    type FfiType = ();
+
+    fn try_lift(_: ()) -> Result<()> {
+        Ok(())
+    }
+
+    fn try_read(_buf: &mut &[u8]) -> Result<()> {
+        Ok(())
+    }
+}
+
 // Implement LowerReturn/LiftReturn for the unit type (void returns)
 
 unsafe impl<UT> LowerReturn<UT> for () {
@@ -653,3 +1206,182 @@ where
 unsafe impl<UT> LiftRef<UT> for str {
     type LiftType = String;
 }
+
+// Support for lowering borrowed values, which is used for methods that return references.
+//
+// These serialize the value directly from the reference, using the same format as the
+// corresponding owned type.
+
+/// Lower a `Copy` value by copying it out of the reference
+macro_rules! impl_lower_borrowed_for_copy_type {
+    ($($T:ty),*) => {
+        $(
+            unsafe impl<UT> LowerBorrowed<UT> for $T {
+                type FfiType = <$T as Lower<UT>>::FfiType;
+
+                fn lower_borrowed(obj: &$T) -> Self::FfiType {
+                    <$T as Lower<UT>>::lower(*obj)
+                }
+
+                fn write_borrowed(obj: &$T, buf: &mut Vec<u8>) {
+                    <$T as Lower<UT>>::write(*obj, buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_lower_borrowed_for_copy_type!(
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64, bool, char, Duration, SystemTime
+);
+
+unsafe impl<UT> LowerBorrowed<UT> for str {
+    type FfiType = RustBuffer;
+
+    fn lower_borrowed(obj: &str) -> RustBuffer {
+        RustBuffer::from_vec(obj.as_bytes().to_vec())
+    }
+
+    fn write_borrowed(obj: &str, buf: &mut Vec<u8>) {
+        // Same format as `String`
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len);
+        buf.put(obj.as_bytes());
+    }
+}
+
+unsafe impl<UT> LowerBorrowed<UT> for String {
+    type FfiType = RustBuffer;
+
+    fn lower_borrowed(obj: &String) -> RustBuffer {
+        <str as LowerBorrowed<UT>>::lower_borrowed(obj)
+    }
+
+    fn write_borrowed(obj: &String, buf: &mut Vec<u8>) {
+        <str as LowerBorrowed<UT>>::write_borrowed(obj, buf)
+    }
+}
+
+unsafe impl<UT, T: LowerBorrowed<UT>> LowerBorrowed<UT> for [T] {
+    type FfiType = RustBuffer;
+
+    fn lower_borrowed(obj: &[T]) -> RustBuffer {
+        let mut buf = Vec::new();
+        Self::write_borrowed(obj, &mut buf);
+        RustBuffer::from_vec(buf)
+    }
+
+    fn write_borrowed(obj: &[T], buf: &mut Vec<u8>) {
+        // Same format as `Vec<T>`
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len);
+        for item in obj {
+            T::write_borrowed(item, buf);
+        }
+    }
+}
+
+unsafe impl<UT, T: LowerBorrowed<UT>> LowerBorrowed<UT> for Vec<T> {
+    type FfiType = RustBuffer;
+
+    fn lower_borrowed(obj: &Vec<T>) -> RustBuffer {
+        <[T] as LowerBorrowed<UT>>::lower_borrowed(obj)
+    }
+
+    fn write_borrowed(obj: &Vec<T>, buf: &mut Vec<u8>) {
+        <[T] as LowerBorrowed<UT>>::write_borrowed(obj, buf)
+    }
+}
+
+unsafe impl<UT, T: LowerBorrowed<UT>> LowerBorrowed<UT> for Option<T> {
+    type FfiType = RustBuffer;
+
+    fn lower_borrowed(obj: &Option<T>) -> RustBuffer {
+        let mut buf = Vec::new();
+        Self::write_borrowed(obj, &mut buf);
+        RustBuffer::from_vec(buf)
+    }
+
+    fn write_borrowed(obj: &Option<T>, buf: &mut Vec<u8>) {
+        // Same format as `Option<T>`
+        match obj {
+            None => buf.put_i8(0),
+            Some(v) => {
+                buf.put_i8(1);
+                T::write_borrowed(v, buf);
+            }
+        }
+    }
+}
+
+unsafe impl<K, V, UT> LowerBorrowed<UT> for HashMap<K, V>
+where
+    K: LowerBorrowed<UT> + std::hash::Hash + Eq,
+    V: LowerBorrowed<UT>,
+{
+    type FfiType = RustBuffer;
+
+    fn lower_borrowed(obj: &HashMap<K, V>) -> RustBuffer {
+        let mut buf = Vec::new();
+        Self::write_borrowed(obj, &mut buf);
+        RustBuffer::from_vec(buf)
+    }
+
+    fn write_borrowed(obj: &HashMap<K, V>, buf: &mut Vec<u8>) {
+        // Same format as `HashMap<K, V>`
+        let len = i32::try_from(obj.len()).unwrap();
+        buf.put_i32(len);
+        for (key, value) in obj {
+            K::write_borrowed(key, buf);
+            V::write_borrowed(value, buf);
+        }
+    }
+}
+
+/// Objects are lowered by cloning the `Arc`, which only increments the reference count.
+unsafe impl<UT, T: ?Sized> LowerBorrowed<UT> for Arc<T>
+where
+    Arc<T>: Lower<UT>,
+{
+    type FfiType = <Arc<T> as Lower<UT>>::FfiType;
+
+    fn lower_borrowed(obj: &Arc<T>) -> Self::FfiType {
+        <Arc<T> as Lower<UT>>::lower(Arc::clone(obj))
+    }
+
+    fn write_borrowed(obj: &Arc<T>, buf: &mut Vec<u8>) {
+        <Arc<T> as Lower<UT>>::write(Arc::clone(obj), buf)
+    }
+}
+
+unsafe impl<UT, T: LowerBorrowed<UT> + ?Sized> Lower<UT> for &T {
+    type FfiType = T::FfiType;
+
+    fn lower(obj: &T) -> Self::FfiType {
+        T::lower_borrowed(obj)
+    }
+
+    fn write(obj: &T, buf: &mut Vec<u8>) {
+        T::write_borrowed(obj, buf)
+    }
+}
+
+unsafe impl<UT, T: LowerBorrowed<UT> + ?Sized> LowerReturn<UT> for &T {
+    type ReturnType = T::FfiType;
+
+    fn lower_return(obj: &T) -> Result<Self::ReturnType, RustCallError> {
+        Ok(T::lower_borrowed(obj))
+    }
+}
+
+impl<UT, T: TypeId<UT> + ?Sized> TypeId<UT> for &T {
+    const TYPE_ID_META: MetadataBuffer = T::TYPE_ID_META;
+}
+
+impl<UT> TypeId<UT> for str {
+    const TYPE_ID_META: MetadataBuffer = <String as TypeId<UT>>::TYPE_ID_META;
+}
+
+impl<UT, T: TypeId<UT>> TypeId<UT> for [T] {
+    const TYPE_ID_META: MetadataBuffer = <Vec<T> as TypeId<UT>>::TYPE_ID_META;
+}
@@ -413,6 +413,29 @@ pub unsafe trait LiftRef<UT> {
     type LiftType: Lift<UT> + Borrow<Self>;
 }
 
+/// Lower a value from a reference
+///
+/// This is used to implement [Lower] for `&T`, which allows methods to return borrowed values
+/// like `&str`, `&[u8]` or `&Record`.  The value is serialized straight from the reference, rather
+/// than cloning it first.  It's implemented for the builtin types and derived for records and
+/// enums whose fields all implement it.
+///
+/// ## Safety
+///
+/// All traits are unsafe (implementing it requires `unsafe impl`) because we can't guarantee
+/// that it's safe to pass your type out to foreign-language code and back again. Buggy
+/// implementations of this trait might violate some assumptions made by the generated code,
+/// or might not match with the corresponding code in the generated foreign-language bindings.
+/// These traits should not be used directly, only in generated code, and the generated code should
+/// have fixture tests to test that everything works correctly together.
+pub unsafe trait LowerBorrowed<UT> {
+    type FfiType: FfiDefault;
+
+    fn lower_borrowed(obj: &Self) -> Self::FfiType;
+
+    fn write_borrowed(obj: &Self, buf: &mut Vec<u8>);
+}
+
 /// Type ID metadata
 ///
 /// This is used to build up more complex metadata.  For example, the `MetadataBuffer` for function
@@ -421,6 +444,28 @@ pub trait TypeId<UT> {
     const TYPE_ID_META: MetadataBuffer;
 }
 
+/// FFI conversions for a generic record or enum
+///
+/// Deriving `Record` or `Enum` for a generic type implements this instead of [FfiConverter].
+/// `uniffi::instantiate!` then implements [FfiConverter] and the metadata for each concrete
+/// instance, using the name it's given.
+///
+/// ## Safety
+///
+/// This is only implemented by the derive macros, see [FfiConverter] for why it's unsafe.
+#[doc(hidden)]
+pub unsafe trait FfiConverterGeneric<UT>: Sized {
+    /// Metadata code for [TypeId::TYPE_ID_META], for example `TYPE_RECORD`
+    const TYPE_ID_CODE: u8;
+    /// Metadata code for the item, for example `RECORD`
+    const METADATA_CODE: u8;
+    /// Item metadata that follows the module path and name
+    const METADATA_TAIL: MetadataBuffer;
+
+    fn write(obj: Self, buf: &mut Vec<u8>);
+    fn try_read(buf: &mut &[u8]) -> Result<Self>;
+}
+
 pub trait ConvertError<UT>: Sized {
     fn try_convert_unexpected_callback_error(e: UnexpectedUniFFICallbackError) -> Result<Self>;
 }
@@ -80,16 +80,20 @@ pub mod ffi;
 mod ffi_converter_impls;
 mod ffi_converter_traits;
 pub mod metadata;
+mod object_lock;
 mod oneshot;
 
 #[cfg(feature = "scaffolding-ffi-buffer-fns")]
 pub use ffi::ffiserialize::FfiBufferElement;
 pub use ffi::*;
 pub use ffi_converter_traits::{
-    ConvertError, FfiConverter, FfiConverterArc, HandleAlloc, Lift, LiftRef, LiftReturn, Lower,
-    LowerError, LowerReturn, TypeId,
+    ConvertError, FfiConverter, FfiConverterArc, FfiConverterGeneric, HandleAlloc, Lift, LiftRef,
+    LiftReturn, Lower, LowerBorrowed, LowerError, LowerReturn, TypeId,
 };
 pub use metadata::*;
+pub use object_lock::{
+    AsyncReceiver, BorrowMutReceiver, BorrowReceiver, LiftReceiver, LockedObject,
+};
 
 // Re-export the libs that we use in the generated code,
 // so the consumer doesn't have to depend on them directly.
@@ -99,6 +103,7 @@ pub mod deps {
     #[cfg(feature = "tokio")]
     pub use async_compat;
     pub use bytes;
+    pub use futures_core;
     pub use static_assertions;
 }
 
@@ -41,6 +41,8 @@ pub mod codes {
     pub const TRAIT_INTERFACE: u8 = 12;
     pub const OBJECT_TRAIT_IMPL: u8 = 14;
     pub const CUSTOM_TYPE: u8 = 15;
+    pub const CONSTANT: u8 = 16;
+    pub const STATIC_METHOD: u8 = 17;
 
     // Type codes
     pub const TYPE_U8: u8 = 0;
@@ -70,6 +72,16 @@ pub mod codes {
     pub const TYPE_TRAIT_INTERFACE: u8 = 24;
     pub const TYPE_BOX: u8 = 26;
     pub const TYPE_HASH_SET: u8 = 27;
+    pub const TYPE_TUPLE: u8 = 28;
+    pub const TYPE_ARRAY: u8 = 29;
+    pub const TYPE_U128: u8 = 30;
+    pub const TYPE_I128: u8 = 31;
+    pub const TYPE_CHAR: u8 = 32;
+    pub const TYPE_ORDERED_MAP: u8 = 33;
+    pub const TYPE_ORDERED_SET: u8 = 34;
+    pub const TYPE_STREAM: u8 = 35;
+    pub const TYPE_ITERATOR: u8 = 36;
+    pub const TYPE_CLOSURE: u8 = 37;
     pub const TYPE_UNIT: u8 = 255;
 
     // TraitKind sub-codes.
@@ -87,6 +99,9 @@ pub mod codes {
     pub const LIT_EMPTY_SEQ: u8 = 6;
     pub const LIT_EMPTY_MAP: u8 = 7;
     pub const LIT_EMPTY_SET: u8 = 8;
+    pub const LIT_ENUM: u8 = 9;
+    pub const LIT_RECORD: u8 = 10;
+    pub const LIT_CHAR: u8 = 11;
 
     // DefaultValue codes
     pub const DEFVALUE_DEFAULT: u8 = 0;
@@ -265,6 +280,31 @@ impl AsRef<[u8]> for MetadataBuffer {
     }
 }
 
+// Get the `TYPE_ID_META` for a record field, given a closure that accesses it.
+//
+// The proc-macros only see field names when they encode a record literal for an exported
+// constant, this lets the compiler fill in the field types for them.
+pub const fn field_type_id_meta<R, F: crate::TypeId<UT>, UT>(_: fn(&R) -> &F) -> MetadataBuffer {
+    F::TYPE_ID_META
+}
+
+// Check that a path used as an exported constant value is an enum variant.
+//
+// The proc-macros can't tell `Level::High` apart from a path to another constant, like
+// `Limits::DEFAULT`.  This fails the build for the latter, based on the `TYPE_ID_META` of the
+// value, skipping any `Option` wrappers for `Some(...)` values.  It returns an empty buffer, so it
+// can be concatenated into the constant metadata.
+pub const fn check_enum_literal(type_id_meta: MetadataBuffer) -> MetadataBuffer {
+    let mut i = 0;
+    while i < type_id_meta.size && type_id_meta.bytes[i] == codes::TYPE_OPTION {
+        i += 1;
+    }
+    if i >= type_id_meta.size || type_id_meta.bytes[i] != codes::TYPE_ENUM {
+        panic!("exported constants can only use paths for enum variants, not other constants");
+    }
+    MetadataBuffer::new()
+}
+
 // Create a checksum for a MetadataBuffer
 //
 // This is used by the bindings code to verify that the library they link to is the same one
@@ -0,0 +1,246 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Support for objects stored behind a lock
+//!
+//! Objects are normally passed across the FFI as `Arc<T>`, which means exported methods can only
+//! input `&self` or `Arc<Self>`.  Objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`
+//! are stored as `Arc<Mutex<T>>` or `Arc<RwLock<T>>` instead.  This allows them to export
+//! `&mut self` methods: the scaffolding locks the object for the duration of each method call.
+//!
+//! The scaffolding code for methods lifts `self` using [LiftReceiver].  This is the same as
+//! [LiftRef] for everything except locked objects, which can't implement [LiftRef] since there's
+//! no way to borrow the object from the lock without a guard.
+//!
+//! [BorrowReceiver] and [BorrowMutReceiver] are then used to get a reference to the object from
+//! the lifted `self` argument.  For regular objects, that's a reference to the `Arc`.  For locked
+//! objects, it's a lock guard.  Records and enums with methods are lifted by value, so `self` is
+//! a reference to the lifted value.
+//!
+//! Async methods can't lock the object, since the guard would need to be held across `.await`
+//! points.  They use [AsyncReceiver] instead, which is only implemented for unlocked types.
+
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use bytes::{Buf, BufMut};
+
+use crate::{
+    check_remaining, FfiConverterArc, Handle, Lift, LiftRef, MetadataBuffer, Result, RustCallError,
+};
+
+/// Object stored behind a lock
+///
+/// This is implemented by `#[derive(uniffi::Object)]` for objects with the `#[uniffi(mutex)]` or
+/// `#[uniffi(rwlock)]` attribute.  It's used to implement [FfiConverterArc] for the lock type,
+/// which means `Arc<Mutex<T>>` or `Arc<RwLock<T>>` can be passed across the FFI.
+///
+/// ## Safety
+///
+/// This has the same safety requirements as [FfiConverterArc].  `TYPE_ID_META` must describe an
+/// interface type whose clone and free functions expect handles created from `Arc<Self::Lock>`.
+pub unsafe trait LockedObject<UT>: Sized + 'static {
+    /// Type that stores the object, either `Mutex<Self>` or `RwLock<Self>`.
+    type Lock;
+
+    const TYPE_ID_META: MetadataBuffer;
+}
+
+macro_rules! impl_ffi_converter_arc_for_lock {
+    ($lock:ident, $($bounds:tt)*) => {
+        unsafe impl<UT, T> FfiConverterArc<UT> for $lock<T>
+        where
+            T: LockedObject<UT, Lock = $lock<T>> + $($bounds)*,
+        {
+            type FfiType = Handle;
+
+            fn lower(obj: Arc<Self>) -> Handle {
+                Handle::from_arc(obj)
+            }
+
+            fn try_lift(handle: Handle) -> Result<Arc<Self>> {
+                Ok(unsafe { handle.into_arc() })
+            }
+
+            fn write(obj: Arc<Self>, buf: &mut Vec<u8>) {
+                buf.put_u64(<Self as FfiConverterArc<UT>>::lower(obj).as_raw());
+            }
+
+            fn try_read(buf: &mut &[u8]) -> Result<Arc<Self>> {
+                check_remaining(buf, 8)?;
+                <Self as FfiConverterArc<UT>>::try_lift(Handle::from_raw_unchecked(buf.get_u64()))
+            }
+
+            const TYPE_ID_META: MetadataBuffer = T::TYPE_ID_META;
+        }
+    };
+}
+
+impl_ffi_converter_arc_for_lock!(Mutex, Send);
+impl_ffi_converter_arc_for_lock!(RwLock, Send + Sync);
+
+/// Lift the `self` argument of a method
+///
+/// Locked objects implement this for the local `UniFfiTag` only.  A blanket `UT` param would
+/// overlap with the impl below, since the compiler can't rule out other crates implementing
+/// [LiftRef] for the object.
+///
+/// ## Safety
+///
+/// This has the same safety requirements as [LiftRef].
+#[doc(hidden)]
+pub unsafe trait LiftReceiver<UT> {
+    type LiftType: Lift<UT>;
+}
+
+unsafe impl<UT, T: ?Sized + LiftRef<UT>> LiftReceiver<UT> for T {
+    type LiftType = T::LiftType;
+}
+
+/// Get a reference to `self` for a `&self` method call
+///
+/// The method is called on the `Ref` value, so it's auto-dereferenced to `T`.
+#[doc(hidden)]
+pub trait BorrowReceiver<T: ?Sized> {
+    type Ref<'a>: Deref
+    where
+        Self: 'a;
+
+    fn borrow_receiver(&self) -> Result<Self::Ref<'_>, RustCallError>;
+}
+
+/// Get a mutable reference to `self` for a `&mut self` method call
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`&mut self` methods are only supported for objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`",
+    label = "`{T}` is not stored behind a lock"
+)]
+pub trait BorrowMutReceiver<T: ?Sized> {
+    type RefMut<'a>: DerefMut<Target = T>
+    where
+        Self: 'a;
+
+    fn borrow_mut_receiver(&self) -> Result<Self::RefMut<'_>, RustCallError>;
+}
+
+/// Get a reference to `self` for an async `&self` method call
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "async methods aren't supported for objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`",
+    label = "`{T}` is stored behind a lock, which can't be held across `.await` points"
+)]
+pub trait AsyncReceiver<T: ?Sized> {
+    fn async_receiver(&self) -> &T;
+}
+
+fn lock_poisoned<T: ?Sized>() -> RustCallError {
+    RustCallError::InternalError(format!(
+        "Lock poisoned for {}: a previous method call panicked",
+        std::any::type_name::<T>()
+    ))
+}
+
+/// Records and enums with methods are lifted by value
+impl<T> BorrowReceiver<T> for T {
+    type Ref<'a>
+        = &'a T
+    where
+        Self: 'a;
+
+    fn borrow_receiver(&self) -> Result<&T, RustCallError> {
+        Ok(self)
+    }
+}
+
+impl<T> AsyncReceiver<T> for T {
+    fn async_receiver(&self) -> &T {
+        self
+    }
+}
+
+/// Borrow the `Arc` rather than `T`, this way `&mut self` methods fail to compile with an error
+/// about `Arc` not implementing `DerefMut`.
+impl<T: ?Sized> BorrowReceiver<T> for Arc<T> {
+    type Ref<'a>
+        = &'a Arc<T>
+    where
+        Self: 'a;
+
+    fn borrow_receiver(&self) -> Result<&Arc<T>, RustCallError> {
+        Ok(self)
+    }
+}
+
+impl<T: ?Sized> AsyncReceiver<T> for Arc<T> {
+    fn async_receiver(&self) -> &T {
+        self
+    }
+}
+
+impl<T> BorrowReceiver<T> for Arc<Mutex<T>> {
+    type Ref<'a>
+        = MutexGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn borrow_receiver(&self) -> Result<MutexGuard<'_, T>, RustCallError> {
+        self.lock().map_err(|_| lock_poisoned::<T>())
+    }
+}
+
+impl<T> BorrowMutReceiver<T> for Arc<Mutex<T>> {
+    type RefMut<'a>
+        = MutexGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn borrow_mut_receiver(&self) -> Result<MutexGuard<'_, T>, RustCallError> {
+        self.lock().map_err(|_| lock_poisoned::<T>())
+    }
+}
+
+impl<T> BorrowReceiver<T> for Arc<RwLock<T>> {
+    type Ref<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn borrow_receiver(&self) -> Result<RwLockReadGuard<'_, T>, RustCallError> {
+        self.read().map_err(|_| lock_poisoned::<T>())
+    }
+}
+
+impl<T> BorrowMutReceiver<T> for Arc<RwLock<T>> {
+    type RefMut<'a>
+        = RwLockWriteGuard<'a, T>
+    where
+        Self: 'a;
+
+    fn borrow_mut_receiver(&self) -> Result<RwLockWriteGuard<'_, T>, RustCallError> {
+        self.write().map_err(|_| lock_poisoned::<T>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_poisoned_lock() {
+        let counter = Arc::new(Mutex::new(0));
+        let counter2 = Arc::clone(&counter);
+        std::thread::spawn(move || {
+            let _guard = counter2.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join()
+        .unwrap_err();
+        assert!(matches!(
+            BorrowMutReceiver::<i32>::borrow_mut_receiver(&counter),
+            Err(RustCallError::InternalError(_))
+        ));
+    }
+}
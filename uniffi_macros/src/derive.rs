@@ -9,7 +9,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
-    DeriveInput,
+    DeriveInput, Type,
 };
 
 pub fn expand_derive(
@@ -113,6 +113,47 @@ impl DeriveOptions {
         }
     }
 
+    /// Generate a `LowerBorrowed` impl for a record or enum
+    ///
+    /// `write_impl` writes the fields of `obj`, which is a reference to the value, using
+    /// [write_borrowed].  The impl is bounded on the fields implementing `LowerBorrowed`, so items
+    /// with fields that can't be lowered from a reference still compile, they just can't be
+    /// returned by reference.
+    ///
+    /// This is only generated for the blanket impls.  The bounds need to reference the `UT`
+    /// param, otherwise the compiler checks them eagerly, which fails for fields that don't
+    /// implement `LowerBorrowed` and overflows for recursive types.
+    pub fn lower_borrowed_impl<'a>(
+        &self,
+        ident: &Ident,
+        field_types: impl IntoIterator<Item = &'a Type>,
+        write_impl: TokenStream,
+    ) -> Option<TokenStream> {
+        if self.local_tag {
+            return None;
+        }
+        let field_types = field_types.into_iter();
+        Some(quote! {
+            #[automatically_derived]
+            unsafe impl<UT> ::uniffi::LowerBorrowed<UT> for #ident
+            where
+                #(#field_types: ::uniffi::LowerBorrowed<UT>,)*
+            {
+                type FfiType = ::uniffi::RustBuffer;
+
+                fn lower_borrowed(obj: &Self) -> ::uniffi::RustBuffer {
+                    let mut buf = ::std::vec::Vec::new();
+                    <Self as ::uniffi::LowerBorrowed<UT>>::write_borrowed(obj, &mut buf);
+                    ::uniffi::RustBuffer::from_vec(buf)
+                }
+
+                fn write_borrowed(obj: &Self, buf: &mut ::std::vec::Vec<u8>) {
+                    #write_impl
+                }
+            }
+        })
+    }
+
     /// Generate a call to `derive_ffi_traits!` that will derive some of the FFI traits
     pub fn derive_ffi_traits(&self, ty: impl ToTokens, trait_names: &[&str]) -> TokenStream {
         let trait_idents = trait_names
@@ -133,3 +174,10 @@ impl DeriveOptions {
         }
     }
 }
+
+/// Write function for [DeriveOptions::lower_borrowed_impl]
+pub fn write_borrowed(ty: impl ToTokens) -> TokenStream {
+    quote! {
+        <#ty as ::uniffi::LowerBorrowed<UT>>::write_borrowed
+    }
+}
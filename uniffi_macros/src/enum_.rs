@@ -1,14 +1,16 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{
-    parse::ParseStream, spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Expr, Index, Lit,
-    LitStr, Token, Variant,
+    parse::ParseStream, spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Expr, Field,
+    Generics, Index, Lit, LitStr, Token, Type, Variant,
 };
 
 use crate::{
     default::default_value_metadata_calls,
-    ffiops,
+    derive, ffiops,
+    generics::{expand_generic_item, GenericItem},
     record::FieldAttributeArguments,
+    serde_names::{self, SerdeNames},
     util::{
         create_metadata_items, either_attribute_arg, extract_docstring, ident_to_string, kw,
         orig_name_metadata, try_metadata_value_from_usize, try_read_field, AttributeSliceExt,
@@ -21,11 +23,16 @@ use uniffi_meta::EnumShape;
 /// Stores parsed data from the Derive Input for the enum.
 pub struct EnumItem {
     ident: Ident,
+    generics: Generics,
     enum_: DataEnum,
     docstring: String,
     discr_type: Option<Ident>,
     non_exhaustive: bool,
     attr: EnumAttr,
+    // Name from either `#[uniffi(name)]` or `#[serde(rename)]`
+    name: Option<String>,
+    // Set when names come from the serde attributes
+    serde: Option<SerdeNames>,
 }
 
 impl EnumItem {
@@ -39,13 +46,26 @@ impl EnumItem {
                 ))
             }
         };
+        let attr: EnumAttr = input.attrs.parse_uniffi_attr_args()?;
+        let serde = attr
+            .serde_names
+            .is_some()
+            .then(|| SerdeNames::from_attrs(&input.attrs))
+            .transpose()?;
+        let name = attr
+            .name
+            .clone()
+            .or_else(|| serde.as_ref().and_then(|s| s.rename.clone()));
         Ok(Self {
             enum_,
             ident: input.ident,
+            generics: input.generics,
             docstring: extract_docstring(&input.attrs)?,
             discr_type: Self::extract_repr(&input.attrs)?,
             non_exhaustive: Self::extract_non_exhaustive(&input.attrs),
-            attr: input.attrs.parse_uniffi_attr_args()?,
+            attr,
+            name,
+            serde,
         })
     }
 
@@ -97,6 +117,10 @@ impl EnumItem {
         &self.ident
     }
 
+    pub fn is_generic(&self) -> bool {
+        !self.generics.params.is_empty()
+    }
+
     pub fn enum_(&self) -> &DataEnum {
         &self.enum_
     }
@@ -114,14 +138,45 @@ impl EnumItem {
     }
 
     pub fn foreign_name(&self) -> String {
-        match &self.attr.name {
+        match &self.name {
             Some(name) => name.clone(),
             None => ident_to_string(&self.ident),
         }
     }
 
     pub fn orig_name_metadata(&self) -> TokenStream {
-        orig_name_metadata(self.attr.name.is_some(), &self.ident)
+        orig_name_metadata(self.name.is_some(), &self.ident)
+    }
+
+    /// The name for a variant if it's renamed, either with `#[uniffi(name)]` or serde attributes
+    pub fn variant_rename(&self, v: &Variant) -> syn::Result<Option<String>> {
+        let attrs = v.attrs.parse_uniffi_attr_args::<VariantAttr>()?;
+        match (attrs.name, &self.serde) {
+            (Some(name), _) => Ok(Some(name)),
+            (None, Some(serde)) => serde_names::variant_name(v, serde.rename_all),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// The name for a variant field if it's renamed, either with `#[uniffi(name)]` or serde
+    /// attributes
+    pub fn variant_field_rename(
+        &self,
+        v: &Variant,
+        f: &Field,
+        attrs: &FieldAttributeArguments,
+    ) -> syn::Result<Option<String>> {
+        match (&attrs.name, &self.serde) {
+            (Some(name), _) => Ok(Some(name.clone())),
+            (None, Some(serde)) => {
+                // Like serde, the variant's `rename_all` overrides the enum's `rename_all_fields`
+                let rule = SerdeNames::from_attrs(&v.attrs)?
+                    .rename_all
+                    .or(serde.rename_all_fields);
+                serde_names::field_name(f, rule)
+            }
+            (None, None) => Ok(None),
+        }
     }
 
     pub fn is_flat_error(&self) -> bool {
@@ -136,7 +191,11 @@ impl EnumItem {
 pub fn expand_enum(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
     let item = EnumItem::new(input)?;
     item.check_attributes_valid_for_enum()?;
+    if item.is_generic() {
+        return expand_generic_enum(&item, &options);
+    }
     let ffi_converter_impl = enum_ffi_converter_impl(&item, &options);
+    let lower_borrowed_impl = enum_lower_borrowed_impl(&item, &options);
 
     let meta_static_var = options
         .generate_metadata
@@ -144,10 +203,41 @@ pub fn expand_enum(input: DeriveInput, options: DeriveOptions) -> syn::Result<To
 
     Ok(quote! {
         #ffi_converter_impl
+        #lower_borrowed_impl
         #meta_static_var
     })
 }
 
+fn expand_generic_enum(item: &EnumItem, options: &DeriveOptions) -> syn::Result<TokenStream> {
+    let field_types = item
+        .enum_()
+        .variants
+        .iter()
+        .flat_map(|v| &v.fields)
+        .map(|f| Ok((f, FieldAttributeArguments::for_field(f)?)))
+        .filter(|result| !matches!(result, Ok((_, attrs)) if attrs.skip.is_some()))
+        .map(|result| result.map(|(f, _)| &f.ty))
+        .collect::<syn::Result<_>>()?;
+    let metadata_calls = enum_metadata_calls(item)?;
+    expand_generic_item(
+        GenericItem {
+            ident: item.ident(),
+            generics: &item.generics,
+            renamed: item.name.is_some(),
+            field_types,
+            type_id_code: quote! { ::uniffi::metadata::codes::TYPE_ENUM },
+            metadata_code: quote! { ::uniffi::metadata::codes::ENUM },
+            write_impl: enum_write_impl(item),
+            try_read_impl: enum_try_read_impl(item),
+            metadata_calls: quote! {
+                .concat_bool(false)
+                #metadata_calls
+            },
+        },
+        options,
+    )
+}
+
 pub(crate) fn enum_ffi_converter_impl(item: &EnumItem, options: &DeriveOptions) -> TokenStream {
     enum_or_error_ffi_converter_impl(
         item,
@@ -176,6 +266,56 @@ fn enum_or_error_ffi_converter_impl(
     let ident = item.ident();
     let impl_spec = options.ffi_impl_header("FfiConverter", ident);
     let derive_ffi_traits = options.derive_all_ffi_traits(ident);
+    let write_impl = enum_write_impl(item);
+    let try_read_impl = enum_try_read_impl(item);
+
+    quote! {
+        #[automatically_derived]
+        unsafe #impl_spec {
+            ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
+
+            fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
+                #write_impl
+            }
+
+            fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                #try_read_impl
+            }
+
+            const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(#metadata_type_code)
+                .concat_str(module_path!())
+                .concat_str(#name);
+        }
+
+        #derive_ffi_traits
+    }
+}
+
+fn enum_lower_borrowed_impl(item: &EnumItem, options: &DeriveOptions) -> Option<TokenStream> {
+    let field_types = item
+        .enum_()
+        .variants
+        .iter()
+        .flat_map(|v| &v.fields)
+        .filter(|f| {
+            !FieldAttributeArguments::for_field(f)
+                .map(|attrs| attrs.skip.is_some())
+                .unwrap_or_default()
+        })
+        .map(|f| &f.ty);
+    // `obj` is a reference, so the match binds the fields by reference
+    options.lower_borrowed_impl(
+        item.ident(),
+        field_types,
+        enum_write_fields_impl(item, |ty| derive::write_borrowed(ty)),
+    )
+}
+
+fn enum_write_impl(item: &EnumItem) -> TokenStream {
+    enum_write_fields_impl(item, |ty| ffiops::write(ty))
+}
+
+fn enum_write_fields_impl(item: &EnumItem, write_fn: impl Fn(&Type) -> TokenStream) -> TokenStream {
     let mut write_match_arms: Vec<_> = item
         .enum_()
         .variants
@@ -194,16 +334,30 @@ fn enum_or_error_ffi_converter_impl(
                 })
                 .collect::<Vec<Ident>>();
             let idx = Index::from(i + 1);
-            let write_fields =
-                std::iter::zip(v.fields.iter(), field_idents.iter()).map(|(f, ident)| {
-                    let write = ffiops::write(&f.ty);
-                    quote! { #write(#ident, buf); }
-                });
             let is_tuple = v.fields.iter().any(|f| f.ident.is_none());
+            let mut field_patterns = vec![];
+            let mut write_fields = vec![];
+            for (f, ident) in std::iter::zip(v.fields.iter(), field_idents.iter()) {
+                let skipped = FieldAttributeArguments::for_field(f)
+                    .map(|attrs| attrs.skip.is_some())
+                    .unwrap_or_default();
+                if skipped {
+                    // Skipped fields aren't written, so don't bind them
+                    field_patterns.push(if is_tuple {
+                        quote! { _ }
+                    } else {
+                        quote! { #ident: _ }
+                    });
+                } else {
+                    let write = write_fn(&f.ty);
+                    field_patterns.push(quote! { #ident });
+                    write_fields.push(quote! { #write(#ident, buf); });
+                }
+            }
             let fields = if is_tuple {
-                quote! { ( #(#field_idents),* ) }
+                quote! { ( #(#field_patterns),* ) }
             } else {
-                quote! { { #(#field_idents),* } }
+                quote! { { #(#field_patterns),* } }
             };
 
             quote! {
@@ -219,10 +373,13 @@ fn enum_or_error_ffi_converter_impl(
             _ => ::std::panic!("Unexpected variant in non-exhaustive enum"),
         })
     }
-    let write_impl = quote! {
+    quote! {
         match obj { #(#write_match_arms)* }
-    };
+    }
+}
 
+fn enum_try_read_impl(item: &EnumItem) -> TokenStream {
+    let name = item.foreign_name();
     let try_read_match_arms = item.enum_().variants.iter().enumerate().map(|(i, v)| {
         let idx = Index::from(i + 1);
         let v_ident = &v.ident;
@@ -240,64 +397,53 @@ fn enum_or_error_ffi_converter_impl(
         }
     });
     let error_format_string = format!("Invalid {name} enum value: {{}}");
-    let try_read_impl = quote! {
+    quote! {
         ::uniffi::check_remaining(buf, 4)?;
 
         ::std::result::Result::Ok(match ::uniffi::deps::bytes::Buf::get_i32(buf) {
             #(#try_read_match_arms)*
             v => ::uniffi::deps::anyhow::bail!(#error_format_string, v),
         })
-    };
-
-    quote! {
-        #[automatically_derived]
-        unsafe #impl_spec {
-            ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
-
-            fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
-                #write_impl
-            }
-
-            fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
-                #try_read_impl
-            }
-
-            const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(#metadata_type_code)
-                .concat_str(module_path!())
-                .concat_str(#name);
-        }
-
-        #derive_ffi_traits
     }
 }
 
 pub(crate) fn enum_meta_static_var(item: &EnumItem) -> syn::Result<TokenStream> {
     let name = &item.foreign_name();
     let orig_name_calls = &item.orig_name_metadata();
-    let non_exhaustive = item.is_non_exhaustive();
-    let docstring = item.docstring();
-    let shape = EnumShape::Enum.as_u8();
+    let metadata_calls = enum_metadata_calls(item)?;
 
-    let mut metadata_expr = quote! {
+    let metadata_expr = quote! {
         ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::ENUM)
             .concat_str(module_path!())
             .concat_str(#name)
             #orig_name_calls
-            .concat_value(#shape)
+            #metadata_calls
+    };
+    Ok(create_metadata_items("enum", name, metadata_expr, None))
+}
+
+// Enum metadata for the shape, variants and docstring, which follows the name
+fn enum_metadata_calls(item: &EnumItem) -> syn::Result<TokenStream> {
+    let non_exhaustive = item.is_non_exhaustive();
+    let docstring = item.docstring();
+    let shape = EnumShape::Enum.as_u8();
+
+    let mut metadata_calls = quote! {
+        .concat_value(#shape)
     };
-    metadata_expr.extend(match item.discr_type() {
+    metadata_calls.extend(match item.discr_type() {
         None => quote! { .concat_bool(false) },
         Some(t) => {
             let type_id_meta = ffiops::type_id_meta(t);
             quote! { .concat_bool(true).concat(#type_id_meta) }
         }
     });
-    metadata_expr.extend(variant_metadata(item)?);
-    metadata_expr.extend(quote! {
+    metadata_calls.extend(variant_metadata(item)?);
+    metadata_calls.extend(quote! {
         .concat_bool(#non_exhaustive)
         .concat_long_str(#docstring)
     });
-    Ok(create_metadata_items("enum", name, metadata_expr, None))
+    Ok(metadata_calls)
 }
 
 fn variant_value(v: &Variant) -> syn::Result<TokenStream> {
@@ -356,27 +502,29 @@ pub fn variant_metadata(item: &EnumItem) -> syn::Result<Vec<TokenStream>> {
         try_metadata_value_from_usize(enum_.variants.len(), "UniFFI limits enums to 256 variants")?;
     std::iter::once(Ok(quote! { .concat_value(#variants_len) }))
         .chain(enum_.variants.iter().map(|v| {
+            let fields = v
+                .fields
+                .iter()
+                .map(|f| Ok((f, FieldAttributeArguments::for_field(f)?)))
+                .filter(|result| !matches!(result, Ok((_, attrs)) if attrs.skip.is_some()))
+                .collect::<syn::Result<Vec<_>>>()?;
             let fields_len = try_metadata_value_from_usize(
-                v.fields.len(),
+                fields.len(),
                 "UniFFI limits enum variants to 256 fields",
             )?;
 
-            let field_metadata = v
-                .fields
-                .iter()
-                .map(|f| {
-                    let attrs = f
-                        .attrs
-                        .parse_uniffi_attr_args::<FieldAttributeArguments>()?;
+            let field_metadata = fields
+                .into_iter()
+                .map(|(f, attrs)| {
+                    let rename = item.variant_field_rename(v, f, &attrs)?;
                     let field_orig_name = match &f.ident {
-                        Some(ident) => orig_name_metadata(attrs.name.is_some(), ident),
+                        Some(ident) => orig_name_metadata(rename.is_some(), ident),
                         None => quote! {
                             .concat_bool(false)
                         },
                     };
-                    let name = attrs
-                        .name
-                        .unwrap_or(f.ident.as_ref().map(ident_to_string).unwrap_or_default());
+                    let name =
+                        rename.unwrap_or(f.ident.as_ref().map(ident_to_string).unwrap_or_default());
                     let type_id = ffiops::type_id_meta(&f.ty);
                     let default_calls = default_value_metadata_calls(&attrs.default)?;
                     let docstring = extract_docstring(&f.attrs)?;
@@ -391,10 +539,9 @@ pub fn variant_metadata(item: &EnumItem) -> syn::Result<Vec<TokenStream>> {
                 })
                 .collect::<syn::Result<Vec<_>>>()?;
 
-            let attrs = v.attrs.parse_uniffi_attr_args::<VariantAttr>()?;
-
-            let variant_orig_name = orig_name_metadata(attrs.name.is_some(), &v.ident);
-            let name = attrs.name.unwrap_or(ident_to_string(&v.ident));
+            let rename = item.variant_rename(v)?;
+            let variant_orig_name = orig_name_metadata(rename.is_some(), &v.ident);
+            let name = rename.unwrap_or(ident_to_string(&v.ident));
             let value_tokens = variant_value(v)?;
 
             let docstring = extract_docstring(&v.attrs)?;
@@ -419,6 +566,7 @@ pub struct EnumAttr {
     pub flat_error: Option<kw::flat_error>,
     pub with_try_read: Option<kw::with_try_read>,
     pub name: Option<String>,
+    pub serde_names: Option<kw::serde_names>,
 }
 
 impl UniffiAttributeArgs for EnumAttr {
@@ -445,6 +593,11 @@ impl UniffiAttributeArgs for EnumAttr {
                 name,
                 ..Self::default()
             })
+        } else if lookahead.peek(kw::serde_names) {
+            Ok(Self {
+                serde_names: input.parse()?,
+                ..Self::default()
+            })
         } else {
             Err(lookahead.error())
         }
@@ -455,6 +608,7 @@ impl UniffiAttributeArgs for EnumAttr {
             flat_error: either_attribute_arg(self.flat_error, other.flat_error)?,
             with_try_read: either_attribute_arg(self.with_try_read, other.with_try_read)?,
             name: either_attribute_arg(self.name, other.name)?,
+            serde_names: either_attribute_arg(self.serde_names, other.serde_names)?,
         })
     }
 }
@@ -4,7 +4,7 @@ use syn::{DeriveInput, Index};
 use uniffi_meta::EnumShape;
 
 use crate::{
-    enum_::{rich_error_ffi_converter_impl, variant_metadata, EnumItem, VariantAttr},
+    enum_::{rich_error_ffi_converter_impl, variant_metadata, EnumItem},
     ffiops,
     util::{
         create_metadata_items, extract_docstring, ident_to_string, orig_name_metadata,
@@ -203,9 +203,9 @@ pub fn flat_error_variant_metadata(item: &EnumItem) -> syn::Result<Vec<TokenStre
         try_metadata_value_from_usize(enum_.variants.len(), "UniFFI limits enums to 256 variants")?;
     std::iter::once(Ok(quote! { .concat_value(#variants_len) }))
         .chain(enum_.variants.iter().map(|v| {
-            let attrs = v.attrs.parse_uniffi_attr_args::<VariantAttr>()?;
-            let orig_name = orig_name_metadata(attrs.name.is_some(), &v.ident);
-            let name = attrs.name.unwrap_or(ident_to_string(&v.ident));
+            let rename = item.variant_rename(v)?;
+            let orig_name = orig_name_metadata(rename.is_some(), &v.ident);
+            let name = rename.unwrap_or(ident_to_string(&v.ident));
 
             let docstring = extract_docstring(&v.attrs)?;
             Ok(quote! {
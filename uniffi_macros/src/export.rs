@@ -8,6 +8,7 @@ use syn::{visit_mut::VisitMut, Item, Type};
 
 mod attributes;
 mod callback_interface;
+mod constant;
 mod item;
 mod scaffolding;
 mod trait_interface;
@@ -17,6 +18,7 @@ use self::{
     item::{ExportItem, ImplItem},
     scaffolding::{
         gen_constructor_scaffolding, gen_ffi_function, gen_fn_scaffolding, gen_method_scaffolding,
+        gen_static_method_scaffolding,
     },
 };
 use crate::util::{create_metadata_items, ident_to_string, mod_path};
@@ -56,8 +58,12 @@ pub(crate) fn expand_export(
         } => {
             if let Some(rt) = &args.async_runtime {
                 let has_async_methods = items.iter().any(|item| {
-                    matches!(item, ImplItem::Method(sig) if sig.is_async)
-                        || matches!(item, ImplItem::Constructor(sig) if sig.is_async)
+                    matches!(
+                        item,
+                        ImplItem::Method(sig)
+                            | ImplItem::Constructor(sig)
+                            | ImplItem::StaticMethod(sig) if sig.is_async || sig.returns_stream
+                    )
                 });
 
                 if !has_async_methods {
@@ -86,6 +92,11 @@ pub(crate) fn expand_export(
                             trait_.as_ref(),
                         )
                     }
+                    ImplItem::StaticMethod(sig) => {
+                        let async_runtime =
+                            sig.async_runtime.clone().or(args.async_runtime.clone());
+                        gen_static_method_scaffolding(sig, async_runtime.as_ref(), udl_mode)
+                    }
                 })
                 .collect::<syn::Result<_>>()?;
             let trait_impl_tokens = trait_.map(|t| {
@@ -158,6 +169,12 @@ pub(crate) fn expand_export(
             uniffi_traits,
             ..
         } => utrait::expand_uniffi_trait_export(self_ident, uniffi_traits, include_meta),
+        ExportItem::Constant {
+            ident,
+            ty,
+            expr,
+            docstring,
+        } => constant::gen_constant_metadata(&ident, &ty, &expr, &docstring, include_meta),
     }
 }
 
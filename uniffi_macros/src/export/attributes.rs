@@ -250,6 +250,26 @@ impl UniffiAttributeArgs for ExportStructArgs {
             Ok(Self {
                 traits: HashSet::from([UniffiTraitDiscriminants::Ord]),
             })
+        } else if lookahead.peek(kw::Clone) {
+            input.parse::<Option<kw::Clone>>()?;
+            Ok(Self {
+                traits: HashSet::from([UniffiTraitDiscriminants::Clone]),
+            })
+        } else if lookahead.peek(kw::Default) {
+            input.parse::<Option<kw::Default>>()?;
+            Ok(Self {
+                traits: HashSet::from([UniffiTraitDiscriminants::Default]),
+            })
+        } else if lookahead.peek(kw::PartialOrd) {
+            input.parse::<Option<kw::PartialOrd>>()?;
+            Ok(Self {
+                traits: HashSet::from([UniffiTraitDiscriminants::PartialOrd]),
+            })
+        } else if lookahead.peek(kw::FromStr) {
+            input.parse::<Option<kw::FromStr>>()?;
+            Ok(Self {
+                traits: HashSet::from([UniffiTraitDiscriminants::FromStr]),
+            })
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -270,16 +290,21 @@ impl UniffiAttributeArgs for ExportStructArgs {
 #[derive(Clone)]
 pub enum AsyncRuntime {
     Tokio(LitStr),
+    /// Path to a type that implements `uniffi::AsyncRuntimeAdapter`
+    Adapter(Path),
 }
 
 impl Parse for AsyncRuntime {
     fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if !input.peek(LitStr) {
+            return Ok(Self::Adapter(input.parse()?));
+        }
         let lit: LitStr = input.parse()?;
         match lit.value().as_str() {
             "tokio" => Ok(Self::Tokio(lit)),
             _ => Err(syn::Error::new_spanned(
                 lit,
-                "unknown async runtime, currently only `tokio` is supported",
+                "unknown async runtime, expected `\"tokio\"` or a path to a type that implements `uniffi::AsyncRuntimeAdapter`",
             )),
         }
     }
@@ -289,6 +314,7 @@ impl ToTokens for AsyncRuntime {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             AsyncRuntime::Tokio(lit) => lit.to_tokens(tokens),
+            AsyncRuntime::Adapter(path) => path.to_tokens(tokens),
         }
     }
 }
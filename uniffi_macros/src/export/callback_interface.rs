@@ -43,6 +43,10 @@ pub(super) fn trait_impl(
                 sig.span,
                 "Constructors not allowed in trait interfaces",
             )),
+            ImplItem::StaticMethod(sig) => Err(syn::Error::new(
+                sig.span,
+                "Static methods not allowed in trait interfaces",
+            )),
             ImplItem::Method(sig) => Ok(sig),
         })
         .collect::<syn::Result<Vec<_>>>()?;
@@ -223,6 +227,12 @@ fn gen_method_impl(sig: &FnSignature, vtable_cell: &Ident) -> syn::Result<TokenS
     let self_param = match receiver {
         Some(ReceiverArg::Ref) => quote! { &self },
         Some(ReceiverArg::Arc) => quote! { self: Arc<Self> },
+        Some(ReceiverArg::RefMut) => {
+            return Err(syn::Error::new(
+                *span,
+                "callback interface methods can't take &mut self",
+            ));
+        }
         None => {
             return Err(syn::Error::new(
                 *span,
@@ -0,0 +1,260 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Expr, GenericArgument, Lit, Member, PathArguments, Type, UnOp};
+
+use crate::util::{create_metadata_items, ident_to_string};
+
+pub(super) fn gen_constant_metadata(
+    ident: &Ident,
+    ty: &Type,
+    expr: &Expr,
+    docstring: &str,
+    include_meta: bool,
+) -> syn::Result<TokenStream> {
+    // Exported constants are only read by the foreign bindings, so reference the item to keep
+    // `dead_code` from firing on it.
+    let use_ident = format_ident!("_uniffi_use_constant_{}", ident_to_string(ident));
+    let use_item = quote! {
+        #[doc(hidden)]
+        #[allow(dead_code, non_snake_case)]
+        fn #use_ident() {
+            let _ = &#ident;
+        }
+    };
+    if !include_meta {
+        return Ok(use_item);
+    }
+    let name = ident_to_string(ident);
+    let type_id_meta = type_id_meta(ty);
+    let literal_calls = literal_metadata_calls(expr, Some(ty), &type_id_meta)?;
+    let metadata_expr = quote! {
+        ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::CONSTANT)
+            .concat_str(module_path!())
+            .concat_str(#name)
+            .concat(#type_id_meta)
+            #literal_calls
+            .concat_long_str(#docstring)
+    };
+    let metadata_items = create_metadata_items("constant", &name, metadata_expr, None);
+    Ok(quote! {
+        #use_item
+        #metadata_items
+    })
+}
+
+// `&str` constants are exported as strings, everything else uses its own type.
+fn type_id_meta(ty: &Type) -> TokenStream {
+    match ty {
+        Type::Reference(r) if matches!(&*r.elem, Type::Path(p) if p.path.is_ident("str")) => {
+            quote! { <::std::string::String as ::uniffi::TypeId<crate::UniFfiTag>>::TYPE_ID_META }
+        }
+        _ => quote! { <#ty as ::uniffi::TypeId<crate::UniFfiTag>>::TYPE_ID_META },
+    }
+}
+
+/// Metadata calls for a constant value
+///
+/// `ty` is the declared type of the value, if it's known.  Record fields don't have one, since
+/// the macro only sees the field names.  `type_meta` is an expression for the `TYPE_ID_META` of
+/// the value, which is always available.
+fn literal_metadata_calls(
+    expr: &Expr,
+    ty: Option<&Type>,
+    type_meta: &TokenStream,
+) -> syn::Result<TokenStream> {
+    match expr {
+        Expr::Paren(e) => literal_metadata_calls(&e.expr, ty, type_meta),
+        Expr::Group(e) => literal_metadata_calls(&e.expr, ty, type_meta),
+        Expr::Lit(e) => lit_metadata_calls(&e.lit, ""),
+        Expr::Unary(e) if matches!(e.op, UnOp::Neg(_)) => match &*e.expr {
+            Expr::Lit(e) if matches!(e.lit, Lit::Int(_) | Lit::Float(_)) => {
+                lit_metadata_calls(&e.lit, "-")
+            }
+            _ => Err(unsupported(expr)),
+        },
+        Expr::Path(p) if p.path.is_ident("None") => Ok(quote! {
+            .concat_value(::uniffi::metadata::codes::LIT_NONE)
+        }),
+        // Paths like `Color::Red` are enum variants.  Builtin types don't have variants, so paths
+        // like `u32::MAX` must be other constants.  Record fields don't have a declared type, so
+        // check the first segment of the path instead and let `check_enum_literal` catch anything
+        // else at compile time.
+        Expr::Path(p) if p.qself.is_none() && p.path.segments.len() > 1 => {
+            let first = &p.path.segments.first().unwrap().ident;
+            if ty.is_some_and(is_builtin_type)
+                || is_builtin_name(first)
+                || first == "std"
+                || first == "core"
+            {
+                return Err(syn::Error::new_spanned(
+                    expr,
+                    "exported constants of builtin types must be literals, not paths to other constants",
+                ));
+            }
+            let variant = ident_to_string(&p.path.segments.last().unwrap().ident);
+            Ok(quote! {
+                .concat(::uniffi::metadata::check_enum_literal(#type_meta))
+                .concat_value(::uniffi::metadata::codes::LIT_ENUM)
+                .concat_str(#variant)
+            })
+        }
+        Expr::Call(call) if is_path(&call.func, &["Some"]) && call.args.len() == 1 => {
+            let inner =
+                literal_metadata_calls(&call.args[0], ty.and_then(option_inner_type), type_meta)?;
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_SOME)
+                .concat_value(::uniffi::metadata::codes::DEFVALUE_LITERAL)
+                #inner
+            })
+        }
+        Expr::Call(call) if is_path(&call.func, &["String", "new"]) && call.args.is_empty() => {
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_STR)
+                .concat_str("")
+            })
+        }
+        Expr::Struct(s) => {
+            if let Some(rest) = &s.rest {
+                return Err(syn::Error::new_spanned(
+                    rest,
+                    "struct update syntax is not supported in exported constants",
+                ));
+            }
+            let path = &s.path;
+            let len = u8::try_from(s.fields.len())
+                .map_err(|_| syn::Error::new_spanned(s, "too many fields in exported constant"))?;
+            let field_calls = s
+                .fields
+                .iter()
+                .map(|f| {
+                    let Member::Named(ident) = &f.member else {
+                        return Err(syn::Error::new_spanned(
+                            &f.member,
+                            "only named fields are supported in exported constants",
+                        ));
+                    };
+                    let name = ident_to_string(ident);
+                    let field_meta = quote! {
+                        ::uniffi::metadata::field_type_id_meta::<#path, _, crate::UniFfiTag>(
+                            |r| &r.#ident
+                        )
+                    };
+                    let value_calls = literal_metadata_calls(&f.expr, None, &field_meta)?;
+                    Ok(quote! {
+                        .concat_str(#name)
+                        .concat(#field_meta)
+                        #value_calls
+                    })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_RECORD)
+                .concat_value(#len)
+                #(#field_calls)*
+            })
+        }
+        _ => Err(unsupported(expr)),
+    }
+}
+
+fn lit_metadata_calls(lit: &Lit, sign: &str) -> syn::Result<TokenStream> {
+    match lit {
+        Lit::Str(s) => Ok(quote! {
+            .concat_value(::uniffi::metadata::codes::LIT_STR)
+            .concat_str(#s)
+        }),
+        Lit::Int(i) => {
+            let digits = format!("{sign}{}", i.base10_digits());
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_INT)
+                .concat_str(#digits)
+            })
+        }
+        Lit::Float(f) => {
+            let digits = format!("{sign}{}", f.base10_digits());
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_FLOAT)
+                .concat_str(#digits)
+            })
+        }
+        Lit::Bool(b) => Ok(quote! {
+            .concat_value(::uniffi::metadata::codes::LIT_BOOL)
+            .concat_bool(#b)
+        }),
+        Lit::Char(c) => {
+            let code_point = u32::from(c.value());
+            Ok(quote! {
+                .concat_value(::uniffi::metadata::codes::LIT_CHAR)
+                .concat_u32(#code_point)
+            })
+        }
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "this type of literal is not currently supported in exported constants",
+        )),
+    }
+}
+
+/// Is this one of the builtin types that constants can be declared with?
+fn is_builtin_type(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(r) => is_builtin_type(&r.elem),
+        Type::Paren(p) => is_builtin_type(&p.elem),
+        Type::Group(g) => is_builtin_type(&g.elem),
+        Type::Path(p) if p.qself.is_none() => p.path.get_ident().is_some_and(is_builtin_name),
+        _ => false,
+    }
+}
+
+fn is_builtin_name(ident: &Ident) -> bool {
+    [
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "f32", "f64", "bool",
+        "char", "str", "String",
+    ]
+    .iter()
+    .any(|name| ident == name)
+}
+
+/// The `T` in `Option<T>`
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(p) = ty else {
+        return None;
+    };
+    let segment = p.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    }
+}
+
+fn is_path(expr: &Expr, segments: &[&str]) -> bool {
+    match expr {
+        Expr::Path(p) => {
+            p.qself.is_none()
+                && p.path.segments.len() == segments.len()
+                && p.path
+                    .segments
+                    .iter()
+                    .zip(segments)
+                    .all(|(seg, name)| seg.ident == name && seg.arguments.is_none())
+        }
+        _ => false,
+    }
+}
+
+fn unsupported(expr: &Expr) -> syn::Error {
+    syn::Error::new_spanned(
+        expr,
+        "unsupported constant value: exported constants must be literals, enum variants or records of those",
+    )
+}
@@ -48,6 +48,12 @@ pub(super) enum ExportItem {
         self_ident: Ident,
         uniffi_traits: Vec<UniffiTraitDiscriminants>,
     },
+    Constant {
+        ident: Ident,
+        ty: syn::Type,
+        expr: syn::Expr,
+        docstring: String,
+    },
 }
 
 impl ExportItem {
@@ -63,7 +69,30 @@ impl ExportItem {
             syn::Item::Trait(item) => Self::from_trait(item, attr_args),
             syn::Item::Struct(item) => Self::from_struct(item, attr_args),
             syn::Item::Enum(item) => Self::from_enum(item, attr_args),
-            // FIXME: Support const / static?
+            syn::Item::Const(item) => {
+                syn::parse::<syn::parse::Nothing>(attr_args)?;
+                Ok(Self::Constant {
+                    docstring: extract_docstring(&item.attrs)?,
+                    ident: item.ident,
+                    ty: *item.ty,
+                    expr: *item.expr,
+                })
+            }
+            syn::Item::Static(item) => {
+                syn::parse::<syn::parse::Nothing>(attr_args)?;
+                if let syn::StaticMutability::Mut(m) = item.mutability {
+                    return Err(syn::Error::new_spanned(
+                        m,
+                        "`static mut` items are not supported by uniffi::export",
+                    ));
+                }
+                Ok(Self::Constant {
+                    docstring: extract_docstring(&item.attrs)?,
+                    ident: item.ident,
+                    ty: *item.ty,
+                    expr: *item.expr,
+                })
+            }
             _ => Err(syn::Error::new(
                 Span::call_site(),
                 "unsupported item: This block doesn't support `uniffi::export`",
@@ -99,6 +128,7 @@ impl ExportItem {
             }
         };
 
+        let is_trait_impl = item.trait_.is_some();
         let items = item
             .items
             .into_iter()
@@ -130,6 +160,14 @@ impl ExportItem {
                         attrs.args,
                         docstring,
                     )?)
+                } else if impl_fn.sig.receiver().is_none() && !is_trait_impl {
+                    ImplItem::StaticMethod(FnSignature::new_static_method(
+                        self_ident.clone(),
+                        foreign_self_ident,
+                        impl_fn.sig,
+                        attrs.args,
+                        docstring,
+                    )?)
                 } else {
                     ImplItem::Method(FnSignature::new_method(
                         self_ident.clone(),
@@ -198,11 +236,20 @@ impl ExportItem {
                         ));
                     }
                 };
-                if let Some(default) = tim.default {
-                    return Err(syn::Error::new_spanned(
-                        default,
-                        "uniffi::export'd trait methods can't have a default implementation.",
-                    ));
+                let has_default = tim.default.is_some();
+                if let Some(default) = &tim.default {
+                    if callback_interface_only {
+                        return Err(syn::Error::new_spanned(
+                            default,
+                            "callback interface methods can't have a default implementation.",
+                        ));
+                    }
+                    if tim.sig.asyncness.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            default,
+                            "async trait methods can't have a default implementation.",
+                        ));
+                    }
                 }
 
                 let docstring = extract_docstring(&tim.attrs)?;
@@ -218,6 +265,7 @@ impl ExportItem {
                         tim.sig,
                         attrs.args,
                         i as u32,
+                        has_default,
                         docstring,
                     )?)
                 };
@@ -270,6 +318,7 @@ impl ExportItem {
 pub(super) enum ImplItem {
     Constructor(FnSignature),
     Method(FnSignature),
+    StaticMethod(FnSignature),
 }
 
 fn type_as_type_path(ty: &syn::Type) -> syn::Result<&syn::TypePath> {
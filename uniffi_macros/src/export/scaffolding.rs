@@ -6,10 +6,10 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use std::iter;
 
-use super::attributes::AsyncRuntime;
+use super::{attributes::AsyncRuntime, trait_interface::default_method_ident};
 use crate::{
     ffiops,
-    fnsig::{FnKind, FnSignature},
+    fnsig::{FnKind, FnSignature, ReceiverArg},
 };
 
 pub(super) fn gen_fn_scaffolding(
@@ -23,11 +23,11 @@ pub(super) fn gen_fn_scaffolding(
             "Unexpected self param (Note: uniffi::export must be used on the impl block, not its containing fn's)"
         ));
     }
-    if !sig.is_async {
+    if !sig.is_async && !sig.returns_stream {
         if let Some(async_runtime) = ar {
             return Err(syn::Error::new_spanned(
                 async_runtime,
-                "this attribute is only allowed on async functions",
+                "this attribute is only allowed on async functions and functions that return streams",
             ));
         }
     }
@@ -64,6 +64,22 @@ pub(super) fn gen_constructor_scaffolding(
     })
 }
 
+pub(super) fn gen_static_method_scaffolding(
+    sig: FnSignature,
+    ar: Option<&AsyncRuntime>,
+    udl_mode: bool,
+) -> syn::Result<TokenStream> {
+    let metadata_items = (!udl_mode).then(|| {
+        sig.metadata_items()
+            .unwrap_or_else(syn::Error::into_compile_error)
+    });
+    let scaffolding_func = gen_ffi_function(&sig, ar, udl_mode, None)?;
+    Ok(quote! {
+        #scaffolding_func
+        #metadata_items
+    })
+}
+
 pub(super) fn gen_method_scaffolding(
     sig: FnSignature,
     ar: Option<&AsyncRuntime>,
@@ -75,9 +91,26 @@ pub(super) fn gen_method_scaffolding(
             sig.span,
             "associated functions are not currently supported",
         ));
+    } else if sig.is_async && matches!(sig.receiver, Some(ReceiverArg::RefMut)) {
+        return Err(syn::Error::new(
+            sig.span,
+            "async methods can't take &mut self",
+        ));
     } else {
         gen_ffi_function(&sig, ar, udl_mode, use_trait)?
     };
+    // Trait methods with a default implementation get a second scaffolding function, which
+    // foreign implementations use to call the Rust default.
+    let default_scaffolding_func = match sig.default_scaffolding_fn_ident() {
+        Some(ffi_ident) => Some(gen_ffi_function_from_bits(
+            &sig,
+            ScaffoldingBits::new_for_trait_default_method(&sig),
+            ffi_ident,
+            ar,
+            None,
+        )?),
+        None => None,
+    };
 
     let metadata_items = (!udl_mode).then(|| {
         sig.metadata_items()
@@ -85,6 +118,7 @@ pub(super) fn gen_method_scaffolding(
     });
     Ok(quote! {
         #scaffolding_func
+        #default_scaffolding_func
         #metadata_items
     })
 }
@@ -97,6 +131,9 @@ struct ScaffoldingBits {
     param_types: Vec<TokenStream>,
     /// Lift closure.  See `FnSignature::lift_closure` for an explanation of this.
     lift_closure: TokenStream,
+    /// Statements to borrow `self` from the lifted args, for sync methods that input a reference.
+    /// This binds `uniffi_self`, which `rust_fn_call` uses.
+    borrow_self: TokenStream,
     /// Expression to call the Rust function after a successful lift.
     rust_fn_call: TokenStream,
     /// Convert the result of `rust_fn_call`, stored in a variable named `uniffi_result` into its final value.
@@ -120,6 +157,7 @@ impl ScaffoldingBits {
             param_names: sig.scaffolding_param_names().collect(),
             param_types: sig.scaffolding_param_types().collect(),
             lift_closure: sig.lift_closure(None),
+            borrow_self: quote! {},
             rust_fn_call,
             convert_result,
         }
@@ -138,7 +176,7 @@ impl ScaffoldingBits {
             quote! { #self_ident }
         };
 
-        let ref_type = ffiops::lift_ref_type(&self_type);
+        let ref_type = ffiops::lift_receiver_type(&self_type);
         let lift_type = ffiops::lift_type(&ref_type);
         let try_lift = ffiops::try_lift(&ref_type);
 
@@ -151,7 +189,40 @@ impl ScaffoldingBits {
             }
         }));
         let call_params = sig.rust_call_params(true);
-        let rust_fn_call = quote! { uniffi_args.0.#ident(#call_params) };
+        // For sync methods that input a reference, borrow `self` from the lifted value.  This
+        // locks objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`.  The borrow
+        // lasts until the return value is lowered, since it may be a reference into `self`.
+        //
+        // Async methods can't hold a lock across `.await` points, `AsyncReceiver` is only
+        // implemented for objects that aren't stored behind a lock.
+        let (borrow_self, rust_fn_call) = match (&sig.receiver, sig.is_async) {
+            (Some(ReceiverArg::Ref), false) => (
+                quote! {
+                    let uniffi_self = match <#ref_type as ::uniffi::BorrowReceiver<#self_type>>::borrow_receiver(&uniffi_args.0) {
+                        ::std::result::Result::Ok(v) => v,
+                        ::std::result::Result::Err(e) => return ::std::result::Result::Err(e),
+                    };
+                },
+                quote! { uniffi_self.#ident(#call_params) },
+            ),
+            (Some(ReceiverArg::RefMut), false) => (
+                quote! {
+                    let mut uniffi_self = match <#ref_type as ::uniffi::BorrowMutReceiver<#self_type>>::borrow_mut_receiver(&uniffi_args.0) {
+                        ::std::result::Result::Ok(v) => v,
+                        ::std::result::Result::Err(e) => return ::std::result::Result::Err(e),
+                    };
+                },
+                quote! { (*uniffi_self).#ident(#call_params) },
+            ),
+            (Some(ReceiverArg::Ref), true) => (
+                quote! {},
+                quote! {
+                    <#ref_type as ::uniffi::AsyncReceiver<#self_type>>::async_receiver(&uniffi_args.0)
+                        .#ident(#call_params)
+                },
+            ),
+            _ => (quote! {}, quote! { uniffi_args.0.#ident(#call_params) }),
+        };
         // UDL mode adds an extra conversion (#1749)
         let convert_result = if udl_mode && sig.looks_like_result {
             quote! { uniffi_result .map_err(::std::convert::Into::into) }
@@ -167,6 +238,42 @@ impl ScaffoldingBits {
                 .chain(sig.scaffolding_param_types())
                 .collect(),
             lift_closure,
+            borrow_self,
+            rust_fn_call,
+            convert_result,
+        }
+    }
+
+    fn new_for_trait_default_method(sig: &FnSignature) -> Self {
+        let self_ident = match &sig.kind {
+            FnKind::TraitMethod { self_ident, .. } => self_ident,
+            _ => unreachable!("default implementations are only supported for trait methods"),
+        };
+        let default_ident = default_method_ident(&sig.ident);
+        let call_params = sig.rust_call_params(true);
+        Self {
+            borrow_self: quote! {},
+            rust_fn_call: quote! { uniffi_args.0.#default_ident(#call_params) },
+            ..Self::new_for_method(sig, self_ident, true, false)
+        }
+    }
+
+    fn new_for_static_method(sig: &FnSignature, self_ident: &Ident, udl_mode: bool) -> Self {
+        let ident = &sig.ident;
+        let call_params = sig.rust_call_params(false);
+        let rust_fn_call = quote! { #self_ident::#ident(#call_params) };
+        // UDL mode adds an extra conversion (#1749)
+        let convert_result = if udl_mode && sig.looks_like_result {
+            quote! { uniffi_result.map_err(::std::convert::Into::into) }
+        } else {
+            quote! { uniffi_result }
+        };
+
+        Self {
+            param_names: sig.scaffolding_param_names().collect(),
+            param_types: sig.scaffolding_param_types().collect(),
+            lift_closure: sig.lift_closure(None),
+            borrow_self: quote! {},
             rust_fn_call,
             convert_result,
         }
@@ -190,6 +297,7 @@ impl ScaffoldingBits {
             param_names: sig.scaffolding_param_names().collect(),
             param_types: sig.scaffolding_param_types().collect(),
             lift_closure: sig.lift_closure(None),
+            borrow_self: quote! {},
             rust_fn_call,
             convert_result,
         }
@@ -206,17 +314,14 @@ pub(super) fn gen_ffi_function(
     udl_mode: bool,
     use_trait: Option<&syn::Path>,
 ) -> syn::Result<TokenStream> {
-    let ScaffoldingBits {
-        param_names,
-        param_types,
-        lift_closure,
-        rust_fn_call,
-        convert_result,
-    } = match &sig.kind {
+    let bits = match &sig.kind {
         FnKind::Function => ScaffoldingBits::new_for_function(sig, udl_mode),
         FnKind::Method { self_ident, .. } => {
             ScaffoldingBits::new_for_method(sig, self_ident, false, udl_mode)
         }
+        FnKind::StaticMethod { self_ident, .. } => {
+            ScaffoldingBits::new_for_static_method(sig, self_ident, udl_mode)
+        }
         FnKind::TraitMethod { self_ident, .. } => {
             ScaffoldingBits::new_for_method(sig, self_ident, true, udl_mode)
         }
@@ -224,8 +329,46 @@ pub(super) fn gen_ffi_function(
             ScaffoldingBits::new_for_constructor(sig, self_ident, udl_mode)
         }
     };
+    gen_ffi_function_from_bits(sig, bits, sig.scaffolding_fn_ident()?, ar, use_trait)
+}
+
+fn gen_ffi_function_from_bits(
+    sig: &FnSignature,
+    bits: ScaffoldingBits,
+    ffi_ident: Ident,
+    ar: Option<&AsyncRuntime>,
+    use_trait: Option<&syn::Path>,
+) -> syn::Result<TokenStream> {
+    let ScaffoldingBits {
+        param_names,
+        param_types,
+        lift_closure,
+        borrow_self,
+        rust_fn_call,
+        convert_result,
+    } = bits;
+
+    let convert_result = if sig.returns_stream {
+        // The items are fetched with futures, which need the async runtime too
+        match ar {
+            Some(AsyncRuntime::Tokio(_)) => quote! {
+                ::uniffi::RustStream::with_runtime(#convert_result, |next| {
+                    ::std::boxed::Box::pin(::uniffi::deps::async_compat::Compat::new(next))
+                })
+            },
+            Some(AsyncRuntime::Adapter(path)) => quote! {
+                ::uniffi::RustStream::with_runtime(#convert_result, |next| {
+                    ::std::boxed::Box::pin(<#path as ::uniffi::AsyncRuntimeAdapter>::wrap(next))
+                })
+            },
+            None => quote! { ::uniffi::RustStream::new(#convert_result) },
+        }
+    } else if sig.returns_iterator {
+        quote! { ::uniffi::Iter::new(#convert_result) }
+    } else {
+        convert_result
+    };
 
-    let ffi_ident = sig.scaffolding_fn_ident()?;
     let ffi_fn_name = ffi_ident.to_string();
     let name = &sig.name;
     let return_ty = &sig.return_ty;
@@ -251,6 +394,7 @@ pub(super) fn gen_ffi_function(
                     let result = match uniffi_lift_args() {
                         ::std::result::Result::Ok(uniffi_args) => {
                             ::uniffi::deps::trace!("lift_args success: {}", #ffi_fn_name);
+                            #borrow_self
                             let uniffi_result = #rust_fn_call;
                             ::uniffi::deps::trace!("call success: {}", #ffi_fn_name);
                             let uniffi_lowered_return = #lower_return(#convert_result);
@@ -269,10 +413,15 @@ pub(super) fn gen_ffi_function(
             #scaffolding_fn_ffi_buffer_version
         }
     } else {
-        let mut future_expr = rust_fn_call;
-        if matches!(ar, Some(AsyncRuntime::Tokio(_))) {
-            future_expr = quote! { ::uniffi::deps::async_compat::Compat::new(#future_expr) }
-        }
+        let future_expr = match ar {
+            Some(AsyncRuntime::Tokio(_)) => {
+                quote! { ::uniffi::deps::async_compat::Compat::new(#rust_fn_call) }
+            }
+            Some(AsyncRuntime::Adapter(path)) => {
+                quote! { <#path as ::uniffi::AsyncRuntimeAdapter>::wrap(#rust_fn_call) }
+            }
+            None => rust_fn_call,
+        };
         let scaffolding_fn_ffi_buffer_version =
             ffi_buffer_scaffolding_fn(&ffi_ident, &quote! { ::uniffi::Handle}, &param_types, false);
 
@@ -273,11 +273,34 @@ pub fn alter_trait(item: &ItemTrait) -> TokenStream {
         ..
     } = item;
 
+    // For each method with a default implementation, add a hidden copy of it.  Implementors
+    // never override these, which lets the default scaffolding functions call the Rust default
+    // even when the foreign side has overridden the original method.
+    let default_method_copies = items.iter().filter_map(|item| match item {
+        syn::TraitItem::Fn(syn::TraitItemFn {
+            sig,
+            default: Some(block),
+            ..
+        }) => {
+            let sig = syn::Signature {
+                ident: default_method_ident(&sig.ident),
+                ..sig.clone()
+            };
+            Some(quote! {
+                #[doc(hidden)]
+                #sig #block
+            })
+        }
+        _ => None,
+    });
+
     quote! {
         #(#attrs)*
         #vis #unsafety #auto_token #trait_token #ident #generics #colon_token #supertraits {
             #(#items)*
 
+            #(#default_method_copies)*
+
             #[doc(hidden)]
             fn uniffi_foreign_handle(&self) -> ::std::option::Option<::uniffi::Handle> {
                 ::std::option::Option::None
@@ -285,3 +308,11 @@ pub fn alter_trait(item: &ItemTrait) -> TokenStream {
         }
     }
 }
+
+/// Ident for the hidden copy of a trait method's default implementation, see [alter_trait]
+pub(super) fn default_method_ident(ident: &Ident) -> Ident {
+    Ident::new(
+        &format!("uniffi_default_{}", ident_to_string(ident)),
+        ident.span(),
+    )
+}
@@ -163,6 +163,114 @@ pub(crate) fn expand_uniffi_trait_export(
                     ));
                 }
             }
+            UniffiTraitDiscriminants::Clone => {
+                let method = quote! {
+                    fn uniffi_trait_clone(&self) -> #self_ident {
+                        ::uniffi::deps::static_assertions::assert_impl_all!(#self_ident: ::std::clone::Clone);
+                        ::std::clone::Clone::clone(self)
+                    }
+                };
+                let (ffi_func, method_meta) = process_uniffi_trait_method(&method, &self_ident)?;
+                // metadata for the trait - which includes metadata for the method.
+                let discr = UniffiTraitDiscriminants::Clone as u8;
+                impl_items.push(method);
+                global_items.push(ffi_func);
+                if include_metadata {
+                    global_items.push(crate::util::create_metadata_items(
+                        "uniffi_trait",
+                        &format!("{}_Clone", self_ident.unraw()),
+                        quote! {
+                            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::UNIFFI_TRAIT)
+                            .concat_value(#discr)
+                            .concat(#method_meta)
+                        },
+                        None,
+                    ));
+                }
+            }
+            UniffiTraitDiscriminants::Default => {
+                let method = quote! {
+                    fn uniffi_trait_default() -> #self_ident {
+                        ::uniffi::deps::static_assertions::assert_impl_all!(#self_ident: ::std::default::Default);
+                        ::std::default::Default::default()
+                    }
+                };
+                let (ffi_func, method_meta) =
+                    process_uniffi_trait_static_method(&method, &self_ident)?;
+                // metadata for the trait - which includes metadata for the static method.
+                let discr = UniffiTraitDiscriminants::Default as u8;
+                impl_items.push(method);
+                global_items.push(ffi_func);
+                if include_metadata {
+                    global_items.push(crate::util::create_metadata_items(
+                        "uniffi_trait",
+                        &format!("{}_Default", self_ident.unraw()),
+                        quote! {
+                            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::UNIFFI_TRAIT)
+                            .concat_value(#discr)
+                            .concat(#method_meta)
+                        },
+                        None,
+                    ));
+                }
+            }
+            UniffiTraitDiscriminants::PartialOrd => {
+                let method_partial_cmp = quote! {
+                    fn uniffi_trait_partial_ord_partial_cmp(&self, other: &#self_ident) -> ::std::option::Option<i8> {
+                        use ::std::cmp::PartialOrd;
+                        ::uniffi::deps::static_assertions::assert_impl_all!(#self_ident: PartialOrd); // This object has a trait method which requires `PartialOrd` be implemented.
+                        PartialOrd::partial_cmp(self, other).map(|ordering| ordering as i8)
+                    }
+                };
+                let (ffi_func_partial_cmp, method_meta_partial_cmp) =
+                    process_uniffi_trait_method(&method_partial_cmp, &self_ident)?;
+                // metadata for the trait itself.
+                let discr = UniffiTraitDiscriminants::PartialOrd as u8;
+                impl_items.push(method_partial_cmp);
+                global_items.push(ffi_func_partial_cmp);
+                if include_metadata {
+                    global_items.push(crate::util::create_metadata_items(
+                        "uniffi_trait",
+                        &format!("{}_PartialOrd", self_ident.unraw()),
+                        quote! {
+                            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::UNIFFI_TRAIT)
+                            .concat_value(#discr)
+                            .concat(#method_meta_partial_cmp)
+                        },
+                        None,
+                    ));
+                }
+            }
+            UniffiTraitDiscriminants::FromStr => {
+                // The `FromStr::Err` type must be exported as an error, since it's thrown by the
+                // generated static method.
+                let method = quote! {
+                    fn uniffi_trait_from_str(
+                        s: String,
+                    ) -> ::std::result::Result<#self_ident, <#self_ident as ::std::str::FromStr>::Err> {
+                        ::uniffi::deps::static_assertions::assert_impl_all!(#self_ident: ::std::str::FromStr);
+                        ::std::str::FromStr::from_str(&s)
+                    }
+                };
+                let (ffi_func, method_meta) =
+                    process_uniffi_trait_static_method(&method, &self_ident)?;
+                // metadata for the trait - which includes metadata for the static method.
+                let discr = UniffiTraitDiscriminants::FromStr as u8;
+                impl_items.push(method);
+                global_items.push(ffi_func);
+                if include_metadata {
+                    global_items.push(crate::util::create_metadata_items(
+                        "uniffi_trait",
+                        &format!("{}_FromStr", self_ident.unraw()),
+                        quote! {
+                            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::UNIFFI_TRAIT)
+                            .concat_value(#discr)
+                            .concat(#method_meta)
+                        },
+                        None,
+                    ));
+                }
+            }
         }
     }
     Ok(quote! {
@@ -209,3 +317,27 @@ fn process_uniffi_trait_method(
     .metadata_expr()?;
     Ok((ffi_func, method_meta))
 }
+
+fn process_uniffi_trait_static_method(
+    method: &TokenStream,
+    self_ident: &Ident,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let item = syn::parse(method.clone().into())?;
+
+    let syn::Item::Fn(item) = item else {
+        unreachable!()
+    };
+
+    let docstring = extract_docstring(&item.attrs)?;
+    let sig = FnSignature::new_static_method(
+        self_ident.clone(),
+        self_ident.clone(),
+        item.sig,
+        ExportFnArgs::default(),
+        docstring,
+    )?;
+    let ffi_func = gen_ffi_function(&sig, None, false, None)?;
+    // metadata for the static method, which will be packed inside metadata for the trait.
+    let method_meta = sig.metadata_expr()?;
+    Ok((ffi_func, method_meta))
+}
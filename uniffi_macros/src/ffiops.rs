@@ -93,6 +93,11 @@ pub fn lift_ref_type(ty: impl ToTokens) -> TokenStream {
     quote! { <#ty as ::uniffi::LiftRef<crate::UniFfiTag>>::LiftType }
 }
 
+/// LiftReceiver type
+pub fn lift_receiver_type(ty: impl ToTokens) -> TokenStream {
+    quote! { <#ty as ::uniffi::LiftReceiver<crate::UniFfiTag>>::LiftType }
+}
+
 /// Lower into rust buffer function
 pub fn lower_into_rust_buffer(ty: impl ToTokens) -> TokenStream {
     quote! {
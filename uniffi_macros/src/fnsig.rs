@@ -13,7 +13,10 @@ use crate::{
 };
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{spanned::Spanned, FnArg, Ident, Pat, Receiver, ReturnType, Type};
+use syn::{
+    spanned::Spanned, visit_mut::VisitMut, FnArg, GenericArgument, Ident, Lifetime, Pat,
+    PathArguments, Receiver, ReturnType, Type, TypeParamBound, TypeReference,
+};
 
 /// Syntactic check for `&[u8]`. Matches the bare identifier `u8` only —
 /// fully-qualified paths like `&[::std::primitive::u8]` or user-defined
@@ -43,6 +46,12 @@ pub(crate) struct FnSignature {
     pub receiver: Option<ReceiverArg>,
     pub args: Vec<NamedArg>,
     pub return_ty: TokenStream,
+    // Does the Rust function return `impl Stream<Item = T>`?
+    // If so, `return_ty` is `RustStream<T>` and the scaffolding wraps the returned stream.
+    pub returns_stream: bool,
+    // Does the Rust function return `impl Iterator<Item = T>` or `Box<dyn Iterator<Item = T>>`?
+    // If so, `return_ty` is `Iter<T>` and the scaffolding wraps the returned iterator.
+    pub returns_iterator: bool,
     // Does this the return type look like a result?
     // Only use this in UDL mode.
     // In general, it's not reliable because it fails for type aliases.
@@ -95,15 +104,38 @@ impl FnSignature {
         )
     }
 
+    pub(crate) fn new_static_method(
+        self_ident: Ident,
+        foreign_self_ident: Ident,
+        sig: syn::Signature,
+        args: ExportFnArgs,
+        docstring: String,
+    ) -> syn::Result<Self> {
+        Self::new(
+            FnKind::StaticMethod {
+                self_ident,
+                foreign_self_ident,
+            },
+            sig,
+            args,
+            docstring,
+        )
+    }
+
     pub(crate) fn new_trait_method(
         self_ident: Ident,
         sig: syn::Signature,
         args: ExportFnArgs,
         index: u32,
+        has_default: bool,
         docstring: String,
     ) -> syn::Result<Self> {
         Self::new(
-            FnKind::TraitMethod { self_ident, index },
+            FnKind::TraitMethod {
+                self_ident,
+                index,
+                has_default,
+            },
             sig,
             args,
             docstring,
@@ -119,12 +151,35 @@ impl FnSignature {
         let span = sig.span();
         let ident = sig.ident;
         let looks_like_result = looks_like_result(&sig.output);
-        let output = match sig.output {
-            ReturnType::Default => quote! { () },
-            ReturnType::Type(_, ty) => quote! { #ty },
+        let mut returns_borrowed = false;
+        let (output, returns_stream, returns_iterator) = match sig.output {
+            ReturnType::Default => (quote! { () }, false, false),
+            ReturnType::Type(_, mut ty) => {
+                if let Some(item_ty) = stream_item_type(&ty) {
+                    (quote! { ::uniffi::RustStream<#item_ty> }, true, false)
+                } else if let Some(item_ty) = iterator_item_type(&ty) {
+                    (quote! { ::uniffi::Iter<#item_ty> }, false, true)
+                } else {
+                    returns_borrowed = erase_return_lifetimes(&mut ty);
+                    (quote! { #ty }, false, false)
+                }
+            }
         };
         let is_async = sig.asyncness.is_some();
 
+        if returns_stream && matches!(kind, FnKind::TraitMethod { .. }) {
+            return Err(syn::Error::new(
+                span,
+                "Streams can't be returned from trait methods",
+            ));
+        }
+        if returns_iterator && matches!(kind, FnKind::TraitMethod { .. }) {
+            return Err(syn::Error::new(
+                span,
+                "Iterators can't be returned from trait methods",
+            ));
+        }
+
         let mut input_iter = sig
             .inputs
             .into_iter()
@@ -151,6 +206,17 @@ impl FnSignature {
             })
             .collect::<syn::Result<Vec<_>>>()?;
 
+        if returns_borrowed {
+            let is_method = matches!(kind, FnKind::Method { .. })
+                && matches!(receiver, Some(ReceiverArg::Ref | ReceiverArg::RefMut));
+            if !is_method || is_async {
+                return Err(syn::Error::new(
+                    span,
+                    "Borrowed return values are only supported for sync methods that take `&self` or `&mut self`",
+                ));
+            }
+        }
+
         if let Some(ident) = export_fn_args.defaults.idents().first() {
             return Err(syn::Error::new(
                 ident.span(),
@@ -158,7 +224,8 @@ impl FnSignature {
             ));
         }
 
-        if !is_async && export_fn_args.async_runtime.is_some() {
+        // Stream items are fetched with futures, so streams can use an async runtime too
+        if !is_async && !returns_stream && export_fn_args.async_runtime.is_some() {
             return Err(syn::Error::new(
                 export_fn_args.async_runtime.span(),
                 "Function not async".to_string(),
@@ -179,6 +246,8 @@ impl FnSignature {
             receiver,
             args,
             return_ty: output,
+            returns_stream,
+            returns_iterator,
             looks_like_result,
             docstring,
         })
@@ -246,6 +315,12 @@ impl FnSignature {
                 let object_name = ident_to_string(foreign_self_ident);
                 uniffi_meta::method_symbol_name(&self.mod_path, &object_name, name)
             }
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let self_name = ident_to_string(foreign_self_ident);
+                uniffi_meta::static_method_symbol_name(&self.mod_path, &self_name, name)
+            }
             FnKind::TraitMethod { self_ident, .. } => {
                 uniffi_meta::method_symbol_name(&self.mod_path, &ident_to_string(self_ident), name)
             }
@@ -259,6 +334,27 @@ impl FnSignature {
         Ok(Ident::new(&name, Span::call_site()))
     }
 
+    /// Name of the scaffolding function that calls the default implementation of a trait method
+    ///
+    /// Returns `None` if this isn't a trait method with a default implementation.
+    pub fn default_scaffolding_fn_ident(&self) -> Option<Ident> {
+        match &self.kind {
+            FnKind::TraitMethod {
+                self_ident,
+                has_default: true,
+                ..
+            } => {
+                let name = uniffi_meta::trait_default_method_symbol_name(
+                    &self.mod_path,
+                    &ident_to_string(self_ident),
+                    &self.name,
+                );
+                Some(Ident::new(&name, Span::call_site()))
+            }
+            _ => None,
+        }
+    }
+
     /// Scaffolding parameters expressions for each of our arguments
     pub fn scaffolding_param_names(&self) -> impl Iterator<Item = TokenStream> + '_ {
         self.args.iter().map(|a| {
@@ -327,7 +423,29 @@ impl FnSignature {
                 })
             }
 
-            FnKind::TraitMethod { self_ident, index } => {
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let self_name = ident_to_string(foreign_self_ident);
+                Ok(quote! {
+                    ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::STATIC_METHOD)
+                        .concat_str(module_path!())
+                        .concat_str(#self_name)
+                        .concat_str(#name)
+                        #orig_name
+                        .concat_bool(#is_async)
+                        .concat_value(#args_len)
+                        #(#arg_metadata_calls)*
+                        .concat(#type_id_meta)
+                        .concat_long_str(#docstring)
+                })
+            }
+
+            FnKind::TraitMethod {
+                self_ident,
+                index,
+                has_default,
+            } => {
                 let object_name = ident_to_string(self_ident);
                 Ok(quote! {
                     ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TRAIT_METHOD)
@@ -337,6 +455,7 @@ impl FnSignature {
                         .concat_str(#name)
                         #orig_name
                         .concat_bool(#is_async)
+                        .concat_bool(#has_default)
                         .concat_value(#args_len)
                         #(#arg_metadata_calls)*
                         .concat(#type_id_meta)
@@ -386,6 +505,18 @@ impl FnSignature {
                 ))
             }
 
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let self_name = ident_to_string(foreign_self_ident);
+                Ok(create_metadata_items(
+                    "static_method",
+                    &format!("{self_name}_{name}"),
+                    self.metadata_expr()?,
+                    Some(self.checksum_symbol_name()),
+                ))
+            }
+
             FnKind::TraitMethod { self_ident, .. } => {
                 let object_name = ident_to_string(self_ident);
                 Ok(create_metadata_items(
@@ -420,6 +551,12 @@ impl FnSignature {
                 let object_name = ident_to_string(foreign_self_ident);
                 uniffi_meta::method_checksum_symbol_name(&self.mod_path, &object_name, name)
             }
+            FnKind::StaticMethod {
+                foreign_self_ident, ..
+            } => {
+                let self_name = ident_to_string(foreign_self_ident);
+                uniffi_meta::static_method_checksum_symbol_name(&self.mod_path, &self_name, name)
+            }
             FnKind::TraitMethod { self_ident, .. } => uniffi_meta::method_checksum_symbol_name(
                 &self.mod_path,
                 &ident_to_string(self_ident),
@@ -467,11 +604,15 @@ impl Arg {
 
 pub(crate) enum ReceiverArg {
     Ref,
+    RefMut,
     Arc,
 }
 
 impl From<Receiver> for ReceiverArg {
     fn from(receiver: Receiver) -> Self {
+        if receiver.reference.is_some() && receiver.mutability.is_some() {
+            return ReceiverArg::RefMut;
+        }
         if let Type::Path(p) = *receiver.ty {
             if let Some(segment) = p.path.segments.last() {
                 // This comparison will fail if a user uses a typedef for Arc.  Maybe we could
@@ -557,6 +698,96 @@ fn looks_like_result(return_type: &ReturnType) -> bool {
     false
 }
 
+/// Erase the lifetimes from a return type, returning true if the type contains a reference.
+///
+/// Methods that return borrowed values, like `&str` or `Option<&'a Record>`, return references
+/// tied to `self`.  The scaffolding names the return type in expressions like
+/// `<&str as LowerReturn<UT>>::lower_return`, so replace the lifetimes with `'_` and let the
+/// compiler infer them.
+fn erase_return_lifetimes(ty: &mut Type) -> bool {
+    struct EraseLifetimes {
+        found_reference: bool,
+    }
+
+    impl VisitMut for EraseLifetimes {
+        fn visit_type_reference_mut(&mut self, r: &mut TypeReference) {
+            self.found_reference = true;
+            r.lifetime = None;
+            syn::visit_mut::visit_type_reference_mut(self, r);
+        }
+
+        fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+            *lifetime = Lifetime::new("'_", lifetime.span());
+        }
+    }
+
+    let mut visitor = EraseLifetimes {
+        found_reference: false,
+    };
+    visitor.visit_type_mut(ty);
+    visitor.found_reference
+}
+
+/// Syntactic check for `impl Stream<Item = T>`, returning `T`.
+///
+/// Other bounds, like `+ Send + 'static`, are allowed.  The trait can be referenced by any path
+/// that ends in `Stream`, for example `futures::Stream` or `futures::stream::Stream`.
+fn stream_item_type(ty: &Type) -> Option<&Type> {
+    let Type::ImplTrait(impl_trait) = ty else {
+        return None;
+    };
+    bounds_item_type(&impl_trait.bounds, "Stream")
+}
+
+/// Syntactic check for `impl Iterator<Item = T>` or `Box<dyn Iterator<Item = T> + Send>`,
+/// returning `T`.
+///
+/// Like for streams, other bounds are allowed and the trait can be referenced by any path that
+/// ends in `Iterator`.
+fn iterator_item_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::ImplTrait(impl_trait) => bounds_item_type(&impl_trait.bounds, "Iterator"),
+        Type::Path(type_path) => {
+            let seg = type_path.path.segments.last()?;
+            if seg.ident != "Box" {
+                return None;
+            }
+            let PathArguments::AngleBracketed(args) = &seg.arguments else {
+                return None;
+            };
+            let Some(GenericArgument::Type(Type::TraitObject(trait_object))) = args.args.first()
+            else {
+                return None;
+            };
+            bounds_item_type(&trait_object.bounds, "Iterator")
+        }
+        _ => None,
+    }
+}
+
+/// Find the `Item` associated type for the `trait_name` bound
+fn bounds_item_type<'a>(
+    bounds: impl IntoIterator<Item = &'a TypeParamBound>,
+    trait_name: &str,
+) -> Option<&'a Type> {
+    bounds.into_iter().find_map(|bound| {
+        let TypeParamBound::Trait(trait_bound) = bound else {
+            return None;
+        };
+        let seg = trait_bound.path.segments.last()?;
+        if seg.ident != trait_name {
+            return None;
+        }
+        let PathArguments::AngleBracketed(args) = &seg.arguments else {
+            return None;
+        };
+        args.args.iter().find_map(|arg| match arg {
+            GenericArgument::AssocType(assoc) if assoc.ident == "Item" => Some(&assoc.ty),
+            _ => None,
+        })
+    })
+}
+
 #[derive(Debug)]
 pub(crate) enum FnKind {
     Function,
@@ -568,8 +799,14 @@ pub(crate) enum FnKind {
         self_ident: Ident,
         foreign_self_ident: Ident,
     },
+    StaticMethod {
+        self_ident: Ident,
+        foreign_self_ident: Ident,
+    },
     TraitMethod {
         self_ident: Ident,
         index: u32,
+        // Does the trait provide a default implementation for this method?
+        has_default: bool,
     },
 }
@@ -0,0 +1,186 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Support for generic records and enums
+//!
+//! Foreign languages only see concrete types, so deriving `Record` or `Enum` for a generic type
+//! doesn't implement the FFI traits or generate metadata.  Instead, it implements the hidden
+//! `FfiConverterGeneric` trait that `uniffi::instantiate!` uses to do that for each instantiation,
+//! for example `uniffi::instantiate!(Page<User> as UserPage)`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    GenericParam, Generics, Token, Type, WherePredicate,
+};
+
+use crate::{
+    util::{create_metadata_items, ident_to_string},
+    DeriveOptions,
+};
+
+/// A generic record or enum that's been derived
+pub struct GenericItem<'a> {
+    pub ident: &'a Ident,
+    pub generics: &'a Generics,
+    /// Set if the item was renamed, which isn't allowed since `instantiate!` names each instance
+    pub renamed: bool,
+    /// Types of the fields that are lowered and lifted
+    pub field_types: Vec<&'a Type>,
+    /// Metadata code for the `TYPE_ID_META` const, for example `TYPE_RECORD`
+    pub type_id_code: TokenStream,
+    /// Metadata code for the item, for example `RECORD`
+    pub metadata_code: TokenStream,
+    /// Body of `FfiConverter::write`
+    pub write_impl: TokenStream,
+    /// Body of `FfiConverter::try_read`
+    pub try_read_impl: TokenStream,
+    /// Metadata that follows the module path and name
+    pub metadata_calls: TokenStream,
+}
+
+pub fn expand_generic_item(
+    item: GenericItem<'_>,
+    options: &DeriveOptions,
+) -> syn::Result<TokenStream> {
+    let GenericItem {
+        ident,
+        generics,
+        renamed,
+        field_types,
+        type_id_code,
+        metadata_code,
+        write_impl,
+        try_read_impl,
+        metadata_calls,
+    } = item;
+
+    if options.local_tag || !options.generate_metadata {
+        return Err(syn::Error::new_spanned(
+            generics,
+            "generic types are only supported with `#[derive]`",
+        ));
+    }
+    if renamed {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "generic types can't be renamed, they're named by `uniffi::instantiate!`",
+        ));
+    }
+    for param in &generics.params {
+        if !matches!(param, GenericParam::Type(_)) {
+            return Err(syn::Error::new_spanned(
+                param,
+                "generic types can only have type parameters",
+            ));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut predicates: Vec<WherePredicate> = where_clause
+        .map(|w| w.predicates.iter().cloned().collect())
+        .unwrap_or_default();
+    predicates.extend(field_types.into_iter().map(|ty| {
+        syn::parse_quote! {
+            #ty: ::uniffi::Lower<crate::UniFfiTag>
+                + ::uniffi::Lift<crate::UniFfiTag>
+                + ::uniffi::TypeId<crate::UniFfiTag>
+        }
+    }));
+
+    Ok(quote! {
+        #[automatically_derived]
+        unsafe impl #impl_generics ::uniffi::FfiConverterGeneric<crate::UniFfiTag>
+            for #ident #ty_generics where #(#predicates),*
+        {
+            const TYPE_ID_CODE: u8 = #type_id_code;
+            const METADATA_CODE: u8 = #metadata_code;
+            const METADATA_TAIL: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::new()
+                #metadata_calls;
+
+            fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
+                #write_impl
+            }
+
+            fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                #try_read_impl
+            }
+        }
+    })
+}
+
+/// Arguments for `uniffi::instantiate!`
+pub struct InstantiateArgs {
+    instances: Punctuated<Instance, Token![,]>,
+}
+
+impl Parse for InstantiateArgs {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self {
+            instances: input.parse_terminated(Instance::parse, Token![,])?,
+        })
+    }
+}
+
+/// A single `Type<Args> as Name` instance
+struct Instance {
+    ty: Type,
+    name: Ident,
+}
+
+impl Parse for Instance {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let ty = input.parse()?;
+        input.parse::<Token![as]>()?;
+        let name = input.parse()?;
+        Ok(Self { ty, name })
+    }
+}
+
+pub fn expand_instantiate(args: InstantiateArgs) -> TokenStream {
+    args.instances
+        .into_iter()
+        .map(|Instance { ty, name }| {
+            let name = ident_to_string(&name);
+            let generic = quote! { <#ty as ::uniffi::FfiConverterGeneric<crate::UniFfiTag>> };
+            let meta_static_var = create_metadata_items(
+                "instance",
+                &name,
+                quote! {
+                    ::uniffi::MetadataBuffer::from_code(#generic::METADATA_CODE)
+                        .concat_str(module_path!())
+                        .concat_str(#name)
+                        .concat(#generic::METADATA_TAIL)
+                },
+                None,
+            );
+
+            quote! {
+                #[automatically_derived]
+                unsafe impl ::uniffi::FfiConverter<crate::UniFfiTag> for #ty {
+                    ::uniffi::ffi_converter_rust_buffer_lift_and_lower!(crate::UniFfiTag);
+
+                    fn write(obj: Self, buf: &mut ::std::vec::Vec<u8>) {
+                        #generic::write(obj, buf)
+                    }
+
+                    fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
+                        #generic::try_read(buf)
+                    }
+
+                    const TYPE_ID_META: ::uniffi::MetadataBuffer =
+                        ::uniffi::MetadataBuffer::from_code(#generic::TYPE_ID_CODE)
+                            .concat_str(module_path!())
+                            .concat_str(#name);
+                }
+
+                ::uniffi::derive_ffi_traits!(local #ty);
+
+                #meta_static_var
+            }
+        })
+        .collect()
+}
@@ -23,9 +23,11 @@ mod error;
 mod export;
 mod ffiops;
 mod fnsig;
+mod generics;
 mod object;
 mod record;
 mod remote;
+mod serde_names;
 mod setup_scaffolding;
 mod test;
 mod util;
@@ -142,6 +144,14 @@ pub fn derive_error(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Generate FFI code for instances of generic records and enums
+///
+/// For example, `uniffi::instantiate!(Page<User> as UserPage, Page<Post> as PostPage)`.
+#[proc_macro]
+pub fn instantiate(tokens: TokenStream) -> TokenStream {
+    generics::expand_instantiate(parse_macro_input!(tokens)).into()
+}
+
 /// Generate FFI code for a custom type
 #[proc_macro]
 pub fn custom_type(tokens: TokenStream) -> TokenStream {
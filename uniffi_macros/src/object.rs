@@ -18,6 +18,8 @@ use uniffi_meta::{ObjectImpl, TraitKind};
 #[derive(Clone, Default)]
 pub struct ObjectAttr {
     pub name: Option<String>,
+    pub mutex: Option<kw::mutex>,
+    pub rwlock: Option<kw::rwlock>,
 }
 
 impl UniffiAttributeArgs for ObjectAttr {
@@ -27,7 +29,20 @@ impl UniffiAttributeArgs for ObjectAttr {
             let _: kw::name = input.parse()?;
             let _: Token![=] = input.parse()?;
             let name = Some(input.parse::<LitStr>()?.value());
-            Ok(Self { name })
+            Ok(Self {
+                name,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::mutex) {
+            Ok(Self {
+                mutex: input.parse()?,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::rwlock) {
+            Ok(Self {
+                rwlock: input.parse()?,
+                ..Self::default()
+            })
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -37,9 +52,18 @@ impl UniffiAttributeArgs for ObjectAttr {
     }
 
     fn merge(self, other: Self) -> syn::Result<Self> {
-        Ok(Self {
+        let merged = Self {
             name: either_attribute_arg(self.name, other.name)?,
-        })
+            mutex: either_attribute_arg(self.mutex, other.mutex)?,
+            rwlock: either_attribute_arg(self.rwlock, other.rwlock)?,
+        };
+        if let (Some(_), Some(rwlock)) = (&merged.mutex, &merged.rwlock) {
+            return Err(syn::Error::new(
+                rwlock.span,
+                "`mutex` and `rwlock` can't be used together",
+            ));
+        }
+        Ok(merged)
     }
 }
 
@@ -78,6 +102,27 @@ impl ObjectItem {
     fn docstring(&self) -> &str {
         self.docstring.as_str()
     }
+
+    /// Type that's stored in the `Arc` for handles
+    ///
+    /// This is `Self`, unless the object is stored behind a lock.
+    fn storage_type(&self) -> TokenStream {
+        let ident = &self.ident;
+        match self.lock_type() {
+            Some(lock) => quote! { #lock<#ident> },
+            None => quote! { #ident },
+        }
+    }
+
+    fn lock_type(&self) -> Option<TokenStream> {
+        if self.attr.mutex.is_some() {
+            Some(quote! { ::std::sync::Mutex })
+        } else if self.attr.rwlock.is_some() {
+            Some(quote! { ::std::sync::RwLock })
+        } else {
+            None
+        }
+    }
 }
 
 pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
@@ -102,7 +147,11 @@ pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<
         )
         .unwrap_or_else(syn::Error::into_compile_error)
     });
-    let interface_impl = interface_impl(&object, &options);
+    let interface_impl = match object.lock_type() {
+        Some(lock) => locked_interface_impl(&object, lock, &options),
+        None => interface_impl(&object, &options),
+    };
+    let storage_type = object.storage_type();
 
     Ok(quote! {
         #[doc(hidden)]
@@ -114,7 +163,7 @@ pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<
             ::uniffi::deps::trace!("clone: {} ({:?})", #name, handle);
             ::uniffi::rust_call(call_status, || {
                 unsafe {
-                    handle.clone_arc_handle::<#ident>()
+                    handle.clone_arc_handle::<#storage_type>()
                 };
                 ::std::result::Result::Ok(handle)
             })
@@ -129,7 +178,7 @@ pub fn expand_object(input: DeriveInput, options: DeriveOptions) -> syn::Result<
             ::uniffi::deps::trace!("free: {} ({:?})", #name, handle);
             ::uniffi::rust_call(call_status, || {
                 ::std::mem::drop(unsafe {
-                    handle.into_arc::<#ident>()
+                    handle.into_arc::<#storage_type>()
                 });
                 ::std::result::Result::Ok(())
             });
@@ -253,6 +302,74 @@ fn interface_impl(object: &ObjectItem, options: &DeriveOptions) -> TokenStream {
     }
 }
 
+/// FFI trait impls for objects declared with `#[uniffi(mutex)]` or `#[uniffi(rwlock)]`
+///
+/// These are stored as `Arc<Mutex<Self>>` or `Arc<RwLock<Self>>`.  `uniffi_core` implements
+/// `FfiConverterArc` for the lock type, so we only need to implement `LockedObject` plus the
+/// traits that are used to pass `Self` by value or as a method receiver.  `LiftRef` isn't
+/// implemented, since `&Self` can't be borrowed from the lock.
+fn locked_interface_impl(
+    object: &ObjectItem,
+    lock: TokenStream,
+    options: &DeriveOptions,
+) -> TokenStream {
+    let name = object.foreign_name();
+    let ident = object.ident();
+    let locked_object_impl_spec = options.ffi_impl_header("LockedObject", ident);
+    let lower_return_impl_spec = options.ffi_impl_header("LowerReturn", ident);
+    let lower_error_impl_spec = options.ffi_impl_header("LowerError", ident);
+    let type_id_impl_spec = options.ffi_impl_header("TypeId", ident);
+    let arc_lock_type = quote! { ::std::sync::Arc<#lock<Self>> };
+    let type_id_meta_arc = ffiops::type_id_meta(&arc_lock_type);
+    let lower_return_type_arc = ffiops::lower_return_type(&arc_lock_type);
+    let lower_return_arc = ffiops::lower_return(&arc_lock_type);
+    let lower_error_arc = ffiops::lower_error(&arc_lock_type);
+    let single_threaded_annotation = wasm_single_threaded_annotation();
+
+    quote! {
+        // See `interface_impl` for why we assert this.
+        #single_threaded_annotation
+        ::uniffi::deps::static_assertions::assert_impl_all!(
+            #lock<#ident>: ::core::marker::Sync, ::core::marker::Send
+        );
+
+        ::uniffi::deps::static_assertions::const_assert!(::std::mem::size_of::<*const ()>() <= 8);
+
+        #[doc(hidden)]
+        #[automatically_derived]
+        unsafe #locked_object_impl_spec {
+            type Lock = #lock<Self>;
+
+            const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TYPE_INTERFACE)
+                .concat_str(module_path!())
+                .concat_str(#name);
+        }
+
+        unsafe #lower_return_impl_spec {
+            type ReturnType = #lower_return_type_arc;
+
+            fn lower_return(obj: Self) -> ::std::result::Result<Self::ReturnType, ::uniffi::RustCallError> {
+                #lower_return_arc(::std::sync::Arc::new(#lock::new(obj)))
+            }
+        }
+
+        unsafe #lower_error_impl_spec {
+            fn lower_error(obj: Self) -> ::uniffi::RustBuffer {
+                #lower_error_arc(::std::sync::Arc::new(#lock::new(obj)))
+            }
+        }
+
+        // This uses the local tag, see `LiftReceiver` for why.
+        unsafe impl ::uniffi::LiftReceiver<crate::UniFfiTag> for #ident {
+            type LiftType = #arc_lock_type;
+        }
+
+        #type_id_impl_spec {
+            const TYPE_ID_META: ::uniffi::MetadataBuffer = #type_id_meta_arc;
+        }
+    }
+}
+
 pub(crate) fn interface_meta_static_var(
     name: &str,
     orig_name_metadata: TokenStream,
@@ -1,10 +1,15 @@
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use syn::{parse::ParseStream, Data, DataStruct, DeriveInput, Field, LitStr, Token};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::ParseStream, Data, DataStruct, DeriveInput, Expr, Field, Fields, Generics, LitStr,
+    Member, Token,
+};
 
 use crate::{
     default::{default_value_metadata_calls, DefaultValue},
-    ffiops,
+    derive, ffiops,
+    generics::{expand_generic_item, GenericItem},
+    serde_names::{self, SerdeNames},
     util::{
         create_metadata_items, either_attribute_arg, extract_docstring, ident_to_string, kw,
         orig_name_metadata, try_metadata_value_from_usize, try_read_field, AttributeSliceExt,
@@ -17,6 +22,7 @@ use crate::{
 #[derive(Clone, Default)]
 pub struct RecordAttr {
     pub name: Option<String>,
+    pub serde_names: Option<kw::serde_names>,
 }
 
 impl UniffiAttributeArgs for RecordAttr {
@@ -26,7 +32,15 @@ impl UniffiAttributeArgs for RecordAttr {
             let _: kw::name = input.parse()?;
             let _: Token![=] = input.parse()?;
             let name = Some(input.parse::<LitStr>()?.value());
-            Ok(Self { name })
+            Ok(Self {
+                name,
+                ..Self::default()
+            })
+        } else if lookahead.peek(kw::serde_names) {
+            Ok(Self {
+                serde_names: input.parse()?,
+                ..Self::default()
+            })
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -38,6 +52,7 @@ impl UniffiAttributeArgs for RecordAttr {
     fn merge(self, other: Self) -> syn::Result<Self> {
         Ok(Self {
             name: either_attribute_arg(self.name, other.name)?,
+            serde_names: either_attribute_arg(self.serde_names, other.serde_names)?,
         })
     }
 }
@@ -45,9 +60,13 @@ impl UniffiAttributeArgs for RecordAttr {
 /// Stores parsed data from the Derive Input for the struct.
 struct RecordItem {
     ident: Ident,
+    generics: Generics,
     record: DataStruct,
     docstring: String,
-    attr: RecordAttr,
+    // Name from either `#[uniffi(name)]` or `#[serde(rename)]`
+    name: Option<String>,
+    // Set when names come from the serde attributes
+    serde: Option<SerdeNames>,
 }
 
 impl RecordItem {
@@ -62,11 +81,21 @@ impl RecordItem {
             }
         };
         let attr = input.attrs.parse_uniffi_attr_args::<RecordAttr>()?;
+        let serde = attr
+            .serde_names
+            .is_some()
+            .then(|| SerdeNames::from_attrs(&input.attrs))
+            .transpose()?;
+        let name = attr
+            .name
+            .or_else(|| serde.as_ref().and_then(|s| s.rename.clone()));
         Ok(Self {
             ident: input.ident,
+            generics: input.generics,
             record,
             docstring: extract_docstring(&input.attrs)?,
-            attr,
+            name,
+            serde,
         })
     }
 
@@ -74,13 +103,30 @@ impl RecordItem {
         &self.ident
     }
 
+    fn is_generic(&self) -> bool {
+        !self.generics.params.is_empty()
+    }
+
     fn foreign_name(&self) -> String {
-        match &self.attr.name {
+        match &self.name {
             Some(name) => name.clone(),
             None => ident_to_string(&self.ident),
         }
     }
 
+    /// The name for a field if it's renamed, either with `#[uniffi(name)]` or serde attributes
+    fn field_rename(
+        &self,
+        f: &Field,
+        attrs: &FieldAttributeArguments,
+    ) -> syn::Result<Option<String>> {
+        match (&attrs.name, &self.serde) {
+            (Some(name), _) => Ok(Some(name.clone())),
+            (None, Some(serde)) => serde_names::field_name(f, serde.rename_all),
+            (None, None) => Ok(None),
+        }
+    }
+
     fn struct_(&self) -> &DataStruct {
         &self.record
     }
@@ -92,6 +138,9 @@ impl RecordItem {
 
 pub fn expand_record(input: DeriveInput, options: DeriveOptions) -> syn::Result<TokenStream> {
     let record = RecordItem::new(input)?;
+    if record.is_generic() {
+        return expand_generic_record(&record, &options);
+    }
     let ffi_converter =
         record_ffi_converter_impl(&record, &options).unwrap_or_else(syn::Error::into_compile_error);
     let meta_static_var = options
@@ -104,6 +153,35 @@ pub fn expand_record(input: DeriveInput, options: DeriveOptions) -> syn::Result<
     })
 }
 
+fn expand_generic_record(record: &RecordItem, options: &DeriveOptions) -> syn::Result<TokenStream> {
+    let field_types = record
+        .struct_()
+        .fields
+        .iter()
+        .map(|f| Ok((f, FieldAttributeArguments::for_field(f)?)))
+        .filter(|result| !matches!(result, Ok((_, attrs)) if attrs.skip.is_some()))
+        .map(|result| result.map(|(f, _)| &f.ty))
+        .collect::<syn::Result<_>>()?;
+    let metadata_calls = record_metadata_calls(record)?;
+    expand_generic_item(
+        GenericItem {
+            ident: record.ident(),
+            generics: &record.generics,
+            renamed: record.name.is_some(),
+            field_types,
+            type_id_code: quote! { ::uniffi::metadata::codes::TYPE_RECORD },
+            metadata_code: quote! { ::uniffi::metadata::codes::RECORD },
+            write_impl: record_write_impl(record)?,
+            try_read_impl: record_try_read_impl(record),
+            metadata_calls: quote! {
+                .concat_bool(false)
+                #metadata_calls
+            },
+        },
+        options,
+    )
+}
+
 fn record_ffi_converter_impl(
     record: &RecordItem,
     options: &DeriveOptions,
@@ -112,8 +190,9 @@ fn record_ffi_converter_impl(
     let impl_spec = options.ffi_impl_header("FfiConverter", ident);
     let derive_ffi_traits = options.derive_all_ffi_traits(ident);
     let name = &record.foreign_name();
-    let write_impl: TokenStream = record.struct_().fields.iter().map(write_field).collect();
-    let try_read_fields: TokenStream = record.struct_().fields.iter().map(try_read_field).collect();
+    let write_impl = record_write_impl(record)?;
+    let try_read_impl = record_try_read_impl(record);
+    let lower_borrowed_impl = record_lower_borrowed_impl(record, options)?;
 
     Ok(quote! {
         #[automatically_derived]
@@ -125,7 +204,7 @@ fn record_ffi_converter_impl(
             }
 
             fn try_read(buf: &mut &[::std::primitive::u8]) -> ::uniffi::deps::anyhow::Result<Self> {
-                ::std::result::Result::Ok(Self { #try_read_fields })
+                #try_read_impl
             }
 
             const TYPE_ID_META: ::uniffi::MetadataBuffer = ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::TYPE_RECORD)
@@ -134,21 +213,107 @@ fn record_ffi_converter_impl(
         }
 
         #derive_ffi_traits
+        #lower_borrowed_impl
     })
 }
 
-fn write_field(f: &Field) -> TokenStream {
-    let ident = &f.ident;
-    let write = ffiops::write(&f.ty);
+fn record_lower_borrowed_impl(
+    record: &RecordItem,
+    options: &DeriveOptions,
+) -> syn::Result<Option<TokenStream>> {
+    let mut field_types = vec![];
+    let mut write_fields = vec![];
+    for (f, member) in record
+        .struct_()
+        .fields
+        .iter()
+        .zip(record.struct_().fields.members())
+    {
+        if FieldAttributeArguments::for_field(f)?.skip.is_some() {
+            continue;
+        }
+        let write_borrowed = derive::write_borrowed(&f.ty);
+        field_types.push(&f.ty);
+        write_fields.push(quote! { #write_borrowed(&obj.#member, buf); });
+    }
+    Ok(options.lower_borrowed_impl(record.ident(), field_types, quote! { #(#write_fields)* }))
+}
+
+fn record_write_impl(record: &RecordItem) -> syn::Result<TokenStream> {
+    let fields = &record.struct_().fields;
+    fields
+        .iter()
+        .zip(fields.members())
+        .map(write_field)
+        .collect()
+}
+
+fn record_try_read_impl(record: &RecordItem) -> TokenStream {
+    let fields = &record.struct_().fields;
+    let try_read_fields: TokenStream = fields.iter().map(try_read_field).collect();
+    // Tuple structs are constructed positionally, unit structs with `Self {}`.
+    let construct = match fields {
+        Fields::Unnamed(_) => quote! { Self(#try_read_fields) },
+        _ => quote! { Self { #try_read_fields } },
+    };
     quote! {
-        #write(obj.#ident, buf);
+        ::std::result::Result::Ok(#construct)
     }
 }
 
+fn write_field((f, member): (&Field, Member)) -> syn::Result<TokenStream> {
+    if FieldAttributeArguments::for_field(f)?.skip.is_some() {
+        return Ok(quote! {});
+    }
+    let write = ffiops::write(&f.ty);
+    Ok(quote! {
+        #write(obj.#member, buf);
+    })
+}
+
 #[derive(Default)]
 pub struct FieldAttributeArguments {
     pub(crate) default: Option<DefaultValue>,
     pub(crate) name: Option<String>,
+    pub(crate) skip: Option<SkippedField>,
+}
+
+/// A field that's not exposed to the foreign side, set with `#[uniffi(skip)]`
+pub enum SkippedField {
+    // Filled in with `Default::default()` when lifting
+    Default,
+    // Filled in with an expression when lifting, ie `#[uniffi(skip = expr)]`
+    Expr(Box<Expr>),
+}
+
+impl ToTokens for SkippedField {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            SkippedField::Default => tokens.extend(quote! { skip }),
+            SkippedField::Expr(expr) => expr.to_tokens(tokens),
+        }
+    }
+}
+
+impl FieldAttributeArguments {
+    pub(crate) fn for_field(f: &Field) -> syn::Result<Self> {
+        let attrs = f.attrs.parse_uniffi_attr_args::<Self>()?;
+        if attrs.skip.is_some() && (attrs.default.is_some() || attrs.name.is_some()) {
+            return Err(syn::Error::new_spanned(
+                f,
+                "skipped fields can't have a `default` or `name`",
+            ));
+        }
+        Ok(attrs)
+    }
+
+    /// The value for a skipped field when lifting, or `None` if the field isn't skipped
+    pub(crate) fn skipped_value(&self) -> Option<TokenStream> {
+        self.skip.as_ref().map(|skip| match skip {
+            SkippedField::Default => quote! { ::std::default::Default::default() },
+            SkippedField::Expr(expr) => quote! { #expr },
+        })
+    }
 }
 
 impl UniffiAttributeArgs for FieldAttributeArguments {
@@ -174,6 +339,18 @@ impl UniffiAttributeArgs for FieldAttributeArguments {
                 name,
                 ..Self::default()
             })
+        } else if lookahead.peek(kw::skip) {
+            let _: kw::skip = input.parse()?;
+            let skip = Some(if input.peek(Token![=]) {
+                let _: Token![=] = input.parse()?;
+                SkippedField::Expr(Box::new(input.parse()?))
+            } else {
+                SkippedField::Default
+            });
+            Ok(Self {
+                skip,
+                ..Self::default()
+            })
         } else {
             Err(lookahead.error())
         }
@@ -183,36 +360,60 @@ impl UniffiAttributeArgs for FieldAttributeArguments {
         Ok(Self {
             default: either_attribute_arg(self.default, other.default)?,
             name: either_attribute_arg(self.name, other.name)?,
+            skip: either_attribute_arg(self.skip, other.skip)?,
         })
     }
 }
 
 fn record_meta_static_var(record: &RecordItem) -> syn::Result<TokenStream> {
     let name = &record.foreign_name();
-    let rec_orig_name_metadata = orig_name_metadata(record.attr.name.is_some(), &record.ident);
-    let docstring = record.docstring();
-    let fields_len = try_metadata_value_from_usize(
-        record.struct_().fields.len(),
-        "UniFFI limits structs to 256 fields",
-    )?;
+    let rec_orig_name_metadata = orig_name_metadata(record.name.is_some(), &record.ident);
+    let metadata_calls = record_metadata_calls(record)?;
 
-    let concat_fields: TokenStream = record
+    Ok(create_metadata_items(
+        "record",
+        name,
+        quote! {
+            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::RECORD)
+                .concat_str(module_path!())
+                .concat_str(#name)
+                #rec_orig_name_metadata
+                #metadata_calls
+        },
+        None,
+    ))
+}
+
+// Record metadata for the fields and docstring, which follows the name
+fn record_metadata_calls(record: &RecordItem) -> syn::Result<TokenStream> {
+    let docstring = record.docstring();
+    let fields = record
         .struct_()
         .fields
         .iter()
-        .map(|f| {
-            let attrs = f
-                .attrs
-                .parse_uniffi_attr_args::<FieldAttributeArguments>()?;
+        .map(|f| Ok((f, FieldAttributeArguments::for_field(f)?)))
+        .filter(|result| !matches!(result, Ok((_, attrs)) if attrs.skip.is_some()))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let fields_len =
+        try_metadata_value_from_usize(fields.len(), "UniFFI limits structs to 256 fields")?;
+
+    // Skipped fields aren't counted, so the exported tuple fields are always `v0`, `v1`, ...
+    let concat_fields: TokenStream = fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, (f, attrs))| {
+            let rename = record.field_rename(f, &attrs)?;
             let orig_name_metadata = match &f.ident {
-                Some(ident) => orig_name_metadata(attrs.name.is_some(), ident),
+                Some(ident) => orig_name_metadata(rename.is_some(), ident),
                 None => quote! {
                     .concat_bool(false)
                 },
             };
-            let name = attrs
-                .name
-                .unwrap_or(ident_to_string(f.ident.as_ref().unwrap()));
+            // Tuple struct fields are named positionally unless they're renamed
+            let name = rename.unwrap_or_else(|| match &f.ident {
+                Some(ident) => ident_to_string(ident),
+                None => format!("v{i}"),
+            });
             let docstring = extract_docstring(&f.attrs)?;
             let default = default_value_metadata_calls(&attrs.default)?;
             let type_id_meta = ffiops::type_id_meta(&f.ty);
@@ -229,18 +430,9 @@ fn record_meta_static_var(record: &RecordItem) -> syn::Result<TokenStream> {
         })
         .collect::<syn::Result<_>>()?;
 
-    Ok(create_metadata_items(
-        "record",
-        name,
-        quote! {
-            ::uniffi::MetadataBuffer::from_code(::uniffi::metadata::codes::RECORD)
-                .concat_str(module_path!())
-                .concat_str(#name)
-                #rec_orig_name_metadata
-                .concat_value(#fields_len)
-                #concat_fields
-                .concat_long_str(#docstring)
-        },
-        None,
-    ))
+    Ok(quote! {
+        .concat_value(#fields_len)
+        #concat_fields
+        .concat_long_str(#docstring)
+    })
 }
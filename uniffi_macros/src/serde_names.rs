@@ -0,0 +1,181 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Support for `#[uniffi(serde_names)]`, which names records, enums and their members using
+//! their `#[serde(...)]` attributes.
+//!
+//! Only the attributes that affect naming are read, everything else is ignored.  This includes
+//! `alias`, since items only have a single name in the foreign bindings.
+
+use proc_macro2::TokenTree;
+use syn::{meta::ParseNestedMeta, Attribute, Expr, Field, LitStr, Token, Variant};
+
+use crate::util::ident_to_string;
+
+/// Naming attributes from `#[serde(...)]` on a container, variant or field
+#[derive(Default)]
+pub struct SerdeNames {
+    pub rename: Option<String>,
+    pub rename_all: Option<RenameRule>,
+    pub rename_all_fields: Option<RenameRule>,
+}
+
+impl SerdeNames {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut names = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    if let Some(name) = serialize_name(&meta)? {
+                        names.rename = Some(name.value());
+                    }
+                } else if meta.path.is_ident("rename_all") {
+                    if let Some(rule) = serialize_name(&meta)? {
+                        names.rename_all = Some(RenameRule::from_lit(&rule)?);
+                    }
+                } else if meta.path.is_ident("rename_all_fields") {
+                    if let Some(rule) = serialize_name(&meta)? {
+                        names.rename_all_fields = Some(RenameRule::from_lit(&rule)?);
+                    }
+                } else if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<Expr>()?;
+                } else if !meta.input.is_empty() && !meta.input.peek(Token![,]) {
+                    meta.input.parse::<TokenTree>()?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(names)
+    }
+}
+
+/// The serde name of a field, from its `rename` attribute or the `rename_all` rule that applies to it
+pub fn field_name(f: &Field, rename_all: Option<RenameRule>) -> syn::Result<Option<String>> {
+    if let Some(name) = SerdeNames::from_attrs(&f.attrs)?.rename {
+        return Ok(Some(name));
+    }
+    Ok(match (&f.ident, rename_all) {
+        (Some(ident), Some(rule)) => Some(rule.apply_to_field(&ident_to_string(ident))),
+        _ => None,
+    })
+}
+
+/// The serde name of a variant, from its `rename` attribute or the enum's `rename_all` rule
+pub fn variant_name(v: &Variant, rename_all: Option<RenameRule>) -> syn::Result<Option<String>> {
+    if let Some(name) = SerdeNames::from_attrs(&v.attrs)?.rename {
+        return Ok(Some(name));
+    }
+    Ok(rename_all.map(|rule| rule.apply_to_variant(&ident_to_string(&v.ident))))
+}
+
+/// Parse the value of `rename = "..."` or `rename(serialize = "...", deserialize = "...")`
+///
+/// For the second form we use the serialize name, since that's what serde outputs.
+fn serialize_name(meta: &ParseNestedMeta<'_>) -> syn::Result<Option<LitStr>> {
+    if meta.input.peek(Token![=]) {
+        return Ok(Some(meta.value()?.parse()?));
+    }
+    let mut name = None;
+    meta.parse_nested_meta(|inner| {
+        let value: LitStr = inner.value()?.parse()?;
+        if inner.path.is_ident("serialize") {
+            name = Some(value);
+        }
+        Ok(())
+    })?;
+    Ok(name)
+}
+
+/// A `rename_all` rule, applied the same way as serde does
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        Ok(match lit.value().as_str() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return Err(syn::Error::new_spanned(lit, "unknown serde rename rule")),
+        })
+    }
+
+    /// Apply the rule to an enum variant name, which is expected to be in Pascal
+    pub fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Self::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Apply the rule to a field name, which is expected to be in snake_case
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
+    }
+}
@@ -25,6 +25,9 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
         format_ident!("ffi_{normalized_module_path}_rustbuffer_reserve");
     let reexport_hack_ident = format_ident!("{normalized_module_path}_uniffi_reexport_hack");
     let ffi_rust_future_scaffolding_fns = rust_future_scaffolding_fns(&normalized_module_path);
+    let ffi_rust_stream_scaffolding_fns = rust_stream_scaffolding_fns(&normalized_module_path);
+    let ffi_rust_iter_scaffolding_fns = rust_iter_scaffolding_fns(&normalized_module_path);
+    let ffi_closure_scaffolding_fns = closure_scaffolding_fns(&normalized_module_path);
 
     Ok(quote! {
         // Unit struct to parameterize the FfiConverter trait.
@@ -107,6 +110,12 @@ pub fn setup_scaffolding(namespace: String) -> Result<TokenStream> {
 
         #ffi_rust_future_scaffolding_fns
 
+        #ffi_rust_stream_scaffolding_fns
+
+        #ffi_rust_iter_scaffolding_fns
+
+        #ffi_closure_scaffolding_fns
+
         // Code to re-export the UniFFI scaffolding functions.
         //
         // Some build environments won't always re-export the functions from dependencies.
@@ -207,3 +216,122 @@ fn rust_future_scaffolding_fns(module_path: &str) -> TokenStream {
     })
     .collect()
 }
+
+/// Generates the rust_stream_* functions
+///
+/// Unlike futures, these don't depend on the item type.  `rust_stream_next` always returns a
+/// future for a `RustBuffer`.
+fn rust_stream_scaffolding_fns(module_path: &str) -> TokenStream {
+    let ffi_rust_stream_next = format_ident!("ffi_{module_path}_rust_stream_next");
+    let ffi_rust_stream_free = format_ident!("ffi_{module_path}_rust_stream_free");
+
+    quote! {
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_stream_next(handle: ::uniffi::Handle) -> ::uniffi::Handle {
+            ::uniffi::ffi::rust_stream_next(handle)
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_stream_free(handle: ::uniffi::Handle) {
+            ::uniffi::ffi::rust_stream_free(handle)
+        }
+    }
+}
+
+/// Generates the rust_iter_* functions
+///
+/// Like the stream functions, these don't depend on the item type.  `rust_iter_next_chunk` always
+/// returns a `RustBuffer`.
+fn rust_iter_scaffolding_fns(module_path: &str) -> TokenStream {
+    let ffi_rust_iter_next_chunk = format_ident!("ffi_{module_path}_rust_iter_next_chunk");
+    let ffi_rust_iter_free = format_ident!("ffi_{module_path}_rust_iter_free");
+
+    quote! {
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_iter_next_chunk(
+            handle: ::uniffi::Handle,
+            max_items: ::std::primitive::u32,
+            call_status: &mut ::uniffi::RustCallStatus,
+        ) -> ::uniffi::RustBuffer {
+            ::uniffi::rust_call(call_status, || {
+                ::std::result::Result::Ok(::uniffi::ffi::rust_iter_next_chunk(handle, max_items))
+            })
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_iter_free(handle: ::uniffi::Handle) {
+            ::uniffi::ffi::rust_iter_free(handle)
+        }
+    }
+}
+
+/// Generates the closure functions
+///
+/// Closures are type-erased, so these don't depend on the closure type.  The arguments and return
+/// value are always passed as `RustBuffer`s.  This also generates the foreign closure VTable
+/// storage for the component.
+fn closure_scaffolding_fns(module_path: &str) -> TokenStream {
+    let ffi_rust_closure_call = format_ident!("ffi_{module_path}_rust_closure_call");
+    let ffi_rust_closure_clone = format_ident!("ffi_{module_path}_rust_closure_clone");
+    let ffi_rust_closure_free = format_ident!("ffi_{module_path}_rust_closure_free");
+    let ffi_foreign_closure_init_vtable =
+        format_ident!("ffi_{module_path}_foreign_closure_init_vtable");
+
+    quote! {
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_closure_call(
+            handle: ::uniffi::Handle,
+            args: ::uniffi::RustBuffer,
+            call_status: &mut ::uniffi::RustCallStatus,
+        ) -> ::uniffi::RustBuffer {
+            ::uniffi::rust_call(call_status, || {
+                ::uniffi::ffi::rust_closure_call(handle, args)
+                    .map_err(|e| {
+                        ::uniffi::RustCallError::InternalError(::std::string::ToString::to_string(&e))
+                    })
+            })
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_closure_clone(handle: ::uniffi::Handle) -> ::uniffi::Handle {
+            ::uniffi::ffi::rust_closure_clone(handle)
+        }
+
+        #[allow(clippy::missing_safety_doc, missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub unsafe extern "C" fn #ffi_rust_closure_free(handle: ::uniffi::Handle) {
+            ::uniffi::ffi::rust_closure_free(handle)
+        }
+
+        static UNIFFI_FOREIGN_CLOSURE_VTABLE: ::uniffi::UniffiForeignPointerCell<::uniffi::ForeignClosureVTable> =
+            ::uniffi::UniffiForeignPointerCell::new();
+
+        #[allow(missing_docs)]
+        #[doc(hidden)]
+        #[unsafe(no_mangle)]
+        pub extern "C" fn #ffi_foreign_closure_init_vtable(
+            vtable: ::std::ptr::NonNull<::uniffi::ForeignClosureVTable>,
+        ) {
+            UNIFFI_FOREIGN_CLOSURE_VTABLE.set(vtable);
+        }
+
+        impl ::uniffi::HasForeignClosureVTable for UniFfiTag {
+            fn foreign_closure_vtable() -> &'static ::uniffi::ForeignClosureVTable {
+                UNIFFI_FOREIGN_CLOSURE_VTABLE.get()
+            }
+        }
+    }
+}
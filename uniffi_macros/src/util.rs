@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use crate::ffiops;
+use crate::{ffiops, record::FieldAttributeArguments};
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use std::path::{Path as StdPath, PathBuf};
@@ -78,14 +78,23 @@ pub fn mod_path() -> syn::Result<String> {
 
 pub fn try_read_field(f: &syn::Field) -> TokenStream {
     let ident = &f.ident;
-    let try_read = ffiops::try_read(&f.ty);
+    let value = match FieldAttributeArguments::for_field(f) {
+        Ok(attrs) => match attrs.skipped_value() {
+            Some(value) => value,
+            None => {
+                let try_read = ffiops::try_read(&f.ty);
+                quote! { #try_read(buf)? }
+            }
+        },
+        Err(e) => e.into_compile_error(),
+    };
 
     match ident {
         Some(ident) => quote! {
-            #ident: #try_read(buf)?,
+            #ident: #value,
         },
         None => quote! {
-            #try_read(buf)?,
+            #value,
         },
     }
 }
@@ -270,6 +279,10 @@ pub mod kw {
     syn::custom_keyword!(with_try_read);
     syn::custom_keyword!(name);
     syn::custom_keyword!(non_exhaustive);
+    syn::custom_keyword!(skip);
+    syn::custom_keyword!(mutex);
+    syn::custom_keyword!(rwlock);
+    syn::custom_keyword!(serde_names);
     syn::custom_keyword!(lower);
     syn::custom_keyword!(try_lift);
     syn::custom_keyword!(remote);
@@ -282,6 +295,10 @@ pub mod kw {
     syn::custom_keyword!(Eq);
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(Ord);
+    syn::custom_keyword!(Clone);
+    syn::custom_keyword!(Default);
+    syn::custom_keyword!(PartialOrd);
+    syn::custom_keyword!(FromStr);
     // Not used anymore
     syn::custom_keyword!(handle_unknown_callback_error);
 }
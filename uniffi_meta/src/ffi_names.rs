@@ -38,6 +38,22 @@ pub fn method_symbol_name(module_path: &str, object_name: &str, name: &str) -> S
     format!("uniffi_{namespace}_fn_method_{object_name}_{name}")
 }
 
+/// FFI symbol name for a static method
+pub fn static_method_symbol_name(module_path: &str, self_name: &str, name: &str) -> String {
+    let namespace = crate_name(module_path).replace("::", "__");
+    let self_name = self_name.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    format!("uniffi_{namespace}_fn_static_method_{self_name}_{name}")
+}
+
+/// FFI symbol name for calling the Rust default implementation of a trait method
+pub fn trait_default_method_symbol_name(module_path: &str, trait_name: &str, name: &str) -> String {
+    let namespace = crate_name(module_path).replace("::", "__");
+    let trait_name = trait_name.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    format!("uniffi_{namespace}_fn_default_method_{trait_name}_{name}")
+}
+
 /// FFI symbol name for the `clone` function for an object.
 pub fn clone_fn_symbol_name(module_path: &str, object_name: &str) -> String {
     let namespace = crate_name(module_path).replace("::", "__");
@@ -89,6 +105,18 @@ pub fn method_checksum_symbol_name(module_path: &str, object_name: &str, name: &
     format!("uniffi_{namespace}_checksum_method_{object_name}_{name}")
 }
 
+/// FFI checksum symbol name for a static method
+pub fn static_method_checksum_symbol_name(
+    module_path: &str,
+    self_name: &str,
+    name: &str,
+) -> String {
+    let namespace = crate_name(module_path).replace("::", "__");
+    let self_name = self_name.to_ascii_lowercase();
+    let name = name.to_ascii_lowercase();
+    format!("uniffi_{namespace}_checksum_static_method_{self_name}_{name}")
+}
+
 /// Get the symbol name for a FFI-buffer version of a function
 pub fn ffi_buffer_symbol_name(fn_name: &str) -> String {
     match fn_name.strip_prefix("uniffi_") {
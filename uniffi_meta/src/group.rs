@@ -41,7 +41,8 @@ pub fn create_metadata_groups(items: &[Metadata]) -> MetadataGroupMap {
 }
 
 /// Consume the items into the previously created metadata groups.
-pub fn group_metadata(group_map: &mut MetadataGroupMap, items: Vec<Metadata>) -> Result<()> {
+pub fn group_metadata(group_map: &mut MetadataGroupMap, mut items: Vec<Metadata>) -> Result<()> {
+    resolve_constant_literals(&mut items)?;
     for item in items {
         if matches!(&item, Metadata::Namespace(_)) {
             continue;
@@ -60,6 +61,86 @@ pub fn group_metadata(group_map: &mut MetadataGroupMap, items: Vec<Metadata>) ->
     Ok(())
 }
 
+/// Normalize the literals used by constants.
+///
+/// The proc-macros only see the Rust source, so record literals use the Rust field names, in
+/// whatever order the source listed them, and enum literals use the Rust variant names.  This
+/// rewrites them to use the exported names, with record fields in definition order.
+pub fn resolve_constant_literals(items: &mut [Metadata]) -> Result<()> {
+    let mut records = HashMap::new();
+    let mut enums = HashMap::new();
+    for item in items.iter() {
+        match item {
+            Metadata::Record(r) => {
+                records.insert((r.module_path.clone(), r.name.clone()), r.clone());
+            }
+            Metadata::Enum(e) => {
+                enums.insert((e.module_path.clone(), e.name.clone()), e.clone());
+            }
+            _ => (),
+        }
+    }
+    for item in items.iter_mut() {
+        if let Metadata::Constant(constant) = item {
+            resolve_constant_literal(&mut constant.value, &records, &enums)?;
+        }
+    }
+    Ok(())
+}
+
+fn resolve_constant_literal(
+    literal: &mut LiteralMetadata,
+    records: &HashMap<(String, String), RecordMetadata>,
+    enums: &HashMap<(String, String), EnumMetadata>,
+) -> Result<()> {
+    match literal {
+        LiteralMetadata::Some { inner } => {
+            if let DefaultValueMetadata::Literal(literal) = inner.as_mut() {
+                resolve_constant_literal(literal, records, enums)?;
+            }
+        }
+        LiteralMetadata::Enum(variant_name, Type::Enum { module_path, name }) => {
+            // Enums from crates we don't have metadata for are left as-is
+            let Some(enum_) = enums.get(&(module_path.clone(), name.clone())) else {
+                return Ok(());
+            };
+            let Some(variant) = enum_
+                .variants
+                .iter()
+                .find(|v| v.orig_name.as_deref().unwrap_or(&v.name) == variant_name)
+            else {
+                bail!("Enum literal for {name} has unknown variant {variant_name}");
+            };
+            *variant_name = variant.name.clone();
+        }
+        LiteralMetadata::Record { fields, ty } => {
+            let Type::Record { module_path, name } = ty else {
+                bail!("Record literal with non-record type: {ty:?}");
+            };
+            // Records from crates we don't have metadata for are left as-is
+            if let Some(record) = records.get(&(module_path.clone(), name.clone())) {
+                // Put the fields in declaration order and use the exported names.  The literal
+                // also has values for `#[uniffi(skip)]` fields, which the bindings never see.
+                let mut remaining = std::mem::take(fields);
+                for field in record.fields.iter() {
+                    let rust_name = field.orig_name.as_deref().unwrap_or(&field.name);
+                    let Some(pos) = remaining.iter().position(|f| f.name == rust_name) else {
+                        bail!("Record literal for {name} is missing field {rust_name}");
+                    };
+                    let mut value = remaining.remove(pos);
+                    value.name = field.name.clone();
+                    fields.push(value);
+                }
+            }
+            for field in fields.iter_mut() {
+                resolve_constant_literal(&mut field.value, records, enums)?;
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct MetadataGroup {
     pub namespace: NamespaceMetadata,
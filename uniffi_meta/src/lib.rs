@@ -10,7 +10,10 @@ mod ffi_names;
 pub use ffi_names::*;
 
 mod group;
-pub use group::{create_metadata_groups, group_metadata, MetadataGroup, MetadataGroupMap};
+pub use group::{
+    create_metadata_groups, group_metadata, resolve_constant_literals, MetadataGroup,
+    MetadataGroupMap,
+};
 
 mod reader;
 pub use reader::{read_metadata, read_metadata_type};
@@ -41,6 +44,18 @@ impl Checksum for bool {
     }
 }
 
+impl Checksum for char {
+    fn checksum<H: Hasher>(&self, state: &mut H) {
+        state.write(&u32::from(*self).to_le_bytes());
+    }
+}
+
+impl Checksum for u32 {
+    fn checksum<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.to_le_bytes());
+    }
+}
+
 impl Checksum for u64 {
     fn checksum<H: Hasher>(&self, state: &mut H) {
         state.write(&self.to_le_bytes());
@@ -200,6 +215,8 @@ pub struct MethodMetadata {
     pub return_type: Option<Type>,
     pub throws: Option<Type>,
     pub takes_self_by_arc: bool, // unused except by rust udl bindgen.
+    // Trait method with a default implementation in Rust
+    pub has_default: bool,
     pub checksum: Option<u16>,
     pub docstring: Option<String>,
 }
@@ -209,11 +226,44 @@ impl MethodMetadata {
         method_symbol_name(&self.module_path, &self.self_name, &self.name)
     }
 
+    /// FFI symbol for the function that calls the Rust default implementation, if there is one
+    pub fn default_ffi_symbol_name(&self) -> Option<String> {
+        self.has_default.then(|| {
+            trait_default_method_symbol_name(&self.module_path, &self.self_name, &self.name)
+        })
+    }
+
     pub fn checksum_symbol_name(&self) -> String {
         method_checksum_symbol_name(&self.module_path, &self.self_name, &self.name)
     }
 }
 
+/// Associated function without a receiver, exported as a static method on its type
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StaticMethodMetadata {
+    pub module_path: String,
+    pub self_name: String,
+    pub name: String,
+    // Original name, if this was renamed
+    pub orig_name: Option<String>,
+    pub is_async: bool,
+    pub inputs: Vec<FnParamMetadata>,
+    pub return_type: Option<Type>,
+    pub throws: Option<Type>,
+    pub checksum: Option<u16>,
+    pub docstring: Option<String>,
+}
+
+impl StaticMethodMetadata {
+    pub fn ffi_symbol_name(&self) -> String {
+        static_method_symbol_name(&self.module_path, &self.self_name, &self.name)
+    }
+
+    pub fn checksum_symbol_name(&self) -> String {
+        static_method_checksum_symbol_name(&self.module_path, &self.self_name, &self.name)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TraitMethodMetadata {
     pub module_path: String,
@@ -229,6 +279,8 @@ pub struct TraitMethodMetadata {
     pub return_type: Option<Type>,
     pub throws: Option<Type>,
     pub takes_self_by_arc: bool, // unused except by rust udl bindgen.
+    // Does the trait provide a default implementation for this method?
+    pub has_default: bool,
     pub checksum: Option<u16>,
     pub docstring: Option<String>,
 }
@@ -255,6 +307,7 @@ impl From<TraitMethodMetadata> for MethodMetadata {
             return_type: meta.return_type,
             throws: meta.throws,
             takes_self_by_arc: meta.takes_self_by_arc,
+            has_default: meta.has_default,
             checksum: meta.checksum,
             docstring: meta.docstring,
         }
@@ -286,6 +339,7 @@ impl FnParamMetadata {
 pub enum LiteralMetadata {
     Boolean(bool),
     String(String),
+    Char(char),
     // Integers are represented as the widest representation we can.
     // Number formatting vary with language and radix, so we avoid a lot of parsing and
     // formatting duplication by using only signed and unsigned variants.
@@ -301,7 +355,14 @@ pub enum LiteralMetadata {
     EmptyMap,
     EmptySet,
     None,
-    Some { inner: Box<DefaultValueMetadata> },
+    Some {
+        inner: Box<DefaultValueMetadata>,
+    },
+    // A record value, with every field given as a literal.
+    Record {
+        fields: Vec<FieldLiteralMetadata>,
+        ty: Type,
+    },
 }
 
 impl LiteralMetadata {
@@ -313,6 +374,14 @@ impl LiteralMetadata {
     }
 }
 
+/// A single field value in a [LiteralMetadata::Record].
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Checksum)]
+pub struct FieldLiteralMetadata {
+    pub name: String,
+    pub ty: Type,
+    pub value: LiteralMetadata,
+}
+
 // Represent the radix of integer literal values.
 // We preserve the radix into the generated bindings for readability reasons.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Checksum, Node, MapNode)]
@@ -461,40 +530,51 @@ pub enum UniffiTraitMetadata {
     Ord {
         cmp: MethodMetadata,
     },
+    Clone {
+        clone: MethodMetadata,
+    },
+    // `Default` and `FromStr` create new values, so they're exposed as static methods.
+    Default {
+        default: StaticMethodMetadata,
+    },
+    PartialOrd {
+        partial_cmp: MethodMetadata,
+    },
+    FromStr {
+        from_str: StaticMethodMetadata,
+    },
 }
 
 impl UniffiTraitMetadata {
-    fn module_path(&self) -> &str {
-        &match self {
-            UniffiTraitMetadata::Debug { fmt } => fmt,
-            UniffiTraitMetadata::Display { fmt } => fmt,
-            UniffiTraitMetadata::Eq { eq, .. } => eq,
-            UniffiTraitMetadata::Hash { hash } => hash,
-            UniffiTraitMetadata::Ord { cmp } => cmp,
+    /// `(module_path, self_name, name)` for the first method of the trait
+    fn method_names(&self) -> (&str, &str, &str) {
+        match self {
+            UniffiTraitMetadata::Debug { fmt: meth }
+            | UniffiTraitMetadata::Display { fmt: meth }
+            | UniffiTraitMetadata::Eq { eq: meth, .. }
+            | UniffiTraitMetadata::Hash { hash: meth }
+            | UniffiTraitMetadata::Ord { cmp: meth }
+            | UniffiTraitMetadata::Clone { clone: meth }
+            | UniffiTraitMetadata::PartialOrd { partial_cmp: meth } => {
+                (&meth.module_path, &meth.self_name, &meth.name)
+            }
+            UniffiTraitMetadata::Default { default: meth }
+            | UniffiTraitMetadata::FromStr { from_str: meth } => {
+                (&meth.module_path, &meth.self_name, &meth.name)
+            }
         }
-        .module_path
+    }
+
+    pub fn module_path(&self) -> &str {
+        self.method_names().0
     }
 
     pub fn self_name(&self) -> &str {
-        &match self {
-            UniffiTraitMetadata::Debug { fmt } => fmt,
-            UniffiTraitMetadata::Display { fmt } => fmt,
-            UniffiTraitMetadata::Eq { eq, .. } => eq,
-            UniffiTraitMetadata::Hash { hash } => hash,
-            UniffiTraitMetadata::Ord { cmp } => cmp,
-        }
-        .self_name
+        self.method_names().1
     }
 
     pub fn name(&self) -> &str {
-        &match self {
-            UniffiTraitMetadata::Debug { fmt } => fmt,
-            UniffiTraitMetadata::Display { fmt } => fmt,
-            UniffiTraitMetadata::Eq { eq, .. } => eq,
-            UniffiTraitMetadata::Hash { hash } => hash,
-            UniffiTraitMetadata::Ord { cmp } => cmp,
-        }
-        .name
+        self.method_names().2
     }
 }
 
@@ -506,6 +586,10 @@ pub enum UniffiTraitDiscriminants {
     Eq,
     Hash,
     Ord,
+    Clone,
+    Default,
+    PartialOrd,
+    FromStr,
 }
 
 impl UniffiTraitDiscriminants {
@@ -516,6 +600,10 @@ impl UniffiTraitDiscriminants {
             2 => UniffiTraitDiscriminants::Eq,
             3 => UniffiTraitDiscriminants::Hash,
             4 => UniffiTraitDiscriminants::Ord,
+            5 => UniffiTraitDiscriminants::Clone,
+            6 => UniffiTraitDiscriminants::Default,
+            7 => UniffiTraitDiscriminants::PartialOrd,
+            8 => UniffiTraitDiscriminants::FromStr,
             _ => anyhow::bail!("invalid trait discriminant {v}"),
         })
     }
@@ -545,6 +633,16 @@ pub struct CustomTypeMetadata {
     pub docstring: Option<String>,
 }
 
+/// A `const` or `static` item exported with a literal value.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ConstantMetadata {
+    pub module_path: String,
+    pub name: String,
+    pub ty: Type,
+    pub value: LiteralMetadata,
+    pub docstring: Option<String>,
+}
+
 /// Returns the last 16 bits of the value's hash as computed with [`SipHasher13`].
 ///
 /// This is used as a safeguard against different UniFFI versions being used for scaffolding and
@@ -568,10 +666,12 @@ pub enum Metadata {
     Enum(EnumMetadata),
     Constructor(ConstructorMetadata),
     Method(MethodMetadata),
+    StaticMethod(StaticMethodMetadata),
     TraitMethod(TraitMethodMetadata),
     CustomType(CustomTypeMetadata),
     UniffiTrait(UniffiTraitMetadata),
     ObjectTraitImpl(ObjectTraitImplMetadata),
+    Constant(ConstantMetadata),
 }
 
 impl Metadata {
@@ -586,6 +686,7 @@ impl Metadata {
             Metadata::Func(meta) => &meta.module_path,
             Metadata::Constructor(meta) => &meta.module_path,
             Metadata::Method(meta) => &meta.module_path,
+            Metadata::StaticMethod(meta) => &meta.module_path,
             Metadata::Record(meta) => &meta.module_path,
             Metadata::Enum(meta) => &meta.module_path,
             Metadata::Object(meta) => &meta.module_path,
@@ -594,6 +695,7 @@ impl Metadata {
             Metadata::CustomType(meta) => &meta.module_path,
             Metadata::UniffiTrait(meta) => meta.module_path(),
             Metadata::ObjectTraitImpl(t) => t.ty.crate_name().expect("type has no crate name"),
+            Metadata::Constant(meta) => &meta.module_path,
         }
     }
 }
@@ -628,6 +730,12 @@ impl From<MethodMetadata> for Metadata {
     }
 }
 
+impl From<StaticMethodMetadata> for Metadata {
+    fn from(m: StaticMethodMetadata) -> Self {
+        Self::StaticMethod(m)
+    }
+}
+
 impl From<RecordMetadata> for Metadata {
     fn from(r: RecordMetadata) -> Self {
         Self::Record(r)
@@ -658,6 +766,12 @@ impl From<TraitMethodMetadata> for Metadata {
     }
 }
 
+impl From<ConstantMetadata> for Metadata {
+    fn from(v: ConstantMetadata) -> Self {
+        Self::Constant(v)
+    }
+}
+
 impl From<CustomTypeMetadata> for Metadata {
     fn from(v: CustomTypeMetadata) -> Self {
         Self::CustomType(v)
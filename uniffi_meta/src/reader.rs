@@ -51,6 +51,7 @@ impl<'a> MetadataReader<'a> {
             codes::FUNC => self.read_func()?.into(),
             codes::CONSTRUCTOR => self.read_constructor()?.into(),
             codes::METHOD => self.read_method()?.into(),
+            codes::STATIC_METHOD => self.read_static_method()?.into(),
             codes::RECORD => self.read_record()?.into(),
             codes::ENUM => self.read_enum()?.into(),
             codes::INTERFACE => self.read_object(ObjectImpl::Struct)?.into(),
@@ -64,6 +65,7 @@ impl<'a> MetadataReader<'a> {
             codes::UNIFFI_TRAIT => self.read_uniffi_trait()?.into(),
             codes::OBJECT_TRAIT_IMPL => self.read_object_trait_impl()?.into(),
             codes::CUSTOM_TYPE => self.read_custom_type()?.into(),
+            codes::CONSTANT => self.read_constant()?.into(),
             _ => bail!("Unexpected metadata code: {value:?}"),
         })
     }
@@ -149,6 +151,9 @@ impl<'a> MetadataReader<'a> {
             codes::TYPE_I32 => Type::Int32,
             codes::TYPE_U64 => Type::UInt64,
             codes::TYPE_I64 => Type::Int64,
+            codes::TYPE_U128 => Type::UInt128,
+            codes::TYPE_I128 => Type::Int128,
+            codes::TYPE_CHAR => Type::Char,
             codes::TYPE_F32 => Type::Float32,
             codes::TYPE_F64 => Type::Float64,
             codes::TYPE_BOOL => Type::Boolean,
@@ -203,13 +208,43 @@ impl<'a> MetadataReader<'a> {
                     }
                 }
             }
-            codes::TYPE_HASH_MAP => Type::Map {
+            codes::TYPE_ARRAY => {
+                let len = self.read_u32()?;
+                Type::Array {
+                    inner_type: Box::new(self.read_type()?),
+                    len,
+                }
+            }
+            codes::TYPE_HASH_MAP | codes::TYPE_ORDERED_MAP => Type::Map {
                 key_type: Box::new(self.read_type()?),
                 value_type: Box::new(self.read_type()?),
+                ordered: value == codes::TYPE_ORDERED_MAP,
             },
-            codes::TYPE_HASH_SET => Type::Set {
+            codes::TYPE_HASH_SET | codes::TYPE_ORDERED_SET => Type::Set {
                 inner_type: Box::new(self.read_type()?),
+                ordered: value == codes::TYPE_ORDERED_SET,
             },
+            codes::TYPE_TUPLE => {
+                let len = self.read_u8()?;
+                Type::Tuple {
+                    inner_types: (0..len).map(|_| self.read_type()).collect::<Result<_>>()?,
+                }
+            }
+            codes::TYPE_STREAM => Type::Stream {
+                item_type: Box::new(self.read_type()?),
+            },
+            codes::TYPE_ITERATOR => Type::Iterator {
+                item_type: Box::new(self.read_type()?),
+            },
+            codes::TYPE_CLOSURE => {
+                let count = self.read_u8()?;
+                Type::Closure {
+                    argument_types: (0..count)
+                        .map(|_| self.read_type())
+                        .collect::<Result<_>>()?,
+                    return_type: self.read_optional_type()?.map(Box::new),
+                }
+            }
             codes::TYPE_UNIT => bail!("Unexpected TYPE_UNIT"),
             codes::TYPE_RESULT => bail!("Unexpected TYPE_RESULT"),
             _ => bail!("Unexpected metadata type code: {value:?}"),
@@ -316,6 +351,30 @@ impl<'a> MetadataReader<'a> {
             return_type,
             throws,
             takes_self_by_arc: false, // not emitted by macros
+            has_default: false,
+            checksum: self.calc_checksum(),
+            docstring,
+        })
+    }
+
+    fn read_static_method(&mut self) -> Result<StaticMethodMetadata> {
+        let module_path = self.read_string()?;
+        let self_name = self.read_string()?;
+        let name = self.read_string()?;
+        let orig_name = self.read_optional_string()?;
+        let is_async = self.read_bool()?;
+        let inputs = self.read_inputs()?;
+        let (return_type, throws) = self.read_return_type()?;
+        let docstring = self.read_optional_long_string()?;
+        Ok(StaticMethodMetadata {
+            module_path,
+            self_name,
+            name,
+            orig_name,
+            is_async,
+            inputs,
+            return_type,
+            throws,
             checksum: self.calc_checksum(),
             docstring,
         })
@@ -392,34 +451,69 @@ impl<'a> MetadataReader<'a> {
         })
     }
 
+    fn read_constant(&mut self) -> Result<ConstantMetadata> {
+        let module_path = self.read_string()?;
+        let name = self.read_string()?;
+        let ty = self.read_type()?;
+        let value = self.read_literal(&name, &ty)?;
+        Ok(ConstantMetadata {
+            module_path,
+            name,
+            ty,
+            value,
+            docstring: self.read_optional_long_string()?,
+        })
+    }
+
     fn read_uniffi_trait(&mut self) -> Result<UniffiTraitMetadata> {
         let code = self.read_u8()?;
-        let mut read_metadata_method = || -> Result<MethodMetadata> {
-            let code = self.read_u8()?;
-            ensure!(code == codes::METHOD, "expected METHOD but read {code}");
-            self.read_method()
-        };
-
         Ok(match UniffiTraitDiscriminants::from(code)? {
             UniffiTraitDiscriminants::Debug => UniffiTraitMetadata::Debug {
-                fmt: read_metadata_method()?,
+                fmt: self.read_uniffi_trait_method()?,
             },
             UniffiTraitDiscriminants::Display => UniffiTraitMetadata::Display {
-                fmt: read_metadata_method()?,
+                fmt: self.read_uniffi_trait_method()?,
             },
             UniffiTraitDiscriminants::Eq => UniffiTraitMetadata::Eq {
-                eq: read_metadata_method()?,
-                ne: read_metadata_method()?,
+                eq: self.read_uniffi_trait_method()?,
+                ne: self.read_uniffi_trait_method()?,
             },
             UniffiTraitDiscriminants::Hash => UniffiTraitMetadata::Hash {
-                hash: read_metadata_method()?,
+                hash: self.read_uniffi_trait_method()?,
             },
             UniffiTraitDiscriminants::Ord => UniffiTraitMetadata::Ord {
-                cmp: read_metadata_method()?,
+                cmp: self.read_uniffi_trait_method()?,
+            },
+            UniffiTraitDiscriminants::Clone => UniffiTraitMetadata::Clone {
+                clone: self.read_uniffi_trait_method()?,
+            },
+            UniffiTraitDiscriminants::Default => UniffiTraitMetadata::Default {
+                default: self.read_uniffi_trait_static_method()?,
+            },
+            UniffiTraitDiscriminants::PartialOrd => UniffiTraitMetadata::PartialOrd {
+                partial_cmp: self.read_uniffi_trait_method()?,
+            },
+            UniffiTraitDiscriminants::FromStr => UniffiTraitMetadata::FromStr {
+                from_str: self.read_uniffi_trait_static_method()?,
             },
         })
     }
 
+    fn read_uniffi_trait_method(&mut self) -> Result<MethodMetadata> {
+        let code = self.read_u8()?;
+        ensure!(code == codes::METHOD, "expected METHOD but read {code}");
+        self.read_method()
+    }
+
+    fn read_uniffi_trait_static_method(&mut self) -> Result<StaticMethodMetadata> {
+        let code = self.read_u8()?;
+        ensure!(
+            code == codes::STATIC_METHOD,
+            "expected STATIC_METHOD but read {code}"
+        );
+        self.read_static_method()
+    }
+
     fn read_callback_interface(&mut self) -> Result<CallbackInterfaceMetadata> {
         Ok(CallbackInterfaceMetadata {
             module_path: self.read_string()?,
@@ -435,6 +529,7 @@ impl<'a> MetadataReader<'a> {
         let name = self.read_string()?;
         let orig_name = self.read_optional_string()?;
         let is_async = self.read_bool()?;
+        let has_default = self.read_bool()?;
         let inputs = self.read_inputs()?;
         let (return_type, throws) = self.read_return_type()?;
         let docstring = self.read_optional_long_string()?;
@@ -449,6 +544,7 @@ impl<'a> MetadataReader<'a> {
             return_type,
             throws,
             takes_self_by_arc: false, // not emitted by macros
+            has_default,
             checksum: self.calc_checksum(),
             docstring,
         })
@@ -582,6 +678,17 @@ impl<'a> MetadataReader<'a> {
                 );
                 LiteralMetadata::String(self.read_string()?)
             }
+            codes::LIT_CHAR => {
+                ensure!(
+                    matches!(ty, Type::Char),
+                    "field {name} of type {ty:?} can't have a default value of type char"
+                );
+                let value = self.read_u32()?;
+                LiteralMetadata::Char(
+                    char::from_u32(value)
+                        .with_context(|| format!("invalid char for field {name}: {value}"))?,
+                )
+            }
             codes::LIT_INT => {
                 let base10_digits = self.read_string()?;
                 // procmacros emit the type for discriminant values based purely on whether the constant
@@ -645,6 +752,28 @@ impl<'a> MetadataReader<'a> {
             codes::LIT_EMPTY_SEQ => LiteralMetadata::EmptySequence,
             codes::LIT_EMPTY_MAP => LiteralMetadata::EmptyMap,
             codes::LIT_EMPTY_SET => LiteralMetadata::EmptySet,
+            codes::LIT_ENUM => match ty {
+                Type::Enum { .. } => LiteralMetadata::Enum(self.read_string()?, ty.clone()),
+                _ => bail!("{name} of type {ty:?} can't have an enum variant value"),
+            },
+            codes::LIT_RECORD => match ty {
+                Type::Record { .. } => {
+                    let len = self.read_u8()?;
+                    let fields = (0..len)
+                        .map(|_| {
+                            let name = self.read_string()?;
+                            let ty = self.read_type()?;
+                            let value = self.read_literal(&name, &ty)?;
+                            Ok(FieldLiteralMetadata { name, ty, value })
+                        })
+                        .collect::<Result<_>>()?;
+                    LiteralMetadata::Record {
+                        fields,
+                        ty: ty.clone(),
+                    }
+                }
+                _ => bail!("{name} of type {ty:?} can't have a record value"),
+            },
             _ => bail!("Unexpected literal kind code: {literal_kind:?}"),
         })
     }
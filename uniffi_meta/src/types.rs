@@ -91,9 +91,13 @@ pub enum Type {
     Int32,
     UInt64,
     Int64,
+    UInt128,
+    Int128,
     Float32,
     Float64,
     Boolean,
+    /// A Unicode scalar value, `char` in Rust.
+    Char,
     String,
     Bytes,
     Timestamp,
@@ -131,12 +135,44 @@ pub enum Type {
     Sequence {
         inner_type: Box<Type>,
     },
+    /// Fixed-size array, `[T; N]` in Rust.
+    Array {
+        inner_type: Box<Type>,
+        len: u32,
+    },
     Map {
         key_type: Box<Type>,
         value_type: Box<Type>,
+        /// True if the entry order is meaningful, for example `BTreeMap`.
+        /// Bindings should use a container that preserves it.
+        ordered: bool,
     },
     Set {
         inner_type: Box<Type>,
+        /// True if the item order is meaningful, for example `BTreeSet`.
+        ordered: bool,
+    },
+    Tuple {
+        inner_types: Vec<Type>,
+    },
+    /// Async stream of items, `impl Stream<Item = T>` in Rust.
+    /// Streams are passed as handles, each item is returned from a Rust future as an
+    /// `Option<T>` where `None` signals the end of the stream.
+    Stream {
+        item_type: Box<Type>,
+    },
+    /// Iterator of items, `impl Iterator<Item = T>` in Rust.
+    /// Iterators are passed as handles, items are returned in chunks as a `Vec<T>` where an empty
+    /// `Vec` signals the end of the iterator.
+    Iterator {
+        item_type: Box<Type>,
+    },
+    /// Closure, `Box<dyn Fn(A, B) -> R + Send + Sync>` or `Arc<dyn Fn(A, B) -> R + Send + Sync>`
+    /// in Rust.  Closures are passed as handles, the arguments and return value are serialized
+    /// into `RustBuffer`s when calling them.
+    Closure {
+        argument_types: Vec<Type>,
+        return_type: Option<Box<Type>>,
     },
     // Custom type on the scaffolding side
     Custom {
@@ -157,11 +193,24 @@ impl Type {
         match self {
             Type::Optional { inner_type }
             | Type::Sequence { inner_type }
-            | Type::Set { inner_type } => inner_type.iter_types(),
+            | Type::Array { inner_type, .. }
+            | Type::Set { inner_type, .. } => inner_type.iter_types(),
+            Type::Stream { item_type } | Type::Iterator { item_type } => item_type.iter_types(),
             Type::Map {
                 key_type,
                 value_type,
+                ..
             } => Box::new(key_type.iter_types().chain(value_type.iter_types())),
+            Type::Tuple { inner_types } => Box::new(inner_types.iter().flat_map(Type::iter_types)),
+            Type::Closure {
+                argument_types,
+                return_type,
+            } => Box::new(
+                argument_types
+                    .iter()
+                    .chain(return_type.as_deref())
+                    .flat_map(Type::iter_types),
+            ),
             Type::Custom { builtin, .. } => builtin.iter_types(),
             _ => Box::new(std::iter::empty()),
         }
@@ -215,9 +264,13 @@ impl Type {
         match self {
             Type::Optional { inner_type }
             | Type::Sequence { inner_type }
-            | Type::Set { inner_type } => {
+            | Type::Array { inner_type, .. }
+            | Type::Set { inner_type, .. } => {
                 inner_type.rename_recursive(name_transformer);
             }
+            Type::Stream { item_type } | Type::Iterator { item_type } => {
+                item_type.rename_recursive(name_transformer);
+            }
             Type::Map {
                 key_type,
                 value_type,
@@ -226,6 +279,19 @@ impl Type {
                 key_type.rename_recursive(name_transformer);
                 value_type.rename_recursive(name_transformer);
             }
+            Type::Tuple { inner_types } => {
+                for inner_type in inner_types {
+                    inner_type.rename_recursive(name_transformer);
+                }
+            }
+            Type::Closure {
+                argument_types,
+                return_type,
+            } => {
+                for ty in argument_types.iter_mut().chain(return_type.as_deref_mut()) {
+                    ty.rename_recursive(name_transformer);
+                }
+            }
             Type::Custom { builtin, .. } => {
                 builtin.rename_recursive(name_transformer);
             }
@@ -34,7 +34,7 @@ macro_rules! simple_nodes {
     };
 }
 
-simple_nodes!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64, String, bool,);
+simple_nodes!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64, String, bool, char,);
 
 impl<Input, Output, Context> MapNode<Box<Output>, Context> for Box<Input>
 where
@@ -177,6 +177,7 @@ impl APIConverter<MethodMetadata> for weedle::interface::OperationInterfaceMembe
             return_type,
             throws,
             takes_self_by_arc,
+            has_default: false,
             checksum: None,
             docstring: self.docstring.as_ref().map(|v| convert_docstring(&v.0)),
         })
@@ -224,6 +225,7 @@ impl APIConverter<TraitMethodMetadata> for weedle::interface::OperationInterface
             return_type,
             throws,
             takes_self_by_arc,
+            has_default: false,
             checksum: None,
             docstring: self.docstring.as_ref().map(|v| convert_docstring(&v.0)),
         })
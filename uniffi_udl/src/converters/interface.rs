@@ -69,6 +69,7 @@ impl APIConverter<ObjectMetadata> for weedle::InterfaceDefinition<'_> {
                 return_type,
                 throws: None,
                 takes_self_by_arc: false,
+                has_default: false,
                 checksum: None,
                 docstring: None,
             })
@@ -203,6 +203,7 @@ fn make_uniffi_traits(
             return_type,
             throws: None,
             takes_self_by_arc: false,
+            has_default: false,
             checksum: None,
             docstring: None,
         })
@@ -148,7 +148,8 @@ mod test {
                 "{}",
                 Type::Map {
                     key_type: Box::new(Type::String),
-                    value_type: Box::new(Type::String)
+                    value_type: Box::new(Type::String),
+                    ordered: false,
                 }
             )?,
             Literal::EmptyMap
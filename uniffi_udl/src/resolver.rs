@@ -148,6 +148,7 @@ impl TypeResolver for weedle::types::RecordType<'_> {
         let map = Type::Map {
             key_type: Box::new(key_type),
             value_type: Box::new(value_type),
+            ordered: false,
         };
         Ok(map)
     }